@@ -1,6 +1,5 @@
 use firehazard::*;
 
-use std::collections::*;
 use std::path::PathBuf;
 
 
@@ -8,7 +7,7 @@ use std::path::PathBuf;
 #[derive(Debug)]
 pub struct Token {
     pub integrity:      sid::integrity::Level,
-    pub privileges:     HashSet<privilege::Luid>,
+    pub privileges:     privilege::Set,
     pub enabled:        Vec<sid::Ptr<'static>>,
     pub restricted:     Option<Vec<sid::Ptr<'static>>>,
 }
@@ -67,7 +66,7 @@ impl Target {
         let build = if cfg!(debug_assertions) { "debug" } else { "release" };
         let arch = "x86_64-pc-windows-msvc"; // XXX
 
-        let se_change_notify_privilege = privilege::name::CHANGE_NOTIFY.lookup_luid().unwrap();
+        let se_change_notify_privilege = privilege::name::CHANGE_NOTIFY;
         let mut targets = vec![
             Target {
                 exe: manifest_dir.join(format!(r"crates\no-std\target\{build}\examples\trivial.exe")),
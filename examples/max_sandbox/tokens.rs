@@ -16,7 +16,7 @@ pub fn create(target: &crate::settings::Target) -> Tokens {
         &sandbox_process_token,
         None,
         |saa| !target.spawn.enabled.iter().any(|e| *saa.sid == **e),
-        |p| !target.spawn.privileges.contains(&p.luid),
+        |p| !target.spawn.privileges.contains(p.luid),
         Some(&target.spawn.restricted.as_ref().unwrap_or(&all_group_sids).iter().copied().map(|r| sid::AndAttributes::new(r, None)).collect::<Vec<_>>()[..]),
     ).unwrap();
 
@@ -24,7 +24,7 @@ pub fn create(target: &crate::settings::Target) -> Tokens {
         &sandbox_process_token,
         None,
         |saa| !target.lockdown.enabled.iter().any(|e| *saa.sid == **e),
-        |p| !target.lockdown.privileges.contains(&p.luid),
+        |p| !target.lockdown.privileges.contains(p.luid),
         Some(&target.lockdown.restricted.as_ref().unwrap_or(&all_group_sids).iter().copied().map(|r| sid::AndAttributes::new(r, None)).collect::<Vec<_>>()[..]),
     ).unwrap();
 
@@ -39,7 +39,6 @@ pub fn create(target: &crate::settings::Target) -> Tokens {
         let mut acl = acl::Builder::new(acl::REVISION);
         acl.add_acl(acl::REVISION, 0, restricted.default_dacl().unwrap().default_dacl().unwrap()).unwrap(); // allow debuggers to attach, task managers to kill, etc.
         acl.add_access_allowed_ace(acl::REVISION, token::ADJUST_DEFAULT | token::QUERY, logon_session_sid).unwrap();
-        acl.finish().unwrap();
         restricted.set_default_dacl(&mut acl).unwrap();
     }
 
@@ -93,7 +93,6 @@ fn server(mut args: std::env::Args) {
     let rev = acl::REVISION; // TODO: make acl::REVISION nonsense more internal?  choice between it and REVISION_DS is if object guids are present...?
     let mut dacl = acl::Builder::new(rev);
     dacl.add_access_allowed_ace(rev, file::GENERIC_READ | file::GENERIC_WRITE, logon).unwrap(); // If the client needs to open the pipe for read or write, the server needs the other, even though it's creating the pipe.
-    dacl.finish().unwrap(); // TODO: gross
     let security_descriptor = security::DescriptorBuilder::new().dacl(&mut dacl, false).unwrap().finish();
     let security_attributes = security::Attributes::new(Some(&security_descriptor), false);
 
@@ -8,6 +8,9 @@ cargo build --manifest-path crates/no-std/Cargo.toml --release --example trivial
 cargo test --workspace
 @if ERRORLEVEL 1 goto :die
 
+cargo test -p firehazard --all-features
+@if ERRORLEVEL 1 goto :die
+
 cargo build --examples
 @if ERRORLEVEL 1 goto :die
 
@@ -9,4 +9,5 @@
 //!     *   [If you ask for STANDARD_RIGHTS_REQUIRED, you may as well ask for the moon](https://devblogs.microsoft.com/oldnewthing/20080227-00/?p=23303)
 
 pub(crate) mod constants;       pub use constants::*;
+mod generic_mapping;            pub use generic_mapping::*;
 mod mask;                       pub use mask::*;
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use winapi::um::winnt::*;
+
+
+
+#[doc(alias = "GENERIC_MAPPING")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-generic_mapping)\]
+/// GENERIC_MAPPING: Which object specific rights `GENERIC_{READ,WRITE,EXECUTE,ALL}` stand for
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let mapped = access::GenericMapping::FILE.map(access::GENERIC_READ | access::DELETE);
+/// assert_eq!(u32::from(mapped), 0x0012_0089 | 0x0001_0000); // FILE_GENERIC_READ | DELETE
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)] pub struct GenericMapping {
+    pub generic_read:       access::Mask,
+    pub generic_write:      access::Mask,
+    pub generic_execute:    access::Mask,
+    pub generic_all:        access::Mask,
+}
+
+structure!(@assert layout access::GenericMapping => GENERIC_MAPPING {
+    generic_read        == GenericRead,
+    generic_write       == GenericWrite,
+    generic_execute     == GenericExecute,
+    generic_all         == GenericAll,
+});
+
+impl GenericMapping {
+    /// The generic mapping used by files, directories, and pipes (`FILE_GENERIC_*`, `FILE_ALL_ACCESS`.)
+    pub const FILE : Self = Self::from_u32s(FILE_GENERIC_READ, FILE_GENERIC_WRITE, FILE_GENERIC_EXECUTE, FILE_ALL_ACCESS);
+
+    /// The generic mapping used by registry keys (`KEY_READ`, `KEY_WRITE`, `KEY_EXECUTE`, `KEY_ALL_ACCESS`.)
+    pub const KEY  : Self = Self::from_u32s(KEY_READ, KEY_WRITE, KEY_EXECUTE, KEY_ALL_ACCESS);
+
+    const fn from_u32s(read: u32, write: u32, execute: u32, all: u32) -> Self {
+        unsafe { Self {
+            generic_read:       access::Mask::from_unchecked(read),
+            generic_write:      access::Mask::from_unchecked(write),
+            generic_execute:    access::Mask::from_unchecked(execute),
+            generic_all:        access::Mask::from_unchecked(all),
+        }}
+    }
+
+    #[doc(alias = "MapGenericMask")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-mapgenericmask)\]
+    /// MapGenericMask: Replace `GENERIC_{READ,WRITE,EXECUTE,ALL}` in `mask` with the rights they map to.
+    ///
+    pub fn map(&self, mask: impl Into<access::Mask>) -> access::Mask {
+        let mask = mask.into();
+        let mut mapped = mask & !(access::GENERIC_READ | access::GENERIC_WRITE | access::GENERIC_EXECUTE | access::GENERIC_ALL);
+        if mask & access::GENERIC_READ      != access::Mask::default() { mapped |= self.generic_read     }
+        if mask & access::GENERIC_WRITE     != access::Mask::default() { mapped |= self.generic_write    }
+        if mask & access::GENERIC_EXECUTE   != access::Mask::default() { mapped |= self.generic_execute  }
+        if mask & access::GENERIC_ALL       != access::Mask::default() { mapped |= self.generic_all      }
+        mapped
+    }
+}
+
+impl From<GenericMapping> for GENERIC_MAPPING {
+    fn from(m: GenericMapping) -> Self {
+        Self { GenericRead: m.generic_read.into(), GenericWrite: m.generic_write.into(), GenericExecute: m.generic_execute.into(), GenericAll: m.generic_all.into() }
+    }
+}
+
+#[cfg(std)] #[test] fn map() {
+    let file = access::GenericMapping::FILE;
+    assert_eq!(u32::from(file.map(access::GENERIC_ALL)),                        FILE_ALL_ACCESS);
+    assert_eq!(u32::from(file.map(access::GENERIC_WRITE | access::DELETE)),     FILE_GENERIC_WRITE | DELETE);
+    assert_eq!(u32::from(file.map(access::MAXIMUM_ALLOWED)),                    MAXIMUM_ALLOWED);
+    assert_eq!(u32::from(access::GenericMapping::KEY.map(access::GENERIC_READ)), KEY_READ);
+}
@@ -27,7 +27,7 @@ impl Mask {
 
 impl Debug for Mask {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        use winapi::um::winnt::*;
+        use crate::winapi::um::winnt::*;
         flags!(self.0, fmt, "0x{:04X}", [
             GENERIC_ALL,
             GENERIC_EXECUTE,
@@ -46,3 +46,10 @@ impl Debug for Mask {
         ])
     }
 }
+
+#[cfg(std)] #[test] fn debug_fmt() {
+    use crate::access;
+    assert_eq!("0",                     std::format!("{:?}", Mask::default()));
+    assert_eq!("GENERIC_ALL | DELETE",  std::format!("{:?}", access::GENERIC_ALL | access::DELETE));
+    assert_eq!("DELETE | 0x0001",       std::format!("{:?}", access::DELETE | unsafe { Mask::from_unchecked(0x0001) }));
+}
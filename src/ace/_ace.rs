@@ -9,5 +9,8 @@
 mod ace_flags;                      pub use ace_flags::*;
 mod ace_header;                     pub use ace_header::*;
 mod ace_iter;                       pub use ace_iter::*;
+mod ace_mandatory_label;            pub use ace_mandatory_label::*;
 mod ace_ptr;                        pub use ace_ptr::*;
 mod ace_type;                       pub use ace_type::*;
+
+#[path = "condition/_condition.rs"] pub mod condition;
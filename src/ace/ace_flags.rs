@@ -32,7 +32,7 @@ impl Flags {
                 for (short, flag, _long) in SHORT_FLAG_LONG {
                     if 0 == v & flag { continue }
                     write!(fmt, "{short}")?;
-                    v =! flag;
+                    v &= !flag;
                 }
                 if v != 0 { write!(fmt, "??")?; } // 0x20
                 Ok(())
@@ -48,7 +48,7 @@ impl Debug for Flags {
         for (_short, flag, long) in SHORT_FLAG_LONG {
             if 0 == v & flag { continue }
             write!(fmt, "{long}")?;
-            v =! flag;
+            v &= !flag;
             if v != 0 { write!(fmt, " | ")?; }
         }
         if v != 0 { write!(fmt, "0x{:02X}", v)? }
@@ -61,52 +61,52 @@ impl Debug for Flags {
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// OBJECT_INHERIT_ACE
 ///
-pub const OBJECT_INHERIT_ACE            : Flags     = Flags     (winapi::um::winnt::OBJECT_INHERIT_ACE          ); // 0x01
+pub const OBJECT_INHERIT_ACE            : Flags     = Flags     (crate::winapi::um::winnt::OBJECT_INHERIT_ACE          ); // 0x01
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// CONTAINER_INHERIT_ACE
 ///
-pub const CONTAINER_INHERIT_ACE         : Flags     = Flags     (winapi::um::winnt::CONTAINER_INHERIT_ACE       ); // 0x02
+pub const CONTAINER_INHERIT_ACE         : Flags     = Flags     (crate::winapi::um::winnt::CONTAINER_INHERIT_ACE       ); // 0x02
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// NO_PROPAGATE_INHERIT_ACE
 ///
-pub const NO_PROPAGATE_INHERIT_ACE      : Flags     = Flags     (winapi::um::winnt::NO_PROPAGATE_INHERIT_ACE    ); // 0x04
+pub const NO_PROPAGATE_INHERIT_ACE      : Flags     = Flags     (crate::winapi::um::winnt::NO_PROPAGATE_INHERIT_ACE    ); // 0x04
 
 
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// INHERIT_ONLY_ACE
 ///
-pub const INHERIT_ONLY_ACE              : Flags     = Flags     (winapi::um::winnt::INHERIT_ONLY_ACE            ); // 0x08
+pub const INHERIT_ONLY_ACE              : Flags     = Flags     (crate::winapi::um::winnt::INHERIT_ONLY_ACE            ); // 0x08
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// INHERITED_ACE
 ///
-pub const INHERITED_ACE                 : Flags     = Flags     (winapi::um::winnt::INHERITED_ACE               ); // 0x10
+pub const INHERITED_ACE                 : Flags     = Flags     (crate::winapi::um::winnt::INHERITED_ACE               ); // 0x10
 
 /// \[<strike>microsoft.com</strike>\]
 /// VALID_INHERIT_FLAGS
 ///
-pub const VALID_INHERIT_FLAGS           : FlagsMask = FlagsMask (winapi::um::winnt::VALID_INHERIT_FLAGS         ); // 0x1F
+pub const VALID_INHERIT_FLAGS           : FlagsMask = FlagsMask (crate::winapi::um::winnt::VALID_INHERIT_FLAGS         ); // 0x1F
 
 // 0x20
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// SUCCESSFUL_ACCESS_ACE_FLAG
 ///
-pub const SUCCESSFUL_ACCESS_ACE_FLAG    : Flags     = Flags     (winapi::um::winnt::SUCCESSFUL_ACCESS_ACE_FLAG  ); // 0x40 - also previously TRUST_PROTECTED_FILTER_ACE_FLAG
+pub const SUCCESSFUL_ACCESS_ACE_FLAG    : Flags     = Flags     (crate::winapi::um::winnt::SUCCESSFUL_ACCESS_ACE_FLAG  ); // 0x40 - also previously TRUST_PROTECTED_FILTER_ACE_FLAG
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members)\]
 /// FAILED_ACCESS_ACE_FLAG
 ///
-pub const FAILED_ACCESS_ACE_FLAG        : Flags     = Flags     (winapi::um::winnt::FAILED_ACCESS_ACE_FLAG      ); // 0x80
+pub const FAILED_ACCESS_ACE_FLAG        : Flags     = Flags     (crate::winapi::um::winnt::FAILED_ACCESS_ACE_FLAG      ); // 0x80
 
 
 
 macro_rules! table { ( $( $short:literal $sddl:ident $flag:tt )* ) => {
     &[$(
-        (winapi::shared::sddl::$sddl, winapi::um::winnt::$flag, stringify!($flag))
+        (crate::winapi::shared::sddl::$sddl, crate::winapi::um::winnt::$flag, stringify!($flag))
     ),*]
 }}
 
@@ -123,3 +123,11 @@ const SHORT_FLAG_LONG : &'static [(&'static str, u8, &'static str)] = table! [
     // "TP" SDDL_TRUST_PROTECTED_FILTER TRUST_PROTECTED_FILTER_ACE_FLAG     N/A: Windows Server 2016, Windows 10 Version 1607, Windows 10 Version 1511, Windows 10 Version 1507, Windows Server 2012 R2, Windows 8.1, Windows Server 2012, Windows 8, Windows Server 2008 R2, Windows 7, Windows Server 2008, Windows Vista and Windows Server 2003
     // "CR" SDDL_CRITICAL               CRITICAL_ACE_FLAG                   N/A: Windows Server Version 1803, Windows 10 Version 1803, Windows Server Version 1709, Windows 10 Version 1709, Windows 10 Version 1703, Windows Server 2016, Windows 10 Version 1607, Windows 10 Version 1511, Windows 10 Version 1507, Windows Server 2012 R2, Windows 8.1, Windows Server 2012, Windows 8, Windows Server 2008 R2, Windows 7, Windows Server 2008, Windows Vista and Windows Server 2003
 ];
+
+#[cfg(std)] #[test] fn fmt() {
+    let ci_oi = CONTAINER_INHERIT_ACE | OBJECT_INHERIT_ACE;
+    assert_eq!("0",                                             std::format!("{:?}", Flags::default()));
+    assert_eq!("CONTAINER_INHERIT_ACE | OBJECT_INHERIT_ACE",    std::format!("{:?}", ci_oi));
+    assert_eq!("CONTAINER_INHERIT_ACE | 0x20",                  std::format!("{:?}", CONTAINER_INHERIT_ACE | unsafe { Flags::from_unchecked(0x20) }));
+    assert_eq!("CIOI",                                          std::format!("{}", ci_oi.short_flags().unwrap()));
+}
@@ -14,7 +14,6 @@ use winapi::um::winnt::*;
 impl<'a> Iter<'a> {
     pub const fn empty() -> Self { Self { next_ace: NonNull::dangling(), count: 0, ph: PhantomData } }
     pub fn new(acl: impl Into<Self>) -> Self { acl.into() }
-    pub(crate) fn as_ptr(&self) -> *mut ace::Header { self.next_ace.as_ptr() }
 }
 
 impl Default for Iter<'_> { fn default() -> Self { Self::empty() } }
@@ -23,7 +22,7 @@ impl<'a> From<acl::Ref<'a>> for Iter<'a> {
     fn from(acl: acl::Ref<'a>) -> Self {
         Self {
             next_ace:   unsafe { acl.as_pacl().add(1).cast() },
-            count:      acl.get_acl_size_information().AceCount,
+            count:      acl.ace_count() as _,
             ph:         PhantomData
         }
     }
@@ -49,3 +48,24 @@ impl<'a> Iterator for Iter<'a> {
         (n, Some(n))
     }
 }
+
+#[cfg(std)] #[test] fn iter_raw_acl() {
+    #[repr(C, align(4))] struct Aligned([u8; 8 + 20 + 24]);
+    let mut acl = Aligned([0; 52]);
+    acl.0[ 0 ..  8].copy_from_slice(&[2, 0, 52, 0, 2, 0, 0, 0]); // ACL { AclRevision, Sbz1, AclSize, AceCount, Sbz2 }
+    acl.0[ 8 .. 28].copy_from_slice(&[0, 0x03, 20, 0,  0x00, 0x00, 0x00, 0x10,  1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0]); // ACCESS_ALLOWED_ACE { ..., GENERIC_ALL, S-1-1-0 }
+    acl.0[28 .. 52].copy_from_slice(&[1, 0x00, 24, 0,  0x00, 0x00, 0x04, 0x00,  1, 2, 0, 0, 0, 0, 0, 5,  32, 0, 0, 0,  0x20, 0x02, 0, 0]); // ACCESS_DENIED_ACE { ..., WRITE_DAC, S-1-5-32-544 }
+    let acl = unsafe { acl::Ref::from_raw(NonNull::from(&mut acl).cast(), 52) }.unwrap();
+
+    let aces = acl.aces().map(|ace| *ace.header()).collect::<std::vec::Vec<_>>();
+    assert_eq!(2, aces.len());
+    assert_eq!(ace::Type::ACCESS_ALLOWED,                               aces[0].ty);
+    assert_eq!(ace::CONTAINER_INHERIT_ACE | ace::OBJECT_INHERIT_ACE,    aces[0].flags);
+    assert_eq!(20,                                                      aces[0].size);
+    assert_eq!(ace::Type::ACCESS_DENIED,                                aces[1].ty);
+    assert_eq!(24,                                                      aces[1].size);
+
+    let dbg = std::format!("{acl:?}");
+    assert!(dbg.contains("sid: S-1-1-0"),       "{dbg}");
+    assert!(dbg.contains("sid: S-1-5-32-544"),  "{dbg}");
+}
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+
+use winapi::um::winnt::*;
+
+use core::fmt::{self, Debug, Formatter};
+
+
+
+#[doc(alias = "SYSTEM_MANDATORY_LABEL_NO_WRITE_UP")]
+#[doc(alias = "SYSTEM_MANDATORY_LABEL_NO_READ_UP")]
+#[doc(alias = "SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace#members)\]
+/// SYSTEM_MANDATORY_LABEL_ACE::Mask: DWORD/[u32] bitset of `SYSTEM_MANDATORY_LABEL_*` values
+///
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)] pub struct MandatoryLabelPolicy(u32);
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace#members)\]
+/// SYSTEM_MANDATORY_LABEL_ACE::Mask: DWORD/[u32] mask of `SYSTEM_MANDATORY_LABEL_*` values
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)] pub struct MandatoryLabelPolicyMask(u32);
+
+flags!(impl .. for MandatoryLabelPolicy(u32) - MandatoryLabelPolicyMask);
+
+impl MandatoryLabelPolicy {
+    /// ### Safety
+    /// *   Some APIs might theoretically assume the policy only contains `SYSTEM_MANDATORY_LABEL_VALID_MASK` bits?
+    pub const unsafe fn from_unchecked(policy: u32) -> Self { Self(policy) }
+
+    pub fn as_u32(self) -> u32 { self.0 }
+
+    #[doc(alias = "SYSTEM_MANDATORY_LABEL_NO_WRITE_UP")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace#members)\]
+    /// SYSTEM_MANDATORY_LABEL_NO_WRITE_UP: lower integrity subjects can't write to the object
+    ///
+    pub const NO_WRITE_UP   : Self = Self(SYSTEM_MANDATORY_LABEL_NO_WRITE_UP);
+
+    #[doc(alias = "SYSTEM_MANDATORY_LABEL_NO_READ_UP")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace#members)\]
+    /// SYSTEM_MANDATORY_LABEL_NO_READ_UP: lower integrity subjects can't read the object
+    ///
+    pub const NO_READ_UP    : Self = Self(SYSTEM_MANDATORY_LABEL_NO_READ_UP);
+
+    #[doc(alias = "SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace#members)\]
+    /// SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP: lower integrity subjects can't execute the object
+    ///
+    pub const NO_EXECUTE_UP : Self = Self(SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP);
+
+    #[doc(alias = "SYSTEM_MANDATORY_LABEL_VALID_MASK")]
+    /// \[<strike>microsoft.com</strike>\]
+    /// SYSTEM_MANDATORY_LABEL_VALID_MASK
+    ///
+    pub const VALID_MASK    : MandatoryLabelPolicyMask = MandatoryLabelPolicyMask(SYSTEM_MANDATORY_LABEL_VALID_MASK);
+}
+
+impl Debug for MandatoryLabelPolicy {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        flags!(self.0, fmt, "0x{:X}", [
+            SYSTEM_MANDATORY_LABEL_NO_WRITE_UP,
+            SYSTEM_MANDATORY_LABEL_NO_READ_UP,
+            SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP,
+        ])
+    }
+}
+
+
+
+#[doc(alias = "SYSTEM_MANDATORY_LABEL_ACE")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace)\]
+/// ≈ SYSTEM_MANDATORY_LABEL_ACE, parsed
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let sd = security::RelativeDescriptor::from_sddl("S:(ML;;NWNR;;;LW)").unwrap();
+/// let label = sd.integrity_label().unwrap();
+/// assert_eq!(label.level, sid::integrity::Low);
+/// assert_eq!(label.policy, ace::MandatoryLabelPolicy::NO_WRITE_UP | ace::MandatoryLabelPolicy::NO_READ_UP);
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MandatoryLabel {
+    /// ACE_HEADER::AceFlags (e.g. [`ace::OBJECT_INHERIT_ACE`] | [`ace::CONTAINER_INHERIT_ACE`] to label the children of a directory)
+    pub flags:  ace::Flags,
+
+    /// The integrity level of the object (the `S-1-16-*` SID of the ACE)
+    pub level:  sid::integrity::Level,
+
+    /// Which kinds of access are denied to subjects with a lower integrity level
+    pub policy: MandatoryLabelPolicy,
+}
+
+impl MandatoryLabel {
+    /// A non-inheritable label.
+    pub const fn new(level: sid::integrity::Level, policy: MandatoryLabelPolicy) -> Self { Self { flags: unsafe { ace::Flags::from_unchecked(0) }, level, policy } }
+
+    /// Parse a `SYSTEM_MANDATORY_LABEL_ACE`.
+    ///
+    /// Returns [`None`] if `ace` isn't a mandatory label, is truncated, or its SID isn't a single sub-authority `S-1-16-*` SID.
+    pub fn from_ace(ace: ace::Ref) -> Option<Self> {
+        let header = ace.header();
+        if header.ty != ace::Type::SYSTEM_MANDATORY_LABEL { return None }
+        let (mask, sid) = (ace.mask()?, ace.sid()?);
+        if sid.authority_u64() != 16 { return None } // SECURITY_MANDATORY_LABEL_AUTHORITY
+        let &[rid] = sid.subauthorities() else { return None };
+        Some(Self { flags: header.flags, level: sid::integrity::Level::new(rid), policy: MandatoryLabelPolicy(mask.into()) })
+    }
+
+    /// `true` if the label only applies to children (`INHERIT_ONLY_ACE`), not the object itself.
+    pub fn is_inherit_only(&self) -> bool { self.flags.as_u8() & INHERIT_ONLY_ACE != 0 }
+}
+
+impl<'a> TryFrom<ace::Ref<'a>> for MandatoryLabel {
+    type Error = ace::Ref<'a>;
+    fn try_from(ace: ace::Ref<'a>) -> Result<Self, Self::Error> { Self::from_ace(ace).ok_or(ace) }
+}
+
+
+
+#[cfg(std)] #[test] fn fmt() {
+    use std::format;
+    type P = MandatoryLabelPolicy;
+    assert_eq!("0",                                                                     format!("{:?}", P::default()));
+    assert_eq!("SYSTEM_MANDATORY_LABEL_NO_WRITE_UP | SYSTEM_MANDATORY_LABEL_NO_READ_UP", format!("{:?}", P::NO_READ_UP | P::NO_WRITE_UP));
+    assert_eq!("SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP | 0x10",                           format!("{:?}", P::NO_EXECUTE_UP | unsafe { P::from_unchecked(0x10) }));
+    assert_eq!(P::NO_WRITE_UP, P::VALID_MASK & unsafe { P::from_unchecked(0x11) });
+}
@@ -14,7 +14,7 @@ use core::fmt::{self, Debug, Formatter};
 ///
 #[derive(Clone, Copy)] #[repr(transparent)] pub struct Ref<'a>(NonNull<ace::Header>, PhantomData<&'a ace::Header>);
 
-impl Ref<'_> {
+impl<'a> Ref<'a> {
     /// ### Safety
     /// `ace_header` should point to a valid [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header) for the lifetime `'a` given [`ace::Ref<'a>`].
     pub const unsafe fn from_raw_unchecked(ace: NonNull<ace::Header>) -> Self { Self(ace, PhantomData) }
@@ -24,19 +24,104 @@ impl Ref<'_> {
     pub unsafe fn from_raw(ace: *mut ace::Header) -> Option<Self> { NonNull::new(ace).map(|ace| unsafe { Self::from_raw_unchecked(ace) }) }
 
     pub fn header(&self) -> &ace::Header { unsafe { self.0.as_ref() } }
+
+    /// The entire ACE (including the [`ace::Header`]) as `header().size` bytes.
+    pub fn as_bytes(&self) -> &'a [u8] { unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), self.header().size.into()) } }
+
+    /// ACCESS_MASK of the ACE, or [`None`] if the ACE type is unknown or the ACE is truncated.
+    pub fn mask(&self) -> Option<access::Mask> {
+        self.body()?;
+        let mask = self.as_bytes().get(4..8)?;
+        Some(unsafe { access::Mask::from_unchecked(u32::from_le_bytes(mask.try_into().ok()?)) })
+    }
+
+    /// `*_OBJECT_ACE::Flags` (`ACE_OBJECT_TYPE_PRESENT`, `ACE_INHERITED_OBJECT_TYPE_PRESENT`), or [`None`] if this isn't an object ACE.
+    pub fn object_flags(&self) -> Option<u32> { self.body()?.object_flags }
+
+    /// `*_OBJECT_ACE::ObjectType`, if present.
+    pub fn object_type(&self) -> Option<GUID> {
+        let body = self.body()?;
+        if body.object_flags? & ACE_OBJECT_TYPE_PRESENT == 0 { return None }
+        read_guid(self.as_bytes(), 12)
+    }
+
+    /// `*_OBJECT_ACE::InheritedObjectType`, if present.
+    pub fn inherited_object_type(&self) -> Option<GUID> {
+        let body = self.body()?;
+        let flags = body.object_flags?;
+        if flags & ACE_INHERITED_OBJECT_TYPE_PRESENT == 0 { return None }
+        read_guid(self.as_bytes(), if flags & ACE_OBJECT_TYPE_PRESENT == 0 { 12 } else { 28 })
+    }
+
+    /// The ACE's SID, or [`None`] if the ACE type is unknown or the ACE is truncated.
+    pub fn sid(&self) -> Option<sid::Ptr<'a>> {
+        let body = self.body()?;
+        let sid = unsafe { self.0.cast::<u8>().add(body.sid_start) };
+        Some(unsafe { sid::Ptr::from_raw_unchecked(sid.as_ptr().cast()) })
+    }
+
+    /// Trailing bytes after the SID (e.g. the conditional expression of a callback ACE, or the claim of a resource attribute ACE.)
+    pub fn application_data(&self) -> &'a [u8] {
+        match self.body() {
+            Some(body)  => &self.as_bytes()[body.sid_end..],
+            None        => &[],
+        }
+    }
+
+    /// Parse the [`claim::Attribute`] of a `SYSTEM_RESOURCE_ATTRIBUTE_ACE`, or [`None`] if this is some other type of ACE (or truncated.)
+    #[cfg(alloc)] pub fn resource_attribute(&self) -> Option<Result<claim::Attribute, claim::AttributeError>> {
+        if self.header().ty != ace::Type::SYSTEM_RESOURCE_ATTRIBUTE { return None }
+        self.body()?;
+        Some(claim::Attribute::from_relative_bytes(self.application_data()))
+    }
+
+    /// Locate the (bounds checked) variable length parts of known ACE types.
+    fn body(&self) -> Option<Body> {
+        let bytes = self.as_bytes();
+        let (object_flags, sid_start) = match self.header().ty.as_u8() {
+            ACCESS_ALLOWED_ACE_TYPE | ACCESS_DENIED_ACE_TYPE | SYSTEM_AUDIT_ACE_TYPE | SYSTEM_ALARM_ACE_TYPE |
+            ACCESS_ALLOWED_CALLBACK_ACE_TYPE | ACCESS_DENIED_CALLBACK_ACE_TYPE | SYSTEM_AUDIT_CALLBACK_ACE_TYPE | SYSTEM_ALARM_CALLBACK_ACE_TYPE |
+            SYSTEM_MANDATORY_LABEL_ACE_TYPE | SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE | SYSTEM_SCOPED_POLICY_ID_ACE_TYPE |
+            SYSTEM_PROCESS_TRUST_LABEL_ACE_TYPE | SYSTEM_ACCESS_FILTER_ACE_TYPE
+                => (None, 8),
+            ACCESS_ALLOWED_OBJECT_ACE_TYPE | ACCESS_DENIED_OBJECT_ACE_TYPE | SYSTEM_AUDIT_OBJECT_ACE_TYPE | SYSTEM_ALARM_OBJECT_ACE_TYPE |
+            ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE | ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE | SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE | SYSTEM_ALARM_CALLBACK_OBJECT_ACE_TYPE
+                => {
+                let flags = u32::from_le_bytes(bytes.get(8..12)?.try_into().ok()?);
+                let guids = 16 * (flags & ACE_OBJECT_TYPE_PRESENT).count_ones() + 16 * (flags & ACE_INHERITED_OBJECT_TYPE_PRESENT).count_ones();
+                (Some(flags), 12 + usize::from32(guids))
+            },
+            _ => return None, // ACCESS_ALLOWED_COMPOUND_ACE_TYPE, reserved types, etc.
+        };
+        let sub_authority_count = *bytes.get(sid_start + 1)?;
+        let sid_end = sid_start + 8 + 4 * usize::from(sub_authority_count);
+        if sub_authority_count > 15 || sid_end > bytes.len() { return None }
+        Some(Body { object_flags, sid_start, sid_end })
+    }
+}
+
+#[derive(Clone, Copy)] struct Body {
+    object_flags:   Option<u32>,
+    sid_start:      usize,
+    sid_end:        usize,
+}
+
+fn read_guid(bytes: &[u8], offset: usize) -> Option<GUID> {
+    let b : &[u8; 16] = bytes.get(offset .. offset + 16)?.try_into().ok()?;
+    Some(GUID {
+        Data1: u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        Data2: u16::from_le_bytes([b[4], b[5]]),
+        Data3: u16::from_le_bytes([b[6], b[7]]),
+        Data4: [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+    })
 }
 
 impl Debug for Ref<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        let header = unsafe { self.0.read() };
+        let header = *self.header();
         let mut d = fmt.debug_struct("ace::Ref");
         d.field("header", &header);
 
-        macro_rules! sid_of { ( $ace:expr ) => {&{
-            let sid_start : *const u32 = provenance_addr(self.0.as_ptr(), &$ace.SidStart);
-            sid::Ptr::from_raw_unchecked(sid_start as *mut _)
-        }}}
-
         struct Hex(u32);   impl Debug for Hex { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "0x{:08x}", self.0) } }
         struct Guid(GUID); impl Debug for Guid { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
             let [a,b,c,d,e,f,g,h] = self.0.Data4;
@@ -47,92 +132,31 @@ impl Debug for Ref<'_> {
         }}
 
         // https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header#members
-        match header.ty {
-            ace::Type::ACCESS_ALLOWED => {
-                let ace : &ACCESS_ALLOWED_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            ace::Type::ACCESS_ALLOWED_CALLBACK => {
-                let ace : &ACCESS_ALLOWED_CALLBACK_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            ace::Type::ACCESS_ALLOWED_CALLBACK_OBJECT => {
-                let ace : &ACCESS_ALLOWED_CALLBACK_OBJECT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask",                     &Hex(ace.Mask)                  );
-                d.field("flags",                    &Hex(ace.Flags)                 );
-                d.field("object_type",              &Guid(ace.ObjectType)           );
-                d.field("inherited_object_type",    &Guid(ace.InheritedObjectType)  );
-                d.field("sid",                      unsafe { sid_of!(ace) }         );
-                d.finish()
-            },
-            // ace::Type::ACCESS_ALLOWED_COMPOUND => Reserved.
-            ace::Type::ACCESS_ALLOWED_OBJECT => {
-                let ace : &ACCESS_ALLOWED_OBJECT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask",                     &Hex(ace.Mask)                  );
-                d.field("flags",                    &Hex(ace.Flags)                 );
-                d.field("object_type",              &Guid(ace.ObjectType)           );
-                d.field("inherited_object_type",    &Guid(ace.InheritedObjectType)  );
-                d.field("sid",                      unsafe { sid_of!(ace) }         );
-                d.finish()
-            },
-            ace::Type::ACCESS_DENIED => {
-                let ace : &ACCESS_DENIED_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            ace::Type::ACCESS_DENIED_CALLBACK => {
-                let ace : &ACCESS_DENIED_CALLBACK_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            ace::Type::ACCESS_DENIED_CALLBACK_OBJECT => {
-                let ace : &ACCESS_DENIED_CALLBACK_OBJECT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask",                     &Hex(ace.Mask)                  );
-                d.field("flags",                    &Hex(ace.Flags)                 );
-                d.field("object_type",              &Guid(ace.ObjectType)           );
-                d.field("inherited_object_type",    &Guid(ace.InheritedObjectType)  );
-                d.field("sid",                      unsafe { sid_of!(ace) }         );
-                d.finish()
-            },
-            ace::Type::ACCESS_DENIED_OBJECT => {
-                let ace : &ACCESS_DENIED_OBJECT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask",                     &Hex(ace.Mask)                  );
-                d.field("flags",                    &Hex(ace.Flags)                 );
-                d.field("object_type",              &Guid(ace.ObjectType)           );
-                d.field("inherited_object_type",    &Guid(ace.InheritedObjectType)  );
-                d.field("sid",                      unsafe { sid_of!(ace) }         );
-                d.finish()
-            },
-            // many reserved types
-            ace::Type::SYSTEM_AUDIT => {
-                let ace : &SYSTEM_AUDIT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            ace::Type::SYSTEM_AUDIT_CALLBACK => {
-                let ace : &SYSTEM_AUDIT_CALLBACK_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            ace::Type::SYSTEM_AUDIT_CALLBACK_OBJECT => {
-                let ace : &SYSTEM_AUDIT_CALLBACK_OBJECT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask",                     &Hex(ace.Mask)                  );
-                d.field("flags",                    &Hex(ace.Flags)                 );
-                d.field("object_type",              &Guid(ace.ObjectType)           );
-                d.field("inherited_object_type",    &Guid(ace.InheritedObjectType)  );
-                d.field("sid",                      unsafe { sid_of!(ace) }         );
-                d.finish()
-            },
-            ace::Type::SYSTEM_AUDIT_OBJECT => {
-                let ace : &SYSTEM_AUDIT_OBJECT_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask",                     &Hex(ace.Mask)                  );
-                d.field("flags",                    &Hex(ace.Flags)                 );
-                d.field("object_type",              &Guid(ace.ObjectType)           );
-                d.field("inherited_object_type",    &Guid(ace.InheritedObjectType)  );
-                d.field("sid",                      unsafe { sid_of!(ace) }         );
-                d.finish()
-            },
-            ace::Type::SYSTEM_MANDATORY_LABEL => {
-                let ace : &SYSTEM_MANDATORY_LABEL_ACE = unsafe { self.0.cast().as_ref() };
-                d.field("mask", &Hex(ace.Mask)).field("sid", unsafe { sid_of!(ace) }).finish()
-            },
-            _ => d.finish_non_exhaustive()
-        }
+        let Some(sid) = self.sid() else { return d.finish_non_exhaustive() };
+        if let Some(mask)   = self.mask()                   { d.field("mask",                   &Hex(mask.into())   ); }
+        if let Some(flags)  = self.object_flags()           { d.field("flags",                  &Hex(flags)         ); }
+        if let Some(guid)   = self.object_type()            { d.field("object_type",            &Guid(guid)         ); }
+        if let Some(guid)   = self.inherited_object_type()  { d.field("inherited_object_type",  &Guid(guid)         ); }
+        d.field("sid", &sid);
+        let data = self.application_data();
+        if !data.is_empty() { d.field("application_data", &data.len()); }
+        d.finish()
     }
 }
+
+#[cfg(std)] #[test] fn object_ace_layout() {
+    // ACCESS_ALLOWED_OBJECT_ACE with only an InheritedObjectType: the absent ObjectType GUID takes up no space.
+    #[repr(C, align(4))] struct Aligned([u8; 4 + 4 + 4 + 16 + 12]);
+    let mut ace = Aligned([0; 40]);
+    ace.0[ 0 .. 12].copy_from_slice(&[0x05, 0, 40, 0,  0x00, 0x01, 0x00, 0x00,  0x02, 0, 0, 0]);
+    ace.0[12 .. 28].copy_from_slice(&[0x78, 0x56, 0x34, 0x12,  0x34, 0x12,  0x78, 0x56,  1, 2, 3, 4, 5, 6, 7, 8]);
+    ace.0[28 .. 40].copy_from_slice(&[1, 1, 0, 0, 0, 0, 0, 5,  18, 0, 0, 0]);
+    let ace = unsafe { ace::Ref::from_raw_unchecked(NonNull::from(&mut ace).cast()) };
+
+    assert_eq!(Some(0x100),             ace.mask().map(u32::from));
+    assert_eq!(Some(2),                 ace.object_flags());
+    assert!(ace.object_type().is_none());
+    assert_eq!(Some(0x12345678),        ace.inherited_object_type().map(|g| g.Data1));
+    assert_eq!(&*sid!(S-1-5-18),        &*ace.sid().unwrap());
+    assert!(ace.application_data().is_empty());
+}
@@ -1,4 +1,4 @@
-use winapi::um::winnt::*;
+use crate::winapi::um::winnt::*;
 
 use core::fmt::{self, Debug, Formatter};
 
@@ -127,6 +127,12 @@ impl Type {
 }
 
 impl Type {
+    /// ### Safety
+    /// *   Some APIs might theoretically assume ACE types are valid?
+    pub const unsafe fn from_unchecked(ty: u8) -> Self { Self(ty) }
+
+    pub fn as_u8(self) -> u8 { self.0 }
+
     pub fn short_type(&self) -> Option<&'static str> {
         Some(match *self {
             Self::ACCESS_ALLOWED                    => "A",
@@ -176,6 +182,14 @@ impl Type {
             _                                       => return None
         })
     }
+
+    /// `true` for the `*_CALLBACK*` ACE types, whose application data is typically a [conditional expression](ace::condition).
+    pub fn is_callback(&self) -> bool {
+        matches!(*self,
+            Self::ACCESS_ALLOWED_CALLBACK | Self::ACCESS_DENIED_CALLBACK | Self::SYSTEM_AUDIT_CALLBACK |
+            Self::ACCESS_ALLOWED_CALLBACK_OBJECT | Self::ACCESS_DENIED_CALLBACK_OBJECT | Self::SYSTEM_AUDIT_CALLBACK_OBJECT
+        )
+    }
 }
 
 impl Debug for Type {
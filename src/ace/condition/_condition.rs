@@ -0,0 +1,39 @@
+//! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language-for-conditional-aces-)\]
+//! Conditional ACE expressions, as stored in the application data of callback ACEs (`ACCESS_ALLOWED_CALLBACK_ACE` etc.)
+//!
+//! An [`Expression`] can be converted to and from both its binary form ([`Expression::to_bytes`], [`Expression::from_bytes`])
+//! and its SDDL form ([`Display`](core::fmt::Display), [`FromStr`](core::str::FromStr)), and evaluated against claims with [`Expression::evaluate`].
+//!
+//! ### Examples
+//! ```
+//! # use firehazard::*;
+//! use ace::condition::{Context, Expression};
+//!
+//! let condition : Expression = "(@User.clearance >= 3) && (Member_of {SID(BA)})".parse().unwrap();
+//! assert_eq!(condition.to_string(), "((@User.clearance >= 3) && (Member_of {SID(BA)}))");
+//!
+//! let bytes = condition.to_bytes();
+//! assert!(bytes.starts_with(b"artx"));
+//! assert_eq!(Ok(&condition), Expression::from_bytes(&bytes).as_ref());
+//!
+//! let user    = [claim::Attribute::new("Clearance", claim::Values::Int64(vec![5]))];
+//! let groups  = [sid::AndAttributes::new(sid!(S-1-5-32-544), sid::group::ENABLED)];
+//! let context = Context { user_claims: &user, groups: &groups, ..Context::default() };
+//! assert_eq!(Some(true),  condition.evaluate(&context));
+//! assert_eq!(Some(false), condition.evaluate(&Context { groups: &[], ..context }));
+//! assert_eq!(None,        condition.evaluate(&Context { user_claims: &[], ..context })); // unknown
+//! ```
+//!
+//! ### References
+//! *   [Security Descriptor Definition Language for Conditional ACEs](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language-for-conditional-aces-)
+//! *   [\[MS-DTYP\] 2.4.4.17 Conditional ACEs](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-dtyp/7d2ba7e4-10c0-4ec9-8d3c-6c6fbbbbd24c)
+//! *   [\[MS-DTYP\] 2.5.1.1 SDDL Syntax for Conditional ACEs](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-dtyp/86d2dd9d-5a8c-4a61-a5a3-2bb9bb6d0fc8)
+
+#[cfg(alloc)] mod condition_binary;
+mod condition_error;                    pub use condition_error::*;
+#[cfg(alloc)] mod condition_evaluate;   #[cfg(alloc)] pub use condition_evaluate::*;
+#[cfg(alloc)] mod condition_expression; #[cfg(alloc)] pub use condition_expression::*;
+#[cfg(alloc)] mod condition_sddl;
+
+/// Expressions nested deeper than this are rejected by [`Expression::from_bytes`] and [`str::parse`], to bound recursion.
+pub const MAX_DEPTH : usize = 256;
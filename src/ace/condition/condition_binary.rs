@@ -0,0 +1,203 @@
+use crate::prelude::*;
+use super::*;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+
+
+impl Expression {
+    /// Serialize as conditional ACE application data: `artx`, the postfix token stream, then zero padding to a DWORD boundary.
+    ///
+    /// Integer literals are always encoded as 64-bit integer (`0x04`) tokens.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::from(*b"artx");
+        self.encode(&mut bytes);
+        while !bytes.len().is_multiple_of(4) { bytes.push(0); }
+        bytes
+    }
+
+    /// Parse conditional ACE application data, such as the [`application_data`](ace::Ref::application_data) of an `ACCESS_ALLOWED_CALLBACK_ACE`.
+    ///
+    /// Every length is bounds checked before use, so `bytes` may come from an untrusted source.
+    /// Padding (`0x00`) tokens are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = bytes.strip_prefix(b"artx").ok_or(DecodeError::MissingSignature)?;
+        let mut stack : Vec<(Expression, usize)> = Vec::new(); // (expression, depth)
+
+        while let Some((&token, rest)) = r.split_first() {
+            r = rest;
+            let (expression, depth) = match token {
+                0x00 => continue,
+                0xF8 ..= 0xFB => {
+                    let source = Source::ALL.iter().find(|e| e.1 == token).unwrap().0;
+                    (Expression::Attribute(Attribute { source, name: string(&mut r)? }), 1)
+                },
+                _ => match Operator::from_token(token) {
+                    Some(op) if op.is_unary() => {
+                        let (operand, depth) = stack.pop().ok_or(DecodeError::MissingOperand)?;
+                        (Expression::Unary(op, Box::new(operand)), depth + 1)
+                    },
+                    Some(op) => {
+                        let (rhs, r_depth) = stack.pop().ok_or(DecodeError::MissingOperand)?;
+                        let (lhs, l_depth) = stack.pop().ok_or(DecodeError::MissingOperand)?;
+                        (Expression::Binary(op, Box::new(lhs), Box::new(rhs)), l_depth.max(r_depth) + 1)
+                    },
+                    None => (Expression::Literal(literal(token, &mut r, 1)?), 1),
+                },
+            };
+            if depth > MAX_DEPTH { return Err(DecodeError::TooDeep) }
+            stack.push((expression, depth));
+        }
+
+        match stack.len() {
+            0 => Err(DecodeError::MissingOperand),
+            1 => Ok(stack.pop().unwrap().0),
+            _ => Err(DecodeError::ExtraOperands),
+        }
+    }
+
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Expression::Attribute(a)        => { bytes.push(a.source.token()); push_string(bytes, &a.name) },
+            Expression::Literal(l)          => l.encode(bytes),
+            Expression::Unary(op, operand)  => { operand.encode(bytes); bytes.push(op.token()) },
+            Expression::Binary(op, lhs, rhs)=> { lhs.encode(bytes); rhs.encode(bytes); bytes.push(op.token()) },
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Expression {
+    type Error = DecodeError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { Self::from_bytes(bytes) }
+}
+
+impl Literal {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Literal::Integer(i)     => { bytes.push(0x04); bytes.extend_from_slice(&i.value.to_le_bytes()); bytes.push(i.sign as u8); bytes.push(i.base as u8) },
+            Literal::String(s)      => { bytes.push(0x10); push_string(bytes, s) },
+            Literal::OctetString(o) => { bytes.push(0x18); push_len(bytes, o.len()); bytes.extend_from_slice(o) },
+            Literal::Sid(sid)       => { bytes.push(0x51); push_len(bytes, sid.as_bytes().len()); bytes.extend_from_slice(sid.as_bytes()) },
+            Literal::Composite(c)   => {
+                bytes.push(0x50);
+                let start = bytes.len();
+                bytes.extend_from_slice(&[0; 4]); // length, written below
+                c.iter().for_each(|l| l.encode(bytes));
+                let len = (bytes.len() - start - 4) as u32;
+                bytes[start .. start + 4].copy_from_slice(&len.to_le_bytes());
+            },
+        }
+    }
+}
+
+fn push_len(bytes: &mut Vec<u8>, len: usize) { bytes.extend_from_slice(&(len as u32).to_le_bytes()) }
+
+fn push_string(bytes: &mut Vec<u8>, s: &str) {
+    push_len(bytes, 2 * s.encode_utf16().count());
+    s.encode_utf16().for_each(|u| bytes.extend_from_slice(&u.to_le_bytes()));
+}
+
+fn take<'b>(r: &mut &'b [u8], len: usize) -> Result<&'b [u8], DecodeError> {
+    let (head, rest) = r.split_at_checked(len).ok_or(DecodeError::Truncated)?;
+    *r = rest;
+    Ok(head)
+}
+
+fn sized<'b>(r: &mut &'b [u8]) -> Result<&'b [u8], DecodeError> {
+    let len = take(r, 4)?;
+    take(r, usize::from32(u32::from_le_bytes([len[0], len[1], len[2], len[3]])))
+}
+
+fn string(r: &mut &[u8]) -> Result<String, DecodeError> {
+    let bytes = sized(r)?;
+    if !bytes.len().is_multiple_of(2) { return Err(DecodeError::InvalidString) }
+    char::decode_utf16(bytes.chunks_exact(2).map(|u| u16::from_le_bytes([u[0], u[1]]))).collect::<Result<_, _>>().map_err(|_| DecodeError::InvalidString)
+}
+
+fn literal(token: u8, r: &mut &[u8], depth: usize) -> Result<Literal, DecodeError> {
+    Ok(match token {
+        0x01 ..= 0x04 => {
+            let i = take(r, 10)?;
+            let value = i64::from_le_bytes([i[0], i[1], i[2], i[3], i[4], i[5], i[6], i[7]]);
+            let sign = match i[8] { 1 => Sign::Positive, 2 => Sign::Negative, 3 => Sign::None, _ => return Err(DecodeError::InvalidInteger) };
+            let base = match i[9] { 1 => Base::Octal, 2 => Base::Decimal, 3 => Base::Hexadecimal, _ => return Err(DecodeError::InvalidInteger) };
+            Literal::Integer(Integer { value, sign, base })
+        },
+        0x10 => Literal::String(string(r)?),
+        0x18 => Literal::OctetString(sized(r)?.to_vec()),
+        0x51 => Literal::Sid(sid::Owned::from_bytes(sized(r)?).ok_or(DecodeError::InvalidSid)?),
+        0x50 => {
+            if depth >= MAX_DEPTH { return Err(DecodeError::TooDeep) }
+            let mut c = sized(r)?;
+            let mut values = Vec::new();
+            while let Some((&token, rest)) = c.split_first() {
+                c = rest;
+                if Operator::from_token(token).is_some() || matches!(token, 0x00 | 0xF8 ..= 0xFB) { return Err(DecodeError::InvalidComposite) }
+                values.push(literal(token, &mut c, depth + 1)?);
+            }
+            Literal::Composite(values)
+        },
+        _ => return Err(DecodeError::UnknownToken(token)),
+    })
+}
+
+
+
+#[cfg(std)] #[test] fn golden_bytes() {
+    use std::vec;
+
+    // (@User.x >= -3) && (Member_of {SID(BA)})
+    let condition = Expression::binary(Operator::And,
+        Expression::binary(Operator::GreaterThanOrEqual, Attribute::user("x"), Literal::Integer(Integer { value: -3, sign: Sign::Negative, base: Base::Decimal })),
+        Expression::unary(Operator::MemberOf, Literal::Composite(vec![Literal::Sid(sid::Owned::from(&*sid!(S-1-5-32-544)))])),
+    );
+    let bytes = condition.to_bytes();
+    assert_eq!(bytes, &[
+        b'a', b'r', b't', b'x',
+        0xF9, 2, 0, 0, 0, b'x', 0,                                              // @User.x
+        0x04, 0xFD, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02, 0x02,       // -3
+        0x85,                                                                   // >=
+        0x50, 21, 0, 0, 0,                                                      // {
+        0x51, 16, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 0x20, 2, 0, 0,  // SID(BA)
+        0x89,                                                                   // } Member_of
+        0xA0,                                                                   // &&
+        0,                                                                      // padding
+    ][..]);
+    assert_eq!(Ok(&condition), Expression::from_bytes(&bytes).as_ref());
+
+    let octets = Expression::binary(Operator::Equals, Attribute::resource("ü"), Literal::OctetString(vec![1, 2]));
+    assert_eq!(Ok(&octets), Expression::from_bytes(&octets.to_bytes()).as_ref());
+
+    use DecodeError as E;
+    assert_eq!(Err(E::MissingSignature),        Expression::from_bytes(b"artX\x87"));
+    assert_eq!(Err(E::MissingOperand),          Expression::from_bytes(b"artx\0\0\0\0"));
+    assert_eq!(Err(E::MissingOperand),          Expression::from_bytes(b"artx\x87"));
+    assert_eq!(Err(E::ExtraOperands),           Expression::from_bytes(b"artx\xF8\0\0\0\0\xF8\0\0\0\0"));
+    assert_eq!(Err(E::Truncated),               Expression::from_bytes(b"artx\xF8\x02\0\0\0x"));
+    assert_eq!(Err(E::InvalidString),           Expression::from_bytes(b"artx\xF8\x01\0\0\0x"));
+    assert_eq!(Err(E::InvalidString),           Expression::from_bytes(b"artx\xF8\x02\0\0\0\x00\xD8"));
+    assert_eq!(Err(E::InvalidInteger),          Expression::from_bytes(b"artx\x04\0\0\0\0\0\0\0\0\x04\x02"));
+    assert_eq!(Err(E::InvalidSid),              Expression::from_bytes(b"artx\x51\x04\0\0\0\x01\0\0\0"));
+    assert_eq!(Err(E::InvalidComposite),        Expression::from_bytes(b"artx\x50\x05\0\0\0\xF8\0\0\0\0"));
+    assert_eq!(Err(E::UnknownToken(0x42)),      Expression::from_bytes(b"artx\x42"));
+    assert_eq!(Ok(Expression::Literal(Literal::Integer(Integer { value: 1, sign: Sign::Positive, base: Base::Hexadecimal }))),
+        Expression::from_bytes(b"artx\x01\x01\0\0\0\0\0\0\0\x01\x03"));
+
+    let mut deep = Vec::from(*b"artx\xF8\0\0\0\0");
+    deep.resize(deep.len() + MAX_DEPTH - 1, 0xA2);
+    assert!(Expression::from_bytes(&deep).is_ok());
+    deep.push(0xA2);
+    assert_eq!(Err(E::TooDeep),                 Expression::from_bytes(&deep));
+
+    // Every truncation and single byte corruption must be rejected or parsed without panicking.
+    for len in 0 .. bytes.len() { let _ = Expression::from_bytes(&bytes[..len]); }
+    for offset in 0 .. bytes.len() {
+        for value in [0x00, 0x01, 0x04, 0x10, 0x50, 0x51, 0x7F, 0x80, 0xA2, 0xF9, 0xFF] {
+            let mut bytes = bytes.clone();
+            bytes[offset] = value;
+            let _ = Expression::from_bytes(&bytes);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Debug, Display, Formatter};
+
+
+
+/// Error parsing the binary form of a conditional [`Expression`](super::Expression)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum DecodeError {
+    /// The application data doesn't start with `artx`.
+    MissingSignature,
+    /// A token is cut off by the end of the buffer (or of its composite.)
+    Truncated,
+    /// A byte that isn't a known operator, operand, or padding token.
+    UnknownToken(u8),
+    /// An integer literal has an invalid sign or base byte.
+    InvalidInteger,
+    /// A string literal or attribute name has an odd byte length, or isn't valid UTF-16.
+    InvalidString,
+    /// A SID literal has the wrong revision, more than 15 sub-authorities, or a length that doesn't match its sub-authority count.
+    InvalidSid,
+    /// A composite literal contains an operator or attribute.
+    InvalidComposite,
+    /// An operator doesn't have enough operands, or the expression is empty.
+    MissingOperand,
+    /// The token stream leaves more than one expression.
+    ExtraOperands,
+    /// The expression is nested deeper than [`MAX_DEPTH`](super::MAX_DEPTH).
+    TooDeep,
+}
+
+impl From<DecodeError> for firehazard::Error {
+    fn from(err: DecodeError) -> Self {
+        Self(match err {
+            DecodeError::InvalidSid => ERROR_INVALID_SID,
+            _                       => ERROR_INVALID_DATA,
+        })
+    }
+}
+
+#[cfg(std)] impl From<DecodeError> for std::io::Error {
+    fn from(err: DecodeError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidData, err) }
+}
+
+impl core::error::Error for DecodeError {}
+
+impl Display for DecodeError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            DecodeError::MissingSignature   => write!(fmt, "conditional expression doesn't start with \"artx\""),
+            DecodeError::Truncated          => write!(fmt, "conditional expression token is truncated"),
+            DecodeError::UnknownToken(t)    => write!(fmt, "unknown conditional expression token 0x{t:02X}"),
+            DecodeError::InvalidInteger     => write!(fmt, "conditional expression integer has an invalid sign or base"),
+            DecodeError::InvalidString      => write!(fmt, "conditional expression string isn't valid UTF-16"),
+            DecodeError::InvalidSid         => write!(fmt, "conditional expression SID is malformed"),
+            DecodeError::InvalidComposite   => write!(fmt, "conditional expression composite contains a non-literal token"),
+            DecodeError::MissingOperand     => write!(fmt, "conditional expression operator is missing an operand"),
+            DecodeError::ExtraOperands      => write!(fmt, "conditional expression has unused operands"),
+            DecodeError::TooDeep            => write!(fmt, "conditional expression is nested too deeply"),
+        }
+    }
+}
+
+
+
+/// Error parsing the [SDDL form](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language-for-conditional-aces-) of a conditional [`Expression`](super::Expression)
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub(super) offset:  usize,
+    pub(super) kind:    ParseErrorKind,
+}
+
+impl ParseError {
+    #[cfg(alloc)] pub(super) fn new(offset: usize, kind: ParseErrorKind) -> Self { Self { offset, kind } }
+
+    /// Byte offset into the expression string where the error was detected.
+    pub fn offset(&self) -> usize { self.offset }
+
+    pub fn kind(&self) -> ParseErrorKind { self.kind }
+}
+
+/// What went wrong parsing the SDDL form of a conditional [`Expression`](super::Expression)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum ParseErrorKind {
+    /// The string ended in the middle of an expression.
+    UnexpectedEnd,
+    /// A character that isn't valid at this position.
+    UnexpectedChar(char),
+    /// An integer that is malformed or doesn't fit in 64 bits.
+    InvalidInteger,
+    /// A `SID(...)` literal containing a malformed SID string, or an unknown or domain relative alias.
+    InvalidSid,
+    /// A `#...` octet string with an odd number of hex digits.
+    InvalidOctetString,
+    /// An empty attribute name, or a malformed `%XXXX` escape.
+    InvalidAttributeName,
+    /// A relational operator whose left hand side isn't an attribute, or a logical operator applied to a literal.
+    InvalidOperand,
+    /// The expression is nested deeper than [`MAX_DEPTH`](super::MAX_DEPTH).
+    TooDeep,
+}
+
+impl From<ParseError> for firehazard::Error {
+    fn from(err: ParseError) -> Self {
+        Self(match err.kind {
+            ParseErrorKind::InvalidSid  => ERROR_INVALID_SID,
+            _                           => ERROR_INVALID_PARAMETER,
+        })
+    }
+}
+
+#[cfg(std)] impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidInput, err) }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Debug for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "condition::ParseError {{ offset: {}, kind: {:?} }}", self.offset, self.kind)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ParseErrorKind::UnexpectedEnd           => "unexpected end of conditional expression",
+            ParseErrorKind::UnexpectedChar(ch)      => return write!(fmt, "unexpected character {ch:?} at offset {}", self.offset),
+            ParseErrorKind::InvalidInteger          => "invalid integer",
+            ParseErrorKind::InvalidSid              => "invalid SID",
+            ParseErrorKind::InvalidOctetString      => "octet string has an odd number of hex digits",
+            ParseErrorKind::InvalidAttributeName    => "invalid attribute name",
+            ParseErrorKind::InvalidOperand          => "invalid operand",
+            ParseErrorKind::TooDeep                 => "conditional expression is nested too deeply",
+        };
+        write!(fmt, "{msg} at offset {}", self.offset)
+    }
+}
@@ -0,0 +1,276 @@
+use crate::prelude::*;
+use super::*;
+
+use winapi::um::winnt::*;
+
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+
+
+
+/// The claims, attributes, and SIDs a conditional [`Expression`] is [evaluated](Expression::evaluate) against
+///
+/// Construct with [`Context::default`], then override fields with struct update syntax.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Context<'a> {
+    /// `@User.*`: the token's user claims (e.g. from [`claim::Attribute::from_information`] of `token::OwnedHandle::user_claim_attributes`.)
+    pub user_claims:            &'a [claim::Attribute],
+
+    /// `@Device.*`: the token's device claims.
+    pub device_claims:          &'a [claim::Attribute],
+
+    /// `@Resource.*`: the resource attributes (`SYSTEM_RESOURCE_ATTRIBUTE_ACE`s) of the object's SACL.
+    pub resource_attributes:    &'a [claim::Attribute],
+
+    /// Unprefixed attributes, supplied by the resource manager.
+    pub local_attributes:       &'a [claim::Attribute],
+
+    /// `Member_of*`: the token's user and groups.
+    /// Only [`sid::group::ENABLED`] SIDs are members, unless [`Context::deny`] is set, in which case [`sid::group::USE_FOR_DENY_ONLY`] SIDs are also members.
+    pub groups:                 &'a [sid::AndAttributes<'a>],
+
+    /// `Device_Member_of*`: the token's device groups.
+    pub device_groups:          &'a [sid::AndAttributes<'a>],
+
+    /// The expression guards a deny ACE: [`sid::group::USE_FOR_DENY_ONLY`] SIDs and [`claim::Flags::USE_FOR_DENY_ONLY`] claims apply.
+    pub deny:                   bool,
+}
+
+impl Expression {
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-dtyp/4b9d2f71-6d3b-4f3a-a4a6-28ad8b4cc1de)\]
+    /// Evaluate the expression as `TRUE` ([`Some(true)`]), `FALSE` ([`Some(false)`]), or `UNKNOWN` ([`None`])
+    ///
+    /// An expression is `UNKNOWN` if it references a missing (or [disabled](claim::Flags::DISABLED)) attribute, or compares values of different types.
+    /// `UNKNOWN` propagates through `!`, and through `&&` / `||` unless the other operand decides the result.
+    /// A callback ACE only applies if its condition is `TRUE`... or, for a deny ACE, `UNKNOWN`.
+    ///
+    /// Attribute names are case insensitive, as are string comparisons unless either operand is a [`claim::Flags::VALUE_CASE_SENSITIVE`] attribute.
+    pub fn evaluate(&self, context: &Context) -> Option<bool> {
+        use Operator::*;
+        match self {
+            Expression::Unary(Not, e)       => e.evaluate(context).map(|b| !b),
+            Expression::Binary(And, l, r)   => match (l.evaluate(context), r.evaluate(context)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true))            => Some(true),
+                _                                   => None,
+            },
+            Expression::Binary(Or, l, r)    => match (l.evaluate(context), r.evaluate(context)) {
+                (Some(true), _) | (_, Some(true))   => Some(true),
+                (Some(false), Some(false))          => Some(false),
+                _                                   => None,
+            },
+            Expression::Unary(op @ (Exists | NotExists), e) => match &**e {
+                Expression::Attribute(a)            => Some(context.lookup(a).is_some() == (*op == Exists)),
+                _                                   => None,
+            },
+            Expression::Unary(op, e) => {
+                let values = values(e, context)?;
+                let (groups, any, negate) = match op {
+                    MemberOf                => (context.groups,         false,  false),
+                    DeviceMemberOf          => (context.device_groups,  false,  false),
+                    MemberOfAny             => (context.groups,         true,   false),
+                    DeviceMemberOfAny       => (context.device_groups,  true,   false),
+                    NotMemberOf             => (context.groups,         false,  true),
+                    NotDeviceMemberOf       => (context.device_groups,  false,  true),
+                    NotMemberOfAny          => (context.groups,         true,   true),
+                    NotDeviceMemberOfAny    => (context.device_groups,  true,   true),
+                    _                       => return None,
+                };
+                let held = values.items.iter().map(|v| match v { Value::Sid(sid) => Some(holds(groups, sid, context.deny)), _ => None }).collect::<Option<Vec<_>>>()?;
+                let member = if any { held.iter().any(|h| *h) } else { held.iter().all(|h| *h) };
+                Some(member != negate)
+            },
+            Expression::Binary(op, l, r) => {
+                let (l, r) = (values(l, context)?, values(r, context)?);
+                let case_sensitive = l.case_sensitive || r.case_sensitive;
+                let single = |ord: fn(Ordering) -> bool| match (&l.items[..], &r.items[..]) {
+                    ([l], [r])  => compare(l, r, case_sensitive).map(ord),
+                    _           => None,
+                };
+                match op {
+                    Equals              => set_eq(&l, &r, case_sensitive),
+                    NotEquals           => set_eq(&l, &r, case_sensitive).map(|b| !b),
+                    LessThan            => single(Ordering::is_lt),
+                    LessThanOrEqual     => single(Ordering::is_le),
+                    GreaterThan         => single(Ordering::is_gt),
+                    GreaterThanOrEqual  => single(Ordering::is_ge),
+                    Contains            => contains_all(&l, &r, case_sensitive),
+                    NotContains         => contains_all(&l, &r, case_sensitive).map(|b| !b),
+                    AnyOf               => contains_any(&l, &r, case_sensitive),
+                    NotAnyOf            => contains_any(&l, &r, case_sensitive).map(|b| !b),
+                    _                   => None,
+                }
+            },
+            Expression::Attribute(_) | Expression::Literal(_) => match &values(self, context)?.items[..] {
+                [Value::Int(i)]     => Some(*i != 0),
+                [Value::Str(s)]     => Some(!s.is_empty()),
+                _                   => None,
+            },
+        }
+    }
+}
+
+impl<'a> Context<'a> {
+    fn lookup(&self, attribute: &Attribute) -> Option<&'a claim::Attribute> {
+        let claims = match attribute.source {
+            Source::Local       => self.local_attributes,
+            Source::User        => self.user_claims,
+            Source::Resource    => self.resource_attributes,
+            Source::Device      => self.device_claims,
+        };
+        claims.iter().find(|claim| {
+            let flags = claim.flags.as_u32();
+            flags & CLAIM_SECURITY_ATTRIBUTE_DISABLED == 0 &&
+            (self.deny || flags & CLAIM_SECURITY_ATTRIBUTE_USE_FOR_DENY_ONLY == 0) &&
+            str_eq(&claim.name, &attribute.name, false)
+        })
+    }
+}
+
+
+
+enum Value<'a> {
+    Int(i128),
+    Str(&'a str),
+    Sid(&'a sid::Owned),
+    Octets(&'a [u8]),
+}
+
+struct Values<'a> {
+    items:          Vec<Value<'a>>,
+    case_sensitive: bool,
+}
+
+/// The values of an attribute or literal operand, or [`None`] if `UNKNOWN`.
+fn values<'a>(e: &'a Expression, context: &Context<'a>) -> Option<Values<'a>> {
+    fn flatten<'a>(l: &'a Literal, items: &mut Vec<Value<'a>>) {
+        match l {
+            Literal::Integer(i)     => items.push(Value::Int(i.value.into())),
+            Literal::String(s)      => items.push(Value::Str(s)),
+            Literal::OctetString(o) => items.push(Value::Octets(o)),
+            Literal::Sid(sid)       => items.push(Value::Sid(sid)),
+            Literal::Composite(c)   => c.iter().for_each(|l| flatten(l, items)),
+        }
+    }
+
+    let mut items = Vec::new();
+    match e {
+        Expression::Literal(l) => {
+            flatten(l, &mut items);
+            Some(Values { items, case_sensitive: false })
+        },
+        Expression::Attribute(a) => {
+            let claim = context.lookup(a)?;
+            match &claim.values {
+                claim::Values::Int64(v)         => items.extend(v.iter().map(|i| Value::Int((*i).into()))),
+                claim::Values::Uint64(v)        => items.extend(v.iter().map(|u| Value::Int((*u).into()))),
+                claim::Values::String(v)        => items.extend(v.iter().map(|s| Value::Str(s))),
+                claim::Values::Sid(v)           => items.extend(v.iter().map(Value::Sid)),
+                claim::Values::Boolean(v)       => items.extend(v.iter().map(|b| Value::Int((*b).into()))),
+                claim::Values::OctetString(v)   => items.extend(v.iter().map(|o| Value::Octets(o))),
+            }
+            Some(Values { items, case_sensitive: claim.flags.as_u32() & CLAIM_SECURITY_ATTRIBUTE_VALUE_CASE_SENSITIVE != 0 })
+        },
+        _ => None,
+    }
+}
+
+/// Compare two values, or [`None`] if they're of different (or unordered) types.
+fn compare(l: &Value, r: &Value, case_sensitive: bool) -> Option<Ordering> {
+    match (l, r) {
+        (Value::Int(l),     Value::Int(r))      => Some(l.cmp(r)),
+        (Value::Str(l),     Value::Str(r))      if case_sensitive => Some(l.cmp(r)),
+        (Value::Str(l),     Value::Str(r))      => Some(l.chars().flat_map(char::to_lowercase).cmp(r.chars().flat_map(char::to_lowercase))),
+        (Value::Sid(l),     Value::Sid(r))      => Some(l.cmp(r)),
+        (Value::Octets(l),  Value::Octets(r))   => Some(l.cmp(r)),
+        _                                       => None,
+    }
+}
+
+fn contains(set: &Values, item: &Value, case_sensitive: bool) -> Option<bool> {
+    let mut found = false;
+    for v in set.items.iter() { found |= compare(v, item, case_sensitive)?.is_eq(); }
+    Some(found)
+}
+
+fn contains_all(l: &Values, r: &Values, case_sensitive: bool) -> Option<bool> {
+    r.items.iter().try_fold(true, |all, item| Some(contains(l, item, case_sensitive)? && all))
+}
+
+fn contains_any(l: &Values, r: &Values, case_sensitive: bool) -> Option<bool> {
+    r.items.iter().try_fold(false, |any, item| Some(contains(l, item, case_sensitive)? || any))
+}
+
+fn set_eq(l: &Values, r: &Values, case_sensitive: bool) -> Option<bool> {
+    Some(contains_all(l, r, case_sensitive)? && contains_all(r, l, case_sensitive)?)
+}
+
+fn str_eq(a: &str, b: &str, case_sensitive: bool) -> bool {
+    compare(&Value::Str(a), &Value::Str(b), case_sensitive) == Some(Ordering::Equal)
+}
+
+fn holds(groups: &[sid::AndAttributes], sid: &sid::Owned, deny: bool) -> bool {
+    let usable = if deny { SE_GROUP_ENABLED | SE_GROUP_USE_FOR_DENY_ONLY } else { SE_GROUP_ENABLED };
+    groups.iter().any(|g| *g.sid == *sid && u32::from(g.attributes) & usable != 0 && (deny || u32::from(g.attributes) & SE_GROUP_USE_FOR_DENY_ONLY == 0))
+}
+
+
+
+#[cfg(std)] #[test] fn evaluate() {
+    use std::vec;
+
+    let user = [
+        claim::Attribute::new("Clearance",  claim::Values::Int64(vec![3])),
+        claim::Attribute::new("Projects",   claim::Values::String(vec!["Firehazard".into(), "Thindx".into()])),
+        claim::Attribute { flags: claim::Flags::VALUE_CASE_SENSITIVE, ..claim::Attribute::new("Code", claim::Values::String(vec!["AbC".into()])) },
+        claim::Attribute { flags: claim::Flags::DISABLED, ..claim::Attribute::new("Disabled", claim::Values::Boolean(vec![true])) },
+        claim::Attribute { flags: claim::Flags::USE_FOR_DENY_ONLY, ..claim::Attribute::new("DenyOnly", claim::Values::Boolean(vec![true])) },
+    ];
+    let resource = [claim::Attribute::new("Secrecy", claim::Values::Uint64(vec![2]))];
+    let groups = [
+        sid::AndAttributes::new(sid!(S-1-1-0),      sid::group::ENABLED),
+        sid::AndAttributes::new(sid!(S-1-5-32-544), sid::group::USE_FOR_DENY_ONLY),
+    ];
+    let allow = Context { user_claims: &user, resource_attributes: &resource, groups: &groups, ..Context::default() };
+    let deny  = Context { deny: true, ..allow };
+
+    let eval = |condition: &str, context: &Context| condition.parse::<Expression>().unwrap().evaluate(context);
+    for (condition, expected) in [
+        ("@User.clearance >= 3",                                Some(true)),
+        ("@User.Clearance > 3",                                 Some(false)),
+        ("@User.Clearance >= @Resource.Secrecy",                Some(true)),
+        ("@User.Clearance == \"3\"",                            None),
+        ("@User.Missing == 3",                                  None),
+        ("!(@User.Missing == 3)",                               None),
+        ("(@User.Missing == 3) && (@User.Clearance == 4)",      Some(false)),
+        ("(@User.Missing == 3) || (@User.Clearance == 3)",      Some(true)),
+        ("(@User.Missing == 3) || (@User.Clearance == 4)",      None),
+        ("Exists @User.Clearance",                              Some(true)),
+        ("Not_Exists @User.Missing",                            Some(true)),
+        ("Exists @User.Disabled",                               Some(false)),
+        ("Exists @User.DenyOnly",                               Some(false)),
+        ("@User.Clearance",                                     Some(true)),
+        ("@User.Projects == {\"thindx\", \"FIREHAZARD\"}",      Some(true)),
+        ("@User.Projects == \"thindx\"",                        Some(false)),
+        ("@User.Projects Contains \"thindx\"",                  Some(true)),
+        ("@User.Projects Contains {\"thindx\", \"other\"}",     Some(false)),
+        ("@User.Projects Any_of {\"thindx\", \"other\"}",       Some(true)),
+        ("@User.Projects Not_Any_of {\"other\"}",               Some(true)),
+        ("@User.Projects < \"z\"",                              None), // multiple values
+        ("@User.Code == \"abc\"",                               Some(false)),
+        ("@User.Code == \"AbC\"",                               Some(true)),
+        ("Member_of {SID(WD)}",                                 Some(true)),
+        ("Member_of {SID(WD), SID(BA)}",                        Some(false)),
+        ("Member_of_Any {SID(WD), SID(BA)}",                    Some(true)),
+        ("Not_Member_of SID(BU)",                               Some(true)),
+        ("Device_Member_of_Any {SID(WD)}",                      Some(false)),
+        ("Member_of {1}",                                       None),
+    ] {
+        assert_eq!(expected, eval(condition, &allow), "{condition:?}");
+    }
+
+    assert_eq!(Some(true), eval("Member_of {SID(WD), SID(BA)}",   &deny));
+    assert_eq!(Some(true), eval("Exists @User.DenyOnly",          &deny));
+}
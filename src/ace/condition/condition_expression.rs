@@ -0,0 +1,289 @@
+use crate::prelude::*;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language-for-conditional-aces-)\]
+/// A conditional ACE expression (or subexpression)
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// use ace::condition::{Attribute, Expression, Literal, Operator};
+///
+/// let condition = Expression::binary(Operator::And,
+///     Expression::binary(Operator::GreaterThanOrEqual, Attribute::user("clearance"), Literal::from(3)),
+///     Expression::unary(Operator::MemberOf, Literal::Composite(vec![Literal::Sid(sid::Owned::from(&*sid!(S-1-5-32-544)))])),
+/// );
+/// assert_eq!(condition.to_string(), "((@User.clearance >= 3) && (Member_of {SID(BA)}))");
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Expression {
+    /// A reference to a claim or resource attribute, e.g. `@User.clearance`
+    Attribute(Attribute),
+
+    /// A constant, e.g. `3`, `"text"`, or `{SID(BA), SID(BU)}`
+    Literal(Literal),
+
+    /// A unary [`Operator`] (e.g. `!`, `Exists`, `Member_of`) and its operand
+    Unary(Operator, Box<Expression>),
+
+    /// A binary [`Operator`] (e.g. `&&`, `==`, `Contains`) and its left and right operands
+    Binary(Operator, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    /// `(op operand)`
+    ///
+    /// ### Panics
+    /// If `op` isn't a unary operator.
+    pub fn unary(op: Operator, operand: impl Into<Expression>) -> Self {
+        assert!(op.is_unary(), "{op:?} is not a unary operator");
+        Expression::Unary(op, Box::new(operand.into()))
+    }
+
+    /// `(lhs op rhs)`
+    ///
+    /// ### Panics
+    /// If `op` isn't a binary operator.
+    pub fn binary(op: Operator, lhs: impl Into<Expression>, rhs: impl Into<Expression>) -> Self {
+        assert!(!op.is_unary(), "{op:?} is not a binary operator");
+        Expression::Binary(op, Box::new(lhs.into()), Box::new(rhs.into()))
+    }
+
+    /// How deeply nested this expression is (1 for a lone attribute or literal.)
+    pub fn depth(&self) -> usize {
+        match self {
+            Expression::Attribute(_) | Expression::Literal(_)   => 1,
+            Expression::Unary(_, operand)                       => 1 + operand.depth(),
+            Expression::Binary(_, lhs, rhs)                     => 1 + lhs.depth().max(rhs.depth()),
+        }
+    }
+}
+
+impl From<Attribute> for Expression { fn from(a: Attribute) -> Self { Expression::Attribute(a) } }
+impl From<Literal>   for Expression { fn from(l: Literal  ) -> Self { Expression::Literal(l) } }
+
+
+
+/// An operator of a conditional [`Expression`], and its binary token
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operator {
+    /// `==` (0x80)
+    Equals,
+    /// `!=` (0x81)
+    NotEquals,
+    /// `<` (0x82)
+    LessThan,
+    /// `<=` (0x83)
+    LessThanOrEqual,
+    /// `>` (0x84)
+    GreaterThan,
+    /// `>=` (0x85)
+    GreaterThanOrEqual,
+    /// `Contains` (0x86): the left hand side contains every value of the right hand side
+    Contains,
+    /// `Any_of` (0x88): the left and right hand sides share at least one value
+    AnyOf,
+    /// `Not_Contains` (0x8E)
+    NotContains,
+    /// `Not_Any_of` (0x8F)
+    NotAnyOf,
+
+    /// `Member_of` (0x89): the token holds every SID of the operand
+    MemberOf,
+    /// `Device_Member_of` (0x8A): the device holds every SID of the operand
+    DeviceMemberOf,
+    /// `Member_of_Any` (0x8B): the token holds at least one SID of the operand
+    MemberOfAny,
+    /// `Device_Member_of_Any` (0x8C): the device holds at least one SID of the operand
+    DeviceMemberOfAny,
+    /// `Not_Member_of` (0x90)
+    NotMemberOf,
+    /// `Not_Device_Member_of` (0x91)
+    NotDeviceMemberOf,
+    /// `Not_Member_of_Any` (0x92)
+    NotMemberOfAny,
+    /// `Not_Device_Member_of_Any` (0x93)
+    NotDeviceMemberOfAny,
+
+    /// `Exists` (0x87): the attribute is present
+    Exists,
+    /// `Not_Exists` (0x8D): the attribute is absent
+    NotExists,
+    /// `&&` (0xA0)
+    And,
+    /// `||` (0xA1)
+    Or,
+    /// `!` (0xA2)
+    Not,
+}
+
+impl Operator {
+    pub(crate) const ALL : &'static [(Operator, u8, &'static str)] = &[
+        (Operator::Equals,                  0x80, "=="                      ),
+        (Operator::NotEquals,               0x81, "!="                      ),
+        (Operator::LessThan,                0x82, "<"                       ),
+        (Operator::LessThanOrEqual,         0x83, "<="                      ),
+        (Operator::GreaterThan,             0x84, ">"                       ),
+        (Operator::GreaterThanOrEqual,      0x85, ">="                      ),
+        (Operator::Contains,                0x86, "Contains"                ),
+        (Operator::AnyOf,                   0x88, "Any_of"                  ),
+        (Operator::NotContains,             0x8E, "Not_Contains"            ),
+        (Operator::NotAnyOf,                0x8F, "Not_Any_of"              ),
+        (Operator::MemberOf,                0x89, "Member_of"               ),
+        (Operator::DeviceMemberOf,          0x8A, "Device_Member_of"        ),
+        (Operator::MemberOfAny,             0x8B, "Member_of_Any"           ),
+        (Operator::DeviceMemberOfAny,       0x8C, "Device_Member_of_Any"    ),
+        (Operator::NotMemberOf,             0x90, "Not_Member_of"           ),
+        (Operator::NotDeviceMemberOf,       0x91, "Not_Device_Member_of"    ),
+        (Operator::NotMemberOfAny,          0x92, "Not_Member_of_Any"       ),
+        (Operator::NotDeviceMemberOfAny,    0x93, "Not_Device_Member_of_Any"),
+        (Operator::Exists,                  0x87, "Exists"                  ),
+        (Operator::NotExists,               0x8D, "Not_Exists"              ),
+        (Operator::And,                     0xA0, "&&"                      ),
+        (Operator::Or,                      0xA1, "||"                      ),
+        (Operator::Not,                     0xA2, "!"                       ),
+    ];
+
+    fn entry(self) -> &'static (Operator, u8, &'static str) { Self::ALL.iter().find(|e| e.0 == self).unwrap() }
+
+    /// The binary token of the operator (e.g. `0x80` for [`Operator::Equals`])
+    pub fn token(self) -> u8 { self.entry().1 }
+
+    /// The operator for a binary token, if `token` is an operator token.
+    pub fn from_token(token: u8) -> Option<Self> { Self::ALL.iter().find(|e| e.1 == token).map(|e| e.0) }
+
+    /// The SDDL spelling of the operator (e.g. `"=="`, `"Member_of"`)
+    pub fn as_sddl(self) -> &'static str { self.entry().2 }
+
+    /// `true` if the operator takes a single operand (`!`, `Exists`, `Member_of`, ...)
+    pub fn is_unary(self) -> bool {
+        use Operator::*;
+        matches!(self,
+            MemberOf | DeviceMemberOf | MemberOfAny | DeviceMemberOfAny |
+            NotMemberOf | NotDeviceMemberOf | NotMemberOfAny | NotDeviceMemberOfAny |
+            Exists | NotExists | Not
+        )
+    }
+
+    /// `true` for the logical operators (`&&`, `||`, `!`), whose operands are themselves boolean expressions.
+    pub fn is_logical(self) -> bool { matches!(self, Operator::And | Operator::Or | Operator::Not) }
+}
+
+
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language-for-conditional-aces-#attribute-names)\]
+/// A named attribute of a conditional [`Expression`], e.g. `@User.clearance`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Attribute {
+    pub source: Source,
+    pub name:   String,
+}
+
+impl Attribute {
+    pub fn new(source: Source, name: impl Into<String>) -> Self { Self { source, name: name.into() } }
+
+    /// `name` (a local attribute, without a prefix)
+    pub fn local   (name: impl Into<String>) -> Self { Self::new(Source::Local,    name) }
+
+    /// `@User.name`
+    pub fn user    (name: impl Into<String>) -> Self { Self::new(Source::User,     name) }
+
+    /// `@Resource.name`
+    pub fn resource(name: impl Into<String>) -> Self { Self::new(Source::Resource, name) }
+
+    /// `@Device.name`
+    pub fn device  (name: impl Into<String>) -> Self { Self::new(Source::Device,   name) }
+}
+
+/// Which set of claims or attributes an [`Attribute`] is looked up in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Source {
+    /// No prefix (0xF8): an attribute supplied by the resource manager
+    Local,
+    /// `@User.` (0xF9): a user claim of the token
+    User,
+    /// `@Resource.` (0xFA): a resource attribute (`SYSTEM_RESOURCE_ATTRIBUTE_ACE`) of the object's SACL
+    Resource,
+    /// `@Device.` (0xFB): a device claim of the token
+    Device,
+}
+
+impl Source {
+    pub(crate) const ALL : &'static [(Source, u8, &'static str)] = &[
+        (Source::Local,     0xF8, ""            ),
+        (Source::User,      0xF9, "@User."      ),
+        (Source::Resource,  0xFA, "@Resource."  ),
+        (Source::Device,    0xFB, "@Device."    ),
+    ];
+
+    /// The binary token of the attribute (e.g. `0xF9` for [`Source::User`])
+    pub fn token(self) -> u8 { Self::ALL.iter().find(|e| e.0 == self).unwrap().1 }
+
+    /// The SDDL prefix of the attribute (e.g. `"@User."`, or `""` for [`Source::Local`])
+    pub fn prefix(self) -> &'static str { Self::ALL.iter().find(|e| e.0 == self).unwrap().2 }
+}
+
+
+
+/// A constant of a conditional [`Expression`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Literal {
+    /// `3`, `-0x10`, `017` (0x04)
+    Integer(Integer),
+    /// `"text"` (0x10)
+    String(String),
+    /// `#0a0b` (0x18)
+    OctetString(Vec<u8>),
+    /// `SID(BA)` (0x51)
+    Sid(sid::Owned),
+    /// `{1, 2, 3}` (0x50)
+    Composite(Vec<Literal>),
+}
+
+impl From<i64>          for Literal { fn from(value: i64        ) -> Self { Literal::Integer(Integer::new(value)) } }
+impl From<Integer>      for Literal { fn from(value: Integer    ) -> Self { Literal::Integer(value) } }
+impl From<&str>         for Literal { fn from(value: &str       ) -> Self { Literal::String(value.into()) } }
+impl From<String>       for Literal { fn from(value: String     ) -> Self { Literal::String(value) } }
+impl From<sid::Owned>   for Literal { fn from(value: sid::Owned ) -> Self { Literal::Sid(value) } }
+impl From<Vec<Literal>> for Literal { fn from(value: Vec<Literal>) -> Self { Literal::Composite(value) } }
+
+/// An integer [`Literal`], and how it's written in SDDL
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Integer {
+    pub value:  i64,
+    pub sign:   Sign,
+    pub base:   Base,
+}
+
+impl Integer {
+    /// An unsigned decimal integer.
+    pub const fn new(value: i64) -> Self { Self { value, sign: Sign::None, base: Base::Decimal } }
+}
+
+/// The sign byte of an [`Integer`] literal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// `+` (0x01)
+    Positive = 1,
+    /// `-` (0x02)
+    Negative = 2,
+    /// No sign (0x03)
+    None     = 3,
+}
+
+/// The base byte of an [`Integer`] literal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Base {
+    /// `017` (0x01)
+    Octal       = 1,
+    /// `15` (0x02)
+    Decimal     = 2,
+    /// `0xF` (0x03)
+    Hexadecimal = 3,
+}
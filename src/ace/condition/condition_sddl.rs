@@ -0,0 +1,417 @@
+use crate::prelude::*;
+use super::*;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+
+
+impl Display for Expression {
+    /// The SDDL form of the expression.  Every operator is parenthesized, e.g. `((@User.x >= 3) && (Member_of {SID(BA)}))`.
+    ///
+    /// SDDL has no escape syntax for string literals: strings containing `"` are written as-is, and won't parse back into the same expression.
+    /// [`security::Descriptor::to_sddl`] rejects such expressions.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Expression::Attribute(a)            => Display::fmt(a, fmt),
+            Expression::Literal(l)              => Display::fmt(l, fmt),
+            Expression::Unary(Operator::Not, e) => write!(fmt, "(!{e})"),
+            Expression::Unary(op, e)            => write!(fmt, "({} {e})", op.as_sddl()),
+            Expression::Binary(op, lhs, rhs)    => write!(fmt, "({lhs} {} {rhs})", op.as_sddl()),
+        }
+    }
+}
+
+impl Display for Attribute {
+    /// `@User.name`, with characters other than `A-Z a-z 0-9 _ : . /` escaped as `%XXXX` UTF-16 code units.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.write_str(self.source.prefix())?;
+        // A local attribute can't start with a digit (an integer), or be spelled like an operator keyword.
+        let escape_first = self.source == Source::Local && (
+            self.name.starts_with(|ch: char| ch.is_ascii_digit()) ||
+            Operator::ALL.iter().any(|(_, _, sddl)| sddl.eq_ignore_ascii_case(&self.name))
+        );
+        for (i, ch) in self.name.chars().enumerate() {
+            if is_name_char(ch) && !(i == 0 && escape_first) {
+                write!(fmt, "{ch}")?;
+            } else {
+                let mut units = [0; 2];
+                for unit in ch.encode_utf16(&mut units) { write!(fmt, "%{unit:04X}")?; }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Literal::Integer(i)     => Display::fmt(i, fmt),
+            Literal::String(s)      => write!(fmt, "\"{s}\""),
+            Literal::OctetString(o) => { fmt.write_str("#")?; o.iter().try_for_each(|b| write!(fmt, "{b:02x}")) },
+            Literal::Sid(sid)       => match sid.as_sid_ptr().sddl_alias() {
+                Some(alias) => write!(fmt, "SID({alias})"),
+                None        => write!(fmt, "SID({})", *sid.as_sid_ptr()),
+            },
+            Literal::Composite(c)   => {
+                fmt.write_str("{")?;
+                for (i, l) in c.iter().enumerate() {
+                    if i != 0 { fmt.write_str(", ")?; }
+                    Display::fmt(l, fmt)?;
+                }
+                fmt.write_str("}")
+            },
+        }
+    }
+}
+
+impl Expression {
+    /// `false` if the SDDL form of this expression wouldn't parse back into the same expression.
+    ///
+    /// SDDL has no escape syntax for string literals, so strings containing `"` (or NUL, which would truncate the SDDL) can't be represented.
+    pub(crate) fn is_sddl_representable(&self) -> bool {
+        match self {
+            Expression::Attribute(_)            => true,
+            Expression::Literal(l)              => l.is_sddl_representable(),
+            Expression::Unary(_, e)             => e.is_sddl_representable(),
+            Expression::Binary(_, lhs, rhs)     => lhs.is_sddl_representable() && rhs.is_sddl_representable(),
+        }
+    }
+}
+
+impl Literal {
+    fn is_sddl_representable(&self) -> bool {
+        match self {
+            Literal::String(s)      => !s.contains(['"', '\0']),
+            Literal::Composite(c)   => c.iter().all(Literal::is_sddl_representable),
+            _                       => true,
+        }
+    }
+}
+
+impl Display for Integer {
+    /// `3`, `-3`, `+3`, `0x3`, or `03`, depending on [`Integer::sign`] and [`Integer::base`].
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let magnitude = self.value.unsigned_abs();
+        if self.value < 0 || (self.value == 0 && self.sign == Sign::Negative) {
+            fmt.write_str("-")?;
+        } else if self.sign == Sign::Positive {
+            fmt.write_str("+")?;
+        }
+        match self.base {
+            Base::Octal if magnitude == 0   => fmt.write_str("0"),
+            Base::Octal                     => write!(fmt, "0{magnitude:o}"),
+            Base::Decimal                   => write!(fmt, "{magnitude}"),
+            Base::Hexadecimal               => write!(fmt, "0x{magnitude:x}"),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    /// Parse the SDDL form of a conditional expression, e.g. `(@User.clearance >= 3) && (Member_of {SID(BA)})`.
+    ///
+    /// Operators and `SID(...)` are case insensitive.
+    /// `||` binds more loosely than `&&`, which binds more loosely than `!`, which binds more loosely than relational operators.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut p = Parser { s, pos: 0, nesting: 0 };
+        let (expression, _) = p.or()?;
+        p.skip_ws();
+        if p.pos != s.len() { return Err(p.unexpected()) }
+        Ok(expression)
+    }
+}
+
+
+
+fn is_name_char(ch: char) -> bool { ch.is_ascii_alphanumeric() || matches!(ch, '_' | ':' | '.' | '/') }
+
+struct Parser<'s> {
+    s:          &'s str,
+    pos:        usize,
+    nesting:    usize, // parentheses and `!`s, which recurse without necessarily deepening the expression
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str { &self.s[self.pos..] }
+    fn peek(&self) -> Option<u8> { self.s.as_bytes().get(self.pos).copied() }
+    fn skip_ws(&mut self) { while self.peek().is_some_and(|b| b.is_ascii_whitespace()) { self.pos += 1; } }
+    fn err(&self, kind: ParseErrorKind) -> ParseError { ParseError::new(self.pos, kind) }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let matches = self.rest().get(..token.len()).is_some_and(|r| r.eq_ignore_ascii_case(token));
+        if matches { self.pos += token.len(); }
+        matches
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.rest().chars().next() {
+            None        => self.err(ParseErrorKind::UnexpectedEnd),
+            Some(ch)    => self.err(ParseErrorKind::UnexpectedChar(ch)),
+        }
+    }
+
+    /// The `[A-Za-z_]+` keyword at the current position, if it's one of `ops`.  Doesn't advance.
+    fn keyword(&self, ops: impl Fn(Operator) -> bool) -> Option<(Operator, usize)> {
+        let len = self.rest().bytes().take_while(|b| b.is_ascii_alphabetic() || *b == b'_').count();
+        let word = &self.rest()[..len];
+        if len == 0 || self.rest()[len..].starts_with(|ch: char| is_name_char(ch) || ch == '%') { return None }
+        Operator::ALL.iter().find(|(op, _, sddl)| ops(*op) && sddl.eq_ignore_ascii_case(word)).map(|(op, _, _)| (*op, len))
+    }
+
+    fn nest(&self, depth: usize) -> Result<usize, ParseError> {
+        if depth > MAX_DEPTH { Err(self.err(ParseErrorKind::TooDeep)) } else { Ok(depth) }
+    }
+
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        self.nesting += 1;
+        if self.nesting > MAX_DEPTH { return Err(self.err(ParseErrorKind::TooDeep)) }
+        let r = f(self);
+        self.nesting -= 1;
+        r
+    }
+
+    /// `and || and || ...`
+    fn or(&mut self) -> Result<(Expression, usize), ParseError> { self.logical(Operator::Or, "||", Self::and) }
+
+    /// `not && not && ...`
+    fn and(&mut self) -> Result<(Expression, usize), ParseError> { self.logical(Operator::And, "&&", Self::not) }
+
+    fn logical(&mut self, op: Operator, token: &str, next: fn(&mut Self) -> Result<(Expression, usize), ParseError>) -> Result<(Expression, usize), ParseError> {
+        let (mut lhs, mut depth) = next(self)?;
+        loop {
+            self.skip_ws();
+            if !self.eat(token) { return Ok((lhs, depth)) }
+            let (rhs, r_depth) = next(self)?;
+            depth = self.nest(depth.max(r_depth) + 1)?;
+            lhs = Expression::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    /// `!not` or `primary`
+    fn not(&mut self) -> Result<(Expression, usize), ParseError> {
+        self.skip_ws();
+        if self.peek() == Some(b'!') && self.s.as_bytes().get(self.pos + 1) != Some(&b'=') {
+            self.pos += 1;
+            let (operand, depth) = self.nested(Self::not)?;
+            let depth = self.nest(depth + 1)?;
+            return Ok((Expression::Unary(Operator::Not, Box::new(operand)), depth));
+        }
+        self.primary()
+    }
+
+    /// `(or)`, `Exists attribute`, `Member_of operand`, `attribute op operand`, or `attribute`
+    fn primary(&mut self) -> Result<(Expression, usize), ParseError> {
+        self.skip_ws();
+        let start = self.pos;
+
+        if self.peek() == Some(b'(') {
+            self.pos += 1;
+            let (expression, depth) = self.nested(Self::or)?;
+            self.skip_ws();
+            if self.peek() != Some(b')') { return Err(self.unexpected()) }
+            self.pos += 1;
+            return Ok((expression, depth));
+        }
+
+        if let Some((op, len)) = self.keyword(Operator::is_unary) {
+            self.pos += len;
+            self.skip_ws();
+            let operand_start = self.pos;
+            let (operand, depth) = self.operand(1)?;
+            if matches!(op, Operator::Exists | Operator::NotExists) && !matches!(operand, Expression::Attribute(_)) {
+                return Err(ParseError::new(operand_start, ParseErrorKind::InvalidOperand));
+            }
+            let depth = self.nest(depth + 1)?;
+            return Ok((Expression::Unary(op, Box::new(operand)), depth));
+        }
+
+        let (lhs, l_depth) = self.operand(1)?;
+        self.skip_ws();
+        let op = [
+            ("==", Operator::Equals), ("!=", Operator::NotEquals),
+            ("<=", Operator::LessThanOrEqual), (">=", Operator::GreaterThanOrEqual),
+            ("<",  Operator::LessThan), (">",  Operator::GreaterThan),
+        ].into_iter().find(|(token, _)| self.eat(token)).map(|(_, op)| op).or_else(|| {
+            let (op, len) = self.keyword(|op| matches!(op, Operator::Contains | Operator::AnyOf | Operator::NotContains | Operator::NotAnyOf))?;
+            self.pos += len;
+            Some(op)
+        });
+
+        if !matches!(lhs, Expression::Attribute(_)) { return Err(ParseError::new(start, ParseErrorKind::InvalidOperand)) }
+        let Some(op) = op else { return Ok((lhs, l_depth)) };
+        self.skip_ws();
+        let (rhs, r_depth) = self.operand(1)?;
+        let depth = self.nest(l_depth.max(r_depth) + 1)?;
+        Ok((Expression::Binary(op, Box::new(lhs), Box::new(rhs)), depth))
+    }
+
+    /// An attribute or literal
+    fn operand(&mut self, depth: usize) -> Result<(Expression, usize), ParseError> {
+        let depth = self.nest(depth)?;
+        match self.peek() {
+            Some(b'@') => {
+                let source = Source::ALL.iter().map(|e| e.0).filter(|s| *s != Source::Local).find(|s| self.eat(s.prefix()));
+                let Some(source) = source else { return Err(self.err(ParseErrorKind::InvalidAttributeName)) };
+                Ok((Expression::Attribute(Attribute { source, name: self.name()? }), depth))
+            },
+            Some(b) if b.is_ascii_alphabetic() || b == b'_' || b == b'%' => {
+                if self.eat("SID(") { return Ok((Expression::Literal(self.sid()?), depth)) }
+                Ok((Expression::Attribute(Attribute { source: Source::Local, name: self.name()? }), depth))
+            },
+            _ => Ok((Expression::Literal(self.literal(depth)?), depth)),
+        }
+    }
+
+    /// `"string"`, `#octets`, `SID(...)`, `{literal, ...}`, or an integer
+    fn literal(&mut self, depth: usize) -> Result<Literal, ParseError> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b'"') => {
+                let Some(len) = self.rest()[1..].find('"') else { self.pos = self.s.len(); return Err(self.unexpected()) };
+                let s = String::from(&self.rest()[1 .. 1 + len]);
+                self.pos += len + 2;
+                Ok(Literal::String(s))
+            },
+            Some(b'#') => {
+                self.pos += 1;
+                let len = self.rest().bytes().take_while(u8::is_ascii_hexdigit).count();
+                if !len.is_multiple_of(2) { return Err(ParseError::new(start, ParseErrorKind::InvalidOctetString)) }
+                let octets = (0 .. len).step_by(2).map(|i| u8::from_str_radix(&self.rest()[i .. i + 2], 16).unwrap()).collect();
+                self.pos += len;
+                Ok(Literal::OctetString(octets))
+            },
+            Some(b'{') => {
+                let depth = self.nest(depth + 1)?;
+                self.pos += 1;
+                let mut values = Vec::new();
+                loop {
+                    self.skip_ws();
+                    if values.is_empty() && self.peek() == Some(b'}') { break }
+                    values.push(self.literal(depth)?);
+                    self.skip_ws();
+                    if self.peek() == Some(b',') { self.pos += 1; continue }
+                    if self.peek() == Some(b'}') { break }
+                    return Err(self.unexpected());
+                }
+                self.pos += 1;
+                Ok(Literal::Composite(values))
+            },
+            Some(b'S' | b's') if self.eat("SID(") => self.sid(),
+            Some(b'+' | b'-' | b'0' ..= b'9') => self.integer(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// The rest of `SID(BA)` or `SID(S-1-5-32-544)`, after `SID(`
+    fn sid(&mut self) -> Result<Literal, ParseError> {
+        let start = self.pos;
+        let Some(len) = self.rest().find(')') else { self.pos = self.s.len(); return Err(self.unexpected()) };
+        let s = self.rest()[..len].trim_matches(|ch: char| ch.is_ascii_whitespace());
+        let sid = match sid::parse_alias(s) {
+            Some(sid)   => sid::Owned::from(&*sid),
+            None        => s.parse().map_err(|_| ParseError::new(start, ParseErrorKind::InvalidSid))?,
+        };
+        self.pos += len + 1;
+        Ok(Literal::Sid(sid))
+    }
+
+    /// `[+-]` then decimal, `0x` hexadecimal, or `0` octal
+    fn integer(&mut self) -> Result<Literal, ParseError> {
+        let start = self.pos;
+        let sign = match self.peek() {
+            Some(b'+')  => { self.pos += 1; Sign::Positive },
+            Some(b'-')  => { self.pos += 1; Sign::Negative },
+            _           => Sign::None,
+        };
+        let octal = self.rest().starts_with('0') && self.rest()[1..].starts_with(|ch: char| ch.is_ascii_digit());
+        let (base, radix) = if self.eat("0x") { (Base::Hexadecimal, 16) } else if octal { self.pos += 1; (Base::Octal, 8) } else { (Base::Decimal, 10) };
+        let len = self.rest().bytes().take_while(u8::is_ascii_alphanumeric).count();
+        let digits = &self.rest()[..len];
+        let magnitude = if digits.is_empty() { None } else { u64::from_str_radix(digits, radix).ok() };
+        let value = magnitude.and_then(|m| if sign == Sign::Negative { 0_i64.checked_sub_unsigned(m) } else { i64::try_from(m).ok() });
+        let Some(value) = value else { return Err(ParseError::new(start, ParseErrorKind::InvalidInteger)) };
+        self.pos += len;
+        Ok(Literal::Integer(Integer { value, sign, base }))
+    }
+
+    /// An attribute name: `A-Z a-z 0-9 _ : . /` or `%XXXX` UTF-16 code units
+    fn name(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        let mut units = Vec::new();
+        loop {
+            match self.rest().chars().next() {
+                Some(ch) if is_name_char(ch) => { units.push(ch as u16); self.pos += 1 },
+                Some('%') => {
+                    let unit = self.rest().get(1..5).filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit())).and_then(|hex| u16::from_str_radix(hex, 16).ok());
+                    let Some(unit) = unit else { return Err(self.err(ParseErrorKind::InvalidAttributeName)) };
+                    units.push(unit);
+                    self.pos += 5;
+                },
+                _ => break,
+            }
+        }
+        if units.is_empty() { return Err(ParseError::new(start, ParseErrorKind::InvalidAttributeName)) }
+        String::from_utf16(&units).map_err(|_| ParseError::new(start, ParseErrorKind::InvalidAttributeName))
+    }
+}
+
+
+
+#[cfg(std)] #[test] fn round_trip() {
+    use std::string::ToString;
+    for (input, canonical) in [
+        ("(@User.clearance >= 3) && (Member_of {SID(BA)})",             "((@User.clearance >= 3) && (Member_of {SID(BA)}))"),
+        ("@User.a==1||@User.b!=2&&!(@Device.c<3)",                      "((@User.a == 1) || ((@User.b != 2) && (!(@Device.c < 3))))"),
+        ("(Exists @Resource.Secrecy) && (Not_Exists @User.x)",          "((Exists @Resource.Secrecy) && (Not_Exists @User.x))"),
+        ("(@user.Project any_of {\"a\", \"b\"})",                       "(@User.Project Any_of {\"a\", \"b\"})"),
+        ("(@User.x Not_Contains @Resource.y)",                          "(@User.x Not_Contains @Resource.y)"),
+        ("(Device_Member_of_Any {SID(S-1-5-21-1-2-3-1001), SID(WD)})",  "(Device_Member_of_Any {SID(S-1-5-21-1-2-3-1001), SID(WD)})"),
+        ("(not_member_of{sid(ba)})",                                    "(Not_Member_of {SID(BA)})"),
+        ("(@User.x == -0x1F) || (@User.y == 017) || (@User.z == +0)",   "(((@User.x == -0x1f) || (@User.y == 017)) || (@User.z == +0))"),
+        ("@User.x == 0",                                                "(@User.x == 0)"),
+        ("(@User.x == #0aFF) && (@User.y == #)",                        "((@User.x == #0aff) && (@User.y == #))"),
+        ("(local%0040name == {})",                                      "(local%0040name == {})"),
+        ("(%0045xists)",                                                "%0045xists"),
+        ("(@User.x == -9223372036854775808)",                           "(@User.x == -9223372036854775808)"),
+    ] {
+        let expression : Expression = input.parse().unwrap_or_else(|err| panic!("{input:?}: {err}"));
+        assert_eq!(canonical, expression.to_string(), "{input:?}");
+        assert_eq!(expression, canonical.parse().unwrap(), "{canonical:?}");
+        assert_eq!(Ok(&expression), Expression::from_bytes(&expression.to_bytes()).as_ref(), "{input:?}");
+    }
+}
+
+#[cfg(std)] #[test] fn parse_errors() {
+    use ParseErrorKind::*;
+    for (input, offset, kind) in [
+        ("",                                0, UnexpectedEnd),
+        ("(@User.x == 1",                  13, UnexpectedEnd),
+        ("(@User.x == 1))",                14, UnexpectedChar(')')),
+        ("@User.x == \"abc",               15, UnexpectedEnd),
+        ("@Usr.x == 1",                     0, InvalidAttributeName),
+        ("@User.%00zz == 1",                6, InvalidAttributeName),
+        ("@User.x == 0x",                  11, InvalidInteger),
+        ("@User.x == 09",                  11, InvalidInteger),
+        ("@User.x == 9223372036854775808", 11, InvalidInteger),
+        ("@User.x == #abc",                11, InvalidOctetString),
+        ("Member_of SID(DA)",              14, InvalidSid),
+        ("Exists 1",                        7, InvalidOperand),
+        ("1 == @User.x",                    0, InvalidOperand),
+        ("!1",                              1, InvalidOperand),
+        ("@User.x == {1, 2",               16, UnexpectedEnd),
+    ] {
+        let err = input.parse::<Expression>().unwrap_err();
+        assert_eq!((offset, kind), (err.offset(), err.kind()), "{input:?}: {err}");
+    }
+
+    let deep = "!".repeat(MAX_DEPTH - 1) + "@User.x";
+    assert!(deep.parse::<Expression>().is_ok());
+    assert_eq!(TooDeep, ("!".repeat(MAX_DEPTH) + "@User.x").parse::<Expression>().unwrap_err().kind());
+    assert_eq!(TooDeep, ("(".repeat(100_000)).parse::<Expression>().unwrap_err().kind());
+}
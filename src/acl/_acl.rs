@@ -2,7 +2,10 @@
 //! [`ACL`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-acl)
 //! related types and manipulation functions
 
-mod acl_builder;                        pub use acl_builder::*;
+#[cfg(alloc)] mod acl_builder;          #[cfg(alloc)] pub use acl_builder::*;
+#[cfg(alloc)] mod acl_builder_error;    #[cfg(alloc)] pub use acl_builder_error::*;
+#[cfg(alloc)] mod acl_canonical;
 mod acl_in_default_or_null_or_ref;      pub use acl_in_default_or_null_or_ref::*;
+mod acl_problem;                        pub use acl_problem::*;
 mod acl_ptr;                            pub use acl_ptr::*;
 mod acl_revision;                       pub use acl_revision::*;
@@ -1,101 +1,160 @@
 use crate::prelude::*;
 
 use winapi::shared::guiddef::GUID;
-use winapi::shared::minwindef::FALSE;
-use winapi::shared::winerror::*;
-use winapi::um::securitybaseapi::*;
-use winapi::um::winnt::ACL;
+use winapi::um::winnt::*;
+
+use alloc::vec::Vec;
+
+use core::fmt::{self, Debug, Formatter};
+
+
 
 /// `WORD` sizes etc. limit the size to `0xFFFF`.
 /// However, InitializeAcl will fail with `0xFFFF`, since the end isn't DWORD aligned.
-/// Instead, specify `0xFFFC` which *is* DWORD aligned.
+/// Instead, limit ourselves to `0xFFFC` which *is* DWORD aligned.
 macro_rules! max_acl_bytes { () => { 0xFFFC } }
 
 
 
+#[doc(alias = "InitializeAcl")]
+#[doc(alias = "AddAce")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/creating-or-modifying-an-acl)\]
+/// Owned, growable ACL
+///
+/// ACEs are serialized directly instead of calling `InitializeAcl` / `Add*Ace`, but the resulting bytes match what `advapi32.dll` would produce.
+/// This allows ACLs to be assembled (and tested) on any platform.
+/// The ACL is always exactly as large as the ACEs it contains, and may grow up to `0xFFFC` bytes.
+///
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Builder {
-    u: U,
-}
-
-#[repr(C)] union U {
-    bytes:  [u8; max_acl_bytes!()], // max acl is 64 KiB
-    acl:    ACL,
+    buffer: Vec<u32>, // ACL header + ACEs, exactly `AclSize` bytes.  ACEs are always a multiple of 4 bytes.
 }
 
+impl<'a> From<&'a     Builder> for acl::Ref<'a> { fn from(b: &'a     Builder) -> Self { b.as_acl() } }
 impl<'a> From<&'a mut Builder> for acl::Ref<'a> { fn from(b: &'a mut Builder) -> Self { b.as_acl() } }
+impl     From<acl::Ref<'_>>    for Builder      { fn from(acl: acl::Ref) -> Self { Self::from_acl(acl) } }
+
+impl Debug for Builder { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(&self.as_acl(), fmt) } }
 
 impl Builder {
     #[doc(alias = "InitializeAcl")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializeacl)\]
-    /// InitializeAcl
+    /// ≈ InitializeAcl
     ///
     pub fn new(revision: acl::Revision) -> Self {
-        let mut b = Self { u: U { bytes: [0; max_acl_bytes!()] } };
-        assert!(FALSE != unsafe { InitializeAcl(
-            &mut b.u.acl,
-            max_acl_bytes!(),
-            revision.into(),
-        )}, "GetLastError()=={:?}", firehazard::Error::get_last());
+        let header = [u8::from(revision), 0, size_of::<ACL>() as u8, 0, 0, 0, 0, 0]; // AclRevision, Sbz1, AclSize, AceCount, Sbz2
+        Self { buffer: bytemuck::cast_slice(&header[..]).to_vec() }
+    }
+
+    /// Copy an existing ACL (header and ACEs) into a new [`acl::Builder`].
+    /// Misaligned ACEs are zero padded to a `DWORD` boundary, and their `AceSize` grown to match.
+    /// Copying stops at the first ACE that is truncated or smaller than its header.
+    pub fn from_acl(acl: acl::Ref) -> Self {
+        let mut b = Self::new(unsafe { acl::Revision::from_unchecked(acl.acl_revision()) });
+        // Walk the raw bytes rather than `acl.aces()`, which requires every ACE to already be `DWORD` aligned.
+        let (bytes, mut pos, mut ace_count) = (acl.as_bytes(), size_of::<ACL>(), 0);
+        while ace_count < acl.ace_count() {
+            let Some(&[_, _, lo, hi]) = bytes.get(pos .. pos + 4) else { break };
+            let Some(ace) = bytes.get(pos .. pos + usize::from(u16::from_le_bytes([lo, hi]))).filter(|ace| ace.len() >= 4) else { break };
+            let offset = b.buffer.len();
+            b.buffer.extend(ace.chunks(4).map(|w| u32::from_ne_bytes(core::array::from_fn(|i| w.get(i).copied().unwrap_or(0)))));
+            let size = ((b.buffer.len() - offset) * 4) as u16; // AceSize includes any padding
+            bytemuck::cast_slice_mut::<u32, u8>(&mut b.buffer[offset .. offset + 1])[2..4].copy_from_slice(&size.to_le_bytes());
+            pos += ace.len();
+            ace_count += 1;
+        }
+        b.set_header(acl.acl_revision(), ace_count as u16);
         b
     }
 
-    fn as_winapi(&mut self) -> *mut ACL { unsafe { &mut self.u.acl } }
-    fn as_winapi_const(&self) -> *mut ACL { unsafe { &self.u.acl as *const _ as *mut _ } }
-    pub fn as_acl(&self) -> acl::Ref { unsafe { acl::Ref::from_raw_unchecked(NonNull::new_unchecked((&raw const self.u.acl).cast_mut())) } }
-    #[allow(dead_code)] pub(crate) fn as_bytes(&self) -> &[u8] { let len = usize::from32(self.as_acl().get_acl_size_information().AclBytesInUse); unsafe { &self.u.bytes[..len] } }
+    pub fn as_acl(&self) -> acl::Ref<'_> { unsafe { acl::Ref::from_raw_unchecked(NonNull::new_unchecked(self.buffer.as_ptr().cast_mut().cast())) } }
+
+    /// The entire ACL (header and ACEs.)
+    pub fn as_bytes(&self) -> &[u8] { bytemuck::cast_slice(&self.buffer[..]) }
 
+    fn set_header(&mut self, revision: u8, ace_count: u16) {
+        let size = self.as_bytes().len() as u16;
+        let header : &mut [u8] = &mut bytemuck::cast_slice_mut(&mut self.buffer[..])[..size_of::<ACL>()];
+        header[0] = revision;
+        header[2..4].copy_from_slice(&size.to_le_bytes());
+        header[4..6].copy_from_slice(&ace_count.to_le_bytes());
+    }
 
-    pub fn finish(&mut self) -> firehazard::Result<&mut Self> {
-        // TODO: eliminate the need for this by only temporarilly growing AclSize immediately before add_*_ace and then shrinking it afterwards?
-        let size = self.as_acl().get_acl_size_information().AclBytesInUse;
-        let size = u16::try_from(size).map_err(|_| ERROR_ALLOTTED_SPACE_EXCEEDED)?;
-        self.u.acl.AclSize = size;
+    /// Insert `aces` (`ace_count` complete, DWORD sized ACEs) before the ACE at `index`, or at the end if `index` is out of bounds.
+    /// The ACL revision is raised to `revision` if lower.
+    pub(crate) fn insert(&mut self, index: usize, revision: acl::Revision, aces: &[u8], ace_count: u16) -> Result<&mut Self, acl::BuilderError> {
+        debug_assert!(aces.len().is_multiple_of(4));
+        let acl = self.as_acl();
+        if acl.acl_size() + aces.len() > max_acl_bytes!() { return Err(acl::BuilderError::TooLarge) }
+        let offset = size_of::<ACL>() + acl.aces().take(index).map(|ace| usize::from(ace.header().size)).sum::<usize>();
+        let (revision, ace_count) = (acl.acl_revision().max(revision.into()), acl.ace_count() as u16 + ace_count);
+        self.buffer.splice(offset/4 .. offset/4, aces.chunks_exact(4).map(|w| u32::from_ne_bytes([w[0], w[1], w[2], w[3]])));
+        self.set_header(revision, ace_count);
         Ok(self)
     }
 
+    /// Serialize and append an ACE with a mask and SID (i.e. everything but compound ACEs.)
+    pub(crate) fn push(&mut self, revision: acl::Revision, ace: Ace) -> Result<&mut Self, acl::BuilderError> {
+        let sid = ace.sid.as_bytes();
+        if sid.is_empty() { return Err(acl::BuilderError::InvalidSid) }
+
+        let mut bytes = Vec::with_capacity(8 + 4 + 32 + sid.len() + ace.application_data.len() + 3);
+        bytes.extend_from_slice(&[ace.ty.as_u8(), ace.flags.as_u8(), 0, 0]); // size written below
+        bytes.extend_from_slice(&ace.mask.to_le_bytes());
+        let revision = if let Some([object_type, inherited_object_type]) = ace.object {
+            let object_flags = (ACE_OBJECT_TYPE_PRESENT * u32::from(object_type.is_some())) | (ACE_INHERITED_OBJECT_TYPE_PRESENT * u32::from(inherited_object_type.is_some()));
+            bytes.extend_from_slice(&object_flags.to_le_bytes());
+            for guid in [object_type, inherited_object_type].into_iter().flatten() {
+                bytes.extend_from_slice(&guid.Data1.to_le_bytes());
+                bytes.extend_from_slice(&guid.Data2.to_le_bytes());
+                bytes.extend_from_slice(&guid.Data3.to_le_bytes());
+                bytes.extend_from_slice(&guid.Data4);
+            }
+            revision.max(acl::REVISION_DS)
+        } else {
+            revision
+        };
+        bytes.extend_from_slice(sid);
+        bytes.extend_from_slice(ace.application_data);
+        while !bytes.len().is_multiple_of(4) { bytes.push(0); }
+
+        let size = u16::try_from(bytes.len()).map_err(|_| acl::BuilderError::TooLarge)?;
+        bytes[2..4].copy_from_slice(&size.to_le_bytes());
+        self.insert(usize::MAX, revision, &bytes, 1)
+    }
+
     // https://learn.microsoft.com/en-us/windows/win32/secauthz/authorization-functions
 
     #[doc(alias = "AddAccessAllowedAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessallowedace)\]
-    /// AddAccessAllowedAce
+    /// ≈ AddAccessAllowedAce
     ///
     pub fn add_access_allowed_ace<'acl, 'sid>(&'acl mut self,
         ace_revision:   acl::Revision,
         access_mask:    impl Into<access::Mask>,
         sid:            impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAccessAllowedAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            access_mask.into().into(),
-            sid.into().as_psid(),
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        self.add_access_allowed_ace_ex(ace_revision, ace::Flags::default(), access_mask, sid)
     }
 
     #[doc(alias = "AddAccessAllowedAceEx")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessallowedaceex)\]
-    /// AddAccessAllowedAceEx
+    /// ≈ AddAccessAllowedAceEx
     ///
     pub fn add_access_allowed_ace_ex<'acl, 'sid>(&'acl mut self,
         ace_revision:   acl::Revision,
         ace_flags:      ace::Flags,
         access_mask:    impl Into<access::Mask>,
         sid:            impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAccessAllowedAceEx(
-            self.as_winapi(),
-            ace_revision.into(),
-            ace_flags.into(),
-            access_mask.into().into(),
-            sid.into().as_psid(),
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        self.push(ace_revision, Ace::new(ace::Type::ACCESS_ALLOWED, ace_flags, access_mask.into().into(), sid.into()))
     }
 
     #[doc(alias = "AddAccessAllowedObjectAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessallowedobjectace)\]
-    /// AddAccessAllowedObjectAce
+    /// ≈ AddAccessAllowedObjectAce
+    ///
+    /// The ACL revision is raised to [`acl::REVISION_DS`] if lower.
     ///
     pub fn add_access_allowed_object_ace<'acl, 'sid>(&'acl mut self,
         ace_revision:               acl::Revision,
@@ -104,60 +163,77 @@ impl Builder {
         object_type_guid:           impl Into<Option<GUID>>,
         inherited_object_type_guid: impl Into<Option<GUID>>,
         sid:                        impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAccessAllowedObjectAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            ace_flags.into(),
-            access_mask.into().into(),
-            object_type_guid.into().as_mut().map_or(null_mut(), |g| g),
-            inherited_object_type_guid.into().as_mut().map_or(null_mut(), |g| g),
-            sid.into().as_psid()
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::ACCESS_ALLOWED_OBJECT, ace_flags, access_mask.into().into(), sid.into()).object(object_type_guid.into(), inherited_object_type_guid.into());
+        self.push(ace_revision, ace)
+    }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-access_allowed_callback_ace)\]
+    /// Add an ACCESS_ALLOWED_CALLBACK_ACE
+    ///
+    /// `application_data` (typically a conditional expression) is zero padded to a DWORD boundary.
+    ///
+    pub fn add_access_allowed_callback_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:       acl::Revision,
+        ace_flags:          ace::Flags,
+        access_mask:        impl Into<access::Mask>,
+        sid:                impl Into<sid::Ptr<'sid>>,
+        application_data:   &[u8],
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::ACCESS_ALLOWED_CALLBACK, ace_flags, access_mask.into().into(), sid.into()).application_data(application_data);
+        self.push(ace_revision, ace)
+    }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-access_allowed_callback_object_ace)\]
+    /// Add an ACCESS_ALLOWED_CALLBACK_OBJECT_ACE
+    ///
+    /// The ACL revision is raised to [`acl::REVISION_DS`] if lower.
+    /// `application_data` (typically a conditional expression) is zero padded to a DWORD boundary.
+    ///
+    #[allow(clippy::too_many_arguments)] // parameters mirror add_access_allowed_object_ace + application_data (or use push with an acl::Ace)
+    pub fn add_access_allowed_callback_object_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:               acl::Revision,
+        ace_flags:                  ace::Flags,
+        access_mask:                impl Into<access::Mask>,
+        object_type_guid:           impl Into<Option<GUID>>,
+        inherited_object_type_guid: impl Into<Option<GUID>>,
+        sid:                        impl Into<sid::Ptr<'sid>>,
+        application_data:           &[u8],
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::ACCESS_ALLOWED_CALLBACK_OBJECT, ace_flags, access_mask.into().into(), sid.into()).object(object_type_guid.into(), inherited_object_type_guid.into()).application_data(application_data);
+        self.push(ace_revision, ace)
     }
 
     #[doc(alias = "AddAccessDeniedAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessdeniedace)\]
-    /// AddAccessDeniedAce
+    /// ≈ AddAccessDeniedAce
     ///
     pub fn add_access_denied_ace<'acl, 'sid>(&'acl mut self,
         ace_revision:   acl::Revision,
         access_mask:    impl Into<access::Mask>,
         sid:            impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAccessDeniedAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            access_mask.into().into(),
-            sid.into().as_psid(),
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        self.add_access_denied_ace_ex(ace_revision, ace::Flags::default(), access_mask, sid)
     }
 
     #[doc(alias = "AddAccessDeniedAceEx")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessdeniedaceex)\]
-    /// AddAccessDeniedAceEx
+    /// ≈ AddAccessDeniedAceEx
     ///
     pub fn add_access_denied_ace_ex<'acl, 'sid>(&'acl mut self,
         ace_revision:   acl::Revision,
         ace_flags:      ace::Flags,
         access_mask:    impl Into<access::Mask>,
         sid:            impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAccessDeniedAceEx(
-            self.as_winapi(),
-            ace_revision.into(),
-            ace_flags.into(),
-            access_mask.into().into(),
-            sid.into().as_psid(),
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        self.push(ace_revision, Ace::new(ace::Type::ACCESS_DENIED, ace_flags, access_mask.into().into(), sid.into()))
     }
 
     #[doc(alias = "AddAccessDeniedObjectAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessdeniedobjectace)\]
-    /// AddAccessDeniedObjectAce
+    /// ≈ AddAccessDeniedObjectAce
+    ///
+    /// The ACL revision is raised to [`acl::REVISION_DS`] if lower.
     ///
     pub fn add_access_denied_object_ace<'acl, 'sid>(&'acl mut self,
         ace_revision:               acl::Revision,
@@ -166,44 +242,66 @@ impl Builder {
         object_type_guid:           impl Into<Option<GUID>>,
         inherited_object_type_guid: impl Into<Option<GUID>>,
         sid:                        impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAccessDeniedObjectAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            ace_flags.into(),
-            access_mask.into().into(),
-            object_type_guid.into().as_mut().map_or(null_mut(), |g| g),
-            inherited_object_type_guid.into().as_mut().map_or(null_mut(), |g| g),
-            sid.into().as_psid()
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::ACCESS_DENIED_OBJECT, ace_flags, access_mask.into().into(), sid.into()).object(object_type_guid.into(), inherited_object_type_guid.into());
+        self.push(ace_revision, ace)
+    }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-access_denied_callback_ace)\]
+    /// Add an ACCESS_DENIED_CALLBACK_ACE
+    ///
+    /// `application_data` (typically a conditional expression) is zero padded to a DWORD boundary.
+    ///
+    pub fn add_access_denied_callback_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:       acl::Revision,
+        ace_flags:          ace::Flags,
+        access_mask:        impl Into<access::Mask>,
+        sid:                impl Into<sid::Ptr<'sid>>,
+        application_data:   &[u8],
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::ACCESS_DENIED_CALLBACK, ace_flags, access_mask.into().into(), sid.into()).application_data(application_data);
+        self.push(ace_revision, ace)
     }
 
     #[doc(alias = "AddAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addace)\]
-    /// AddAce &mdash; add an entire Acl
+    /// ≈ AddAce &mdash; add an entire Acl
+    ///
+    /// The ACEs are inserted before `starting_ace_index`, or appended if `starting_ace_index` is past the end (e.g. `u32::MAX`.)
     ///
     pub fn add_acl(&mut self,
         ace_revision:           acl::Revision,
         starting_ace_index:     u32,
         acl:                    acl::Ref
-    ) -> firehazard::Result<&mut Self> {
-        let size : u16 = acl.aces().map(|a| a.header().size).sum();
-        let ptr = acl.aces().as_ptr().cast();
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            starting_ace_index,
-            ptr,
-            size.into(),
-        )})?;
-        Ok(self)
+    ) -> Result<&mut Self, acl::BuilderError> {
+        let aces = &acl.as_bytes()[size_of::<ACL>() .. size_of::<ACL>() + acl.aces().map(|a| usize::from(a.header().size)).sum::<usize>()];
+        self.insert(usize::from32(starting_ace_index), ace_revision, aces, acl.ace_count() as u16)
+    }
+
+    #[doc(alias = "AddAuditAccessAce")]
+    #[doc(alias = "AddAuditAccessAceEx")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addauditaccessaceex)\]
+    /// ≈ AddAuditAccessAceEx
+    ///
+    pub fn add_audit_access_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:               acl::Revision,
+        ace_flags:                  ace::Flags,
+        access_mask:                impl Into<access::Mask>,
+        sid:                        impl Into<sid::Ptr<'sid>>,
+        audit_success:              bool,
+        audit_failure:              bool,
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace_flags = ace_flags | audit_flags(audit_success, audit_failure);
+        self.push(ace_revision, Ace::new(ace::Type::SYSTEM_AUDIT, ace_flags, access_mask.into().into(), sid.into()))
     }
 
     #[doc(alias = "AddAuditAccessObjectAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addauditaccessobjectace)\]
-    /// AddAuditAccessObjectAce
+    /// ≈ AddAuditAccessObjectAce
+    ///
+    /// The ACL revision is raised to [`acl::REVISION_DS`] if lower.
     ///
+    #[allow(clippy::too_many_arguments)] // parameters mirror AddAuditAccessObjectAce (or use push with an acl::Ace)
     pub fn add_audit_access_object_ace<'acl, 'sid>(&'acl mut self,
         ace_revision:               acl::Revision,
         ace_flags:                  ace::Flags,
@@ -213,53 +311,101 @@ impl Builder {
         sid:                        impl Into<sid::Ptr<'sid>>,
         audit_success:              bool,
         audit_failure:              bool,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddAuditAccessObjectAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            ace_flags.into(),
-            access_mask.into().into(),
-            object_type_guid.into().as_mut().map_or(null_mut(), |g| g),
-            inherited_object_type_guid.into().as_mut().map_or(null_mut(), |g| g),
-            sid.into().as_psid(),
-            audit_success.into(),
-            audit_failure.into(),
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace_flags = ace_flags | audit_flags(audit_success, audit_failure);
+        let ace = Ace::new(ace::Type::SYSTEM_AUDIT_OBJECT, ace_flags, access_mask.into().into(), sid.into()).object(object_type_guid.into(), inherited_object_type_guid.into());
+        self.push(ace_revision, ace)
+    }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_audit_callback_ace)\]
+    /// Add a SYSTEM_AUDIT_CALLBACK_ACE
+    ///
+    /// `application_data` (typically a conditional expression) is zero padded to a DWORD boundary.
+    ///
+    pub fn add_audit_access_callback_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:       acl::Revision,
+        ace_flags:          ace::Flags,
+        access_mask:        impl Into<access::Mask>,
+        sid:                impl Into<sid::Ptr<'sid>>,
+        application_data:   &[u8],
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::SYSTEM_AUDIT_CALLBACK, ace_flags, access_mask.into().into(), sid.into()).application_data(application_data);
+        self.push(ace_revision, ace)
     }
 
     #[doc(alias="AddMandatoryAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addmandatoryace)\]
-    /// AddMandatoryAce
+    /// ≈ AddMandatoryAce
     ///
     pub fn add_mandatory_ace<'acl, 'sid>(&'acl mut self,
         ace_revision:       acl::Revision,
         ace_flags:          ace::Flags,
-        mandatory_policy:   u32, // TODO: strengthen type to limit to SYSTEM_MANDATORY_LABEL_*
+        mandatory_policy:   ace::MandatoryLabelPolicy,
         sid:                impl Into<sid::Ptr<'sid>>,
-    ) -> firehazard::Result<&'acl mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { AddMandatoryAce(
-            self.as_winapi(),
-            ace_revision.into(),
-            ace_flags.into(),
-            mandatory_policy.into(),
-            sid.into().as_psid(),
-        )})?;
-        Ok(self)
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        self.push(ace_revision, Ace::new(ace::Type::SYSTEM_MANDATORY_LABEL, ace_flags, mandatory_policy.into(), sid.into()))
     }
 
-    // TODO: https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addresourceattributeace
-    // TODO: https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addscopedpolicyidace
+    #[doc(alias = "AddResourceAttributeAce")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addresourceattributeace)\]
+    /// ≈ AddResourceAttributeAce
+    ///
+    /// `attribute` is an already serialized [`CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_relative_v1),
+    /// and is zero padded to a DWORD boundary.
+    /// See [`add_resource_attribute`](Self::add_resource_attribute) to serialize a typed [`claim::Attribute`] instead.
+    /// `AddResourceAttributeAce` requires `sid` to be Everyone (`S-1-1-0`).
+    ///
+    pub fn add_resource_attribute_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:   acl::Revision,
+        ace_flags:      ace::Flags,
+        access_mask:    impl Into<access::Mask>,
+        sid:            impl Into<sid::Ptr<'sid>>,
+        attribute:      &[u8],
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let ace = Ace::new(ace::Type::SYSTEM_RESOURCE_ATTRIBUTE, ace_flags, access_mask.into().into(), sid.into()).application_data(attribute);
+        self.push(ace_revision, ace)
+    }
+
+    #[doc(alias = "AddResourceAttributeAce")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addresourceattributeace)\]
+    /// ≈ AddResourceAttributeAce(..., 0, Everyone, attribute, ...)
+    ///
+    /// Serializes `attribute` as a `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1` (see [`claim::Attribute::to_relative_bytes`].)
+    ///
+    pub fn add_resource_attribute<'acl>(&'acl mut self,
+        ace_revision:   acl::Revision,
+        ace_flags:      ace::Flags,
+        attribute:      &claim::Attribute,
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        let attribute = attribute.to_relative_bytes().map_err(acl::BuilderError::InvalidClaim)?;
+        self.add_resource_attribute_ace(ace_revision, ace_flags, (), sid!(S-1-1-0), &attribute)
+    }
+
+    #[doc(alias = "AddScopedPolicyIDAce")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addscopedpolicyidace)\]
+    /// ≈ AddScopedPolicyIDAce
+    ///
+    pub fn add_scoped_policy_id_ace<'acl, 'sid>(&'acl mut self,
+        ace_revision:   acl::Revision,
+        ace_flags:      ace::Flags,
+        access_mask:    impl Into<access::Mask>,
+        sid:            impl Into<sid::Ptr<'sid>>,
+    ) -> Result<&'acl mut Self, acl::BuilderError> {
+        self.push(ace_revision, Ace::new(ace::Type::SYSTEM_SCOPED_POLICY_ID, ace_flags, access_mask.into().into(), sid.into()))
+    }
 
     #[doc(alias = "DeleteAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-deleteace)\]
-    /// DeleteAce
+    /// ≈ DeleteAce
     ///
     pub fn delete_ace(&mut self, ace_index: u32) -> firehazard::Result<&mut Self> {
-        firehazard::Error::get_last_if(FALSE == unsafe { DeleteAce(
-            self.as_winapi(),
-            ace_index,
-        )})?;
+        let acl = self.as_acl();
+        let (revision, ace_count) = (acl.acl_revision(), acl.ace_count() as u16);
+        let offset = size_of::<ACL>() + acl.aces().take(usize::from32(ace_index)).map(|ace| usize::from(ace.header().size)).sum::<usize>();
+        let ace = self.get_ace(ace_index)?;
+        let size = usize::from(ace.header().size);
+        self.buffer.drain(offset/4 .. (offset + size)/4);
+        self.set_header(revision, ace_count - 1);
         Ok(self)
     }
 
@@ -267,17 +413,131 @@ impl Builder {
 
     #[doc(alias = "GetAce")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getace)\]
-    /// GetAce
+    /// ≈ GetAce
     ///
-    pub fn get_ace(&self, ace_index: u32) -> firehazard::Result<ace::Ref> {
-        let mut ace = null_mut();
-        firehazard::Error::get_last_if(FALSE == unsafe { GetAce(
-            self.as_winapi_const(),
-            ace_index,
-            &mut ace,
-        )})?;
-        unsafe { ace::Ref::from_raw(ace.cast()) }.ok_or(Error(ERROR_INVALID_HANDLE))
+    pub fn get_ace(&self, ace_index: u32) -> firehazard::Result<ace::Ref<'_>> {
+        self.as_acl().aces().nth(usize::from32(ace_index)).ok_or(Error(ERROR_INVALID_PARAMETER))
     }
 
     // https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setaclinformation
 }
+
+
+
+/// The parts of an ACE with an access mask and SID, serialized by [`Builder::push`]
+pub(crate) struct Ace<'a> {
+    ty:                 ace::Type,
+    flags:              ace::Flags,
+    mask:               u32,
+    object:             Option<[Option<GUID>; 2]>,
+    sid:                sid::Ptr<'a>,
+    application_data:   &'a [u8],
+}
+
+impl<'a> Ace<'a> {
+    pub(crate) fn new(ty: ace::Type, flags: ace::Flags, mask: u32, sid: sid::Ptr<'a>) -> Self { Self { ty, flags, mask, object: None, sid, application_data: &[] } }
+    pub(crate) fn object(self, object_type: Option<GUID>, inherited_object_type: Option<GUID>) -> Self { Self { object: Some([object_type, inherited_object_type]), ..self } }
+    pub(crate) fn application_data(self, application_data: &'a [u8]) -> Self { Self { application_data, ..self } }
+}
+
+fn audit_flags(success: bool, failure: bool) -> ace::Flags {
+    let mut flags = ace::Flags::default();
+    if success { flags |= ace::SUCCESSFUL_ACCESS_ACE_FLAG; }
+    if failure { flags |= ace::FAILED_ACCESS_ACE_FLAG; }
+    flags
+}
+
+
+
+#[cfg(std)] #[test] fn golden_bytes() {
+    let guid = GUID { Data1: 0xbf967aba, Data2: 0x0de6, Data3: 0x11d0, Data4: [0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2] };
+
+    let mut acl = Builder::new(acl::REVISION);
+    assert_eq!(acl.as_bytes(), &[2, 0, 8, 0, 0, 0, 0, 0]);
+    acl.add_access_denied_ace(acl::REVISION, access::GENERIC_WRITE, sid!(S-1-5-7)).unwrap();
+    acl.add_access_allowed_ace_ex(acl::REVISION, ace::OBJECT_INHERIT_ACE | ace::CONTAINER_INHERIT_ACE, access::GENERIC_ALL, sid!(S-1-5-32-544)).unwrap();
+    acl.add_mandatory_ace(acl::REVISION, ace::Flags::default(), ace::MandatoryLabelPolicy::NO_WRITE_UP, sid!(S-1-16-4096)).unwrap();
+    assert_eq!(acl.as_bytes(), &[
+        0x02, 0x00, 0x48, 0x00,  0x03, 0x00, 0x00, 0x00,                                        // ACL { ACL_REVISION, sbz1, size, count, sbz2 }
+        0x01, 0x00, 0x14, 0x00,  0x00, 0x00, 0x00, 0x40,  1, 1, 0, 0, 0, 0, 0, 5,  7, 0, 0, 0,  // ACCESS_DENIED_ACE { ..., GENERIC_WRITE, S-1-5-7 }
+        0x00, 0x03, 0x18, 0x00,  0x00, 0x00, 0x00, 0x10,  1, 2, 0, 0, 0, 0, 0, 5,  32, 0, 0, 0,  0x20, 0x02, 0, 0, // ACCESS_ALLOWED_ACE { OI|CI, GENERIC_ALL, S-1-5-32-544 }
+        0x11, 0x00, 0x14, 0x00,  0x01, 0x00, 0x00, 0x00,  1, 1, 0, 0, 0, 0, 0, 16, 0, 0x10, 0, 0, // SYSTEM_MANDATORY_LABEL_ACE { ..., NO_WRITE_UP, S-1-16-4096 }
+    ][..]);
+
+    let mut acl = Builder::new(acl::REVISION);
+    acl.add_audit_access_object_ace(acl::REVISION, ace::Flags::default(), access::READ_CONTROL, None, guid, sid!(S-1-1-0), true, false).unwrap();
+    acl.add_access_allowed_callback_ace(acl::REVISION, ace::Flags::default(), access::READ_CONTROL, sid!(S-1-1-0), b"artx\x01").unwrap();
+    assert_eq!(acl.as_bytes(), &[
+        0x04, 0x00, 0x4C, 0x00,  0x02, 0x00, 0x00, 0x00,                                        // ACL { ACL_REVISION_DS, sbz1, size, count, sbz2 }
+        0x07, 0x40, 0x28, 0x00,  0x00, 0x00, 0x02, 0x00,  0x02, 0x00, 0x00, 0x00,               // SYSTEM_AUDIT_OBJECT_ACE { SUCCESSFUL_ACCESS, READ_CONTROL, ACE_INHERITED_OBJECT_TYPE_PRESENT }
+        0xba, 0x7a, 0x96, 0xbf,  0xe6, 0x0d,  0xd0, 0x11,  0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2,
+        1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0,                                                    // S-1-1-0
+        0x09, 0x00, 0x1C, 0x00,  0x00, 0x00, 0x02, 0x00,  1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0,  // ACCESS_ALLOWED_CALLBACK_ACE { ..., READ_CONTROL, S-1-1-0, ... }
+        b'a', b'r', b't', b'x',  0x01, 0, 0, 0,                                                 // ... "artx\x01" + padding
+    ][..]);
+    assert_eq!(b"artx\x01\0\0\0", acl.get_ace(1).unwrap().application_data());
+
+    let mut copy = Builder::new(acl::REVISION);
+    copy.add_access_allowed_ace(acl::REVISION, access::GENERIC_READ, sid!(S-1-5-18)).unwrap();
+    copy.add_acl(acl::REVISION_DS, 0, acl.as_acl()).unwrap();
+    assert_eq!(u8::from(acl::REVISION_DS), copy.as_acl().acl_revision());
+    assert_eq!(&copy.as_bytes()[8 .. 0x4C], &acl.as_bytes()[8..]);
+    copy.delete_ace(0).unwrap();
+    assert_eq!(copy.as_acl().aces().map(|ace| ace.header().ty).collect::<Vec<_>>(), [ace::Type::ACCESS_ALLOWED_CALLBACK, ace::Type::ACCESS_ALLOWED]);
+    assert_eq!(0x08 + 0x1C + 0x14, copy.as_acl().acl_size());
+    assert_eq!(Err(Error(ERROR_INVALID_PARAMETER)), copy.delete_ace(2).map(|_| ()));
+    assert_eq!(copy, Builder::from_acl(copy.as_acl()));
+
+    #[repr(C, align(4))] struct Aligned<const N: usize>([u8; N]);
+    let misaligned = Aligned([2, 0, 14, 0, 1, 0, 0, 0,  0x42, 0, 6, 0, 0xAA, 0xBB]);
+    let misaligned = unsafe { acl::Ref::from_raw_unchecked(NonNull::new_unchecked(misaligned.0.as_ptr().cast_mut().cast())) };
+    assert_eq!(Builder::from_acl(misaligned).as_bytes(), &[2, 0, 16, 0, 1, 0, 0, 0,  0x42, 0, 8, 0, 0xAA, 0xBB, 0, 0][..]); // padded, AceSize updated
+}
+
+#[cfg(std)] #[test] fn too_large() {
+    let sid = sid!(S-1-5-21-1-2-3-4-5-6-7-8-9-10-11-12-13-14); // 68 byte SID → 76 byte ACE
+    let mut acl = Builder::new(acl::REVISION);
+    for _ in 0 .. (max_acl_bytes!() - size_of::<ACL>()) / 76 { acl.add_access_allowed_ace(acl::REVISION, access::GENERIC_ALL, sid).unwrap(); }
+    let before = acl.clone();
+    assert_eq!(Err(acl::BuilderError::TooLarge), acl.add_access_allowed_ace(acl::REVISION, access::GENERIC_ALL, sid).map(|_| ()));
+    assert_eq!(before, acl);
+    assert!(acl.as_bytes().len() <= max_acl_bytes!());
+
+    let null = unsafe { sid::Ptr::from_raw_unchecked(core::ptr::null_mut()) };
+    assert_eq!(Err(acl::BuilderError::InvalidSid), Builder::new(acl::REVISION).add_access_allowed_ace(acl::REVISION, access::GENERIC_ALL, null).map(|_| ()));
+}
+
+#[cfg(all(std, windows))] #[test] fn matches_advapi32() {
+    use winapi::um::securitybaseapi::*;
+
+    #[repr(C, align(4))] struct Buffer([u8; 1024]);
+    let mut buffer = Buffer([0; 1024]);
+    let pacl : *mut ACL = buffer.0.as_mut_ptr().cast();
+    let mut guid = GUID { Data1: 0xbf967aba, Data2: 0x0de6, Data3: 0x11d0, Data4: [0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2] };
+    let (everyone, admins, low) = (sid!(S-1-1-0), sid!(S-1-5-32-544), sid!(S-1-16-4096));
+    let oici = ace::OBJECT_INHERIT_ACE | ace::CONTAINER_INHERIT_ACE;
+
+    unsafe {
+        assert_ne!(0, InitializeAcl(pacl, 1024, ACL_REVISION.into()));
+        assert_ne!(0, AddAccessDeniedAce(pacl, ACL_REVISION.into(), GENERIC_WRITE, everyone.as_psid()));
+        assert_ne!(0, AddAccessAllowedAceEx(pacl, ACL_REVISION.into(), oici.into(), GENERIC_ALL, admins.as_psid()));
+        assert_ne!(0, AddAccessAllowedObjectAce(pacl, ACL_REVISION_DS.into(), 0, READ_CONTROL, &mut guid, null_mut(), everyone.as_psid()));
+        assert_ne!(0, AddAuditAccessAceEx(pacl, ACL_REVISION_DS.into(), 0, READ_CONTROL, everyone.as_psid(), 1, 1));
+        assert_ne!(0, AddMandatoryAce(pacl, ACL_REVISION.into(), 0, SYSTEM_MANDATORY_LABEL_NO_WRITE_UP, low.as_psid()));
+    }
+
+    let mut acl = Builder::new(acl::REVISION);
+    acl.add_access_denied_ace(acl::REVISION, access::GENERIC_WRITE, everyone).unwrap();
+    acl.add_access_allowed_ace_ex(acl::REVISION, oici, access::GENERIC_ALL, admins).unwrap();
+    acl.add_access_allowed_object_ace(acl::REVISION_DS, ace::Flags::default(), access::READ_CONTROL, guid, None, everyone).unwrap();
+    acl.add_audit_access_ace(acl::REVISION_DS, ace::Flags::default(), access::READ_CONTROL, everyone, true, true).unwrap();
+    acl.add_mandatory_ace(acl::REVISION, ace::Flags::default(), ace::MandatoryLabelPolicy::NO_WRITE_UP, low).unwrap();
+
+    let size = usize::from(unsafe { (*pacl).AclSize });
+    let advapi32 = unsafe { acl::Ref::from_raw_unchecked(NonNull::new_unchecked(pacl)) };
+    let used = size_of::<ACL>() + advapi32.aces().map(|a| usize::from(a.header().size)).sum::<usize>();
+    assert_eq!(1024, size); // InitializeAcl doesn't shrink
+    assert_eq!(&acl.as_bytes()[8..], &buffer.0[8..used]);
+    assert_eq!(&acl.as_bytes()[..2], &buffer.0[..2]); // AclRevision, Sbz1
+    assert_eq!(&acl.as_bytes()[4..8], &buffer.0[4..8]); // AceCount, Sbz2
+}
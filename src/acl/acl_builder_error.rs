@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// Error adding ACEs to an [`acl::Builder`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum BuilderError {
+    /// The ACL would exceed the `0xFFFC` byte limit of an ACL.
+    TooLarge,
+    /// The SID was null.
+    InvalidSid,
+    /// The claim attribute of a resource attribute ACE couldn't be serialized.
+    InvalidClaim(claim::AttributeError),
+}
+
+impl From<BuilderError> for firehazard::Error {
+    fn from(err: BuilderError) -> Self {
+        match err {
+            BuilderError::TooLarge          => Self(ERROR_ALLOTTED_SPACE_EXCEEDED),
+            BuilderError::InvalidSid        => Self(ERROR_INVALID_SID),
+            BuilderError::InvalidClaim(err) => err.into(),
+        }
+    }
+}
+
+#[cfg(std)] impl From<BuilderError> for std::io::Error {
+    fn from(err: BuilderError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidInput, err) }
+}
+
+impl core::error::Error for BuilderError {}
+
+impl Display for BuilderError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            BuilderError::TooLarge          => write!(fmt, "ACL exceeds 0xFFFC bytes"),
+            BuilderError::InvalidSid        => write!(fmt, "null SID"),
+            BuilderError::InvalidClaim(err) => write!(fmt, "invalid resource attribute: {err}"),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::prelude::*;
+use acl::{Problem, ProblemKind};
+
+use winapi::um::winnt::*;
+
+use alloc::vec::Vec;
+
+
+
+impl acl::Ref<'_> {
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/order-of-aces-in-a-dacl)\]
+    /// Check the ACL for truncated, unknown, misordered, redundant, or overlapping ACEs.
+    ///
+    /// Canonical order is explicit deny ACEs, then explicit allow (and other) ACEs, then inherited ACEs.
+    /// All problems are reported, in ACE order.
+    ///
+    pub fn validate(self) -> Result<(), Vec<Problem>> {
+        let (aces, structural) = walk(self);
+        let mut problems = Vec::new();
+        let mut max_rank = 0;
+
+        for (index, &ace) in aces.iter().enumerate() {
+            let header = ace.header();
+            if header.ty.long_type().is_none() { problems.push(Problem::new(index, ProblemKind::UnknownType(header.ty))); continue }
+            let Some(sid) = ace.sid() else { problems.push(Problem::new(index, ProblemKind::Truncated)); continue };
+            if !ace.application_data().is_empty() && !has_application_data(header.ty) { problems.push(Problem::new(index, ProblemKind::TrailingBytes)); }
+
+            let rank = rank(ace);
+            if rank < max_rank { problems.push(Problem::new(index, ProblemKind::Misordered)); }
+            max_rank = max_rank.max(rank);
+
+            let Some(mask) = ace.mask().map(u32::from) else { continue };
+            for (prev_index, &prev) in aces[..index].iter().enumerate() {
+                let Some(prev_mask) = prev.mask().map(u32::from) else { continue };
+                if mask & prev_mask == 0 { continue }
+                if same_key(prev, ace) && mask & !prev_mask == 0 {
+                    problems.push(Problem::new(index, ProblemKind::Redundant { covered_by: prev_index }));
+                    break;
+                }
+                let comparable = class(prev) != Class::Other && class(ace) != Class::Other
+                    && prev.header().flags == header.flags
+                    && prev.sid().is_some_and(|prev_sid| *prev_sid == *sid)
+                    && object_types(prev) == object_types(ace);
+                if comparable {
+                    problems.push(Problem::new(index, ProblemKind::Overlapping { with: prev_index }));
+                    break;
+                }
+            }
+        }
+
+        problems.extend(structural);
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
+    }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/order-of-aces-in-a-dacl)\]
+    /// Rewrite the ACL into canonical order, merging the access masks of otherwise identical ACEs.
+    ///
+    /// *   ACEs are stably sorted into explicit deny, explicit allow (and other), then inherited ACEs.
+    /// *   An ACE is merged into an earlier one with the same type, flags, SID, object types, and application data,
+    ///     unless an ACE of the opposite (allow vs deny) kind lies between them, which would change the meaning of the ACL.
+    /// *   Unknown ACE types are preserved as-is.
+    ///
+    /// Fails if an ACE is [truncated](ProblemKind::Truncated) or [misaligned](ProblemKind::Misaligned).
+    ///
+    pub fn canonicalize(self) -> Result<acl::Builder, Problem> {
+        let (aces, structural) = walk(self);
+        if let Some(problem) = structural { return Err(problem) }
+        if let Some(index) = aces.iter().position(|ace| ace.header().ty.long_type().is_some() && ace.sid().is_none()) {
+            return Err(Problem::new(index, ProblemKind::Truncated));
+        }
+
+        let mut sorted = aces;
+        sorted.sort_by_key(|&ace| rank(ace)); // stable
+
+        let mut merged : Vec<(ace::Ref, u32)> = Vec::with_capacity(sorted.len());
+        'aces: for ace in sorted {
+            if let Some(mask) = ace.mask().map(u32::from) {
+                for (prev, prev_mask) in merged.iter_mut().rev() {
+                    if same_key(*prev, ace) { *prev_mask |= mask; continue 'aces }
+                    if class(*prev).opposes(class(ace)) { break }
+                }
+            }
+            merged.push((ace, ace.mask().map_or(0, u32::from)));
+        }
+
+        let mut acl = acl::Builder::new(unsafe { acl::Revision::from_unchecked(self.acl_revision()) });
+        let mut bytes = Vec::new();
+        for (ace, mask) in merged {
+            bytes.clear();
+            bytes.extend_from_slice(ace.as_bytes());
+            if ace.mask().is_some() { bytes[4..8].copy_from_slice(&mask.to_le_bytes()); }
+            acl.insert(usize::MAX, acl::REVISION, &bytes, 1).expect("merging ACEs never grows the ACL");
+        }
+        Ok(acl)
+    }
+}
+
+
+
+/// Bounds checked walk of `acl`'s ACEs, stopping at the first ACE that can't be safely located.
+fn walk(acl: acl::Ref) -> (Vec<ace::Ref>, Option<Problem>) {
+    let bytes = acl.as_bytes();
+    let mut aces = Vec::with_capacity(acl.ace_count());
+    let mut offset = size_of::<ACL>();
+    for index in 0 .. acl.ace_count() {
+        let Some(header) = bytes.get(offset .. offset + size_of::<ace::Header>()) else { return (aces, Some(Problem::new(index, ProblemKind::Truncated))) };
+        let size = usize::from(u16::from_le_bytes([header[2], header[3]]));
+        if size < size_of::<ace::Header>() || offset + size > bytes.len() { return (aces, Some(Problem::new(index, ProblemKind::Truncated))) }
+        if !size.is_multiple_of(4) { return (aces, Some(Problem::new(index, ProblemKind::Misaligned))) }
+        aces.push(unsafe { ace::Ref::from_raw_unchecked(NonNull::from(&bytes[offset]).cast()) });
+        offset += size;
+    }
+    (aces, None)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)] enum Class { Deny, Allow, Other }
+
+impl Class {
+    fn opposes(self, other: Self) -> bool { matches!((self, other), (Class::Deny, Class::Allow) | (Class::Allow, Class::Deny)) }
+}
+
+fn class(ace: ace::Ref) -> Class {
+    match ace.header().ty.as_u8() {
+        ACCESS_DENIED_ACE_TYPE  | ACCESS_DENIED_OBJECT_ACE_TYPE  | ACCESS_DENIED_CALLBACK_ACE_TYPE  | ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE  => Class::Deny,
+        ACCESS_ALLOWED_ACE_TYPE | ACCESS_ALLOWED_OBJECT_ACE_TYPE | ACCESS_ALLOWED_CALLBACK_ACE_TYPE | ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE => Class::Allow,
+        _ => Class::Other,
+    }
+}
+
+/// 0: explicit deny, 1: explicit allow (or other), 2: inherited
+fn rank(ace: ace::Ref) -> u8 {
+    if ace.header().flags & ace::INHERITED_ACE != ace::Flags::default() { return 2 }
+    if class(ace) == Class::Deny { 0 } else { 1 }
+}
+
+fn has_application_data(ty: ace::Type) -> bool {
+    matches!(ty.as_u8(),
+        ACCESS_ALLOWED_CALLBACK_ACE_TYPE | ACCESS_DENIED_CALLBACK_ACE_TYPE | SYSTEM_AUDIT_CALLBACK_ACE_TYPE | SYSTEM_ALARM_CALLBACK_ACE_TYPE |
+        ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE | ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE | SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE | SYSTEM_ALARM_CALLBACK_OBJECT_ACE_TYPE |
+        SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE | SYSTEM_ACCESS_FILTER_ACE_TYPE
+    )
+}
+
+/// `[ObjectType, InheritedObjectType]` (`GUID` doesn't implement [`PartialEq`])
+fn object_types(ace: ace::Ref) -> [Option<u128>; 2] {
+    [ace.object_type(), ace.inherited_object_type()].map(|guid| guid.map(|g| u128::from(g.Data1) << 96 | u128::from(g.Data2) << 80 | u128::from(g.Data3) << 64 | u128::from(u64::from_be_bytes(g.Data4))))
+}
+
+/// Identical except for the access mask
+fn same_key(a: ace::Ref, b: ace::Ref) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    a.len() >= 8 && a.len() == b.len() && a[..4] == b[..4] && a[8..] == b[8..]
+}
+
+
+
+#[cfg(std)] #[test] fn validate_and_canonicalize() {
+    let (admins, users, everyone) = (sid!(S-1-5-32-544), sid!(S-1-5-32-545), sid!(S-1-1-0));
+    let inherited = ace::INHERITED_ACE;
+
+    let mut acl = acl::Builder::new(acl::REVISION);
+    acl.add_access_allowed_ace(acl::REVISION, access::GENERIC_READ, users).unwrap();                   // 0
+    acl.add_access_allowed_ace_ex(acl::REVISION, inherited, access::GENERIC_ALL, admins).unwrap();     // 1
+    acl.add_access_denied_ace(acl::REVISION, access::WRITE_DAC, everyone).unwrap();                     // 2: misordered
+    acl.add_access_allowed_ace(acl::REVISION, access::GENERIC_READ, users).unwrap();                   // 3: misordered, redundant with 0
+    acl.add_access_allowed_ace(acl::REVISION, access::GENERIC_READ | access::GENERIC_WRITE, users).unwrap(); // 4: misordered, overlaps 0
+    acl.add_access_denied_ace(acl::REVISION, access::WRITE_OWNER, everyone).unwrap();                   // 5: misordered
+
+    assert_eq!(Err(std::vec![
+        Problem::new(2, ProblemKind::Misordered),
+        Problem::new(3, ProblemKind::Misordered),
+        Problem::new(3, ProblemKind::Redundant { covered_by: 0 }),
+        Problem::new(4, ProblemKind::Misordered),
+        Problem::new(4, ProblemKind::Overlapping { with: 0 }),
+        Problem::new(5, ProblemKind::Misordered),
+    ]), acl.as_acl().validate());
+
+    let canonical = acl.as_acl().canonicalize().unwrap();
+    assert_eq!(Ok(()), canonical.as_acl().validate());
+
+    let mut expected = acl::Builder::new(acl::REVISION);
+    expected.add_access_denied_ace(acl::REVISION, access::WRITE_DAC | access::WRITE_OWNER, everyone).unwrap();
+    expected.add_access_allowed_ace(acl::REVISION, access::GENERIC_READ | access::GENERIC_WRITE, users).unwrap();
+    expected.add_access_allowed_ace_ex(acl::REVISION, inherited, access::GENERIC_ALL, admins).unwrap();
+    assert_eq!(expected, canonical);
+    assert_eq!(canonical, canonical.as_acl().canonicalize().unwrap());
+
+    // Inherited ACEs must not be merged across an ACE of the opposite kind
+    let mut acl = acl::Builder::new(acl::REVISION);
+    acl.add_access_allowed_ace_ex(acl::REVISION, inherited, access::GENERIC_READ, users).unwrap();
+    acl.add_access_denied_ace_ex(acl::REVISION, inherited, access::GENERIC_WRITE, everyone).unwrap();
+    acl.add_access_allowed_ace_ex(acl::REVISION, inherited, access::GENERIC_WRITE, users).unwrap();
+    assert_eq!(acl, acl.as_acl().canonicalize().unwrap());
+}
+
+#[cfg(std)] #[test] fn validate_malformed() {
+    #[repr(C, align(4))] struct Aligned<const N: usize>([u8; N]);
+    let acl = |bytes: &[u8]| unsafe { acl::Ref::from_raw_unchecked(NonNull::new_unchecked(bytes.as_ptr().cast_mut().cast())) };
+    let problems = |bytes: &[u8]| acl(bytes).validate().unwrap_err();
+
+    let truncated = Aligned([2, 0, 24, 0, 1, 0, 0, 0,  0, 0, 20, 0,  0, 0, 0, 0x10,  1, 1, 0, 0, 0, 0, 0, 1]); // AceSize runs past AclSize
+    assert_eq!(std::vec![Problem::new(0, ProblemKind::Truncated)], problems(&truncated.0));
+    assert_eq!(Err(Problem::new(0, ProblemKind::Truncated)), acl(&truncated.0).canonicalize());
+
+    let short_sid = Aligned([2, 0, 24, 0, 1, 0, 0, 0,  0, 0, 16, 0,  0, 0, 0, 0x10,  1, 1, 0, 0, 0, 0, 0, 1]); // SID needs 4 more bytes than AceSize
+    assert_eq!(std::vec![Problem::new(0, ProblemKind::Truncated)], problems(&short_sid.0));
+
+    let misaligned = Aligned([2, 0, 32, 0, 2, 0, 0, 0,  0x42, 0, 6, 0, 0, 0,  0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(std::vec![Problem::new(0, ProblemKind::Misaligned)], problems(&misaligned.0));
+
+    let unknown_and_trailing = Aligned([2, 0, 40, 0, 2, 0, 0, 0,  0x42, 0, 8, 0, 0, 0, 0, 0,  0, 0, 24, 0,  0, 0, 0, 0x10,  1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0,  0xAA, 0xBB, 0xCC, 0xDD]);
+    assert_eq!(std::vec![
+        Problem::new(0, ProblemKind::UnknownType(unsafe { ace::Type::from_unchecked(0x42) })),
+        Problem::new(1, ProblemKind::TrailingBytes),
+    ], problems(&unknown_and_trailing.0));
+    assert_eq!(acl(&unknown_and_trailing.0).as_bytes(), acl(&unknown_and_trailing.0).canonicalize().unwrap().as_bytes()); // unknown ACEs are preserved
+}
@@ -20,7 +20,7 @@ impl core::fmt::Debug for Default { fn fmt(&self, fmt: &mut core::fmt::Formatter
 
 impl core::fmt::Debug for Null { fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result { write!(fmt, "acl::Null") } }
 
-impl From<Null> for firehazard::Error { fn from(_: Null) -> Self { ERROR::INVALID_ACL.into() } }
+impl From<Null> for firehazard::Error { fn from(_: Null) -> Self { Error(ERROR_INVALID_ACL) } }
 
 
 
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// A problem with an ACL, as reported by [`acl::Ref::validate`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Problem {
+    /// Index of the offending ACE.
+    pub index:  usize,
+    pub kind:   ProblemKind,
+}
+
+impl Problem {
+    #[cfg(alloc)] pub(crate) fn new(index: usize, kind: ProblemKind) -> Self { Self { index, kind } }
+}
+
+/// What's wrong with an ACE, as reported by [`acl::Ref::validate`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum ProblemKind {
+    /// The ACE extends past the end of the ACL, or is too small to contain its header, access mask, or SID.
+    /// ACEs after this one cannot be located.
+    Truncated,
+    /// The ACE's size isn't a multiple of 4.
+    /// ACEs after this one cannot be located.
+    Misaligned,
+    /// The ACE has bytes after its SID, but isn't a callback, resource attribute, or access filter ACE.
+    TrailingBytes,
+    /// The ACE type isn't one this crate knows how to interpret.
+    UnknownType(ace::Type),
+    /// The ACE isn't in canonical order (explicit deny, explicit allow, then inherited ACEs.)
+    Misordered,
+    /// The ACE's access mask is entirely covered by an earlier, otherwise identical ACE.
+    Redundant { covered_by: usize },
+    /// The ACE's access mask overlaps that of an earlier ACE for the same SID, inheritance flags, and object types.
+    Overlapping { with: usize },
+}
+
+impl From<Problem> for firehazard::Error {
+    fn from(_: Problem) -> Self { Self(ERROR_INVALID_ACL) }
+}
+
+impl core::error::Error for Problem {}
+
+impl Display for Problem {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let index = self.index;
+        match self.kind {
+            ProblemKind::Truncated                  => write!(fmt, "ACE {index} is truncated"),
+            ProblemKind::Misaligned                 => write!(fmt, "ACE {index} size isn't a multiple of 4"),
+            ProblemKind::TrailingBytes              => write!(fmt, "ACE {index} has unexpected bytes after its SID"),
+            ProblemKind::UnknownType(ty)            => write!(fmt, "ACE {index} has unknown type 0x{:02X}", ty.as_u8()),
+            ProblemKind::Misordered                 => write!(fmt, "ACE {index} isn't in canonical order"),
+            ProblemKind::Redundant { covered_by }   => write!(fmt, "ACE {index} is redundant with ACE {covered_by}"),
+            ProblemKind::Overlapping { with }       => write!(fmt, "ACE {index} overlaps ACE {with}"),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
-use winapi::shared::winerror::*;
-use winapi::um::securitybaseapi::GetAclInformation;
+#[cfg(windows)] use winapi::shared::winerror::*;
+#[cfg(windows)] use winapi::um::securitybaseapi::GetAclInformation;
 use winapi::um::winnt::*;
 
 use core::fmt::{self, Debug, Formatter};
@@ -37,6 +37,9 @@ impl<'a> Ref<'a> {
     pub fn ace_count(self) -> usize { unsafe { self.0.as_ref().AceCount as _ } }
     // Sbz2: padding
 
+    /// The entire ACL (header and ACEs) as `acl_size()` bytes.
+    pub fn as_bytes(self) -> &'a [u8] { unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), self.acl_size()) } }
+
     pub fn aces(self) -> ace::Iter<'a> { ace::Iter::new(self) }
 
     pub fn as_pacl(self) -> NonNull<ACL> { self.0 }
@@ -46,7 +49,7 @@ impl<'a> Ref<'a> {
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getaclinformation)\]
     /// GetAclInformation(..., AclRevisionInformation)
     ///
-    #[allow(dead_code)]
+    #[cfg(windows)] #[allow(dead_code)]
     pub(crate) fn get_acl_revision_information(self) -> ACL_REVISION_INFORMATION { unsafe { self.get_acl_information(AclRevisionInformation) } }
 
     #[doc(alias = "GetAclInformation")]
@@ -54,7 +57,7 @@ impl<'a> Ref<'a> {
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getaclinformation)\]
     /// GetAclInformation(..., AclRevisionInformation)
     ///
-    #[allow(dead_code)]
+    #[cfg(windows)] #[allow(dead_code)]
     pub(crate) fn get_acl_revision(self) -> acl::Revision { unsafe { acl::Revision::from_unchecked(self.get_acl_revision_information().AclRevision as _) } }
 
     #[doc(alias = "GetAclInformation")]
@@ -62,6 +65,7 @@ impl<'a> Ref<'a> {
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getaclinformation)\]
     /// GetAclInformation(..., AclSizeInformation)
     ///
+    #[cfg(windows)] #[allow(dead_code)]
     pub(crate) fn get_acl_size_information(self) -> ACL_SIZE_INFORMATION { unsafe { self.get_acl_information(AclSizeInformation) } }
 
     #[doc(alias = "GetAclInformation")]
@@ -70,7 +74,7 @@ impl<'a> Ref<'a> {
     ///
     /// ### Safety
     /// *   `class` must be valid and must match `T`
-    unsafe fn get_acl_information<T: Default>(self, class: u32) -> T {
+    #[cfg(windows)] unsafe fn get_acl_information<T: Default>(self, class: u32) -> T {
         let mut info : T = Default::default();
         let success = 0 != unsafe { GetAclInformation(self.0.as_ptr(), &mut info as *mut _ as *mut _, core::mem::size_of::<T>() as _, class) };
         assert!(success, "GetAclInformation failed with {:?}", firehazard::Error::get_last());
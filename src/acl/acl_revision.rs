@@ -1,4 +1,4 @@
-use winapi::um::winnt::{ACL_REVISION, ACL_REVISION_DS};
+use crate::winapi::um::winnt::{ACL_REVISION, ACL_REVISION_DS};
 
 use core::fmt::{self, Debug, Formatter};
 
@@ -2,13 +2,13 @@
 
 #[cfg(alloc)] pub(crate) use ::alloc_::*;
 
-mod allocator;                  pub use allocator::*;
-mod cbox;                       pub use cbox::*;
-mod cboxsized;                  pub use cboxsized::*;
-mod cstring;                    pub use cstring::*;
+#[cfg(windows)] mod allocator;  #[cfg(windows)] pub use allocator::*;
+#[cfg(windows)] mod cbox;       #[cfg(windows)] pub use cbox::*;
+#[cfg(windows)] mod cboxsized;  #[cfg(windows)] pub use cboxsized::*;
+#[cfg(windows)] mod cstring;    #[cfg(windows)] pub use cstring::*;
 
-pub use funcs::*;
-pub(crate) mod funcs {
+#[cfg(windows)] pub use funcs::*;
+#[cfg(windows)] pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\heap_enable_termination_on_corruption.rs");
+    include!("funcs/heap_enable_termination_on_corruption.rs");
 }
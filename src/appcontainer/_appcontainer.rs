@@ -1,5 +1,7 @@
 //! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/appcontainer-isolation)\]
 //! AppContainer isolation management functions
 
-pub use funcs::*;
-#[path = "appcontainer_funcs.rs"] pub(crate) mod funcs;
+mod appcontainer_derive;                                        pub use appcontainer_derive::*;
+
+#[cfg(windows)] pub use funcs::*;
+#[cfg(windows)] #[path = "appcontainer_funcs.rs"] pub(crate) mod funcs;
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use crate::util::sha256::Sha256;
+
+use winapi::um::winnt::*;
+
+
+
+#[doc(alias = "DeriveAppContainerSidFromAppContainerName")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-deriveappcontainersidfromappcontainername)\]
+/// ≈ DeriveAppContainerSidFromAppContainerName, without calling into `userenv.dll`
+///
+/// The SID is `S-1-15-2-` followed by the first 7 little endian [`u32`]s of the SHA-256 hash of the lowercased, UTF-16LE encoded name.
+/// Only ASCII is lowercased - AppContainer names are limited to `[-_. A-Za-z0-9]` anyways.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// assert_eq!(
+///     appcontainer::derive_sid_from_name("firehazard appcontainer"),
+///     *sid!(S-1-15-2-1616271714-4138532714-4113762788-3985685701-3505203873-1678203538-1574651687),
+/// );
+/// ```
+///
+pub fn derive_sid_from_name(app_container_name: &str) -> sid::Owned {
+    let mut sha = Sha256::new();
+    for unit in app_container_name.encode_utf16() {
+        let unit = if unit < 0x80 { u16::from((unit as u8).to_ascii_lowercase()) } else { unit };
+        sha.update(&unit.to_le_bytes());
+    }
+    let hash = sha.finish();
+
+    let mut sub_authorities = [SECURITY_APP_PACKAGE_BASE_RID; SECURITY_APP_PACKAGE_RID_COUNT as usize];
+    for (sa, word) in sub_authorities[1..].iter_mut().zip(hash.chunks_exact(4)) { *sa = u32::from_le_bytes([word[0], word[1], word[2], word[3]]); }
+    sid::Owned::new(SID_REVISION, 15, &sub_authorities).expect("8 sub-authorities") // SECURITY_APP_PACKAGE_AUTHORITY
+}
+
+#[cfg(std)] #[test] fn derive_sids() {
+    for (name, expected) in [
+        ("firehazard appcontainer",                                 sid!(S-1-15-2-1616271714-4138532714-4113762788-3985685701-3505203873-1678203538-1574651687)),
+        ("FireHazard AppContainer",                                 sid!(S-1-15-2-1616271714-4138532714-4113762788-3985685701-3505203873-1678203538-1574651687)),
+        ("firehazard create_app_container_profile doc example",    sid!(S-1-15-2-382764940-2791294323-2722217349-2365663742-3629851628-1828535050-2293899398)),
+    ] {
+        assert_eq!(derive_sid_from_name(name), *expected, "{name:?}");
+    }
+}
@@ -0,0 +1,57 @@
+//! Broker/target IPC: a sandboxed target process asks a privileged broker to perform operations (open a file, ...) on its behalf
+//!
+//! ### Overview
+//! *   [`Request`]s are typed messages with an opcode and a typed response, (de)serialized by the pure-Rust [`Encode`] / [`Decode`] traits.
+//! *   [`Frame`]s wrap encoded messages in a length-prefixed, versioned envelope.
+//! *   [`Transport`]s move frames: any <code>[Read](std::io::Read) + [Write](std::io::Write)</code> stream via [`Stream`], an in-memory [`Memory`] pair (for tests),
+//!     or a message mode [`pipe::named::Connected`](crate::pipe::named::Connected) on windows.
+//! *   The broker's [`Dispatcher`] routes each request to a per-request handler, which doubles as that request's policy:
+//!     it receives the request and a caller-defined context (e.g. a verified [`Peer`]), and returns either a response or an error code such as `ERROR_ACCESS_DENIED`.
+//! *   The target's [`Client`] sends requests and waits for their responses.
+//!
+//! On windows, [`Peer::verify`] ties a connected pipe to one of the broker's own child processes (via `GetNamedPipeClientProcessId`),
+//! and [`Peer::transfer`] duplicates handles into that process, to be returned as [`RemoteHandle`]s.
+//! [`Peer::transfer_checked`] first checks the handle against a [`Policy`]: an ordered list of allow/deny rules by object type, path, and maximum access.
+//!
+//! ### Wire format
+//! Every frame is a little endian `u32` length (of the rest of the frame), a `u16` [`VERSION`], a `u16` kind (`1` = request, `2` = response),
+//! a `u32` id (echoed by the response), a `u32` opcode (requests) or status (responses: `0` = success, otherwise a win32 error code), and the payload.
+//! Integers are little endian, `bool`s are a single `0`/`1` byte, and strings, lists and byte buffers are prefixed by a `u32` length.
+//!
+//! ### Examples
+//! ```
+//! use firehazard::broker::{self, Decode, DecodeError, Encode};
+//! # const ERROR_ACCESS_DENIED : u32 = 5; // winapi::shared::winerror::ERROR_ACCESS_DENIED
+//!
+//! struct Greet { name: String }
+//! impl Encode for Greet { fn encode(&self, out: &mut Vec<u8>) { self.name.encode(out) } }
+//! impl Decode for Greet { fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> { Ok(Self { name: String::decode(input)? }) } }
+//! impl broker::Request for Greet { const OP : u32 = 1; type Response = String; }
+//!
+//! struct Target { pid: u32 }
+//!
+//! let mut dispatcher = broker::Dispatcher::<Target>::new();
+//! dispatcher.on(|target: &mut Target, greet: Greet| {
+//!     if greet.name.is_empty() { return Err(firehazard::Error::from(ERROR_ACCESS_DENIED)) }
+//!     Ok(format!("hello {} from {}", greet.name, target.pid))
+//! });
+//!
+//! let (broker, target) = broker::Memory::pair();
+//! let server = std::thread::spawn(move || { let mut broker = broker; dispatcher.serve(&mut Target { pid: 42 }, &mut broker) });
+//!
+//! let mut client = broker::Client::new(target);
+//! assert_eq!(client.call(&Greet { name: "world".into() }).unwrap(), "hello world from 42");
+//! assert!(matches!(client.call(&Greet { name: "".into() }), Err(broker::Error::Remote(e)) if e == ERROR_ACCESS_DENIED));
+//! drop(client);
+//! server.join().unwrap().unwrap();
+//! ```
+
+mod broker_client;                      pub use broker_client::*;
+mod broker_codec;                       pub use broker_codec::*;
+mod broker_dispatcher;                  pub use broker_dispatcher::*;
+mod broker_error;                       pub use broker_error::*;
+mod broker_frame;                       pub use broker_frame::*;
+#[cfg(windows)] mod broker_peer;        #[cfg(windows)] pub use broker_peer::*;
+mod broker_policy;                      pub use broker_policy::*;
+mod broker_policy_error;                pub use broker_policy_error::*;
+mod broker_transport;                   pub use broker_transport::*;
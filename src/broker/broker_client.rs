@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use broker::{Decode, Frame, FrameKind, Request, Transport};
+
+use std::io;
+
+
+
+/// Sends [`broker::Request`](crate::broker::Request)s to a [`broker::Dispatcher`](crate::broker::Dispatcher), within the target process
+#[derive(Debug)]
+pub struct Client<T: Transport> {
+    transport:  T,
+    next_id:    u32,
+}
+
+impl<T: Transport> Client<T> {
+    pub fn new(transport: T) -> Self { Self { transport, next_id: 1 } }
+
+    pub fn transport(&self) -> &T { &self.transport }
+    pub fn transport_mut(&mut self) -> &mut T { &mut self.transport }
+    pub fn into_transport(self) -> T { self.transport }
+
+    /// Send `request` and wait for its response.
+    ///
+    /// ### Errors
+    /// *   [`broker::Error::Remote`](crate::broker::Error::Remote) if the broker refused or failed the request.
+    /// *   [`broker::Error::Io`](crate::broker::Error::Io) if the transport failed or was closed.
+    /// *   [`broker::Error::Decode`](crate::broker::Error::Decode) or [`broker::Error::UnexpectedResponse`](crate::broker::Error::UnexpectedResponse) if the broker misbehaved.
+    pub fn call<R: Request>(&mut self, request: &R) -> Result<R::Response, broker::Error> {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+
+        self.transport.send(&Frame { kind: FrameKind::Request, id, code: R::OP, payload: request.to_bytes() })?;
+        let response = self.transport.recv()?.ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+        if response.kind != FrameKind::Response || response.id != id { return Err(broker::Error::UnexpectedResponse) }
+        if response.code != 0 { return Err(broker::Error::Remote(firehazard::Error(response.code))) }
+        Ok(R::Response::from_bytes(&response.payload)?)
+    }
+}
+
+
+
+#[test] fn client_dispatcher() {
+    use broker::{DecodeError, Encode};
+    use std::string::String;
+
+    struct Echo(String);
+    impl Encode for Echo { fn encode(&self, out: &mut std::vec::Vec<u8>) { self.0.encode(out) } }
+    impl Decode for Echo { fn decode(input: &mut &[u8]) -> Result<Self, DecodeError> { Ok(Self(String::decode(input)?)) } }
+    impl Request for Echo { const OP : u32 = 1; type Response = String; }
+
+    struct Unhandled;
+    impl Encode for Unhandled { fn encode(&self, _out: &mut std::vec::Vec<u8>) {} }
+    impl Decode for Unhandled { fn decode(_input: &mut &[u8]) -> Result<Self, DecodeError> { Ok(Self) } }
+    impl Request for Unhandled { const OP : u32 = 2; type Response = (); }
+
+    struct Mismatched;
+    impl Encode for Mismatched { fn encode(&self, out: &mut std::vec::Vec<u8>) { out.push(0xFF) } }
+    impl Decode for Mismatched { fn decode(_input: &mut &[u8]) -> Result<Self, DecodeError> { Ok(Self) } }
+    impl Request for Mismatched { const OP : u32 = 1; type Response = String; }
+
+    let mut dispatcher = broker::Dispatcher::<u32>::new();
+    dispatcher.on(|calls: &mut u32, Echo(s)| {
+        *calls += 1;
+        if s == "deny" { Err(firehazard::Error(ERROR_ACCESS_DENIED)) } else { Ok(s) }
+    });
+
+    let (server, client) = broker::Memory::pair();
+    let server = std::thread::spawn(move || { let mut calls = 0; dispatcher.serve(&mut calls, server).map(|()| calls) });
+
+    let mut client = Client::new(client);
+    assert_eq!(client.call(&Echo("hello".into())).unwrap(), "hello");
+    assert!(matches!(client.call(&Echo("deny".into())), Err(broker::Error::Remote(e)) if e == ERROR_ACCESS_DENIED));
+    assert!(matches!(client.call(&Unhandled), Err(broker::Error::Remote(e)) if e == ERROR_NOT_SUPPORTED));
+    assert!(matches!(client.call(&Mismatched), Err(broker::Error::Remote(e)) if e == ERROR_INVALID_DATA));
+    drop(client);
+    assert_eq!(server.join().unwrap().unwrap(), 2);
+}
@@ -0,0 +1,154 @@
+use crate::prelude::*;
+use broker::DecodeError as E;
+
+use std::string::String;
+use std::vec::Vec;
+
+
+
+/// A typed message sent from a [`broker::Client`](crate::broker::Client) to a [`broker::Dispatcher`](crate::broker::Dispatcher), answered by a [`Self::Response`]
+pub trait Request : Encode + Decode {
+    /// Identifies the request type on the wire.  Must be unique per [`broker::Dispatcher`](crate::broker::Dispatcher).
+    const OP : u32;
+
+    /// The type of a successful response.
+    type Response : Encode + Decode;
+}
+
+/// Serialize `self` into the [wire format](crate::broker#wire-format)
+pub trait Encode {
+    fn encode(&self, out: &mut Vec<u8>);
+
+    fn to_bytes(&self) -> Vec<u8> { let mut out = Vec::new(); self.encode(&mut out); out }
+}
+
+/// Deserialize `Self` from the [wire format](crate::broker#wire-format), advancing `input` past what was consumed
+pub trait Decode : Sized {
+    fn decode(input: &mut &[u8]) -> Result<Self, E>;
+
+    /// Decode a complete payload, rejecting [`DecodeError::TrailingBytes`](E::TrailingBytes).
+    fn from_bytes(mut input: &[u8]) -> Result<Self, E> {
+        let value = Self::decode(&mut input)?;
+        if !input.is_empty() { return Err(E::TrailingBytes) }
+        Ok(value)
+    }
+}
+
+/// A handle value that is only meaningful within the process receiving the message
+///
+/// Produced on windows by [`broker::Peer::transfer`](crate::broker::Peer::transfer), which duplicates a handle into the target process.
+/// Encoded as a `u64` regardless of pointer size.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RemoteHandle(pub u64);
+
+fn take<'a>(input: &mut &'a [u8], n: usize) -> Result<&'a [u8], E> {
+    let (head, tail) = input.split_at_checked(n).ok_or(E::Truncated)?;
+    *input = tail;
+    Ok(head)
+}
+
+fn len(input: &mut &[u8]) -> Result<usize, E> {
+    let len = usize::from32(u32::decode(input)?);
+    // every element takes at least one byte... except for ()s, which no sane request would send millions of
+    if len > input.len() { return Err(E::Truncated) }
+    Ok(len)
+}
+
+fn encode_len(len: usize, out: &mut Vec<u8>) {
+    u32::try_from(len).expect("broker message collection too large").encode(out)
+}
+
+macro_rules! ints { ($($ty:ty),* $(,)?) => {$(
+    impl Encode for $ty { fn encode(&self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_le_bytes()) } }
+    impl Decode for $ty { fn decode(input: &mut &[u8]) -> Result<Self, E> { Ok(Self::from_le_bytes(take(input, size_of::<Self>())?.try_into().unwrap())) } }
+)*}}
+ints!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl Encode for () { fn encode(&self, _out: &mut Vec<u8>) {} }
+impl Decode for () { fn decode(_input: &mut &[u8]) -> Result<Self, E> { Ok(()) } }
+
+impl Encode for bool { fn encode(&self, out: &mut Vec<u8>) { out.push(*self as u8) } }
+impl Decode for bool {
+    fn decode(input: &mut &[u8]) -> Result<Self, E> {
+        match u8::decode(input)? { 0 => Ok(false), 1 => Ok(true), _ => Err(E::InvalidValue) }
+    }
+}
+
+impl Encode for RemoteHandle { fn encode(&self, out: &mut Vec<u8>) { self.0.encode(out) } }
+impl Decode for RemoteHandle { fn decode(input: &mut &[u8]) -> Result<Self, E> { Ok(Self(u64::decode(input)?)) } }
+
+impl Encode for str { fn encode(&self, out: &mut Vec<u8>) { encode_len(self.len(), out); out.extend_from_slice(self.as_bytes()) } }
+impl Encode for String { fn encode(&self, out: &mut Vec<u8>) { self.as_str().encode(out) } }
+impl Decode for String {
+    fn decode(input: &mut &[u8]) -> Result<Self, E> {
+        let len = len(input)?;
+        let bytes = take(input, len)?;
+        Ok(core::str::from_utf8(bytes).map_err(|_| E::InvalidValue)?.into())
+    }
+}
+
+impl<T: Encode> Encode for [T] { fn encode(&self, out: &mut Vec<u8>) { encode_len(self.len(), out); for e in self { e.encode(out) } } }
+impl<T: Encode> Encode for Vec<T> { fn encode(&self, out: &mut Vec<u8>) { self.as_slice().encode(out) } }
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(input: &mut &[u8]) -> Result<Self, E> {
+        let len = len(input)?;
+        let mut v = Vec::with_capacity(len);
+        for _ in 0 .. len { v.push(T::decode(input)?) }
+        Ok(v)
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.is_some().encode(out);
+        if let Some(value) = self { value.encode(out) }
+    }
+}
+impl<T: Decode> Decode for Option<T> {
+    fn decode(input: &mut &[u8]) -> Result<Self, E> { if bool::decode(input)? { Ok(Some(T::decode(input)?)) } else { Ok(None) } }
+}
+
+impl<T: Encode + ?Sized> Encode for &T { fn encode(&self, out: &mut Vec<u8>) { (**self).encode(out) } }
+
+macro_rules! tuples { ($(($($t:ident),+))*) => {$(
+    impl<$($t: Encode),+> Encode for ($($t,)+) {
+        #[allow(non_snake_case)] fn encode(&self, out: &mut Vec<u8>) { let ($($t,)+) = self; $($t.encode(out);)+ }
+    }
+    impl<$($t: Decode),+> Decode for ($($t,)+) {
+        fn decode(input: &mut &[u8]) -> Result<Self, E> { Ok(($($t::decode(input)?,)+)) }
+    }
+)*}}
+tuples! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+}
+
+
+
+#[test] fn codec() {
+    use std::vec;
+    fn round_trip<T: Encode + Decode + PartialEq + core::fmt::Debug>(value: T, bytes: &[u8]) {
+        assert_eq!(value.to_bytes(), bytes);
+        assert_eq!(T::from_bytes(bytes), Ok(value));
+        for len in 0 .. bytes.len() { assert_eq!(T::from_bytes(&bytes[..len]), Err(E::Truncated), "{len}") }
+        assert_eq!(T::from_bytes(&[bytes, &[0]].concat()), Err(E::TrailingBytes));
+    }
+
+    round_trip((), &[]);
+    round_trip(true, &[1]);
+    round_trip(0x1234u16, &[0x34, 0x12]);
+    round_trip(-2i32, &[0xFE, 0xFF, 0xFF, 0xFF]);
+    round_trip(RemoteHandle(0x1234), &[0x34, 0x12, 0, 0, 0, 0, 0, 0]);
+    round_trip(String::from("hi"), &[2, 0, 0, 0, b'h', b'i']);
+    round_trip(vec![1u8, 2], &[2, 0, 0, 0, 1, 2]);
+    round_trip(Some(3u8), &[1, 3]);
+    round_trip(None::<u8>, &[0]);
+    round_trip((1u8, String::from("x"), false), &[1, 1, 0, 0, 0, b'x', 0]);
+
+    assert_eq!(bool::from_bytes(&[2]), Err(E::InvalidValue));
+    assert_eq!(String::from_bytes(&[1, 0, 0, 0, 0xFF]), Err(E::InvalidValue));
+    assert_eq!(Vec::<u8>::from_bytes(&[0xFF, 0xFF, 0xFF, 0xFF]), Err(E::Truncated)); // no 4 GiB preallocation
+}
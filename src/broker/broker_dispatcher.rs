@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use broker::{Encode, Frame, FrameKind, Request, Transport};
+
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::io;
+use std::vec::Vec;
+
+
+
+/// Routes [`broker::Request`](crate::broker::Request)s to per-request handlers, within the broker process
+///
+/// Each handler is also the policy for its request: it sees the decoded request and a caller defined context `C`
+/// (typically who the target is, e.g. a verified [`broker::Peer`](crate::broker::Peer)) and either performs the request,
+/// or refuses it with an error code such as `ERROR_ACCESS_DENIED`.
+///
+/// Requests without a handler are refused with `ERROR_NOT_SUPPORTED`, and requests whose payload fails to decode with `ERROR_INVALID_DATA`.
+///
+pub struct Dispatcher<C: ?Sized> {
+    handlers: BTreeMap<u32, Box<Handler<C>>>,
+}
+
+type Handler<C> = dyn FnMut(&mut C, &[u8]) -> firehazard::Result<Vec<u8>> + Send;
+
+impl<C: ?Sized> Default for Dispatcher<C> {
+    fn default() -> Self { Self { handlers: BTreeMap::new() } }
+}
+
+impl<C: ?Sized> core::fmt::Debug for Dispatcher<C> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("broker::Dispatcher").field("ops", &self.handlers.keys()).finish()
+    }
+}
+
+impl<C: ?Sized> Dispatcher<C> {
+    /// Create a dispatcher that refuses every request.
+    pub fn new() -> Self { Self::default() }
+
+    /// Handle requests of type `R` with `handler`.
+    ///
+    /// ### Panics
+    /// If a handler for <code>R::[OP](crate::broker::Request::OP)</code> was already registered.
+    pub fn on<R: Request>(&mut self, mut handler: impl FnMut(&mut C, R) -> firehazard::Result<R::Response> + Send + 'static) -> &mut Self {
+        let prev = self.handlers.insert(R::OP, Box::new(move |ctx, payload| {
+            let request = R::from_bytes(payload)?;
+            Ok(handler(ctx, request)?.to_bytes())
+        }));
+        assert!(prev.is_none(), "broker::Dispatcher::on: op {} already has a handler", R::OP);
+        self
+    }
+
+    /// Handle a single request frame, returning the response frame to send back.
+    ///
+    /// A response frame passed in is answered with `ERROR_INVALID_DATA`.
+    pub fn handle(&mut self, ctx: &mut C, request: &Frame) -> Frame {
+        let result = match (request.kind, self.handlers.get_mut(&request.code)) {
+            (FrameKind::Request, Some(handler)) => handler(ctx, &request.payload),
+            (FrameKind::Request, None)          => Err(firehazard::Error(ERROR_NOT_SUPPORTED)),
+            (FrameKind::Response, _)            => Err(firehazard::Error(ERROR_INVALID_DATA)),
+        };
+        let (code, payload) = match result {
+            Ok(payload) => (0, payload),
+            Err(err)    => (u32::from(err), Vec::new()),
+        };
+        Frame { kind: FrameKind::Response, id: request.id, code, payload }
+    }
+
+    /// Handle requests from `transport` until the peer disconnects.
+    ///
+    /// Returns an error if `transport` fails, or receives an undecodable frame.
+    pub fn serve(&mut self, ctx: &mut C, mut transport: impl Transport) -> io::Result<()> {
+        while let Some(request) = transport.recv()? {
+            let response = self.handle(ctx, &request);
+            transport.send(&response)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Debug, Display, Formatter};
+use std::io;
+
+
+
+/// Error decoding a [`broker::Frame`](crate::broker::Frame) or a [`broker::Decode`](crate::broker::Decode) payload
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum DecodeError {
+    /// The input ended before the frame or value did.
+    Truncated,
+    /// The frame's length prefix exceeds [`broker::MAX_FRAME_SIZE`](crate::broker::MAX_FRAME_SIZE).
+    TooLarge(usize),
+    /// The frame was written with a different [`broker::VERSION`](crate::broker::VERSION).
+    UnsupportedVersion(u16),
+    /// The frame kind is neither a request nor a response.
+    InvalidKind(u16),
+    /// A value was out of range (e.g. a `bool` other than `0`/`1`, or a string that isn't UTF-8.)
+    InvalidValue,
+    /// The payload had bytes left over after decoding.
+    TrailingBytes,
+}
+
+impl From<DecodeError> for firehazard::Error {
+    fn from(_: DecodeError) -> Self { Self(ERROR_INVALID_DATA) }
+}
+
+impl From<DecodeError> for io::Error {
+    fn from(err: DecodeError) -> Self { io::Error::new(io::ErrorKind::InvalidData, err) }
+}
+
+impl core::error::Error for DecodeError {}
+
+impl Display for DecodeError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Truncated                     => write!(fmt, "broker message truncated"),
+            Self::TooLarge(len)                 => write!(fmt, "broker frame too large ({len} bytes)"),
+            Self::UnsupportedVersion(version)   => write!(fmt, "unsupported broker protocol version {version}"),
+            Self::InvalidKind(kind)             => write!(fmt, "invalid broker frame kind {kind}"),
+            Self::InvalidValue                  => write!(fmt, "invalid value in broker message"),
+            Self::TrailingBytes                 => write!(fmt, "trailing bytes after broker message"),
+        }
+    }
+}
+
+
+
+/// Error making a [`broker::Client::call`](crate::broker::Client::call)
+#[non_exhaustive] pub enum Error {
+    /// The [`broker::Transport`](crate::broker::Transport) failed, or was closed before a response arrived.
+    Io(io::Error),
+    /// The response payload couldn't be decoded as the request's response type.
+    Decode(DecodeError),
+    /// The broker refused or failed the request with a win32 error code (e.g. `ERROR_ACCESS_DENIED`.)
+    Remote(firehazard::Error),
+    /// The response wasn't a response frame, or didn't match the request's id.
+    UnexpectedResponse,
+}
+
+impl From<io::Error>   for Error { fn from(err: io::Error  ) -> Self { Self::Io(err) } }
+impl From<DecodeError> for Error { fn from(err: DecodeError) -> Self { Self::Decode(err) } }
+
+impl From<Error> for firehazard::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err)              => err.raw_os_error().map_or(Self(ERROR_BROKEN_PIPE), |code| Self(code as u32)),
+            Error::Decode(err)          => err.into(),
+            Error::Remote(err)          => err,
+            Error::UnexpectedResponse   => Self(ERROR_INVALID_DATA),
+        }
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err)              => err,
+            Error::Decode(err)          => err.into(),
+            Error::Remote(err)          => io::Error::from_raw_os_error(u32::from(err) as _),
+            Error::UnexpectedResponse   => io::Error::new(io::ErrorKind::InvalidData, Error::UnexpectedResponse),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Io(err)       => Some(err),
+            Self::Decode(err)   => Some(err),
+            _                   => None,
+        }
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err)               => write!(fmt, "broker::Error::Io({err:?})"),
+            Self::Decode(err)           => write!(fmt, "broker::Error::Decode({err:?})"),
+            Self::Remote(err)           => write!(fmt, "broker::Error::Remote({err:?})"),
+            Self::UnexpectedResponse    => write!(fmt, "broker::Error::UnexpectedResponse"),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err)               => write!(fmt, "broker transport error: {err}"),
+            Self::Decode(err)           => Display::fmt(err, fmt),
+            Self::Remote(err)           => write!(fmt, "broker refused request: {err:?}"),
+            Self::UnexpectedResponse    => write!(fmt, "unexpected broker response"),
+        }
+    }
+}
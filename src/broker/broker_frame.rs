@@ -0,0 +1,123 @@
+use crate::prelude::*;
+use broker::DecodeError as E;
+
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+
+
+/// The [wire format](crate::broker#wire-format) version written by, and the only version accepted by, this crate
+pub const VERSION : u16 = 1;
+
+/// The largest frame (excluding its `u32` length prefix) this crate will read, to bound memory use when reading from untrusted peers
+pub const MAX_FRAME_SIZE : usize = 1 << 20;
+
+const HEADER_SIZE : usize = 2 + 2 + 4 + 4;
+
+/// Whether a [`Frame`] is a request or a response
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameKind {
+    Request,
+    Response,
+}
+
+/// A single length-prefixed, versioned message (see the [wire format](crate::broker#wire-format))
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Frame {
+    pub kind:       FrameKind,
+    /// Chosen by the [`Client`](crate::broker::Client), echoed by the response.
+    pub id:         u32,
+    /// [`Request::OP`](crate::broker::Request::OP) for requests, `0` or a win32 error code for responses.
+    pub code:       u32,
+    pub payload:    Vec<u8>,
+}
+
+impl Frame {
+    /// Encode, including the `u32` length prefix.
+    ///
+    /// ### Panics
+    /// If the payload would exceed [`MAX_FRAME_SIZE`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let len = HEADER_SIZE + self.payload.len();
+        assert!(len <= MAX_FRAME_SIZE, "broker::Frame payload exceeds MAX_FRAME_SIZE");
+        let kind : u16 = match self.kind { FrameKind::Request => 1, FrameKind::Response => 2 };
+        let mut bytes = Vec::with_capacity(4 + len);
+        bytes.extend_from_slice(&(len as u32).to_le_bytes());
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&kind.to_le_bytes());
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        bytes.extend_from_slice(&self.code.to_le_bytes());
+        bytes.extend_from_slice(&self.payload);
+        bytes
+    }
+
+    /// Decode exactly one frame, including its `u32` length prefix.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, E> {
+        let (len, rest) = bytes.split_first_chunk::<4>().ok_or(E::Truncated)?;
+        let len = usize::from32(u32::from_le_bytes(*len));
+        if len > MAX_FRAME_SIZE { return Err(E::TooLarge(len)) }
+        if rest.len() < len { return Err(E::Truncated) }
+        if rest.len() > len { return Err(E::TrailingBytes) }
+        Self::from_body(rest)
+    }
+
+    /// Read one frame from a byte stream, or [`None`] if the stream ended cleanly before the frame started.
+    pub fn read_from(stream: &mut impl Read) -> io::Result<Option<Self>> {
+        let mut len = [0u8; 4];
+        let mut read = 0;
+        while read < len.len() {
+            match stream.read(&mut len[read..]) {
+                Ok(0) if read == 0                          => return Ok(None),
+                Ok(0)                                       => return Err(E::Truncated.into()),
+                Ok(n)                                       => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {},
+                Err(err)                                    => return Err(err),
+            }
+        }
+        let len = usize::from32(u32::from_le_bytes(len));
+        if len > MAX_FRAME_SIZE { return Err(E::TooLarge(len).into()) }
+        let mut body = std::vec![0u8; len];
+        stream.read_exact(&mut body).map_err(|err| if err.kind() == io::ErrorKind::UnexpectedEof { E::Truncated.into() } else { err })?;
+        Ok(Some(Self::from_body(&body)?))
+    }
+
+    /// Write one frame to a byte stream, as a single `write_all`.
+    pub fn write_to(&self, stream: &mut impl Write) -> io::Result<()> {
+        stream.write_all(&self.to_bytes())?;
+        stream.flush()
+    }
+
+    fn from_body(body: &[u8]) -> Result<Self, E> {
+        let (header, payload) = body.split_at_checked(HEADER_SIZE).ok_or(E::Truncated)?;
+        let u16_at = |i: usize| u16::from_le_bytes([header[i], header[i+1]]);
+        let u32_at = |i: usize| u32::from_le_bytes([header[i], header[i+1], header[i+2], header[i+3]]);
+        let version = u16_at(0);
+        if version != VERSION { return Err(E::UnsupportedVersion(version)) }
+        let kind = match u16_at(2) {
+            1       => FrameKind::Request,
+            2       => FrameKind::Response,
+            other   => return Err(E::InvalidKind(other)),
+        };
+        Ok(Self { kind, id: u32_at(4), code: u32_at(8), payload: payload.to_vec() })
+    }
+}
+
+
+
+#[test] fn frame() {
+    let frame = Frame { kind: FrameKind::Request, id: 7, code: 3, payload: std::vec![1, 2, 3] };
+    let bytes = frame.to_bytes();
+    assert_eq!(bytes, [15,0,0,0, 1,0, 1,0, 7,0,0,0, 3,0,0,0, 1,2,3]);
+    assert_eq!(Frame::from_bytes(&bytes), Ok(frame.clone()));
+    assert_eq!(Frame::read_from(&mut &bytes[..]).unwrap(), Some(frame.clone()));
+    assert_eq!(Frame::read_from(&mut &[][..]).unwrap(), None);
+
+    for len in 0 .. bytes.len() {
+        assert_eq!(Frame::from_bytes(&bytes[..len]), Err(E::Truncated), "{len}");
+        if len != 0 { assert_eq!(Frame::read_from(&mut &bytes[..len]).unwrap_err().kind(), io::ErrorKind::InvalidData, "{len}"); }
+    }
+    assert_eq!(Frame::from_bytes(&[bytes.as_slice(), &[0]].concat()), Err(E::TrailingBytes));
+    assert_eq!(Frame::from_bytes(&[0xFF, 0xFF, 0xFF, 0xFF]), Err(E::TooLarge(0xFFFF_FFFF)));
+    assert_eq!(Frame::from_bytes(&[12,0,0,0, 2,0, 1,0, 0,0,0,0, 0,0,0,0]), Err(E::UnsupportedVersion(2)));
+    assert_eq!(Frame::from_bytes(&[12,0,0,0, 1,0, 3,0, 0,0,0,0, 0,0,0,0]), Err(E::InvalidKind(3)));
+}
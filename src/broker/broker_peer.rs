@@ -0,0 +1,93 @@
+use crate::prelude::*;
+use broker::RemoteHandle;
+
+
+
+/// A target process verified to be on the other end of a [`pipe::named::Connected`](crate::pipe::named::Connected)
+///
+/// Pass this (or something containing it) as the context of a [`broker::Dispatcher`](crate::broker::Dispatcher)
+/// so request handlers know which target they're serving, and can [`transfer`](Self::transfer) handles to it.
+///
+#[derive(Debug)]
+pub struct Peer {
+    process:    process::OwnedHandle,
+    pid:        process::Id,
+}
+
+impl Peer {
+    /// Verify the client of `connection` is one of `children`, typically the target processes spawned by this broker.
+    ///
+    /// The client's process ID (per `GetNamedPipeClientProcessId`) is compared against `GetProcessId` of each child.
+    /// As the broker holds open handles to `children`, their process IDs cannot have been reused by an unrelated process.
+    ///
+    /// ### Errors
+    /// *   `ERROR_ACCESS_DENIED`   if the client isn't any of `children`
+    /// *   `ERROR_*`               if the client process ID, or a child's process ID, couldn't be queried
+    ///
+    pub fn verify<'c>(connection: &pipe::named::Connected, children: impl IntoIterator<Item = &'c process::OwnedHandle>) -> firehazard::Result<Self> {
+        let pid = pipe::named::get_client_process_id(connection)?;
+        for child in children {
+            if get_process_id(child)? == pid {
+                let process = child.try_clone_to_owned()?;
+                return Ok(Self { process, pid });
+            }
+        }
+        Err(firehazard::Error(ERROR_ACCESS_DENIED))
+    }
+
+    pub fn process(&self) -> &process::OwnedHandle { &self.process }
+    pub fn pid(&self) -> process::Id { self.pid }
+
+    /// Duplicate `handle` into the peer process, with `access` (or the same access as `handle` if [`None`]).
+    ///
+    /// The peer process must have been opened with `PROCESS_DUP_HANDLE` access.
+    /// The resulting [`RemoteHandle`] is owned by the peer: send it in a response, and the peer should [`RemoteHandle::into_local`] it.
+    /// If the peer never learns about the handle, it leaks until the peer exits.
+    ///
+    pub fn transfer(&self, handle: &impl AsLocalHandle, access: impl Into<Option<access::Mask>>) -> firehazard::Result<RemoteHandle> {
+        let remote = duplicate_handle(handle, &self.process, access, false)?;
+        Ok(RemoteHandle(remote as usize as u64))
+    }
+
+    /// [`transfer`](Self::transfer) `handle`, if `policy` allows it.
+    ///
+    /// The transfer is described with [`broker::Query::from_handle`](crate::broker::Query::from_handle) and evaluated by `policy`.
+    /// The resulting [`broker::Audit`](crate::broker::Audit) is passed to `audit` (e.g. for logging) whether the transfer was allowed or not.
+    ///
+    /// ### Errors
+    /// *   `ERROR_ACCESS_DENIED`   if `policy` denies the transfer
+    /// *   `ERROR_*`               if `handle` couldn't be queried or duplicated
+    ///
+    pub fn transfer_checked<'h>(
+        &self,
+        policy: &broker::Policy,
+        handle: impl Into<handle::Pseudo<'h>>,
+        access: impl Into<Option<access::Mask>>,
+        audit:  impl FnOnce(&broker::Audit),
+    ) -> firehazard::Result<RemoteHandle> {
+        let handle = handle.into();
+        let (decision, record) = policy.evaluate(&broker::Query::from_handle(handle, access)?);
+        audit(&record);
+        match decision {
+            broker::Decision::Allow { access }  => self.transfer(&handle, access),
+            broker::Decision::Deny              => Err(firehazard::Error(ERROR_ACCESS_DENIED)),
+        }
+    }
+}
+
+impl RemoteHandle {
+    /// Take ownership of a handle [`transfer`](Peer::transfer)red into the current process.
+    ///
+    /// ### Errors
+    /// *   `ERROR_INVALID_HANDLE`  if the handle is null or doesn't fit in a pointer
+    ///
+    /// ### Safety
+    /// *   The handle must have been transferred into the current process (a handle value from any other process may alias an unrelated handle)
+    /// *   The handle must not be taken ownership of more than once
+    /// *   The handle must be of the correct type for `H`
+    ///
+    pub unsafe fn into_local<H: FromLocalHandle>(self) -> firehazard::Result<H> {
+        let handle = usize::try_from(self.0).map_err(|_| firehazard::Error(ERROR_INVALID_HANDLE))?;
+        unsafe { H::from_raw(handle as *mut c_void) }
+    }
+}
@@ -0,0 +1,437 @@
+use crate::prelude::*;
+use broker::PolicyParseError as E;
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+
+
+/// Whether a matching [`Rule`] allows or denies a handle transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Allow,
+    Deny,
+}
+
+/// Which paths a [`Rule`] matches
+///
+/// Matching is ASCII case insensitive, treats `/` and `\` alike, and ignores a leading `\\?\` (as returned by `GetFinalPathNameByHandle`.)
+/// Paths containing `.` or `..` components never match, so a rule for `C:\sandbox\**` can't be escaped with `C:\sandbox\..\secrets`.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathPattern {
+    /// Matches the path itself, and anything beneath it (`C:\a` matches `C:\a` and `C:\a\b`, but not `C:\ab`.)
+    Prefix(String),
+
+    /// `?` matches any single character other than a separator, `*` any run of characters other than separators, and `**` anything at all.
+    Glob(String),
+}
+
+/// A single line of a [`Policy`]
+///
+/// A rule matches a [`Query`] if every condition it specifies matches.
+/// [`Action::Allow`] rules may additionally cap the access that can be granted: requests exceeding `max_access` are denied.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rule {
+    pub action:         Action,
+    /// Match the [object type name](crate::nt_query_object_type_name) (ASCII case insensitive), e.g. `"File"`, `"Event"`, `"Section"`.
+    pub object_type:    Option<String>,
+    /// Match the object's path.  Objects without a path never match rules with a path condition.
+    pub path:           Option<PathPattern>,
+    /// For [`Action::Allow`] rules, the most access that may be granted.
+    ///
+    /// Access rights are compared bitwise: map generic rights (e.g. via [`access::GenericMapping`](crate::access::GenericMapping)) before querying if you allow any.
+    pub max_access:     Option<access::Mask>,
+}
+
+/// A request to duplicate a handle into a sandboxed process, as evaluated by a [`Policy`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Query {
+    pub object_type:    String,
+    pub path:           Option<String>,
+    /// The access the duplicated handle would have.
+    pub access:         access::Mask,
+}
+
+/// The outcome of evaluating a [`Query`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Decision {
+    /// Duplicate the handle with `access`.
+    Allow { access: access::Mask },
+    Deny,
+}
+
+/// A record of why a [`Policy`] reached a [`Decision`], suitable for logging (see [`Display`])
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Audit {
+    pub decision:       Decision,
+    pub query:          Query,
+    /// The index of the first matching rule, or [`None`] if no rule matched (and the query was denied by default.)
+    pub rule:           Option<usize>,
+    /// The line of the first matching rule, if the [`Policy`] was parsed from text.
+    pub line:           Option<usize>,
+    /// Requested access rights beyond the matching rule's `max_access`, if any (which causes the query to be denied.)
+    pub excess_access:  access::Mask,
+}
+
+/// An ordered list of handle duplication [`Rule`]s, evaluated with first-match semantics
+///
+/// Queries that match no rule are denied.
+///
+/// ### Text format
+/// One rule per line: `allow` or `deny`, followed by whitespace separated `key=value` conditions.
+/// Blank lines, and anything after a `#` (outside of quotes), are ignored.
+/// Values containing whitespace or `#` may be double quoted.
+///
+/// | Key       | Condition |
+/// | ----------| ----------|
+/// | `type`    | [`Rule::object_type`]
+/// | `prefix`  | [`Rule::path`] = [`PathPattern::Prefix`]
+/// | `path`    | [`Rule::path`] = [`PathPattern::Glob`]
+/// | `max`     | [`Rule::max_access`] (`allow` only): pipe separated numbers (e.g. `0x00120089`) or names (`GENERIC_READ`, `SYNCHRONIZE`, ...)
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let policy : broker::Policy = r#"
+///     ## sandbox scratch space: read/write, but not delete
+///     allow type=File prefix="C:\Sandbox Scratch" max=0x0012019F
+///     deny  type=File path=C:\Windows\**\*.exe
+///     allow type=File path=C:\Windows\** max=0x00120089    # FILE_GENERIC_READ
+///     allow type=Event max=SYNCHRONIZE|0x0002             # EVENT_MODIFY_STATE
+/// "#.parse().unwrap();
+///
+/// let query = |object_type: &str, path: Option<&str>, access: u32| broker::Query {
+///     object_type: object_type.into(),
+///     path: path.map(String::from),
+///     access: unsafe { access::Mask::from_unchecked(access) },
+/// };
+///
+/// let (decision, audit) = policy.evaluate(&query("File", Some(r"\\?\C:\Windows\win.ini"), 0x00120089));
+/// assert!(matches!(decision, broker::Decision::Allow { .. }));
+/// assert_eq!(audit.line, Some(5));
+///
+/// let (decision, audit) = policy.evaluate(&query("file", Some(r"C:\windows\system32\cmd.exe"), 0x00120089));
+/// assert_eq!(decision, broker::Decision::Deny);
+/// assert_eq!(audit.line, Some(4));
+///
+/// let (decision, audit) = policy.evaluate(&query("File", Some(r"C:\Windows\win.ini"), 0x00120116)); // FILE_GENERIC_WRITE
+/// assert_eq!(decision, broker::Decision::Deny);
+/// assert_eq!(u32::from(audit.excess_access), 0x00000116);
+///
+/// let (decision, audit) = policy.evaluate(&query("Process", None, 0x1000));
+/// assert_eq!(decision, broker::Decision::Deny);
+/// assert_eq!(audit.rule, None);
+/// println!("{audit}");
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Policy {
+    rules: Vec<(Rule, Option<usize>)>,
+}
+
+impl Policy {
+    /// Create a policy that denies everything.
+    pub fn new() -> Self { Self::default() }
+
+    /// Append a rule, to be checked after all existing rules.
+    pub fn push(&mut self, rule: Rule) { self.rules.push((rule, None)) }
+
+    pub fn rules(&self) -> impl Iterator<Item = &Rule> { self.rules.iter().map(|(rule, _)| rule) }
+
+    /// Evaluate `query` against each rule in order, returning the first matching rule's decision (or [`Decision::Deny`] if none match.)
+    pub fn evaluate(&self, query: &Query) -> (Decision, Audit) {
+        let mut audit = Audit { decision: Decision::Deny, query: query.clone(), rule: None, line: None, excess_access: access::Mask::default() };
+        if let Some((index, (rule, line))) = self.rules.iter().enumerate().find(|(_, (rule, _))| rule.matches(query)) {
+            audit.rule = Some(index);
+            audit.line = *line;
+            audit.excess_access = rule.max_access.map_or(access::Mask::default(), |max| query.access & !max);
+            if rule.action == Action::Allow && audit.excess_access == access::Mask::default() {
+                audit.decision = Decision::Allow { access: query.access };
+            }
+        }
+        (audit.decision, audit)
+    }
+}
+
+impl Rule {
+    /// Whether this rule's conditions match `query` (regardless of [`Self::max_access`].)
+    pub fn matches(&self, query: &Query) -> bool {
+        if let Some(object_type) = self.object_type.as_deref() {
+            if !object_type.eq_ignore_ascii_case(&query.object_type) { return false }
+        }
+        if let Some(pattern) = self.path.as_ref() {
+            let Some(path) = query.path.as_deref() else { return false };
+            if !pattern.matches(path) { return false }
+        }
+        true
+    }
+}
+
+impl PathPattern {
+    /// Whether `path` matches this pattern.
+    pub fn matches(&self, path: &str) -> bool {
+        let Some(path) = normalize(path) else { return false };
+        match self {
+            PathPattern::Prefix(prefix) => {
+                let prefix = normalize_pattern(prefix);
+                let prefix = prefix.strip_suffix('\\').unwrap_or(&prefix);
+                match path.strip_prefix(prefix) {
+                    Some(rest)  => rest.is_empty() || rest.starts_with('\\'),
+                    None        => false,
+                }
+            },
+            PathPattern::Glob(glob) => glob_matches(&normalize_pattern(glob).chars().collect::<Vec<_>>(), &path.chars().collect::<Vec<_>>()),
+        }
+    }
+}
+
+fn normalize_pattern(pattern: &str) -> String {
+    let pattern = pattern.replace('/', "\\").to_ascii_lowercase();
+    match pattern.strip_prefix(r"\\?\") {
+        Some(rest)  => rest.into(),
+        None        => pattern,
+    }
+}
+
+fn normalize(path: &str) -> Option<String> {
+    let path = normalize_pattern(path);
+    if path.split('\\').any(|component| component == "." || component == "..") { return None }
+    Some(path)
+}
+
+fn glob_matches(glob: &[char], path: &[char]) -> bool {
+    // matches[j] = whether the glob consumed so far matches path[..j]
+    let mut matches = std::vec![false; path.len() + 1];
+    matches[0] = true;
+    let mut g = 0;
+    while g < glob.len() {
+        let star2 = glob[g] == '*' && glob.get(g+1) == Some(&'*');
+        match glob[g] {
+            '*' => for j in 1 ..= path.len() { matches[j] |= matches[j-1] && (star2 || path[j-1] != '\\') },
+            c   => for j in (0 ..= path.len()).rev() { matches[j] = j > 0 && matches[j-1] && (if c == '?' { path[j-1] != '\\' } else { path[j-1] == c }) },
+        }
+        g += if star2 { 2 } else { 1 };
+    }
+    matches[path.len()]
+}
+
+
+
+impl FromStr for Policy {
+    type Err = E;
+    fn from_str(text: &str) -> Result<Self, E> {
+        let mut policy = Policy::new();
+        for (index, line) in text.lines().enumerate() {
+            let line_no = index + 1;
+            let mut tokens = tokenize(line).map_err(|()| E::UnterminatedQuote { line: line_no })?.into_iter();
+            let Some(action) = tokens.next() else { continue };
+            let action = match action.as_str() {
+                "allow" => Action::Allow,
+                "deny"  => Action::Deny,
+                _       => return Err(E::UnknownAction { line: line_no }),
+            };
+            let mut rule = Rule { action, object_type: None, path: None, max_access: None };
+            for token in tokens {
+                let Some((key, value)) = token.split_once('=') else { return Err(E::MissingValue { line: line_no }) };
+                let value = value.to_string();
+                let duplicate = match key {
+                    "type"      => rule.object_type.replace(value).is_some(),
+                    "prefix"    => rule.path.replace(PathPattern::Prefix(value)).is_some(),
+                    "path"      => rule.path.replace(PathPattern::Glob(value)).is_some(),
+                    "max"       => rule.max_access.replace(parse_mask(&value).ok_or(E::InvalidMask { line: line_no })?).is_some(),
+                    _           => return Err(E::UnknownKey { line: line_no }),
+                };
+                if duplicate { return Err(E::DuplicateKey { line: line_no }) }
+            }
+            if action == Action::Deny && rule.max_access.is_some() { return Err(E::MaxOnDeny { line: line_no }) }
+            policy.rules.push((rule, Some(line_no)));
+        }
+        Ok(policy)
+    }
+}
+
+/// Split on whitespace, honoring `"quotes"` (which are removed) and `#` comments.
+fn tokenize(line: &str) -> Result<Vec<String>, ()> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        match chars.peek() {
+            None | Some('#') => return Ok(tokens),
+            Some(_) => {},
+        }
+        let mut token = String::new();
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            if c != '"' { token.push(c); continue }
+            loop {
+                match chars.next() {
+                    Some('"')   => break,
+                    Some(c)     => token.push(c),
+                    None        => return Err(()),
+                }
+            }
+        }
+        tokens.push(token);
+    }
+}
+
+const MASK_NAMES : &[(&str, access::Mask)] = &[
+    ("DELETE",                  access::DELETE),
+    ("READ_CONTROL",            access::READ_CONTROL),
+    ("WRITE_DAC",               access::WRITE_DAC),
+    ("WRITE_OWNER",             access::WRITE_OWNER),
+    ("SYNCHRONIZE",             access::SYNCHRONIZE),
+    ("ACCESS_SYSTEM_SECURITY",  access::ACCESS_SYSTEM_SECURITY),
+    ("MAXIMUM_ALLOWED",         access::MAXIMUM_ALLOWED),
+    ("GENERIC_READ",            access::GENERIC_READ),
+    ("GENERIC_WRITE",           access::GENERIC_WRITE),
+    ("GENERIC_EXECUTE",         access::GENERIC_EXECUTE),
+    ("GENERIC_ALL",             access::GENERIC_ALL),
+];
+
+fn parse_mask(text: &str) -> Option<access::Mask> {
+    let mut mask = 0u32;
+    for part in text.split('|') {
+        let part = part.trim();
+        mask |= if let Some(hex) = part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+            u32::from_str_radix(hex, 16).ok()?
+        } else if part.starts_with(|c: char| c.is_ascii_digit()) {
+            part.parse().ok()?
+        } else {
+            u32::from(MASK_NAMES.iter().find(|(name, _)| *name == part)?.1)
+        };
+    }
+    Some(unsafe { access::Mask::from_unchecked(mask) })
+}
+
+
+
+impl Display for Policy {
+    /// Renders the policy in the [text format](Self#text-format), one rule per line.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        for rule in self.rules() { writeln!(fmt, "{rule}")? }
+        Ok(())
+    }
+}
+
+impl Display for Rule {
+    /// Renders the rule as a line of the [text format](Policy#text-format).
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fn value(fmt: &mut Formatter, key: &str, value: &str) -> fmt::Result {
+            if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '#') {
+                write!(fmt, " {key}=\"{value}\"")
+            } else {
+                write!(fmt, " {key}={value}")
+            }
+        }
+        fmt.write_str(match self.action { Action::Allow => "allow", Action::Deny => "deny" })?;
+        if let Some(t) = self.object_type.as_deref() { value(fmt, "type", t)? }
+        match self.path.as_ref() {
+            Some(PathPattern::Prefix(p))    => value(fmt, "prefix", p)?,
+            Some(PathPattern::Glob(p))      => value(fmt, "path", p)?,
+            None                            => {},
+        }
+        if let Some(max) = self.max_access { write!(fmt, " max=0x{:08X}", u32::from(max))? }
+        Ok(())
+    }
+}
+
+impl Display for Audit {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self.decision {
+            Decision::Allow { .. }  => write!(fmt, "allowed")?,
+            Decision::Deny          => write!(fmt, "denied")?,
+        }
+        write!(fmt, " {}", self.query.object_type)?;
+        if let Some(path) = self.query.path.as_deref() { write!(fmt, " {path:?}")? }
+        write!(fmt, " access=0x{:08X}", u32::from(self.query.access))?;
+        match (self.rule, self.line) {
+            (None, _)               => write!(fmt, ": no matching rule")?,
+            (Some(_), Some(line))   => write!(fmt, ": by rule on line {line}")?,
+            (Some(rule), None)      => write!(fmt, ": by rule #{rule}")?,
+        }
+        if self.excess_access != access::Mask::default() { write!(fmt, " (exceeds max access by 0x{:08X})", u32::from(self.excess_access))? }
+        Ok(())
+    }
+}
+
+
+
+#[cfg(windows)] impl Query {
+    /// Describe duplicating `handle` with `access` (or the same access as `handle` if [`None`]).
+    ///
+    /// *   [`Self::object_type`] is queried with [`nt_query_object_type_name`].
+    /// *   [`Self::path`] is queried with [`get_final_path_name_by_handle`] for `"File"` objects (and left [`None`] if that fails, e.g. for pipes.)
+    /// *   [`Self::access`] defaults to the access `handle` was granted, per `NtQueryObject(..., ObjectBasicInformation, ...)`.
+    ///
+    pub fn from_handle<'h>(handle: impl Into<handle::Pseudo<'h>>, access: impl Into<Option<access::Mask>>) -> firehazard::Result<Self> {
+        let handle = handle.into();
+        let object_type = nt_query_object_type_name(handle)?.into_string().map_err(|_| firehazard::Error(ERROR_INVALID_DATA))?;
+        let path = match object_type.as_str() {
+            "File"  => get_final_path_name_by_handle(&handle, 0).ok().and_then(|path| path.into_os_string().into_string().ok()),
+            _       => None,
+        };
+        let access = match access.into() {
+            Some(access)    => access,
+            None            => crate::handle::funcs::nt_query_object::<ntdll::PUBLIC_OBJECT_BASIC_INFORMATION>(handle)?.GrantedAccess,
+        };
+        Ok(Self { object_type, path, access })
+    }
+}
+
+
+
+#[test] fn glob() {
+    let glob = |glob: &str, path: &str| PathPattern::Glob(glob.into()).matches(path);
+    assert!( glob(r"C:\a\*.txt",    r"c:/A/b.TXT"));
+    assert!(!glob(r"C:\a\*.txt",    r"C:\a\b\c.txt"));
+    assert!( glob(r"C:\a\**.txt",   r"C:\a\b\c.txt"));
+    assert!( glob(r"C:\a\**",       r"\\?\C:\a\b\c"));
+    assert!(!glob(r"C:\a\**",       r"C:\a\..\b"));
+    assert!( glob(r"C:\a\?",        r"C:\a\b"));
+    assert!(!glob(r"C:\a\?",        r"C:\a\bc"));
+    assert!(!glob(r"C:\a?b",        r"C:\a\b"));
+    assert!( glob(r"*",             r""));
+
+    let prefix = |prefix: &str, path: &str| PathPattern::Prefix(prefix.into()).matches(path);
+    assert!( prefix(r"C:\a",        r"C:\a"));
+    assert!( prefix(r"C:\a\",       r"C:\A\b"));
+    assert!(!prefix(r"C:\a",        r"C:\ab"));
+    assert!(!prefix(r"C:\a",        r"C:\a\..\b"));
+}
+
+#[test] fn policy() {
+    use std::format;
+
+    let text = "allow type=File prefix=\"C:\\Program Files\" max=GENERIC_READ|0x0001 # comment\n\n  deny type=Section\nallow\n";
+    let policy : Policy = text.parse().unwrap();
+    assert_eq!(policy.rules().count(), 3);
+    assert_eq!(format!("{policy}"), "allow type=File prefix=\"C:\\Program Files\" max=0x80000001\ndeny type=Section\nallow\n");
+    assert_eq!(format!("{policy}").parse::<Policy>().unwrap().rules().collect::<Vec<_>>(), policy.rules().collect::<Vec<_>>());
+
+    let query = |object_type: &str, path: Option<&str>, access: u32| Query { object_type: object_type.into(), path: path.map(String::from), access: unsafe { access::Mask::from_unchecked(access) } };
+    let (decision, audit) = policy.evaluate(&query("File", Some(r"C:\Program Files\x"), 1));
+    assert_eq!(decision, Decision::Allow { access: unsafe { access::Mask::from_unchecked(1) } });
+    assert_eq!((audit.rule, audit.line), (Some(0), Some(1)));
+    assert_eq!(format!("{audit}"), "allowed File \"C:\\\\Program Files\\\\x\" access=0x00000001: by rule on line 1");
+
+    let (decision, audit) = policy.evaluate(&query("File", Some(r"C:\Program Files\x"), 3));
+    assert_eq!(decision, Decision::Deny);
+    assert_eq!(u32::from(audit.excess_access), 2);
+
+    assert_eq!(policy.evaluate(&query("Section", None, 1)).0, Decision::Deny);
+    assert!(matches!(policy.evaluate(&query("Event", None, 1)).0, Decision::Allow { .. }));
+    assert_eq!(Policy::new().evaluate(&query("Event", None, 1)).1.rule, None);
+
+    assert_eq!("permit".parse::<Policy>(),                  Err(E::UnknownAction    { line: 1 }));
+    assert_eq!("\nallow owner=x".parse::<Policy>(),         Err(E::UnknownKey       { line: 2 }));
+    assert_eq!("allow type".parse::<Policy>(),              Err(E::MissingValue     { line: 1 }));
+    assert_eq!("allow path=a prefix=b".parse::<Policy>(),   Err(E::DuplicateKey     { line: 1 }));
+    assert_eq!("allow max=READ".parse::<Policy>(),          Err(E::InvalidMask      { line: 1 }));
+    assert_eq!("deny max=1".parse::<Policy>(),              Err(E::MaxOnDeny        { line: 1 }));
+    assert_eq!("allow path=\"a".parse::<Policy>(),          Err(E::UnterminatedQuote{ line: 1 }));
+}
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// Error parsing a [`broker::Policy`](crate::broker::Policy) from its [text format](crate::broker::Policy#text-format)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum PolicyParseError {
+    /// A rule didn't start with `allow` or `deny`.
+    UnknownAction       { line: usize },
+    /// A condition key other than `type`, `prefix`, `path`, or `max`.
+    UnknownKey          { line: usize },
+    /// A condition without `=value`.
+    MissingValue        { line: usize },
+    /// The same condition (or both `prefix` and `path`) was given twice.
+    DuplicateKey        { line: usize },
+    /// A `max` that isn't a `|` separated list of numbers and access right names.
+    InvalidMask         { line: usize },
+    /// A `deny` rule with a `max`.
+    MaxOnDeny           { line: usize },
+    /// A `"` without a closing `"`.
+    UnterminatedQuote   { line: usize },
+}
+
+impl PolicyParseError {
+    /// The 1-based line number the error occured on.
+    pub fn line(&self) -> usize {
+        match *self {
+            Self::UnknownAction { line } | Self::UnknownKey { line } | Self::MissingValue { line } | Self::DuplicateKey { line } |
+            Self::InvalidMask { line } | Self::MaxOnDeny { line } | Self::UnterminatedQuote { line } => line,
+        }
+    }
+}
+
+impl From<PolicyParseError> for firehazard::Error {
+    fn from(_: PolicyParseError) -> Self { Self(ERROR_INVALID_DATA) }
+}
+
+impl From<PolicyParseError> for std::io::Error {
+    fn from(err: PolicyParseError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidData, err) }
+}
+
+impl core::error::Error for PolicyParseError {}
+
+impl Display for PolicyParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let problem = match self {
+            Self::UnknownAction     { .. } => "expected `allow` or `deny`",
+            Self::UnknownKey        { .. } => "unknown condition (expected `type`, `prefix`, `path`, or `max`)",
+            Self::MissingValue      { .. } => "condition is missing `=value`",
+            Self::DuplicateKey      { .. } => "duplicate condition",
+            Self::InvalidMask       { .. } => "invalid access mask",
+            Self::MaxOnDeny         { .. } => "`max` is only valid on `allow` rules",
+            Self::UnterminatedQuote { .. } => "unterminated quote",
+        };
+        write!(fmt, "line {}: {problem}", self.line())
+    }
+}
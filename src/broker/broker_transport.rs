@@ -0,0 +1,99 @@
+use crate::prelude::*;
+use broker::Frame;
+
+use std::io::{self, Read, Write};
+use std::sync::mpsc;
+
+
+
+/// Sends and receives whole [`broker::Frame`](crate::broker::Frame)s
+pub trait Transport {
+    /// Send a single frame.
+    fn send(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Receive a single frame, or [`None`] if the peer closed the connection.
+    fn recv(&mut self) -> io::Result<Option<Frame>>;
+}
+
+impl<T: Transport + ?Sized> Transport for &mut T {
+    fn send(&mut self, frame: &Frame) -> io::Result<()> { (**self).send(frame) }
+    fn recv(&mut self) -> io::Result<Option<Frame>> { (**self).recv() }
+}
+
+
+
+/// A [`broker::Transport`](crate::broker::Transport) over any byte stream (sockets, anonymous pipes, byte mode named pipes, ...)
+///
+/// Frames are delimited by their `u32` length prefix, so this also works for message mode pipes.
+///
+#[derive(Debug)]
+pub struct Stream<S: Read + Write>(pub S);
+
+impl<S: Read + Write> Transport for Stream<S> {
+    fn send(&mut self, frame: &Frame) -> io::Result<()> { frame.write_to(&mut self.0) }
+    fn recv(&mut self) -> io::Result<Option<Frame>> { Frame::read_from(&mut self.0) }
+}
+
+
+
+/// An in-process [`broker::Transport`](crate::broker::Transport), connected to exactly one other [`Memory`] transport
+///
+/// Frames are still round tripped through their encoded bytes, so this exercises the same codec as a real transport.
+/// Dropping either end closes the connection.
+///
+#[derive(Debug)]
+pub struct Memory {
+    tx: mpsc::Sender<std::vec::Vec<u8>>,
+    rx: mpsc::Receiver<std::vec::Vec<u8>>,
+}
+
+impl Memory {
+    /// Create two connected transports.
+    pub fn pair() -> (Self, Self) {
+        let (atx, brx) = mpsc::channel();
+        let (btx, arx) = mpsc::channel();
+        (Self { tx: atx, rx: arx }, Self { tx: btx, rx: brx })
+    }
+}
+
+impl Transport for Memory {
+    fn send(&mut self, frame: &Frame) -> io::Result<()> {
+        self.tx.send(frame.to_bytes()).map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+
+    fn recv(&mut self) -> io::Result<Option<Frame>> {
+        match self.rx.recv() {
+            Ok(bytes)   => Ok(Some(Frame::from_bytes(&bytes)?)),
+            Err(_)      => Ok(None),
+        }
+    }
+}
+
+
+
+#[cfg(windows)] impl Transport for pipe::named::Connected {
+    /// Send a frame as a single message.
+    fn send(&mut self, frame: &Frame) -> io::Result<()> { Write::write_all(self, &frame.to_bytes()) }
+
+    /// Receive a frame (a broken pipe or disconnected client is treated as the connection closing.)
+    fn recv(&mut self) -> io::Result<Option<Frame>> {
+        match Frame::read_from(&mut self.bytes()) {
+            Err(err) if err.raw_os_error() == Some(ERROR_BROKEN_PIPE as _)          => Ok(None),
+            Err(err) if err.raw_os_error() == Some(ERROR_PIPE_NOT_CONNECTED as _)   => Ok(None),
+            r => r,
+        }
+    }
+}
+
+
+
+#[test] fn stream() {
+    let frame = Frame { kind: broker::FrameKind::Response, id: 1, code: 0, payload: std::vec![42] };
+    let mut stream = Stream(io::Cursor::new(std::vec::Vec::new()));
+    stream.send(&frame).unwrap();
+    stream.send(&frame).unwrap();
+    stream.0.set_position(0);
+    assert_eq!(stream.recv().unwrap().as_ref(), Some(&frame));
+    assert_eq!(stream.recv().unwrap().as_ref(), Some(&frame));
+    assert_eq!(stream.recv().unwrap(), None);
+}
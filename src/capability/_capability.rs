@@ -22,954 +22,989 @@
 
 use crate::prelude::*;
 
+use core::fmt::{self, Debug, Formatter};
 
+mod capability_derive;                  pub use capability_derive::*;
 
-/// <code>\([id](Self::id)(), [sid](Self::sid)()\) = \(`"internetClient"`, [sid!]\(S-1-15-3-1\)\)</code>
-#[derive(Clone, Copy, Debug)] #[repr(transparent)] pub struct Constant(CStrNonNull<'static, u16>);
 
-/// ≈ [sid::Box]
-#[derive(Debug)] #[repr(transparent)] pub struct Sid(pub sid::Box<alloc::LocalAllocFree>); // XXX?
-impl core::ops::Deref for Sid { type Target = sid::Box<alloc::LocalAllocFree>; fn deref(&self) -> &Self::Target { &self.0 } }
+
+/// <code>\([name](Self::name)(), [sid](Self::sid)()\) = \(`"internetClient"`, [sid!]\(S-1-15-3-1\)\)</code>
+#[derive(Clone, Copy)] pub struct Constant {
+    name:                   &'static str,
+    #[cfg(windows)] id:     CStrNonNull<'static, u16>,
+    hash:                   [u32; 8], // SHA-256 of the uppercased, UTF-16LE encoded name
+}
+
+impl Debug for Constant { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "Constant({:?})", self.name) } }
 
 impl Constant {
     /// Human readable name like `internetClient`
-    pub fn id(&self) -> CStrNonNull<'static, u16> { self.0 }
+    pub fn name(&self) -> &'static str { self.name }
+
+    /// Human readable name like `internetClient`
+    #[cfg(windows)] pub fn id(&self) -> CStrNonNull<'static, u16> { self.id }
+
+    /// SID like `S-1-15-3-1` or `S-1-15-3-1024-...`
+    ///
+    /// Precomputed: equivalent to the capability SID of [`derive_sids_from_name`], without hashing.
+    pub fn sid(&self) -> sid::Owned { capability_sid(self.name, &self.hash) }
 
-    /// SID like `S-1-15-3-1`
+    /// Group SID like `S-1-5-32-...`
     ///
-    /// [`None`] if [`derive_capability_sids_from_name`] is not supported on this platform.
-    pub fn sid(&self) -> Option<Sid> {
-        let (_groups, mut sids) = derive_capability_sids_from_name(self.0).ok()?;
-        debug_assert!(sids.len() <= 1);
-        Some(Sid(sids.pop()?))
+    /// Precomputed: equivalent to the group SID of [`derive_sids_from_name`], without hashing.
+    pub fn group_sid(&self) -> sid::Owned { group_sid(&self.hash) }
+
+    /// Find the constant with a matching [capability SID](Self::sid) or [group SID](Self::group_sid).
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let internet_client = capability::Constant::from_sid(&*sid!(S-1-15-3-1)).unwrap();
+    /// assert_eq!(internet_client.name(), "internetClient");
+    ///
+    /// let activate_as_user = capability::Constant::from_sid(&*sid!(S-1-15-3-1024-1619559953-3382903645-900470658-1831728285-1265525240-911141481-3610949621-2233473754)).unwrap();
+    /// assert_eq!(activate_as_user.name(), "activateAsUser");
+    ///
+    /// assert!(capability::Constant::from_sid(&*sid!(S-1-5-32-544)).is_none());
+    /// ```
+    pub fn from_sid(sid: &sid::Value) -> Option<Self> {
+        let hash = match (sid.authority_u64(), sid.subauthorities()) {
+            (15, &[3, rid])                     => return ALL.iter().copied().find(|c| legacy_rid(c.name) == Some(rid)),
+            (15, &[3, 1024, ref hash @ ..])     => hash,
+            (5,  &[32, ref hash @ ..])          => hash,
+            _                                   => return None,
+        };
+        ALL.iter().copied().find(|c| c.hash[..] == *hash)
     }
 }
 
-macro_rules! constants { ( $( pub const $id:ident = $label:tt; )* ) => {
+macro_rules! constants { ( $( pub const $id:ident = $label:tt [$($hash:literal),+ $(,)?]; )* ) => {
     // TODO: doc attr support
     $(
         #[doc = concat!("`\"", $label, "\"`")]
-        pub const $id : Constant = Constant(cstr16!($label));
+        pub const $id : Constant = Constant { name: $label, #[cfg(windows)] id: cstr16!($label), hash: [$($hash),+] };
     )*
+
+    /// Every capability constant in this module
+    pub const ALL : &[Constant] = &[$($id),*];
 }}
 
 constants! {
-    pub const ID_CAP_ACCESSIBILITY_CLIENT                           = "ID_CAP_ACCESSIBILITY_CLIENT";
-    pub const ID_CAP_ACCESS_FAMILY_NOTES_API                        = "ID_CAP_ACCESS_FAMILY_NOTES_API";
-    pub const ID_CAP_ACCESS_REMINDER_IMAGES                         = "ID_CAP_ACCESS_REMINDER_IMAGES";
-    pub const ID_CAP_ACC_MGR_ADMIN                                  = "ID_CAP_ACC_MGR_ADMIN";
-    pub const ID_CAP_ADAPTIVE_BRIGHTNESS_CONTROL                    = "ID_CAP_ADAPTIVE_BRIGHTNESS_CONTROL";
-    pub const ID_CAP_ADVERTISING_CONFIG                             = "ID_CAP_ADVERTISING_CONFIG";
-    pub const ID_CAP_AIS_TOKEN_MANAGER                              = "ID_CAP_AIS_TOKEN_MANAGER";
-    pub const ID_CAP_APPCONTAINER_PACKAGE_CERTIFICATES              = "ID_CAP_APPCONTAINER_PACKAGE_CERTIFICATES";
-    pub const ID_CAP_APPOINTMENTS                                   = "ID_CAP_APPOINTMENTS";
-    pub const ID_CAP_APPPREINSTALL_DIRECTORY                        = "ID_CAP_APPPREINSTALL_DIRECTORY";
-    pub const ID_CAP_APPPREINSTALL_EVENTS                           = "ID_CAP_APPPREINSTALL_EVENTS";
-    pub const ID_CAP_APPRESOLVER                                    = "ID_CAP_APPRESOLVER";
-    pub const ID_CAP_APPX_EXECUTION                                 = "ID_CAP_APPX_EXECUTION";
-    pub const ID_CAP_APP_STORE_PURCHASE_HISTORY                     = "ID_CAP_APP_STORE_PURCHASE_HISTORY";
-    pub const ID_CAP_AUDIO_EVENTSND                                 = "ID_CAP_AUDIO_EVENTSND";
-    pub const ID_CAP_AUDIO_INTERNAL                                 = "ID_CAP_AUDIO_INTERNAL";
-    pub const ID_CAP_AUDIO_ROUTING_CONTROLLER                       = "ID_CAP_AUDIO_ROUTING_CONTROLLER";
-    pub const ID_CAP_AUDIO_SETTINGS                                 = "ID_CAP_AUDIO_SETTINGS";
-    pub const ID_CAP_AUTHHOST                                       = "ID_CAP_AUTHHOST";
-    pub const ID_CAP_BACKGROUND_EXECUTION_MANAGEMENT                = "ID_CAP_BACKGROUND_EXECUTION_MANAGEMENT";
-    pub const ID_CAP_BACKGROUND_SETTINGS_MANAGEMENT                 = "ID_CAP_BACKGROUND_SETTINGS_MANAGEMENT";
-    pub const ID_CAP_BACKGROUND_WORKER                              = "ID_CAP_BACKGROUND_WORKER";
-    pub const ID_CAP_BACKUPROAMRESTORE                              = "ID_CAP_BACKUPROAMRESTORE";
-    pub const ID_CAP_BASEOS_UPDATEAPI                               = "ID_CAP_BASEOS_UPDATEAPI";
-    pub const ID_CAP_BINGCLIENT_BINGCONFIGURATION                   = "ID_CAP_BINGCLIENT_BINGCONFIGURATION";
-    pub const ID_CAP_BINGCLIENT_CA_INTERESTEXTRACTION               = "ID_CAP_BINGCLIENT_CA_INTERESTEXTRACTION";
-    pub const ID_CAP_BINGCLIENT_DDC                                 = "ID_CAP_BINGCLIENT_DDC";
-    pub const ID_CAP_BINGCLIENT_IDENTITY                            = "ID_CAP_BINGCLIENT_IDENTITY";
-    pub const ID_CAP_BINGCLIENT_OSS                                 = "ID_CAP_BINGCLIENT_OSS";
-    pub const ID_CAP_BINGCLIENT_SUGGESTSHIGHLIGHTS                  = "ID_CAP_BINGCLIENT_SUGGESTSHIGHLIGHTS";
-    pub const ID_CAP_BINGCLIENT_TEE                                 = "ID_CAP_BINGCLIENT_TEE";
-    pub const ID_CAP_BLUETOOTH                                      = "ID_CAP_BLUETOOTH";
-    pub const ID_CAP_BLUETOOTH_ADMIN                                = "ID_CAP_BLUETOOTH_ADMIN";
-    pub const ID_CAP_BMR2_MONITOR_SERVICE                           = "ID_CAP_BMR2_MONITOR_SERVICE";
-    pub const ID_CAP_BMR_CONFIGURATION                              = "ID_CAP_BMR_CONFIGURATION";
-    pub const ID_CAP_BMR_SYNC                                       = "ID_CAP_BMR_SYNC";
-    pub const ID_CAP_BROKER_NAVIGATION                              = "ID_CAP_BROKER_NAVIGATION";
-    pub const ID_CAP_BROWSER_ACCESSIBILITY_SETTINGS                 = "ID_CAP_BROWSER_ACCESSIBILITY_SETTINGS";
-    pub const ID_CAP_BROWSER_FEATURE_CONTROL_KEYS                   = "ID_CAP_BROWSER_FEATURE_CONTROL_KEYS";
-    pub const ID_CAP_BSS_AIM_INTERFACE                              = "ID_CAP_BSS_AIM_INTERFACE";
-    pub const ID_CAP_BSS_NABSYNC_INTERFACE                          = "ID_CAP_BSS_NABSYNC_INTERFACE";
-    pub const ID_CAP_BSS_PUSH_INTERFACE                             = "ID_CAP_BSS_PUSH_INTERFACE";
-    pub const ID_CAP_BSS_REMINDER_INTERFACE                         = "ID_CAP_BSS_REMINDER_INTERFACE";
-    pub const ID_CAP_BUILTIN_BASEPRIORITY                           = "ID_CAP_BUILTIN_BASEPRIORITY";
-    pub const ID_CAP_BUILTIN_CREATEGLOBAL                           = "ID_CAP_BUILTIN_CREATEGLOBAL";
-    pub const ID_CAP_BUILTIN_CREATEPERMANENT                        = "ID_CAP_BUILTIN_CREATEPERMANENT";
-    pub const ID_CAP_BUILTIN_DEFAULT                                = "ID_CAP_BUILTIN_DEFAULT";
-    pub const ID_CAP_BUILTIN_IMPERSONATE                            = "ID_CAP_BUILTIN_IMPERSONATE";
-    pub const ID_CAP_BUILTIN_PROFILE                                = "ID_CAP_BUILTIN_PROFILE";
-    pub const ID_CAP_BUILTIN_SETTIME                                = "ID_CAP_BUILTIN_SETTIME";
-    pub const ID_CAP_BUILTIN_SHUTDOWN                               = "ID_CAP_BUILTIN_SHUTDOWN";
-    pub const ID_CAP_BUILTIN_SYMBOLICLINK                           = "ID_CAP_BUILTIN_SYMBOLICLINK";
-    pub const ID_CAP_BUILTIN_TCB                                    = "ID_CAP_BUILTIN_TCB";
-    pub const ID_CAP_BUTTONS                                        = "ID_CAP_BUTTONS";
-    pub const ID_CAP_CALLMESSAGING_FILTER                           = "ID_CAP_CALLMESSAGING_FILTER";
-    pub const ID_CAP_CAMERA                                         = "ID_CAP_CAMERA";
-    pub const ID_CAP_CA_ACTIONURI_TEST_EVENT                        = "ID_CAP_CA_ACTIONURI_TEST_EVENT";
-    pub const ID_CAP_CA_ADMIN                                       = "ID_CAP_CA_ADMIN";
-    pub const ID_CAP_CA_BACKGROUND_PROCESSOR                        = "ID_CAP_CA_BACKGROUND_PROCESSOR";
-    pub const ID_CAP_CA_BACKGROUND_PROCESSOR_ADMIN                  = "ID_CAP_CA_BACKGROUND_PROCESSOR_ADMIN";
-    pub const ID_CAP_CA_CONTEXT                                     = "ID_CAP_CA_CONTEXT";
-    pub const ID_CAP_CA_DND_MANAGER                                 = "ID_CAP_CA_DND_MANAGER";
-    pub const ID_CAP_CA_ENABLED                                     = "ID_CAP_CA_ENABLED";
-    pub const ID_CAP_CA_HISTORY                                     = "ID_CAP_CA_HISTORY";
-    pub const ID_CAP_CA_RULES                                       = "ID_CAP_CA_RULES";
-    pub const ID_CAP_CA_SIGNALS_GENERIC                             = "ID_CAP_CA_SIGNALS_GENERIC";
-    pub const ID_CAP_CA_SIGNALS_MANAGER                             = "ID_CAP_CA_SIGNALS_MANAGER";
-    pub const ID_CAP_CA_SIGNALS_MANAGER_ADMIN                       = "ID_CAP_CA_SIGNALS_MANAGER_ADMIN";
-    pub const ID_CAP_CA_UPLOAD_FOLDER                               = "ID_CAP_CA_UPLOAD_FOLDER";
-    pub const ID_CAP_CELLUX_CONFIG_READ                             = "ID_CAP_CELLUX_CONFIG_READ";
-    pub const ID_CAP_CELL_API_ADMIN                                 = "ID_CAP_CELL_API_ADMIN";
-    pub const ID_CAP_CELL_API_COMMON                                = "ID_CAP_CELL_API_COMMON";
-    pub const ID_CAP_CELL_API_LOCATION                              = "ID_CAP_CELL_API_LOCATION";
-    pub const ID_CAP_CELL_API_MESSAGING                             = "ID_CAP_CELL_API_MESSAGING";
-    pub const ID_CAP_CELL_API_MODEMLOGGING                          = "ID_CAP_CELL_API_MODEMLOGGING";
-    pub const ID_CAP_CELL_API_OEM_PASSTHROUGH                       = "ID_CAP_CELL_API_OEM_PASSTHROUGH";
-    pub const ID_CAP_CELL_API_TELEPHONY                             = "ID_CAP_CELL_API_TELEPHONY";
-    pub const ID_CAP_CELL_API_UICC                                  = "ID_CAP_CELL_API_UICC";
-    pub const ID_CAP_CELL_API_UICC_LOWLEVEL                         = "ID_CAP_CELL_API_UICC_LOWLEVEL";
-    pub const ID_CAP_CELL_CELLMGR                                   = "ID_CAP_CELL_CELLMGR";
-    pub const ID_CAP_CELL_CMCSPWWAN_PLUS                            = "ID_CAP_CELL_CMCSPWWAN_PLUS";
-    pub const ID_CAP_CELL_MSFT_UICC_DATASTORE                       = "ID_CAP_CELL_MSFT_UICC_DATASTORE";
-    pub const ID_CAP_CELL_OEM_UICC_DATASTORE                        = "ID_CAP_CELL_OEM_UICC_DATASTORE";
-    pub const ID_CAP_CELL_RCSPRESENCE                               = "ID_CAP_CELL_RCSPRESENCE";
-    pub const ID_CAP_CELL_VIDEOTELEPHONY                            = "ID_CAP_CELL_VIDEOTELEPHONY";
-    pub const ID_CAP_CELL_WNF                                       = "ID_CAP_CELL_WNF";
-    pub const ID_CAP_CELL_WNF_ADMIN                                 = "ID_CAP_CELL_WNF_ADMIN";
-    pub const ID_CAP_CELL_WNF_PII                                   = "ID_CAP_CELL_WNF_PII";
-    pub const ID_CAP_CHAMBER_PROFILE_CODE_INSTALLTEMP_RWD           = "ID_CAP_CHAMBER_PROFILE_CODE_INSTALLTEMP_RWD";
-    pub const ID_CAP_CHAMBER_PROFILE_CODE_NITEMP_RW                 = "ID_CAP_CHAMBER_PROFILE_CODE_NITEMP_RW";
-    pub const ID_CAP_CHAMBER_PROFILE_CODE_R                         = "ID_CAP_CHAMBER_PROFILE_CODE_R";
-    pub const ID_CAP_CHAMBER_PROFILE_CODE_RW                        = "ID_CAP_CHAMBER_PROFILE_CODE_RW";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_LIVETILES_RWD             = "ID_CAP_CHAMBER_PROFILE_DATA_LIVETILES_RWD";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_MEDIA_RWD                 = "ID_CAP_CHAMBER_PROFILE_DATA_MEDIA_RWD";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_PLATFORMDATA_ALL          = "ID_CAP_CHAMBER_PROFILE_DATA_PLATFORMDATA_ALL";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_R                         = "ID_CAP_CHAMBER_PROFILE_DATA_R";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_RW                        = "ID_CAP_CHAMBER_PROFILE_DATA_RW";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_R            = "ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_R";
-    pub const ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_RWD          = "ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_RWD";
-    pub const ID_CAP_CLIPBOARD                                      = "ID_CAP_CLIPBOARD";
-    pub const ID_CAP_COMMANDCHANNEL                                 = "ID_CAP_COMMANDCHANNEL";
-    pub const ID_CAP_COMMS_APPLICATIONS                             = "ID_CAP_COMMS_APPLICATIONS";
-    pub const ID_CAP_COMMS_COMMON                                   = "ID_CAP_COMMS_COMMON";
-    pub const ID_CAP_COMMS_SERVICES                                 = "ID_CAP_COMMS_SERVICES";
-    pub const ID_CAP_COMMS_SETTINGS                                 = "ID_CAP_COMMS_SETTINGS";
-    pub const ID_CAP_CONTACTS                                       = "ID_CAP_CONTACTS";
-    pub const ID_CAP_CONTENTSHARING                                 = "ID_CAP_CONTENTSHARING";
-    pub const ID_CAP_CORTANA_RULES_DB                               = "ID_CAP_CORTANA_RULES_DB";
-    pub const ID_CAP_CREATE_PROCESS_IN_CHAMBER                      = "ID_CAP_CREATE_PROCESS_IN_CHAMBER";
-    pub const ID_CAP_CREDENTIAL_COLLECTION_UI                       = "ID_CAP_CREDENTIAL_COLLECTION_UI";
-    pub const ID_CAP_CRITICAL_DATA                                  = "ID_CAP_CRITICAL_DATA";
-    pub const ID_CAP_CSP_BMR_PROVISION                              = "ID_CAP_CSP_BMR_PROVISION";
-    pub const ID_CAP_CSP_DMCLIENT                                   = "ID_CAP_CSP_DMCLIENT";
-    pub const ID_CAP_CSP_FOUNDATION                                 = "ID_CAP_CSP_FOUNDATION";
-    pub const ID_CAP_CSP_LOCATION                                   = "ID_CAP_CSP_LOCATION";
-    pub const ID_CAP_CSP_MAIL                                       = "ID_CAP_CSP_MAIL";
-    pub const ID_CAP_CSP_NODECACHE                                  = "ID_CAP_CSP_NODECACHE";
-    pub const ID_CAP_CSP_OEM                                        = "ID_CAP_CSP_OEM";
-    pub const ID_CAP_CSP_PHONE                                      = "ID_CAP_CSP_PHONE";
-    pub const ID_CAP_CSP_W4_APPLICATION                             = "ID_CAP_CSP_W4_APPLICATION";
-    pub const ID_CAP_CSP_WIFI_HOTSPOT                               = "ID_CAP_CSP_WIFI_HOTSPOT";
-    pub const ID_CAP_DATACOLLECTION_ACTIVITY                        = "ID_CAP_DATACOLLECTION_ACTIVITY";
-    pub const ID_CAP_DATACOLLECTION_COLLECTOR                       = "ID_CAP_DATACOLLECTION_COLLECTOR";
-    pub const ID_CAP_DATACOLLECTION_RAWETW                          = "ID_CAP_DATACOLLECTION_RAWETW";
-    pub const ID_CAP_DATAPLANUSAGE                                  = "ID_CAP_DATAPLANUSAGE";
-    pub const ID_CAP_DATAPLANUSAGE_ADMIN                            = "ID_CAP_DATAPLANUSAGE_ADMIN";
-    pub const ID_CAP_DCP                                            = "ID_CAP_DCP";
-    pub const ID_CAP_DEBUG                                          = "ID_CAP_DEBUG";
-    pub const ID_CAP_DEBUG_FOLDERS                                  = "ID_CAP_DEBUG_FOLDERS";
-    pub const ID_CAP_DEBUG_NAVIGATION                               = "ID_CAP_DEBUG_NAVIGATION";
-    pub const ID_CAP_DEVELOPERUNLOCK                                = "ID_CAP_DEVELOPERUNLOCK";
-    pub const ID_CAP_DEVELOPERUNLOCK_API                            = "ID_CAP_DEVELOPERUNLOCK_API";
-    pub const ID_CAP_DEVELOPERUNLOCK_CODEDUI                        = "ID_CAP_DEVELOPERUNLOCK_CODEDUI";
-    pub const ID_CAP_DEVICE_LOCK                                    = "ID_CAP_DEVICE_LOCK";
-    pub const ID_CAP_DEVICE_LOCK_ADMIN                              = "ID_CAP_DEVICE_LOCK_ADMIN";
-    pub const ID_CAP_DEVICE_MANAGEMENT                              = "ID_CAP_DEVICE_MANAGEMENT";
-    pub const ID_CAP_DEVICE_MANAGEMENT_ADMIN                        = "ID_CAP_DEVICE_MANAGEMENT_ADMIN";
-    pub const ID_CAP_DEVICE_MANAGEMENT_BOOTSTRAP                    = "ID_CAP_DEVICE_MANAGEMENT_BOOTSTRAP";
-    pub const ID_CAP_DEVICE_MANAGEMENT_SECURITY_POLICIES            = "ID_CAP_DEVICE_MANAGEMENT_SECURITY_POLICIES";
-    pub const ID_CAP_DIAGNOSTIC_CLIENT                              = "ID_CAP_DIAGNOSTIC_CLIENT";
-    pub const ID_CAP_DISPLAY_CONTROL                                = "ID_CAP_DISPLAY_CONTROL";
-    pub const ID_CAP_DMCLIENT_APPMGMT                               = "ID_CAP_DMCLIENT_APPMGMT";
-    pub const ID_CAP_DO_NOT_DISTURB                                 = "ID_CAP_DO_NOT_DISTURB";
-    pub const ID_CAP_DRIVE_MODE_ADMIN                               = "ID_CAP_DRIVE_MODE_ADMIN";
-    pub const ID_CAP_DUASVC                                         = "ID_CAP_DUASVC";
-    pub const ID_CAP_DU_AGENT                                       = "ID_CAP_DU_AGENT";
-    pub const ID_CAP_DU_CORE_API                                    = "ID_CAP_DU_CORE_API";
-    pub const ID_CAP_DU_CSP                                         = "ID_CAP_DU_CSP";
-    pub const ID_CAP_DU_MIGRATION_MANAGER_STATUS                    = "ID_CAP_DU_MIGRATION_MANAGER_STATUS";
-    pub const ID_CAP_DU_MIGRATION_WNF_EVENTS                        = "ID_CAP_DU_MIGRATION_WNF_EVENTS";
-    pub const ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_MICROSOFT      = "ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_MICROSOFT";
-    pub const ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_OEM            = "ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_OEM";
-    pub const ID_CAP_DU_MIGRATOR_STATUS_MICROSOFT                   = "ID_CAP_DU_MIGRATOR_STATUS_MICROSOFT";
-    pub const ID_CAP_DU_MIGRATOR_STATUS_OEM                         = "ID_CAP_DU_MIGRATOR_STATUS_OEM";
-    pub const ID_CAP_DU_PROVISIONING                                = "ID_CAP_DU_PROVISIONING";
-    pub const ID_CAP_DU_SHARED_DATA                                 = "ID_CAP_DU_SHARED_DATA";
-    pub const ID_CAP_DU_USS                                         = "ID_CAP_DU_USS";
-    pub const ID_CAP_DU_UX                                          = "ID_CAP_DU_UX";
-    pub const ID_CAP_DU_UX_FEATURE_DISCOVERY                        = "ID_CAP_DU_UX_FEATURE_DISCOVERY";
-    pub const ID_CAP_EAS_CREDENTIALS                                = "ID_CAP_EAS_CREDENTIALS";
-    pub const ID_CAP_EDM_CACHE_RWDELETE                             = "ID_CAP_EDM_CACHE_RWDELETE";
-    pub const ID_CAP_EDM_CACHE_WRITE                                = "ID_CAP_EDM_CACHE_WRITE";
-    pub const ID_CAP_ENDPOINTDISCOVERY                              = "ID_CAP_ENDPOINTDISCOVERY";
-    pub const ID_CAP_ENROLLMENT                                     = "ID_CAP_ENROLLMENT";
-    pub const ID_CAP_ENROLLMENT_ADMIN                               = "ID_CAP_ENROLLMENT_ADMIN";
-    pub const ID_CAP_ENROLLMENT_POLL                                = "ID_CAP_ENROLLMENT_POLL";
-    pub const ID_CAP_ENROLLMENT_RENEW                               = "ID_CAP_ENROLLMENT_RENEW";
-    pub const ID_CAP_ENTERPRISERESOURCESTORE                        = "ID_CAP_ENTERPRISERESOURCESTORE";
-    pub const ID_CAP_ENTERPRISE_AUTHENTICATION                      = "ID_CAP_ENTERPRISE_AUTHENTICATION";
-    pub const ID_CAP_ENTERPRISE_ENROLLMENT                          = "ID_CAP_ENTERPRISE_ENROLLMENT";
-    pub const ID_CAP_ENTERPRISE_SERVICE                             = "ID_CAP_ENTERPRISE_SERVICE";
-    pub const ID_CAP_ENTERPRISE_SHARED_DATA                         = "ID_CAP_ENTERPRISE_SHARED_DATA";
-    pub const ID_CAP_ETW_PROFILER                                   = "ID_CAP_ETW_PROFILER";
-    pub const ID_CAP_EVERYONE                                       = "ID_CAP_EVERYONE";
-    pub const ID_CAP_EVERYONE_INROM                                 = "ID_CAP_EVERYONE_INROM";
-    pub const ID_CAP_EXTERNAL_DISPLAY                               = "ID_CAP_EXTERNAL_DISPLAY";
-    pub const ID_CAP_FAILURE_REPORT_CONTENT_PROVIDER                = "ID_CAP_FAILURE_REPORT_CONTENT_PROVIDER";
-    pub const ID_CAP_FINDMYPHONE                                    = "ID_CAP_FINDMYPHONE";
-    pub const ID_CAP_FOREGROUND_TASK_MANAGER                        = "ID_CAP_FOREGROUND_TASK_MANAGER";
-    pub const ID_CAP_GAMERSERVICES                                  = "ID_CAP_GAMERSERVICES";
-    pub const ID_CAP_GLOBALIZATION_SETTINGS                         = "ID_CAP_GLOBALIZATION_SETTINGS";
-    pub const ID_CAP_HTTP_ACCEPT_LANGUAGE_HEADER                    = "ID_CAP_HTTP_ACCEPT_LANGUAGE_HEADER";
-    pub const ID_CAP_ICS_RO                                         = "ID_CAP_ICS_RO";
-    pub const ID_CAP_ICS_RW                                         = "ID_CAP_ICS_RW";
-    pub const ID_CAP_IDENTITY_DEVICE                                = "ID_CAP_IDENTITY_DEVICE";
-    pub const ID_CAP_IDENTITY_DEVICE_1ST_PARTY                      = "ID_CAP_IDENTITY_DEVICE_1ST_PARTY";
-    pub const ID_CAP_IDENTITY_USER                                  = "ID_CAP_IDENTITY_USER";
-    pub const ID_CAP_IDENTITY_USER_1ST_PARTY                        = "ID_CAP_IDENTITY_USER_1ST_PARTY";
-    pub const ID_CAP_IDM_IMAGE_CACHE                                = "ID_CAP_IDM_IMAGE_CACHE";
-    pub const ID_CAP_IMMERSIVE_SHELL                                = "ID_CAP_IMMERSIVE_SHELL";
-    pub const ID_CAP_INPUT_CORE                                     = "ID_CAP_INPUT_CORE";
-    pub const ID_CAP_INPUT_FEATURES                                 = "ID_CAP_INPUT_FEATURES";
-    pub const ID_CAP_INPUT_INJECTION                                = "ID_CAP_INPUT_INJECTION";
-    pub const ID_CAP_INPUT_LOCALES                                  = "ID_CAP_INPUT_LOCALES";
-    pub const ID_CAP_INPUT_SERVICE                                  = "ID_CAP_INPUT_SERVICE";
-    pub const ID_CAP_INSTALL_CERTIFICATES                           = "ID_CAP_INSTALL_CERTIFICATES";
-    pub const ID_CAP_INTENTTEXTRACTION_OPTIN                        = "ID_CAP_INTENTTEXTRACTION_OPTIN";
-    pub const ID_CAP_INTERNAL_DEPLOYMENT                            = "ID_CAP_INTERNAL_DEPLOYMENT";
-    pub const ID_CAP_INTERNET_EXPLORER_BROWSER_HISTORY              = "ID_CAP_INTERNET_EXPLORER_BROWSER_HISTORY";
-    pub const ID_CAP_INTERNET_EXPLORER_DATA_OPTIMIZATION            = "ID_CAP_INTERNET_EXPLORER_DATA_OPTIMIZATION";
-    pub const ID_CAP_INTERNET_EXPLORER_FAVORITES                    = "ID_CAP_INTERNET_EXPLORER_FAVORITES";
-    pub const ID_CAP_INTERNET_EXPLORER_HKCU_WRITE                   = "ID_CAP_INTERNET_EXPLORER_HKCU_WRITE";
-    pub const ID_CAP_INTERNET_EXPLORER_HKLM_SECURITY_SETTINGS       = "ID_CAP_INTERNET_EXPLORER_HKLM_SECURITY_SETTINGS";
-    pub const ID_CAP_INTERNET_EXPLORER_INTRANET_ZONE_SETTINGS       = "ID_CAP_INTERNET_EXPLORER_INTRANET_ZONE_SETTINGS";
-    pub const ID_CAP_INTERNET_EXPLORER_REMOTEDEBUGGING              = "ID_CAP_INTERNET_EXPLORER_REMOTEDEBUGGING";
-    pub const ID_CAP_INTERNET_EXPLORER_ROAMING                      = "ID_CAP_INTERNET_EXPLORER_ROAMING";
-    pub const ID_CAP_INTERNET_EXPLORER_SEARCH_PROVIDER_KEYS_HKCU    = "ID_CAP_INTERNET_EXPLORER_SEARCH_PROVIDER_KEYS_HKCU";
-    pub const ID_CAP_INTEROPSERVICES                                = "ID_CAP_INTEROPSERVICES";
-    pub const ID_CAP_ISV_CAMERA                                     = "ID_CAP_ISV_CAMERA";
-    pub const ID_CAP_KEYBOARD                                       = "ID_CAP_KEYBOARD";
-    pub const ID_CAP_KIDZONE_ADMIN                                  = "ID_CAP_KIDZONE_ADMIN";
-    pub const ID_CAP_KIDZONE_CUSTOMIZATION                          = "ID_CAP_KIDZONE_CUSTOMIZATION";
-    pub const ID_CAP_LANGUAGEUNDERSTANDING                          = "ID_CAP_LANGUAGEUNDERSTANDING";
-    pub const ID_CAP_LASS_ADMIN                                     = "ID_CAP_LASS_ADMIN";
-    pub const ID_CAP_LASS_REMOTELOCK                                = "ID_CAP_LASS_REMOTELOCK";
-    pub const ID_CAP_LEGACY_VOICEMAIL_HANDLER                       = "ID_CAP_LEGACY_VOICEMAIL_HANDLER";
-    pub const ID_CAP_LEXICONUPDATE                                  = "ID_CAP_LEXICONUPDATE";
-    pub const ID_CAP_LIVEID                                         = "ID_CAP_LIVEID";
-    pub const ID_CAP_LIVETOKEN_WNF_EVENTS                           = "ID_CAP_LIVETOKEN_WNF_EVENTS";
-    pub const ID_CAP_LOCATION                                       = "ID_CAP_LOCATION";
-    pub const ID_CAP_LOCATION_ADMIN                                 = "ID_CAP_LOCATION_ADMIN";
-    pub const ID_CAP_LOCATION_BTPOLICY                              = "ID_CAP_LOCATION_BTPOLICY";
-    pub const ID_CAP_LOCATION_GNSSDRIVER                            = "ID_CAP_LOCATION_GNSSDRIVER";
-    pub const ID_CAP_MAP                                            = "ID_CAP_MAP";
-    pub const ID_CAP_MAP_ADMIN                                      = "ID_CAP_MAP_ADMIN";
-    pub const ID_CAP_MAP_WRITE                                      = "ID_CAP_MAP_WRITE";
-    pub const ID_CAP_MEDIALIB                                       = "ID_CAP_MEDIALIB";
-    pub const ID_CAP_MEDIALIB_AUDIO                                 = "ID_CAP_MEDIALIB_AUDIO";
-    pub const ID_CAP_MEDIALIB_INT                                   = "ID_CAP_MEDIALIB_INT";
-    pub const ID_CAP_MEDIALIB_PHOTO                                 = "ID_CAP_MEDIALIB_PHOTO";
-    pub const ID_CAP_MEDIALIB_PHOTO_FULL                            = "ID_CAP_MEDIALIB_PHOTO_FULL";
-    pub const ID_CAP_MEDIALIB_PLAYBACK                              = "ID_CAP_MEDIALIB_PLAYBACK";
-    pub const ID_CAP_MEDIALIB_VIDEO                                 = "ID_CAP_MEDIALIB_VIDEO";
-    pub const ID_CAP_MEDIASERVICE_VOLUMELIMIT_INT                   = "ID_CAP_MEDIASERVICE_VOLUMELIMIT_INT";
-    pub const ID_CAP_MICMUTEPOLICY_BYPASS                           = "ID_CAP_MICMUTEPOLICY_BYPASS";
-    pub const ID_CAP_MICROPHONE                                     = "ID_CAP_MICROPHONE";
-    pub const ID_CAP_MONITOR_NAVIGATION                             = "ID_CAP_MONITOR_NAVIGATION";
-    pub const ID_CAP_MOUSE                                          = "ID_CAP_MOUSE";
-    pub const ID_CAP_MO_CLOUDMESSAGING                              = "ID_CAP_MO_CLOUDMESSAGING";
-    pub const ID_CAP_MSS_BYTESTREAM_RPC                             = "ID_CAP_MSS_BYTESTREAM_RPC";
-    pub const ID_CAP_MULTIMEDIA_ENCODER_HARDWARE                    = "ID_CAP_MULTIMEDIA_ENCODER_HARDWARE";
-    pub const ID_CAP_MULTIVARIANT                                   = "ID_CAP_MULTIVARIANT";
-    pub const ID_CAP_MULTIVARIANT_INSTALL_DATA                      = "ID_CAP_MULTIVARIANT_INSTALL_DATA";
-    pub const ID_CAP_NARRATOR_SETTINGS                              = "ID_CAP_NARRATOR_SETTINGS";
-    pub const ID_CAP_NATIVE_NETWORK_REPLACEMENT                     = "ID_CAP_NATIVE_NETWORK_REPLACEMENT";
-    pub const ID_CAP_NAVIGATIONBAR_ADMIN                            = "ID_CAP_NAVIGATIONBAR_ADMIN";
-    pub const ID_CAP_NETWORKING                                     = "ID_CAP_NETWORKING";
-    pub const ID_CAP_NETWORKING_ADMIN                               = "ID_CAP_NETWORKING_ADMIN";
-    pub const ID_CAP_NETWORKING_INTERNET_CLIENT                     = "ID_CAP_NETWORKING_INTERNET_CLIENT";
-    pub const ID_CAP_NETWORKING_INTERNET_CLIENT_SERVER              = "ID_CAP_NETWORKING_INTERNET_CLIENT_SERVER";
-    pub const ID_CAP_NETWORKING_PRIVATE_NETWORK_CLIENT_SERVER       = "ID_CAP_NETWORKING_PRIVATE_NETWORK_CLIENT_SERVER";
-    pub const ID_CAP_NETWORKING_VPN_ADMIN                           = "ID_CAP_NETWORKING_VPN_ADMIN";
-    pub const ID_CAP_NETWORKING_VPN_PROVIDER                        = "ID_CAP_NETWORKING_VPN_PROVIDER";
-    pub const ID_CAP_NETWORKING_VPN_SERVICES                        = "ID_CAP_NETWORKING_VPN_SERVICES";
-    pub const ID_CAP_NFC_ADMIN                                      = "ID_CAP_NFC_ADMIN";
-    pub const ID_CAP_NOCENTER_SOUNDS                                = "ID_CAP_NOCENTER_SOUNDS";
-    pub const ID_CAP_NTSERVICES                                     = "ID_CAP_NTSERVICES";
-    pub const ID_CAP_NVREAD                                         = "ID_CAP_NVREAD";
-    pub const ID_CAP_NVREADWRITE                                    = "ID_CAP_NVREADWRITE";
-    pub const ID_CAP_O365_DISCOVERY                                 = "ID_CAP_O365_DISCOVERY";
-    pub const ID_CAP_OEMPUBLICDIRECTORY                             = "ID_CAP_OEMPUBLICDIRECTORY";
-    pub const ID_CAP_OEM_ADC                                        = "ID_CAP_OEM_ADC";
-    pub const ID_CAP_OEM_CUSTOM                                     = "ID_CAP_OEM_CUSTOM";
-    pub const ID_CAP_OEM_DEPLOYMENT                                 = "ID_CAP_OEM_DEPLOYMENT";
-    pub const ID_CAP_OFFICE_LAUNCH_URL                              = "ID_CAP_OFFICE_LAUNCH_URL";
-    pub const ID_CAP_OFFICE_MSDRM_HKCU                              = "ID_CAP_OFFICE_MSDRM_HKCU";
-    pub const ID_CAP_ONENOTE_EVENTS                                 = "ID_CAP_ONENOTE_EVENTS";
-    pub const ID_CAP_OOBE_PRIVATE                                   = "ID_CAP_OOBE_PRIVATE";
-    pub const ID_CAP_ORIENTATION_LOCK                               = "ID_CAP_ORIENTATION_LOCK";
-    pub const ID_CAP_PEOPLE_EXTENSION                               = "ID_CAP_PEOPLE_EXTENSION";
-    pub const ID_CAP_PEOPLE_EXTENSION_IM                            = "ID_CAP_PEOPLE_EXTENSION_IM";
-    pub const ID_CAP_PEOPLE_EXTENSION_MOBILE                        = "ID_CAP_PEOPLE_EXTENSION_MOBILE";
-    pub const ID_CAP_PERSONA                                        = "ID_CAP_PERSONA";
-    pub const ID_CAP_PERSONAL_INFORMATION_IMPORT                    = "ID_CAP_PERSONAL_INFORMATION_IMPORT";
-    pub const ID_CAP_PHONEBROKER_INTERFACE                          = "ID_CAP_PHONEBROKER_INTERFACE";
-    pub const ID_CAP_PHONEDIALER                                    = "ID_CAP_PHONEDIALER";
-    pub const ID_CAP_PHONEPROVISIONER_DEVICEUPDATE                  = "ID_CAP_PHONEPROVISIONER_DEVICEUPDATE";
-    pub const ID_CAP_PHONEPROVISIONER_EVENTS                        = "ID_CAP_PHONEPROVISIONER_EVENTS";
-    pub const ID_CAP_PHONE_2ND_PARTY                                = "ID_CAP_PHONE_2ND_PARTY";
-    pub const ID_CAP_PHONE_ADMIN                                    = "ID_CAP_PHONE_ADMIN";
-    pub const ID_CAP_PHONE_INTERNAL                                 = "ID_CAP_PHONE_INTERNAL";
-    pub const ID_CAP_PHOTOS_SETTINGS_R                              = "ID_CAP_PHOTOS_SETTINGS_R";
-    pub const ID_CAP_PHOTOS_SETTINGS_RW                             = "ID_CAP_PHOTOS_SETTINGS_RW";
-    pub const ID_CAP_PICKER_CONTRACT_UI                             = "ID_CAP_PICKER_CONTRACT_UI";
-    pub const ID_CAP_PLATFORM_EXTENSIBILITY                         = "ID_CAP_PLATFORM_EXTENSIBILITY";
-    pub const ID_CAP_PLAYREADY                                      = "ID_CAP_PLAYREADY";
-    pub const ID_CAP_PLAYREADY_ADMIN                                = "ID_CAP_PLAYREADY_ADMIN";
-    pub const ID_CAP_PM_1ST_PARTY                                   = "ID_CAP_PM_1ST_PARTY";
-    pub const ID_CAP_PM_BSS                                         = "ID_CAP_PM_BSS";
-    pub const ID_CAP_PM_INSTALL                                     = "ID_CAP_PM_INSTALL";
-    pub const ID_CAP_POIDATASTORE                                   = "ID_CAP_POIDATASTORE";
-    pub const ID_CAP_POIDATASTORE_ADMIN                             = "ID_CAP_POIDATASTORE_ADMIN";
-    pub const ID_CAP_POLICY_MANAGER                                 = "ID_CAP_POLICY_MANAGER";
-    pub const ID_CAP_POLICY_MANAGER_READONLY                        = "ID_CAP_POLICY_MANAGER_READONLY";
-    pub const ID_CAP_POWERNOTIF_USER                                = "ID_CAP_POWERNOTIF_USER";
-    pub const ID_CAP_PRESERVED_DATA                                 = "ID_CAP_PRESERVED_DATA";
-    pub const ID_CAP_PRIV_ABOUTCPL                                  = "ID_CAP_PRIV_ABOUTCPL";
-    pub const ID_CAP_PRIV_ACCESSIBILITYCPL                          = "ID_CAP_PRIV_ACCESSIBILITYCPL";
-    pub const ID_CAP_PRIV_ACCESSORIESCPL                            = "ID_CAP_PRIV_ACCESSORIESCPL";
-    pub const ID_CAP_PRIV_ACCESSORYMGRSVC                           = "ID_CAP_PRIV_ACCESSORYMGRSVC";
-    pub const ID_CAP_PRIV_ACCOUNTPROVSVC                            = "ID_CAP_PRIV_ACCOUNTPROVSVC";
-    pub const ID_CAP_PRIV_ACTIONURIHOST                             = "ID_CAP_PRIV_ACTIONURIHOST";
-    pub const ID_CAP_PRIV_ADVERTISINGIDCPL                          = "ID_CAP_PRIV_ADVERTISINGIDCPL";
-    pub const ID_CAP_PRIV_ALARMS                                    = "ID_CAP_PRIV_ALARMS";
-    pub const ID_CAP_PRIV_APHCHECK                                  = "ID_CAP_PRIV_APHCHECK";
-    pub const ID_CAP_PRIV_APMUX                                     = "ID_CAP_PRIV_APMUX";
-    pub const ID_CAP_PRIV_APPCORNER                                 = "ID_CAP_PRIV_APPCORNER";
-    pub const ID_CAP_PRIV_APPPREINSTALLER                           = "ID_CAP_PRIV_APPPREINSTALLER";
-    pub const ID_CAP_PRIV_APPRESOLVERUI                             = "ID_CAP_PRIV_APPRESOLVERUI";
-    pub const ID_CAP_PRIV_APPSDATAMIGRATOR                          = "ID_CAP_PRIV_APPSDATAMIGRATOR";
-    pub const ID_CAP_PRIV_APPXEXECUTIONSVC                          = "ID_CAP_PRIV_APPXEXECUTIONSVC";
-    pub const ID_CAP_PRIV_AUTHHOST_MSA                              = "ID_CAP_PRIV_AUTHHOST_MSA";
-    pub const ID_CAP_PRIV_AUTHHOST_WAB_A                            = "ID_CAP_PRIV_AUTHHOST_WAB_A";
-    pub const ID_CAP_PRIV_AUTHHOST_WAB_B                            = "ID_CAP_PRIV_AUTHHOST_WAB_B";
-    pub const ID_CAP_PRIV_AUTHHOST_WAB_C                            = "ID_CAP_PRIV_AUTHHOST_WAB_C";
-    pub const ID_CAP_PRIV_AUTHHOST_WAB_ENTERPRISE                   = "ID_CAP_PRIV_AUTHHOST_WAB_ENTERPRISE";
-    pub const ID_CAP_PRIV_AUTHHOST_WAB_SSO                          = "ID_CAP_PRIV_AUTHHOST_WAB_SSO";
-    pub const ID_CAP_PRIV_AUTHHOST_WAB_SSO_ENTERPRISE               = "ID_CAP_PRIV_AUTHHOST_WAB_SSO_ENTERPRISE";
-    pub const ID_CAP_PRIV_AUTOTIMEUPDATE                            = "ID_CAP_PRIV_AUTOTIMEUPDATE";
-    pub const ID_CAP_PRIV_BATTERYSAVERCPL                           = "ID_CAP_PRIV_BATTERYSAVERCPL";
-    pub const ID_CAP_PRIV_BLUETOOTHPBAPSVC                          = "ID_CAP_PRIV_BLUETOOTHPBAPSVC";
-    pub const ID_CAP_PRIV_BMR2MONITORSVC                            = "ID_CAP_PRIV_BMR2MONITORSVC";
-    pub const ID_CAP_PRIV_BMR2SCHEDULETRIGGER                       = "ID_CAP_PRIV_BMR2SCHEDULETRIGGER";
-    pub const ID_CAP_PRIV_BMRCPL                                    = "ID_CAP_PRIV_BMRCPL";
-    pub const ID_CAP_PRIV_BMRSCHEDULETRIGGER                        = "ID_CAP_PRIV_BMRSCHEDULETRIGGER";
-    pub const ID_CAP_PRIV_BRIGHTNESSCPL                             = "ID_CAP_PRIV_BRIGHTNESSCPL";
-    pub const ID_CAP_PRIV_BSSVC                                     = "ID_CAP_PRIV_BSSVC";
-    pub const ID_CAP_PRIV_BTAGSERVICE                               = "ID_CAP_PRIV_BTAGSERVICE";
-    pub const ID_CAP_PRIV_BTCONNMGR                                 = "ID_CAP_PRIV_BTCONNMGR";
-    pub const ID_CAP_PRIV_BTHAVCTPSVC                               = "ID_CAP_PRIV_BTHAVCTPSVC";
-    pub const ID_CAP_PRIV_BTSERV                                    = "ID_CAP_PRIV_BTSERV";
-    pub const ID_CAP_PRIV_BTUXCPL                                   = "ID_CAP_PRIV_BTUXCPL";
-    pub const ID_CAP_PRIV_CALC7                                     = "ID_CAP_PRIV_CALC7";
-    pub const ID_CAP_PRIV_CAPTURESVC                                = "ID_CAP_PRIV_CAPTURESVC";
-    pub const ID_CAP_PRIV_CASVCSHARED3                              = "ID_CAP_PRIV_CASVCSHARED3";
-    pub const ID_CAP_PRIV_CELLMANAGER                               = "ID_CAP_PRIV_CELLMANAGER";
-    pub const ID_CAP_PRIV_CELLULARDATACOLLECTOR                     = "ID_CAP_PRIV_CELLULARDATACOLLECTOR";
-    pub const ID_CAP_PRIV_CELLUX                                    = "ID_CAP_PRIV_CELLUX";
-    pub const ID_CAP_PRIV_CERTINSTALLER                             = "ID_CAP_PRIV_CERTINSTALLER";
-    pub const ID_CAP_PRIV_CFMSVC                                    = "ID_CAP_PRIV_CFMSVC";
-    pub const ID_CAP_PRIV_CGSVC                                     = "ID_CAP_PRIV_CGSVC";
-    pub const ID_CAP_PRIV_CLOUDSTORAGECPL                           = "ID_CAP_PRIV_CLOUDSTORAGECPL";
-    pub const ID_CAP_PRIV_CMSERVICE                                 = "ID_CAP_PRIV_CMSERVICE";
-    pub const ID_CAP_PRIV_COMMANDCHANNEL                            = "ID_CAP_PRIV_COMMANDCHANNEL";
-    pub const ID_CAP_PRIV_COMMSAPHOST                               = "ID_CAP_PRIV_COMMSAPHOST";
-    pub const ID_CAP_PRIV_COMMSAPPLICATIONS                         = "ID_CAP_PRIV_COMMSAPPLICATIONS";
-    pub const ID_CAP_PRIV_COMMSCERTINSTSVC                          = "ID_CAP_PRIV_COMMSCERTINSTSVC";
-    pub const ID_CAP_PRIV_COMMSMESSAGESVC                           = "ID_CAP_PRIV_COMMSMESSAGESVC";
-    pub const ID_CAP_PRIV_COMMSMMSTRANSPORT                         = "ID_CAP_PRIV_COMMSMMSTRANSPORT";
-    pub const ID_CAP_PRIV_CONTACTSTOKENSVC                          = "ID_CAP_PRIV_CONTACTSTOKENSVC";
-    pub const ID_CAP_PRIV_CONTENTSHARESVC                           = "ID_CAP_PRIV_CONTENTSHARESVC";
-    pub const ID_CAP_PRIV_CONTENTSHARINGAPP                         = "ID_CAP_PRIV_CONTENTSHARINGAPP";
-    pub const ID_CAP_PRIV_COREUIREGISTRAR                           = "ID_CAP_PRIV_COREUIREGISTRAR";
-    pub const ID_CAP_PRIV_DACCERTINSTSVC                            = "ID_CAP_PRIV_DACCERTINSTSVC";
-    pub const ID_CAP_PRIV_DATACOLLECTION                            = "ID_CAP_PRIV_DATACOLLECTION";
-    pub const ID_CAP_PRIV_DATASENSESVC                              = "ID_CAP_PRIV_DATASENSESVC";
-    pub const ID_CAP_PRIV_DATASMART                                 = "ID_CAP_PRIV_DATASMART";
-    pub const ID_CAP_PRIV_DATETIMECPL                               = "ID_CAP_PRIV_DATETIMECPL";
-    pub const ID_CAP_PRIV_DCPSVC                                    = "ID_CAP_PRIV_DCPSVC";
-    pub const ID_CAP_PRIV_DEBUGGERMUXNOTIFY                         = "ID_CAP_PRIV_DEBUGGERMUXNOTIFY";
-    pub const ID_CAP_PRIV_DIAGNOSTICSVC                             = "ID_CAP_PRIV_DIAGNOSTICSVC";
-    pub const ID_CAP_PRIV_DMCFGHOST                                 = "ID_CAP_PRIV_DMCFGHOST";
-    pub const ID_CAP_PRIV_DMOMACPNETWMO                             = "ID_CAP_PRIV_DMOMACPNETWMO";
-    pub const ID_CAP_PRIV_DMOMACPUSERMO                             = "ID_CAP_PRIV_DMOMACPUSERMO";
-    pub const ID_CAP_PRIV_DMWAPPUSHSVC                              = "ID_CAP_PRIV_DMWAPPUSHSVC";
-    pub const ID_CAP_PRIV_DRIVINGMODEMANAGER                        = "ID_CAP_PRIV_DRIVINGMODEMANAGER";
-    pub const ID_CAP_PRIV_DRIVINGMODESETTINGS                       = "ID_CAP_PRIV_DRIVINGMODESETTINGS";
-    pub const ID_CAP_PRIV_DSSVC                                     = "ID_CAP_PRIV_DSSVC";
-    pub const ID_CAP_PRIV_DSTOKENCLEAN                              = "ID_CAP_PRIV_DSTOKENCLEAN";
-    pub const ID_CAP_PRIV_DUACALLBACK                               = "ID_CAP_PRIV_DUACALLBACK";
-    pub const ID_CAP_PRIV_DUACLIENT                                 = "ID_CAP_PRIV_DUACLIENT";
-    pub const ID_CAP_PRIV_DUCLEANUPMIGRATOR                         = "ID_CAP_PRIV_DUCLEANUPMIGRATOR";
-    pub const ID_CAP_PRIV_DUFEATUREDISCOVERY                        = "ID_CAP_PRIV_DUFEATUREDISCOVERY";
-    pub const ID_CAP_PRIV_DUMIGRATIONMANAGER                        = "ID_CAP_PRIV_DUMIGRATIONMANAGER";
-    pub const ID_CAP_PRIV_DUMIGRATIONPROVISIONERMICROSOFT           = "ID_CAP_PRIV_DUMIGRATIONPROVISIONERMICROSOFT";
-    pub const ID_CAP_PRIV_DUMIGRATIONPROVISIONEROEM                 = "ID_CAP_PRIV_DUMIGRATIONPROVISIONEROEM";
-    pub const ID_CAP_PRIV_DUPOSTUPDATEUX                            = "ID_CAP_PRIV_DUPOSTUPDATEUX";
-    pub const ID_CAP_PRIV_DUSTARTINGMIGRATOR                        = "ID_CAP_PRIV_DUSTARTINGMIGRATOR";
-    pub const ID_CAP_PRIV_DUSVC                                     = "ID_CAP_PRIV_DUSVC";
-    pub const ID_CAP_PRIV_ENROLLMENTCLIENT                          = "ID_CAP_PRIV_ENROLLMENTCLIENT";
-    pub const ID_CAP_PRIV_ENTAPPSERVICE                             = "ID_CAP_PRIV_ENTAPPSERVICE";
-    pub const ID_CAP_PRIV_ENTERPRISEINSTALL                         = "ID_CAP_PRIV_ENTERPRISEINSTALL";
-    pub const ID_CAP_PRIV_ENTERPRISEMGMSVC                          = "ID_CAP_PRIV_ENTERPRISEMGMSVC";
-    pub const ID_CAP_PRIV_ENTERPRISERING                            = "ID_CAP_PRIV_ENTERPRISERING";
-    pub const ID_CAP_PRIV_ENTERPRISEVALIDATION                      = "ID_CAP_PRIV_ENTERPRISEVALIDATION";
-    pub const ID_CAP_PRIV_EXECMANSERVICE                            = "ID_CAP_PRIV_EXECMANSERVICE";
-    pub const ID_CAP_PRIV_FINDMYPHONE                               = "ID_CAP_PRIV_FINDMYPHONE";
-    pub const ID_CAP_PRIV_FLYOUTDATAMIGRATOR                        = "ID_CAP_PRIV_FLYOUTDATAMIGRATOR";
-    pub const ID_CAP_PRIV_GROVELER                                  = "ID_CAP_PRIV_GROVELER";
-    pub const ID_CAP_PRIV_GWPCENROLLSVC                             = "ID_CAP_PRIV_GWPCENROLLSVC";
-    pub const ID_CAP_PRIV_HFA                                       = "ID_CAP_PRIV_HFA";
-    pub const ID_CAP_PRIV_HOTSPOTHOST                               = "ID_CAP_PRIV_HOTSPOTHOST";
-    pub const ID_CAP_PRIV_HUBTILERESTOREHOST                        = "ID_CAP_PRIV_HUBTILERESTOREHOST";
-    pub const ID_CAP_PRIV_ICSENTITLEMENTHOST                        = "ID_CAP_PRIV_ICSENTITLEMENTHOST";
-    pub const ID_CAP_PRIV_ICSSVC                                    = "ID_CAP_PRIV_ICSSVC";
-    pub const ID_CAP_PRIV_INPUTSERVICE                              = "ID_CAP_PRIV_INPUTSERVICE";
-    pub const ID_CAP_PRIV_INSTALLERWORKER                           = "ID_CAP_PRIV_INSTALLERWORKER";
-    pub const ID_CAP_PRIV_INTERNETEXPLORER                          = "ID_CAP_PRIV_INTERNETEXPLORER";
-    pub const ID_CAP_PRIV_IPOVERUSB                                 = "ID_CAP_PRIV_IPOVERUSB";
-    pub const ID_CAP_PRIV_KEYBOARDCPL                               = "ID_CAP_PRIV_KEYBOARDCPL";
-    pub const ID_CAP_PRIV_KIDZONECONFIGURATION                      = "ID_CAP_PRIV_KIDZONECONFIGURATION";
-    pub const ID_CAP_PRIV_KIDZONECUSTOMIZATION                      = "ID_CAP_PRIV_KIDZONECUSTOMIZATION";
-    pub const ID_CAP_PRIV_LASSCREDENTIALEXPIRATIONCHECK             = "ID_CAP_PRIV_LASSCREDENTIALEXPIRATIONCHECK";
-    pub const ID_CAP_PRIV_LAUNCHAPPSVC                              = "ID_CAP_PRIV_LAUNCHAPPSVC";
-    pub const ID_CAP_PRIV_LEXICONUPDATE                             = "ID_CAP_PRIV_LEXICONUPDATE";
-    pub const ID_CAP_PRIV_LFSVC                                     = "ID_CAP_PRIV_LFSVC";
-    pub const ID_CAP_PRIV_LIVETOKEN                                 = "ID_CAP_PRIV_LIVETOKEN";
-    pub const ID_CAP_PRIV_LOCATIONUXCPL                             = "ID_CAP_PRIV_LOCATIONUXCPL";
-    pub const ID_CAP_PRIV_LOCKANDWALLPAPER                          = "ID_CAP_PRIV_LOCKANDWALLPAPER";
-    pub const ID_CAP_PRIV_MEDIA                                     = "ID_CAP_PRIV_MEDIA";
-    pub const ID_CAP_PRIV_MEDIASERVICE                              = "ID_CAP_PRIV_MEDIASERVICE";
-    pub const ID_CAP_PRIV_MIRRORCPL                                 = "ID_CAP_PRIV_MIRRORCPL";
-    pub const ID_CAP_PRIV_MOBILEUI                                  = "ID_CAP_PRIV_MOBILEUI";
-    pub const ID_CAP_PRIV_MOSHOST                                   = "ID_CAP_PRIV_MOSHOST";
-    pub const ID_CAP_PRIV_MSATICKETSVC                              = "ID_CAP_PRIV_MSATICKETSVC";
-    pub const ID_CAP_PRIV_MSGIMTRANSPORT                            = "ID_CAP_PRIV_MSGIMTRANSPORT";
-    pub const ID_CAP_PRIV_MSGSMSTRANSPORT                           = "ID_CAP_PRIV_MSGSMSTRANSPORT";
-    pub const ID_CAP_PRIV_MTP                                       = "ID_CAP_PRIV_MTP";
-    pub const ID_CAP_PRIV_MVPROVISIONHOST                           = "ID_CAP_PRIV_MVPROVISIONHOST";
-    pub const ID_CAP_PRIV_MVUX                                      = "ID_CAP_PRIV_MVUX";
-    pub const ID_CAP_PRIV_NABSYNC                                   = "ID_CAP_PRIV_NABSYNC";
-    pub const ID_CAP_PRIV_NOCENTERSETTINGSCPL                       = "ID_CAP_PRIV_NOCENTERSETTINGSCPL";
-    pub const ID_CAP_PRIV_NOTIFICATIONPLATFORMMIGRATOR              = "ID_CAP_PRIV_NOTIFICATIONPLATFORMMIGRATOR";
-    pub const ID_CAP_PRIV_NOTIFSVC                                  = "ID_CAP_PRIV_NOTIFSVC";
-    pub const ID_CAP_PRIV_OFFICE                                    = "ID_CAP_PRIV_OFFICE";
-    pub const ID_CAP_PRIV_OMADMCLIENT_ENTERPRISE                    = "ID_CAP_PRIV_OMADMCLIENT_ENTERPRISE";
-    pub const ID_CAP_PRIV_OMADMCLIENT_MOBILE_OPERATOR               = "ID_CAP_PRIV_OMADMCLIENT_MOBILE_OPERATOR";
-    pub const ID_CAP_PRIV_OMADMPRC                                  = "ID_CAP_PRIV_OMADMPRC";
-    pub const ID_CAP_PRIV_OOBE                                      = "ID_CAP_PRIV_OOBE";
-    pub const ID_CAP_PRIV_ORIENTSRV                                 = "ID_CAP_PRIV_ORIENTSRV";
-    pub const ID_CAP_PRIV_PACMANSERVICE                             = "ID_CAP_PRIV_PACMANSERVICE";
-    pub const ID_CAP_PRIV_PHONEAUDIOSRV                             = "ID_CAP_PRIV_PHONEAUDIOSRV";
-    pub const ID_CAP_PRIV_PHONEPROVISIONER                          = "ID_CAP_PRIV_PHONEPROVISIONER";
-    pub const ID_CAP_PRIV_PHONEPROVISIONER_OEM                      = "ID_CAP_PRIV_PHONEPROVISIONER_OEM";
-    pub const ID_CAP_PRIV_PHONESVCSG                                = "ID_CAP_PRIV_PHONESVCSG";
-    pub const ID_CAP_PRIV_PHOTOS                                    = "ID_CAP_PRIV_PHOTOS";
-    pub const ID_CAP_PRIV_PHOTOSSVC                                 = "ID_CAP_PRIV_PHOTOSSVC";
-    pub const ID_CAP_PRIV_PIMIDXMAINT                               = "ID_CAP_PRIV_PIMIDXMAINT";
-    pub const ID_CAP_PRIV_PLACESSVC                                 = "ID_CAP_PRIV_PLACESSVC";
-    pub const ID_CAP_PRIV_POSTDUAPPMIGRATOR                         = "ID_CAP_PRIV_POSTDUAPPMIGRATOR";
-    pub const ID_CAP_PRIV_POWERNOTIF                                = "ID_CAP_PRIV_POWERNOTIF";
-    pub const ID_CAP_PRIV_PROXIMITYSVC                              = "ID_CAP_PRIV_PROXIMITYSVC";
-    pub const ID_CAP_PRIV_PROXYSVC                                  = "ID_CAP_PRIV_PROXYSVC";
-    pub const ID_CAP_PRIV_REALWORLD_BINGCLIENT                      = "ID_CAP_PRIV_REALWORLD-BINGCLIENT";
-    pub const ID_CAP_PRIV_REALWORLD_INTERESTEXTRACTION              = "ID_CAP_PRIV_REALWORLD-INTERESTEXTRACTION";
-    pub const ID_CAP_PRIV_REBOOTDEVICE                              = "ID_CAP_PRIV_REBOOTDEVICE";
-    pub const ID_CAP_PRIV_REGIONCPL                                 = "ID_CAP_PRIV_REGIONCPL";
-    pub const ID_CAP_PRIV_REMEMBER                                  = "ID_CAP_PRIV_REMEMBER";
-    pub const ID_CAP_PRIV_RETAILDEMO                                = "ID_CAP_PRIV_RETAILDEMO";
-    pub const ID_CAP_PRIV_RETAILDEMOERROR                           = "ID_CAP_PRIV_RETAILDEMOERROR";
-    pub const ID_CAP_PRIV_RETAILDEMOGLOB                            = "ID_CAP_PRIV_RETAILDEMOGLOB";
-    pub const ID_CAP_PRIV_RETAILDEMOUI                              = "ID_CAP_PRIV_RETAILDEMOUI";
-    pub const ID_CAP_PRIV_RILADAPTATION                             = "ID_CAP_PRIV_RILADAPTATION";
-    pub const ID_CAP_PRIV_RINGTONESANDSOUNDS                        = "ID_CAP_PRIV_RINGTONESANDSOUNDS";
-    pub const ID_CAP_PRIV_ROAMINGCPL                                = "ID_CAP_PRIV_ROAMINGCPL";
-    pub const ID_CAP_PRIV_ROTATIONLOCKCPL                           = "ID_CAP_PRIV_ROTATIONLOCKCPL";
-    pub const ID_CAP_PRIV_SAPISVR                                   = "ID_CAP_PRIV_SAPISVR";
-    pub const ID_CAP_PRIV_SECMIGRATOR                               = "ID_CAP_PRIV_SECMIGRATOR";
-    pub const ID_CAP_PRIV_SEMGRSVC                                  = "ID_CAP_PRIV_SEMGRSVC";
-    pub const ID_CAP_PRIV_SETTINGS                                  = "ID_CAP_PRIV_SETTINGS";
-    pub const ID_CAP_PRIV_SHELLDATAMIGRATOR                         = "ID_CAP_PRIV_SHELLDATAMIGRATOR";
-    pub const ID_CAP_PRIV_SIREPSVC                                  = "ID_CAP_PRIV_SIREPSVC";
-    pub const ID_CAP_PRIV_SOFTAPUX                                  = "ID_CAP_PRIV_SOFTAPUX";
-    pub const ID_CAP_PRIV_SPEECHCPL                                 = "ID_CAP_PRIV_SPEECHCPL";
-    pub const ID_CAP_PRIV_SPEECHUPDATE                              = "ID_CAP_PRIV_SPEECHUPDATE";
-    pub const ID_CAP_PRIV_START                                     = "ID_CAP_PRIV_START";
-    pub const ID_CAP_PRIV_STORAGESENSE                              = "ID_CAP_PRIV_STORAGESENSE";
-    pub const ID_CAP_PRIV_STORAGESVC                                = "ID_CAP_PRIV_STORAGESVC";
-    pub const ID_CAP_PRIV_STOREDATAMIGRATOR                         = "ID_CAP_PRIV_STOREDATAMIGRATOR";
-    pub const ID_CAP_PRIV_TASKSCHEDULERSVC                          = "ID_CAP_PRIV_TASKSCHEDULERSVC";
-    pub const ID_CAP_PRIV_TELCPL                                    = "ID_CAP_PRIV_TELCPL";
-    pub const ID_CAP_PRIV_TELREPSVC                                 = "ID_CAP_PRIV_TELREPSVC";
-    pub const ID_CAP_PRIV_THEMECPL                                  = "ID_CAP_PRIV_THEMECPL";
-    pub const ID_CAP_PRIV_TILEMIGRATOR                              = "ID_CAP_PRIV_TILEMIGRATOR";
-    pub const ID_CAP_PRIV_TIMEBROKER                                = "ID_CAP_PRIV_TIMEBROKER";
-    pub const ID_CAP_PRIV_UPDATEMGRSVC                              = "ID_CAP_PRIV_UPDATEMGRSVC";
-    pub const ID_CAP_PRIV_USBCPL                                    = "ID_CAP_PRIV_USBCPL";
-    pub const ID_CAP_PRIV_USERDATASERVICE                           = "ID_CAP_PRIV_USERDATASERVICE";
-    pub const ID_CAP_PRIV_USSREPORTING                              = "ID_CAP_PRIV_USSREPORTING";
-    pub const ID_CAP_PRIV_UTKSERVICE                                = "ID_CAP_PRIV_UTKSERVICE";
-    pub const ID_CAP_PRIV_UTKUX                                     = "ID_CAP_PRIV_UTKUX";
-    pub const ID_CAP_PRIV_VPNUX                                     = "ID_CAP_PRIV_VPNUX";
-    pub const ID_CAP_PRIV_WALLET                                    = "ID_CAP_PRIV_WALLET";
-    pub const ID_CAP_PRIV_WALLETSVC                                 = "ID_CAP_PRIV_WALLETSVC";
-    pub const ID_CAP_PRIV_WEHCSPHELPER                              = "ID_CAP_PRIV_WEHCSPHELPER";
-    pub const ID_CAP_PRIV_WEHSTART                                  = "ID_CAP_PRIV_WEHSTART";
-    pub const ID_CAP_PRIV_WIFICONNSVC                               = "ID_CAP_PRIV_WIFICONNSVC";
-    pub const ID_CAP_PRIV_WIFICPASSIST                              = "ID_CAP_PRIV_WIFICPASSIST";
-    pub const ID_CAP_PRIV_WIFICPBROWSERUX                           = "ID_CAP_PRIV_WIFICPBROWSERUX";
-    pub const ID_CAP_PRIV_WIFIUDPTEST                               = "ID_CAP_PRIV_WIFIUDPTEST";
-    pub const ID_CAP_PRIV_WIFIUXBLUE                                = "ID_CAP_PRIV_WIFIUXBLUE";
-    pub const ID_CAP_PRIV_WLID2MSA                                  = "ID_CAP_PRIV_WLID2MSA";
-    pub const ID_CAP_PRIV_WPABSVC                                   = "ID_CAP_PRIV_WPABSVC";
-    pub const ID_CAP_PRIV_WPNARRATOR                                = "ID_CAP_PRIV_WPNARRATOR";
-    pub const ID_CAP_PRIV_WPNCERTINSTSVC                            = "ID_CAP_PRIV_WPNCERTINSTSVC";
-    pub const ID_CAP_PRIV_WPTOOLS                                   = "ID_CAP_PRIV_WPTOOLS";
-    pub const ID_CAP_PRIV_WPTPMVSCMGRSVC                            = "ID_CAP_PRIV_WPTPMVSCMGRSVC";
-    pub const ID_CAP_PRIV_WPUITESTTOOLS                             = "ID_CAP_PRIV_WPUITESTTOOLS";
-    pub const ID_CAP_PRIV_ZMEDIAQUEUESVC                            = "ID_CAP_PRIV_ZMEDIAQUEUESVC";
-    pub const ID_CAP_PRIV_ZMF                                       = "ID_CAP_PRIV_ZMF";
-    pub const ID_CAP_PRIV_ZMF_SERVICE                               = "ID_CAP_PRIV_ZMF_SERVICE";
-    pub const ID_CAP_PROVISIONING_PACKAGE_API_ADMIN                 = "ID_CAP_PROVISIONING_PACKAGE_API_ADMIN";
-    pub const ID_CAP_PROVISIONWPCERTIFICATE                         = "ID_CAP_PROVISIONWPCERTIFICATE";
-    pub const ID_CAP_PROXIMITY                                      = "ID_CAP_PROXIMITY";
-    pub const ID_CAP_PUBLIC_FOLDER_FULL                             = "ID_CAP_PUBLIC_FOLDER_FULL";
-    pub const ID_CAP_PUBLISH_ALARM_STATE                            = "ID_CAP_PUBLISH_ALARM_STATE";
-    pub const ID_CAP_PUBLISH_OOBE_STATE                             = "ID_CAP_PUBLISH_OOBE_STATE";
-    pub const ID_CAP_PUSHROUTER                                     = "ID_CAP_PUSHROUTER";
-    pub const ID_CAP_PUSH_NOTIFICATION                              = "ID_CAP_PUSH_NOTIFICATION";
-    pub const ID_CAP_PUSH_SERVER                                    = "ID_CAP_PUSH_SERVER";
-    pub const ID_CAP_QUICK_SETTINGS                                 = "ID_CAP_QUICK_SETTINGS";
-    pub const ID_CAP_READGWPCERTIFICATE                             = "ID_CAP_READGWPCERTIFICATE";
-    pub const ID_CAP_REBOOT_FLASHING_MODE                           = "ID_CAP_REBOOT_FLASHING_MODE";
-    pub const ID_CAP_REMEMBER_ADMIN                                 = "ID_CAP_REMEMBER_ADMIN";
-    pub const ID_CAP_REMEMBER_API                                   = "ID_CAP_REMEMBER_API";
-    pub const ID_CAP_REMOVABLE_STORAGE                              = "ID_CAP_REMOVABLE_STORAGE";
-    pub const ID_CAP_RESET_PHONE                                    = "ID_CAP_RESET_PHONE";
-    pub const ID_CAP_RESOURCE_MANAGER                               = "ID_CAP_RESOURCE_MANAGER";
-    pub const ID_CAP_RETAILDEMO_BACKGROUNDIMAGE                     = "ID_CAP_RETAILDEMO_BACKGROUNDIMAGE";
-    pub const ID_CAP_RETAILDEMO_CLIENT                              = "ID_CAP_RETAILDEMO_CLIENT";
-    pub const ID_CAP_RETAILDEMO_GLOB_REGISTRY                       = "ID_CAP_RETAILDEMO_GLOB_REGISTRY";
-    pub const ID_CAP_RETAILDEMO_OFFLINECONTENT                      = "ID_CAP_RETAILDEMO_OFFLINECONTENT";
-    pub const ID_CAP_RINGTONE_ADD                                   = "ID_CAP_RINGTONE_ADD";
-    pub const ID_CAP_ROAMING_CONFIGURATION                          = "ID_CAP_ROAMING_CONFIGURATION";
-    pub const ID_CAP_ROTATION_MANAGER                               = "ID_CAP_ROTATION_MANAGER";
-    pub const ID_CAP_RUNTIME_CONFIG                                 = "ID_CAP_RUNTIME_CONFIG";
-    pub const ID_CAP_SCREENCAPTURE                                  = "ID_CAP_SCREENCAPTURE";
-    pub const ID_CAP_SCREEN_RECORDER                                = "ID_CAP_SCREEN_RECORDER";
-    pub const ID_CAP_SCREEN_RECORDER_BKG                            = "ID_CAP_SCREEN_RECORDER_BKG";
-    pub const ID_CAP_SEARCHMAPS_SHAREDCONFIG                        = "ID_CAP_SEARCHMAPS_SHAREDCONFIG";
-    pub const ID_CAP_SEND_TO_ONENOTE                                = "ID_CAP_SEND_TO_ONENOTE";
-    pub const ID_CAP_SENSORS                                        = "ID_CAP_SENSORS";
-    pub const ID_CAP_SETDEVICENAME                                  = "ID_CAP_SETDEVICENAME";
-    pub const ID_CAP_SETTINGSYNC                                    = "ID_CAP_SETTINGSYNC";
-    pub const ID_CAP_SETTINGSYNC_CONFIGURATION                      = "ID_CAP_SETTINGSYNC_CONFIGURATION";
-    pub const ID_CAP_SETTINGS_MANAGEMENT_PROVIDER                   = "ID_CAP_SETTINGS_MANAGEMENT_PROVIDER";
-    pub const ID_CAP_SHARED_OBJECT_DIRECTORY                        = "ID_CAP_SHARED_OBJECT_DIRECTORY";
-    pub const ID_CAP_SHARED_USER_CERTIFICATES                       = "ID_CAP_SHARED_USER_CERTIFICATES";
-    pub const ID_CAP_SHARE_DELEGATE                                 = "ID_CAP_SHARE_DELEGATE";
-    pub const ID_CAP_SHELL_DEVICE_LOCK_UI_API                       = "ID_CAP_SHELL_DEVICE_LOCK_UI_API";
-    pub const ID_CAP_SHELL_LAUNCH_SESSION                           = "ID_CAP_SHELL_LAUNCH_SESSION";
-    pub const ID_CAP_SHELL_NAVIGATION                               = "ID_CAP_SHELL_NAVIGATION";
-    pub const ID_CAP_SHELL_NOTIFICATION_CLIENT                      = "ID_CAP_SHELL_NOTIFICATION_CLIENT";
-    pub const ID_CAP_SHELL_OEM_ADMIN                                = "ID_CAP_SHELL_OEM_ADMIN";
-    pub const ID_CAP_SHELL_RESET_NAVIGATION                         = "ID_CAP_SHELL_RESET_NAVIGATION";
-    pub const ID_CAP_SHELL_TEST_CLIENT                              = "ID_CAP_SHELL_TEST_CLIENT";
-    pub const ID_CAP_SHOW_VOLUME_CONTROL                            = "ID_CAP_SHOW_VOLUME_CONTROL";
-    pub const ID_CAP_SMS                                            = "ID_CAP_SMS";
-    pub const ID_CAP_SMS_COMPANION                                  = "ID_CAP_SMS_COMPANION";
-    pub const ID_CAP_SMS_INTERCEPT_AGENT                            = "ID_CAP_SMS_INTERCEPT_AGENT";
-    pub const ID_CAP_SMS_INTERCEPT_RECIPIENT                        = "ID_CAP_SMS_INTERCEPT_RECIPIENT";
-    pub const ID_CAP_SOUND_CONTROL                                  = "ID_CAP_SOUND_CONTROL";
-    pub const ID_CAP_SPEECH_GRAMMARS                                = "ID_CAP_SPEECH_GRAMMARS";
-    pub const ID_CAP_SPEECH_RECOGNITION                             = "ID_CAP_SPEECH_RECOGNITION";
-    pub const ID_CAP_SPEECH_RECOGNITION_SYSTEM                      = "ID_CAP_SPEECH_RECOGNITION_SYSTEM";
-    pub const ID_CAP_SPEECH_SETTINGS                                = "ID_CAP_SPEECH_SETTINGS";
-    pub const ID_CAP_SPEECH_UPDATE                                  = "ID_CAP_SPEECH_UPDATE";
-    pub const ID_CAP_SPLASH_CONFIG                                  = "ID_CAP_SPLASH_CONFIG";
-    pub const ID_CAP_STARTMENU_CONFIG                               = "ID_CAP_STARTMENU_CONFIG";
-    pub const ID_CAP_STORAGE_MANAGEMENT                             = "ID_CAP_STORAGE_MANAGEMENT";
-    pub const ID_CAP_SUPPRESS_MSA_CONNECT_ARD                       = "ID_CAP_SUPPRESS_MSA_CONNECT_ARD";
-    pub const ID_CAP_SYNC_EXTENSION                                 = "ID_CAP_SYNC_EXTENSION";
-    pub const ID_CAP_SYSTEMTRAY_ADMIN                               = "ID_CAP_SYSTEMTRAY_ADMIN";
-    pub const ID_CAP_SYSTEM_ALLOC_WINDOWID                          = "ID_CAP_SYSTEM_ALLOC_WINDOWID";
-    pub const ID_CAP_SYSTEM_COMPOSITOR                              = "ID_CAP_SYSTEM_COMPOSITOR";
-    pub const ID_CAP_SYSTEM_COUNTERS                                = "ID_CAP_SYSTEM_COUNTERS";
-    pub const ID_CAP_SYSTEM_REGISTRAR                               = "ID_CAP_SYSTEM_REGISTRAR";
-    pub const ID_CAP_SYSTEM_WAITCURSOR                              = "ID_CAP_SYSTEM_WAITCURSOR";
-    pub const ID_CAP_TELEMETRY_ADMIN                                = "ID_CAP_TELEMETRY_ADMIN";
-    pub const ID_CAP_TELEMETRY_CONFIGURE                            = "ID_CAP_TELEMETRY_CONFIGURE";
-    pub const ID_CAP_TELEMETRY_STUDY                                = "ID_CAP_TELEMETRY_STUDY";
-    pub const ID_CAP_TEST_NAVIGATION                                = "ID_CAP_TEST_NAVIGATION";
-    pub const ID_CAP_TILERESTOREDATA                                = "ID_CAP_TILERESTOREDATA";
-    pub const ID_CAP_TOUCH                                          = "ID_CAP_TOUCH";
-    pub const ID_CAP_TOUCH_TEST                                     = "ID_CAP_TOUCH_TEST";
-    pub const ID_CAP_TPM_VSCMANAGER                                 = "ID_CAP_TPM_VSCMANAGER";
-    pub const ID_CAP_TS_SCHEDULES_ALL                               = "ID_CAP_TS_SCHEDULES_ALL";
-    pub const ID_CAP_USB                                            = "ID_CAP_USB";
-    pub const ID_CAP_USER_ACTIVITY                                  = "ID_CAP_USER_ACTIVITY";
-    pub const ID_CAP_VIDEOSINK_INTERNAL                             = "ID_CAP_VIDEOSINK_INTERNAL";
-    pub const ID_CAP_VOICEMAIL                                      = "ID_CAP_VOICEMAIL";
-    pub const ID_CAP_VOIP                                           = "ID_CAP_VOIP";
-    pub const ID_CAP_VOIP_CALL_CONTROLLER                           = "ID_CAP_VOIP_CALL_CONTROLLER";
-    pub const ID_CAP_VSTEST_INSTALL_FOLDER                          = "ID_CAP_VSTEST_INSTALL_FOLDER";
-    pub const ID_CAP_W32TIME_API                                    = "ID_CAP_W32TIME_API";
-    pub const ID_CAP_WAB_RESOURCES                                  = "ID_CAP_WAB_RESOURCES";
-    pub const ID_CAP_WALLET                                         = "ID_CAP_WALLET";
-    pub const ID_CAP_WALLET_ADMIN                                   = "ID_CAP_WALLET_ADMIN";
-    pub const ID_CAP_WALLET_DEALS                                   = "ID_CAP_WALLET_DEALS";
-    pub const ID_CAP_WALLET_PAYMENTINSTRUMENTS                      = "ID_CAP_WALLET_PAYMENTINSTRUMENTS";
-    pub const ID_CAP_WALLET_SECUREELEMENT                           = "ID_CAP_WALLET_SECUREELEMENT";
-    pub const ID_CAP_WALLPAPER_ADMIN                                = "ID_CAP_WALLPAPER_ADMIN";
-    pub const ID_CAP_WBOEXT                                         = "ID_CAP_WBOEXT";
-    pub const ID_CAP_WEBBROWSERCOMPONENT                            = "ID_CAP_WEBBROWSERCOMPONENT";
-    pub const ID_CAP_WEB_CREDENTIALS                                = "ID_CAP_WEB_CREDENTIALS";
-    pub const ID_CAP_WIFI_ADMIN                                     = "ID_CAP_WIFI_ADMIN";
-    pub const ID_CAP_WIFI_BASIC                                     = "ID_CAP_WIFI_BASIC";
-    pub const ID_CAP_WIFI_BROWSER                                   = "ID_CAP_WIFI_BROWSER";
-    pub const ID_CAP_WIFI_HOTSPOT_HOST                              = "ID_CAP_WIFI_HOTSPOT_HOST";
-    pub const ID_CAP_WIFI_PROFILE_ADMIN                             = "ID_CAP_WIFI_PROFILE_ADMIN";
-    pub const ID_CAP_WIFI_TILE_MANAGER                              = "ID_CAP_WIFI_TILE_MANAGER";
-    pub const ID_CAP_WPN_PLATFORM                                   = "ID_CAP_WPN_PLATFORM";
-    pub const ID_CAP_WPN_PLATFORM_REG_KEY                           = "ID_CAP_WPN_PLATFORM_REG_KEY";
-    pub const ID_CAP_WPTOOLS_INSTALL_FOLDER                         = "ID_CAP_WPTOOLS_INSTALL_FOLDER";
-    pub const ID_CAP_ZMFSERVICES                                    = "ID_CAP_ZMFSERVICES";
-    pub const ID_CAP_ZTRACE                                         = "ID_CAP_ZTRACE";
-    pub const Microsoft_firmwareRead_cw5n1h2txyewy                  = "Microsoft.firmwareRead_cw5n1h2txyewy";
-    pub const Microsoft_firmwareWrite_cw5n1h2txyewy                 = "Microsoft.firmwareWrite_cw5n1h2txyewy";
-
-    pub const accessoryManager                          = "accessoryManager";
-    pub const activateAsUser                            = "activateAsUser";
-    pub const activity                                  = "activity";
-    pub const activityData                              = "activityData";
-    pub const activitySystem                            = "activitySystem";
-    pub const allAppMods                                = "allAppMods";
-    pub const allJoyn                                   = "allJoyn";
-    pub const allowElevation                            = "allowElevation";
-    pub const appBroadcast                              = "appBroadcast";
-    pub const appBroadcastServices                      = "appBroadcastServices";
-    pub const appBroadcastSettings                      = "appBroadcastSettings";
-    pub const appCaptureServices                        = "appCaptureServices";
-    pub const appCaptureSettings                        = "appCaptureSettings";
-    pub const appDiagnostics                            = "appDiagnostics";
-    pub const appLicensing                              = "appLicensing";
-    pub const appManagementSystem                       = "appManagementSystem";
-    pub const applicationDefaults                       = "applicationDefaults";
-    pub const applicationViewActivation                 = "applicationViewActivation";
-    pub const appointments                              = "appointments";
-    pub const appointmentsSystem                        = "appointmentsSystem";
-    pub const audioDeviceConfiguration                  = "audioDeviceConfiguration";
-    pub const automatedAppLaunch                        = "automatedAppLaunch";
-    pub const backgroundMediaPlayback                   = "backgroundMediaPlayback";
-    pub const backgroundMediaRecording                  = "backgroundMediaRecording";
-    pub const backgroundSpatialPerception               = "backgroundSpatialPerception";
-    pub const backgroundVoIP                            = "backgroundVoIP";
-    pub const biometricSystem                           = "biometricSystem";
-    pub const blockedChatMessages                       = "blockedChatMessages";
-    pub const bluetooth                                 = "bluetooth";
-    pub const bluetooth_genericAttributeProfile         = "bluetooth.genericAttributeProfile";
-    pub const bluetooth_rfcomm                          = "bluetooth.rfcomm";
-    pub const bluetoothAdapter                          = "bluetoothAdapter";
-    pub const bluetoothDeviceSettings                   = "bluetoothDeviceSettings";
-    pub const bluetoothSync                             = "bluetoothSync";
-    pub const bootstrapNetworkConnection                = "bootstrapNetworkConnection";
-    pub const broadFileSystemAccess                     = "broadFileSystemAccess";
-    pub const browserAppList                            = "browserAppList";
-    pub const browserCredentials                        = "browserCredentials";
-    pub const cameraProcessingExtension                 = "cameraProcessingExtension";
-    pub const capabilityAccessConsentDeviceSettings     = "capabilityAccessConsentDeviceSettings";
-    pub const cellularData                              = "cellularData";
-    pub const cellularDeviceControl                     = "cellularDeviceControl";
-    pub const cellularDeviceIdentity                    = "cellularDeviceIdentity";
-    pub const cellularMessaging                         = "cellularMessaging";
-    pub const chat                                      = "chat";
-    pub const chatSystem                                = "chatSystem";
-    pub const childWebContent                           = "childWebContent";
-    pub const chromeInstallFiles                        = "chromeInstallFiles";
-    pub const cloudExperienceHost                       = "cloudExperienceHost";
-    pub const cloudStore                                = "cloudStore";
-    pub const codeGeneration                            = "codeGeneration";
-    pub const comPort                                   = "comPort";
-    pub const componentUiInWebContent                   = "componentUiInWebContent";
-    pub const confirmAppClose                           = "confirmAppClose";
-    pub const constrainedImpersonation                  = "constrainedImpersonation";
-    pub const contacts                                  = "contacts";
-    pub const contactsSystem                            = "contactsSystem";
-    pub const contentDeliveryManagerSettings            = "contentDeliveryManagerSettings";
-    pub const contentRestrictions                       = "contentRestrictions";
-    pub const coreShell                                 = "coreShell";
-    pub const cortanaPermissions                        = "cortanaPermissions";
-    pub const cortanaSettings                           = "cortanaSettings";
-    pub const cortanaSpeechAccessory                    = "cortanaSpeechAccessory";
-    pub const cortanaSurface                            = "cortanaSurface";
-    pub const curatedTileCollections                    = "curatedTileCollections";
-    pub const customInstallActions                      = "customInstallActions";
-    pub const dateAndTimeDeviceSettings                 = "dateAndTimeDeviceSettings";
-    pub const dependencyTarget                          = "dependencyTarget";
-    pub const developerSettings                         = "developerSettings";
-    pub const developmentModeNetwork                    = "developmentModeNetwork";
-    pub const deviceEncryptionManagement                = "deviceEncryptionManagement";
-    pub const deviceIdentityManagement                  = "deviceIdentityManagement";
-    pub const deviceLockManagement                      = "deviceLockManagement";
-    pub const deviceManagementAdministrator             = "deviceManagementAdministrator";
-    pub const deviceManagementDeviceLockPolicies        = "deviceManagementDeviceLockPolicies";
-    pub const deviceManagementDmAccount                 = "deviceManagementDmAccount";
-    pub const deviceManagementEmailAccount              = "deviceManagementEmailAccount";
-    pub const deviceManagementFoundation                = "deviceManagementFoundation";
-    pub const deviceManagementRegistration              = "deviceManagementRegistration";
-    pub const deviceManagementWapSecurityPolicies       = "deviceManagementWapSecurityPolicies";
-    pub const devicePortalProvider                      = "devicePortalProvider";
-    pub const deviceProvisioningAdministrator           = "deviceProvisioningAdministrator";
-    pub const deviceUnlock                              = "deviceUnlock";
-    pub const diagnostics                               = "diagnostics";
-    pub const displayDeviceSettings                     = "displayDeviceSettings";
-    pub const documentsLibrary                          = "documentsLibrary";
-    pub const dualSimTiles                              = "dualSimTiles";
-    pub const email                                     = "email";
-    pub const emailSystem                               = "emailSystem";
-    pub const enterpriseAuthentication                  = "enterpriseAuthentication";
-    pub const enterpriseCloudSSO                        = "enterpriseCloudSSO";
-    pub const enterpriseDataPolicy                      = "enterpriseDataPolicy";
-    pub const enterpriseDeviceLockdown                  = "enterpriseDeviceLockdown";
-    pub const eraApplication                            = "eraApplication";
-    pub const exclusiveResource                         = "exclusiveResource";
-    pub const expandedResources                         = "expandedResources";
-    pub const extendedBackgroundTaskTime                = "extendedBackgroundTaskTime";
-    pub const extendedExecutionBackgroundAudio          = "extendedExecutionBackgroundAudio";
-    pub const extendedExecutionCritical                 = "extendedExecutionCritical";
-    pub const extendedExecutionUnconstrained            = "extendedExecutionUnconstrained";
-    pub const featureStagingInfo                        = "featureStagingInfo";
-    pub const feedbackLogCollection                     = "feedbackLogCollection";
-    pub const firstSignInSettings                       = "firstSignInSettings";
-    pub const flashPlayerSupport                        = "flashPlayerSupport";
-    pub const fullFileSystemAccess                      = "fullFileSystemAccess";
-    pub const gameBarServices                           = "gameBarServices";
-    pub const gameConfigStoreManagement                 = "gameConfigStoreManagement";
-    pub const gameList                                  = "gameList";
-    pub const gameMonitor                               = "gameMonitor";
-    pub const gamingContainerResources                  = "gamingContainerResources";
-    pub const gazeInput                                 = "gazeInput";
-    pub const globalMediaControl                        = "globalMediaControl";
-    pub const graphicsCapture                           = "graphicsCapture";
-    pub const hevcPlayback                              = "hevcPlayback";
-    pub const hfxSystem                                 = "hfxSystem";
-    pub const hidTelephony                              = "hidTelephony";
-    pub const holographicCompositor                     = "holographicCompositor";
-    pub const holographicCompositorSystem               = "holographicCompositorSystem";
-    pub const humanInterfaceDevice                      = "humanInterfaceDevice";
-    pub const imeSystem                                 = "imeSystem";
-    pub const inProcessMediaExtension                   = "inProcessMediaExtension";
-    pub const indexedContent                            = "indexedContent";
-    pub const inputForegroundObservation                = "inputForegroundObservation";
-    pub const inputInjection                            = "inputInjection";
-    pub const inputInjectionBrokered                    = "inputInjectionBrokered";
-    pub const inputObservation                          = "inputObservation";
-    pub const inputSettings                             = "inputSettings";
-    pub const inputSuppression                          = "inputSuppression";
-    pub const internetClient                            = "internetClient";
-    pub const internetClientServer                      = "internetClientServer";
-    pub const interopServices                           = "interopServices";
-    pub const keyboardDeviceSettings                    = "keyboardDeviceSettings";
-    pub const kinectAudio                               = "kinectAudio";
-    pub const kinectExpressions                         = "kinectExpressions";
-    pub const kinectFace                                = "kinectFace";
-    pub const kinectGamechat                            = "kinectGamechat";
-    pub const kinectRequired                            = "kinectRequired";
-    pub const kinectVideo                               = "kinectVideo";
-    pub const kinectVision                              = "kinectVision";
-    pub const languageAndRegionDeviceSettings           = "languageAndRegionDeviceSettings";
-    pub const languageSettings                          = "languageSettings";
-    pub const liveIdService                             = "liveIdService";
-    pub const localExperienceInternal                   = "localExperienceInternal";
-    pub const localSystemServices                       = "localSystemServices";
-    pub const location                                  = "location";
-    pub const locationHistory                           = "locationHistory";
-    pub const locationSystem                            = "locationSystem";
-    pub const lockScreenCreatives                       = "lockScreenCreatives";
-    pub const lowLevel                                  = "lowLevel";
-    pub const lowLevelDevices                           = "lowLevelDevices";
-    pub const lpacAppExperience                         = "lpacAppExperience";
-    pub const lpacChromeInstallFiles                    = "lpacChromeInstallFiles";
-    pub const lpacClipboard                             = "lpacClipboard";
-    pub const lpacCom                                   = "lpacCom";
-    pub const lpacCryptoServices                        = "lpacCryptoServices";
-    pub const lpacEnterprisePolicyChangeNotifications   = "lpacEnterprisePolicyChangeNotifications";
-    pub const lpacIME                                   = "lpacIME";
-    pub const lpacIdentityServices                      = "lpacIdentityServices";
-    pub const lpacInstrumentation                       = "lpacInstrumentation";
-    pub const lpacMedia                                 = "lpacMedia";
-    pub const lpacPackageManagerOperation               = "lpacPackageManagerOperation";
-    pub const lpacPayments                              = "lpacPayments";
-    pub const lpacPnPNotifications                      = "lpacPnPNotifications";
-    pub const lpacPrinting                              = "lpacPrinting";
-    pub const lpacServicesManagement                    = "lpacServicesManagement";
-    pub const lpacSessionManagement                     = "lpacSessionManagement";
-    pub const lpacWebPlatform                           = "lpacWebPlatform";
-    pub const microphone                                = "microphone";
-    pub const microsoftEdgeRemoteDebugging              = "microsoftEdgeRemoteDebugging";
-    pub const mixedRealityEnvironmentInternal           = "mixedRealityEnvironmentInternal";
-    pub const mmsTransportSystem                        = "mmsTransportSystem";
-    pub const modifiableApp                             = "modifiableApp";
-    pub const multiplaneOverlay                         = "multiplaneOverlay";
-    pub const muma                                      = "muma";
-    pub const musicLibrary                              = "musicLibrary";
-    pub const networkConnectionManagerProvisioning      = "networkConnectionManagerProvisioning";
-    pub const networkDataPlanProvisioning               = "networkDataPlanProvisioning";
-    pub const networkDataUsageManagement                = "networkDataUsageManagement";
-    pub const networkDeviceSettings                     = "networkDeviceSettings";
-    pub const networkDiagnostics                        = "networkDiagnostics";
-    pub const networkingVpnProvider                     = "networkingVpnProvider";
-    pub const nfcSystem                                 = "nfcSystem";
-    pub const notificationsDeviceSettings               = "notificationsDeviceSettings";
-    pub const objects3D                                 = "objects3D";
-    pub const oemDeployment                             = "oemDeployment";
-    pub const oemPublicDirectory                        = "oemPublicDirectory";
-    pub const offlineMapsManagement                     = "offlineMapsManagement";
-    pub const oneProcessVoIP                            = "oneProcessVoIP";
-    pub const optical                                   = "optical";
-    pub const pacJsWorker                               = "pacJsWorker";
-    pub const packageContents                           = "packageContents";
-    pub const packageManagement                         = "packageManagement";
-    pub const packagePolicySystem                       = "packagePolicySystem";
-    pub const packageQuery                              = "packageQuery";
-    pub const packageWriteRedirectionCompatibilityShim  = "packageWriteRedirectionCompatibilityShim";
-    pub const packagedServices                          = "packagedServices";
-    pub const perceptionMonitoring                      = "perceptionMonitoring";
-    pub const perceptionSensorsExperimental             = "perceptionSensorsExperimental";
-    pub const perceptionSystem                          = "perceptionSystem";
-    pub const permissiveLearningMode                    = "permissiveLearningMode";
-    pub const personalizationDeviceSettings             = "personalizationDeviceSettings";
-    pub const phoneCall                                 = "phoneCall";
-    pub const phoneCallHistory                          = "phoneCallHistory";
-    pub const phoneCallHistoryPublic                    = "phoneCallHistoryPublic";
-    pub const phoneCallHistorySystem                    = "phoneCallHistorySystem";
-    pub const phoneCallSystem                           = "phoneCallSystem";
-    pub const phoneLineTransportManagement              = "phoneLineTransportManagement";
-    pub const picturesLibrary                           = "picturesLibrary";
-    pub const pointOfService                            = "pointOfService";
-    pub const powerDeviceSettings                       = "powerDeviceSettings";
-    pub const preemptiveCamera                          = "preemptiveCamera";
-    pub const previewHfx                                = "previewHfx";
-    pub const previewInkWorkspace                       = "previewInkWorkspace";
-    pub const previewPenWorkspace                       = "previewPenWorkspace";
-    pub const previewStore                              = "previewStore";
-    pub const previewUiComposition                      = "previewUiComposition";
-    pub const privateNetworkClientServer                = "privateNetworkClientServer";
-    pub const projectionDeviceSettings                  = "projectionDeviceSettings";
-    pub const protectedApp                              = "protectedApp";
-    pub const proximity                                 = "proximity";
-    pub const radios                                    = "radios";
-    pub const recordedCallsFolder                       = "recordedCallsFolder";
-    pub const regionSettings                            = "regionSettings";
-    pub const registryRead                              = "registryRead";
-    pub const relatedPackages                           = "relatedPackages";
-    pub const remoteFileAccess                          = "remoteFileAccess";
-    pub const remotePassportAuthentication              = "remotePassportAuthentication";
-    pub const remoteSystem                              = "remoteSystem";
-    pub const removableStorage                          = "removableStorage";
-    pub const resetPhone                                = "resetPhone";
-    pub const runFullTrust                              = "runFullTrust";
-    pub const screenDuplication                         = "screenDuplication";
-    pub const searchSettings                            = "searchSettings";
-    pub const secondaryAuthenticationFactor             = "secondaryAuthenticationFactor";
-    pub const secureAssessment                          = "secureAssessment";
-    pub const sensors_custom                            = "sensors.custom";
-    pub const serialCommunication                       = "serialCommunication";
-    pub const sessionImpersonation                      = "sessionImpersonation";
-    pub const settingSyncConfiguration                  = "settingSyncConfiguration";
-    pub const sharedMachineKeysCapability               = "sharedMachineKeysCapability";
-    pub const sharedUserCertificates                    = "sharedUserCertificates";
-    pub const shellDisplayManagement                    = "shellDisplayManagement";
-    pub const shellExperience                           = "shellExperience";
-    pub const shellExperienceComposer                   = "shellExperienceComposer";
-    pub const slapiQueryLicenseValue                    = "slapiQueryLicenseValue";
-    pub const smbios                                    = "smbios";
-    pub const sms                                       = "sms";
-    pub const smsSend                                   = "smsSend";
-    pub const smsSystem                                 = "smsSystem";
-    pub const smsTransportSystem                        = "smsTransportSystem";
-    pub const spatialPerception                         = "spatialPerception";
-    pub const startScreenManagement                     = "startScreenManagement";
-    pub const storeAppInstall                           = "storeAppInstall";
-    pub const storeAppInstallation                      = "storeAppInstallation";
-    pub const storeConfiguration                        = "storeConfiguration";
-    pub const storeLicenseManagement                    = "storeLicenseManagement";
-    pub const storeOptionalPackageInstallManagement     = "storeOptionalPackageInstallManagement";
-    pub const systemDialog                              = "systemDialog";
-    pub const systemDialogEmergency                     = "systemDialogEmergency";
-    pub const systemManagement                          = "systemManagement";
-    pub const systemRegistrar                           = "systemRegistrar";
-    pub const targetedContent                           = "targetedContent";
-    pub const targetedContentSubscription               = "targetedContentSubscription";
-    pub const teamEditionDeviceCredential               = "teamEditionDeviceCredential";
-    pub const teamEditionExperience                     = "teamEditionExperience";
-    pub const teamEditionView                           = "teamEditionView";
-    pub const telemetryData                             = "telemetryData";
-    pub const terminalPowerManagement                   = "terminalPowerManagement";
-    pub const thumbnailCache                            = "thumbnailCache";
-    pub const timezone                                  = "timezone";
-    pub const uiAccess                                  = "uiAccess";
-    pub const uiAutomationSystem                        = "uiAutomationSystem";
-    pub const unvirtualizedResources                    = "unvirtualizedResources";
-    pub const unzipFile                                 = "unzipFile";
-    pub const updateAndSecurityDeviceSettings           = "updateAndSecurityDeviceSettings";
-    pub const usb                                       = "usb";
-    pub const userAccountInformation                    = "userAccountInformation";
-    pub const userDataAccountSetup                      = "userDataAccountSetup";
-    pub const userDataAccountsProvider                  = "userDataAccountsProvider";
-    pub const userDataSystem                            = "userDataSystem";
-    pub const userDataTasks                             = "userDataTasks";
-    pub const userDataTasksSystem                       = "userDataTasksSystem";
-    pub const userManagementSystem                      = "userManagementSystem";
-    pub const userNotificationListener                  = "userNotificationListener";
-    pub const userPrincipalName                         = "userPrincipalName";
-    pub const userSigninSupport                         = "userSigninSupport";
-    pub const userSystemId                              = "userSystemId";
-    pub const userWebAccounts                           = "userWebAccounts";
-    pub const videosLibrary                             = "videosLibrary";
-    pub const visualElementsSystem                      = "visualElementsSystem";
-    pub const visualVoiceMail                           = "visualVoiceMail";
-    pub const vmWorkerProcess                           = "vmWorkerProcess";
-    pub const voipCall                                  = "voipCall";
-    pub const walletSystem                              = "walletSystem";
-    pub const webPlatformMediaExtension                 = "webPlatformMediaExtension";
-    pub const webcam                                    = "webcam";
-    pub const wiFiControl                               = "wiFiControl";
-    pub const wiFiDirect                                = "wiFiDirect";
-    pub const wifiData                                  = "wifiData";
-    pub const windowManagement                          = "windowManagement";
-    pub const windowManagementSystem                    = "windowManagementSystem";
-    pub const windowsHelloCredentialAccess              = "windowsHelloCredentialAccess";
-    pub const windowsPerformanceCounters                = "windowsPerformanceCounters";
-    pub const xboxAccessoryManagement                   = "xboxAccessoryManagement";
-    pub const xboxBroadcaster                           = "xboxBroadcaster";
-    pub const xboxGameSpeechWindow                      = "xboxGameSpeechWindow";
-    pub const xboxLiveAuthenticationProvider            = "xboxLiveAuthenticationProvider";
-    pub const xboxSystemApplicationClipQuery            = "xboxSystemApplicationClipQuery";
-    pub const xboxTrackingStream                        = "xboxTrackingStream";
+    pub const ID_CAP_ACCESSIBILITY_CLIENT                           = "ID_CAP_ACCESSIBILITY_CLIENT"                        [ 774208497, 3724433823, 2280098247, 1267052767, 1256890434, 2028991070, 3207881850, 2803170042];
+    pub const ID_CAP_ACCESS_FAMILY_NOTES_API                        = "ID_CAP_ACCESS_FAMILY_NOTES_API"                     [4034606539, 3852398287, 1427559995, 2694090195, 2448273113,  353852140, 1809865592, 2600500240];
+    pub const ID_CAP_ACCESS_REMINDER_IMAGES                         = "ID_CAP_ACCESS_REMINDER_IMAGES"                      [3579285253,  486657032, 2339407271, 1097237001,  449504006,  450309546, 3162681956, 3486359964];
+    pub const ID_CAP_ACC_MGR_ADMIN                                  = "ID_CAP_ACC_MGR_ADMIN"                               [1950009213,  936060211, 4172343339, 3246850340, 3186610465,  853341579, 1335282943, 1979374645];
+    pub const ID_CAP_ADAPTIVE_BRIGHTNESS_CONTROL                    = "ID_CAP_ADAPTIVE_BRIGHTNESS_CONTROL"                 [ 479219474, 2885956267, 3095518087, 1672553700, 1388096776, 1506847129,  551921452, 2537366653];
+    pub const ID_CAP_ADVERTISING_CONFIG                             = "ID_CAP_ADVERTISING_CONFIG"                          [2107512568, 3225149457, 3285279747, 3063084584,  464288558, 2194022119, 1392047761, 1333630829];
+    pub const ID_CAP_AIS_TOKEN_MANAGER                              = "ID_CAP_AIS_TOKEN_MANAGER"                           [1291817228,  610580991, 3780615219,  543256413, 1457574973, 3662851358, 3391846946, 1480869071];
+    pub const ID_CAP_APPCONTAINER_PACKAGE_CERTIFICATES              = "ID_CAP_APPCONTAINER_PACKAGE_CERTIFICATES"           [1785506828, 2708045220,  809329652, 2677058173, 1247703850, 3684664803, 2394966205,  399964050];
+    pub const ID_CAP_APPOINTMENTS                                   = "ID_CAP_APPOINTMENTS"                                [3143806525, 1203864249, 1421818192, 3633341372, 1897640038,  385730927, 2799706934, 2446152123];
+    pub const ID_CAP_APPPREINSTALL_DIRECTORY                        = "ID_CAP_APPPREINSTALL_DIRECTORY"                     [2368898550, 2909701736, 2311648875, 2362607016, 1412437129, 1740687545, 1969129245, 1832168360];
+    pub const ID_CAP_APPPREINSTALL_EVENTS                           = "ID_CAP_APPPREINSTALL_EVENTS"                        [1520186181, 4105242225, 2862429206,   45244800, 2487292187, 1628964330, 2775026264, 1724778590];
+    pub const ID_CAP_APPRESOLVER                                    = "ID_CAP_APPRESOLVER"                                 [3094623581, 3144933562, 3788420847, 1528667299, 1176901618, 2584390066,  311061140, 3036856076];
+    pub const ID_CAP_APPX_EXECUTION                                 = "ID_CAP_APPX_EXECUTION"                              [1173725387, 2802302313,  777589934, 1957358426,  967091676,  138068922, 4100040705, 2806756745];
+    pub const ID_CAP_APP_STORE_PURCHASE_HISTORY                     = "ID_CAP_APP_STORE_PURCHASE_HISTORY"                  [2762723886,  699437721,  563337419, 3485614415, 3685218464, 2719939591, 1603517964, 1123239774];
+    pub const ID_CAP_AUDIO_EVENTSND                                 = "ID_CAP_AUDIO_EVENTSND"                              [3566883707, 2090469952, 4000426518, 2545929165, 2736425139, 3724057170, 4055890952,  748922986];
+    pub const ID_CAP_AUDIO_INTERNAL                                 = "ID_CAP_AUDIO_INTERNAL"                              [3984113290, 1951214720,  295432403, 3927478148, 2117019826, 1479021443,  410720065, 2782635805];
+    pub const ID_CAP_AUDIO_ROUTING_CONTROLLER                       = "ID_CAP_AUDIO_ROUTING_CONTROLLER"                    [1407847332, 2225157494, 2539225917, 1407507193, 2911240183,  647879305,  859002855, 1914193657];
+    pub const ID_CAP_AUDIO_SETTINGS                                 = "ID_CAP_AUDIO_SETTINGS"                              [1497275846, 4176677876,  502210763, 1484366811,  246812187,  868265464, 3897222467, 4279133868];
+    pub const ID_CAP_AUTHHOST                                       = "ID_CAP_AUTHHOST"                                    [  55485814, 2295463119,  735945442,  260437639, 2010566355, 3849876005, 3889339505,  502386160];
+    pub const ID_CAP_BACKGROUND_EXECUTION_MANAGEMENT                = "ID_CAP_BACKGROUND_EXECUTION_MANAGEMENT"             [1780463809, 2742525129, 4082841646, 3281504010, 4127967651, 1878430409, 2403667859, 2561806002];
+    pub const ID_CAP_BACKGROUND_SETTINGS_MANAGEMENT                 = "ID_CAP_BACKGROUND_SETTINGS_MANAGEMENT"              [2551391033, 2827527651, 4120476989, 1282825331, 2929669343, 3031057253, 4138841412, 3417199028];
+    pub const ID_CAP_BACKGROUND_WORKER                              = "ID_CAP_BACKGROUND_WORKER"                           [1741983003, 3498682802, 1664031165,  975629034, 1571979591, 2899377402, 3207487498,  619052110];
+    pub const ID_CAP_BACKUPROAMRESTORE                              = "ID_CAP_BACKUPROAMRESTORE"                           [4082037088, 4217120093, 2308735545, 2106322260,  869813013, 2589086500,  590205673, 3019284848];
+    pub const ID_CAP_BASEOS_UPDATEAPI                               = "ID_CAP_BASEOS_UPDATEAPI"                            [ 679637434, 3128451123, 1167404577, 3154959650,  803474904,  218218583,   15891891, 3838649586];
+    pub const ID_CAP_BINGCLIENT_BINGCONFIGURATION                   = "ID_CAP_BINGCLIENT_BINGCONFIGURATION"                [ 532672612, 2535533432,  631466788, 2911315649, 3957057489, 3698973828,  872084021, 3795439233];
+    pub const ID_CAP_BINGCLIENT_CA_INTERESTEXTRACTION               = "ID_CAP_BINGCLIENT_CA_INTERESTEXTRACTION"            [2654298321, 3126663644, 3465578781, 3690022524,   40210665, 1128891521, 1313141078, 4062297563];
+    pub const ID_CAP_BINGCLIENT_DDC                                 = "ID_CAP_BINGCLIENT_DDC"                              [ 439302105, 3995225964, 2197718367, 2376906723, 2991929506, 1952713319, 1066660296, 1695714271];
+    pub const ID_CAP_BINGCLIENT_IDENTITY                            = "ID_CAP_BINGCLIENT_IDENTITY"                         [2618725217, 1222280506, 4132408283,  564471669, 1089507412, 2925163453, 1043056729, 2693205124];
+    pub const ID_CAP_BINGCLIENT_OSS                                 = "ID_CAP_BINGCLIENT_OSS"                              [ 435944931,   54420597, 3338906200, 3345479808, 2456274549, 4074872695, 1761409988,  188340225];
+    pub const ID_CAP_BINGCLIENT_SUGGESTSHIGHLIGHTS                  = "ID_CAP_BINGCLIENT_SUGGESTSHIGHLIGHTS"               [2359426853, 3165480374, 3427303185, 3421379290, 1876759900, 1634947527, 1429429182, 2467017213];
+    pub const ID_CAP_BINGCLIENT_TEE                                 = "ID_CAP_BINGCLIENT_TEE"                              [3409176561,  473512496, 2812240848, 2543312645, 1800585963, 2118549181, 2489490965, 3932591650];
+    pub const ID_CAP_BLUETOOTH                                      = "ID_CAP_BLUETOOTH"                                   [1580694559, 2959402385, 3771899359, 3243404771, 3956832714, 1496846988, 1783519482, 1490354012];
+    pub const ID_CAP_BLUETOOTH_ADMIN                                = "ID_CAP_BLUETOOTH_ADMIN"                             [2364622686, 1209598981, 1314580344,   87135040, 2525187997,  694429591, 4168472881, 1211665324];
+    pub const ID_CAP_BMR2_MONITOR_SERVICE                           = "ID_CAP_BMR2_MONITOR_SERVICE"                        [2693527334, 1337007066, 1883740003, 1735309777, 1406880000, 2797140601, 1201504069,  710887520];
+    pub const ID_CAP_BMR_CONFIGURATION                              = "ID_CAP_BMR_CONFIGURATION"                           [1978150470,   10096009,  263378758,  562195422,   12847347,  492548490,  896560308,  148888295];
+    pub const ID_CAP_BMR_SYNC                                       = "ID_CAP_BMR_SYNC"                                    [2229662683, 3765844208, 1904010406, 4196972972, 2965617636, 1922668814, 1713445599,  820889161];
+    pub const ID_CAP_BROKER_NAVIGATION                              = "ID_CAP_BROKER_NAVIGATION"                           [2513931980, 3380788110, 1060404816, 3186982467, 3171498299,  588903361, 3695693470, 1095960871];
+    pub const ID_CAP_BROWSER_ACCESSIBILITY_SETTINGS                 = "ID_CAP_BROWSER_ACCESSIBILITY_SETTINGS"              [ 152858275, 3945439053, 3403198893,  188157179, 1305470982, 1891534904, 1554805694,   34966059];
+    pub const ID_CAP_BROWSER_FEATURE_CONTROL_KEYS                   = "ID_CAP_BROWSER_FEATURE_CONTROL_KEYS"                [4265353158, 1228794670, 3447850780, 1716427074,  130500562, 3904527144, 1531709440, 1055414426];
+    pub const ID_CAP_BSS_AIM_INTERFACE                              = "ID_CAP_BSS_AIM_INTERFACE"                           [2179963497, 2968166932, 3521883097, 1866989665, 3851457265,  888987564, 1195022228, 3455095722];
+    pub const ID_CAP_BSS_NABSYNC_INTERFACE                          = "ID_CAP_BSS_NABSYNC_INTERFACE"                       [ 749309868, 4081629156, 1083044879, 2036503106, 2685033498, 1583617464, 3038022565, 1252625419];
+    pub const ID_CAP_BSS_PUSH_INTERFACE                             = "ID_CAP_BSS_PUSH_INTERFACE"                          [1794988709,  521124326, 3607759388, 1839555998, 2591476086, 3110284630, 2797442604, 2763198506];
+    pub const ID_CAP_BSS_REMINDER_INTERFACE                         = "ID_CAP_BSS_REMINDER_INTERFACE"                      [4153508582, 4198885821,  509135658, 3207407925,   49096855, 2767364659,  968676770, 4033600652];
+    pub const ID_CAP_BUILTIN_BASEPRIORITY                           = "ID_CAP_BUILTIN_BASEPRIORITY"                        [1181148661,   22499708, 2470344036,  931925202, 2950179431, 1000539013, 3412595612, 1574801768];
+    pub const ID_CAP_BUILTIN_CREATEGLOBAL                           = "ID_CAP_BUILTIN_CREATEGLOBAL"                        [3357019356, 2961067734, 2532854842, 3419577255, 4172991591, 3155328790, 3609120188, 4283827966];
+    pub const ID_CAP_BUILTIN_CREATEPERMANENT                        = "ID_CAP_BUILTIN_CREATEPERMANENT"                     [3326150854, 4137902692,  917304988,   26626351, 1360080517, 1692660272, 4035333147, 2634461079];
+    pub const ID_CAP_BUILTIN_DEFAULT                                = "ID_CAP_BUILTIN_DEFAULT"                             [2701977582, 1536670721,  438331367,  874189579,   75301735, 3851249492, 2561000152, 2078707090];
+    pub const ID_CAP_BUILTIN_IMPERSONATE                            = "ID_CAP_BUILTIN_IMPERSONATE"                         [ 429397116, 1271375398, 1449406447, 1284198602, 4047195577,  279088506, 1956681019, 2532734761];
+    pub const ID_CAP_BUILTIN_PROFILE                                = "ID_CAP_BUILTIN_PROFILE"                             [2044405903,  271988726, 2434661463,  197560331, 3723257092, 1241225875, 4017709341, 1981091994];
+    pub const ID_CAP_BUILTIN_SETTIME                                = "ID_CAP_BUILTIN_SETTIME"                             [4209503679, 2184748342, 2834895087, 1614851454,  197222061,  881709283, 4263638572, 2069438112];
+    pub const ID_CAP_BUILTIN_SHUTDOWN                               = "ID_CAP_BUILTIN_SHUTDOWN"                            [1756535202, 1541376426, 2541887379,  543898708, 1993746524, 3896427000, 2382021892,  865238545];
+    pub const ID_CAP_BUILTIN_SYMBOLICLINK                           = "ID_CAP_BUILTIN_SYMBOLICLINK"                        [1066260197, 1356834439, 3357501007, 2618001302, 3970141590, 3532527633, 3941847875, 2576383025];
+    pub const ID_CAP_BUILTIN_TCB                                    = "ID_CAP_BUILTIN_TCB"                                 [3469742348, 3307254138, 2238970105, 3722559411, 3372219397, 2972199482, 2833688915, 1459669886];
+    pub const ID_CAP_BUTTONS                                        = "ID_CAP_BUTTONS"                                     [3313386555, 1123182543, 1317621395, 2575484634, 1423689632, 1043279998, 4225037970, 3373142843];
+    pub const ID_CAP_CALLMESSAGING_FILTER                           = "ID_CAP_CALLMESSAGING_FILTER"                        [1451828072, 3833841407, 2487868269, 1682516936, 2971256151, 3560914302, 3988562740,  770600926];
+    pub const ID_CAP_CAMERA                                         = "ID_CAP_CAMERA"                                      [ 978770360,  120978706,  370478463, 3607821418, 2899652023, 1167433329, 2132211847, 2760562517];
+    pub const ID_CAP_CA_ACTIONURI_TEST_EVENT                        = "ID_CAP_CA_ACTIONURI_TEST_EVENT"                     [ 448337351, 1876666087, 4192873654, 1626907260, 1353416210, 3467321998,  123075107, 2965681553];
+    pub const ID_CAP_CA_ADMIN                                       = "ID_CAP_CA_ADMIN"                                    [3612834734,   30756299, 1967518233,  238853155, 1154709274, 4237082518, 1438520316,  845939314];
+    pub const ID_CAP_CA_BACKGROUND_PROCESSOR                        = "ID_CAP_CA_BACKGROUND_PROCESSOR"                     [2858115719, 2690105117, 1069316131,  573108403,  332108802, 1340454167, 2223975900, 3354190050];
+    pub const ID_CAP_CA_BACKGROUND_PROCESSOR_ADMIN                  = "ID_CAP_CA_BACKGROUND_PROCESSOR_ADMIN"               [3782025050, 1647302113, 2370086233, 2063809792,  820248190, 3675929772, 1752287697,  700841558];
+    pub const ID_CAP_CA_CONTEXT                                     = "ID_CAP_CA_CONTEXT"                                  [ 367276224, 1663220776, 1797541150, 2156458172, 3327882143, 3843035871,  288351802,    7587667];
+    pub const ID_CAP_CA_DND_MANAGER                                 = "ID_CAP_CA_DND_MANAGER"                              [2202497961, 3866980025, 1813944090, 3581134618, 3594422516, 1482082306,  295424382, 1409227270];
+    pub const ID_CAP_CA_ENABLED                                     = "ID_CAP_CA_ENABLED"                                  [2770947241, 2548309330, 4016079166, 3155969074, 3583215963, 2305875380, 1027304261, 1019494965];
+    pub const ID_CAP_CA_HISTORY                                     = "ID_CAP_CA_HISTORY"                                  [2848768508, 3825541055, 2638714291, 1589996885, 1567941337, 3744226842, 1381518379, 2837622372];
+    pub const ID_CAP_CA_RULES                                       = "ID_CAP_CA_RULES"                                    [ 959215011, 3412900017,  544875034,  193879845, 3506011039, 2952254742,  115735274, 3531463173];
+    pub const ID_CAP_CA_SIGNALS_GENERIC                             = "ID_CAP_CA_SIGNALS_GENERIC"                          [4054145690, 2982338618, 1132615502,  799412948, 3260153009,  115469652, 3576881169,   65623520];
+    pub const ID_CAP_CA_SIGNALS_MANAGER                             = "ID_CAP_CA_SIGNALS_MANAGER"                          [2556164737, 1708414419,  443065478,  530114444, 3129782021, 3314373326,  492143000, 1335852534];
+    pub const ID_CAP_CA_SIGNALS_MANAGER_ADMIN                       = "ID_CAP_CA_SIGNALS_MANAGER_ADMIN"                    [  59223725, 3230630075, 2973349639, 2777015785, 1640163886, 4054965956, 3679119211, 4060977542];
+    pub const ID_CAP_CA_UPLOAD_FOLDER                               = "ID_CAP_CA_UPLOAD_FOLDER"                            [1951404291,  504935253, 1396626343, 1215703164,  362839001,  452165249, 1565140029, 2185492478];
+    pub const ID_CAP_CELLUX_CONFIG_READ                             = "ID_CAP_CELLUX_CONFIG_READ"                          [1679065123, 1721283697, 1225774233,   43142431, 2129630509, 1849699245, 2914712325, 1749964373];
+    pub const ID_CAP_CELL_API_ADMIN                                 = "ID_CAP_CELL_API_ADMIN"                              [2803041463, 1725003285, 1956436409, 1006166631,  360273912, 3247948615,  893119137, 4064144795];
+    pub const ID_CAP_CELL_API_COMMON                                = "ID_CAP_CELL_API_COMMON"                             [ 712952650, 1018556565, 4166965235, 2100616537, 3656881935, 2871598564,  528382029, 1858528910];
+    pub const ID_CAP_CELL_API_LOCATION                              = "ID_CAP_CELL_API_LOCATION"                           [4207553911, 1817762215, 3910401269, 4171476381, 2059234055, 1375302801, 3048877942, 1871034040];
+    pub const ID_CAP_CELL_API_MESSAGING                             = "ID_CAP_CELL_API_MESSAGING"                          [1390424552, 1691338358, 2576246362, 1875410883, 3946323183, 1010518229, 2157243190, 3430877616];
+    pub const ID_CAP_CELL_API_MODEMLOGGING                          = "ID_CAP_CELL_API_MODEMLOGGING"                       [2832124402, 3901469529, 2407320272, 3002914429, 4108188867, 4150506065, 1712079598, 1792979476];
+    pub const ID_CAP_CELL_API_OEM_PASSTHROUGH                       = "ID_CAP_CELL_API_OEM_PASSTHROUGH"                    [2532104890, 1144039736, 4161170727, 2796197587, 3834497427, 3804406954, 1820288173, 4083745594];
+    pub const ID_CAP_CELL_API_TELEPHONY                             = "ID_CAP_CELL_API_TELEPHONY"                          [1055897915, 1960384303,  607641621, 3024505513,  157352144,   47521771, 3445903922, 2731787806];
+    pub const ID_CAP_CELL_API_UICC                                  = "ID_CAP_CELL_API_UICC"                               [1142684182, 2416172965, 1570433021, 2300812426,  702579314, 4123790801, 1698860274, 4119760252];
+    pub const ID_CAP_CELL_API_UICC_LOWLEVEL                         = "ID_CAP_CELL_API_UICC_LOWLEVEL"                      [4044237190, 1123927719, 3336954744,  352269214, 3077346362, 4208884923, 2732909663, 2268947172];
+    pub const ID_CAP_CELL_CELLMGR                                   = "ID_CAP_CELL_CELLMGR"                                [1011430818,  504156455,  495266597,  296451570, 3607001594, 3993194721, 2992667838, 3528562777];
+    pub const ID_CAP_CELL_CMCSPWWAN_PLUS                            = "ID_CAP_CELL_CMCSPWWAN_PLUS"                         [1662710934, 4107732244, 1716792499, 2342878532, 1814454919, 3453377593, 2393766285, 3650167968];
+    pub const ID_CAP_CELL_MSFT_UICC_DATASTORE                       = "ID_CAP_CELL_MSFT_UICC_DATASTORE"                    [4288754362, 3316631918, 1416683637,  798734870, 1206362566, 2951566787, 1762360165,  945464657];
+    pub const ID_CAP_CELL_OEM_UICC_DATASTORE                        = "ID_CAP_CELL_OEM_UICC_DATASTORE"                     [1293099652, 1545311409, 3420187935, 2306721718, 4044725951, 3918725199, 3035268950, 2961784272];
+    pub const ID_CAP_CELL_RCSPRESENCE                               = "ID_CAP_CELL_RCSPRESENCE"                            [2856872537, 4175015198, 2332593846, 3669470251, 3464949743, 3365367046, 3505357433, 3554028318];
+    pub const ID_CAP_CELL_VIDEOTELEPHONY                            = "ID_CAP_CELL_VIDEOTELEPHONY"                         [1559994394, 1959683275, 1549514529, 3701899334, 2514993386, 1123522177, 2207473709, 2365152892];
+    pub const ID_CAP_CELL_WNF                                       = "ID_CAP_CELL_WNF"                                    [2231829592, 2351861398,  512327129, 1827557680, 1957081373, 3041355704, 2025819583, 1412596825];
+    pub const ID_CAP_CELL_WNF_ADMIN                                 = "ID_CAP_CELL_WNF_ADMIN"                              [2282571734,  394824064, 4052628103, 4076434356,  475413489, 3401166389, 3997647808, 2153845265];
+    pub const ID_CAP_CELL_WNF_PII                                   = "ID_CAP_CELL_WNF_PII"                                [1863506267, 3852601845, 1409170095, 1814884997, 1140501474, 1069579054, 2361145386,  320494034];
+    pub const ID_CAP_CHAMBER_PROFILE_CODE_INSTALLTEMP_RWD           = "ID_CAP_CHAMBER_PROFILE_CODE_INSTALLTEMP_RWD"        [2146644134, 2024455220, 1284882637, 2831036192, 2508141528, 4184327510, 2674580988, 2641125967];
+    pub const ID_CAP_CHAMBER_PROFILE_CODE_NITEMP_RW                 = "ID_CAP_CHAMBER_PROFILE_CODE_NITEMP_RW"              [ 130999807, 4161686417, 1506270330, 2761379233,  360188501,  790367969, 3769759909, 1329473333];
+    pub const ID_CAP_CHAMBER_PROFILE_CODE_R                         = "ID_CAP_CHAMBER_PROFILE_CODE_R"                      [ 760839710, 2499727841, 2823183578, 4284379333, 3759118430, 1440462302, 2888334003, 3474405188];
+    pub const ID_CAP_CHAMBER_PROFILE_CODE_RW                        = "ID_CAP_CHAMBER_PROFILE_CODE_RW"                     [1082845251, 1271666872, 1657514203,  202760775, 1683052000, 2228060881, 3229908919, 4277100147];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_LIVETILES_RWD             = "ID_CAP_CHAMBER_PROFILE_DATA_LIVETILES_RWD"          [3673267486, 1624631328, 3780745698, 3083101337, 2147515375,  159379544,  183356863, 1893571485];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_MEDIA_RWD                 = "ID_CAP_CHAMBER_PROFILE_DATA_MEDIA_RWD"              [3446555215, 2116936656, 4025360356, 1391670897, 2494086073, 3837975492, 2825792617, 2561959825];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_PLATFORMDATA_ALL          = "ID_CAP_CHAMBER_PROFILE_DATA_PLATFORMDATA_ALL"       [4068926054,  953498713,  112279193, 1169636279,  732685254,  649374754,  731607957, 1094719338];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_R                         = "ID_CAP_CHAMBER_PROFILE_DATA_R"                      [ 209286841,  806096319,  346990515, 1341445195, 1612311295, 1983720117, 1968221581, 2117969178];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_RW                        = "ID_CAP_CHAMBER_PROFILE_DATA_RW"                     [ 290842309,  829523119, 1542270326, 1380981194,  348398733, 3560821538, 3655423283, 3640713896];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_R            = "ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_R"         [1652071953,  392283240, 3245432346, 3922158446, 2305877741, 3919642795, 1902202120,  386425179];
+    pub const ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_RWD          = "ID_CAP_CHAMBER_PROFILE_DATA_SHELLCONTENT_RWD"       [ 810264795, 3383871660, 3719408920,  365764770, 2418770429, 1696445471, 2788378125, 3321511505];
+    pub const ID_CAP_CLIPBOARD                                      = "ID_CAP_CLIPBOARD"                                   [ 222844236,  352667633,  174289967, 4257093873, 1313571823, 1841976787, 3378976390, 2573227038];
+    pub const ID_CAP_COMMANDCHANNEL                                 = "ID_CAP_COMMANDCHANNEL"                              [3256124627, 3810317426, 1289640966, 3240387748, 3150113111,  874408365, 2462977246, 2395127491];
+    pub const ID_CAP_COMMS_APPLICATIONS                             = "ID_CAP_COMMS_APPLICATIONS"                          [1058646221, 1183543647, 2392987849, 1892576843, 3102671259, 2476554530,  385957336, 3795885151];
+    pub const ID_CAP_COMMS_COMMON                                   = "ID_CAP_COMMS_COMMON"                                [ 958069687, 2287469568, 2224753988, 1576311305, 2481697567, 2467831222, 1365457947, 1367821625];
+    pub const ID_CAP_COMMS_SERVICES                                 = "ID_CAP_COMMS_SERVICES"                              [3389991906, 3378251674, 2462981191, 3444717786, 4076244086, 4214765756, 1099135658, 3374345300];
+    pub const ID_CAP_COMMS_SETTINGS                                 = "ID_CAP_COMMS_SETTINGS"                              [2108243982, 4111216374, 2698154246,  543354630,  440637545, 1266287597, 4226678196, 3622450172];
+    pub const ID_CAP_CONTACTS                                       = "ID_CAP_CONTACTS"                                    [1197171996, 1180401634,  863664575, 1500363675,  265870253, 3312865253,  308402948,  872443910];
+    pub const ID_CAP_CONTENTSHARING                                 = "ID_CAP_CONTENTSHARING"                              [1927887678,  486748875, 3175723770, 3033794450, 1133866179, 1587462665, 1579896495, 3707033231];
+    pub const ID_CAP_CORTANA_RULES_DB                               = "ID_CAP_CORTANA_RULES_DB"                            [1173506711, 2330812073,  493000098, 3487738017, 2690907467,  838571177, 3077079861, 3568139547];
+    pub const ID_CAP_CREATE_PROCESS_IN_CHAMBER                      = "ID_CAP_CREATE_PROCESS_IN_CHAMBER"                   [1443056127, 1295755577, 1017491144, 1684624860,   97926465, 2048979871,  166773580, 1762370867];
+    pub const ID_CAP_CREDENTIAL_COLLECTION_UI                       = "ID_CAP_CREDENTIAL_COLLECTION_UI"                    [ 248168713, 1396508272, 3611549221, 3895905881, 1413340024, 3837678032, 1841449369,  890846513];
+    pub const ID_CAP_CRITICAL_DATA                                  = "ID_CAP_CRITICAL_DATA"                               [ 138363809,    3467088, 1490215246,  587520221, 1793178322, 2493211141, 3269190374, 2695746050];
+    pub const ID_CAP_CSP_BMR_PROVISION                              = "ID_CAP_CSP_BMR_PROVISION"                           [2954823967, 1070973611, 3115288494, 1657493556, 1118044130, 3628346031, 3558862584, 3571426833];
+    pub const ID_CAP_CSP_DMCLIENT                                   = "ID_CAP_CSP_DMCLIENT"                                [ 332167875, 1987229510, 2155973060, 4197885389, 2557206516, 3565524291, 4199577024, 1508571988];
+    pub const ID_CAP_CSP_FOUNDATION                                 = "ID_CAP_CSP_FOUNDATION"                              [3962685180, 2727725489, 1431031852, 2008534362, 1444577716, 1123901425, 2142846841, 2140214728];
+    pub const ID_CAP_CSP_LOCATION                                   = "ID_CAP_CSP_LOCATION"                                [ 414058345, 1567702652, 2123242003, 1184004469, 1239901273,  162919860, 1899731930, 1037055215];
+    pub const ID_CAP_CSP_MAIL                                       = "ID_CAP_CSP_MAIL"                                    [2074114630,   43164284, 3897716515, 2849770325, 4236841356, 3347585675,   27537832, 3423802682];
+    pub const ID_CAP_CSP_NODECACHE                                  = "ID_CAP_CSP_NODECACHE"                               [1817452701, 2791420327, 2147259100, 2427997475, 1013553902, 1158036212, 2248568046, 1548551427];
+    pub const ID_CAP_CSP_OEM                                        = "ID_CAP_CSP_OEM"                                     [4148803163, 4222635583, 3650302675,  772522717, 1888208462,  337264360,  108366612, 3255716077];
+    pub const ID_CAP_CSP_PHONE                                      = "ID_CAP_CSP_PHONE"                                   [1252078408, 1235448904, 3611794438,  402533519,  233538133,  905155232,  517208258, 2165556586];
+    pub const ID_CAP_CSP_W4_APPLICATION                             = "ID_CAP_CSP_W4_APPLICATION"                          [ 771369014, 2513237227, 1020759731,  403978089, 4128290342, 4224802253, 1695118744, 2068837445];
+    pub const ID_CAP_CSP_WIFI_HOTSPOT                               = "ID_CAP_CSP_WIFI_HOTSPOT"                            [1761694984, 3912493281, 1861110515,  878234316, 2619819446, 3144417484,  438739344, 2061860810];
+    pub const ID_CAP_DATACOLLECTION_ACTIVITY                        = "ID_CAP_DATACOLLECTION_ACTIVITY"                     [3924836869, 2402652201,  917751304, 2195433838,  527715606,  501985156, 3022072383,  502617940];
+    pub const ID_CAP_DATACOLLECTION_COLLECTOR                       = "ID_CAP_DATACOLLECTION_COLLECTOR"                    [3861360422, 3747984805, 2526703588, 3139918551, 3843580662, 2644649359, 3887668524, 3974780753];
+    pub const ID_CAP_DATACOLLECTION_RAWETW                          = "ID_CAP_DATACOLLECTION_RAWETW"                       [2688760231, 3723009682, 2246147497, 2638749555, 1371528447,  603967105,  532615831, 1890968348];
+    pub const ID_CAP_DATAPLANUSAGE                                  = "ID_CAP_DATAPLANUSAGE"                               [ 809918879, 3266368437, 1032700330, 4059202277, 1287781095,  322135617, 2724141017, 3164731245];
+    pub const ID_CAP_DATAPLANUSAGE_ADMIN                            = "ID_CAP_DATAPLANUSAGE_ADMIN"                         [4164502307,  472739483, 2012792192,  485056344, 3049938215, 3872781645, 2769192755, 3736333899];
+    pub const ID_CAP_DCP                                            = "ID_CAP_DCP"                                         [1212681987, 3261125259, 1288304779,  959287455, 3456885356,  894705911, 3613934734, 2257627486];
+    pub const ID_CAP_DEBUG                                          = "ID_CAP_DEBUG"                                       [4124136541, 3153762127, 1995690476, 2520270420, 1733563741, 1220593188, 2070907279,   92460020];
+    pub const ID_CAP_DEBUG_FOLDERS                                  = "ID_CAP_DEBUG_FOLDERS"                               [2334113612, 2399514642,  934278562,  796768778, 3716544688,  125856520,  684501636,  973439742];
+    pub const ID_CAP_DEBUG_NAVIGATION                               = "ID_CAP_DEBUG_NAVIGATION"                            [3255960977, 3884914884, 2503084951, 1927402358, 2683065496, 2192479232,  837259997, 1888831111];
+    pub const ID_CAP_DEVELOPERUNLOCK                                = "ID_CAP_DEVELOPERUNLOCK"                             [2489250862, 3731101856,  757172019, 2830005102, 2903107461, 2549818383, 1921265406,  345878668];
+    pub const ID_CAP_DEVELOPERUNLOCK_API                            = "ID_CAP_DEVELOPERUNLOCK_API"                         [ 435026874,  574125424, 2562811554, 2720811615, 3432479418, 1962428897, 4127210868,  641492088];
+    pub const ID_CAP_DEVELOPERUNLOCK_CODEDUI                        = "ID_CAP_DEVELOPERUNLOCK_CODEDUI"                     [2499537368,  280841756,  309285745, 1864881169, 1548990357, 1104000173,  362454985, 3042039205];
+    pub const ID_CAP_DEVICE_LOCK                                    = "ID_CAP_DEVICE_LOCK"                                 [1489831172, 3244057183, 1355226266, 2620099493, 1119665031, 4229374708, 3428460220,  288589085];
+    pub const ID_CAP_DEVICE_LOCK_ADMIN                              = "ID_CAP_DEVICE_LOCK_ADMIN"                           [1239508518, 2238973216, 3807685320, 1092683601,  713720309, 4144038406, 3673310705,  586043027];
+    pub const ID_CAP_DEVICE_MANAGEMENT                              = "ID_CAP_DEVICE_MANAGEMENT"                           [2228988914, 1158933111,  610973818, 3842313431, 3370586393, 3655460759, 2764951963, 2319834171];
+    pub const ID_CAP_DEVICE_MANAGEMENT_ADMIN                        = "ID_CAP_DEVICE_MANAGEMENT_ADMIN"                     [2155373409, 2588654755, 3485356470, 3792173750, 3253731926, 2855594029, 1916234755, 4233411287];
+    pub const ID_CAP_DEVICE_MANAGEMENT_BOOTSTRAP                    = "ID_CAP_DEVICE_MANAGEMENT_BOOTSTRAP"                 [1328671235,  350676372, 4116672234,  162651118,  748707714, 3181081022, 2309470168, 3270492729];
+    pub const ID_CAP_DEVICE_MANAGEMENT_SECURITY_POLICIES            = "ID_CAP_DEVICE_MANAGEMENT_SECURITY_POLICIES"         [1964422670, 2805317472, 2342955854, 3804864184, 3183813567, 4062829734, 3944473842, 3339738272];
+    pub const ID_CAP_DIAGNOSTIC_CLIENT                              = "ID_CAP_DIAGNOSTIC_CLIENT"                           [1379664725, 2008455863,  758815057, 1406777863, 3082387944,  488339782, 3210944971, 4086137459];
+    pub const ID_CAP_DISPLAY_CONTROL                                = "ID_CAP_DISPLAY_CONTROL"                             [2480063834,  169439344,   49649540,  687616893, 1042058597, 1212544839, 3006004344,   88034819];
+    pub const ID_CAP_DMCLIENT_APPMGMT                               = "ID_CAP_DMCLIENT_APPMGMT"                            [ 976733580, 2223430783, 2526734031, 2536390451,   33293082, 1916709982, 1878906195,  543786063];
+    pub const ID_CAP_DO_NOT_DISTURB                                 = "ID_CAP_DO_NOT_DISTURB"                              [ 685409917, 1960778880, 2661502165, 1243528365, 3712556246,  383787831, 4205420744, 1235633388];
+    pub const ID_CAP_DRIVE_MODE_ADMIN                               = "ID_CAP_DRIVE_MODE_ADMIN"                            [ 118476523, 1560294579, 1523501274,  533019312, 3677284674, 2916650447,  543332398, 2461112060];
+    pub const ID_CAP_DUASVC                                         = "ID_CAP_DUASVC"                                      [3014337281, 3076985269, 2306973044,  931956108, 1654243562, 1528310509, 3874019897, 2265632643];
+    pub const ID_CAP_DU_AGENT                                       = "ID_CAP_DU_AGENT"                                    [2531874001, 4195569115, 1037263328, 4149345804, 1095754468, 4107092350,    4233463, 3469999000];
+    pub const ID_CAP_DU_CORE_API                                    = "ID_CAP_DU_CORE_API"                                 [1226953425, 3419189512, 3223653583, 1347440823, 3119245460, 2794704593, 2920336145, 1823471725];
+    pub const ID_CAP_DU_CSP                                         = "ID_CAP_DU_CSP"                                      [2214225167, 3330007875,  629360422, 3050574468, 1464325285, 1869819653, 2258590376, 1960021788];
+    pub const ID_CAP_DU_MIGRATION_MANAGER_STATUS                    = "ID_CAP_DU_MIGRATION_MANAGER_STATUS"                 [1451293913, 2682386164, 1698168141,  507782222, 1261213888, 3428560876, 2362614729,  796567568];
+    pub const ID_CAP_DU_MIGRATION_WNF_EVENTS                        = "ID_CAP_DU_MIGRATION_WNF_EVENTS"                     [ 784231915, 1165677272, 3126119956, 3469223958,  894119068, 2636790375,  948156418, 4061084044];
+    pub const ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_MICROSOFT      = "ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_MICROSOFT"   [1803325392, 1198559281, 2792641443, 4096426005,  950982541, 3858215606, 1016569512, 3952164362];
+    pub const ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_OEM            = "ID_CAP_DU_MIGRATOR_PROVISIONING_STATUS_OEM"         [2996827300,  423150910, 1944581438,  935479097, 2914241558,  772285636, 1545424803, 2981458854];
+    pub const ID_CAP_DU_MIGRATOR_STATUS_MICROSOFT                   = "ID_CAP_DU_MIGRATOR_STATUS_MICROSOFT"                [ 847915132, 3397513221, 2729164747,  661030598, 2229682356, 2736855220, 3175867399, 1073982662];
+    pub const ID_CAP_DU_MIGRATOR_STATUS_OEM                         = "ID_CAP_DU_MIGRATOR_STATUS_OEM"                      [1519675861, 2437020546, 1615672005,  647331025,  902022321, 3878165358,  289944754, 3198462634];
+    pub const ID_CAP_DU_PROVISIONING                                = "ID_CAP_DU_PROVISIONING"                             [  18617438, 3852555694,  615306130, 3253026272, 2048140001, 1944573627, 2413547685, 2470068587];
+    pub const ID_CAP_DU_SHARED_DATA                                 = "ID_CAP_DU_SHARED_DATA"                              [ 255531134, 2625125579, 3847820191, 1331643250, 2281001489, 4033983812, 1313350698, 2181535199];
+    pub const ID_CAP_DU_USS                                         = "ID_CAP_DU_USS"                                      [3410625515, 1897376185, 2271064583, 2692904646, 3543270832, 3287988002, 2757857819, 1512463620];
+    pub const ID_CAP_DU_UX                                          = "ID_CAP_DU_UX"                                       [2203255898,  671293067,  536297485, 3631363548, 2248610747, 2997346404, 3626990492, 3282168510];
+    pub const ID_CAP_DU_UX_FEATURE_DISCOVERY                        = "ID_CAP_DU_UX_FEATURE_DISCOVERY"                     [1240962295, 1066817092,  382400508,  111070999, 3644175079, 2796254822, 2992821092, 3665506769];
+    pub const ID_CAP_EAS_CREDENTIALS                                = "ID_CAP_EAS_CREDENTIALS"                             [3314012467,  548991937, 3239568731, 3627493128,  309556088, 3389531339,  100475702, 2195576633];
+    pub const ID_CAP_EDM_CACHE_RWDELETE                             = "ID_CAP_EDM_CACHE_RWDELETE"                          [1560490093, 3150263679, 3913073905,  493218528, 1217089098, 3525877903, 1094563869, 3290915522];
+    pub const ID_CAP_EDM_CACHE_WRITE                                = "ID_CAP_EDM_CACHE_WRITE"                             [3600665919, 2363766130, 3622485169, 2660972934, 2570866048, 1280798460, 4214171462, 2231352199];
+    pub const ID_CAP_ENDPOINTDISCOVERY                              = "ID_CAP_ENDPOINTDISCOVERY"                           [ 180657347,  122741296, 4180725554, 3164626001,  398111854, 1243558791, 1608794046,  395299256];
+    pub const ID_CAP_ENROLLMENT                                     = "ID_CAP_ENROLLMENT"                                  [3008406548, 3193339295, 1251318144, 3954455267, 3334506038, 1529522549, 1458996898, 2362216182];
+    pub const ID_CAP_ENROLLMENT_ADMIN                               = "ID_CAP_ENROLLMENT_ADMIN"                            [ 849533162, 3606139354,  920253203, 3521488610, 4067001817, 2836320569,  628689121, 4067438904];
+    pub const ID_CAP_ENROLLMENT_POLL                                = "ID_CAP_ENROLLMENT_POLL"                             [1219577130,   94837940, 4021897505, 2542399292,  149408625, 4230882943,  598849806, 3414254126];
+    pub const ID_CAP_ENROLLMENT_RENEW                               = "ID_CAP_ENROLLMENT_RENEW"                            [1066792675, 1856752121,  448263667,  183492887, 2384563306,  190501776, 2027390660, 2623264803];
+    pub const ID_CAP_ENTERPRISERESOURCESTORE                        = "ID_CAP_ENTERPRISERESOURCESTORE"                     [3545864811, 1630256340, 3708683831,  891538989, 1829828887, 1735606505,  351795614, 2246489594];
+    pub const ID_CAP_ENTERPRISE_AUTHENTICATION                      = "ID_CAP_ENTERPRISE_AUTHENTICATION"                   [2565089908, 3235485705, 3531660385, 2422532460, 2509806275, 3383142038,  151746435, 3208414149];
+    pub const ID_CAP_ENTERPRISE_ENROLLMENT                          = "ID_CAP_ENTERPRISE_ENROLLMENT"                       [ 744342384,  171107169, 3300840640, 3661608118, 1718901758, 2019771437, 2988664388, 1285509149];
+    pub const ID_CAP_ENTERPRISE_SERVICE                             = "ID_CAP_ENTERPRISE_SERVICE"                          [2915258276, 1983216279, 3932826278, 2679152272, 3008442640, 1290338783, 2673756690,  218180599];
+    pub const ID_CAP_ENTERPRISE_SHARED_DATA                         = "ID_CAP_ENTERPRISE_SHARED_DATA"                      [ 620567821,  737739306,  169290276, 3267440968, 3369811615, 2277188922,  284744457, 2472170725];
+    pub const ID_CAP_ETW_PROFILER                                   = "ID_CAP_ETW_PROFILER"                                [ 286988506, 3024496329, 1242029813, 1489639228, 4118076720, 3312578029, 1950888325, 3262055617];
+    pub const ID_CAP_EVERYONE                                       = "ID_CAP_EVERYONE"                                    [3625662137, 2682091254,  856171984, 2868379045, 3001028726, 1009205972, 4175949866,  684286152];
+    pub const ID_CAP_EVERYONE_INROM                                 = "ID_CAP_EVERYONE_INROM"                              [1455060294, 2006611718, 1394859594, 1785343116,  982899002, 3021835531, 2553697285, 1415319556];
+    pub const ID_CAP_EXTERNAL_DISPLAY                               = "ID_CAP_EXTERNAL_DISPLAY"                            [2902951254, 1797683809,  212196379, 3580678323, 4090286333,  519029020, 2280042480, 4265124335];
+    pub const ID_CAP_FAILURE_REPORT_CONTENT_PROVIDER                = "ID_CAP_FAILURE_REPORT_CONTENT_PROVIDER"             [2128461642, 3036957772, 2339447430,   62716173, 3211901513,  102092477, 1795471829, 3600068674];
+    pub const ID_CAP_FINDMYPHONE                                    = "ID_CAP_FINDMYPHONE"                                 [3695219931,  660708132, 2934828679, 1584772490, 4250982312,  345006032, 1319211406, 2159038307];
+    pub const ID_CAP_FOREGROUND_TASK_MANAGER                        = "ID_CAP_FOREGROUND_TASK_MANAGER"                     [1516636972, 4002063081, 3519513159, 4121188293, 2993883606, 1826224279,  276807110, 2856712331];
+    pub const ID_CAP_GAMERSERVICES                                  = "ID_CAP_GAMERSERVICES"                               [1872380395, 2394361243, 3570888592, 3826462484,   53510525, 1115525068, 1082957871, 3617547939];
+    pub const ID_CAP_GLOBALIZATION_SETTINGS                         = "ID_CAP_GLOBALIZATION_SETTINGS"                      [2034345757, 2366417288, 1978395495,  338449883, 4149174378, 2073426543,  324039589, 2688632710];
+    pub const ID_CAP_HTTP_ACCEPT_LANGUAGE_HEADER                    = "ID_CAP_HTTP_ACCEPT_LANGUAGE_HEADER"                 [2947865464, 3707630253, 1836471950, 3892478024,  484581425, 2237510785, 3513261613, 2086455247];
+    pub const ID_CAP_ICS_RO                                         = "ID_CAP_ICS_RO"                                      [3370394368, 3817654550, 1919658829, 3274896815, 2391583585, 3783478911, 1164931198, 2338837334];
+    pub const ID_CAP_ICS_RW                                         = "ID_CAP_ICS_RW"                                      [2376854566,  714973910, 1571250757, 2327293875, 1093179939,  177522618, 3998750142, 2013394283];
+    pub const ID_CAP_IDENTITY_DEVICE                                = "ID_CAP_IDENTITY_DEVICE"                             [3429164866, 2191917383,  213833408,  991824664,  198389277, 3980030541, 4112569327, 2473651841];
+    pub const ID_CAP_IDENTITY_DEVICE_1ST_PARTY                      = "ID_CAP_IDENTITY_DEVICE_1ST_PARTY"                   [ 168874820, 4035925987, 1866170320, 4055887615, 2101277113, 1306559416, 2689441767, 3305189688];
+    pub const ID_CAP_IDENTITY_USER                                  = "ID_CAP_IDENTITY_USER"                               [2460573504, 1107513584,  874306478, 1530161711,  981307678, 1044080339,  874738631, 1612604526];
+    pub const ID_CAP_IDENTITY_USER_1ST_PARTY                        = "ID_CAP_IDENTITY_USER_1ST_PARTY"                     [1416632374, 3152971308, 3633238730, 2124956326, 2422158797, 1274540462, 2007697920,  257886977];
+    pub const ID_CAP_IDM_IMAGE_CACHE                                = "ID_CAP_IDM_IMAGE_CACHE"                             [ 618155720, 2268190192,  758728301, 3788533235, 2325132483, 1298638933, 1897612795, 1138920060];
+    pub const ID_CAP_IMMERSIVE_SHELL                                = "ID_CAP_IMMERSIVE_SHELL"                             [2430522714, 2303381836, 3993576826, 1394744092, 1889838422, 4211954870, 2677099355, 3219218595];
+    pub const ID_CAP_INPUT_CORE                                     = "ID_CAP_INPUT_CORE"                                  [1233963979, 3604205921,  783019062, 2710191449,  448176888, 3593028319, 2099797817, 2075145449];
+    pub const ID_CAP_INPUT_FEATURES                                 = "ID_CAP_INPUT_FEATURES"                              [3262710851, 1710859767, 2737508763, 3495686296,  640998709, 1991764799, 2766712646, 1250801225];
+    pub const ID_CAP_INPUT_INJECTION                                = "ID_CAP_INPUT_INJECTION"                             [1035056073, 1733005359, 1519077546, 1086916186, 2279488988, 2183739572,  220941870, 1919561933];
+    pub const ID_CAP_INPUT_LOCALES                                  = "ID_CAP_INPUT_LOCALES"                               [1101397092, 2440240829, 3797790566, 4209785274, 2009480717,  948395056, 1229586094,  215337311];
+    pub const ID_CAP_INPUT_SERVICE                                  = "ID_CAP_INPUT_SERVICE"                               [3352920935,  867635778,  712737162, 1784698022, 3870224835, 2806424464,    9011912, 2570127640];
+    pub const ID_CAP_INSTALL_CERTIFICATES                           = "ID_CAP_INSTALL_CERTIFICATES"                        [3082507980, 3684210319, 2652201932, 3690136872,  602693295, 4058059558, 2202901681, 3741552030];
+    pub const ID_CAP_INTENTTEXTRACTION_OPTIN                        = "ID_CAP_INTENTTEXTRACTION_OPTIN"                     [ 980967807, 3836891893,  323676848, 3003869716, 2276576678, 2034561973, 2078281271, 3587510752];
+    pub const ID_CAP_INTERNAL_DEPLOYMENT                            = "ID_CAP_INTERNAL_DEPLOYMENT"                         [ 910556621, 3497303107, 1591549455,  558772794, 2771772607,   83600851, 3078711426, 1008145493];
+    pub const ID_CAP_INTERNET_EXPLORER_BROWSER_HISTORY              = "ID_CAP_INTERNET_EXPLORER_BROWSER_HISTORY"           [ 215216260, 2541310576, 1871977666, 2482418043, 3551962778,  764791109,  430462217, 2133980401];
+    pub const ID_CAP_INTERNET_EXPLORER_DATA_OPTIMIZATION            = "ID_CAP_INTERNET_EXPLORER_DATA_OPTIMIZATION"         [2512033128, 1934371329, 1396917299,  602712499, 2118838388, 3662193539, 1816711069,  891295454];
+    pub const ID_CAP_INTERNET_EXPLORER_FAVORITES                    = "ID_CAP_INTERNET_EXPLORER_FAVORITES"                 [ 167496267, 3773424399,  418724309, 3678360354, 2720289775, 3317683410, 1531689842, 3812883038];
+    pub const ID_CAP_INTERNET_EXPLORER_HKCU_WRITE                   = "ID_CAP_INTERNET_EXPLORER_HKCU_WRITE"                [2684667327, 3122140483, 2021074168, 1405552287, 3551959018, 4245185083,  761018022, 1374931399];
+    pub const ID_CAP_INTERNET_EXPLORER_HKLM_SECURITY_SETTINGS       = "ID_CAP_INTERNET_EXPLORER_HKLM_SECURITY_SETTINGS"    [1064255300, 3705235173, 3265537868, 2561867353,  104214424, 1331983388,  269160328, 2424223790];
+    pub const ID_CAP_INTERNET_EXPLORER_INTRANET_ZONE_SETTINGS       = "ID_CAP_INTERNET_EXPLORER_INTRANET_ZONE_SETTINGS"    [2296493237, 4207573705, 3065918245, 3944155167, 1056189801, 4048482375, 4148527376,  654950682];
+    pub const ID_CAP_INTERNET_EXPLORER_REMOTEDEBUGGING              = "ID_CAP_INTERNET_EXPLORER_REMOTEDEBUGGING"           [ 909491541, 1327750333, 4143730011, 2825595118,   97896828,  602374609, 2956890295, 2044876867];
+    pub const ID_CAP_INTERNET_EXPLORER_ROAMING                      = "ID_CAP_INTERNET_EXPLORER_ROAMING"                   [4040839448, 4059499125, 3846365656, 3092493563, 3782258769,  333365809, 3738708540, 2579162281];
+    pub const ID_CAP_INTERNET_EXPLORER_SEARCH_PROVIDER_KEYS_HKCU    = "ID_CAP_INTERNET_EXPLORER_SEARCH_PROVIDER_KEYS_HKCU" [3396939252, 3037638781, 2203719402, 1477295694, 1589261024, 2068350459,  968012281, 1053905080];
+    pub const ID_CAP_INTEROPSERVICES                                = "ID_CAP_INTEROPSERVICES"                             [3924064082, 2900048707, 2201890956, 2645363950, 3844151028, 2141764874, 1227579062, 1245366485];
+    pub const ID_CAP_ISV_CAMERA                                     = "ID_CAP_ISV_CAMERA"                                  [3574683068, 1752226521, 1155147068,  583173471, 3187734153, 2008299352, 2522859398, 1938523291];
+    pub const ID_CAP_KEYBOARD                                       = "ID_CAP_KEYBOARD"                                    [3836501916, 2568089871, 1148191526,  173975558,  164723197, 2491679206, 4003055065, 1321685210];
+    pub const ID_CAP_KIDZONE_ADMIN                                  = "ID_CAP_KIDZONE_ADMIN"                               [1937517091, 3370629626, 2246338551, 3730719320, 3997315828, 1084494683, 3015107921, 2901759513];
+    pub const ID_CAP_KIDZONE_CUSTOMIZATION                          = "ID_CAP_KIDZONE_CUSTOMIZATION"                       [1723508240, 2717850342,  382424384,  164802953, 1173124549,  428953726, 1413907134, 2523362012];
+    pub const ID_CAP_LANGUAGEUNDERSTANDING                          = "ID_CAP_LANGUAGEUNDERSTANDING"                       [3813515617, 2756186739, 2213645134, 3995971687, 2051712450,  695732763, 3203113883, 1226727160];
+    pub const ID_CAP_LASS_ADMIN                                     = "ID_CAP_LASS_ADMIN"                                  [3401703828, 4223757080, 1281354830,  248557279, 3329705698, 1725461822, 3631041432, 1785263900];
+    pub const ID_CAP_LASS_REMOTELOCK                                = "ID_CAP_LASS_REMOTELOCK"                             [ 981966782, 1720752150, 3221052612, 3446074739,  381423383, 3175214235,  505805607,  964401029];
+    pub const ID_CAP_LEGACY_VOICEMAIL_HANDLER                       = "ID_CAP_LEGACY_VOICEMAIL_HANDLER"                    [ 186728904, 1248966709, 2837533813, 4151748619, 1438224980, 3453962350, 1184454379, 1550029409];
+    pub const ID_CAP_LEXICONUPDATE                                  = "ID_CAP_LEXICONUPDATE"                               [1264160392, 3247308276,   12820063,  458595013, 2768734661, 1421984703, 2613230271,  777289288];
+    pub const ID_CAP_LIVEID                                         = "ID_CAP_LIVEID"                                      [4205469904, 3955512119, 1921840463, 2315182984,  307650067,  690880892, 3528877815, 4066519405];
+    pub const ID_CAP_LIVETOKEN_WNF_EVENTS                           = "ID_CAP_LIVETOKEN_WNF_EVENTS"                        [3197155618, 1476057154,  996217435,  565093570, 1900910048, 2888369104, 1558542099, 3514376177];
+    pub const ID_CAP_LOCATION                                       = "ID_CAP_LOCATION"                                    [2158456844, 3754929254,  744589270, 3611187126, 2481208986,   30837703, 3416168463, 2437063433];
+    pub const ID_CAP_LOCATION_ADMIN                                 = "ID_CAP_LOCATION_ADMIN"                              [3842824567,  178914259,  466740046,  159386189, 4235713590, 3349026085, 1947878110, 3889710422];
+    pub const ID_CAP_LOCATION_BTPOLICY                              = "ID_CAP_LOCATION_BTPOLICY"                           [1354734771,  155420121, 3897373361, 3752280624,  978827147, 3248344458, 2231583132, 1173781009];
+    pub const ID_CAP_LOCATION_GNSSDRIVER                            = "ID_CAP_LOCATION_GNSSDRIVER"                         [ 247686312, 1620689180, 1764687748, 4038688570,   52657645, 1378966326, 1794119530, 4209451477];
+    pub const ID_CAP_MAP                                            = "ID_CAP_MAP"                                         [2170369038, 2364263206, 3644932994, 2881238354,  652065972,  571287047, 4120350065, 3877263117];
+    pub const ID_CAP_MAP_ADMIN                                      = "ID_CAP_MAP_ADMIN"                                   [ 190440352,  439155184, 4282510597,  406381981, 2309681896, 1385846260, 2368095517, 2056010090];
+    pub const ID_CAP_MAP_WRITE                                      = "ID_CAP_MAP_WRITE"                                   [2275930499, 3802028574, 1447246862,  156004382, 3908323893, 3618243178, 2076838221, 2612873139];
+    pub const ID_CAP_MEDIALIB                                       = "ID_CAP_MEDIALIB"                                    [2994109957, 4202701374,  210186623, 1877066336, 3921449257,  878208989, 1344179568, 3804437724];
+    pub const ID_CAP_MEDIALIB_AUDIO                                 = "ID_CAP_MEDIALIB_AUDIO"                              [1829253335, 2916852241, 1691201532, 2042887584, 3082970389, 2581791321, 2654304389, 1137601773];
+    pub const ID_CAP_MEDIALIB_INT                                   = "ID_CAP_MEDIALIB_INT"                                [3656842009,  737305612,  962701380,  725461182, 1973642844,  651574988,  229186158, 3868155400];
+    pub const ID_CAP_MEDIALIB_PHOTO                                 = "ID_CAP_MEDIALIB_PHOTO"                              [3774042465, 3895949944, 2763861358, 2371489234, 2970034413, 4106687434, 1108259975, 3402584636];
+    pub const ID_CAP_MEDIALIB_PHOTO_FULL                            = "ID_CAP_MEDIALIB_PHOTO_FULL"                         [ 340549587,  319435834, 2626276056,  321901470,  621689951, 4197727772, 3605247457, 3248652731];
+    pub const ID_CAP_MEDIALIB_PLAYBACK                              = "ID_CAP_MEDIALIB_PLAYBACK"                           [3048910823,  215476441,  393023149, 2072296119, 2376215360,  537725869, 1269244064,  386967405];
+    pub const ID_CAP_MEDIALIB_VIDEO                                 = "ID_CAP_MEDIALIB_VIDEO"                              [3373296908, 2411484724, 3515767462,  947765668, 3509355982, 3670830776, 3890152790, 1465475484];
+    pub const ID_CAP_MEDIASERVICE_VOLUMELIMIT_INT                   = "ID_CAP_MEDIASERVICE_VOLUMELIMIT_INT"                [3645416969, 1011500028, 2073745651, 1538258254, 1988792832,  559144643,  404630243, 1406387951];
+    pub const ID_CAP_MICMUTEPOLICY_BYPASS                           = "ID_CAP_MICMUTEPOLICY_BYPASS"                        [ 476603989,  259620494,  892947199, 2401694451, 1301517928, 3928814315, 1586620433, 1692557618];
+    pub const ID_CAP_MICROPHONE                                     = "ID_CAP_MICROPHONE"                                  [ 927943109,   13579301, 3264376126,  717908401, 1136080082, 1546768108,  382930956, 2230590623];
+    pub const ID_CAP_MONITOR_NAVIGATION                             = "ID_CAP_MONITOR_NAVIGATION"                          [ 463525949, 3729825856, 3721196555, 1834767050, 1067004685, 3688468005,  502025504, 3138201405];
+    pub const ID_CAP_MOUSE                                          = "ID_CAP_MOUSE"                                       [3009341472,   91027001,  896432746, 1588442292, 4198615671, 3544974328, 2153679188, 2970467515];
+    pub const ID_CAP_MO_CLOUDMESSAGING                              = "ID_CAP_MO_CLOUDMESSAGING"                           [ 573494862,  852034305, 3966313457, 4224520700,  510758616, 3440425295,  640747520, 1074373680];
+    pub const ID_CAP_MSS_BYTESTREAM_RPC                             = "ID_CAP_MSS_BYTESTREAM_RPC"                          [1817041610, 1357507976, 1922323357, 3631384676, 1861098856, 3413700130,  528239895, 2660034030];
+    pub const ID_CAP_MULTIMEDIA_ENCODER_HARDWARE                    = "ID_CAP_MULTIMEDIA_ENCODER_HARDWARE"                 [1662860686, 2816204406, 2911754083, 3516156014, 1995804603, 1363387103, 2936568884, 3601232708];
+    pub const ID_CAP_MULTIVARIANT                                   = "ID_CAP_MULTIVARIANT"                                [2170476733, 2975702239, 2586780612, 1387577921, 3676160382, 3040881160, 4185834788, 1301956967];
+    pub const ID_CAP_MULTIVARIANT_INSTALL_DATA                      = "ID_CAP_MULTIVARIANT_INSTALL_DATA"                   [3149549929, 3972215291, 2780813207, 1878560769, 3866535526, 2700445554, 3348068313, 1081718229];
+    pub const ID_CAP_NARRATOR_SETTINGS                              = "ID_CAP_NARRATOR_SETTINGS"                           [3573991055, 3390900411, 2902283275, 2596561414,  438799178, 3294465257, 1220183201,  288271875];
+    pub const ID_CAP_NATIVE_NETWORK_REPLACEMENT                     = "ID_CAP_NATIVE_NETWORK_REPLACEMENT"                  [3251777039,  978424543, 1177289640,  593613892, 4088880775, 1000623787, 1546748207, 1192316703];
+    pub const ID_CAP_NAVIGATIONBAR_ADMIN                            = "ID_CAP_NAVIGATIONBAR_ADMIN"                         [2677381221, 2364199280,   75434427, 4027356186, 3931609390,  171686425, 1692581318, 3596168183];
+    pub const ID_CAP_NETWORKING                                     = "ID_CAP_NETWORKING"                                  [ 138645281, 2108396503, 3422487579, 2819378634, 2394291191, 1702401616, 2644146957, 3035521095];
+    pub const ID_CAP_NETWORKING_ADMIN                               = "ID_CAP_NETWORKING_ADMIN"                            [3513569483, 3574884580, 2264482402, 1036786788, 2626686027, 1491812377, 1593087649, 4159331373];
+    pub const ID_CAP_NETWORKING_INTERNET_CLIENT                     = "ID_CAP_NETWORKING_INTERNET_CLIENT"                  [2829306766, 4112427273, 2220422607, 4222497197, 2605065597, 1514279654, 4134446667,  545936211];
+    pub const ID_CAP_NETWORKING_INTERNET_CLIENT_SERVER              = "ID_CAP_NETWORKING_INTERNET_CLIENT_SERVER"           [ 525014453, 2813097422, 4141608865, 2528114376,  676196975, 2122114356, 2529573448,   40745366];
+    pub const ID_CAP_NETWORKING_PRIVATE_NETWORK_CLIENT_SERVER       = "ID_CAP_NETWORKING_PRIVATE_NETWORK_CLIENT_SERVER"    [1430229515, 1253603003, 4160494546, 4108025832, 3485602219, 3429995982,  321895001, 3881966585];
+    pub const ID_CAP_NETWORKING_VPN_ADMIN                           = "ID_CAP_NETWORKING_VPN_ADMIN"                        [2402180121, 4188564863, 1108428934, 2917162313, 3356156168,  563273755, 3500347936,  713065434];
+    pub const ID_CAP_NETWORKING_VPN_PROVIDER                        = "ID_CAP_NETWORKING_VPN_PROVIDER"                     [2579400809, 3867311217, 3984994116,  908665914, 3508570097, 1336497314,  873935804, 1444405236];
+    pub const ID_CAP_NETWORKING_VPN_SERVICES                        = "ID_CAP_NETWORKING_VPN_SERVICES"                     [1793611161,  574360918, 2777701736,  773189098, 3605514996, 2754194708, 3865425388, 2767375059];
+    pub const ID_CAP_NFC_ADMIN                                      = "ID_CAP_NFC_ADMIN"                                   [3523166394,   57408359, 2383958183, 3147263783, 1336643290,  347002375, 2636801930, 1129988835];
+    pub const ID_CAP_NOCENTER_SOUNDS                                = "ID_CAP_NOCENTER_SOUNDS"                             [4067107036, 2026004423, 1492954149,  391728022, 1437832288, 2094538104,  818650315, 1611581509];
+    pub const ID_CAP_NTSERVICES                                     = "ID_CAP_NTSERVICES"                                  [1262420712, 2639779015,  994701577, 2179912347,  972350065,  251047734, 1848752318, 2372844867];
+    pub const ID_CAP_NVREAD                                         = "ID_CAP_NVREAD"                                      [1707525735, 3206815453, 1112114967,   48267877, 1887687392, 2051739574, 2847046674,  867112312];
+    pub const ID_CAP_NVREADWRITE                                    = "ID_CAP_NVREADWRITE"                                 [ 366053087, 1602002883, 4042181830, 3743824562, 3607022702, 3824804250,  710359348, 3399734679];
+    pub const ID_CAP_O365_DISCOVERY                                 = "ID_CAP_O365_DISCOVERY"                              [ 810132407,   95717014, 1476393367, 4020883739, 3911322142, 2429325099, 2744200121, 3604556736];
+    pub const ID_CAP_OEMPUBLICDIRECTORY                             = "ID_CAP_OEMPUBLICDIRECTORY"                          [3965197257,  261382818, 3459785493, 2444674519, 2854280670, 4221822968,  408757942,  640176997];
+    pub const ID_CAP_OEM_ADC                                        = "ID_CAP_OEM_ADC"                                     [2622277354, 1768194988,  622092707, 3347898202, 2105816107, 1605862553, 2861187806, 2412770845];
+    pub const ID_CAP_OEM_CUSTOM                                     = "ID_CAP_OEM_CUSTOM"                                  [1893194748, 4220550178, 2702675942, 3564749320, 3271398048, 3617688994, 1131699573, 2458832566];
+    pub const ID_CAP_OEM_DEPLOYMENT                                 = "ID_CAP_OEM_DEPLOYMENT"                              [2958768839, 3411888887, 2065171532, 2969248120, 4109516085, 1754713048, 1134839755, 2233678777];
+    pub const ID_CAP_OFFICE_LAUNCH_URL                              = "ID_CAP_OFFICE_LAUNCH_URL"                           [2681780456, 3661254415, 1111940113, 3201886886, 4164730186,  831959241,  946560143,  266930336];
+    pub const ID_CAP_OFFICE_MSDRM_HKCU                              = "ID_CAP_OFFICE_MSDRM_HKCU"                           [ 991313912,  243902482, 2624699540,  651706158,  961580552,  379012443, 3180448065, 2066896413];
+    pub const ID_CAP_ONENOTE_EVENTS                                 = "ID_CAP_ONENOTE_EVENTS"                              [2744103101,  942172209, 4204088677, 2593947019, 4267115091,  862081063, 2467439316, 1062170560];
+    pub const ID_CAP_OOBE_PRIVATE                                   = "ID_CAP_OOBE_PRIVATE"                                [  10023765,  417243959, 2585496365, 3037374631, 2965961089, 3867533075,  299706992, 2663072434];
+    pub const ID_CAP_ORIENTATION_LOCK                               = "ID_CAP_ORIENTATION_LOCK"                            [1630993814,  601217366,  903566270, 3529248719, 3990361547, 3941825027,  936573278, 1183785738];
+    pub const ID_CAP_PEOPLE_EXTENSION                               = "ID_CAP_PEOPLE_EXTENSION"                            [2421359858, 3292800717, 3937629970, 3049994222, 3691635694, 3595213639,  179172308,   78328930];
+    pub const ID_CAP_PEOPLE_EXTENSION_IM                            = "ID_CAP_PEOPLE_EXTENSION_IM"                         [4185227476, 2246489478, 1464636475, 3416398756, 3533541569, 3173998654, 3158821001,  470107728];
+    pub const ID_CAP_PEOPLE_EXTENSION_MOBILE                        = "ID_CAP_PEOPLE_EXTENSION_MOBILE"                     [2280369771, 2439432829, 1174820939, 1673344414, 3168890416, 2607322881, 4109686021, 2067514948];
+    pub const ID_CAP_PERSONA                                        = "ID_CAP_PERSONA"                                     [3044633684, 1094154820,  641776766, 3206503115, 3208609276, 3010845640, 2241800283,   29677926];
+    pub const ID_CAP_PERSONAL_INFORMATION_IMPORT                    = "ID_CAP_PERSONAL_INFORMATION_IMPORT"                 [3926762833,  421792154, 4182368412, 1167398560,  818829333, 3084791552,  909703691, 1584206034];
+    pub const ID_CAP_PHONEBROKER_INTERFACE                          = "ID_CAP_PHONEBROKER_INTERFACE"                       [2880013976, 3013249434, 3701006845, 3957710220, 2516263994, 2304668363, 2924680743, 2314558444];
+    pub const ID_CAP_PHONEDIALER                                    = "ID_CAP_PHONEDIALER"                                 [1093051720, 1435832445, 1091505747, 4020695147, 1904106210, 3945710135, 4255842428, 2069462530];
+    pub const ID_CAP_PHONEPROVISIONER_DEVICEUPDATE                  = "ID_CAP_PHONEPROVISIONER_DEVICEUPDATE"               [ 126634387, 1552200377,  327233840, 2341745319,  787995398, 2519970009, 2850909541, 3876257800];
+    pub const ID_CAP_PHONEPROVISIONER_EVENTS                        = "ID_CAP_PHONEPROVISIONER_EVENTS"                     [2288109483, 1680818662, 1617236802, 1540498286, 1306607810, 2558363936, 2786130659, 3267337389];
+    pub const ID_CAP_PHONE_2ND_PARTY                                = "ID_CAP_PHONE_2ND_PARTY"                             [3539339505, 4226243241, 1267420962, 3819397169,  973464497, 2001788315, 3187428551, 3777857644];
+    pub const ID_CAP_PHONE_ADMIN                                    = "ID_CAP_PHONE_ADMIN"                                 [1509579144, 1616414286,  290831441, 2323766643, 3889076878,   48906937,  637641166, 1631524129];
+    pub const ID_CAP_PHONE_INTERNAL                                 = "ID_CAP_PHONE_INTERNAL"                              [1877490659, 2995451574,  242579634, 3901418043, 4200316299, 1201490034, 3298011999, 1066557961];
+    pub const ID_CAP_PHOTOS_SETTINGS_R                              = "ID_CAP_PHOTOS_SETTINGS_R"                           [3654571537,  456459843, 2727816309, 1720595288,  664930815,  729242382, 2022569960,  256878772];
+    pub const ID_CAP_PHOTOS_SETTINGS_RW                             = "ID_CAP_PHOTOS_SETTINGS_RW"                          [ 383474558, 1982092961,  748857719,   12167853, 1239771986, 1556659075,  196182164,  184136384];
+    pub const ID_CAP_PICKER_CONTRACT_UI                             = "ID_CAP_PICKER_CONTRACT_UI"                          [3533679592,  508845088, 4147158362, 2209834534,  780686853, 2990759422, 3012222503, 3512449943];
+    pub const ID_CAP_PLATFORM_EXTENSIBILITY                         = "ID_CAP_PLATFORM_EXTENSIBILITY"                      [ 263857873,  668005494,  761739732, 3161538771, 3465510183, 3966289959, 1371968467, 3523812172];
+    pub const ID_CAP_PLAYREADY                                      = "ID_CAP_PLAYREADY"                                   [4135206061,  582434690, 1900056196, 4015487949, 1559463082, 3463418647, 1551032956, 3344374627];
+    pub const ID_CAP_PLAYREADY_ADMIN                                = "ID_CAP_PLAYREADY_ADMIN"                             [1474501820, 3232880798, 2006118808, 2747960001, 3601944205, 1577101783, 2320002750, 1096534255];
+    pub const ID_CAP_PM_1ST_PARTY                                   = "ID_CAP_PM_1ST_PARTY"                                [2713479009, 3760410636, 1990513139, 2266841327, 2303751126, 1036088881, 3793625412, 2788919387];
+    pub const ID_CAP_PM_BSS                                         = "ID_CAP_PM_BSS"                                      [1787915205,  537676324, 2472011802, 3492231904, 2180748126, 1410900452,  865282622,  437816859];
+    pub const ID_CAP_PM_INSTALL                                     = "ID_CAP_PM_INSTALL"                                  [ 409391139, 3678628100,   88783411, 2167653157, 3897844515, 3643021853, 3623997344,  183382781];
+    pub const ID_CAP_POIDATASTORE                                   = "ID_CAP_POIDATASTORE"                                [3241153397, 4253432039, 3060799236, 2826707152,  528194885,  624909121, 2824628106, 3898666271];
+    pub const ID_CAP_POIDATASTORE_ADMIN                             = "ID_CAP_POIDATASTORE_ADMIN"                          [  36401258,   70587612, 3344916962, 2637588153,  750258949, 2245180435, 3584210656, 3494484257];
+    pub const ID_CAP_POLICY_MANAGER                                 = "ID_CAP_POLICY_MANAGER"                              [3957936651,  675018005,  871611421, 2519705367, 4242222257, 3893829117, 3884204443, 1390290388];
+    pub const ID_CAP_POLICY_MANAGER_READONLY                        = "ID_CAP_POLICY_MANAGER_READONLY"                     [3602938784, 1787358088, 2214661780, 2664954802,  116148370, 2166160257, 2890858203, 1037968683];
+    pub const ID_CAP_POWERNOTIF_USER                                = "ID_CAP_POWERNOTIF_USER"                             [2842245559, 1032864762, 2796624370, 3738001547, 3923490807,  610429759, 4111349424,  625336189];
+    pub const ID_CAP_PRESERVED_DATA                                 = "ID_CAP_PRESERVED_DATA"                              [1040435922, 1881642974, 3585912161, 3905079216, 3824131167, 2818711098, 1854894868, 1454561357];
+    pub const ID_CAP_PRIV_ABOUTCPL                                  = "ID_CAP_PRIV_ABOUTCPL"                               [3702191546, 3015283016, 2770801629,  140592313, 3626316548,  384334985, 2679690187, 3889822510];
+    pub const ID_CAP_PRIV_ACCESSIBILITYCPL                          = "ID_CAP_PRIV_ACCESSIBILITYCPL"                       [ 641654939, 3619459617, 1817410735, 3128114563, 2920764708, 2319836985,  186954442, 2689732995];
+    pub const ID_CAP_PRIV_ACCESSORIESCPL                            = "ID_CAP_PRIV_ACCESSORIESCPL"                         [1957495988,    9070034, 1066404512, 2743696392, 3662991827,  749116265, 3833210910, 2144140122];
+    pub const ID_CAP_PRIV_ACCESSORYMGRSVC                           = "ID_CAP_PRIV_ACCESSORYMGRSVC"                        [2264900124, 3115219258, 2345001936, 2525181199, 2649606114, 1195785519, 3023695549, 3081121857];
+    pub const ID_CAP_PRIV_ACCOUNTPROVSVC                            = "ID_CAP_PRIV_ACCOUNTPROVSVC"                         [1595602080, 2174720952, 3404583962, 2795576728,  583970693, 4049526650, 2919582939, 3040619670];
+    pub const ID_CAP_PRIV_ACTIONURIHOST                             = "ID_CAP_PRIV_ACTIONURIHOST"                          [3391759911, 2094846081,  472448896, 2280649339,  450275198,  425525103, 3516743378, 3343886691];
+    pub const ID_CAP_PRIV_ADVERTISINGIDCPL                          = "ID_CAP_PRIV_ADVERTISINGIDCPL"                       [ 381909091, 2365016122, 3893428437, 1117404609, 4122627759, 2114458955, 1417699003, 1682309209];
+    pub const ID_CAP_PRIV_ALARMS                                    = "ID_CAP_PRIV_ALARMS"                                 [2242391463, 3407473979, 1378926805, 3074188594,  432398863, 2188291461, 3411751299,  946431060];
+    pub const ID_CAP_PRIV_APHCHECK                                  = "ID_CAP_PRIV_APHCHECK"                               [3837422361, 1237665578, 2993208839, 2972581921, 4122890600, 1286407940,  688296345, 1713069640];
+    pub const ID_CAP_PRIV_APMUX                                     = "ID_CAP_PRIV_APMUX"                                  [1535128064, 3143797225, 2154691268, 1269457339,  381526852, 3867687138, 1864555621, 3329420561];
+    pub const ID_CAP_PRIV_APPCORNER                                 = "ID_CAP_PRIV_APPCORNER"                              [2878609352,  954291332, 2934343509, 1131563372,  833735718, 1261023847,  863804488, 3309935605];
+    pub const ID_CAP_PRIV_APPPREINSTALLER                           = "ID_CAP_PRIV_APPPREINSTALLER"                        [ 171712513, 3514896565,  998684375, 1254289810, 2187163862, 3663546875, 1698503927, 3157483820];
+    pub const ID_CAP_PRIV_APPRESOLVERUI                             = "ID_CAP_PRIV_APPRESOLVERUI"                          [ 554445715,  786742564, 3694002357, 3330233460, 3753618773, 2156863345, 1096407208, 2476597743];
+    pub const ID_CAP_PRIV_APPSDATAMIGRATOR                          = "ID_CAP_PRIV_APPSDATAMIGRATOR"                       [3487696297, 3818970554, 1252033260, 3159224757, 4038316234, 2125376538,  302071528, 3549326548];
+    pub const ID_CAP_PRIV_APPXEXECUTIONSVC                          = "ID_CAP_PRIV_APPXEXECUTIONSVC"                       [2028063034, 3435194610, 2537653425, 1503090012, 2663390257, 1010064664, 2201220037, 1444065661];
+    pub const ID_CAP_PRIV_AUTHHOST_MSA                              = "ID_CAP_PRIV_AUTHHOST_MSA"                           [2087085145,  804720880, 3188751303, 1794981167, 3446334287,  103296073, 3856581979,  894240969];
+    pub const ID_CAP_PRIV_AUTHHOST_WAB_A                            = "ID_CAP_PRIV_AUTHHOST_WAB_A"                         [3748557192,  518451112, 1917154877, 2262962197,  147719570, 4172978667, 3488055974, 1988248097];
+    pub const ID_CAP_PRIV_AUTHHOST_WAB_B                            = "ID_CAP_PRIV_AUTHHOST_WAB_B"                         [1067666594, 2251637702, 2247661982, 2004280476, 3231167036, 1483383452, 4043679821, 1920683115];
+    pub const ID_CAP_PRIV_AUTHHOST_WAB_C                            = "ID_CAP_PRIV_AUTHHOST_WAB_C"                         [3182100364, 1451944607, 2416512064, 1994434789,  292355036,  619209970, 2082589176, 2107921668];
+    pub const ID_CAP_PRIV_AUTHHOST_WAB_ENTERPRISE                   = "ID_CAP_PRIV_AUTHHOST_WAB_ENTERPRISE"                [3609652516, 4119078797, 1208279700,  423323704, 2403816950, 4250274817,  571205077, 1749820534];
+    pub const ID_CAP_PRIV_AUTHHOST_WAB_SSO                          = "ID_CAP_PRIV_AUTHHOST_WAB_SSO"                       [1813102526, 1050097282, 3157014254,  506371058, 1823446901, 2235907117,   55484178, 2123552703];
+    pub const ID_CAP_PRIV_AUTHHOST_WAB_SSO_ENTERPRISE               = "ID_CAP_PRIV_AUTHHOST_WAB_SSO_ENTERPRISE"            [1199127588, 4224496176, 3316764925, 2369587502, 1950588394, 1064700127, 4292847958, 1032528592];
+    pub const ID_CAP_PRIV_AUTOTIMEUPDATE                            = "ID_CAP_PRIV_AUTOTIMEUPDATE"                         [1143416519, 1427344381,  836360883, 1713309379, 3503698209, 2452405763, 2104369788, 1926333410];
+    pub const ID_CAP_PRIV_BATTERYSAVERCPL                           = "ID_CAP_PRIV_BATTERYSAVERCPL"                        [1263478506, 2474863908, 3409420883, 3719976846, 1318563334, 1629709465,  110908757,  515606643];
+    pub const ID_CAP_PRIV_BLUETOOTHPBAPSVC                          = "ID_CAP_PRIV_BLUETOOTHPBAPSVC"                       [3763269965, 3170182059, 1814927891, 2640840731, 2849796068, 3398854441, 2582491206, 3503159186];
+    pub const ID_CAP_PRIV_BMR2MONITORSVC                            = "ID_CAP_PRIV_BMR2MONITORSVC"                         [3820358817, 2071704856, 3934932299, 1602842318, 2949701797, 1191893763, 1563299998,  373929023];
+    pub const ID_CAP_PRIV_BMR2SCHEDULETRIGGER                       = "ID_CAP_PRIV_BMR2SCHEDULETRIGGER"                    [ 100017478, 2625106909,  749295355, 2858628561,  438098371, 3423777609, 2069243033,  622872817];
+    pub const ID_CAP_PRIV_BMRCPL                                    = "ID_CAP_PRIV_BMRCPL"                                 [2013155637, 1348218385, 3491624005,  677927633, 3018268477,  967545437, 1349403044,  696104603];
+    pub const ID_CAP_PRIV_BMRSCHEDULETRIGGER                        = "ID_CAP_PRIV_BMRSCHEDULETRIGGER"                     [3748851708, 2201806123, 3995113674, 3473972530, 1649424221, 4058121144, 4260912961, 1499625741];
+    pub const ID_CAP_PRIV_BRIGHTNESSCPL                             = "ID_CAP_PRIV_BRIGHTNESSCPL"                          [2101144736,   24086467, 3822401137, 1825411697, 3592091852, 3303705241, 1013927376, 1914276837];
+    pub const ID_CAP_PRIV_BSSVC                                     = "ID_CAP_PRIV_BSSVC"                                  [2953686488, 3244031514, 3661439912, 3864866633,   42195657, 2715269461,  779122698, 3330650105];
+    pub const ID_CAP_PRIV_BTAGSERVICE                               = "ID_CAP_PRIV_BTAGSERVICE"                            [1661448537,  919023469,  735967288, 1509840846, 3311692890,  853435374, 1423953998, 2597194198];
+    pub const ID_CAP_PRIV_BTCONNMGR                                 = "ID_CAP_PRIV_BTCONNMGR"                              [3677158147, 2531316027,  616390032, 2011690052, 1169450927, 1771205767, 2629006959, 2590607143];
+    pub const ID_CAP_PRIV_BTHAVCTPSVC                               = "ID_CAP_PRIV_BTHAVCTPSVC"                            [2283308498, 3418168164, 2871610685, 2497950421,  893254441,  630671784, 2571566870, 2975926952];
+    pub const ID_CAP_PRIV_BTSERV                                    = "ID_CAP_PRIV_BTSERV"                                 [1064962950, 3174434449,   13947245, 1084156204, 1782554780, 3790103479, 2348472782, 1693109098];
+    pub const ID_CAP_PRIV_BTUXCPL                                   = "ID_CAP_PRIV_BTUXCPL"                                [1311357076, 2307295700, 1542398105, 3006901857,  155434232, 1166697143, 1967422306, 3855172469];
+    pub const ID_CAP_PRIV_CALC7                                     = "ID_CAP_PRIV_CALC7"                                  [1654548421, 1917889256,  902976745, 3110127035, 2162851155, 2790573582,  529516099, 2924352939];
+    pub const ID_CAP_PRIV_CAPTURESVC                                = "ID_CAP_PRIV_CAPTURESVC"                             [2868668808,  146244289, 1013011948, 3156134918, 3100598749, 3270096424, 1121277156, 3002655840];
+    pub const ID_CAP_PRIV_CASVCSHARED3                              = "ID_CAP_PRIV_CASVCSHARED3"                           [4158439329,  925322638, 2357067637,  121089629, 3448699366, 1143018234,  209007650, 1133280156];
+    pub const ID_CAP_PRIV_CELLMANAGER                               = "ID_CAP_PRIV_CELLMANAGER"                            [2294816394, 3235242823,  348589421, 4101802767, 3912655153,  439414880, 3461527793,  435546990];
+    pub const ID_CAP_PRIV_CELLULARDATACOLLECTOR                     = "ID_CAP_PRIV_CELLULARDATACOLLECTOR"                  [2771849597,  651062666, 1932460855,  209623011, 1033959201, 1525748800,  806370714,  762061446];
+    pub const ID_CAP_PRIV_CELLUX                                    = "ID_CAP_PRIV_CELLUX"                                 [2111488033, 2058533601, 4147973870,  454350674, 1363887214,  677647169, 3476779878, 1241690390];
+    pub const ID_CAP_PRIV_CERTINSTALLER                             = "ID_CAP_PRIV_CERTINSTALLER"                          [ 725538050, 1907153280,  521305483,  677231200, 1877408990, 2767757795, 2340704612, 1355492110];
+    pub const ID_CAP_PRIV_CFMSVC                                    = "ID_CAP_PRIV_CFMSVC"                                 [2758880953,  229425033, 1378191474, 2534007397,  375331563, 3003877757, 1405607080, 2605462748];
+    pub const ID_CAP_PRIV_CGSVC                                     = "ID_CAP_PRIV_CGSVC"                                  [3320435731, 3817745133, 2699194035,  779285069, 4003765145,  684786849, 3790766046, 2141292731];
+    pub const ID_CAP_PRIV_CLOUDSTORAGECPL                           = "ID_CAP_PRIV_CLOUDSTORAGECPL"                        [2882190351, 1900132400, 2389504047, 3283113898, 1562639320, 1954149104, 2204098722, 4231309866];
+    pub const ID_CAP_PRIV_CMSERVICE                                 = "ID_CAP_PRIV_CMSERVICE"                              [ 909664345, 3717087928, 1798897248, 2198635541, 2083468042,  844014597, 3087740421, 1229840519];
+    pub const ID_CAP_PRIV_COMMANDCHANNEL                            = "ID_CAP_PRIV_COMMANDCHANNEL"                         [1918073098, 4270625974, 2793915480, 2845556562, 1725189868,  591295631, 2814514745, 2666683318];
+    pub const ID_CAP_PRIV_COMMSAPHOST                               = "ID_CAP_PRIV_COMMSAPHOST"                            [3984546063, 3753728537, 3240573427, 1705931156, 3731643542, 1575945508, 3677024129, 3217349060];
+    pub const ID_CAP_PRIV_COMMSAPPLICATIONS                         = "ID_CAP_PRIV_COMMSAPPLICATIONS"                      [4269924204, 1432115617, 2276348624, 1527206843, 2538190826, 2044232883,  597212342, 2757615184];
+    pub const ID_CAP_PRIV_COMMSCERTINSTSVC                          = "ID_CAP_PRIV_COMMSCERTINSTSVC"                       [3186606985, 1559859482, 1383161800, 2779233149, 4049616259,  438737389, 3717049039, 1559903363];
+    pub const ID_CAP_PRIV_COMMSMESSAGESVC                           = "ID_CAP_PRIV_COMMSMESSAGESVC"                        [3570828876, 2449304231, 1447458514, 4166460550, 1325067112, 1937621693,  701994133, 1229712047];
+    pub const ID_CAP_PRIV_COMMSMMSTRANSPORT                         = "ID_CAP_PRIV_COMMSMMSTRANSPORT"                      [3725804922, 1405377437,  927002710,  397285623, 2546525998, 1731533221, 1407242702, 4050426102];
+    pub const ID_CAP_PRIV_CONTACTSTOKENSVC                          = "ID_CAP_PRIV_CONTACTSTOKENSVC"                       [2621497959, 3409937390, 3034511946, 1091013387,  720518078, 1794943562, 4140314382,  189349493];
+    pub const ID_CAP_PRIV_CONTENTSHARESVC                           = "ID_CAP_PRIV_CONTENTSHARESVC"                        [1900095981, 2740182768, 1666706949, 1387942982, 2694400541, 2630027509, 2562029196,  600678296];
+    pub const ID_CAP_PRIV_CONTENTSHARINGAPP                         = "ID_CAP_PRIV_CONTENTSHARINGAPP"                      [1880489724, 2772989376, 3865513202,   44913138, 1120520757, 3547367450,  116773904, 1713866488];
+    pub const ID_CAP_PRIV_COREUIREGISTRAR                           = "ID_CAP_PRIV_COREUIREGISTRAR"                        [  97886205, 2132118884,   11127097, 2831259083, 3080785905, 1973872878, 4279921153, 2889656453];
+    pub const ID_CAP_PRIV_DACCERTINSTSVC                            = "ID_CAP_PRIV_DACCERTINSTSVC"                         [ 884565313, 2237566239, 1474645701,  511570554,  286898737, 3595755270, 3457832312, 2714042919];
+    pub const ID_CAP_PRIV_DATACOLLECTION                            = "ID_CAP_PRIV_DATACOLLECTION"                         [2034919452,   68564597, 3540405225,  467401073, 1284598087, 1803212676, 1555303465, 2962789269];
+    pub const ID_CAP_PRIV_DATASENSESVC                              = "ID_CAP_PRIV_DATASENSESVC"                           [ 219590132, 2705272766, 1289053499, 2676897044, 1786461788, 3758284882, 3169161312, 1324696326];
+    pub const ID_CAP_PRIV_DATASMART                                 = "ID_CAP_PRIV_DATASMART"                              [2698771304, 3218752907, 3710736025, 1005754343, 4093697657,  426895070, 3709630612, 1837112635];
+    pub const ID_CAP_PRIV_DATETIMECPL                               = "ID_CAP_PRIV_DATETIMECPL"                            [2074233398, 1313347228, 3413325327, 2273270931, 1918492852, 3344091505, 1445030439,  111432151];
+    pub const ID_CAP_PRIV_DCPSVC                                    = "ID_CAP_PRIV_DCPSVC"                                 [4252180316, 2012523948, 1998919462,  226244827, 2251911756, 1899007206, 4274800325, 2289022885];
+    pub const ID_CAP_PRIV_DEBUGGERMUXNOTIFY                         = "ID_CAP_PRIV_DEBUGGERMUXNOTIFY"                      [4248291447, 3266028882, 2644683550, 2878301708,  716368600,  390998217, 1946024266,  352767815];
+    pub const ID_CAP_PRIV_DIAGNOSTICSVC                             = "ID_CAP_PRIV_DIAGNOSTICSVC"                          [ 914988669, 1927506841, 3831045698, 3631598257, 3751465082, 1933918687, 1400885089, 3272328612];
+    pub const ID_CAP_PRIV_DMCFGHOST                                 = "ID_CAP_PRIV_DMCFGHOST"                              [3759315688, 2856993014, 2743258494,  699275445, 1386300459, 2025907227, 3508935989, 2177680138];
+    pub const ID_CAP_PRIV_DMOMACPNETWMO                             = "ID_CAP_PRIV_DMOMACPNETWMO"                          [ 618746054, 3879552457, 3833388763, 3718681835, 3259083511, 2389644374, 2632927989, 1153219902];
+    pub const ID_CAP_PRIV_DMOMACPUSERMO                             = "ID_CAP_PRIV_DMOMACPUSERMO"                          [1241477014, 3569732393, 2868988194,  522785542,  405843953, 2428621998, 2426863335, 4189218443];
+    pub const ID_CAP_PRIV_DMWAPPUSHSVC                              = "ID_CAP_PRIV_DMWAPPUSHSVC"                           [  50356377, 2971133637, 1411370572, 4145314111, 3421706337,  346246495, 3345077987, 3555642185];
+    pub const ID_CAP_PRIV_DRIVINGMODEMANAGER                        = "ID_CAP_PRIV_DRIVINGMODEMANAGER"                     [2756568060, 3773383040, 1414135456, 2519682590, 1885648463,  531811447, 3553129208, 3860684886];
+    pub const ID_CAP_PRIV_DRIVINGMODESETTINGS                       = "ID_CAP_PRIV_DRIVINGMODESETTINGS"                    [1893210312,  950967227, 4228776452, 3178098267,  961874846,  696050049, 1549345933,  637617379];
+    pub const ID_CAP_PRIV_DSSVC                                     = "ID_CAP_PRIV_DSSVC"                                  [ 938158914, 3769818490,  234441976, 3823975914, 3714444632,  544707441, 3433724106, 3130068214];
+    pub const ID_CAP_PRIV_DSTOKENCLEAN                              = "ID_CAP_PRIV_DSTOKENCLEAN"                           [1753598334,  175428066, 3235084942, 3715693363, 2936718260,  543860685,  943928971, 4255618496];
+    pub const ID_CAP_PRIV_DUACALLBACK                               = "ID_CAP_PRIV_DUACALLBACK"                            [2986130805, 2501413322, 3791395340, 3785215683, 2579544666, 3361237126, 2315275830, 1785470271];
+    pub const ID_CAP_PRIV_DUACLIENT                                 = "ID_CAP_PRIV_DUACLIENT"                              [2777658290, 2279636284,  388574346,  537721074, 1755329013,  926272591, 2720218503,  777776038];
+    pub const ID_CAP_PRIV_DUCLEANUPMIGRATOR                         = "ID_CAP_PRIV_DUCLEANUPMIGRATOR"                      [3606199257, 3829471794, 3355387145, 3805852311, 3174850739, 2135137762,  865637246, 1165399117];
+    pub const ID_CAP_PRIV_DUFEATUREDISCOVERY                        = "ID_CAP_PRIV_DUFEATUREDISCOVERY"                     [2335941011, 3817492661, 4128572821,   24475218, 2490126733, 3793754962, 1893228051,  662051538];
+    pub const ID_CAP_PRIV_DUMIGRATIONMANAGER                        = "ID_CAP_PRIV_DUMIGRATIONMANAGER"                     [4159897836, 3778929071,   74288370, 2928828425, 2700522578, 2235318407, 1549996062, 2977265692];
+    pub const ID_CAP_PRIV_DUMIGRATIONPROVISIONERMICROSOFT           = "ID_CAP_PRIV_DUMIGRATIONPROVISIONERMICROSOFT"        [2951303005, 4017712365, 1896609040,  517523023,  424445880, 2353086900,  330339111, 2991073158];
+    pub const ID_CAP_PRIV_DUMIGRATIONPROVISIONEROEM                 = "ID_CAP_PRIV_DUMIGRATIONPROVISIONEROEM"              [2581096325,  507112302, 1729479335, 1377207035, 1901540171,  683712378, 3164889141, 3045397011];
+    pub const ID_CAP_PRIV_DUPOSTUPDATEUX                            = "ID_CAP_PRIV_DUPOSTUPDATEUX"                         [2678583037, 3015163657, 4172577353,  415680508, 3900316683,  828873744, 3506516500, 1926251190];
+    pub const ID_CAP_PRIV_DUSTARTINGMIGRATOR                        = "ID_CAP_PRIV_DUSTARTINGMIGRATOR"                     [3816824273, 3012473954, 2342779036, 4030732423, 1327003114, 2535927213, 2707047973, 4187153080];
+    pub const ID_CAP_PRIV_DUSVC                                     = "ID_CAP_PRIV_DUSVC"                                  [1252443961,  848623433, 1222398577, 2695879723, 1985881823, 1240021980, 3398709914, 1367397820];
+    pub const ID_CAP_PRIV_ENROLLMENTCLIENT                          = "ID_CAP_PRIV_ENROLLMENTCLIENT"                       [3485559230,  270281049,  376685461,  408548396, 1234813444, 3738382694, 1153077705, 4163440403];
+    pub const ID_CAP_PRIV_ENTAPPSERVICE                             = "ID_CAP_PRIV_ENTAPPSERVICE"                          [3868996226,  977271375,  822085508, 1450021620, 1780891435, 1926137217,  722674342,  683633183];
+    pub const ID_CAP_PRIV_ENTERPRISEINSTALL                         = "ID_CAP_PRIV_ENTERPRISEINSTALL"                      [1358151887,  431265856, 3821682637, 2629604649, 2988011301, 2255509346,  486164975, 2410112989];
+    pub const ID_CAP_PRIV_ENTERPRISEMGMSVC                          = "ID_CAP_PRIV_ENTERPRISEMGMSVC"                       [ 781896196, 1223984037, 3258298040, 1061519067, 2169943435, 2093573927, 2449958595, 2049344320];
+    pub const ID_CAP_PRIV_ENTERPRISERING                            = "ID_CAP_PRIV_ENTERPRISERING"                         [1805563264, 1780196009,  179773414, 3580453194, 3483601531, 2111661327, 3150942770, 2153924705];
+    pub const ID_CAP_PRIV_ENTERPRISEVALIDATION                      = "ID_CAP_PRIV_ENTERPRISEVALIDATION"                   [ 662772353, 1486615530,  871545394, 1448426327,  510098464, 4116524330, 2676296192, 1484244256];
+    pub const ID_CAP_PRIV_EXECMANSERVICE                            = "ID_CAP_PRIV_EXECMANSERVICE"                         [2823247981, 3776508852,  414256269, 2235771749, 1270235241,  213784370, 1969438168, 3571734898];
+    pub const ID_CAP_PRIV_FINDMYPHONE                               = "ID_CAP_PRIV_FINDMYPHONE"                            [3528575947, 2082175261, 1515314448, 2988173106, 2580967201, 1810545037, 2275089234, 1670465866];
+    pub const ID_CAP_PRIV_FLYOUTDATAMIGRATOR                        = "ID_CAP_PRIV_FLYOUTDATAMIGRATOR"                     [ 191048540,  721407936,  241604018,  737656606, 3165831124, 3710910690,  543113175, 2195473783];
+    pub const ID_CAP_PRIV_GROVELER                                  = "ID_CAP_PRIV_GROVELER"                               [ 270926002, 2390683205, 2629159142,  468774789, 1547585856, 2679586204, 2531634935, 3680400465];
+    pub const ID_CAP_PRIV_GWPCENROLLSVC                             = "ID_CAP_PRIV_GWPCENROLLSVC"                          [2282877110,  451725208, 4253761080,  864674258, 2679644245, 2697973601, 2313802016, 2069110607];
+    pub const ID_CAP_PRIV_HFA                                       = "ID_CAP_PRIV_HFA"                                    [3669755556,  806554671, 2628500819,  650864940,  912276399, 2365803980, 3203072321, 3459722635];
+    pub const ID_CAP_PRIV_HOTSPOTHOST                               = "ID_CAP_PRIV_HOTSPOTHOST"                            [2963954832, 3109667677, 1970791642, 2181992142, 1556456298, 3411533242, 1915486735, 1393436518];
+    pub const ID_CAP_PRIV_HUBTILERESTOREHOST                        = "ID_CAP_PRIV_HUBTILERESTOREHOST"                     [ 847620514, 3120824431,  619625590, 4277702541,  802900985, 1120582411, 2692267185, 1374007447];
+    pub const ID_CAP_PRIV_ICSENTITLEMENTHOST                        = "ID_CAP_PRIV_ICSENTITLEMENTHOST"                     [ 306884668, 4074167805, 4162618654, 2048162625,  618345260, 3140339046,  936887725, 2787263824];
+    pub const ID_CAP_PRIV_ICSSVC                                    = "ID_CAP_PRIV_ICSSVC"                                 [4021440844, 3671501580,  613825872, 2600983013, 1521389129, 1620461136, 3364486837,  205964572];
+    pub const ID_CAP_PRIV_INPUTSERVICE                              = "ID_CAP_PRIV_INPUTSERVICE"                           [1079608954,  912595864, 3714627136,  312640869,  759709924, 2804687460,  964260425, 1077569771];
+    pub const ID_CAP_PRIV_INSTALLERWORKER                           = "ID_CAP_PRIV_INSTALLERWORKER"                        [1092561290,  667732417, 2582754862, 2423160383, 1121008381, 2640335693, 3141220507, 2478737640];
+    pub const ID_CAP_PRIV_INTERNETEXPLORER                          = "ID_CAP_PRIV_INTERNETEXPLORER"                       [2588094740,  411879449, 2974275014,  842057546, 2910191738, 2087297976, 4053104604, 4183899876];
+    pub const ID_CAP_PRIV_IPOVERUSB                                 = "ID_CAP_PRIV_IPOVERUSB"                              [ 678199816, 3862272940, 3874397148, 2791747786, 2444939011, 1994217409, 3303480927, 2371145845];
+    pub const ID_CAP_PRIV_KEYBOARDCPL                               = "ID_CAP_PRIV_KEYBOARDCPL"                            [1175044270, 2227388863, 2175933357, 4032654743, 1674745371,   94929287, 3366867433, 4222577397];
+    pub const ID_CAP_PRIV_KIDZONECONFIGURATION                      = "ID_CAP_PRIV_KIDZONECONFIGURATION"                   [  49602385, 1358416099, 2214300170, 1847033715, 1308293252, 1929746912, 1076142241, 2437606485];
+    pub const ID_CAP_PRIV_KIDZONECUSTOMIZATION                      = "ID_CAP_PRIV_KIDZONECUSTOMIZATION"                   [ 148409550, 2935273770, 3748069116, 3653793863, 2138771866, 2077171053, 1803638391, 2884183948];
+    pub const ID_CAP_PRIV_LASSCREDENTIALEXPIRATIONCHECK             = "ID_CAP_PRIV_LASSCREDENTIALEXPIRATIONCHECK"          [3865140078, 1447353156, 2141374843, 1536353842,  582098320, 2044075971, 3640596553, 1256627641];
+    pub const ID_CAP_PRIV_LAUNCHAPPSVC                              = "ID_CAP_PRIV_LAUNCHAPPSVC"                           [2593718493, 2467597238, 2087101239, 3469223724, 3884163621, 2439614441, 1412188656, 3499485220];
+    pub const ID_CAP_PRIV_LEXICONUPDATE                             = "ID_CAP_PRIV_LEXICONUPDATE"                          [2575724350,  270990983,  427984501, 3237712194, 3749340801,  487435722, 1318817432, 2577895967];
+    pub const ID_CAP_PRIV_LFSVC                                     = "ID_CAP_PRIV_LFSVC"                                  [ 330770410, 3278750078, 2243289951, 3324409014, 1624942838, 1335934781,  731050806, 2436124280];
+    pub const ID_CAP_PRIV_LIVETOKEN                                 = "ID_CAP_PRIV_LIVETOKEN"                              [2208924548, 2949165698, 2901136198, 3736292773, 3260947744,  782056639, 1501481938, 3564265321];
+    pub const ID_CAP_PRIV_LOCATIONUXCPL                             = "ID_CAP_PRIV_LOCATIONUXCPL"                          [4204812590, 2121059246,  274608362, 3739993598,  758867839, 1326983720,  382553715, 1218542030];
+    pub const ID_CAP_PRIV_LOCKANDWALLPAPER                          = "ID_CAP_PRIV_LOCKANDWALLPAPER"                       [3377381122, 2566705835, 2117205411, 3916673358, 3335899580,  488998617, 3826672157, 3350042513];
+    pub const ID_CAP_PRIV_MEDIA                                     = "ID_CAP_PRIV_MEDIA"                                  [3347215405, 1244869068, 1890367129, 2613811403, 3047147966, 2956072433,  739022923,  343410392];
+    pub const ID_CAP_PRIV_MEDIASERVICE                              = "ID_CAP_PRIV_MEDIASERVICE"                           [ 455976710,  821893757, 2630592200,   35478687, 1737164584,  112426754, 3433470142, 2593996472];
+    pub const ID_CAP_PRIV_MIRRORCPL                                 = "ID_CAP_PRIV_MIRRORCPL"                              [ 235196125, 1398389906,  407879002,  202570404, 2821558941, 1236231554, 2280234712, 2435843284];
+    pub const ID_CAP_PRIV_MOBILEUI                                  = "ID_CAP_PRIV_MOBILEUI"                               [3865135691, 3995097961, 3172769699, 3035620359, 3959983614, 3527906269, 1801648162, 3664623177];
+    pub const ID_CAP_PRIV_MOSHOST                                   = "ID_CAP_PRIV_MOSHOST"                                [1793409214, 2989265577, 1560224842,  265991411, 3011945704, 1403349331, 2752070706, 3482104922];
+    pub const ID_CAP_PRIV_MSATICKETSVC                              = "ID_CAP_PRIV_MSATICKETSVC"                           [ 629535606,  795344890, 2664575405,  773753174, 2998394682, 3981686494,  737072917, 4150966295];
+    pub const ID_CAP_PRIV_MSGIMTRANSPORT                            = "ID_CAP_PRIV_MSGIMTRANSPORT"                         [3846147313,   93628275, 2655953855, 3571274273, 2272908779,  113122007,   35580341, 2798989312];
+    pub const ID_CAP_PRIV_MSGSMSTRANSPORT                           = "ID_CAP_PRIV_MSGSMSTRANSPORT"                        [2497720928, 1015098519, 4292111178,  696988299, 3830574450, 1804235028,  381582540, 1005772751];
+    pub const ID_CAP_PRIV_MTP                                       = "ID_CAP_PRIV_MTP"                                    [1840263933, 2482162046, 3711000570, 3838599994, 2109192735, 2420745102, 2590622915, 1952638968];
+    pub const ID_CAP_PRIV_MVPROVISIONHOST                           = "ID_CAP_PRIV_MVPROVISIONHOST"                        [1181420905,  540071444,  604248028, 2013241061, 2411354483, 2026473845, 3365982654, 1672816109];
+    pub const ID_CAP_PRIV_MVUX                                      = "ID_CAP_PRIV_MVUX"                                   [3075372479, 3883527891, 4162398239, 2135503463,   65665125, 3019373915, 2450561919,   17651958];
+    pub const ID_CAP_PRIV_NABSYNC                                   = "ID_CAP_PRIV_NABSYNC"                                [3957654617,  928860116,  157370293, 2123702337, 3106085848, 2231893509, 2631954982, 2674616668];
+    pub const ID_CAP_PRIV_NOCENTERSETTINGSCPL                       = "ID_CAP_PRIV_NOCENTERSETTINGSCPL"                    [3853223726, 3741035021, 1138770937, 1599623577, 1068447796, 1444538781, 1998564519, 3270494465];
+    pub const ID_CAP_PRIV_NOTIFICATIONPLATFORMMIGRATOR              = "ID_CAP_PRIV_NOTIFICATIONPLATFORMMIGRATOR"           [1076528788,  216827213, 1622226201, 2963220978, 2921779409, 4177726458, 2460723215, 1429884197];
+    pub const ID_CAP_PRIV_NOTIFSVC                                  = "ID_CAP_PRIV_NOTIFSVC"                               [2346244069, 1085784738, 1655700058,  502073420, 1731254820, 4032604137,  904990341, 2868863963];
+    pub const ID_CAP_PRIV_OFFICE                                    = "ID_CAP_PRIV_OFFICE"                                 [3673087306, 3729677189, 1986109835, 4213265359,  726331438, 1203736147, 1257127227,  396186728];
+    pub const ID_CAP_PRIV_OMADMCLIENT_ENTERPRISE                    = "ID_CAP_PRIV_OMADMCLIENT_ENTERPRISE"                 [1604915989, 2895934960, 3994119388, 3886423788, 1244775661, 1968485600, 3231407801,  512623004];
+    pub const ID_CAP_PRIV_OMADMCLIENT_MOBILE_OPERATOR               = "ID_CAP_PRIV_OMADMCLIENT_MOBILE_OPERATOR"            [1344405089, 4173432893, 2585552285, 2224571002, 1748971021, 1609676992,  853613614, 2828792277];
+    pub const ID_CAP_PRIV_OMADMPRC                                  = "ID_CAP_PRIV_OMADMPRC"                               [1773742578, 2510474095,  905645315, 2245679183,  262861516,  761192269,  916028203, 1991670177];
+    pub const ID_CAP_PRIV_OOBE                                      = "ID_CAP_PRIV_OOBE"                                   [ 453890642, 1824426784, 2811616944, 4140594911, 1646688992, 2506124761,  995023766, 2341514785];
+    pub const ID_CAP_PRIV_ORIENTSRV                                 = "ID_CAP_PRIV_ORIENTSRV"                              [3181400452, 1416797908,  989308977, 3747790696, 3734543501, 1696371547, 2808734069, 2151008871];
+    pub const ID_CAP_PRIV_PACMANSERVICE                             = "ID_CAP_PRIV_PACMANSERVICE"                          [2805189855, 1003017166, 1483974709, 1272717043, 3720491416, 2069662796, 2496644083, 2984512930];
+    pub const ID_CAP_PRIV_PHONEAUDIOSRV                             = "ID_CAP_PRIV_PHONEAUDIOSRV"                          [2326117251, 1620536281,  669501415, 3467979545,  210332888, 2100455439,  707937703, 2257210027];
+    pub const ID_CAP_PRIV_PHONEPROVISIONER                          = "ID_CAP_PRIV_PHONEPROVISIONER"                       [3508897389, 4162870154, 3700854653, 3770205739, 2874146367, 3200038617, 3090654645, 1346675237];
+    pub const ID_CAP_PRIV_PHONEPROVISIONER_OEM                      = "ID_CAP_PRIV_PHONEPROVISIONER_OEM"                   [2785912586, 3542183935, 2230668930, 1378316680,  379057243, 1254432061,  180818681, 2551661520];
+    pub const ID_CAP_PRIV_PHONESVCSG                                = "ID_CAP_PRIV_PHONESVCSG"                             [3785756314, 1874037688,  486831059, 3600736452, 1573102536, 3041828395, 2534607632,  601551653];
+    pub const ID_CAP_PRIV_PHOTOS                                    = "ID_CAP_PRIV_PHOTOS"                                 [3007893774,  402715186, 3423798824,  808586143,  645551963, 1912673359, 3725514144, 2375488130];
+    pub const ID_CAP_PRIV_PHOTOSSVC                                 = "ID_CAP_PRIV_PHOTOSSVC"                              [2679213671,   32594753, 3922348643, 2182191201, 1577815601, 2520298925,   99644993, 2335202314];
+    pub const ID_CAP_PRIV_PIMIDXMAINT                               = "ID_CAP_PRIV_PIMIDXMAINT"                            [1555797320, 3683531226, 2270661382, 2352025297, 1235015464, 1936320398, 1870536153, 3258992755];
+    pub const ID_CAP_PRIV_PLACESSVC                                 = "ID_CAP_PRIV_PLACESSVC"                              [1496173690, 1301178569, 4038177218, 3216581340, 3749721013, 1088109777, 2384061977,  278242253];
+    pub const ID_CAP_PRIV_POSTDUAPPMIGRATOR                         = "ID_CAP_PRIV_POSTDUAPPMIGRATOR"                      [3706962620, 4170894011,   81612047, 1286833363, 4234944144, 4029078484, 1726981636, 4274159480];
+    pub const ID_CAP_PRIV_POWERNOTIF                                = "ID_CAP_PRIV_POWERNOTIF"                             [ 509696209, 1374255475, 3159938698, 3491878826,  361171972, 3733741909, 1891142005, 1873167936];
+    pub const ID_CAP_PRIV_PROXIMITYSVC                              = "ID_CAP_PRIV_PROXIMITYSVC"                           [ 144776972, 4074767306, 3854404797,  764210525, 3504231269, 4052897789,  491844826, 4072424361];
+    pub const ID_CAP_PRIV_PROXYSVC                                  = "ID_CAP_PRIV_PROXYSVC"                               [2323376210, 3522073209,  741873721,  894459224, 4144752506, 1299807612, 4001944925, 4033841544];
+    pub const ID_CAP_PRIV_REALWORLD_BINGCLIENT                      = "ID_CAP_PRIV_REALWORLD-BINGCLIENT"                   [2445051088,  668463070, 3263314086, 4048754527, 1495437973,   39761879,  886721520,  804229004];
+    pub const ID_CAP_PRIV_REALWORLD_INTERESTEXTRACTION              = "ID_CAP_PRIV_REALWORLD-INTERESTEXTRACTION"           [2194764022,   36395575,  952434030,  410191612, 1452133946, 3025999701, 2346555989,  203037131];
+    pub const ID_CAP_PRIV_REBOOTDEVICE                              = "ID_CAP_PRIV_REBOOTDEVICE"                           [1687306117, 1885862730, 4063032495, 2885838198,  582824517, 1057012852, 1861447068, 2528209113];
+    pub const ID_CAP_PRIV_REGIONCPL                                 = "ID_CAP_PRIV_REGIONCPL"                              [1675877585, 2086428330,  449488024, 3877568825, 1001594570, 1443186182, 2680730761,  498367727];
+    pub const ID_CAP_PRIV_REMEMBER                                  = "ID_CAP_PRIV_REMEMBER"                               [1145325953, 4114910318, 1583895851, 2153160487,  984366925, 4052651286, 4093824185, 1407802964];
+    pub const ID_CAP_PRIV_RETAILDEMO                                = "ID_CAP_PRIV_RETAILDEMO"                             [ 128032855, 1754782114,  752026468, 2074317649, 3535103907, 3893063264, 1319344974, 4271339800];
+    pub const ID_CAP_PRIV_RETAILDEMOERROR                           = "ID_CAP_PRIV_RETAILDEMOERROR"                        [1188913571, 3049063334, 1766416304, 3571428810, 3265128233, 1294081094, 3992520170, 1228541022];
+    pub const ID_CAP_PRIV_RETAILDEMOGLOB                            = "ID_CAP_PRIV_RETAILDEMOGLOB"                         [  19490414,  843937673, 3458097499, 4116789322,  472851226, 4164274620,  642444220, 3449409126];
+    pub const ID_CAP_PRIV_RETAILDEMOUI                              = "ID_CAP_PRIV_RETAILDEMOUI"                           [2763853371,  488130636,  348967529,  790117186, 3176945693,  508165567, 4275720762, 2260152372];
+    pub const ID_CAP_PRIV_RILADAPTATION                             = "ID_CAP_PRIV_RILADAPTATION"                          [ 153050110,  626303931, 1046838420, 2593488200, 1836760913, 3066325101, 3208405356, 2436514250];
+    pub const ID_CAP_PRIV_RINGTONESANDSOUNDS                        = "ID_CAP_PRIV_RINGTONESANDSOUNDS"                     [1010274897, 3904291097, 1962684232, 2804906464, 2541944627, 3164952978, 2138410719, 2728307944];
+    pub const ID_CAP_PRIV_ROAMINGCPL                                = "ID_CAP_PRIV_ROAMINGCPL"                             [1402639919, 3055683677,  336774465,  733452007, 3197485064, 3101458112,  200313199, 1361576526];
+    pub const ID_CAP_PRIV_ROTATIONLOCKCPL                           = "ID_CAP_PRIV_ROTATIONLOCKCPL"                        [1329835884, 1827042785, 2977984329, 1061632676, 1575172798, 2484958169, 1671776830, 1162567977];
+    pub const ID_CAP_PRIV_SAPISVR                                   = "ID_CAP_PRIV_SAPISVR"                                [1851051100,  271001978,  729909982, 4213198121, 2378011146, 2999808796,  378980369, 2877206897];
+    pub const ID_CAP_PRIV_SECMIGRATOR                               = "ID_CAP_PRIV_SECMIGRATOR"                            [ 866457620, 3033726891, 3539250251, 3443294893, 3726445504, 4162692273, 3085621964, 2979677530];
+    pub const ID_CAP_PRIV_SEMGRSVC                                  = "ID_CAP_PRIV_SEMGRSVC"                               [ 900621135,  777576200, 3262908787, 1669140659,  268385349, 3853891864, 3531183707, 1510522807];
+    pub const ID_CAP_PRIV_SETTINGS                                  = "ID_CAP_PRIV_SETTINGS"                               [1846576912, 4188359778, 2380320168,  642780779, 4184405186, 2097067261,   48739394,  800067655];
+    pub const ID_CAP_PRIV_SHELLDATAMIGRATOR                         = "ID_CAP_PRIV_SHELLDATAMIGRATOR"                      [3047934760,  443676354, 4021764950, 1111729992, 1299170816, 1644206259, 1047007023, 2164007222];
+    pub const ID_CAP_PRIV_SIREPSVC                                  = "ID_CAP_PRIV_SIREPSVC"                               [1846372275, 3902299590,  728996079, 1008693644,  358283018, 1997959091, 2032637498, 3972537969];
+    pub const ID_CAP_PRIV_SOFTAPUX                                  = "ID_CAP_PRIV_SOFTAPUX"                               [3499926967, 1715728708, 3506064420,   69368955, 3160133688, 2509407685, 2541674339, 3452143391];
+    pub const ID_CAP_PRIV_SPEECHCPL                                 = "ID_CAP_PRIV_SPEECHCPL"                              [3968925344, 3321291181, 2336227132, 4180140137, 1799090108, 1057914892,  942685562, 1505049321];
+    pub const ID_CAP_PRIV_SPEECHUPDATE                              = "ID_CAP_PRIV_SPEECHUPDATE"                           [1642006283, 3590874400,   80585082, 1289770722, 1716343415,  501789257, 3129029025, 2940269942];
+    pub const ID_CAP_PRIV_START                                     = "ID_CAP_PRIV_START"                                  [2352660754, 1980193992, 1626957833, 1720928371, 1815679778, 2842466853, 2281914575, 4091306965];
+    pub const ID_CAP_PRIV_STORAGESENSE                              = "ID_CAP_PRIV_STORAGESENSE"                           [1228252831, 2612103376, 1387232236, 1515793978, 1412596218,  291077060, 1657792623,  825601594];
+    pub const ID_CAP_PRIV_STORAGESVC                                = "ID_CAP_PRIV_STORAGESVC"                             [1468606356, 4035052994,  488624892, 1625063169, 2266851081, 1303748536, 2981103760, 2479904601];
+    pub const ID_CAP_PRIV_STOREDATAMIGRATOR                         = "ID_CAP_PRIV_STOREDATAMIGRATOR"                      [3879295650, 3554164702, 3518470807, 2654488524,  916544985, 3750166385,  980248580, 3063545627];
+    pub const ID_CAP_PRIV_TASKSCHEDULERSVC                          = "ID_CAP_PRIV_TASKSCHEDULERSVC"                       [2320411231, 1654054039,  674540330, 1067228464, 2579816226, 3839349312, 2395609579,  357628434];
+    pub const ID_CAP_PRIV_TELCPL                                    = "ID_CAP_PRIV_TELCPL"                                 [1192630719, 1802086783,  560408355,  172355066, 2613382029, 2075422834, 3808559831, 3556367855];
+    pub const ID_CAP_PRIV_TELREPSVC                                 = "ID_CAP_PRIV_TELREPSVC"                              [ 759954567, 1618879035, 3632911063, 3472614560, 3201000577, 3364991510, 2496267392,  263670935];
+    pub const ID_CAP_PRIV_THEMECPL                                  = "ID_CAP_PRIV_THEMECPL"                               [ 898148464, 3110238085, 2208206135,  945149308, 4203246047, 2114786413, 2313048350, 2090040086];
+    pub const ID_CAP_PRIV_TILEMIGRATOR                              = "ID_CAP_PRIV_TILEMIGRATOR"                           [ 436586185,   52026773,  166765969, 1036123937, 3234533600,  419960641,   84466538, 3635236668];
+    pub const ID_CAP_PRIV_TIMEBROKER                                = "ID_CAP_PRIV_TIMEBROKER"                             [1607326801, 1418366153, 2845693517, 3449709441, 1325711313, 2236638209, 2258714324, 2685403428];
+    pub const ID_CAP_PRIV_UPDATEMGRSVC                              = "ID_CAP_PRIV_UPDATEMGRSVC"                           [4009101666, 2046666388, 2265272283, 1905888259, 1028963590,  822603263, 4073515277,  932005488];
+    pub const ID_CAP_PRIV_USBCPL                                    = "ID_CAP_PRIV_USBCPL"                                 [3345406764, 3047951656, 3071345274, 1012860710, 3867128349,  853175862, 1262773352, 2104799798];
+    pub const ID_CAP_PRIV_USERDATASERVICE                           = "ID_CAP_PRIV_USERDATASERVICE"                        [ 237799083, 2620806313,   90959224, 2743396360,  530499481, 3745218533, 2341364734, 2171756426];
+    pub const ID_CAP_PRIV_USSREPORTING                              = "ID_CAP_PRIV_USSREPORTING"                           [2091010855,  558178363, 1942860068, 1289906259, 2851990124, 2691632627, 1993401933, 2812194720];
+    pub const ID_CAP_PRIV_UTKSERVICE                                = "ID_CAP_PRIV_UTKSERVICE"                             [3887951740,  209763086, 4129200064, 2241529657, 1597837414, 3688683413, 1385269874, 1077040733];
+    pub const ID_CAP_PRIV_UTKUX                                     = "ID_CAP_PRIV_UTKUX"                                  [2662726976, 2339697119, 2568407464,    6040295, 2529839743, 2593224188,  428918422, 2180621961];
+    pub const ID_CAP_PRIV_VPNUX                                     = "ID_CAP_PRIV_VPNUX"                                  [1473727530, 1913640170, 2920354824, 1056580571, 1735676004, 3516014184, 2321916061, 2243555183];
+    pub const ID_CAP_PRIV_WALLET                                    = "ID_CAP_PRIV_WALLET"                                 [2800740417, 3960537368, 3860671560, 1692167039, 3939514354,  633490387, 2490733519, 4018768329];
+    pub const ID_CAP_PRIV_WALLETSVC                                 = "ID_CAP_PRIV_WALLETSVC"                              [1636702302,  736502375, 1619490408, 4207098407, 1478649930, 3083953453,   86549318,  253305477];
+    pub const ID_CAP_PRIV_WEHCSPHELPER                              = "ID_CAP_PRIV_WEHCSPHELPER"                           [2945130857, 2788986573, 1522490949,  660827540, 3680946165, 2123117829,   80153684, 3461615539];
+    pub const ID_CAP_PRIV_WEHSTART                                  = "ID_CAP_PRIV_WEHSTART"                               [1765846408, 4095915577, 3632719808,  734598617,  597325396, 1350587131, 3807372523, 1316931031];
+    pub const ID_CAP_PRIV_WIFICONNSVC                               = "ID_CAP_PRIV_WIFICONNSVC"                            [1768860323,  859935470, 3668347347, 1662408256, 1734433167, 2872585509, 1769124977, 2077265745];
+    pub const ID_CAP_PRIV_WIFICPASSIST                              = "ID_CAP_PRIV_WIFICPASSIST"                           [3528238444,  625458789, 1021096198, 3535982755, 2742208568, 2031937915, 4042484104, 3718856618];
+    pub const ID_CAP_PRIV_WIFICPBROWSERUX                           = "ID_CAP_PRIV_WIFICPBROWSERUX"                        [2918598489, 4106753816, 3604508632, 3926985554, 3201382861, 3619735703, 2211680038, 2908669717];
+    pub const ID_CAP_PRIV_WIFIUDPTEST                               = "ID_CAP_PRIV_WIFIUDPTEST"                            [4261224142,  385305163, 3154034276,  391765110, 3494673162,  585798159, 1929288193, 1133408915];
+    pub const ID_CAP_PRIV_WIFIUXBLUE                                = "ID_CAP_PRIV_WIFIUXBLUE"                             [3476125085,    5947471, 2063433791, 1160410943, 2834054100,  431249223,  141882988,  654641140];
+    pub const ID_CAP_PRIV_WLID2MSA                                  = "ID_CAP_PRIV_WLID2MSA"                               [2227539920, 1699499254, 4065405815, 2682700641,  961340992, 2457430561, 1572825959, 3751603465];
+    pub const ID_CAP_PRIV_WPABSVC                                   = "ID_CAP_PRIV_WPABSVC"                                [3806987487,  986027095, 1081727791, 3723199209, 3699472031, 3905661352, 3355870133, 1009108391];
+    pub const ID_CAP_PRIV_WPNARRATOR                                = "ID_CAP_PRIV_WPNARRATOR"                             [2624914177, 1840331017, 3257469297, 3218753182, 1932385198,  239283691, 1687747890, 2122943858];
+    pub const ID_CAP_PRIV_WPNCERTINSTSVC                            = "ID_CAP_PRIV_WPNCERTINSTSVC"                         [2342185665, 2059775959, 3318895248, 1641810034, 3293338477, 2402245663, 3685593602, 2902108465];
+    pub const ID_CAP_PRIV_WPTOOLS                                   = "ID_CAP_PRIV_WPTOOLS"                                [3905052883,  610759311, 1031920692, 2100938569, 1233673723, 3274484119, 3510282391, 4179381800];
+    pub const ID_CAP_PRIV_WPTPMVSCMGRSVC                            = "ID_CAP_PRIV_WPTPMVSCMGRSVC"                         [1491039157,  639224935, 2654770223, 1394870486,  845889126, 3402672502, 3894535851,  501813140];
+    pub const ID_CAP_PRIV_WPUITESTTOOLS                             = "ID_CAP_PRIV_WPUITESTTOOLS"                          [2179693112, 3360703106, 3461600832, 3163758732, 3517273755, 1033647209,  912284097, 2993581610];
+    pub const ID_CAP_PRIV_ZMEDIAQUEUESVC                            = "ID_CAP_PRIV_ZMEDIAQUEUESVC"                         [1496374708, 3706934006, 3542559168, 2885280943, 2841798176, 2499985327, 1520182139, 1540674074];
+    pub const ID_CAP_PRIV_ZMF                                       = "ID_CAP_PRIV_ZMF"                                    [1273173521, 3146712497, 2078448291, 2733458722,  802909139, 3203246355, 2821146954, 3585853921];
+    pub const ID_CAP_PRIV_ZMF_SERVICE                               = "ID_CAP_PRIV_ZMF_SERVICE"                            [ 485196242,  568124880, 1124971611, 4167431949,   31846404, 2037316491,  438081976,  696006945];
+    pub const ID_CAP_PROVISIONING_PACKAGE_API_ADMIN                 = "ID_CAP_PROVISIONING_PACKAGE_API_ADMIN"              [ 311846188, 1726209071,  393512827,  486980478, 1930539261, 2256157363, 3355037955, 3399308413];
+    pub const ID_CAP_PROVISIONWPCERTIFICATE                         = "ID_CAP_PROVISIONWPCERTIFICATE"                      [2674953687,  525956956, 1760362519, 1484842268, 4192050511,  891792023,  232225477, 1226868935];
+    pub const ID_CAP_PROXIMITY                                      = "ID_CAP_PROXIMITY"                                   [ 961832831,   49560001, 3564268277,  823739369, 4004423538, 3929902754, 3791854272, 3235258508];
+    pub const ID_CAP_PUBLIC_FOLDER_FULL                             = "ID_CAP_PUBLIC_FOLDER_FULL"                          [2349964318, 1349071835, 3700018674, 2795839825, 3356741129, 2883669508, 3470577093, 3202784741];
+    pub const ID_CAP_PUBLISH_ALARM_STATE                            = "ID_CAP_PUBLISH_ALARM_STATE"                         [2037995276, 2975854651, 3902079615, 2837029540, 3919843913, 2569769192, 3786987952, 2539465887];
+    pub const ID_CAP_PUBLISH_OOBE_STATE                             = "ID_CAP_PUBLISH_OOBE_STATE"                          [ 700897504, 2101906565, 3334856130, 3147460292, 2959667128,  605953356,  630964237, 3504531757];
+    pub const ID_CAP_PUSHROUTER                                     = "ID_CAP_PUSHROUTER"                                  [1552926822, 4258605051,  132417780, 2588897744, 2674935403,   69970809,  231782294, 4104277706];
+    pub const ID_CAP_PUSH_NOTIFICATION                              = "ID_CAP_PUSH_NOTIFICATION"                           [1672661162, 3899259739, 2140686688, 3529225670, 4192234406, 3706555472, 1474790597, 3061776515];
+    pub const ID_CAP_PUSH_SERVER                                    = "ID_CAP_PUSH_SERVER"                                 [2584469538,  484866550,  105467066, 1167101339, 2061030423, 3276836675, 1340675821,  420200726];
+    pub const ID_CAP_QUICK_SETTINGS                                 = "ID_CAP_QUICK_SETTINGS"                              [2879991073, 2910013877,  773734470, 2718964134, 2106486440, 1579062606,  444251481, 2472929647];
+    pub const ID_CAP_READGWPCERTIFICATE                             = "ID_CAP_READGWPCERTIFICATE"                          [ 107706885, 3845841341, 3786024582, 1766054307, 3246899304, 3594761745, 1084978252, 3871892453];
+    pub const ID_CAP_REBOOT_FLASHING_MODE                           = "ID_CAP_REBOOT_FLASHING_MODE"                        [ 656073835, 2691465634, 2133747329, 2981653719,  792909169,  361252246, 2199777653,  312000287];
+    pub const ID_CAP_REMEMBER_ADMIN                                 = "ID_CAP_REMEMBER_ADMIN"                              [2811238511, 2630953397, 1753916443, 3609499826, 2281531683, 3857172754, 3614995491, 1498760025];
+    pub const ID_CAP_REMEMBER_API                                   = "ID_CAP_REMEMBER_API"                                [ 960448228,  124837381, 2563483041,  469891198, 4100770813, 2729644340, 3503047392, 1355429414];
+    pub const ID_CAP_REMOVABLE_STORAGE                              = "ID_CAP_REMOVABLE_STORAGE"                           [ 668139856, 3942372415,  834492617, 2206027386, 4142474725,   66990122, 3772592193, 3235399837];
+    pub const ID_CAP_RESET_PHONE                                    = "ID_CAP_RESET_PHONE"                                 [3119417572, 2277775852, 1626501477, 3450040177, 2684904944, 2756189738, 1231471184, 3044409550];
+    pub const ID_CAP_RESOURCE_MANAGER                               = "ID_CAP_RESOURCE_MANAGER"                            [3767036380, 2452573463, 2915228690, 2136733997, 2036960786, 2577741598, 1529076565,  313898240];
+    pub const ID_CAP_RETAILDEMO_BACKGROUNDIMAGE                     = "ID_CAP_RETAILDEMO_BACKGROUNDIMAGE"                  [2778989464, 3606603284, 1425972647, 2514550578, 3586509582, 3261444934,  850304338, 4013653183];
+    pub const ID_CAP_RETAILDEMO_CLIENT                              = "ID_CAP_RETAILDEMO_CLIENT"                           [2745706742, 1955063667, 4027566326, 1592641635, 1803131328, 3980509628, 3463523326, 1140846690];
+    pub const ID_CAP_RETAILDEMO_GLOB_REGISTRY                       = "ID_CAP_RETAILDEMO_GLOB_REGISTRY"                    [ 586373818, 2786069756, 1542558694, 2430743956, 2663909577,  459838596, 3352703257, 3315834698];
+    pub const ID_CAP_RETAILDEMO_OFFLINECONTENT                      = "ID_CAP_RETAILDEMO_OFFLINECONTENT"                   [3107518054,  121342719, 1646822783, 2853351764,  441338625, 3508733821,  139548560, 2125483853];
+    pub const ID_CAP_RINGTONE_ADD                                   = "ID_CAP_RINGTONE_ADD"                                [3974642852, 1571372058, 4159005296, 1467246296, 2807880853, 3789317012, 1707856622, 2183692766];
+    pub const ID_CAP_ROAMING_CONFIGURATION                          = "ID_CAP_ROAMING_CONFIGURATION"                       [3286512930, 2104113511,  776169894, 3774931132, 1481479886, 2679856876, 3763471432, 1320927836];
+    pub const ID_CAP_ROTATION_MANAGER                               = "ID_CAP_ROTATION_MANAGER"                            [2697509677, 3562892137, 2520345939, 3145480182, 2943594840, 2899422242, 4148452379, 2348924943];
+    pub const ID_CAP_RUNTIME_CONFIG                                 = "ID_CAP_RUNTIME_CONFIG"                              [2605919901, 2028505803, 2616249472, 1916951566, 2429234122,  670920067, 1637099775,  293743863];
+    pub const ID_CAP_SCREENCAPTURE                                  = "ID_CAP_SCREENCAPTURE"                               [1848794505, 1539159629, 1871611947, 1251676914,  485710209,  609345724, 3609089605, 3774113151];
+    pub const ID_CAP_SCREEN_RECORDER                                = "ID_CAP_SCREEN_RECORDER"                             [3681416758, 3923958136,  695973463,  967090356, 1023589011,  667602946, 2720231792, 2874285193];
+    pub const ID_CAP_SCREEN_RECORDER_BKG                            = "ID_CAP_SCREEN_RECORDER_BKG"                         [1742502756, 2084942211, 3441620769, 1644234786, 3971719317, 1862018027, 2570039919, 3620652526];
+    pub const ID_CAP_SEARCHMAPS_SHAREDCONFIG                        = "ID_CAP_SEARCHMAPS_SHAREDCONFIG"                     [3705955382, 2687187824,  198155538, 2126119446, 3951291341, 2497300154, 2574559624, 3570553757];
+    pub const ID_CAP_SEND_TO_ONENOTE                                = "ID_CAP_SEND_TO_ONENOTE"                             [3556700950,  966883876, 2119994786,  835570158, 4186075173, 3321566178, 2342780504,  112422366];
+    pub const ID_CAP_SENSORS                                        = "ID_CAP_SENSORS"                                     [ 894702801, 1859710393,  940606919, 2487237098, 2036547730, 4046982941, 3141396038, 1818075930];
+    pub const ID_CAP_SETDEVICENAME                                  = "ID_CAP_SETDEVICENAME"                               [1054739705, 2344687412, 3568532035, 2283427078,  157244973,  759021052,  568658869, 2716908597];
+    pub const ID_CAP_SETTINGSYNC                                    = "ID_CAP_SETTINGSYNC"                                 [2427587499,  746265140, 4197375139,   43976045, 1292382866, 3210384014,  185107289,   13440002];
+    pub const ID_CAP_SETTINGSYNC_CONFIGURATION                      = "ID_CAP_SETTINGSYNC_CONFIGURATION"                   [4107885954, 3716185742, 3224104934, 1187995184, 1385456426, 4146828806, 1301940450, 2042614762];
+    pub const ID_CAP_SETTINGS_MANAGEMENT_PROVIDER                   = "ID_CAP_SETTINGS_MANAGEMENT_PROVIDER"                [2267846761, 3652167981,  553647163, 3516993597, 1653800410,  782072134, 1504010237, 3294492881];
+    pub const ID_CAP_SHARED_OBJECT_DIRECTORY                        = "ID_CAP_SHARED_OBJECT_DIRECTORY"                     [1128246136,  365952284,  215699971,  148956502, 1240748635, 1079928833, 1554293643, 2607006146];
+    pub const ID_CAP_SHARED_USER_CERTIFICATES                       = "ID_CAP_SHARED_USER_CERTIFICATES"                    [2601028688, 1400909527,  309619950, 1487860706, 2042211356, 3982927395, 4209023184, 4176575247];
+    pub const ID_CAP_SHARE_DELEGATE                                 = "ID_CAP_SHARE_DELEGATE"                              [2607041781,  105400294,  931728278, 1249740349, 2184341645, 1865696420, 2620492605,  166280151];
+    pub const ID_CAP_SHELL_DEVICE_LOCK_UI_API                       = "ID_CAP_SHELL_DEVICE_LOCK_UI_API"                    [ 341400343, 1616620255, 1379272012, 2215826939, 3441876558, 4070683511, 1462915037, 1808767397];
+    pub const ID_CAP_SHELL_LAUNCH_SESSION                           = "ID_CAP_SHELL_LAUNCH_SESSION"                        [ 586922898, 2075895408, 1411270192, 2671636579,  969246145, 3484573908,  234972015,  856650593];
+    pub const ID_CAP_SHELL_NAVIGATION                               = "ID_CAP_SHELL_NAVIGATION"                            [ 459277962,  170024272, 3490928234, 2979052266,  451014435, 3757575450, 1233373492,  803577650];
+    pub const ID_CAP_SHELL_NOTIFICATION_CLIENT                      = "ID_CAP_SHELL_NOTIFICATION_CLIENT"                   [2337451890, 1865582629,  231446391, 1725049013,  988181085, 1064791386, 1119752906, 2873223052];
+    pub const ID_CAP_SHELL_OEM_ADMIN                                = "ID_CAP_SHELL_OEM_ADMIN"                             [2203887919, 4246438731, 4245180999, 4283172919, 2340055564, 1451056652, 4277460884, 2208404897];
+    pub const ID_CAP_SHELL_RESET_NAVIGATION                         = "ID_CAP_SHELL_RESET_NAVIGATION"                      [3527194039,  572934455,  775768958, 3763392049, 3319281739, 2988155326, 4182862322, 3449920013];
+    pub const ID_CAP_SHELL_TEST_CLIENT                              = "ID_CAP_SHELL_TEST_CLIENT"                           [ 128241636, 3307902247, 2817285140,  735132217, 2312911277, 4205809206, 1007611369, 3710680900];
+    pub const ID_CAP_SHOW_VOLUME_CONTROL                            = "ID_CAP_SHOW_VOLUME_CONTROL"                         [ 195345289, 1916222243,  719600493, 4220986442, 3702103107,  831528560, 2223520357, 1885368309];
+    pub const ID_CAP_SMS                                            = "ID_CAP_SMS"                                         [2837814457,  446838958, 4134203363, 3154618340, 3806605380, 2087284607, 1631047062,  833954437];
+    pub const ID_CAP_SMS_COMPANION                                  = "ID_CAP_SMS_COMPANION"                               [ 224192147, 1647023889, 1456540092, 4040574103, 3006085188, 2726105451,  249856595,  360392180];
+    pub const ID_CAP_SMS_INTERCEPT_AGENT                            = "ID_CAP_SMS_INTERCEPT_AGENT"                         [1619915329,  767272629, 3035824254, 1260436064,  142962605, 2206985418, 2539139101,  350820049];
+    pub const ID_CAP_SMS_INTERCEPT_RECIPIENT                        = "ID_CAP_SMS_INTERCEPT_RECIPIENT"                     [4016738814, 1955307875, 4281734135, 3555921050, 1308037760,  503627744, 4071514305,  238101970];
+    pub const ID_CAP_SOUND_CONTROL                                  = "ID_CAP_SOUND_CONTROL"                               [3088484153,  305556632, 2650074917, 3352569615, 3870522476,   76631359, 1413047221, 2993953468];
+    pub const ID_CAP_SPEECH_GRAMMARS                                = "ID_CAP_SPEECH_GRAMMARS"                             [2735174199, 4247224082, 1597245716,  112696005, 3212664062, 1348314186, 3750674045, 3802821443];
+    pub const ID_CAP_SPEECH_RECOGNITION                             = "ID_CAP_SPEECH_RECOGNITION"                          [3535090683, 1467790171, 3683719553, 3624199220,  916717644,  438675671, 3648676404, 4113263664];
+    pub const ID_CAP_SPEECH_RECOGNITION_SYSTEM                      = "ID_CAP_SPEECH_RECOGNITION_SYSTEM"                   [2846054301, 2534429273, 3723715993, 1728552010, 1338545801, 3148967222, 3628011301, 3693733077];
+    pub const ID_CAP_SPEECH_SETTINGS                                = "ID_CAP_SPEECH_SETTINGS"                             [3879701980, 2986429679, 2371327785, 3842365063,  186255825,  910705238, 4224693043, 3507114653];
+    pub const ID_CAP_SPEECH_UPDATE                                  = "ID_CAP_SPEECH_UPDATE"                               [ 646298778, 3199239359, 3008431644, 1046002542, 1923125566,   55209111, 2175085899,  707074146];
+    pub const ID_CAP_SPLASH_CONFIG                                  = "ID_CAP_SPLASH_CONFIG"                               [3919938835, 2933956364, 1380416994, 3749268767, 1973550014,  959549438,  450452208, 1211797886];
+    pub const ID_CAP_STARTMENU_CONFIG                               = "ID_CAP_STARTMENU_CONFIG"                            [ 888614017, 1405439185, 1347356837, 1468645343, 2595212279, 3097397038, 1568056746, 1575295029];
+    pub const ID_CAP_STORAGE_MANAGEMENT                             = "ID_CAP_STORAGE_MANAGEMENT"                          [2312163180, 3907364612, 1791099509, 2361854400, 2875950616, 4273782739, 2484410821, 3641475072];
+    pub const ID_CAP_SUPPRESS_MSA_CONNECT_ARD                       = "ID_CAP_SUPPRESS_MSA_CONNECT_ARD"                    [2761158735, 2028465939,  520306464, 1780722689, 2343629302,  261837627, 1709310277,  266276999];
+    pub const ID_CAP_SYNC_EXTENSION                                 = "ID_CAP_SYNC_EXTENSION"                              [1205308923,  455456419, 3563239602, 2512184885, 1713697228, 3256533572, 3876253951, 4178915363];
+    pub const ID_CAP_SYSTEMTRAY_ADMIN                               = "ID_CAP_SYSTEMTRAY_ADMIN"                            [4025539208, 2394182412, 3665678535, 1424508680,  587899016, 2627545747, 1185390124, 3626693348];
+    pub const ID_CAP_SYSTEM_ALLOC_WINDOWID                          = "ID_CAP_SYSTEM_ALLOC_WINDOWID"                       [3258601125, 2427683295, 3037576761, 1471167596, 3019208459,  398100254, 1843972914, 1229678866];
+    pub const ID_CAP_SYSTEM_COMPOSITOR                              = "ID_CAP_SYSTEM_COMPOSITOR"                           [2772979002, 1706116018, 2550500585, 3031323619, 4106578731, 1216361816, 3959319820, 3727325269];
+    pub const ID_CAP_SYSTEM_COUNTERS                                = "ID_CAP_SYSTEM_COUNTERS"                             [1913374562, 3195220476, 2912288353, 4256722724, 2370057973, 1457864107,  616163060, 4283528709];
+    pub const ID_CAP_SYSTEM_REGISTRAR                               = "ID_CAP_SYSTEM_REGISTRAR"                            [3529259054,  693913535, 2502525446, 2356270295,  440525008,  533313454,  119302349, 3481226655];
+    pub const ID_CAP_SYSTEM_WAITCURSOR                              = "ID_CAP_SYSTEM_WAITCURSOR"                           [ 454091155,  715092148, 1936458702,  714591068, 1449827583, 2962869081, 2955151555,  681894927];
+    pub const ID_CAP_TELEMETRY_ADMIN                                = "ID_CAP_TELEMETRY_ADMIN"                             [4255666143, 1041868521, 2810925777, 1851531193,  881878350, 2185342854, 1190636781,  526370508];
+    pub const ID_CAP_TELEMETRY_CONFIGURE                            = "ID_CAP_TELEMETRY_CONFIGURE"                         [3758790050, 1098763866,  409658466, 1290007948, 3381367864,  119475505,  673337539,  928496103];
+    pub const ID_CAP_TELEMETRY_STUDY                                = "ID_CAP_TELEMETRY_STUDY"                             [2431839632, 2577023175, 3420314292, 3456497880,  733806331, 2894493103, 1171837811, 3072005256];
+    pub const ID_CAP_TEST_NAVIGATION                                = "ID_CAP_TEST_NAVIGATION"                             [2924950472, 2996500796, 2307082749, 1568609809, 1405840275, 1419194553, 1103440866, 2286264851];
+    pub const ID_CAP_TILERESTOREDATA                                = "ID_CAP_TILERESTOREDATA"                             [3498766289, 1752443994, 3708231901,  989869125, 2505011435, 3884157650, 1315978408, 3734161061];
+    pub const ID_CAP_TOUCH                                          = "ID_CAP_TOUCH"                                       [ 255448367,  605612240, 1670378437, 2841375856,  397265853, 1417642585, 2665013689,  578561356];
+    pub const ID_CAP_TOUCH_TEST                                     = "ID_CAP_TOUCH_TEST"                                  [2043118055, 1660937372, 1760295771,   23001125, 4123768582, 2542299099,  179048453, 3995320372];
+    pub const ID_CAP_TPM_VSCMANAGER                                 = "ID_CAP_TPM_VSCMANAGER"                              [ 701820295, 1719202734, 2982983246, 3459181053, 1989146076, 1264491859,  613965645, 1953312053];
+    pub const ID_CAP_TS_SCHEDULES_ALL                               = "ID_CAP_TS_SCHEDULES_ALL"                            [1181515144, 3694836878, 2741228614, 1815774408, 4179666529, 4076015505, 4121245909, 1357839547];
+    pub const ID_CAP_USB                                            = "ID_CAP_USB"                                         [3341945687,   85529345, 3664973523, 1010051450, 1630652010, 1124994769, 4074068333,  542386965];
+    pub const ID_CAP_USER_ACTIVITY                                  = "ID_CAP_USER_ACTIVITY"                               [4260046235, 1918750347, 1973672567, 1621588382, 1482781861,  325684403,  486092017, 2041679643];
+    pub const ID_CAP_VIDEOSINK_INTERNAL                             = "ID_CAP_VIDEOSINK_INTERNAL"                          [1033511018, 1822759248, 1978675957, 1643708290, 4229251492,  667841080, 3971149696,  808357666];
+    pub const ID_CAP_VOICEMAIL                                      = "ID_CAP_VOICEMAIL"                                   [ 888132005, 3448019340,  158356726, 3167060666, 2617767706, 1045037197, 1340612081, 1516862962];
+    pub const ID_CAP_VOIP                                           = "ID_CAP_VOIP"                                        [1095573762, 3316101978, 2022247834, 2111809451, 3987745090, 1723769045, 1076307134, 4157479128];
+    pub const ID_CAP_VOIP_CALL_CONTROLLER                           = "ID_CAP_VOIP_CALL_CONTROLLER"                        [2657401801, 2918572790, 2817216769, 2779471173, 1793032563, 1449312528,  269134460, 2770226695];
+    pub const ID_CAP_VSTEST_INSTALL_FOLDER                          = "ID_CAP_VSTEST_INSTALL_FOLDER"                       [  50897116, 1806242603, 3006338184, 3115873503, 2478008374, 3306358596, 1758746118, 3101274807];
+    pub const ID_CAP_W32TIME_API                                    = "ID_CAP_W32TIME_API"                                 [1419002827, 3045150687,  849488349, 3802312663, 1498001892, 3098869541, 3227875572,  616546729];
+    pub const ID_CAP_WAB_RESOURCES                                  = "ID_CAP_WAB_RESOURCES"                               [3450627587, 3500949094, 3015895069, 2060514397, 4137301598, 1879330820, 4055232919, 1470326601];
+    pub const ID_CAP_WALLET                                         = "ID_CAP_WALLET"                                      [1132648431,  652232442, 2510657139,  221530666, 1093305313, 2143138099, 2249097044, 1024050389];
+    pub const ID_CAP_WALLET_ADMIN                                   = "ID_CAP_WALLET_ADMIN"                                [1314380931, 3989923313, 3249193833, 1963115619, 3940350845, 1282913705, 2904921893, 3519892189];
+    pub const ID_CAP_WALLET_DEALS                                   = "ID_CAP_WALLET_DEALS"                                [1701033769,  137094913, 3738083205,  577272984, 1204217555, 1180762924, 3352773070, 2589626690];
+    pub const ID_CAP_WALLET_PAYMENTINSTRUMENTS                      = "ID_CAP_WALLET_PAYMENTINSTRUMENTS"                   [1071284118, 3523478921, 2250086196, 3560449695, 2200277944, 2152682759,  979385846, 2597897782];
+    pub const ID_CAP_WALLET_SECUREELEMENT                           = "ID_CAP_WALLET_SECUREELEMENT"                        [2574699830, 3181185929, 3027770695,  909065751, 3082512267, 3559348174, 3315132573, 1163471962];
+    pub const ID_CAP_WALLPAPER_ADMIN                                = "ID_CAP_WALLPAPER_ADMIN"                             [3794396841, 2796053954,  115778455, 3480603257, 3921638602, 1435079522, 1181627245, 1104944796];
+    pub const ID_CAP_WBOEXT                                         = "ID_CAP_WBOEXT"                                      [4195170007, 3217416718,  408606996, 1215698218, 3843642158, 2328682911, 1956890848, 3247165808];
+    pub const ID_CAP_WEBBROWSERCOMPONENT                            = "ID_CAP_WEBBROWSERCOMPONENT"                         [3710790608, 1464126134, 2011855470, 2451533004,  964704134, 1797798242, 1092047362, 3684262004];
+    pub const ID_CAP_WEB_CREDENTIALS                                = "ID_CAP_WEB_CREDENTIALS"                             [2720926852, 3985360349, 1076280333, 2472745645,  972265978, 3854372980, 2790955482, 1237008696];
+    pub const ID_CAP_WIFI_ADMIN                                     = "ID_CAP_WIFI_ADMIN"                                  [ 440652535, 2732821954, 2967096804, 2743102374,  560164511, 4036865778, 1144947683, 2219429793];
+    pub const ID_CAP_WIFI_BASIC                                     = "ID_CAP_WIFI_BASIC"                                  [ 683259718, 3451203448, 3339750839, 4227422354,  133059296, 3103485949, 1925116620, 1722723162];
+    pub const ID_CAP_WIFI_BROWSER                                   = "ID_CAP_WIFI_BROWSER"                                [2324933716, 2956100434, 4219743502, 1086821030, 3812704970, 2422414750,  878115116, 1667648065];
+    pub const ID_CAP_WIFI_HOTSPOT_HOST                              = "ID_CAP_WIFI_HOTSPOT_HOST"                           [2838927708, 2070449754, 1304848856, 2028849301,  358321552, 2227845149, 3853260091,  658834658];
+    pub const ID_CAP_WIFI_PROFILE_ADMIN                             = "ID_CAP_WIFI_PROFILE_ADMIN"                          [4054819066,  802391600, 1203984480, 2463650894, 3336624440,   45135620, 2726068655, 3845894840];
+    pub const ID_CAP_WIFI_TILE_MANAGER                              = "ID_CAP_WIFI_TILE_MANAGER"                           [3016363324,  211487058, 2289367980,  625093705, 4253951293,  509245108, 1154031315, 2142883203];
+    pub const ID_CAP_WPN_PLATFORM                                   = "ID_CAP_WPN_PLATFORM"                                [1897905913, 3156468556, 2798359476, 3140430944,  429555755, 1449344379,  229829512, 1303529462];
+    pub const ID_CAP_WPN_PLATFORM_REG_KEY                           = "ID_CAP_WPN_PLATFORM_REG_KEY"                        [2776066945, 3587586217, 1820852381, 2593702941,   53257123,   69866954,  901192403, 2294580339];
+    pub const ID_CAP_WPTOOLS_INSTALL_FOLDER                         = "ID_CAP_WPTOOLS_INSTALL_FOLDER"                      [ 670527361, 4270065953,  442862738, 3755418335, 1291780350,  255240843,   28775031, 2873517487];
+    pub const ID_CAP_ZMFSERVICES                                    = "ID_CAP_ZMFSERVICES"                                 [2108181710, 3990930931, 3595162611, 4117497009, 1326642875, 3084868306, 1753940881, 3334367291];
+    pub const ID_CAP_ZTRACE                                         = "ID_CAP_ZTRACE"                                      [1516196777, 2336187826, 3588042469, 1597350212, 3633128727, 3627081447, 3987028236, 1309635281];
+    pub const Microsoft_firmwareRead_cw5n1h2txyewy                  = "Microsoft.firmwareRead_cw5n1h2txyewy"               [3587299478, 3639007516, 3814668116, 3504216910, 3288238490, 4152770330, 3776762357, 4137276808];
+    pub const Microsoft_firmwareWrite_cw5n1h2txyewy                 = "Microsoft.firmwareWrite_cw5n1h2txyewy"              [ 167150616, 1275062348, 2713805091, 2778296164, 3974222648, 2164638478, 1020776486,  555832402];
+    pub const accessoryManager                          = "accessoryManager"                                   [1069651245, 2375841711, 1570187833, 1826699927, 1726783584, 1420246439,  936999711, 2864509111];
+    pub const activateAsUser                            = "activateAsUser"                                     [1619559953, 3382903645,  900470658, 1831728285, 1265525240,  911141481, 3610949621, 2233473754];
+    pub const activity                                  = "activity"                                           [4191902497, 1978494743, 2749246665, 3072910927,  102050379, 1373940514, 1865125746,  920055924];
+    pub const activityData                              = "activityData"                                       [2946685888, 1412457410, 1274547043, 2288208346, 1419295423, 4263087484, 1197735815,  185032629];
+    pub const activitySystem                            = "activitySystem"                                     [1162883296, 1069378821,  326368785, 1434266408, 2276863517,   33602275,  954297818,  703384370];
+    pub const allAppMods                                = "allAppMods"                                         [ 739809946,   31981425, 3357933805, 1069317161, 1095314212, 1881123208, 2517158727, 2838317017];
+    pub const allJoyn                                   = "allJoyn"                                            [3804131010,  705767314, 2184915385, 1233717497, 4177653708, 4048234552, 2488388519, 2361358067];
+    pub const allowElevation                            = "allowElevation"                                     [3190844328, 4099963570, 3870079217, 2969588245, 2822710570, 1600598934, 3576592281, 2616761512];
+    pub const appBroadcast                              = "appBroadcast"                                       [1711161832, 2391198485,  820063299, 1370696258,  386833486,  159980468, 2370099864, 3003935251];
+    pub const appBroadcastServices                      = "appBroadcastServices"                               [2926717412,  422488402, 1366096836, 1344602270, 1873175643, 1473303204,  936893394, 2894442738];
+    pub const appBroadcastSettings                      = "appBroadcastSettings"                               [3476374539, 3039849540,  366645324, 2483637467, 1772423258, 1707465968, 3111864834, 3269078184];
+    pub const appCaptureServices                        = "appCaptureServices"                                 [1463147068, 3371832618, 1388101890, 3973589861, 2607976136,  547912034,  117841509,  208667311];
+    pub const appCaptureSettings                        = "appCaptureSettings"                                 [ 658842318,  317372455, 4011887121, 1811749129, 3600856248, 3713732611, 2239025110, 3453100640];
+    pub const appDiagnostics                            = "appDiagnostics"                                     [2263946659,  221263054, 3004297223, 2509109377, 4006057435,  143953683,   28675390,  302247413];
+    pub const appLicensing                              = "appLicensing"                                       [2889647217, 2665888344,  755061017, 1229970740, 3900060832,  776474665, 3655643929, 4127345024];
+    pub const appManagementSystem                       = "appManagementSystem"                                [ 881369057, 2832504344, 3519039658, 1339685250, 2577216982, 4012114432, 3791153993,  395796043];
+    pub const applicationDefaults                       = "applicationDefaults"                                [2307497680, 4012621616, 2010379297, 3343964328, 2052510818, 1748739893, 1212548257, 3431941134];
+    pub const applicationViewActivation                 = "applicationViewActivation"                          [2898197306, 3300330394, 3003314763, 2509405055, 2905551251, 1957103989, 1590147722, 3620397556];
+    pub const appointments                              = "appointments"                                       [3206565827, 2991827804, 2894052746, 2323054194, 1177664632, 1286603069, 1189849485, 1972712703];
+    pub const appointmentsSystem                        = "appointmentsSystem"                                 [2643354558,  482754284,  283940418, 2629559125, 2595130947,  547758827,  818480453, 1102480765];
+    pub const audioDeviceConfiguration                  = "audioDeviceConfiguration"                           [ 883896814, 3354213294, 3301286982,  317704133,  585371054, 1013261047, 3043375309, 4225165118];
+    pub const automatedAppLaunch                        = "automatedAppLaunch"                                 [4106739713, 2202381260,  889751371, 2542076894,  949314626, 2070158488, 1307026530, 1634293246];
+    pub const backgroundMediaPlayback                   = "backgroundMediaPlayback"                            [2534516097, 1142442286,  655920092, 1743268574, 1314016795, 1429942190, 2819395560,  270754105];
+    pub const backgroundMediaRecording                  = "backgroundMediaRecording"                           [3600989948,   23999185,  244315476, 3249032439, 1572071926, 2693423960, 1756015955,  476862173];
+    pub const backgroundSpatialPerception               = "backgroundSpatialPerception"                        [ 430053105, 4032735609, 1171668343, 1645936470, 2638819442, 1416532308, 1601544585, 3837596276];
+    pub const backgroundVoIP                            = "backgroundVoIP"                                     [1608535222, 2236177337, 2273647247, 4285317194,   64076989, 4056796171, 3520552447,  587742984];
+    pub const biometricSystem                           = "biometricSystem"                                    [3149641346, 2023381965, 1268122805,  208697403,  105048083, 4278001374, 1912745317,   54431807];
+    pub const blockedChatMessages                       = "blockedChatMessages"                                [1615643396, 3082447698, 3017968123, 3374415059, 2610093431, 2583988378, 2307023373,  470284681];
+    pub const bluetooth                                 = "bluetooth"                                          [3695299237, 4278247513, 1402175595,  525333027, 1997893985,  119680826, 3080251162, 2948828488];
+    pub const bluetooth_genericAttributeProfile         = "bluetooth.genericAttributeProfile"                  [1727386112, 3145810323, 3431268083, 3689970327,  739836844, 3616656621,  880051228, 1594631605];
+    pub const bluetooth_rfcomm                          = "bluetooth.rfcomm"                                   [ 192337609, 3775446108,  269428844, 3253752169,  951748958, 3578505117, 3621846901, 2918023745];
+    pub const bluetoothAdapter                          = "bluetoothAdapter"                                   [4045220798, 4006817410, 2009534922, 2349621079, 2856184499, 1580556691, 4033646817, 3656330790];
+    pub const bluetoothDeviceSettings                   = "bluetoothDeviceSettings"                            [1100675591, 2533292760, 1453534270,  493659277, 2124548245,  453423080, 3673526281, 1287615703];
+    pub const bluetoothSync                             = "bluetoothSync"                                      [1974458983, 3751645300, 1729349480,  143855079, 3029832425, 4231518528, 3654703415, 3501943311];
+    pub const bootstrapNetworkConnection                = "bootstrapNetworkConnection"                         [ 409184936, 3492136685, 1247900983, 2581887906, 3579752202,  163677003, 4103108149, 3681389169];
+    pub const broadFileSystemAccess                     = "broadFileSystemAccess"                              [3247244612, 4072385457,  573406302, 3159362907, 4108726569,  214783218,  394353107, 2658650418];
+    pub const browserAppList                            = "browserAppList"                                     [1786727007, 1419438852, 1346871473,  886967802, 2322011002, 2056872840, 2554717053,  428674754];
+    pub const browserCredentials                        = "browserCredentials"                                 [3826530540,  457977918,  831996869, 4121746106, 3747742610,  578447321, 1227403828, 2925310924];
+    pub const cameraProcessingExtension                 = "cameraProcessingExtension"                          [1769135850, 3630014969, 1076440520, 3467250754, 2559601130, 3896137046,  598179284, 2520253561];
+    pub const capabilityAccessConsentDeviceSettings     = "capabilityAccessConsentDeviceSettings"              [4202252877, 2865353318, 2163093313, 1255794810,  793861730,  357389790, 2203944137, 1979881144];
+    pub const cellularData                              = "cellularData"                                       [2732930991, 1716039000, 1394599507, 3926803129, 3068501044, 2027633224,  866606239,  446136062];
+    pub const cellularDeviceControl                     = "cellularDeviceControl"                              [3523901360, 1745872541,  794127107,  675934034, 1867954868, 1951917511, 1111796624, 2052600462];
+    pub const cellularDeviceIdentity                    = "cellularDeviceIdentity"                             [  11742800, 2107441976, 3443185924, 4134956905, 3840447964, 3749968454, 3843513199,  670971053];
+    pub const cellularMessaging                         = "cellularMessaging"                                  [3659434007, 2290108278, 1125199667, 3679670526, 1293081662, 2164323352, 1777701501, 2595986263];
+    pub const chat                                      = "chat"                                               [1843664571, 2624890912,  906393051, 1078660739, 2972153046, 2454524471,  519964814, 2587554601];
+    pub const chatSystem                                = "chatSystem"                                         [2210865643, 3515987149, 1329579022, 3761842879, 3142652231,  371911945, 4180581417, 4284864962];
+    pub const childWebContent                           = "childWebContent"                                    [2440306377, 3304611049, 1494399071, 1161926223,  163912384, 1437065773, 1456820560, 2390158196];
+    pub const chromeInstallFiles                        = "chromeInstallFiles"                                 [3424233489,  972189580, 2057154623,  747635277, 1604371224,  316187997, 3786583170, 1043257646];
+    pub const cloudExperienceHost                       = "cloudExperienceHost"                                [4043583521, 4256083701, 1687135424, 4293105506, 3284195402, 1880287749, 1981697668, 1245952579];
+    pub const cloudStore                                = "cloudStore"                                         [3035980445, 2343077072, 2039973919, 2593655016, 2336600711, 3402322490, 2613491542, 1611519126];
+    pub const codeGeneration                            = "codeGeneration"                                     [3802075078, 3056353928,  831493480, 1656114792, 3017467262, 3614159431,  110502994, 2980336225];
+    pub const comPort                                   = "comPort"                                            [3079670389, 2007748205, 2562610427,  601937422, 2520430104,  385621523, 2626497331, 2704537329];
+    pub const componentUiInWebContent                   = "componentUiInWebContent"                            [2570221383, 1448989026, 3001874883, 2650828016, 3583537604, 1222031009, 3645131881, 3559315223];
+    pub const confirmAppClose                           = "confirmAppClose"                                    [ 719903687, 4232398539, 3510704256, 4190309334, 1296461745,  392634193, 3994393407, 3122493104];
+    pub const constrainedImpersonation                  = "constrainedImpersonation"                           [1604681682,  535129537, 3273749797, 3666938095,  336295784, 2177615760, 2743807136, 2867270584];
+    pub const contacts                                  = "contacts"                                           [3940324700, 2858494370, 2345038474, 1357291012, 3714428700, 1390950899, 1148638500, 3083056261];
+    pub const contactsSystem                            = "contactsSystem"                                     [2897291008, 3029319760, 3330334796,  465641623, 3782203132,  742823505, 3649274736, 3650177846];
+    pub const contentDeliveryManagerSettings            = "contentDeliveryManagerSettings"                     [4110201754, 1809691228, 1538346907, 3864242205, 2028988089, 3700273171, 2753247048, 3183731716];
+    pub const contentRestrictions                       = "contentRestrictions"                                [1153741845, 4055335118, 1225664883,  195292590, 1759808002, 1431092349, 2272129516, 1486240673];
+    pub const coreShell                                 = "coreShell"                                          [2165721414,  884371012, 2773947476, 2437641138, 4209659587,  972658821, 4033014341,  190168586];
+    pub const cortanaPermissions                        = "cortanaPermissions"                                 [3275915203, 3073501320,  309536135, 1674744297, 1740689076, 4251230105,  810187298, 4091229748];
+    pub const cortanaSettings                           = "cortanaSettings"                                    [1216833578,  114521899, 3977640588, 1343180512, 2505059295,  473916851, 3379430393, 3088591068];
+    pub const cortanaSpeechAccessory                    = "cortanaSpeechAccessory"                             [2393506754,  775327057, 2499928852, 1629457672, 3431788399, 3853256447, 4267427883, 2817119566];
+    pub const cortanaSurface                            = "cortanaSurface"                                     [  62839846,  501466537, 2290531897, 2759907144,  790738421,  810669360,  297897068,  329083804];
+    pub const curatedTileCollections                    = "curatedTileCollections"                             [2761669778,  346687544,  888879415, 1068145723, 1713973600, 1619522427, 2554995326,  316594194];
+    pub const customInstallActions                      = "customInstallActions"                               [3507210475, 1388726999,  495431498,  661836165,   78720062, 3345250209,  313768747, 1276603351];
+    pub const dateAndTimeDeviceSettings                 = "dateAndTimeDeviceSettings"                          [3091062438, 3596320077,  788891217, 2782159909, 2069850133,  879116102,  973189406, 4197634097];
+    pub const dependencyTarget                          = "dependencyTarget"                                   [3632359879, 3209770303, 1264712472, 2987632071, 4029161013, 2743820849, 2346022090, 2809393155];
+    pub const developerSettings                         = "developerSettings"                                  [2897910012, 1573455959,  200501331, 2568813761, 2319087266,  988733333, 3857432473, 3189972763];
+    pub const developmentModeNetwork                    = "developmentModeNetwork"                             [2579371802,   50273823, 2532007077,  778130756,  637227457, 1650229637, 1599285538, 2684141260];
+    pub const deviceEncryptionManagement                = "deviceEncryptionManagement"                         [2239652689, 2664391391, 2534113880, 4255263305, 2927724023,  983973769, 3561532285, 1967325175];
+    pub const deviceIdentityManagement                  = "deviceIdentityManagement"                           [ 940827362, 2949684522, 1275981294, 3378921087, 3884907310, 1905252139, 2180543584,  691417712];
+    pub const deviceLockManagement                      = "deviceLockManagement"                               [1347429957, 3987483571, 1984428752, 1885250686,   25866997, 3084422878, 1348532569,  983810561];
+    pub const deviceManagementAdministrator             = "deviceManagementAdministrator"                      [1813308025, 3893443517, 2936766468, 3261773091, 2430119119,  317633435, 1602637770, 2843472530];
+    pub const deviceManagementDeviceLockPolicies        = "deviceManagementDeviceLockPolicies"                 [1447750909, 3412137041, 2543229612, 2579680811,  949383960, 1512208256, 1474305305, 1405036652];
+    pub const deviceManagementDmAccount                 = "deviceManagementDmAccount"                          [2830772650, 3846338416, 1816072262, 3095855940, 4193335384, 2293034769,  252220343,  157514922];
+    pub const deviceManagementEmailAccount              = "deviceManagementEmailAccount"                       [ 917207464,   68434614, 1080454720, 3650237274, 2024810623, 3125538881, 3710571513, 3065818052];
+    pub const deviceManagementFoundation                = "deviceManagementFoundation"                         [2114238718,  839519356, 3141599949, 1701592612, 4239813495, 2246009235, 3401969156,  562141158];
+    pub const deviceManagementRegistration              = "deviceManagementRegistration"                       [ 150999393,  257915958, 2109302476,  342821789, 1525132724, 4026398146,  564805607,  440935315];
+    pub const deviceManagementWapSecurityPolicies       = "deviceManagementWapSecurityPolicies"                [3057529725, 2845346375, 3525973929, 2302649945, 3073475876,  347241512, 4167996218, 3915214886];
+    pub const devicePortalProvider                      = "devicePortalProvider"                               [ 676841816, 1149237631, 4032147681, 1035363705, 1749014660, 2240504816,  969164111, 1508678073];
+    pub const deviceProvisioningAdministrator           = "deviceProvisioningAdministrator"                    [ 989523358, 1667815365,  316973287,  129935811, 3358257576, 2796868252, 2781088163,  715581871];
+    pub const deviceUnlock                              = "deviceUnlock"                                       [3090417596, 1177152433,  709977159, 3759866339, 3648116925, 1194977332, 3459169701, 1652573254];
+    pub const diagnostics                               = "diagnostics"                                        [3276485077, 3589353304, 4129381419, 1100299628,  406867830, 2425660980, 2594398395,  148359903];
+    pub const displayDeviceSettings                     = "displayDeviceSettings"                              [3996567031,    2672614,  524480011, 1692915980,  260182050, 3211622006, 2077298474, 2793490206];
+    pub const documentsLibrary                          = "documentsLibrary"                                   [4090599227, 2031128978, 4197150514, 3106696474, 3598308373,  297001435, 2835591233, 1745192457];
+    pub const dualSimTiles                              = "dualSimTiles"                                       [ 531085349, 3971269601, 1024280280, 4054111094, 4029683689,  912804737,  715231967, 2563861597];
+    pub const email                                     = "email"                                              [3213385057, 1002943098, 1723051927, 1419152406, 3870126442,  848894659,  559664656, 2364677135];
+    pub const emailSystem                               = "emailSystem"                                        [2357373614, 1717914693, 1151184220, 2820539834, 3900626439, 4045196508, 2174624583, 3459390060];
+    pub const enterpriseAuthentication                  = "enterpriseAuthentication"                           [1254246026, 2307176119, 3817577965, 2076242866, 2452291298, 4213526789, 1884648706, 2393659593];
+    pub const enterpriseCloudSSO                        = "enterpriseCloudSSO"                                 [ 983922258, 2159917625, 2751362240, 3284369410, 2497023943,  943411171, 3503282929, 3741434461];
+    pub const enterpriseDataPolicy                      = "enterpriseDataPolicy"                               [ 373139346,  748750918, 1948434659, 2643498477, 4072104851, 1007166015, 1979446734, 3878125657];
+    pub const enterpriseDeviceLockdown                  = "enterpriseDeviceLockdown"                           [1720708008,  676358685, 3694961389, 3536049837,   28312851, 1003502039,  653286243, 2922628565];
+    pub const eraApplication                            = "eraApplication"                                     [2902294132, 2765181373,  854371626, 2195433701,  590439919, 3431881116,  543403436, 2715691291];
+    pub const exclusiveResource                         = "exclusiveResource"                                  [2031129053, 1104036981,  335214479, 1704595805, 1750505959,  454379672, 2676751368,  273525052];
+    pub const expandedResources                         = "expandedResources"                                  [2260126382,  343122119, 3503137940,  547812879, 2608166238, 1729045573, 3394722501, 2075048815];
+    pub const extendedBackgroundTaskTime                = "extendedBackgroundTaskTime"                         [ 366303795, 2616852666, 3636748606, 1444657452, 4092942175,  931406372, 2783367388, 2252851075];
+    pub const extendedExecutionBackgroundAudio          = "extendedExecutionBackgroundAudio"                   [1757733230, 3792965022, 4183625483, 1509180916, 2800675197, 3882158587, 2291756888,  318020845];
+    pub const extendedExecutionCritical                 = "extendedExecutionCritical"                          [1129237768,   79454143, 2136254559, 1623985096, 3814653484,   63270843,  875342860, 3699824235];
+    pub const extendedExecutionUnconstrained            = "extendedExecutionUnconstrained"                     [ 374222737, 2106488203,  813473153, 3732709437, 2286922564, 1719656165, 2804691494, 2247406137];
+    pub const featureStagingInfo                        = "featureStagingInfo"                                 [1045063015,  423899465, 3012769174,   65638258, 1865874412, 2349348127,  763856749, 1075684855];
+    pub const feedbackLogCollection                     = "feedbackLogCollection"                              [1124026668, 2590638337,  403184773, 3888676922, 1161809615, 3294960644, 2573571652, 4073547852];
+    pub const firstSignInSettings                       = "firstSignInSettings"                                [1915131181, 1661839130, 3466558662, 2365313265,  168482886, 3910651210, 2178004652, 3294308643];
+    pub const flashPlayerSupport                        = "flashPlayerSupport"                                 [4134329593, 2783901983, 2229587954, 3094154347, 3627794263, 3584176859, 1146258567, 3226826104];
+    pub const fullFileSystemAccess                      = "fullFileSystemAccess"                               [3777909873, 1799880613,  452196415, 3098254733, 3833254313,  651931560, 4017485463, 3376623984];
+    pub const gameBarServices                           = "gameBarServices"                                    [ 449500426, 4112254358, 3456904286, 1727260714, 2501084857,  171973213, 3131658879, 2198086578];
+    pub const gameConfigStoreManagement                 = "gameConfigStoreManagement"                          [ 401877177, 3224070873, 2323497442, 1003973387, 3084573282, 2711758241,  819722521, 4059117567];
+    pub const gameList                                  = "gameList"                                           [2845449227,  614308480,   78272248, 3394209543, 1591752031, 1559218649,   93556750, 3923753255];
+    pub const gameMonitor                               = "gameMonitor"                                        [ 904812328, 3382336523,  604674363, 3566595458,   55181553, 1647155837, 3587023889, 1434257878];
+    pub const gamingContainerResources                  = "gamingContainerResources"                           [3999614906, 2618500826,  771955779,  179454160, 3146794842,  978219881, 1888059073, 3547555565];
+    pub const gazeInput                                 = "gazeInput"                                          [3376936998, 3079131011, 3493239218, 4149829184, 2165481223,  457710256, 1867388583,  975281650];
+    pub const globalMediaControl                        = "globalMediaControl"                                 [2090636495, 3766770698, 2683492325, 2447499578,  598008548,  570169362,  643700305, 4067185823];
+    pub const graphicsCapture                           = "graphicsCapture"                                    [ 955681297, 3470559067,  873149510,  312866181,  505149074, 2965990245, 3641224364,  480676545];
+    pub const hevcPlayback                              = "hevcPlayback"                                       [3631914340,  188226977, 3551325271, 2255822655, 4149116707, 2222894358,  109158049, 3700719646];
+    pub const hfxSystem                                 = "hfxSystem"                                          [3112306483, 3660621463,  257260665, 3486542839, 2332589503, 3487152257, 2268758775, 4227522996];
+    pub const hidTelephony                              = "hidTelephony"                                       [1956928088, 1150472397, 2484069381, 3875382541,  842779402, 1827883220, 3710319321, 1517967199];
+    pub const holographicCompositor                     = "holographicCompositor"                              [2535547787, 2859156693, 1909920036,  495084244, 1900230707, 2688850151,  824944487, 2812335965];
+    pub const holographicCompositorSystem               = "holographicCompositorSystem"                        [1602466680, 3296347575, 4127869923, 2900434464, 2561196862,   49964291, 1709077611, 2367554900];
+    pub const humanInterfaceDevice                      = "humanInterfaceDevice"                               [1046399399, 2930200366, 2987218432, 2534044392, 2246125859, 3426736648, 2380978411, 3024971649];
+    pub const imeSystem                                 = "imeSystem"                                          [1465311546, 2257072239,  668808861,  147177181, 4082675985, 2606236256, 3128464940, 3339364813];
+    pub const inProcessMediaExtension                   = "inProcessMediaExtension"                            [ 214718993,  498630924, 2386791142,  577439625, 2810695033, 1494250253, 3518519070, 3271338433];
+    pub const indexedContent                            = "indexedContent"                                     [ 724741592, 1210917904,  489960769,  637019204, 3345707629, 3097053430, 1727148295,   85063603];
+    pub const inputForegroundObservation                = "inputForegroundObservation"                         [ 700980291, 3826703102,   74257294, 1944477230, 3996044019, 1710163232,  669333698, 3456870998];
+    pub const inputInjection                            = "inputInjection"                                     [ 918685303, 2392273179, 1242551144, 2277013827, 3453391213,  358261840, 2217007564,  611397587];
+    pub const inputInjectionBrokered                    = "inputInjectionBrokered"                             [3013640951, 2064330411,  254515802, 3189253915, 2864799581, 3198640868,  225261281,  449094411];
+    pub const inputObservation                          = "inputObservation"                                   [3027914275, 2211407940,  856553809,  632662545, 1682185480, 3508903257,  964318829,  733730950];
+    pub const inputSettings                             = "inputSettings"                                      [ 290691043, 1809104139, 3779483415, 4180028346,  416543034,  474041192, 1165139072, 3529661359];
+    pub const inputSuppression                          = "inputSuppression"                                   [ 765387629,  349433948, 1457807909,  617344056, 1566752638,  257569518,  614478271, 2332265213];
+    pub const internetClient                            = "internetClient"                                     [2779705173, 1925339129, 2667939958, 2414465498, 3395756507, 4015878651,  158944808,  788332705];
+    pub const internetClientServer                      = "internetClientServer"                               [ 309259276, 1558928059, 2828253251, 1567535764, 1640198682, 3321378364, 2265860830, 2415090742];
+    pub const interopServices                           = "interopServices"                                    [3588549841,  719077279, 3941072665, 3448285197, 1036512782, 3700459644, 2560456231, 1231893854];
+    pub const keyboardDeviceSettings                    = "keyboardDeviceSettings"                             [3454965887, 3290650400, 2940896100, 3597022628,  555909890,  266477235, 2006854836, 2653097966];
+    pub const kinectAudio                               = "kinectAudio"                                        [2504670005, 2976793830, 4003121026, 4148067134, 1713612043, 4071409127, 3590382006, 1961407663];
+    pub const kinectExpressions                         = "kinectExpressions"                                  [1742170943,  636614655,  566345436,  671168881,  221913238, 1591444469,  660231079, 2846280225];
+    pub const kinectFace                                = "kinectFace"                                         [2784946709, 2480755460,  597113710,  353309690, 2371995077,  231819874, 2895422829, 2702751838];
+    pub const kinectGamechat                            = "kinectGamechat"                                     [1181832740,   12243858, 3034171161, 2856761475, 3986326068,  709165692, 3600133598, 3151914247];
+    pub const kinectRequired                            = "kinectRequired"                                     [2351847339, 3952159711, 1321995659, 1856695608, 1876855382, 3779947333, 3567888192, 1066310425];
+    pub const kinectVideo                               = "kinectVideo"                                        [2618385555, 2203043339, 3206394193, 4202133921, 1454837690, 4121054217,  161868357, 1762783179];
+    pub const kinectVision                              = "kinectVision"                                       [3480132683, 2418354375, 3864436754, 3567986673, 4231847241,  478638527, 2019413052,  106637949];
+    pub const languageAndRegionDeviceSettings           = "languageAndRegionDeviceSettings"                    [ 770252753, 1444666466,  336377712, 2873026788,   52184070, 1923451027,   23849407, 2228870135];
+    pub const languageSettings                          = "languageSettings"                                   [ 196535348, 1508395381,  528716610, 1782869926, 3623809819, 1847309163, 3382315171, 2404341211];
+    pub const liveIdService                             = "liveIdService"                                      [1941919063,  976504945, 3191785059, 2835515153, 1936800635, 1519032070, 1452055454, 2678282739];
+    pub const localExperienceInternal                   = "localExperienceInternal"                            [2860943891,  259454752, 3991971624, 1256951739, 2310278415, 1079264183, 2380929058, 3727145988];
+    pub const localSystemServices                       = "localSystemServices"                                [3724309453, 2359325875, 2229763891, 1974356874, 3258006466, 2042387340,  619821221, 1440898974];
+    pub const location                                  = "location"                                           [1120341015, 4059530845,  270443254, 1514536596, 2315272569,  284657971,  419501928,  776969430];
+    pub const locationHistory                           = "locationHistory"                                    [3029335854, 3332959268, 2610968494, 1944663922, 1108717379,  267808753, 1292335239, 2860040626];
+    pub const locationSystem                            = "locationSystem"                                     [2587416013, 1330314424, 1690737965, 1725259538, 4126505581, 1558002373, 2875425159, 3881190746];
+    pub const lockScreenCreatives                       = "lockScreenCreatives"                                [ 352835710, 3145586182, 2880647242,  675368078, 3441261668, 1710821006, 2032288639, 3146782223];
+    pub const lowLevel                                  = "lowLevel"                                           [1234007056, 2663856401, 2070564919,  154281843, 2544581321, 3321489116, 4145095046, 1431496914];
+    pub const lowLevelDevices                           = "lowLevelDevices"                                    [2136653787,  990173382, 3730014305, 3794374500, 1001559012, 3111233883,  485923750, 2526317185];
+    pub const lpacAppExperience                         = "lpacAppExperience"                                  [1502825166, 1963708345, 2616377461, 2562897074, 4192028372, 3968301570, 1997628692, 1435953622];
+    pub const lpacChromeInstallFiles                    = "lpacChromeInstallFiles"                             [2302894289,  466761758, 1166120688, 1039016420, 2430351297, 4240214049, 4028510897, 3317428798];
+    pub const lpacClipboard                             = "lpacClipboard"                                      [4092130000,  472000003, 1670882671,  259370826, 3862510858, 3415016346, 1868891083, 3396446831];
+    pub const lpacCom                                   = "lpacCom"                                            [2405443489,  874036122, 4286035555, 1823921565, 1746547431, 2453885448, 3625952902,  991631256];
+    pub const lpacCryptoServices                        = "lpacCryptoServices"                                 [3203351429, 2120443784, 2872670797, 1918958302, 2829055647, 4275794519,  765664414, 2751773334];
+    pub const lpacEnterprisePolicyChangeNotifications   = "lpacEnterprisePolicyChangeNotifications"            [ 126078593, 3658686728, 1984883306,  821399696, 3684079960,  564038680, 3414880098, 3435825201];
+    pub const lpacIME                                   = "lpacIME"                                            [  79080987, 3398622760, 2608912076, 1085899501, 4039864605, 4024366022,  736258278,  368603348];
+    pub const lpacIdentityServices                      = "lpacIdentityServices"                               [1788129303, 2183208577, 3999474272, 3147359985, 1757322193, 3815756386,  151582180, 1888101193];
+    pub const lpacInstrumentation                       = "lpacInstrumentation"                                [3153509613,  960666767, 3724611135, 2725662640,   12138253,  543910227, 1950414635, 4190290187];
+    pub const lpacMedia                                 = "lpacMedia"                                          [1692970155, 4054893335,  185714091, 3362601943, 3526593181, 1159816984, 2199008581,  497492991];
+    pub const lpacPackageManagerOperation               = "lpacPackageManagerOperation"                        [1742180919, 3973133362, 3881819074, 3076390979, 3006877977, 1258694795, 2087530448, 2333862241];
+    pub const lpacPayments                              = "lpacPayments"                                       [2922296261, 1647482768, 2017091146, 3858667068, 4135663662, 2931985894, 1627820925,  818366431];
+    pub const lpacPnPNotifications                      = "lpacPnPNotifications"                               [ 220022770,  701261984, 3991292956, 4208751020, 2918293058, 3396419331, 1700932348, 2078364891];
+    pub const lpacPrinting                              = "lpacPrinting"                                       [4044835139, 2658482041, 3127973164,  329287231, 3865880861, 1938685643,  461067658, 1087000422];
+    pub const lpacServicesManagement                    = "lpacServicesManagement"                             [ 528118966, 3876874398,  709513571, 1907873084, 3598227634, 3698730060,  278077788, 3990600205];
+    pub const lpacSessionManagement                     = "lpacSessionManagement"                              [1864111754,  776273317, 3666925027, 2523908081, 3792458206, 3582472437, 4114419977, 1582884857];
+    pub const lpacWebPlatform                           = "lpacWebPlatform"                                    [3623855041, 1826999956, 3747069818, 3525260223, 3747374510, 1746272624,  950601168,   56556331];
+    pub const microphone                                = "microphone"                                         [3996699186, 3595629362, 3480063212, 3905085333, 2276303035, 3068169911, 3004821721, 4252886170];
+    pub const microsoftEdgeRemoteDebugging              = "microsoftEdgeRemoteDebugging"                       [2687912068, 1527563483, 2246345126, 2445616054, 2679617633, 2814117500, 2092001380,  704615243];
+    pub const mixedRealityEnvironmentInternal           = "mixedRealityEnvironmentInternal"                    [3049152140,  946229307, 4003843583, 1650663789, 1490977625, 1046559530, 3243139619,  975812231];
+    pub const mmsTransportSystem                        = "mmsTransportSystem"                                 [1048653548, 3880638403, 2205339385,  990407011, 3754096113, 1755676234, 2344900531, 1375370936];
+    pub const modifiableApp                             = "modifiableApp"                                      [3647570423, 1170909575, 4194078310, 4044586368, 1349189231, 3780358251, 3939307736, 2707571402];
+    pub const multiplaneOverlay                         = "multiplaneOverlay"                                  [4138369114, 3747645054, 3774339213, 2277265673,  300906310, 2019405316,  606661171, 1835382214];
+    pub const muma                                      = "muma"                                               [ 642225045, 1497410490, 4133325371, 1747563908, 2253433576, 3934691789,  210245039, 1860717921];
+    pub const musicLibrary                              = "musicLibrary"                                       [3863518393, 3626451890, 3669443474, 1999530499, 2406597458, 1735372251, 1080155281,  741764760];
+    pub const networkConnectionManagerProvisioning      = "networkConnectionManagerProvisioning"               [1904668343, 1122143141, 2896894936, 1757704438, 2225457261, 1832870532, 4083204921, 4111087458];
+    pub const networkDataPlanProvisioning               = "networkDataPlanProvisioning"                        [4214965917, 3375290950, 3857009211, 4120063080, 3741332808, 2868847822, 1843154671, 4148511555];
+    pub const networkDataUsageManagement                = "networkDataUsageManagement"                         [1077806553, 3407786159, 3143147184, 1468041508,  245664426, 3461639803, 3586422868, 1013604766];
+    pub const networkDeviceSettings                     = "networkDeviceSettings"                              [4254232129, 3709064871, 1821651845, 2739656797, 2804206191, 3447631095, 3000753041, 3461212826];
+    pub const networkDiagnostics                        = "networkDiagnostics"                                 [ 518545664, 1612196034, 3480841272,  376121288,  393698823, 4139136808,   95323593, 1103535949];
+    pub const networkingVpnProvider                     = "networkingVpnProvider"                              [1068037383,  729401668, 2768096886,  125909118, 1680096985,  174794564, 3112554050, 3241210738];
+    pub const nfcSystem                                 = "nfcSystem"                                          [2169237947,  275284851, 3876357460, 1273727642, 1157490466, 1177376558,  883687086,  945396102];
+    pub const notificationsDeviceSettings               = "notificationsDeviceSettings"                        [  92225113, 3361836817, 3058429206, 1312023296, 1809529312, 1859457108, 3447665438, 3544701957];
+    pub const objects3D                                 = "objects3D"                                          [1714402723, 3681070311, 1045646184,  555837952,  257600184, 3998505355,   63610276, 3865718003];
+    pub const oemDeployment                             = "oemDeployment"                                      [1114507550, 2235118486, 1313240074, 4283153625,  597607960, 2635661315, 2827405174,  912873668];
+    pub const oemPublicDirectory                        = "oemPublicDirectory"                                 [ 278763595,  641296858, 3665893476, 2977301132, 1926709684, 2066268498, 4151792040, 2589241065];
+    pub const offlineMapsManagement                     = "offlineMapsManagement"                              [3456877387, 2052140335,  534762229, 3699386062, 2590405591, 4011441432, 2706472608,  754907458];
+    pub const oneProcessVoIP                            = "oneProcessVoIP"                                     [4048721701, 2850658424,  351864712,   89823626, 3166552844, 1648129654,  666764415, 3345515861];
+    pub const optical                                   = "optical"                                            [1575399732, 3056358718, 2825064311,  550644430, 3464259740, 2132227768,  979495139, 1077632175];
+    pub const pacJsWorker                               = "pacJsWorker"                                        [2854389722, 2213348522, 1345377226, 1426673889, 1057411476, 1183484899, 2204009092,  773751949];
+    pub const packageContents                           = "packageContents"                                    [3635283841, 2530182609,  996808640, 1887759898, 3848208603, 3313616867,  983405619, 2501854204];
+    pub const packageManagement                         = "packageManagement"                                  [ 734518492,  402359323, 2580938124, 1419864735, 4212787651, 2727913556,  228323224,  564805089];
+    pub const packagePolicySystem                       = "packagePolicySystem"                                [1074678882, 1845519692,  958031958,   89677218, 2730550528, 3336438952, 1306664337, 3311493206];
+    pub const packageQuery                              = "packageQuery"                                       [1962849891,  688487262, 3571417821, 3628679630,  802580238, 1922556387,  206211640, 3335523193];
+    pub const packageWriteRedirectionCompatibilityShim  = "packageWriteRedirectionCompatibilityShim"           [2962209256, 2979070741, 3173536111, 2627411448, 3810448907, 2604541049,  103961185,  926209548];
+    pub const packagedServices                          = "packagedServices"                                   [2119561992, 3910021631, 2703022402, 4024892384,  119119367, 1652639859, 1663152375, 2255428156];
+    pub const perceptionMonitoring                      = "perceptionMonitoring"                               [4094556557, 3002736401, 1971953794, 2757521288, 2231035381, 3459874925, 2997907580, 1328483296];
+    pub const perceptionSensorsExperimental             = "perceptionSensorsExperimental"                      [4261096351,  406411886, 1187434358, 1445476386, 2569006900, 2217228443, 3950610055,  882844645];
+    pub const perceptionSystem                          = "perceptionSystem"                                   [  34359262, 2669769421, 2130994847, 3068338639, 3284271446, 2009814230, 2411358368,  814686995];
+    pub const permissiveLearningMode                    = "permissiveLearningMode"                             [3247294477, 1055689029, 3368529789, 3941363664, 2797964971, 2286479452,  540989846, 2924655214];
+    pub const personalizationDeviceSettings             = "personalizationDeviceSettings"                      [1006130002, 2476145911,  936156562, 2453790590,  874145428, 3260978392, 1523843091,  690536145];
+    pub const phoneCall                                 = "phoneCall"                                          [ 383293015, 3350740429, 1839969850, 1819881064, 1569454686, 4198502490,   78857879, 1413643331];
+    pub const phoneCallHistory                          = "phoneCallHistory"                                   [ 951693731,  901288528, 2895271546,  317143909, 1504712250,   25973806, 3907851571, 1618863794];
+    pub const phoneCallHistoryPublic                    = "phoneCallHistoryPublic"                             [1631604711, 3604716289, 3767720303,  698625756, 2814662190,  970047950, 2326260488, 1280393717];
+    pub const phoneCallHistorySystem                    = "phoneCallHistorySystem"                             [2442212369, 1516598453, 2330995131, 3469896071,  605735848, 2536580394, 3691267241, 2105387825];
+    pub const phoneCallSystem                           = "phoneCallSystem"                                    [2035927579,  283314533, 3422103930, 3587774809,  765962649, 3034203285, 3544878962,  607181067];
+    pub const phoneLineTransportManagement              = "phoneLineTransportManagement"                       [4064895015,  342919570, 1527821018, 1398865317, 2844761021, 1295569757, 2893040671, 2720116481];
+    pub const picturesLibrary                           = "picturesLibrary"                                    [1406971207,  126784377, 3203782471, 3962216065, 3911221394, 2948374907, 2464319569, 1634326943];
+    pub const pointOfService                            = "pointOfService"                                     [1849711939, 2055372412, 1430709549,  403095800, 2349372689, 2887650183,   34019435, 3605578527];
+    pub const powerDeviceSettings                       = "powerDeviceSettings"                                [1341596463, 1842471492,  803414513, 1600199594,  325357669, 2429983699, 1407737418, 1885917941];
+    pub const preemptiveCamera                          = "preemptiveCamera"                                   [ 445163804, 4260834626,   64022583, 2890516451, 3185296510, 2128918719, 1210314806,  274821335];
+    pub const previewHfx                                = "previewHfx"                                         [  26449188,  319411058, 3949409539, 1705362049, 1925139826, 1674502854,   27253669,  814862659];
+    pub const previewInkWorkspace                       = "previewInkWorkspace"                                [ 461248178,  238806672,  481084236, 3891410989, 2771223391, 2696077494, 4217549958, 1571088004];
+    pub const previewPenWorkspace                       = "previewPenWorkspace"                                [1316175169, 1773014438, 1613326986,   24619653, 3648585828, 3852118800,   56534641, 2026697600];
+    pub const previewStore                              = "previewStore"                                       [3995113440, 3884054055, 1031826285,  344537609, 2951767964, 1612438789, 3955710486,  685105120];
+    pub const previewUiComposition                      = "previewUiComposition"                               [4039605918, 3873411318,   27610139, 1128268345,   19234073, 2909949027, 3062533374, 2176626134];
+    pub const privateNetworkClientServer                = "privateNetworkClientServer"                         [3451683122, 3669176405, 2623206703,  410298525,  609685457,  245256422, 1312352757, 4006057415];
+    pub const projectionDeviceSettings                  = "projectionDeviceSettings"                           [ 873788335, 2211572166, 2255199562, 3772046584, 2422496701,  771418519, 1902201794, 2292330015];
+    pub const protectedApp                              = "protectedApp"                                       [3201220807, 3619763700, 1592940189, 1757660495,  672602728, 3988728386, 3927502142, 3543502805];
+    pub const proximity                                 = "proximity"                                          [2277154106, 1198253741, 1251649293, 2785554404, 1571676292, 3400936580, 1687833907, 3924095924];
+    pub const radios                                    = "radios"                                             [3819248598, 2325341108,  263814137, 3273722481, 4086152792, 1551417442,  514296332, 2020513533];
+    pub const recordedCallsFolder                       = "recordedCallsFolder"                                [1197439550, 2076375017, 2388317006, 4244034133, 3805565224, 2676722506, 3094586543, 1803227934];
+    pub const regionSettings                            = "regionSettings"                                     [3976976307, 2806419199, 3926481396,  508527851, 3236028540,  826713606,  280954376, 4158454271];
+    pub const registryRead                              = "registryRead"                                       [1065365936, 1281604716, 3511738428, 1654721687,  432734479, 3232135806, 4053264122, 3456934681];
+    pub const relatedPackages                           = "relatedPackages"                                    [1447375928, 2255811402, 3714708160, 2966800328, 1666362609, 2694121934, 4074565427, 2442860904];
+    pub const remoteFileAccess                          = "remoteFileAccess"                                   [1439478919,  990579493, 3627320768, 2665985634, 2354262676, 2096604540,  223614242, 3656862712];
+    pub const remotePassportAuthentication              = "remotePassportAuthentication"                       [3897381677, 2518389896,  958950170, 4086390243, 3353327115, 2219161105, 2047156235,  314897931];
+    pub const remoteSystem                              = "remoteSystem"                                       [3382307235,  172505126, 3436709648, 3853344092, 1878498961, 3808853949, 3782709338, 3842044973];
+    pub const removableStorage                          = "removableStorage"                                   [1727008254, 3289416835, 1480208692,  481164861,  734055790,  346467174,  210577168, 3440148169];
+    pub const resetPhone                                = "resetPhone"                                         [1223709465,  298379339, 2017635312, 1772383802, 1712600024, 1144051261, 1141136244, 3843461989];
+    pub const runFullTrust                              = "runFullTrust"                                       [1365790099, 2797813016, 1714917928,  519942599, 2377126242, 1094757716, 3949770552, 3596009590];
+    pub const screenDuplication                         = "screenDuplication"                                  [2752188826, 3550772256,  221158601, 1503784687, 2973994425,   58216879,  891029702, 2423973439];
+    pub const searchSettings                            = "searchSettings"                                     [1086922356,  207614091, 3724853071,  841836187, 4018695103,   34218837, 3164163255,  155871754];
+    pub const secondaryAuthenticationFactor             = "secondaryAuthenticationFactor"                      [ 759497869, 3426324426, 2080302537,  280970568, 1023192118,  597262764, 3695343976, 1004345243];
+    pub const secureAssessment                          = "secureAssessment"                                   [1231405757,  631568165,  502048027, 2646382484,  613260345, 2075369228, 3000949285, 4219498872];
+    pub const sensors_custom                            = "sensors.custom"                                     [ 464504522, 3613564238, 1750590852, 1017417883, 1861133207, 1479667529, 3846921728, 1947332426];
+    pub const serialCommunication                       = "serialCommunication"                                [2259232173, 3065707605, 3546759525, 3107910369, 1496933107, 2416423869,  535863999, 1547775798];
+    pub const sessionImpersonation                      = "sessionImpersonation"                               [3595123447, 4143809960, 2192099248, 2222090582, 3956830546, 1413326885, 1118183480, 1300124416];
+    pub const settingSyncConfiguration                  = "settingSyncConfiguration"                           [4013343662, 1780721540, 2368661007, 3594614809, 3500637591, 3061816900, 1306469177,  829351717];
+    pub const sharedMachineKeysCapability               = "sharedMachineKeysCapability"                        [3407458705, 1648901477, 3477437477, 2446469098,  609902970, 3968512836, 1044670771,  215142459];
+    pub const sharedUserCertificates                    = "sharedUserCertificates"                             [2054765966, 1632741161, 2974110423, 2018311271, 2881354204,  304237130,  464421340, 1092510287];
+    pub const shellDisplayManagement                    = "shellDisplayManagement"                             [2305152057, 3071518883, 3537672427, 2214617062, 2553570816, 4170319841,  830201352, 3965352074];
+    pub const shellExperience                           = "shellExperience"                                    [3167453650,  624722384,  889205278,  321484983,  714554697, 3592933102,  807660695, 1632717421];
+    pub const shellExperienceComposer                   = "shellExperienceComposer"                            [2152139330, 3124897132,  671935159, 3762809077, 3273429135, 2233686478, 1435376800, 2420532691];
+    pub const slapiQueryLicenseValue                    = "slapiQueryLicenseValue"                             [3578703928, 3742718786,    7859573, 1930844942, 2949799617, 2910175080, 1780299064, 4145191454];
+    pub const smbios                                    = "smbios"                                             [1882001508, 3166212979, 1759549478, 1197938037,   69236898,   20095667, 1131865092,   67241044];
+    pub const sms                                       = "sms"                                                [3602025330,  472807899, 1205063840, 3442271783, 2274535149,  813395580,  170517792, 1323823688];
+    pub const smsSend                                   = "smsSend"                                            [ 128185722,  850430189, 1529384825,  139260854,  329499951, 1660931883, 3499805589, 3019957964];
+    pub const smsSystem                                 = "smsSystem"                                          [3480444203, 4235629365, 4094828944, 2013630719, 3891574212,  838386981, 1711373286, 2543796314];
+    pub const smsTransportSystem                        = "smsTransportSystem"                                 [3362896107,  847302450,  369767657, 4208464410,  123719904, 3972218006, 2749300081,  169903797];
+    pub const spatialPerception                         = "spatialPerception"                                  [2254392608, 3275252242, 2040178665, 1987576641, 1815903846, 2941108822, 3851549638, 2340068026];
+    pub const startScreenManagement                     = "startScreenManagement"                              [ 782401966, 1532617391, 3031078076,  101244278, 3991565062,  447768907, 4209600932, 4293427563];
+    pub const storeAppInstall                           = "storeAppInstall"                                    [4267310653, 3012624349,   32869343,  335676702,  674013981, 1531007892, 2777328540,  762217067];
+    pub const storeAppInstallation                      = "storeAppInstallation"                               [2558976728, 3115931106, 1512009022, 3208506203, 2008579624,  341828572, 3950653509, 2339491937];
+    pub const storeConfiguration                        = "storeConfiguration"                                 [2707581722, 3970398075, 3301609242, 3412871183, 2565310287, 2959982868, 2531230773, 2372594412];
+    pub const storeLicenseManagement                    = "storeLicenseManagement"                             [2819154332, 3691255550, 2499738133, 2646149002, 4290075130, 3069449926,  721213713, 3168903538];
+    pub const storeOptionalPackageInstallManagement     = "storeOptionalPackageInstallManagement"              [3233358573, 3222412776, 4149189431, 1049145218, 2500383674, 4050096179, 2277312776,  364530348];
+    pub const systemDialog                              = "systemDialog"                                       [3430811103, 1394355416, 2761699064, 1385698600,   16500618, 1940864512, 2827186997, 3488085323];
+    pub const systemDialogEmergency                     = "systemDialogEmergency"                              [3424959352, 1973429139, 2846531642, 2399309467, 3258902908, 3185142175, 1698087495, 3907814294];
+    pub const systemManagement                          = "systemManagement"                                   [1023893147,  235863880,  425656572, 4266519675, 2590647553, 3475379062,  430000033, 3360374247];
+    pub const systemRegistrar                           = "systemRegistrar"                                    [1118810203, 3584274876, 1313799976,  736524742,  894047348, 2754362701,  194785068,  153062919];
+    pub const targetedContent                           = "targetedContent"                                    [3036464858, 3155602757, 2052184566, 2810840899, 4148930525, 1208855857, 3369979990, 1199230028];
+    pub const targetedContentSubscription               = "targetedContentSubscription"                        [2720074253, 2593895878, 1367899712, 1504396584, 3115882398, 2003648316, 1959593896, 1615940296];
+    pub const teamEditionDeviceCredential               = "teamEditionDeviceCredential"                        [ 837347585, 1999177933, 4138097223,  648202317, 3984072298, 3984207991, 3571983512, 2471634736];
+    pub const teamEditionExperience                     = "teamEditionExperience"                              [2380060612, 1737381507, 4167045332, 4184987838, 4083872623, 3212612518,  450314405, 3897841498];
+    pub const teamEditionView                           = "teamEditionView"                                    [3891729582, 2544466909, 2712269814, 1176081771, 3640883188, 3155755306, 1116579485, 2332894950];
+    pub const telemetryData                             = "telemetryData"                                      [3373724040, 1170344134,  777822384, 2786649692, 2407439134,  680197326, 2745814539, 2575387933];
+    pub const terminalPowerManagement                   = "terminalPowerManagement"                            [4109863507,  315816024,  705126643, 4107540304, 1229951577, 3907543981, 3063171489, 2612387512];
+    pub const thumbnailCache                            = "thumbnailCache"                                     [4261706848,    3994739,  668717478,  463624320, 2717824891,  581166392, 3604856260,  471283224];
+    pub const timezone                                  = "timezone"                                           [3041133721, 1480096532,  832829000, 2759367675, 4090792977, 1568020035, 4217102551, 1177475503];
+    pub const uiAccess                                  = "uiAccess"                                           [ 409472177, 2062020225,  525776845, 2804668398, 2154477689, 2219989410, 1065520914, 4001898222];
+    pub const uiAutomationSystem                        = "uiAutomationSystem"                                 [1762459923, 1799621485,  809105362, 2834798648, 1117523120, 1672840655,  346792126, 3119424345];
+    pub const unvirtualizedResources                    = "unvirtualizedResources"                             [3844498485, 4280973353, 1424685246, 2583229392, 3035484506, 1199963603, 2231282027, 2249995489];
+    pub const unzipFile                                 = "unzipFile"                                          [3119071125, 1669795191, 3411544654, 3884382078, 2434967118,  699366913, 3568888676, 1151483144];
+    pub const updateAndSecurityDeviceSettings           = "updateAndSecurityDeviceSettings"                    [2642055764, 1853522529, 3601982987,  418283269, 2220187745, 2827089145, 2403472374, 2016924062];
+    pub const usb                                       = "usb"                                                [2220380775, 2622013822, 1599222386, 2219895693, 4014100651, 1227276184,  635187290, 3404514634];
+    pub const userAccountInformation                    = "userAccountInformation"                             [3014353654, 4060050185, 4188274494, 1467411622, 2017116772,  860365275, 2455311434, 3523940624];
+    pub const userDataAccountSetup                      = "userDataAccountSetup"                               [  70329670, 3585251103, 1149629936, 3204666536, 4257240719, 2613976102,  450591375, 1666047922];
+    pub const userDataAccountsProvider                  = "userDataAccountsProvider"                           [ 624372219,  572103895, 3839054141, 4184514356, 2205606268, 3026111568, 3738370332, 3748229556];
+    pub const userDataSystem                            = "userDataSystem"                                     [3324773698, 3647103388, 1207114580, 2173246572, 4287945184, 2279574858,  157813651,  603457015];
+    pub const userDataTasks                             = "userDataTasks"                                      [1767905719, 3826487505,  162337170,  292226844, 3377689209,  415238060,  731096137, 2045567052];
+    pub const userDataTasksSystem                       = "userDataTasksSystem"                                [2697775780, 1542377669, 1066453660, 3527412830, 3006687094, 2339763200, 1478134599, 2680133682];
+    pub const userManagementSystem                      = "userManagementSystem"                               [3935871839,  531328186, 2364288650, 1952049222, 2005882575, 3855556509, 3315028587,   95077501];
+    pub const userNotificationListener                  = "userNotificationListener"                           [1195710214,  366596411, 2746218756, 3015581611, 3786706469, 3006247016, 1014575659, 1338484819];
+    pub const userPrincipalName                         = "userPrincipalName"                                  [2911463036,  237878888,  509312955, 1981876715, 2004097936, 1552120448, 2430620302, 2295502469];
+    pub const userSigninSupport                         = "userSigninSupport"                                  [1730716382, 2949791265, 2036182297,  688374192,  553408039, 4133924312, 4201181712,  267922143];
+    pub const userSystemId                              = "userSystemId"                                       [3755676145, 4259313647, 2808356580, 3373894405, 2165299177,  498323172,   48808592, 1417246423];
+    pub const userWebAccounts                           = "userWebAccounts"                                    [3251049817, 2009456500, 1551197491, 2134558218, 3545351942,  817362876, 3494650202,  599247412];
+    pub const videosLibrary                             = "videosLibrary"                                      [3951209665, 2909853094, 1704132547, 2470142594, 2985570362, 3729008898, 3515249751,  304697738];
+    pub const visualElementsSystem                      = "visualElementsSystem"                               [3299255270, 1847605585, 2201808924,  710406709, 3613095291,  873286183, 3101090833, 2655911836];
+    pub const visualVoiceMail                           = "visualVoiceMail"                                    [ 725803317, 1887434487, 3794882127, 3801973704,  242823434, 3911787684, 1468823602, 3559133575];
+    pub const vmWorkerProcess                           = "vmWorkerProcess"                                    [2268835264, 3721307629,  241982045,  173645152, 1490879176,  104643441, 2915960892, 1612460704];
+    pub const voipCall                                  = "voipCall"                                           [ 528040493, 3731447870,   67007039, 3324466937,  472126288, 3192664210, 2621923198, 3039294295];
+    pub const walletSystem                              = "walletSystem"                                       [3220540237, 2689165624, 4063022621,  485423413, 3446573505,  530027026, 3263391230, 3512805223];
+    pub const webPlatformMediaExtension                 = "webPlatformMediaExtension"                          [ 806118685, 1407903895, 2682038770, 2296481007, 2544426249, 1287025116, 2346593712,  219042290];
+    pub const webcam                                    = "webcam"                                             [4131216513, 4266103714, 3944869821, 2853506808, 3373049249, 4035912394, 2659877950, 3593780078];
+    pub const wiFiControl                               = "wiFiControl"                                        [1435741670,  739137367, 1743980217, 3651543328, 1944853929, 2879019864, 2752253861, 3176136090];
+    pub const wiFiDirect                                = "wiFiDirect"                                         [2630151271,  538039947, 3438384650, 3036271083, 2444732472, 3552936254, 3601025621, 3070579071];
+    pub const wifiData                                  = "wifiData"                                           [ 982116356, 1157887588, 2052581112, 4048931602,  275598005, 2450320559, 1358259137, 3354152412];
+    pub const windowManagement                          = "windowManagement"                                   [3784963939,  525976052, 1984711482,  555102806, 2547956227,  867522058, 1103054514, 4144925092];
+    pub const windowManagementSystem                    = "windowManagementSystem"                             [1911837662, 2060215111, 1926601727,  951193427,  565808881, 1747239484, 3173052995, 3854891390];
+    pub const windowsHelloCredentialAccess              = "windowsHelloCredentialAccess"                       [1902118268,  936929782, 3474333872,  803346623, 1872623265, 3899080591, 2872335817, 3963487957];
+    pub const windowsPerformanceCounters                = "windowsPerformanceCounters"                         [1221526253, 3924910130, 1751405008,   69508222, 1499930412,   38408379, 3528704847,  309525946];
+    pub const xboxAccessoryManagement                   = "xboxAccessoryManagement"                            [ 316617620,  767886417, 2031403316, 4137648062,  386588034, 2282218452,  745559578, 2387228587];
+    pub const xboxBroadcaster                           = "xboxBroadcaster"                                    [1456666081, 3640717758,  443640589,  929037496, 1153660644, 3477228869,  343279110, 3437769230];
+    pub const xboxGameSpeechWindow                      = "xboxGameSpeechWindow"                               [1913821931, 1108981997,  895954514, 4209058812, 2801866697, 2689726501, 1421786583, 1766464325];
+    pub const xboxLiveAuthenticationProvider            = "xboxLiveAuthenticationProvider"                     [1960811123, 3677868401,  325669015, 1068014216, 3567935757, 2531561414,   87310777, 1481187038];
+    pub const xboxSystemApplicationClipQuery            = "xboxSystemApplicationClipQuery"                     [2396548673, 3660548412,  478690371, 1102408774, 3574420896, 3187205843, 2937764204,  870294981];
+    pub const xboxTrackingStream                        = "xboxTrackingStream"                                 [ 609147554, 2685133493, 3856309926,  531891704, 4088700923, 1956945467,  291440665,  298026926];
 }
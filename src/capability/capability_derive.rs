@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use crate::util::sha256::Sha256;
+
+use winapi::um::winnt::*;
+
+
+
+const SECURITY_CAPABILITY_APP_RID : u32 = 0x400; // winapi 0.3.9 has 0x40
+
+/// Capabilities that predate hashed capability SIDs, and have a well known `S-1-15-3-N` SID instead.
+const LEGACY : &[(&str, u32)] = &[
+    ("internetClient",              SECURITY_CAPABILITY_INTERNET_CLIENT                 ),
+    ("internetClientServer",        SECURITY_CAPABILITY_INTERNET_CLIENT_SERVER          ),
+    ("privateNetworkClientServer",  SECURITY_CAPABILITY_PRIVATE_NETWORK_CLIENT_SERVER   ),
+    ("picturesLibrary",             SECURITY_CAPABILITY_PICTURES_LIBRARY                ),
+    ("videosLibrary",               SECURITY_CAPABILITY_VIDEOS_LIBRARY                  ),
+    ("musicLibrary",                SECURITY_CAPABILITY_MUSIC_LIBRARY                   ),
+    ("documentsLibrary",            SECURITY_CAPABILITY_DOCUMENTS_LIBRARY               ),
+    ("enterpriseAuthentication",    SECURITY_CAPABILITY_ENTERPRISE_AUTHENTICATION       ),
+    ("sharedUserCertificates",      SECURITY_CAPABILITY_SHARED_USER_CERTIFICATES        ),
+    ("removableStorage",            SECURITY_CAPABILITY_REMOVABLE_STORAGE               ),
+    ("appointments",                SECURITY_CAPABILITY_APPOINTMENTS                    ),
+    ("contacts",                    SECURITY_CAPABILITY_CONTACTS                        ),
+];
+
+
+
+#[doc(alias = "DeriveCapabilitySidsFromName")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-derivecapabilitysidsfromname)\]
+/// ≈ DeriveCapabilitySidsFromName, without calling into `kernelbase.dll`
+///
+/// Returns `(group_sid, capability_sid)`, where `hash` is the 8 little endian [`u32`]s of the SHA-256 hash of the uppercased, UTF-16LE encoded name:
+/// *   `group_sid` is `S-1-5-32-{hash}`
+/// *   `capability_sid` is `S-1-15-3-1024-{hash}`, or `S-1-15-3-N` for legacy capabilities like `internetClient` (`S-1-15-3-1`)
+///
+/// Only ASCII is uppercased - every known capability name is ASCII.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let (group_sid, sid) = capability::derive_sids_from_name("internetClient");
+/// assert_eq!(group_sid,   *sid!(S-1-5-32-2779705173-1925339129-2667939958-2414465498-3395756507-4015878651-158944808-788332705));
+/// assert_eq!(sid,         *sid!(S-1-15-3-1));
+///
+/// let (group_sid, sid) = capability::derive_sids_from_name("activateAsUser");
+/// assert_eq!(group_sid,   *sid!(S-1-5-32-1619559953-3382903645-900470658-1831728285-1265525240-911141481-3610949621-2233473754));
+/// assert_eq!(sid,         *sid!(S-1-15-3-1024-1619559953-3382903645-900470658-1831728285-1265525240-911141481-3610949621-2233473754));
+/// ```
+///
+pub fn derive_sids_from_name(capability_name: &str) -> (sid::Owned, sid::Owned) {
+    let mut sha = Sha256::new();
+    for unit in capability_name.encode_utf16() {
+        let unit = if unit < 0x80 { u16::from((unit as u8).to_ascii_uppercase()) } else { unit };
+        sha.update(&unit.to_le_bytes());
+    }
+    let digest = sha.finish();
+
+    let mut hash = [0; 8];
+    for (h, word) in hash.iter_mut().zip(digest.chunks_exact(4)) { *h = u32::from_le_bytes([word[0], word[1], word[2], word[3]]); }
+    (group_sid(&hash), capability_sid(capability_name, &hash))
+}
+
+pub(super) fn group_sid(hash: &[u32; 8]) -> sid::Owned {
+    let mut sub_authorities = [SECURITY_BUILTIN_DOMAIN_RID; 9];
+    sub_authorities[1..].copy_from_slice(hash);
+    sid::Owned::new(SID_REVISION, 5, &sub_authorities).expect("9 sub-authorities") // SECURITY_NT_AUTHORITY
+}
+
+pub(super) fn legacy_rid(name: &str) -> Option<u32> {
+    LEGACY.iter().find(|(legacy, _)| legacy.eq_ignore_ascii_case(name)).map(|&(_, rid)| rid)
+}
+
+pub(super) fn capability_sid(name: &str, hash: &[u32; 8]) -> sid::Owned {
+    if let Some(rid) = legacy_rid(name) {
+        return sid::Owned::new(SID_REVISION, 15, &[SECURITY_CAPABILITY_BASE_RID, rid]).expect("2 sub-authorities") // SECURITY_APP_PACKAGE_AUTHORITY
+    }
+    let mut sub_authorities = [SECURITY_CAPABILITY_BASE_RID, SECURITY_CAPABILITY_APP_RID, 0, 0, 0, 0, 0, 0, 0, 0];
+    sub_authorities[2..].copy_from_slice(hash);
+    sid::Owned::new(SID_REVISION, 15, &sub_authorities).expect("10 sub-authorities") // SECURITY_APP_PACKAGE_AUTHORITY
+}
+
+#[cfg(std)] #[test] fn precomputed() {
+    for constant in capability::ALL {
+        let (group_sid, sid) = derive_sids_from_name(constant.name());
+        assert_eq!(group_sid,   constant.group_sid(),   "{constant:?}");
+        assert_eq!(sid,         constant.sid(),         "{constant:?}");
+    }
+    assert_eq!(capability::internetClient.sid(),    *sid!(S-1-15-3-1));
+    assert_eq!(capability::contacts.sid(),          *sid!(S-1-15-3-12));
+    assert_eq!(capability::ALL.len(), 917);
+}
+
+#[cfg(std)] #[test] fn from_sid() {
+    for constant in capability::ALL {
+        assert_eq!(capability::Constant::from_sid(&constant.sid().as_sid_ptr()).map(|c| c.name()),        Some(constant.name()));
+        assert_eq!(capability::Constant::from_sid(&constant.group_sid().as_sid_ptr()).map(|c| c.name()),  Some(constant.name()));
+    }
+    for sid in [sid!(S-1-15-3-13), sid!(S-1-15-3-1024-1-2-3-4-5-6-7-8), sid!(S-1-15-3-1024-1-2-3), sid!(S-1-15-2-1), sid!(S-1-5-32-544)] {
+        assert!(capability::Constant::from_sid(&sid).is_none(), "{sid:?}");
+    }
+    if cfg!(not(windows)) { // LSA might name these first
+        assert_eq!(std::format!("{:?}", sid!(S-1-15-3-1)), "S-1-15-3-1 capability \"internetClient\"");
+    }
+}
+
+#[cfg(all(std, windows))] #[test] fn matches_kernelbase() {
+    for constant in [capability::internetClient, capability::activateAsUser, capability::ID_CAP_ACCESSIBILITY_CLIENT, capability::bluetooth_rfcomm] {
+        let (groups, sids) = derive_capability_sids_from_name(constant.id()).unwrap_or_else(|err| panic!("{constant:?}: {err:?}"));
+        assert_eq!(*groups[0],  constant.group_sid(),   "{constant:?}");
+        assert_eq!(*sids[0],    constant.sid(),         "{constant:?}");
+    }
+}
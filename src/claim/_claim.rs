@@ -0,0 +1,24 @@
+//! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_relative_v1)\]
+//! Claim security attributes, as stored in the resource attribute ACEs (`SYSTEM_RESOURCE_ATTRIBUTE_ACE`) of a SACL
+//!
+//! ### Examples
+//! ```
+//! # use firehazard::*;
+//! let secrecy = claim::Attribute::new("Secrecy", claim::Values::Uint64(vec![3]));
+//!
+//! let mut sacl = acl::Builder::new(acl::REVISION);
+//! sacl.add_resource_attribute(acl::REVISION, ace::Flags::default(), &secrecy).unwrap();
+//!
+//! let ace = sacl.as_acl().aces().next().unwrap();
+//! assert_eq!(Some(Ok(secrecy)), ace.resource_attribute());
+//! ```
+//!
+//! ### References
+//! *   [CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_relative_v1)
+//! *   [\[MS-DTYP\] 2.4.10.1 CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-dtyp/a6fcc8ae-6826-4d1e-a4e8-2ab54a1d2c7d)
+//! *   [Central Access Policies / Dynamic Access Control](https://learn.microsoft.com/en-us/windows-server/identity/solution-guides/dynamic-access-control-overview)
+
+#[cfg(alloc)] mod claim_attribute;          #[cfg(alloc)] pub use claim_attribute::*;
+#[cfg(alloc)] mod claim_attribute_error;    #[cfg(alloc)] pub use claim_attribute_error::*;
+#[cfg(all(alloc, windows))] mod claim_attribute_information;
+mod claim_flags;                            pub use claim_flags::*;
@@ -0,0 +1,252 @@
+use crate::prelude::*;
+
+use winapi::um::winnt::*;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+
+
+#[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1")]
+#[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_V1")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_relative_v1)\]
+/// ≈ CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1, owned
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let project = claim::Attribute {
+///     flags: claim::Flags::VALUE_CASE_SENSITIVE,
+///     ..claim::Attribute::new("Project", claim::Values::String(vec!["firehazard".into(), "thindx".into()]))
+/// };
+/// let bytes = project.to_relative_bytes().unwrap();
+/// assert_eq!(Ok(project), claim::Attribute::from_relative_bytes(&bytes));
+///
+/// assert_eq!(Err(claim::AttributeError::NoValues), claim::Attribute::new("Empty", claim::Values::Int64(vec![])).to_relative_bytes());
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Attribute {
+    /// CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1::Name
+    pub name:   String,
+
+    /// CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1::Flags
+    pub flags:  claim::Flags,
+
+    /// CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1::{ValueType, ValueCount, Values}
+    pub values: Values,
+}
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_relative_v1#members)\]
+/// The typed values of a [`claim::Attribute`]
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Values {
+    /// CLAIM_SECURITY_ATTRIBUTE_TYPE_INT64
+    Int64(Vec<i64>),
+
+    /// CLAIM_SECURITY_ATTRIBUTE_TYPE_UINT64
+    Uint64(Vec<u64>),
+
+    /// CLAIM_SECURITY_ATTRIBUTE_TYPE_STRING
+    String(Vec<String>),
+
+    /// CLAIM_SECURITY_ATTRIBUTE_TYPE_SID
+    Sid(Vec<sid::Owned>),
+
+    /// CLAIM_SECURITY_ATTRIBUTE_TYPE_BOOLEAN
+    Boolean(Vec<bool>),
+
+    /// CLAIM_SECURITY_ATTRIBUTE_TYPE_OCTET_STRING
+    OctetString(Vec<Vec<u8>>),
+}
+
+impl Values {
+    /// `CLAIM_SECURITY_ATTRIBUTE_TYPE_*`
+    pub fn value_type(&self) -> u16 {
+        match self {
+            Values::Int64(_)        => CLAIM_SECURITY_ATTRIBUTE_TYPE_INT64,
+            Values::Uint64(_)       => CLAIM_SECURITY_ATTRIBUTE_TYPE_UINT64,
+            Values::String(_)       => CLAIM_SECURITY_ATTRIBUTE_TYPE_STRING,
+            Values::Sid(_)          => CLAIM_SECURITY_ATTRIBUTE_TYPE_SID,
+            Values::Boolean(_)      => CLAIM_SECURITY_ATTRIBUTE_TYPE_BOOLEAN,
+            Values::OctetString(_)  => CLAIM_SECURITY_ATTRIBUTE_TYPE_OCTET_STRING,
+        }
+    }
+
+    /// The number of values (`ValueCount`.)
+    pub fn len(&self) -> usize {
+        match self {
+            Values::Int64(v)        => v.len(),
+            Values::Uint64(v)       => v.len(),
+            Values::String(v)       => v.len(),
+            Values::Sid(v)          => v.len(),
+            Values::Boolean(v)      => v.len(),
+            Values::OctetString(v)  => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+impl Attribute {
+    /// An attribute with no [`claim::Flags`].
+    pub fn new(name: impl Into<String>, values: Values) -> Self { Self { name: name.into(), flags: claim::Flags::default(), values } }
+
+    /// Serialize as a `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1`, as stored after the SID of a `SYSTEM_RESOURCE_ATTRIBUTE_ACE`.
+    ///
+    /// The header and value offsets are followed by the name, then each value, each padded to a 4 byte boundary.
+    pub fn to_relative_bytes(&self) -> Result<Vec<u8>, claim::AttributeError> {
+        use claim::AttributeError as E;
+        if self.name.is_empty() || self.name.contains('\0') { return Err(E::InvalidName) }
+        if self.values.is_empty() { return Err(E::NoValues) }
+        if let Values::String(v) = &self.values { if v.iter().any(|s| s.contains('\0')) { return Err(E::InteriorNul) } }
+
+        let count = self.values.len();
+        let mut bytes = Vec::with_capacity(16 + 4 * count + 2 * self.name.len() + 2);
+        bytes.extend_from_slice(&0u32.to_le_bytes()); // Name, written below
+        bytes.extend_from_slice(&self.values.value_type().to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes()); // Reserved
+        bytes.extend_from_slice(&self.flags.as_u32().to_le_bytes());
+        bytes.extend_from_slice(&(count as u32).to_le_bytes());
+        bytes.resize(16 + 4 * count, 0); // Values[], written below
+
+        let mut append = |field: usize, data: &mut dyn FnMut(&mut Vec<u8>)| {
+            let offset = bytes.len() as u32;
+            bytes[field .. field + 4].copy_from_slice(&offset.to_le_bytes());
+            data(&mut bytes);
+            while !bytes.len().is_multiple_of(4) { bytes.push(0); }
+        };
+        fn utf16z(s: &str) -> impl FnMut(&mut Vec<u8>) + '_ { move |b| { s.encode_utf16().chain(Some(0)).for_each(|u| b.extend_from_slice(&u.to_le_bytes())) } }
+        fn octets(o: &[u8]) -> impl FnMut(&mut Vec<u8>) + '_ { move |b| { b.extend_from_slice(&(o.len() as u32).to_le_bytes()); b.extend_from_slice(o) } }
+        fn qword(q: u64)    -> impl FnMut(&mut Vec<u8>)      { move |b| b.extend_from_slice(&q.to_le_bytes()) }
+
+        append(0, &mut utf16z(&self.name));
+        let field = |i: usize| 16 + 4 * i;
+        match &self.values {
+            Values::Int64(v)        => v.iter().enumerate().for_each(|(i, v)| append(field(i), &mut qword(*v as u64))),
+            Values::Uint64(v)       => v.iter().enumerate().for_each(|(i, v)| append(field(i), &mut qword(*v))),
+            Values::String(v)       => v.iter().enumerate().for_each(|(i, v)| append(field(i), &mut utf16z(v))),
+            Values::Sid(v)          => v.iter().enumerate().for_each(|(i, v)| append(field(i), &mut octets(v.as_bytes()))),
+            Values::Boolean(v)      => v.iter().enumerate().for_each(|(i, v)| append(field(i), &mut qword(u64::from(*v)))),
+            Values::OctetString(v)  => v.iter().enumerate().for_each(|(i, v)| append(field(i), &mut octets(v))),
+        }
+        Ok(bytes)
+    }
+
+    /// Parse a `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1`, such as the [`application_data`](ace::Ref::application_data) of a `SYSTEM_RESOURCE_ATTRIBUTE_ACE`.
+    ///
+    /// Every offset is bounds checked before use, so `bytes` may come from an untrusted source.
+    /// Trailing bytes (such as DWORD padding) are ignored.
+    pub fn from_relative_bytes(bytes: &[u8]) -> Result<Self, claim::AttributeError> {
+        use claim::AttributeError as E;
+
+        let header = bytes.get(..16).ok_or(E::Truncated)?;
+        let name        = u32_at(header, 0).ok_or(E::Truncated)?;
+        let value_type  = u16::from_le_bytes([header[4], header[5]]);
+        let flags       = u32_at(header, 8).ok_or(E::Truncated)?;
+        let count       = usize::from32(u32_at(header, 12).ok_or(E::Truncated)?);
+        if count == 0 { return Err(E::NoValues) }
+        let offsets = bytes.get(16 ..).and_then(|b| b.get(.. count.checked_mul(4)?)).ok_or(E::Truncated)?;
+        let offsets = offsets.chunks_exact(4).map(|o| usize::from32(u32::from_le_bytes([o[0], o[1], o[2], o[3]])));
+
+        let at = |offset: usize, len: usize| bytes.get(offset..).and_then(|b| b.get(..len)).ok_or(E::InvalidOffset);
+        let qword = |offset: usize| at(offset, 8).map(|q| u64::from_le_bytes([q[0], q[1], q[2], q[3], q[4], q[5], q[6], q[7]]));
+        let octets = |offset: usize| at(offset.checked_add(4).ok_or(E::InvalidOffset)?, usize::from32(u32_at(at(offset, 4)?, 0).ok_or(E::InvalidOffset)?));
+        let string = |offset: usize| -> Result<String, E> {
+            let units = bytes.get(offset..).ok_or(E::InvalidOffset)?.chunks_exact(2).map(|u| u16::from_le_bytes([u[0], u[1]]));
+            let mut s = Vec::new();
+            for unit in units {
+                if unit == 0 { return String::from_utf16(&s).map_err(|_| E::InvalidUtf16) }
+                s.push(unit);
+            }
+            Err(E::UnterminatedString)
+        };
+        let sid = |offset: usize| sid::Owned::from_bytes(octets(offset)?).ok_or(E::InvalidSid);
+
+        let name = string(usize::from32(name))?;
+        if name.is_empty() { return Err(E::InvalidName) }
+        let values = match value_type {
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_INT64         => Values::Int64        (offsets.map(|o| qword(o).map(|q| q as i64)).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_UINT64        => Values::Uint64       (offsets.map(qword).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_STRING        => Values::String       (offsets.map(string).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_SID           => Values::Sid          (offsets.map(sid).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_BOOLEAN       => Values::Boolean      (offsets.map(|o| qword(o).map(|q| q != 0)).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_OCTET_STRING  => Values::OctetString  (offsets.map(|o| octets(o).map(<[u8]>::to_vec)).collect::<Result<_, _>>()?),
+            other                                       => return Err(E::UnsupportedValueType(other)),
+        };
+        Ok(Self { name, flags: unsafe { claim::Flags::from_unchecked(flags) }, values })
+    }
+}
+
+impl TryFrom<&[u8]> for Attribute {
+    type Error = claim::AttributeError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { Self::from_relative_bytes(bytes) }
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Option<u32> { Some(u32::from_le_bytes(bytes.get(offset .. offset + 4)?.try_into().ok()?)) }
+
+
+
+#[cfg(std)] #[test] fn golden_bytes() {
+    use claim::AttributeError as E;
+    use std::vec;
+
+    let attribute = Attribute { flags: claim::Flags::NON_INHERITABLE, ..Attribute::new("Hi", Values::Int64(vec![-2, 3])) };
+    let bytes = attribute.to_relative_bytes().unwrap();
+    assert_eq!(bytes, &[
+        0x18, 0, 0, 0,  0x01, 0x00, 0, 0,  0x01, 0, 0, 0,  0x02, 0, 0, 0,   // Name, ValueType = INT64, Reserved, Flags = NON_INHERITABLE, ValueCount
+        0x20, 0, 0, 0,  0x28, 0, 0, 0,                                      // Values[]
+        b'H', 0, b'i', 0,  0, 0, 0, 0,                                      // "Hi\0" + padding
+        0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,                     // -2
+        0x03, 0, 0, 0, 0, 0, 0, 0,                                          // 3
+    ][..]);
+    assert_eq!(Ok(&attribute), Attribute::from_relative_bytes(&bytes).as_ref());
+
+    for values in [
+        Values::Uint64(vec![u64::MAX]),
+        Values::String(vec!["Secret".into(), "ünïcödé".into(), "".into()]),
+        Values::Sid(vec![sid::Owned::from(&*sid!(S-1-5-32-544)), sid::Owned::from(&*sid!(S-1-1-0))]),
+        Values::Boolean(vec![true, false]),
+        Values::OctetString(vec![vec![1, 2, 3], vec![]]),
+    ] {
+        let attribute = Attribute::new("Test", values);
+        assert_eq!(Ok(&attribute), Attribute::from_relative_bytes(&attribute.to_relative_bytes().unwrap()).as_ref());
+    }
+
+    assert_eq!(Err(E::InvalidName), Attribute::new("",    Values::Boolean(vec![true])).to_relative_bytes());
+    assert_eq!(Err(E::InvalidName), Attribute::new("a\0", Values::Boolean(vec![true])).to_relative_bytes());
+    assert_eq!(Err(E::InteriorNul), Attribute::new("a",   Values::String(vec!["\0".into()])).to_relative_bytes());
+
+    let patch = |offset: usize, patch: &[u8]| {
+        let mut bytes = bytes.clone();
+        bytes[offset .. offset + patch.len()].copy_from_slice(patch);
+        Attribute::from_relative_bytes(&bytes)
+    };
+    assert_eq!(Err(E::Truncated),                   Attribute::from_relative_bytes(&bytes[..15]));
+    assert_eq!(Err(E::Truncated),                   Attribute::from_relative_bytes(&bytes[..23]));
+    assert_eq!(Err(E::Truncated),                   patch(12, &[0xFF, 0xFF, 0xFF, 0xFF]));
+    assert_eq!(Err(E::NoValues),                    patch(12, &[0]));
+    assert_eq!(Err(E::InvalidOffset),               Attribute::from_relative_bytes(&bytes[..0x2F]));
+    assert_eq!(Err(E::InvalidOffset),               patch(0x14, &[0xFF, 0xFF, 0xFF, 0xFF]));
+    assert_eq!(Err(E::InvalidOffset),               patch(0, &[0xFF, 0xFF, 0xFF, 0xFF]));
+    assert_eq!(Err(E::UnterminatedString),          Attribute::from_relative_bytes(&bytes[..0x1C]));
+    assert_eq!(Err(E::InvalidUtf16),                patch(0x18, &[0x00, 0xD8]));
+    assert_eq!(Err(E::InvalidName),                 patch(0x18, &[0, 0]));
+    assert_eq!(Err(E::UnsupportedValueType(4)),     patch(4, &[4]));
+    assert_eq!(Err(E::InvalidOffset),               patch(4, &[5])); // reinterpret -2 as an octet string of 0xFFFFFFFE bytes
+    assert_eq!(Ok(Values::Boolean(vec![true, true])), patch(4, &[6]).map(|a| a.values));
+
+    let octets = Attribute::new("Hi", Values::OctetString(vec![vec![1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0]])).to_relative_bytes().unwrap();
+    let sid = |patch: &[u8]| { let mut bytes = octets.clone(); bytes[4] = 5; bytes[0x1C..0x1C+patch.len()].copy_from_slice(patch); Attribute::from_relative_bytes(&bytes) };
+    assert_eq!(Err(E::InvalidSid),                  sid(&[]));                      // 11 bytes: not 8 + 4 * 1
+    assert_eq!(Err(E::InvalidSid),                  sid(&[4, 0, 0, 0]));            // 4 bytes: less than 8
+    assert_eq!(Ok(Values::Sid(vec![sid::Owned::from(&*sid!(S-1-1-0))])), sid(&[12, 0, 0, 0]).map(|a| a.values));
+    assert_eq!(Err(E::InvalidSid),                  sid(&[12, 0, 0, 0, 2]));        // revision 2
+
+    // Every truncation and single byte corruption must be rejected or parsed without panicking.
+    for len in 0 .. bytes.len() { let _ = Attribute::from_relative_bytes(&bytes[..len]); }
+    for offset in 0 .. bytes.len() {
+        for value in [0x00, 0x01, 0x03, 0x05, 0x10, 0x7F, 0x80, 0xFF] { let _ = patch(offset, &[value]); }
+    }
+}
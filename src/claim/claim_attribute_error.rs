@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// Error serializing or parsing a [`claim::Attribute`] as a `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum AttributeError {
+    /// The name is empty, or contains `'\0'`.
+    InvalidName,
+    /// A string value contains `'\0'`.
+    InteriorNul,
+    /// The attribute has no values.
+    NoValues,
+    /// The buffer is smaller than the 16 byte header and the value offsets it declares.
+    Truncated,
+    /// The name or a value is (partially) outside of the buffer.
+    InvalidOffset,
+    /// The name or a string value is missing its `'\0'` terminator.
+    UnterminatedString,
+    /// The name or a string value isn't valid UTF-16.
+    InvalidUtf16,
+    /// A SID value has the wrong revision, more than 15 sub-authorities, or a length that doesn't match its sub-authority count.
+    InvalidSid,
+    /// `ValueType` isn't one of the supported `CLAIM_SECURITY_ATTRIBUTE_TYPE_*` values (e.g. `CLAIM_SECURITY_ATTRIBUTE_TYPE_FQBN`.)
+    UnsupportedValueType(u16),
+    /// `CLAIM_SECURITY_ATTRIBUTES_INFORMATION::Version` isn't `CLAIM_SECURITY_ATTRIBUTES_INFORMATION_VERSION_V1`.
+    UnsupportedVersion(u16),
+}
+
+impl From<AttributeError> for firehazard::Error {
+    fn from(err: AttributeError) -> Self {
+        Self(match err {
+            AttributeError::InvalidName | AttributeError::InteriorNul | AttributeError::NoValues => ERROR_INVALID_PARAMETER,
+            AttributeError::InvalidSid                                                           => ERROR_INVALID_SID,
+            _                                                                                    => ERROR_INVALID_DATA,
+        })
+    }
+}
+
+#[cfg(std)] impl From<AttributeError> for std::io::Error {
+    fn from(err: AttributeError) -> Self {
+        let kind = match err {
+            AttributeError::InvalidName | AttributeError::InteriorNul | AttributeError::NoValues => std::io::ErrorKind::InvalidInput,
+            _                                                                                    => std::io::ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, err)
+    }
+}
+
+impl core::error::Error for AttributeError {}
+
+impl Display for AttributeError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            AttributeError::InvalidName             => write!(fmt, "claim attribute name is empty or contains '\\0'"),
+            AttributeError::InteriorNul             => write!(fmt, "claim attribute string value contains '\\0'"),
+            AttributeError::NoValues                => write!(fmt, "claim attribute has no values"),
+            AttributeError::Truncated               => write!(fmt, "claim attribute is smaller than its header and value offsets"),
+            AttributeError::InvalidOffset           => write!(fmt, "claim attribute name or value is out of bounds"),
+            AttributeError::UnterminatedString      => write!(fmt, "claim attribute string is missing its '\\0' terminator"),
+            AttributeError::InvalidUtf16            => write!(fmt, "claim attribute string isn't valid UTF-16"),
+            AttributeError::InvalidSid              => write!(fmt, "claim attribute SID value is malformed"),
+            AttributeError::UnsupportedValueType(t) => write!(fmt, "unsupported claim attribute value type 0x{t:02X}"),
+            AttributeError::UnsupportedVersion(v)   => write!(fmt, "unsupported claim security attributes information version {v}"),
+        }
+    }
+}
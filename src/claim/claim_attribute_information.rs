@@ -0,0 +1,82 @@
+use crate::prelude::*;
+use claim::{Attribute, AttributeError as E, Values};
+
+use winapi::um::winnt::*;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+
+
+impl Attribute {
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTES_INFORMATION")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attributes_information)\]
+    /// CLAIM_SECURITY_ATTRIBUTES_INFORMATION → owned attributes
+    ///
+    /// ### Safety
+    /// *   Every pointer reachable from `information` must be valid for the counts and lengths it declares
+    ///     (as is the case for information returned by `GetTokenInformation`, such as [`token::OwnedHandle::user_claim_attributes`].)
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let token = open_process_token(get_current_process(), token::QUERY).unwrap();
+    /// let claims = unsafe { claim::Attribute::from_information(&*token.user_claim_attributes().unwrap()) }.unwrap();
+    /// let context = ace::condition::Context { user_claims: &claims, ..Default::default() };
+    /// ```
+    ///
+    pub unsafe fn from_information(information: &CLAIM_SECURITY_ATTRIBUTES_INFORMATION) -> Result<Vec<Self>, E> {
+        if information.Version != CLAIM_SECURITY_ATTRIBUTES_INFORMATION_VERSION_V1 { return Err(E::UnsupportedVersion(information.Version)) }
+        let attributes = unsafe { slice(*information.Attribute.pAttributeV1(), information.AttributeCount) }?;
+        attributes.iter().map(|attribute| unsafe { Self::from_v1(attribute) }).collect()
+    }
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_V1")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_V1 → owned attribute
+    ///
+    /// ### Safety
+    /// *   `Name` and every value pointer of `attribute` must be valid for the counts and lengths it declares.
+    ///
+    pub unsafe fn from_v1(attribute: &CLAIM_SECURITY_ATTRIBUTE_V1) -> Result<Self, E> {
+        let name = unsafe { utf16z(attribute.Name) }?;
+        if name.is_empty() { return Err(E::InvalidName) }
+        if attribute.ValueCount == 0 { return Err(E::NoValues) }
+
+        let v = &attribute.Values;
+        let n = attribute.ValueCount;
+        let values = unsafe { match attribute.ValueType {
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_INT64         => Values::Int64        (slice(*v.pInt64(), n)?.to_vec()),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_UINT64        => Values::Uint64       (slice(*v.pUint64(), n)?.to_vec()),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_STRING        => Values::String       (slice((*v.ppString()).cast::<PWSTR>(), n)?.iter().map(|s| utf16z(*s)).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_SID           => Values::Sid          (slice(*v.pOctetString(), n)?.iter().map(|o| sid::Owned::from_bytes(octets(o)?).ok_or(E::InvalidSid)).collect::<Result<_, _>>()?),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_BOOLEAN       => Values::Boolean      (slice(*v.pUint64(), n)?.iter().map(|b| *b != 0).collect()),
+            CLAIM_SECURITY_ATTRIBUTE_TYPE_OCTET_STRING  => Values::OctetString  (slice(*v.pOctetString(), n)?.iter().map(|o| octets(o).map(<[u8]>::to_vec)).collect::<Result<_, _>>()?),
+            other                                       => return Err(E::UnsupportedValueType(other)),
+        }};
+        Ok(Self { name, flags: unsafe { claim::Flags::from_unchecked(attribute.Flags) }, values })
+    }
+}
+
+/// ### Safety
+/// *   `ptr` must be null, or valid for `count` elements.
+unsafe fn slice<'a, T>(ptr: *const T, count: u32) -> Result<&'a [T], E> {
+    if count == 0 { return Ok(&[]) }
+    if ptr.is_null() { return Err(E::InvalidOffset) }
+    Ok(unsafe { core::slice::from_raw_parts(ptr, usize::from32(count)) })
+}
+
+/// ### Safety
+/// *   `octets.pValue` must be null, or valid for `octets.ValueLength` bytes.
+unsafe fn octets<'a>(octets: &CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE) -> Result<&'a [u8], E> {
+    unsafe { slice(octets.pValue.cast::<u8>(), octets.ValueLength) }
+}
+
+/// ### Safety
+/// *   `s` must be null, or a valid `\0`-terminated string.
+unsafe fn utf16z(s: PWSTR) -> Result<String, E> {
+    if s.is_null() { return Err(E::InvalidOffset) }
+    let mut len = 0;
+    while unsafe { *s.add(len) } != 0 { len += 1; }
+    String::from_utf16(unsafe { core::slice::from_raw_parts(s, len) }).map_err(|_| E::InvalidUtf16)
+}
@@ -0,0 +1,90 @@
+use crate::winapi::um::winnt::*;
+
+use core::fmt::{self, Debug, Formatter};
+
+
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+/// DWORD/[u32] mask of `CLAIM_SECURITY_ATTRIBUTE_*` flags
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)] pub struct FlagsMask(u32);
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+/// DWORD/[u32] bitset of `CLAIM_SECURITY_ATTRIBUTE_*` flags
+///
+/// The low 16 bits are defined by Windows ([`Flags::VALID_FLAGS`]), the high 16 bits are available for custom use ([`Flags::CUSTOM_FLAGS`].)
+///
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)] pub struct Flags(u32);
+
+flags!(impl .. for Flags(u32) - FlagsMask);
+
+impl Flags {
+    /// ### Safety
+    /// *   Some APIs might theoretically assume flags are a valid?
+    pub const unsafe fn from_unchecked(flags: u32) -> Self { Self(flags) }
+
+    pub fn as_u32(self) -> u32 { self.0 }
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_NON_INHERITABLE")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_NON_INHERITABLE
+    ///
+    pub const NON_INHERITABLE       : Self = Self(CLAIM_SECURITY_ATTRIBUTE_NON_INHERITABLE);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_VALUE_CASE_SENSITIVE")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_VALUE_CASE_SENSITIVE
+    ///
+    pub const VALUE_CASE_SENSITIVE  : Self = Self(CLAIM_SECURITY_ATTRIBUTE_VALUE_CASE_SENSITIVE);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_USE_FOR_DENY_ONLY")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_USE_FOR_DENY_ONLY
+    ///
+    pub const USE_FOR_DENY_ONLY     : Self = Self(CLAIM_SECURITY_ATTRIBUTE_USE_FOR_DENY_ONLY);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_DISABLED_BY_DEFAULT")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_DISABLED_BY_DEFAULT
+    ///
+    pub const DISABLED_BY_DEFAULT   : Self = Self(CLAIM_SECURITY_ATTRIBUTE_DISABLED_BY_DEFAULT);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_DISABLED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_DISABLED
+    ///
+    pub const DISABLED              : Self = Self(CLAIM_SECURITY_ATTRIBUTE_DISABLED);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_MANDATORY")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-claim_security_attribute_v1#members)\]
+    /// CLAIM_SECURITY_ATTRIBUTE_MANDATORY
+    ///
+    pub const MANDATORY             : Self = Self(CLAIM_SECURITY_ATTRIBUTE_MANDATORY);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_VALID_FLAGS")]
+    /// \[<strike>microsoft.com</strike>\]
+    /// CLAIM_SECURITY_ATTRIBUTE_VALID_FLAGS
+    ///
+    pub const VALID_FLAGS           : FlagsMask = FlagsMask(CLAIM_SECURITY_ATTRIBUTE_VALID_FLAGS);
+
+    #[doc(alias = "CLAIM_SECURITY_ATTRIBUTE_CUSTOM_FLAGS")]
+    /// \[<strike>microsoft.com</strike>\]
+    /// CLAIM_SECURITY_ATTRIBUTE_CUSTOM_FLAGS
+    ///
+    pub const CUSTOM_FLAGS          : FlagsMask = FlagsMask(CLAIM_SECURITY_ATTRIBUTE_CUSTOM_FLAGS);
+}
+
+impl Debug for Flags {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        flags!(self.0, fmt, "0x{:X}", [
+            CLAIM_SECURITY_ATTRIBUTE_NON_INHERITABLE,
+            CLAIM_SECURITY_ATTRIBUTE_VALUE_CASE_SENSITIVE,
+            CLAIM_SECURITY_ATTRIBUTE_USE_FOR_DENY_ONLY,
+            CLAIM_SECURITY_ATTRIBUTE_DISABLED_BY_DEFAULT,
+            CLAIM_SECURITY_ATTRIBUTE_DISABLED,
+            CLAIM_SECURITY_ATTRIBUTE_MANDATORY,
+        ])
+    }
+}
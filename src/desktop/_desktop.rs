@@ -66,12 +66,12 @@ mod desktop_handles;                    pub use desktop_handles::*;
 pub use funcs::*;
 pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\close_desktop.rs");
-    include!(r"funcs\create_desktop.rs");
-    include!(r"funcs\enum_desktops.rs");
-    include!(r"funcs\open_desktop.rs");
-    include!(r"funcs\open_input_desktop.rs");
-    include!(r"funcs\open_thread_desktop.rs");
-    include!(r"funcs\switch_desktop.rs");
-    include!(r"funcs\with_thread_desktop.rs");
+    include!("funcs/close_desktop.rs");
+    include!("funcs/create_desktop.rs");
+    include!("funcs/enum_desktops.rs");
+    include!("funcs/open_desktop.rs");
+    include!("funcs/open_input_desktop.rs");
+    include!("funcs/open_thread_desktop.rs");
+    include!("funcs/switch_desktop.rs");
+    include!("funcs/with_thread_desktop.rs");
 }
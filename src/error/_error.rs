@@ -3,7 +3,7 @@
 
 use crate::prelude::*;
 
-include!(r"error.rs");
-include!(r"fast_fail.rs");
-include!(r"handle_conversion_error.rs");
-include!(r"preserve_error_scope.rs");
+include!("error.rs");
+#[cfg(windows)] include!("fast_fail.rs");
+include!("handle_conversion_error.rs");
+#[cfg(windows)] include!("preserve_error_scope.rs");
@@ -3,8 +3,8 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 
 
-pub(crate) trait ResultErrorExt<R>      { fn unerr(self, err: u32, remap: R) -> Self; }
-impl<R> ResultErrorExt<R> for Result<R> { fn unerr(self, err: u32, remap: R) -> Self { match self { Err(e) if e == err => Ok(remap), r => r } } }
+#[cfg(windows)] pub(crate) trait ResultErrorExt<R>      { fn unerr(self, err: u32, remap: R) -> Self; }
+#[cfg(windows)] impl<R> ResultErrorExt<R> for Result<R> { fn unerr(self, err: u32, remap: R) -> Self { match self { Err(e) if e == err => Ok(remap), r => r } } }
 
 
 
@@ -20,13 +20,13 @@ impl Error {
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror)\]
     /// GetLastError
     ///
-    pub fn get_last() -> Self { Self(unsafe { winapi::um::errhandlingapi::GetLastError() }) }
+    #[cfg(windows)] pub fn get_last() -> Self { Self(unsafe { winapi::um::errhandlingapi::GetLastError() }) }
 
     #[doc(alias = "GetLastError")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror)\]
     /// GetLastError if `error`
     ///
-    pub fn get_last_if(error: bool) -> Result<()> { if !error { Ok(()) } else { Err(Self::get_last()) } }
+    #[cfg(windows)] pub fn get_last_if(error: bool) -> Result<()> { if !error { Ok(()) } else { Err(Self::get_last()) } }
 
     pub fn friendly(self) -> &'static str {
         use winapi::shared::ntstatus::*;
@@ -104,15 +104,15 @@ impl core::fmt::Debug for Error {
 
 impl From<Error> for i32 { fn from(err: Error) -> Self { err.0 as _ } }
 impl From<Error> for u32 { fn from(err: Error) -> Self { err.0 as _ } }
-impl From<Error> for crate::io::Error { fn from(err: Error) -> Self { Self::from_raw_os_error(err.into()) } }
+#[cfg(windows)] impl From<Error> for crate::io::Error { fn from(err: Error) -> Self { Self::from_raw_os_error(err.into()) } }
 impl From<i32> for Error { fn from(err: i32      ) -> Self { Self(err as _) } }
 impl From<u32> for Error { fn from(err: u32      ) -> Self { Self(err as _) } }
-impl From<abistr::InteriorNulError> for Error { fn from(_: abistr::InteriorNulError) -> Self { Self(ERROR_ILLEGAL_CHARACTER) } }
-impl From<NtStatus> for Error { fn from(status: NtStatus) -> Self { Self(status.into()) } }
-impl From<winresult::ErrorCode> for Error { fn from(code: winresult::ErrorCode) -> Self { Self(code.into()) } }
+#[cfg(windows)] impl From<abistr::InteriorNulError> for Error { fn from(_: abistr::InteriorNulError) -> Self { Self(ERROR_ILLEGAL_CHARACTER) } }
+#[cfg(windows)] impl From<NtStatus> for Error { fn from(status: NtStatus) -> Self { Self(status.into()) } }
+#[cfg(windows)] impl From<winresult::ErrorCode> for Error { fn from(code: winresult::ErrorCode) -> Self { Self(code.into()) } }
 impl PartialEq<i32> for Error { fn eq(&self, other: &i32) -> bool { *self == Error::from(*other) } }
 impl PartialEq<u32> for Error { fn eq(&self, other: &u32) -> bool { *self == Error::from(*other) } }
-impl PartialEq<winresult::ErrorCode> for Error { fn eq(&self, other: &winresult::ErrorCode) -> bool { *self == Error::from(*other) } }
+#[cfg(windows)] impl PartialEq<winresult::ErrorCode> for Error { fn eq(&self, other: &winresult::ErrorCode) -> bool { *self == Error::from(*other) } }
 impl PartialEq<Error> for i32 { fn eq(&self, other: &Error) -> bool { Error::from(*self) == *other } }
 impl PartialEq<Error> for u32 { fn eq(&self, other: &Error) -> bool { Error::from(*self) == *other } }
-impl PartialEq<Error> for winresult::ErrorCode { fn eq(&self, other: &Error) -> bool { Error::from(*self) == *other } }
+#[cfg(windows)] impl PartialEq<Error> for winresult::ErrorCode { fn eq(&self, other: &Error) -> bool { Error::from(*self) == *other } }
@@ -13,14 +13,14 @@ mod file_type;                  pub use file_type::*;
 pub use funcs::*;
 pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\create_file.rs");
-    include!(r"funcs\flush_file_buffers.rs");
-    include!(r"funcs\get_file_attributes.rs");
-    include!(r"funcs\get_file_type.rs");
-    include!(r"funcs\get_final_path_name_by_handle.rs");
-    include!(r"funcs\nt_query_information_file.rs");
-    include!(r"funcs\set_file_pointer.rs");
+    include!("funcs/create_file.rs");
+    include!("funcs/flush_file_buffers.rs");
+    include!("funcs/get_file_attributes.rs");
+    include!("funcs/get_file_type.rs");
+    include!("funcs/get_final_path_name_by_handle.rs");
+    include!("funcs/nt_query_information_file.rs");
+    include!("funcs/set_file_pointer.rs");
 }
 
-include!(r"information\_file_information_class.rs");
-include!(r"information\file_mode_information.rs");
+include!("information/_file_information_class.rs");
+include!("information/file_mode_information.rs");
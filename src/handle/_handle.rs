@@ -96,11 +96,11 @@
 pub use funcs::*;
 pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\close_handle.rs");
-    include!(r"funcs\compare_object_handles.rs");
-    include!(r"funcs\duplicate_handle.rs");
-    include!(r"funcs\get_handle_information.rs");
-    include!(r"funcs\nt_query_object.rs");
-    include!(r"funcs\set_handle_information.rs");
+    include!("funcs/close_handle.rs");
+    include!("funcs/compare_object_handles.rs");
+    include!("funcs/duplicate_handle.rs");
+    include!("funcs/get_handle_information.rs");
+    include!("funcs/nt_query_object.rs");
+    include!("funcs/set_handle_information.rs");
 }
-include!(r"funcs\debug.rs"); // XXX: don't re-export at crate root
+include!("funcs/debug.rs"); // XXX: don't re-export at crate root
@@ -104,3 +104,45 @@ pub fn duplicate_handle_local_same_access<H>(
         inherit_handle,
     )?)?})
 }
+
+
+
+#[doc(alias = "DuplicateHandle")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-duplicatehandle)\]
+/// DuplicateHandle(-1, source, target_process, access ?? 0, inherit, access ? 0 : DUPLICATE_SAME_ACCESS)
+///
+/// Duplicate `source` *into* `target_process`, returning the new handle's value within `target_process`.
+/// The returned value is meaningless in the current process (unless `target_process` *is* the current process):
+/// don't close it, send it to the target process (e.g. over a pipe) and have the target take ownership of it.
+///
+/// ### Errors
+/// *   `ERROR_ACCESS_DENIED`       if `target_process` lacks `PROCESS_DUP_HANDLE` access, or `source` can't be duplicated with `access`
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let process = duplicate_handle_local_same_access(get_current_process(), false).unwrap();
+/// let remote = duplicate_handle(&process, get_current_process(), None, false).unwrap();
+/// let remote = unsafe { process::OwnedHandle::from_raw(remote) }.unwrap(); // target = current process: take ownership
+/// assert_eq!(get_process_id(&remote), Ok(get_current_process_id()));
+/// ```
+///
+pub fn duplicate_handle<'p>(
+    source:             &impl AsLocalHandle,
+    target_process:     impl Into<process::PseudoHandle<'p>>,
+    access:             impl Into<Option<access::Mask>>,
+    inherit_handle:     bool,
+) -> firehazard::Result<winapi::um::winnt::HANDLE> {
+    let access = access.into();
+    let mut target = null_mut();
+    firehazard::Error::get_last_if(0 == unsafe { winapi::um::handleapi::DuplicateHandle(
+        get_current_process().as_handle(),
+        source.as_handle(),
+        target_process.into().as_handle(),
+        &mut target,
+        access.map_or(0, |a| a.into()),
+        inherit_handle as _,
+        access.map_or(winapi::um::winnt::DUPLICATE_SAME_ACCESS, |_| 0),
+    )})?;
+    Ok(target)
+}
@@ -21,6 +21,7 @@
 //! | [`Read`] + [`Seek`]               | <strike style="opacity: 25%">`firehazard::io::sync::ReadOnlyFile`</strike>    | <span style="opacity: 25%">N/A</span>                                 |
 //! | [`Write`] + [`Seek`]              | <strike style="opacity: 25%">`firehazard::io::sync::WriteOnlyFile`</strike>   | <span style="opacity: 25%">N/A</span>                                 |
 //! |                                   | **with `FILE_FLAG_OVERLAPPED`:**                                              |
+//! | No I/O traits                     | [`firehazard::handle::Owned`] + [`firehazard::io::overlapped`]                | [`firehazard::handle::Borrowed`] + [`firehazard::io::overlapped`]     |
 //! | [`Read`]                          | <span style="opacity: 25%">N/A</span>                                         | <span style="opacity: 25%">N/A</span>                                 |
 //! | [`Write`]                         | <span style="opacity: 25%">N/A</span>                                         | <span style="opacity: 25%">N/A</span>                                 |
 //! | [`Read`] + [`Write`]              | <span style="opacity: 25%">N/A</span>                                         | <span style="opacity: 25%">N/A</span>                                 |
@@ -54,8 +55,7 @@ use crate::prelude::*;
 #[cfg(    std )] mod io_yes_std; #[cfg(    std )] #[doc(no_inline)] pub use std::io    ::{Read, Seek, Write, Error, ErrorKind, Result, SeekFrom};
 #[cfg(not(std))] mod io_not_std; #[cfg(not(std))]                   pub use io_not_std ::{Read, Seek, Write, Error, ErrorKind, Result, SeekFrom};
 
-/// <strike style="opacity: 25%">`HANDLE`s to [file](mod@file)s, [pipe]s, or sockets (created with [`file::FLAG_OVERLAPPED`])</strike> NYI
-pub mod overlapped {} // I'd call it async, but that's a reserved keyword :(
+#[cfg(alloc)] #[path = "overlapped/_overlapped.rs"] pub mod overlapped; // I'd call it async, but that's a reserved keyword :(
 #[path = "io_sync_handles.rs"] pub mod sync;
 
 mod io_counters;                    pub use io_counters::*;
@@ -64,8 +64,8 @@ mod io_status_block;                pub(crate) use io_status_block::StatusBlock;
 #[allow(unused_imports)] pub use funcs::*;
 pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\read_file.rs");
-    include!(r"funcs\write_file.rs");
+    include!("funcs/read_file.rs");
+    include!("funcs/write_file.rs");
 }
 
 #[deprecated = "use `firehazard::io::sync::OwnedFile` instead"          ] #[doc(hidden)] pub type File              = crate::io::sync::OwnedFile;
@@ -0,0 +1,52 @@
+//! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/fileio/synchronous-and-asynchronous-i-o)\]
+//! `HANDLE`s to [file](mod@file)s, [pipe]s, or sockets (created with [`file::FLAG_OVERLAPPED`])
+//!
+//! ### Overview
+//! *   [`Overlapped`] owns a heap allocated `OVERLAPPED` and its manual-reset event, and can be reused for one operation at a time.
+//! *   [`read`], [`write`], and [`connect_named_pipe`] start an [`Operation`], which takes ownership of the [`Overlapped`] and a [`Buffer`] until it completes.
+//! *   [`Operation::wait`] / [`Operation::try_complete`] finish an operation, returning a [`Completion`] with the [`Overlapped`] and [`Buffer`] for reuse.
+//! *   [`Operation::cancel`] requests cancellation.  Dropping an incomplete [`Operation`] cancels it and blocks until the kernel is done with its buffer.
+//! *   [`CompletionPort`] lets a single thread wait on completions from many handles (e.g. one [`Operation`] per pipe instance.)
+//! *   [`Reactor`] services a [`CompletionPort`] on a background thread, waking async tasks (e.g. for `pipe::futures` with the `futures` feature.)
+//!
+//! ### Soundness
+//! The kernel writes to the `OVERLAPPED` and buffer of an operation until it completes, which may be long after the call that started it returns.
+//! As such, [`Operation`]s *own* both, rather than borrowing them: leaking an [`Operation`] (e.g. via [`core::mem::forget`]) merely leaks them too,
+//! instead of letting the kernel scribble over memory that has since been freed or reused.
+//! [`Buffer`] is an `unsafe trait` for the same reason: it's only implemented for types whose bytes don't move when the buffer itself is moved.
+//!
+//! ### Examples
+//! ```
+//! # use firehazard::*;
+//! use firehazard::io::overlapped::{self, Overlapped};
+//!
+//! let name = r"\\.\pipe\local\firehazard-io-overlapped-example";
+//! let server = handle::Owned::from(pipe::named::create_w( // not a pipe::named::Listener: its blocking methods would be UB
+//!     name, pipe::ACCESS_DUPLEX | file::FLAG_OVERLAPPED, pipe::TYPE_BYTE | pipe::READMODE_BYTE,
+//!     pipe::UNLIMITED_INSTANCES, 0, 0, None, None,
+//! ).unwrap());
+//!
+//! let connect = overlapped::connect_named_pipe(&server, Overlapped::new().unwrap());
+//! let client = std::fs::File::options().read(true).write(true).open(name).unwrap();
+//! let overlapped = connect.wait().into_result().unwrap().overlapped;
+//!
+//! // neither operation blocks the other, despite sharing a pipe instance
+//! let read  = overlapped::read (&server, Overlapped::new().unwrap(), vec![0u8; 5]);
+//! let write = overlapped::write(&server, overlapped, b"hello".to_vec());
+//! std::io::Write::write_all(&mut &client, b"world").unwrap();
+//! assert_eq!(std::io::Read::read(&mut &client, &mut [0u8; 5]).unwrap(), 5);
+//! assert_eq!(write.wait().into_result().unwrap().transferred, 5);
+//! assert_eq!(read.wait().into_result().unwrap().buffer, b"world");
+//! ```
+
+use crate::prelude::*;
+
+mod overlapped_buffer;                  pub use overlapped_buffer::*;
+mod overlapped_completion_port;         pub use overlapped_completion_port::*;
+mod overlapped_funcs;                   pub use overlapped_funcs::*;
+mod overlapped_operation;               pub use overlapped_operation::*;
+mod overlapped_overlapped;              pub use overlapped_overlapped::*;
+#[cfg(std)] mod overlapped_reactor;     #[cfg(std)] pub use overlapped_reactor::*;
+
+/// `STATUS_PENDING`, as stored in `OVERLAPPED::Internal` until an operation completes
+const STATUS_PENDING : usize = 0x0000_0103;
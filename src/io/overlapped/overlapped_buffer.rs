@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+
+
+/// A byte buffer that can be owned by an [`Operation`](crate::io::overlapped::Operation) while the kernel reads or writes it
+///
+/// ### Safety
+/// The pointer returned by [`Buffer::as_ptr`] (and [`BufferMut::as_mut_ptr`]) must remain valid for [`Buffer::len`] bytes,
+/// and must not change, when `self` is moved, until `self` is dropped or mutably accessed by anything other than these traits.
+/// Heap allocations qualify (<code>[Vec]&lt;u8&gt;</code>, <code>[Box]&lt;\[u8\]&gt;</code>), inline arrays (`[u8; N]`) do not.
+///
+pub unsafe trait Buffer {
+    fn as_ptr(&self) -> *const u8;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+/// A [`Buffer`] that may be written to (by a [read](crate::io::overlapped::read))
+///
+/// ### Safety
+/// See [`Buffer`].  Additionally, the [`Buffer::len`] bytes at [`BufferMut::as_mut_ptr`] must be writable as arbitrary bytes.
+///
+pub unsafe trait BufferMut : Buffer {
+    fn as_mut_ptr(&mut self) -> *mut u8;
+}
+
+unsafe impl Buffer for ()                       { fn as_ptr(&self) -> *const u8 { NonNull::dangling().as_ptr() } fn len(&self) -> usize { 0 } }
+unsafe impl Buffer for Vec<u8>                  { fn as_ptr(&self) -> *const u8 { self.as_slice().as_ptr() } fn len(&self) -> usize { self.as_slice().len() } }
+unsafe impl Buffer for Box<[u8]>                { fn as_ptr(&self) -> *const u8 { (**self).as_ptr() } fn len(&self) -> usize { (**self).len() } }
+unsafe impl Buffer for String                   { fn as_ptr(&self) -> *const u8 { self.as_str().as_ptr() } fn len(&self) -> usize { self.as_str().len() } }
+unsafe impl Buffer for &'static [u8]            { fn as_ptr(&self) -> *const u8 { (**self).as_ptr() } fn len(&self) -> usize { (**self).len() } }
+unsafe impl Buffer for &'static str             { fn as_ptr(&self) -> *const u8 { (**self).as_ptr() } fn len(&self) -> usize { (**self).len() } }
+unsafe impl Buffer for &'static mut [u8]        { fn as_ptr(&self) -> *const u8 { (**self).as_ptr() } fn len(&self) -> usize { (**self).len() } }
+
+unsafe impl BufferMut for ()                    { fn as_mut_ptr(&mut self) -> *mut u8 { NonNull::dangling().as_ptr() } }
+unsafe impl BufferMut for Vec<u8>               { fn as_mut_ptr(&mut self) -> *mut u8 { self.as_mut_slice().as_mut_ptr() } }
+unsafe impl BufferMut for Box<[u8]>             { fn as_mut_ptr(&mut self) -> *mut u8 { (**self).as_mut_ptr() } }
+unsafe impl BufferMut for &'static mut [u8]     { fn as_mut_ptr(&mut self) -> *mut u8 { (**self).as_mut_ptr() } }
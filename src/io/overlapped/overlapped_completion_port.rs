@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use io::overlapped::Operation;
+
+use winapi::shared::minwindef::FALSE;
+use winapi::um::handleapi::INVALID_HANDLE_VALUE;
+use winapi::um::ioapiset::{CreateIoCompletionPort, GetQueuedCompletionStatus, PostQueuedCompletionStatus};
+use winapi::um::minwinbase::OVERLAPPED;
+use winapi::um::winbase::INFINITE;
+
+use core::time::Duration;
+
+
+
+#[doc(alias = "HANDLE")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/fileio/i-o-completion-ports)\]
+/// Owned non-null I/O completion port `HANDLE`
+///
+/// Queues a [`Packet`] for every [`Operation`] that completes on an [associated](Self::associate) handle,
+/// letting one thread service many pipe instances, files, or sockets.
+/// Each packet identifies its operation by `OVERLAPPED` pointer ([`Packet::is_for`]) and its handle by completion `key`.
+///
+/// Operations that fail to start, or [connect](crate::io::overlapped::connect_named_pipe) immediately, never queue a packet:
+/// check [`Operation::is_complete`] after starting one.
+///
+#[repr(transparent)] pub struct CompletionPort(HANDLENN);
+
+/// A completion packet dequeued from a [`CompletionPort`]
+#[derive(Clone, Copy, Debug)]
+pub struct Packet {
+    /// The `key` the handle was [associated](CompletionPort::associate) with, or [posted](CompletionPort::post) with.
+    pub key:            usize,
+    /// Bytes read or written.
+    pub transferred:    u32,
+    /// The `OVERLAPPED` of the completed [`Operation`], or null for [posted](CompletionPort::post) packets.
+    pub overlapped:     *mut OVERLAPPED,
+    /// The result of the completed I/O (e.g. `Err(ERROR_OPERATION_ABORTED)` if cancelled, `Err(ERROR_BROKEN_PIPE)` if the client disconnected.)
+    pub result:         firehazard::Result<()>,
+}
+
+// SAFETY: `Packet::overlapped` is only an identifier - it's never dereferenced.
+unsafe impl Send for Packet {}
+unsafe impl Sync for Packet {}
+
+handles!(unsafe impl *LocalHandleNN<c_void>     for io::overlapped::{CompletionPort});
+handles!(unsafe impl {Send, Sync}               for io::overlapped::{CompletionPort}); // SAFETY: completion ports are designed to be shared between worker threads
+handles!(       impl Debug                      for io::overlapped::{CompletionPort});
+
+handles!(unsafe impl @convert &'_ io::overlapped::CompletionPort => handle::Borrowed<'_>  );
+handles!(unsafe impl @convert &'_ io::overlapped::CompletionPort => handle::Pseudo<'_>    );
+
+#[doc = r"\[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-closehandle)\] CloseHandle"] impl Drop for CompletionPort { fn drop(&mut self) { unsafe { drop_close_handle_nn(self) } } }
+
+impl CompletionPort {
+    #[doc(alias = "CreateIoCompletionPort")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/fileio/createiocompletionport)\]
+    /// CreateIoCompletionPort(INVALID_HANDLE_VALUE, nullptr, 0, concurrent_threads)
+    ///
+    /// Create a completion port not yet associated with any handles.
+    /// `concurrent_threads` limits how many threads may process packets at once (`0` = one per processor.)
+    ///
+    pub fn new(concurrent_threads: u32) -> firehazard::Result<Self> {
+        let port = unsafe { CreateIoCompletionPort(INVALID_HANDLE_VALUE, null_mut(), 0, concurrent_threads) };
+        unsafe { Self::from_raw(port) }.map_err(|_| firehazard::Error::get_last())
+    }
+
+    #[doc(alias = "CreateIoCompletionPort")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/fileio/createiocompletionport)\]
+    /// CreateIoCompletionPort(handle, port, key, 0)
+    ///
+    /// Queue a [`Packet`] with `key` on this port whenever an [`Operation`] on `handle` completes.
+    /// `handle` must have been opened with [`file::FLAG_OVERLAPPED`], and remains associated until it's closed.
+    ///
+    /// ### Errors
+    /// *   `ERROR_INVALID_PARAMETER`   if `handle` is already associated with a completion port
+    ///
+    pub fn associate(&self, handle: &impl AsLocalHandleNN, key: usize) -> firehazard::Result<()> {
+        let port = unsafe { CreateIoCompletionPort(handle.as_handle_nn().as_ptr().cast(), self.0.as_ptr().cast(), key, 0) };
+        if port.is_null() { Err(firehazard::Error::get_last()) } else { Ok(()) }
+    }
+
+    #[doc(alias = "GetQueuedCompletionStatus")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getqueuedcompletionstatus)\]
+    /// GetQueuedCompletionStatus
+    ///
+    /// Wait up to `timeout` (or forever if [`None`]) for the next [`Packet`].
+    /// A packet for failed I/O is still returned as `Ok(packet)`, with the failure in [`Packet::result`].
+    /// Finish the matching [`Operation`] (e.g. via [`Operation::wait`], which won't block) to reclaim its buffer.
+    ///
+    /// ### Errors
+    /// *   `WAIT_TIMEOUT`              if no packet was queued before `timeout` elapsed
+    /// *   `ERROR_ABANDONED_WAIT_0`    if the port was closed while waiting
+    ///
+    pub fn get(&self, timeout: impl Into<Option<Duration>>) -> firehazard::Result<Packet> {
+        let timeout_ms = timeout.into().map_or(INFINITE, |d| u32::try_from(d.as_millis()).unwrap_or(INFINITE).min(INFINITE-1));
+        let mut transferred = 0;
+        let mut key         = 0;
+        let mut overlapped  = null_mut();
+        let result = firehazard::Error::get_last_if(FALSE == unsafe { GetQueuedCompletionStatus(self.0.as_ptr(), &mut transferred, &mut key, &mut overlapped, timeout_ms) });
+        if overlapped.is_null() { result?; } // no packet dequeued: the wait itself failed
+        Ok(Packet { key, transferred, overlapped, result })
+    }
+
+    #[doc(alias = "PostQueuedCompletionStatus")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-postqueuedcompletionstatus)\]
+    /// PostQueuedCompletionStatus(port, transferred, key, nullptr)
+    ///
+    /// Queue a [`Packet`] with a null [`Packet::overlapped`] (e.g. to wake a thread blocked in [`get`](Self::get) for shutdown.)
+    ///
+    pub fn post(&self, transferred: u32, key: usize) -> firehazard::Result<()> {
+        firehazard::Error::get_last_if(FALSE == unsafe { PostQueuedCompletionStatus(self.0.as_ptr(), transferred, key, null_mut()) })
+    }
+}
+
+impl Packet {
+    /// `true` if this packet was queued by the completion of `operation`.
+    pub fn is_for<B>(&self, operation: &Operation<'_, B>) -> bool { !self.overlapped.is_null() && self.overlapped == operation.overlapped_ptr() }
+}
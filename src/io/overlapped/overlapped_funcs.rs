@@ -0,0 +1,90 @@
+use crate::prelude::*;
+use io::overlapped::{Buffer, BufferMut, Operation, Overlapped};
+
+
+
+#[doc(alias = "ReadFile")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)\]
+/// ReadFile(handle, buffer, ..., overlapped)
+///
+/// Start reading into `buffer` from `handle` (at [`Overlapped::offset`] for files.)
+/// `handle` must have been opened with [`file::FLAG_OVERLAPPED`].
+///
+/// Errors starting the read are reported by the returned [`Operation`]'s [`Completion::result`](crate::io::overlapped::Completion::result).
+///
+pub fn read<'h, B: BufferMut>(handle: &'h impl AsLocalHandleNN, overlapped: Overlapped, buffer: B) -> Operation<'h, B> {
+    unsafe { read_unbound(handle.as_handle_nn(), overlapped, buffer) }
+}
+
+/// ### Safety
+/// *   `handle` must remain valid for `'h`
+pub(crate) unsafe fn read_unbound<'h, B: BufferMut>(handle: HANDLENN, mut overlapped: Overlapped, mut buffer: B) -> Operation<'h, B> {
+    overlapped.reset();
+    let started = firehazard::Error::get_last_if(0 == unsafe { winapi::um::fileapi::ReadFile(
+        handle.as_ptr().cast(),
+        buffer.as_mut_ptr().cast(),
+        buffer.len().try_into().unwrap_or(!0u32),
+        null_mut(),
+        overlapped.as_ptr(),
+    )});
+    unsafe { Operation::new(handle, overlapped, buffer, immediate(started)) }
+}
+
+#[doc(alias = "WriteFile")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-writefile)\]
+/// WriteFile(handle, buffer, ..., overlapped)
+///
+/// Start writing `buffer` to `handle` (at [`Overlapped::offset`] for files.)
+/// `handle` must have been opened with [`file::FLAG_OVERLAPPED`].
+///
+/// Errors starting the write are reported by the returned [`Operation`]'s [`Completion::result`](crate::io::overlapped::Completion::result).
+///
+pub fn write<'h, B: Buffer>(handle: &'h impl AsLocalHandleNN, overlapped: Overlapped, buffer: B) -> Operation<'h, B> {
+    unsafe { write_unbound(handle.as_handle_nn(), overlapped, buffer) }
+}
+
+/// ### Safety
+/// *   `handle` must remain valid for `'h`
+pub(crate) unsafe fn write_unbound<'h, B: Buffer>(handle: HANDLENN, mut overlapped: Overlapped, buffer: B) -> Operation<'h, B> {
+    overlapped.reset();
+    let started = firehazard::Error::get_last_if(0 == unsafe { winapi::um::fileapi::WriteFile(
+        handle.as_ptr().cast(),
+        buffer.as_ptr().cast(),
+        buffer.len().try_into().unwrap_or(!0u32),
+        null_mut(),
+        overlapped.as_ptr(),
+    )});
+    unsafe { Operation::new(handle, overlapped, buffer, immediate(started)) }
+}
+
+#[doc(alias = "ConnectNamedPipe")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)\]
+/// ConnectNamedPipe(pipe, overlapped)
+///
+/// Start waiting for a client to connect to a named pipe instance created with [`file::FLAG_OVERLAPPED`].
+/// If a client already connected between creation and this call, the returned [`Operation`] is already complete.
+///
+pub fn connect_named_pipe<'h>(pipe: &'h impl AsLocalHandleNN, overlapped: Overlapped) -> Operation<'h, ()> {
+    unsafe { connect_named_pipe_unbound(pipe.as_handle_nn(), overlapped) }
+}
+
+/// ### Safety
+/// *   `pipe` must remain valid for `'h`
+pub(crate) unsafe fn connect_named_pipe_unbound<'h>(pipe: HANDLENN, mut overlapped: Overlapped) -> Operation<'h, ()> {
+    overlapped.reset();
+    let started = firehazard::Error::get_last_if(0 == unsafe { winapi::um::namedpipeapi::ConnectNamedPipe(pipe.as_ptr().cast(), overlapped.as_ptr()) });
+    let done = match started {
+        Err(err) if err == ERROR_PIPE_CONNECTED => Some(Ok(())), // already connected: no event signaled, no completion packet posted
+        started                                 => immediate(started),
+    };
+    unsafe { Operation::new(pipe, overlapped, (), done) }
+}
+
+/// [`None`] if the kernel now owns the `OVERLAPPED` (including `ERROR_IO_PENDING`, and `ERROR_MORE_DATA` which still completes normally)
+fn immediate(started: firehazard::Result<()>) -> Option<firehazard::Result<()>> {
+    match started {
+        Ok(())                                                          => None,
+        Err(err) if err == ERROR_IO_PENDING || err == ERROR_MORE_DATA   => None,
+        Err(err)                                                        => Some(Err(err)),
+    }
+}
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+use io::overlapped::{Overlapped, STATUS_PENDING};
+use winapi::um::ioapiset::{CancelIoEx, GetOverlappedResult};
+use winapi::um::minwinbase::OVERLAPPED;
+
+
+
+/// An in-flight overlapped I/O operation on a borrowed `HANDLE`, owning its [`Overlapped`] and buffer `B` until completion
+///
+/// Started by [`io::overlapped::read`](crate::io::overlapped::read), [`write`](crate::io::overlapped::write),
+/// or [`connect_named_pipe`](crate::io::overlapped::connect_named_pipe).
+///
+/// Dropping an operation that hasn't completed [cancels](Self::cancel) it, and then blocks until the kernel has finished with its buffer.
+///
+#[must_use = "dropping an io::overlapped::Operation cancels it"]
+pub struct Operation<'h, B> {
+    handle: HANDLENN,
+    state:  Option<State<B>>,
+    _phantom: PhantomData<&'h HANDLENN>,
+}
+
+struct State<B> {
+    overlapped: Overlapped,
+    buffer:     B,
+    /// The result of an operation that finished without the kernel retaining the `OVERLAPPED` (e.g. failed to start.)
+    done:       Option<firehazard::Result<()>>,
+}
+
+/// The result of a finished [`Operation`], returning ownership of its [`Overlapped`] and buffer
+#[derive(Debug)]
+pub struct Completion<B> {
+    /// `Err(ERROR_OPERATION_ABORTED)` if [cancelled](Operation::cancel).
+    /// `Err(ERROR_MORE_DATA)` if a message mode pipe read was too small for the message: [`Self::transferred`] bytes were read, read again for the rest.
+    pub result:         firehazard::Result<()>,
+    /// Bytes read or written.
+    pub transferred:    u32,
+    pub overlapped:     Overlapped,
+    pub buffer:         B,
+}
+
+// SAFETY: `HANDLENN` is merely borrowed, as by `&'h impl AsLocalHandle`, which the I/O functions accepted from any thread.
+unsafe impl<B: Send> Send for Operation<'_, B> {}
+unsafe impl<B: Sync> Sync for Operation<'_, B> {}
+
+impl<B> core::fmt::Debug for Operation<'_, B> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("io::overlapped::Operation").field("handle", &self.handle).field("complete", &self.is_complete()).finish_non_exhaustive()
+    }
+}
+
+impl<'h, B> Operation<'h, B> {
+    /// ### Safety
+    /// *   `overlapped` must be reset, and `handle` must be valid for `'h`
+    /// *   If `done` is [`None`], an operation referencing `overlapped` and `buffer` must have been started on `handle`
+    pub(super) unsafe fn new(handle: HANDLENN, overlapped: Overlapped, buffer: B, done: Option<firehazard::Result<()>>) -> Self {
+        Self { handle, state: Some(State { overlapped, buffer, done }), _phantom: PhantomData }
+    }
+
+    /// The raw `OVERLAPPED`, e.g. to match against [`Packet::overlapped`](crate::io::overlapped::Packet::overlapped).
+    pub fn overlapped_ptr(&self) -> *mut OVERLAPPED { self.state().overlapped.as_ptr() }
+
+    /// The manual-reset event signaled when the operation completes (e.g. for `WaitForMultipleObjects`.)
+    pub fn event(&self) -> handle::Borrowed<'_> { self.state().overlapped.event() }
+
+    #[doc(alias = "HasOverlappedIoCompleted")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-hasoverlappediocompleted)\]
+    /// HasOverlappedIoCompleted
+    ///
+    /// Operations that failed to start, or connected immediately, are always complete (and won't post to a [`CompletionPort`](crate::io::overlapped::CompletionPort).)
+    pub fn is_complete(&self) -> bool {
+        let state = self.state();
+        state.done.is_some() || state.overlapped.status() != STATUS_PENDING
+    }
+
+    #[doc(alias = "CancelIoEx")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func)\]
+    /// CancelIoEx(handle, overlapped)
+    ///
+    /// Request cancellation.  The operation may still complete successfully if it was already finishing:
+    /// [`wait`](Self::wait) for the actual result.  Cancelling a complete operation is a no-op.
+    pub fn cancel(&self) -> firehazard::Result<()> {
+        if self.is_complete() { return Ok(()) }
+        match firehazard::Error::get_last_if(0 == unsafe { CancelIoEx(self.handle.as_ptr(), self.overlapped_ptr()) }) {
+            Err(err) if err == ERROR_NOT_FOUND  => Ok(()), // completed in the meantime
+            r                                   => r,
+        }
+    }
+
+    #[doc(alias = "GetOverlappedResult")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresult)\]
+    /// GetOverlappedResult(handle, overlapped, ..., TRUE)
+    ///
+    /// Block until the operation completes.
+    pub fn wait(mut self) -> Completion<B> { self.complete(true).unwrap_or_else(|| unreachable!()) }
+
+    #[doc(alias = "GetOverlappedResult")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresult)\]
+    /// GetOverlappedResult(handle, overlapped, ..., FALSE)
+    ///
+    /// Finish the operation if it has completed, or return it unchanged if it's still pending.
+    pub fn try_complete(mut self) -> Result<Completion<B>, Self> {
+        match self.complete(false) {
+            Some(completion)    => Ok(completion),
+            None                => Err(self),
+        }
+    }
+
+    fn state(&self) -> &State<B> { self.state.as_ref().expect("io::overlapped::Operation used after completion") }
+
+    fn complete(&mut self, wait: bool) -> Option<Completion<B>> {
+        let state = self.state.as_mut()?;
+        let mut transferred = 0;
+        let result = if let Some(done) = state.done {
+            done
+        } else {
+            let r = firehazard::Error::get_last_if(0 == unsafe { GetOverlappedResult(self.handle.as_ptr(), state.overlapped.as_ptr(), &mut transferred, wait as _) });
+            if matches!(r, Err(err) if err == ERROR_IO_INCOMPLETE) && !wait { return None }
+            r
+        };
+        let State { overlapped, buffer, .. } = self.state.take()?;
+        Some(Completion { result, transferred, overlapped, buffer })
+    }
+}
+
+impl<B> Drop for Operation<'_, B> {
+    fn drop(&mut self) {
+        if self.state.is_none() { return }
+        let _ = self.cancel();
+        let _ = self.complete(true); // must block: the kernel may still be writing to the buffer and OVERLAPPED
+    }
+}
+
+impl<B> Completion<B> {
+    /// [`Self::result`], keeping the rest of the completion on success.
+    pub fn into_result(self) -> firehazard::Result<Self> {
+        match self.result {
+            Ok(())      => Ok(self),
+            Err(err)    => Err(err),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use winapi::um::minwinbase::OVERLAPPED;
+
+use alloc::boxed::Box;
+
+
+
+#[doc(alias = "OVERLAPPED")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-overlapped)\]
+/// Owned, heap allocated `OVERLAPPED` + manual-reset event
+///
+/// Pass by value to [`io::overlapped::read`](crate::io::overlapped::read) etc. to start an [`Operation`](crate::io::overlapped::Operation),
+/// and get it back from the operation's [`Completion`](crate::io::overlapped::Completion) to reuse it for the next one.
+/// The `OVERLAPPED` never moves in memory, even when [`Overlapped`] itself is moved.
+///
+pub struct Overlapped {
+    overlapped: NonNull<OVERLAPPED>,
+    event:      handle::Owned,
+}
+
+// SAFETY: the `OVERLAPPED` is uniquely owned, and only touched by the kernel while owned by an `Operation`.
+unsafe impl Send for Overlapped {}
+unsafe impl Sync for Overlapped {}
+
+impl Drop for Overlapped {
+    fn drop(&mut self) { drop(unsafe { Box::from_raw(self.overlapped.as_ptr()) }) }
+}
+
+impl core::fmt::Debug for Overlapped {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("io::overlapped::Overlapped").field("offset", &self.offset()).field("event", &self.event).finish()
+    }
+}
+
+impl Overlapped {
+    #[doc(alias = "CreateEventW")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw)\]
+    /// CreateEventW(nullptr, TRUE, FALSE, nullptr) + a zeroed `OVERLAPPED` (offset 0)
+    ///
+    pub fn new() -> firehazard::Result<Self> {
+        let event = unsafe { winapi::um::synchapi::CreateEventW(null_mut(), 1, 0, null()) };
+        let event = unsafe { handle::Owned::from_raw(event) }.map_err(|_| firehazard::Error::get_last())?;
+        let mut overlapped = Box::new(OVERLAPPED::default());
+        overlapped.hEvent = event.as_handle();
+        Ok(Self { overlapped: NonNull::from(Box::leak(overlapped)), event })
+    }
+
+    /// The file offset the next operation will read from / write to.  Ignored by pipes and sockets.
+    pub fn offset(&self) -> u64 {
+        let s = unsafe { self.overlapped.as_ref().u.s() };
+        u64::from(s.Offset) | (u64::from(s.OffsetHigh) << 32)
+    }
+
+    /// Set the file offset the next operation will read from / write to.  Ignored by pipes and sockets.
+    pub fn set_offset(&mut self, offset: u64) {
+        let s = unsafe { self.overlapped.as_mut().u.s_mut() };
+        s.Offset        = offset as u32;
+        s.OffsetHigh    = (offset >> 32) as u32;
+    }
+
+    /// The manual-reset event signaled when an operation completes (e.g. for `WaitForMultipleObjects`.)
+    pub fn event(&self) -> handle::Borrowed<'_> { (&self.event).into() }
+
+    /// The raw `OVERLAPPED`, e.g. to compare against [`Packet::overlapped`](crate::io::overlapped::Packet::overlapped).
+    pub fn as_ptr(&self) -> *mut OVERLAPPED { self.overlapped.as_ptr() }
+
+    /// Prepare for a new operation: reset the status and event, keeping the offset.
+    pub(super) fn reset(&mut self) {
+        let o = unsafe { self.overlapped.as_mut() };
+        o.Internal      = 0;
+        o.InternalHigh  = 0;
+        unsafe { winapi::um::synchapi::ResetEvent(o.hEvent) };
+    }
+
+    /// `OVERLAPPED::Internal`, read volatile as the kernel may be writing it concurrently.
+    pub(super) fn status(&self) -> usize {
+        unsafe { core::ptr::addr_of!((*self.overlapped.as_ptr()).Internal).read_volatile() }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+use io::overlapped::{CompletionPort, Operation};
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::vec::Vec;
+
+use core::task::{Context, Poll, Waker};
+
+
+
+/// A [`CompletionPort`] serviced by a background thread, which wakes async tasks as their [`Operation`]s complete
+///
+/// This is executor agnostic: it only deals in [`Waker`]s, so it works with any executor (or a hand rolled `block_on`.)
+/// [`register`](Self::register) each `FILE_FLAG_OVERLAPPED` handle once, then use [`Registration::poll_complete`] when polling its operations.
+///
+/// The background thread exits once every clone of the [`Reactor`] (including those held by [`Registration`]s) has been dropped.
+///
+#[derive(Clone)] pub struct Reactor(Arc<Owner>);
+
+/// A handle [associated](CompletionPort::associate) with a [`Reactor`]
+///
+/// Dropping the registration does *not* dissociate the handle - close the handle instead.
+///
+pub struct Registration {
+    key:        usize,
+    waiters:    Arc<Waiters>,
+    reactor:    Reactor,
+}
+
+/// Shuts down the reactor thread when dropped.
+struct Owner(Arc<Shared>);
+
+/// Shared between [`Reactor`]s and the reactor thread.
+struct Shared {
+    port:           CompletionPort,
+    next_key:       AtomicUsize,
+    registrations:  Mutex<BTreeMap<usize, Weak<Waiters>>>,
+}
+
+#[derive(Default)] struct Waiters(Mutex<Vec<Waker>>);
+
+/// Completion key [posted](CompletionPort::post) to stop the reactor thread.
+const SHUTDOWN : usize = 0;
+
+impl core::fmt::Debug for Reactor {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("io::overlapped::Reactor").field("port", &self.0.0.port).finish_non_exhaustive()
+    }
+}
+
+impl core::fmt::Debug for Registration {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("io::overlapped::Registration").field("key", &self.key).finish_non_exhaustive()
+    }
+}
+
+impl Reactor {
+    /// Create a new [`CompletionPort`], and spawn a thread to service it.
+    pub fn new() -> firehazard::Result<Self> {
+        let shared = Arc::new(Shared {
+            port:           CompletionPort::new(1)?,
+            next_key:       AtomicUsize::new(SHUTDOWN + 1),
+            registrations:  Default::default(),
+        });
+        let thread = Arc::clone(&shared);
+        std::thread::Builder::new()
+            .name("firehazard::io::overlapped::Reactor".into())
+            .spawn(move || thread.run())
+            .map_err(|err| err.raw_os_error().map_or(firehazard::Error::from(ERROR_NOT_ENOUGH_MEMORY), firehazard::Error::from))?;
+        Ok(Self(Arc::new(Owner(shared))))
+    }
+
+    /// A lazily created [`Reactor`] shared by the whole process (e.g. the default for `pipe::futures`.)
+    ///
+    /// ### Panics
+    /// *   If the reactor couldn't be created (out of memory, handles, or threads.)
+    pub fn global() -> &'static Self {
+        static GLOBAL : OnceLock<Reactor> = OnceLock::new();
+        GLOBAL.get_or_init(|| Self::new().expect("unable to create the global firehazard::io::overlapped::Reactor"))
+    }
+
+    /// The underlying [`CompletionPort`].  Don't [post](CompletionPort::post) completion key `0` to it: that stops the reactor thread.
+    pub fn port(&self) -> &CompletionPort { &self.0.0.port }
+
+    #[doc(alias = "CreateIoCompletionPort")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/fileio/createiocompletionport)\]
+    /// CreateIoCompletionPort(handle, port, key, 0)
+    ///
+    /// Associate `handle` with this reactor, so completing [`Operation`]s on it wake the tasks [polling](Registration::poll_complete) them.
+    /// `handle` must have been opened with [`file::FLAG_OVERLAPPED`], and can only be registered once.
+    ///
+    pub fn register(&self, handle: &impl AsLocalHandleNN) -> firehazard::Result<Registration> {
+        let shared  = &self.0.0;
+        let key     = shared.next_key.fetch_add(1, Relaxed);
+        let waiters = Arc::<Waiters>::default();
+        shared.port.associate(handle, key)?;
+        shared.registrations.lock().unwrap_or_else(|p| p.into_inner()).insert(key, Arc::downgrade(&waiters));
+        Ok(Registration { key, waiters, reactor: self.clone() })
+    }
+}
+
+impl Registration {
+    /// The [`Reactor`] this registration belongs to.
+    pub fn reactor(&self) -> &Reactor { &self.reactor }
+
+    /// [`Poll::Ready`] if `operation` (on the registered handle) has completed.
+    /// Otherwise, `cx`'s [`Waker`] will be woken once an operation on the registered handle completes, and [`Poll::Pending`] is returned.
+    ///
+    /// Wakes may be spurious (e.g. a write completing while a read is being polled): re-poll as usual.
+    ///
+    pub fn poll_complete<B>(&self, cx: &mut Context, operation: &Operation<'_, B>) -> Poll<()> {
+        if operation.is_complete() { return Poll::Ready(()) }
+        {
+            let mut wakers = self.waiters.0.lock().unwrap_or_else(|p| p.into_inner());
+            if !wakers.iter().any(|w| w.will_wake(cx.waker())) { wakers.push(cx.waker().clone()) }
+        }
+        // checked again after registering the waker, in case the completion packet was dequeued in the meantime
+        if operation.is_complete() { Poll::Ready(()) } else { Poll::Pending }
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.reactor.0.0.registrations.lock().unwrap_or_else(|p| p.into_inner()).remove(&self.key);
+    }
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        let _ = self.0.port.post(0, SHUTDOWN);
+    }
+}
+
+impl Shared {
+    fn run(&self) {
+        loop {
+            let Ok(packet) = self.port.get(None) else { return }; // ERROR_ABANDONED_WAIT_0 etc.
+            if packet.key == SHUTDOWN && packet.overlapped.is_null() { return }
+            let waiters = self.registrations.lock().unwrap_or_else(|p| p.into_inner()).get(&packet.key).and_then(Weak::upgrade);
+            let Some(waiters) = waiters else { continue }; // registration dropped with packets still queued
+            let wakers = core::mem::take(&mut *waiters.0.lock().unwrap_or_else(|p| p.into_inner()));
+            for waker in wakers { waker.wake() }
+        }
+    }
+}
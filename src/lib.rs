@@ -1,6 +1,4 @@
 #![doc = include_str!("../Readme.md")]
-#![cfg(windows)]
-
 #![no_std]
 //[debugger_visualizer(natvis_file = r"debug\firehazard.natvis")] // XXX: currently specified via launch.json
 #![cfg_attr(not(std), allow(unused_imports))]
@@ -8,47 +6,52 @@
 #![deny(unreachable_patterns)]
 
 #[cfg(std)] extern crate std;
-#[doc(hidden)] pub extern crate abistr;
-#[doc(hidden)] pub extern crate ialloc;
-#[doc(hidden)] pub extern crate winapi;
+#[cfg(windows)] #[doc(hidden)] pub extern crate abistr;
+#[cfg(windows)] #[doc(hidden)] pub extern crate ialloc;
+#[cfg(windows)] #[doc(hidden)] pub extern crate winapi;
 #[cfg(alloc)]  extern crate alloc as alloc_;
 
 #[cfg(doc)] pub use _doc::*;
 #[cfg(doc)] mod _doc {
     use crate::prelude::*;
-    #[doc = include_str!(r"..\doc\environment.md"               )] pub mod _environment {}
+    #[doc = include_str!("../doc/environment.md"               )] pub mod _environment {}
 }
 
-#[path = r"macros\_macros.rs"] #[macro_use] mod macros;
-#[path = r"access\_access.rs"]              pub mod access;         //#[doc(no_inline)] #[doc(hidden)] pub use access::constants::*;
-#[path = r"ace\_ace.rs"]                    pub mod ace;            //#[doc(no_inline)] #[doc(hidden)] pub use ace::funcs::*;
-#[path = r"acl\_acl.rs"]                    pub mod acl;            //#[doc(no_inline)] #[doc(hidden)] pub use acl::funcs::*;
-#[path = r"alloc\_alloc.rs"]                pub mod alloc;          #[doc(no_inline)] #[doc(hidden)] pub use alloc::funcs::*;
-#[path = r"appcontainer\_appcontainer.rs"]  pub mod appcontainer;   #[doc(no_inline)] #[doc(hidden)] pub use appcontainer::funcs::*;
-#[path = r"capability\_capability.rs"]      pub mod capability;
-#[path = r"debug\_debug.rs"]                pub mod debug;          #[doc(no_inline)] #[doc(hidden)] pub use debug::funcs::*;
-#[path = r"desktop\_desktop.rs"]            pub mod desktop;        #[doc(no_inline)] #[doc(hidden)] pub use desktop::funcs::*;
-#[path = r"dlls\_dlls.rs"]                  mod dlls;               pub(crate) use dlls::*;
-#[path = r"error\_error.rs"]                pub mod error;          #[doc(no_inline)] #[doc(hidden)] pub use error::*; pub use error::{Error, Result};
-#[path = r"file\_file.rs"]                  pub mod file;           #[doc(no_inline)] #[doc(hidden)] pub use file::funcs::*;
-#[path = r"handle\_handle.rs"]              pub mod handle;         #[doc(no_inline)] #[doc(hidden)] pub use handle::{funcs::*, traits::*};
-#[path = r"io\_io.rs"]                      pub mod io;             #[doc(no_inline)] #[doc(hidden)] #[allow(unused_imports)] pub use io::funcs::*;
-#[path = r"job\_job.rs"]                    pub mod job;            #[doc(no_inline)] #[doc(hidden)] pub use job::funcs::*;
-#[path = r"time\_time.rs"]                  pub mod time;           #[doc(no_inline)] #[doc(hidden)] pub use time::funcs::*;
-#[path = r"limit\_limit.rs"]                pub(crate) mod limit;
-#[path = r"os\_os.rs"]                      pub(crate) mod os;
-#[path = r"pipe\_pipe.rs"]                  pub mod pipe;           #[doc(no_inline)] #[doc(hidden)] pub use pipe::NMPWAIT;
-#[path = r"prelude\_prelude.rs"]            pub mod prelude;
-#[path = r"privilege\_privilege.rs"]        pub mod privilege;      #[doc(no_inline)] #[doc(hidden)] pub use privilege::funcs::*;
-#[path = r"process\_process.rs"]            pub mod process;        #[doc(no_inline)] #[doc(hidden)] pub use process::funcs::*;
-#[path = r"pseudoconsole\_pseudoconsole.rs"]pub mod pseudoconsole;  #[doc(no_inline)] #[doc(hidden)] pub use pseudoconsole::funcs::*;
-#[path = r"security\_security.rs"]          pub mod security;       //#[doc(no_inline)] #[doc(hidden)] pub use security::funcs::*;
-#[path = r"sid\_sid.rs"]                    pub mod sid;            #[doc(no_inline)] #[doc(hidden)] pub use sid::funcs::*;
-#[path = r"string\_string.rs"]              pub mod string;         //#[doc(no_inline)] #[doc(hidden)] pub use string::funcs::*;
-#[path = r"thread\_thread.rs"]              pub mod thread;         #[doc(no_inline)] #[doc(hidden)] pub use thread::funcs::*;
-#[path = r"token\_token.rs"]                pub mod token;          #[doc(no_inline)] #[doc(hidden)] pub use token::funcs::*;
-#[path = r"type_check\_type_check.rs"]      pub(crate) mod type_check;
-#[path = r"util\_util.rs"]                  mod util;               pub(crate) use util::*;
-#[path = r"values\_values.rs"]              pub mod values;         #[doc(no_inline)] #[doc(hidden)] pub use values::*;
-#[path = r"volume\_volume.rs"]              pub mod volume;
-#[path = r"winsta\_winsta.rs"]              pub mod winsta;         #[doc(no_inline)] #[doc(hidden)] pub use winsta::funcs::*;
+                #[path = "macros/_macros.rs"] #[macro_use] mod macros;
+                #[path = "access/_access.rs"]              pub mod access;         //#[doc(no_inline)] #[doc(hidden)] pub use access::constants::*;
+                #[path = "ace/_ace.rs"]                    pub mod ace;            //#[doc(no_inline)] #[doc(hidden)] pub use ace::funcs::*;
+                #[path = "acl/_acl.rs"]                    pub mod acl;            //#[doc(no_inline)] #[doc(hidden)] pub use acl::funcs::*;
+                #[path = "alloc/_alloc.rs"]                pub mod alloc;          #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use alloc::funcs::*;
+                #[path = "appcontainer/_appcontainer.rs"]  pub mod appcontainer;   #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use appcontainer::funcs::*;
+#[cfg(std)]     #[path = "broker/_broker.rs"]              pub mod broker;
+                #[path = "capability/_capability.rs"]      pub mod capability;
+                #[path = "claim/_claim.rs"]                pub mod claim;
+#[cfg(windows)] #[path = "debug/_debug.rs"]                pub mod debug;          #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use debug::funcs::*;
+#[cfg(windows)] #[path = "desktop/_desktop.rs"]            pub mod desktop;        #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use desktop::funcs::*;
+#[cfg(windows)] #[path = "dlls/_dlls.rs"]                  mod dlls;               #[cfg(windows)] pub(crate) use dlls::*;
+                #[path = "error/_error.rs"]                pub mod error;          #[doc(no_inline)] #[doc(hidden)] pub use error::*; pub use error::{Error, Result};
+#[cfg(windows)] #[path = "file/_file.rs"]                  pub mod file;           #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use file::funcs::*;
+#[cfg(windows)] #[path = "handle/_handle.rs"]              pub mod handle;         #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use handle::{funcs::*, traits::*};
+#[cfg(windows)] #[path = "io/_io.rs"]                      pub mod io;             #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] #[allow(unused_imports)] pub use io::funcs::*;
+#[cfg(windows)] #[path = "job/_job.rs"]                    pub mod job;            #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use job::funcs::*;
+#[cfg(windows)] #[path = "time/_time.rs"]                  pub mod time;           #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use time::funcs::*;
+#[cfg(windows)] #[path = "limit/_limit.rs"]                pub(crate) mod limit;
+                #[path = "mitigation/_mitigation.rs"]      pub mod mitigation;
+#[cfg(windows)] #[path = "os/_os.rs"]                      pub(crate) mod os;
+#[cfg(windows)] #[path = "pipe/_pipe.rs"]                  pub mod pipe;           #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use pipe::NMPWAIT;
+                #[path = "prelude/_prelude.rs"]            pub mod prelude;
+                #[path = "privilege/_privilege.rs"]        pub mod privilege;      #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use privilege::funcs::*;
+#[cfg(windows)] #[path = "process/_process.rs"]            pub mod process;        #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use process::funcs::*;
+#[cfg(windows)] #[path = "pseudoconsole/_pseudoconsole.rs"]pub mod pseudoconsole;  #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use pseudoconsole::funcs::*;
+#[cfg(alloc)]   #[path = "sandbox/_sandbox.rs"]            pub mod sandbox;
+                #[path = "security/_security.rs"]          pub mod security;       //#[doc(no_inline)] #[doc(hidden)] pub use security::funcs::*;
+                #[path = "sid/_sid.rs"]                    pub mod sid;            #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use sid::funcs::*;
+#[cfg(windows)] #[path = "string/_string.rs"]              pub mod string;         //#[doc(no_inline)] #[doc(hidden)] pub use string::funcs::*;
+#[cfg(windows)] #[path = "thread/_thread.rs"]              pub mod thread;         #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use thread::funcs::*;
+                #[path = "token/_token.rs"]                pub mod token;          #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use token::funcs::*;
+#[cfg(windows)] #[path = "type_check/_type_check.rs"]      pub(crate) mod type_check;
+                #[path = "util/_util.rs"]                  mod util;               pub(crate) use util::*;
+#[cfg(windows)] #[path = "values/_values.rs"]              pub mod values;         #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use values::*;
+#[cfg(windows)] #[path = "volume/_volume.rs"]              pub mod volume;
+#[cfg(windows)] #[path = "winsta/_winsta.rs"]              pub mod winsta;         #[cfg(windows)] #[doc(no_inline)] #[doc(hidden)] pub use winsta::funcs::*;
+#[cfg(not(windows))] #[path = "winapi/_winapi.rs"] pub(crate) mod winapi;
@@ -1,4 +1,4 @@
 #[macro_use] mod macros_flags;
-#[macro_use] mod macros_handles;
+#[cfg(windows)] #[macro_use] mod macros_handles;
 #[macro_use] mod macros_structures;
-#[macro_use] mod macros_tests;
+#[cfg(windows)] #[macro_use] mod macros_tests;
@@ -0,0 +1,48 @@
+//! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessmitigationpolicy)\]
+//! Portable process mitigation [`Policy`] [`Report`]s and named [`MitigationPolicySetting`]s
+//!
+//! Reports can be built, rendered, and diffed on any platform (e.g. when analyzing a log from a sandboxed child.)
+//! [`process::mitigation`](crate::process::mitigation) re-exports these, and adds `Report::collect` on windows.
+//! [`process::creation`](crate::process::creation) re-exports the settings, which also name [`sandbox::Mitigation`](crate::sandbox::Mitigation)s.
+
+#[allow(unused_imports)] use crate::prelude::*;
+
+mod mitigation_report;              pub use mitigation_report::*;
+mod mitigation_setting;             pub use mitigation_setting::*;
+
+
+
+#[doc(alias = "PROCESS_MITIGATION_POLICY")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-process_mitigation_policy)\]
+/// PROCESS_MITIGATION_POLICY
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive] #[repr(u32)] pub enum Policy {
+    // C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
+    #[doc(alias = "ProcessDEPPolicy"                    )] #[doc = "ProcessDEPPolicy"                   ] DEPPolicy,
+    #[doc(alias = "ProcessASLRPolicy"                   )] #[doc = "ProcessASLRPolicy"                  ] ASLRPolicy,
+    #[doc(alias = "ProcessDynamicCodePolicy"            )] #[doc = "ProcessDynamicCodePolicy"           ] DynamicCodePolicy,
+    #[doc(alias = "ProcessStrictHandleCheckPolicy"      )] #[doc = "ProcessStrictHandleCheckPolicy"     ] StrictHandleCheckPolicy,
+    #[doc(alias = "ProcessSystemCallDisablePolicy"      )] #[doc = "ProcessSystemCallDisablePolicy"     ] SystemCallDisablePolicy,
+    #[doc(alias = "ProcessMitigationOptionsMask"        )] #[doc = "ProcessMitigationOptionsMask"       ] MitigationOptionsMask,
+    #[doc(alias = "ProcessExtensionPointDisablePolicy"  )] #[doc = "ProcessExtensionPointDisablePolicy" ] ExtensionPointDisablePolicy,
+    #[doc(alias = "ProcessControlFlowGuardPolicy"       )] #[doc = "ProcessControlFlowGuardPolicy"      ] ControlFlowGuardPolicy,
+    #[doc(alias = "ProcessSignaturePolicy"              )] #[doc = "ProcessSignaturePolicy"             ] SignaturePolicy,
+    #[doc(alias = "ProcessFontDisablePolicy"            )] #[doc = "ProcessFontDisablePolicy"           ] FontDisablePolicy,
+    #[doc(alias = "ProcessImageLoadPolicy"              )] #[doc = "ProcessImageLoadPolicy"             ] ImageLoadPolicy,
+    #[doc(alias = "ProcessSystemCallFilterPolicy"       )] #[doc = "ProcessSystemCallFilterPolicy"      ] SystemCallFilterPolicy,
+    #[doc(alias = "ProcessPayloadRestrictionPolicy"     )] #[doc = "ProcessPayloadRestrictionPolicy"    ] PayloadRestrictionPolicy,
+    #[doc(alias = "ProcessChildProcessPolicy"           )] #[doc = "ProcessChildProcessPolicy"          ] ChildProcessPolicy,
+    #[doc(alias = "ProcessSideChannelIsolationPolicy"   )] #[doc = "ProcessSideChannelIsolationPolicy"  ] SideChannelIsolationPolicy,
+    #[doc(alias = "ProcessUserShadowStackPolicy"        )] #[doc = "ProcessUserShadowStackPolicy"       ] UserShadowStackPolicy,
+    #[doc(alias = "ProcessRedirectionTrustPolicy"       )] #[doc = "ProcessRedirectionTrustPolicy"      ] RedirectionTrustPolicy,
+    #[doc(alias = "ProcessUserPointerAuthPolicy"        )] #[doc = "ProcessUserPointerAuthPolicy"       ] UserPointerAuthPolicy,
+    #[doc(alias = "ProcessSEHOPPolicy"                  )] #[doc = "ProcessSEHOPPolicy"                 ] SEHOPPolicy,
+    //MaxProcessMitigationPolicy
+}
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-process_mitigation_policy)\]
+/// MaxProcessMitigationPolicy
+///
+#[allow(non_upper_case_globals)]
+pub const MaxProcessMitigationPolicy : u32 = Policy::SEHOPPolicy as u32 + 1;
@@ -0,0 +1,362 @@
+use super::*;
+#[allow(unused_imports)] use crate::prelude::*;
+use core::fmt::{self, Debug, Display, Formatter};
+
+
+
+#[doc(alias = "GetProcessMitigationPolicy")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessmitigationpolicy)\]
+/// Every PROCESS_MITIGATION_\* [`Policy`] of a process, as raw flags
+///
+/// *   `Report::collect` queries a live process (windows only.)
+/// *   [`Report::new`] + [`Report::set`] builds one from raw values (e.g. loaded from a log, or received from a sandboxed child) without touching any process.
+///
+/// Formatting with `{}` renders aligned, human readable text (one `Policy.Field = value` line per bitfield.)
+/// [`Report::entries`] and [`Report::key_values`] provide the same data in a structured / machine readable form.
+/// [`Report::diff`] lists every bitfield that differs between two reports (e.g. the broker's and the sandboxed child's.)
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// # use mitigation::*;
+/// let mut broker = Report::new();
+/// broker.set(Policy::ChildProcessPolicy, 0b000);
+/// println!("{broker}");
+///
+/// let mut child = broker;
+/// child.set(Policy::ChildProcessPolicy, 0b001); // NoChildProcessCreation
+/// let diff = broker.diff(&child).map(|d| d.to_string()).collect::<Vec<_>>();
+/// assert_eq!(diff, ["ChildProcessPolicy.NoChildProcessCreation: 0 -> 1"]);
+/// ```
+///
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Report {
+    /// Indexed by [`Policy`].  `[Flags, 0]` for most policies, `[Flags, Permanent]` for [`DEPPolicy`](Policy::DEPPolicy), `[mask1, mask2]` for [`MitigationOptionsMask`](Policy::MitigationOptionsMask).
+    raw: [Option<[u64; 2]>; MaxProcessMitigationPolicy as usize],
+}
+
+impl Report {
+    /// An empty report, with every policy unavailable.
+    pub fn new() -> Self { Self::default() }
+
+    /// Every [`Policy`], in order.
+    #[cfg(windows)] pub(crate) fn policies() -> impl Iterator<Item = Policy> { POLICIES.iter().map(|&(policy, _)| policy) }
+
+    /// Set both raw words of `policy` (`[Flags, 0]`, `[Flags, Permanent]`, or `[mask1, mask2]`), marking it available.
+    #[cfg(windows)] pub(crate) fn set_raw(&mut self, policy: Policy, raw: [u64; 2]) -> &mut Self {
+        self.raw[policy as usize] = Some(raw);
+        self
+    }
+
+    /// Set the raw `Flags` of `policy`, marking it available.
+    ///
+    /// For [`MitigationOptionsMask`](Policy::MitigationOptionsMask), this sets the first mask - prefer `Report::set_options_mask` on windows.
+    pub fn set(&mut self, policy: Policy, flags: u32) -> &mut Self {
+        let raw = self.raw[policy as usize].get_or_insert([0, 0]);
+        raw[0] = flags.into();
+        self
+    }
+
+    /// Set `PROCESS_MITIGATION_DEP_POLICY::Permanent`, marking [`DEPPolicy`](Policy::DEPPolicy) available.
+    pub fn set_dep_permanent(&mut self, permanent: bool) -> &mut Self {
+        let raw = self.raw[Policy::DEPPolicy as usize].get_or_insert([0, 0]);
+        raw[1] = permanent.into();
+        self
+    }
+
+    /// The raw `Flags` of `policy`, or [`None`] if unavailable.
+    pub fn flags(&self, policy: Policy) -> Option<u32> { self.raw[policy as usize].map(|raw| raw[0] as u32) }
+
+    /// A single bitfield (e.g. `report.get(process::ASLRPolicy, "EnableHighEntropy")`), or [`None`] if the policy is unavailable or has no such field.
+    pub fn get(&self, policy: Policy, field: &str) -> Option<u64> { Field::all().find(|f| f.policy == policy && f.name == field)?.get(self) }
+
+    /// Every bitfield of every available policy, in [`Policy`] order.
+    ///
+    /// Unknown bits are reported as a `ReservedFlags` field, but only when nonzero.
+    pub fn entries(&self) -> impl Iterator<Item = ReportEntry> + '_ {
+        Field::all().filter_map(|field| {
+            let value = field.get(self)?;
+            if field.name == RESERVED && value == 0 { return None }
+            Some(ReportEntry { policy: field.policy, field: field.name, value })
+        })
+    }
+
+    /// [`Report::entries`] as `Policy.Field=value` lines, for logs and other tools.
+    pub fn key_values(&self) -> impl Display + '_ { KeyValues(self) }
+
+    /// Every bitfield that differs between `self` and `other`, including policies available in only one of the reports.
+    ///
+    /// Like [`Report::entries`], `ReservedFlags` are only included when nonzero in either report.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = ReportDiff> + 'a {
+        Field::all().filter_map(move |field| {
+            let (left, right) = (field.get(self), field.get(other));
+            if left == right { return None }
+            if field.name == RESERVED && left.unwrap_or(0) == 0 && right.unwrap_or(0) == 0 { return None }
+            Some(ReportDiff { policy: field.policy, field: field.name, left, right })
+        })
+    }
+}
+
+impl Debug for Report {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let mut list = fmt.debug_list();
+        for entry in self.entries() { list.entry(&format_args!("{entry}")); }
+        list.finish()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let width = Field::all().map(|f| policy_name(f.policy).len() + 1 + f.name.len()).max().unwrap_or(0);
+        for &(policy, name) in POLICIES {
+            if self.raw[policy as usize].is_none() {
+                writeln!(fmt, "{name:width$}   (unavailable)")?;
+                continue;
+            }
+            for entry in self.entries().filter(|e| e.policy == policy) {
+                let key_width = width - name.len() - 1;
+                writeln!(fmt, "{name}.{:key_width$} = {}", entry.field, Value(entry.field, Some(entry.value)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+/// A single bitfield of a [`Report`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReportEntry {
+    pub policy: Policy,
+    /// e.g. `"EnableBottomUpRandomization"`, matching the `winnt.h` bitfield name.
+    pub field:  &'static str,
+    pub value:  u64,
+}
+
+/// `Policy.Field=value`
+impl Display for ReportEntry {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "{}.{}={}", policy_name(self.policy), self.field, Value(self.field, Some(self.value))) }
+}
+
+/// A single bitfield that differs between two [`Report`]s
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReportDiff {
+    pub policy: Policy,
+    pub field:  &'static str,
+    /// [`None`] if the policy was unavailable.
+    pub left:   Option<u64>,
+    /// [`None`] if the policy was unavailable.
+    pub right:  Option<u64>,
+}
+
+/// `Policy.Field: left -> right`
+impl Display for ReportDiff {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "{}.{}: {} -> {}", policy_name(self.policy), self.field, Value(self.field, self.left), Value(self.field, self.right)) }
+}
+
+
+
+struct KeyValues<'a>(&'a Report);
+
+impl Display for KeyValues<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        for entry in self.0.entries() { writeln!(fmt, "{entry}")? }
+        Ok(())
+    }
+}
+
+struct Value(&'static str, Option<u64>);
+
+impl Display for Value {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match *self {
+            Value(_, None)                                  => write!(fmt, "-"),
+            Value(RESERVED | "Mask1" | "Mask2", Some(v))    => write!(fmt, "0x{v:X}"),
+            Value(_, Some(v))                               => write!(fmt, "{v}"),
+        }
+    }
+}
+
+const RESERVED : &str = "ReservedFlags";
+
+#[derive(Clone, Copy)] struct Field {
+    policy: Policy,
+    name:   &'static str,
+    word:   usize,
+    shift:  u32,
+    bits:   u32,
+}
+
+impl Field {
+    const fn flag(policy: Policy, name: &'static str, shift: u32) -> Self { Self { policy, name, word: 0, shift, bits: 1 } }
+
+    /// [`FIELDS`], with a `ReservedFlags` pseudo-field after each `Flags` policy.
+    fn all() -> impl Iterator<Item = Field> {
+        POLICIES.iter().flat_map(|&(policy, _)| {
+            let fields = FIELDS.iter().copied().filter(move |f| f.policy == policy);
+            let reserved = (policy != Policy::MitigationOptionsMask).then_some(Field { policy, name: RESERVED, word: 0, shift: 0, bits: 32 });
+            fields.chain(reserved)
+        })
+    }
+
+    fn get(&self, report: &Report) -> Option<u64> {
+        let raw = report.raw[self.policy as usize]?[self.word];
+        if self.name == RESERVED {
+            let known = FIELDS.iter().filter(|f| f.policy == self.policy && f.word == 0).fold(0u64, |known, f| known | (((1u64 << f.bits) - 1) << f.shift));
+            return Some(raw & !known & u64::from(u32::MAX));
+        }
+        let mask = if self.bits >= 64 { !0 } else { (1u64 << self.bits) - 1 };
+        Some((raw >> self.shift) & mask)
+    }
+}
+
+fn policy_name(policy: Policy) -> &'static str { POLICIES.iter().find(|(p, _)| *p == policy).map_or("?", |(_, name)| name) }
+
+use Policy::*;
+
+const POLICIES : &[(Policy, &str)] = &[
+    (DEPPolicy,                     "DEPPolicy"                     ),
+    (ASLRPolicy,                    "ASLRPolicy"                    ),
+    (DynamicCodePolicy,             "DynamicCodePolicy"             ),
+    (StrictHandleCheckPolicy,       "StrictHandleCheckPolicy"       ),
+    (SystemCallDisablePolicy,       "SystemCallDisablePolicy"       ),
+    (MitigationOptionsMask,         "MitigationOptionsMask"         ),
+    (ExtensionPointDisablePolicy,   "ExtensionPointDisablePolicy"   ),
+    (ControlFlowGuardPolicy,        "ControlFlowGuardPolicy"        ),
+    (SignaturePolicy,               "SignaturePolicy"               ),
+    (FontDisablePolicy,             "FontDisablePolicy"             ),
+    (ImageLoadPolicy,               "ImageLoadPolicy"               ),
+    (SystemCallFilterPolicy,        "SystemCallFilterPolicy"        ),
+    (PayloadRestrictionPolicy,      "PayloadRestrictionPolicy"      ),
+    (ChildProcessPolicy,            "ChildProcessPolicy"            ),
+    (SideChannelIsolationPolicy,    "SideChannelIsolationPolicy"    ),
+    (UserShadowStackPolicy,         "UserShadowStackPolicy"         ),
+    (RedirectionTrustPolicy,        "RedirectionTrustPolicy"        ),
+    (UserPointerAuthPolicy,         "UserPointerAuthPolicy"         ),
+    (SEHOPPolicy,                   "SEHOPPolicy"                   ),
+];
+
+// C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
+const FIELDS : &[Field] = &[
+    Field::flag(DEPPolicy,                      "Enable",                                   0),
+    Field::flag(DEPPolicy,                      "DisableAtlThunkEmulation",                 1),
+    Field { policy: DEPPolicy,                  name: "Permanent",                          word: 1, shift: 0, bits: 8 },
+    Field::flag(ASLRPolicy,                     "EnableBottomUpRandomization",              0),
+    Field::flag(ASLRPolicy,                     "EnableForceRelocateImages",                1),
+    Field::flag(ASLRPolicy,                     "EnableHighEntropy",                        2),
+    Field::flag(ASLRPolicy,                     "DisallowStrippedImages",                   3),
+    Field::flag(DynamicCodePolicy,              "ProhibitDynamicCode",                      0),
+    Field::flag(DynamicCodePolicy,              "AllowThreadOptOut",                        1),
+    Field::flag(DynamicCodePolicy,              "AllowRemoteDowngrade",                     2),
+    Field::flag(DynamicCodePolicy,              "AuditProhibitDynamicCode",                 3),
+    Field::flag(StrictHandleCheckPolicy,        "RaiseExceptionOnInvalidHandleReference",   0),
+    Field::flag(StrictHandleCheckPolicy,        "HandleExceptionsPermanentlyEnabled",       1),
+    Field::flag(SystemCallDisablePolicy,        "DisallowWin32kSystemCalls",                0),
+    Field::flag(SystemCallDisablePolicy,        "AuditDisallowWin32kSystemCalls",           1),
+    Field::flag(SystemCallDisablePolicy,        "DisallowFsctlSystemCalls",                 2),
+    Field::flag(SystemCallDisablePolicy,        "AuditDisallowFsctlSystemCalls",            3),
+    Field { policy: MitigationOptionsMask,      name: "Mask1",                              word: 0, shift: 0, bits: 64 },
+    Field { policy: MitigationOptionsMask,      name: "Mask2",                              word: 1, shift: 0, bits: 64 },
+    Field::flag(ExtensionPointDisablePolicy,    "DisableExtensionPoints",                   0),
+    Field::flag(ControlFlowGuardPolicy,         "EnableControlFlowGuard",                   0),
+    Field::flag(ControlFlowGuardPolicy,         "EnableExportSuppression",                  1),
+    Field::flag(ControlFlowGuardPolicy,         "StrictMode",                               2),
+    Field::flag(ControlFlowGuardPolicy,         "EnableXfg",                                3),
+    Field::flag(ControlFlowGuardPolicy,         "EnableXfgAuditMode",                       4),
+    Field::flag(SignaturePolicy,                "MicrosoftSignedOnly",                      0),
+    Field::flag(SignaturePolicy,                "StoreSignedOnly",                          1),
+    Field::flag(SignaturePolicy,                "MitigationOptIn",                          2),
+    Field::flag(SignaturePolicy,                "AuditMicrosoftSignedOnly",                 3),
+    Field::flag(SignaturePolicy,                "AuditStoreSignedOnly",                     4),
+    Field::flag(FontDisablePolicy,              "DisableNonSystemFonts",                    0),
+    Field::flag(FontDisablePolicy,              "AuditNonSystemFontLoading",                1),
+    Field::flag(ImageLoadPolicy,                "NoRemoteImages",                           0),
+    Field::flag(ImageLoadPolicy,                "NoLowMandatoryLabelImages",                1),
+    Field::flag(ImageLoadPolicy,                "PreferSystem32Images",                     2),
+    Field::flag(ImageLoadPolicy,                "AuditNoRemoteImages",                      3),
+    Field::flag(ImageLoadPolicy,                "AuditNoLowMandatoryLabelImages",           4),
+    Field { policy: SystemCallFilterPolicy,     name: "FilterId",                           word: 0, shift: 0, bits: 4 },
+    Field::flag(PayloadRestrictionPolicy,       "EnableExportAddressFilter",                0),
+    Field::flag(PayloadRestrictionPolicy,       "AuditExportAddressFilter",                 1),
+    Field::flag(PayloadRestrictionPolicy,       "EnableExportAddressFilterPlus",            2),
+    Field::flag(PayloadRestrictionPolicy,       "AuditExportAddressFilterPlus",             3),
+    Field::flag(PayloadRestrictionPolicy,       "EnableImportAddressFilter",                4),
+    Field::flag(PayloadRestrictionPolicy,       "AuditImportAddressFilter",                 5),
+    Field::flag(PayloadRestrictionPolicy,       "EnableRopStackPivot",                      6),
+    Field::flag(PayloadRestrictionPolicy,       "AuditRopStackPivot",                       7),
+    Field::flag(PayloadRestrictionPolicy,       "EnableRopCallerCheck",                     8),
+    Field::flag(PayloadRestrictionPolicy,       "AuditRopCallerCheck",                      9),
+    Field::flag(PayloadRestrictionPolicy,       "EnableRopSimExec",                         10),
+    Field::flag(PayloadRestrictionPolicy,       "AuditRopSimExec",                          11),
+    Field::flag(ChildProcessPolicy,             "NoChildProcessCreation",                   0),
+    Field::flag(ChildProcessPolicy,             "AuditNoChildProcessCreation",              1),
+    Field::flag(ChildProcessPolicy,             "AllowSecureProcessCreation",               2),
+    Field::flag(SideChannelIsolationPolicy,     "SmtBranchTargetIsolation",                 0),
+    Field::flag(SideChannelIsolationPolicy,     "IsolateSecurityDomain",                    1),
+    Field::flag(SideChannelIsolationPolicy,     "DisablePageCombine",                       2),
+    Field::flag(SideChannelIsolationPolicy,     "SpeculativeStoreBypassDisable",            3),
+    Field::flag(SideChannelIsolationPolicy,     "RestrictCoreSharing",                      4),
+    Field::flag(UserShadowStackPolicy,          "EnableUserShadowStack",                    0),
+    Field::flag(UserShadowStackPolicy,          "AuditUserShadowStack",                     1),
+    Field::flag(UserShadowStackPolicy,          "SetContextIpValidation",                   2),
+    Field::flag(UserShadowStackPolicy,          "AuditSetContextIpValidation",              3),
+    Field::flag(UserShadowStackPolicy,          "EnableUserShadowStackStrictMode",          4),
+    Field::flag(UserShadowStackPolicy,          "BlockNonCetBinaries",                      5),
+    Field::flag(UserShadowStackPolicy,          "BlockNonCetBinariesNonEhcont",             6),
+    Field::flag(UserShadowStackPolicy,          "AuditBlockNonCetBinaries",                 7),
+    Field::flag(UserShadowStackPolicy,          "CetDynamicApisOutOfProcOnly",              8),
+    Field::flag(UserShadowStackPolicy,          "SetContextIpValidationRelaxedMode",        9),
+    Field::flag(RedirectionTrustPolicy,         "EnforceRedirectionTrust",                  0),
+    Field::flag(RedirectionTrustPolicy,         "AuditRedirectionTrust",                    1),
+    Field::flag(UserPointerAuthPolicy,          "EnablePointerAuthUserIp",                  0),
+    Field::flag(SEHOPPolicy,                    "EnableSehop",                              0),
+];
+
+
+
+#[cfg(std)] #[test] fn from_raw() {
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    let empty = Report::new();
+    assert_eq!(empty.entries().count(), 0);
+    assert_eq!(empty.key_values().to_string(), "");
+
+    let mut broker = Report::new();
+    broker.set(DEPPolicy, 0b11).set_dep_permanent(true).set(ASLRPolicy, 0b0101).set(SystemCallFilterPolicy, 3);
+    assert_eq!(broker.flags(DEPPolicy), Some(0b11));
+    assert_eq!(broker.flags(SEHOPPolicy), None);
+    assert_eq!(broker.get(ASLRPolicy, "EnableHighEntropy"), Some(1));
+    assert_eq!(broker.get(ASLRPolicy, "Enable"), None);
+    assert_eq!(broker.get(SEHOPPolicy, "EnableSehop"), None);
+    assert_eq!(broker.key_values().to_string(), [
+        "DEPPolicy.Enable=1",
+        "DEPPolicy.DisableAtlThunkEmulation=1",
+        "DEPPolicy.Permanent=1",
+        "ASLRPolicy.EnableBottomUpRandomization=1",
+        "ASLRPolicy.EnableForceRelocateImages=0",
+        "ASLRPolicy.EnableHighEntropy=1",
+        "ASLRPolicy.DisallowStrippedImages=0",
+        "SystemCallFilterPolicy.FilterId=3",
+        "",
+    ].join("\n"));
+
+    let text = broker.to_string();
+    assert!(text.contains("\nASLRPolicy.EnableHighEntropy "), "{text}");
+    assert!(text.contains("\nSEHOPPolicy "), "{text}");
+    assert!(text.lines().all(|line| line.find(" = ").or_else(|| line.find("   (unavailable)")) == text.lines().next().unwrap().find(" = ")), "{text}");
+
+    let mut child = broker;
+    child.set(ASLRPolicy, 0b1_0000_0111).set(SEHOPPolicy, 1);
+    assert_eq!(broker.diff(&broker).count(), 0);
+    assert_eq!(broker.diff(&child).map(|d| d.to_string()).collect::<Vec<_>>(), [
+        "ASLRPolicy.EnableForceRelocateImages: 0 -> 1",
+        "ASLRPolicy.ReservedFlags: 0x0 -> 0x100",
+        "SEHOPPolicy.EnableSehop: - -> 1",
+    ]);
+    assert_eq!(child.diff(&broker).map(|d| d.to_string()).collect::<Vec<_>>(), [
+        "ASLRPolicy.EnableForceRelocateImages: 1 -> 0",
+        "ASLRPolicy.ReservedFlags: 0x100 -> 0x0",
+        "SEHOPPolicy.EnableSehop: 1 -> -",
+    ]);
+}
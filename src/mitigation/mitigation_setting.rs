@@ -0,0 +1,242 @@
+//! Named creation-time mitigation settings, their minimum Windows versions, prerequisites, and runtime [`Report`] bitfields:
+//! shared by [`process::creation::MitigationPolicy::issues`](crate::process::creation::MitigationPolicy::issues), [`sandbox::Profile::validate`](crate::sandbox::Profile::validate), and `Report::expected`.
+
+use super::*;
+#[allow(unused_imports)] use crate::prelude::*;
+#[cfg(windows)] use process::creation::{MitigationPolicy, MitigationPolicyMask};
+use core::fmt::{self, Debug, Display, Formatter};
+
+
+
+/// A Windows version, compared by `major`, `minor`, then `build`
+///
+/// ### Examples
+/// ```
+/// # use firehazard::mitigation::WindowsVersion;
+/// assert!(WindowsVersion::WINDOWS_8_1 < WindowsVersion::WINDOWS_10_1507);
+/// assert_eq!(WindowsVersion::WINDOWS_10_2004.to_string(), "10.0.19041");
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowsVersion {
+    pub major:  u32,
+    pub minor:  u32,
+    pub build:  u32,
+}
+
+impl WindowsVersion {
+    pub const fn new(major: u32, minor: u32, build: u32) -> Self { Self { major, minor, build } }
+
+    pub const WINDOWS_7         : Self = Self::new( 6, 1,  7600);
+    pub const WINDOWS_8         : Self = Self::new( 6, 2,  9200);
+    pub const WINDOWS_8_1       : Self = Self::new( 6, 3,  9600);
+    pub const WINDOWS_10_1507   : Self = Self::new(10, 0, 10240);
+    pub const WINDOWS_10_1511   : Self = Self::new(10, 0, 10586);
+    pub const WINDOWS_10_1607   : Self = Self::new(10, 0, 14393);
+    pub const WINDOWS_10_1703   : Self = Self::new(10, 0, 15063);
+    pub const WINDOWS_10_1709   : Self = Self::new(10, 0, 16299);
+    pub const WINDOWS_10_1803   : Self = Self::new(10, 0, 17134);
+    pub const WINDOWS_10_1809   : Self = Self::new(10, 0, 17763);
+    pub const WINDOWS_10_1903   : Self = Self::new(10, 0, 18362);
+    pub const WINDOWS_10_2004   : Self = Self::new(10, 0, 19041);
+    pub const WINDOWS_11_21H2   : Self = Self::new(10, 0, 22000);
+}
+
+impl Display for WindowsVersion {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "{}.{}.{}", self.major, self.minor, self.build) }
+}
+
+
+
+/// A single named setting of a `process::creation::MitigationPolicy`, the oldest Windows version known to accept it, its prerequisites, and its runtime [`Report`] bitfields
+///
+/// Names are the `mitigation_policy`\[`2`\] module name (for `ALWAYS_ON`), or `module.setting` for other settings.
+/// These are also the names of [`sandbox::Mitigation`](crate::sandbox::Mitigation)s.
+///
+/// Minimum versions are best effort, based on the Windows SDK headers and Microsoft's documentation:
+/// older builds reject unknown flags with `ERROR_INVALID_PARAMETER`, or (for some policies) silently ignore them.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// # use mitigation::*;
+/// let s = MitigationPolicySetting::from_name("cet_user_shadow_stacks.strict_mode").unwrap();
+/// assert_eq!(s.policy_name(), "cet_user_shadow_stacks");
+/// assert_eq!(s.min_os(), WindowsVersion::WINDOWS_10_2004);
+/// # #[cfg(windows)]
+/// assert_eq!(s.value(), process::creation::mitigation_policy2::cet_user_shadow_stacks::STRICT_MODE.into());
+///
+/// let s = MitigationPolicySetting::from_name("block_non_cet_binaries").unwrap();
+/// assert_eq!(s.requires(), ["cet_user_shadow_stacks", "cet_user_shadow_stacks.strict_mode"]);
+/// ```
+///
+#[derive(Clone, Copy)]
+pub struct MitigationPolicySetting {
+    name:   &'static str,
+    min_os: WindowsVersion,
+    report: &'static [ReportEntry],
+    #[cfg(windows)] flags: (MitigationPolicyMask, MitigationPolicy),
+}
+
+impl MitigationPolicySetting {
+    /// Every named setting, in `mitigation_policy` then `mitigation_policy2` order.
+    pub fn all() -> impl Iterator<Item = &'static Self> { SETTINGS.iter() }
+
+    /// Look up a setting by name (case sensitive.)
+    pub fn from_name(name: &str) -> Option<&'static Self> { Self::all().find(|s| s.name == name) }
+
+    /// e.g. `"heap_terminate"` or `"control_flow_guard.export_suppression"`
+    pub fn name(&self) -> &'static str { self.name }
+
+    /// The policy this setting belongs to (the name, minus any `.setting` suffix.)
+    pub fn policy_name(&self) -> &'static str { self.name.split_once('.').map_or(self.name, |(policy, _)| policy) }
+
+    /// `true` for `*.always_off` settings, which can't be combined with other settings of the same policy.
+    pub fn is_always_off(&self) -> bool { self.name.ends_with(".always_off") }
+
+    /// The oldest Windows version known to accept this setting.
+    pub fn min_os(&self) -> WindowsVersion { self.min_os }
+
+    /// The runtime [`Report`] bitfields a process created with this setting should have, or `[]` if this setting has no runtime equivalent
+    /// (e.g. `heap_terminate`, or `dep_atl_thunk_enable`, which is meaningless for 64-bit processes.)
+    pub fn report_entries(&self) -> &'static [ReportEntry] { self.report }
+
+    /// Settings, any one of which must also be set for this setting to have an effect (or be accepted), or `[]` if this setting has no prerequisites.
+    pub fn requires(&self) -> &'static [&'static str] { PREREQUISITES.iter().find(|p| p.settings.contains(&self.name)).map_or(&[], |p| p.requires) }
+
+    /// The bits of the policy this setting belongs to (e.g. `heap_terminate::MASK`.)
+    #[cfg(windows)] pub fn mask(&self) -> MitigationPolicyMask { self.flags.0 }
+
+    /// The bits of this setting (e.g. `heap_terminate::ALWAYS_ON`.)
+    #[cfg(windows)] pub fn value(&self) -> MitigationPolicy { self.flags.1 }
+
+    /// `true` if `policy` selects this setting for its policy.
+    #[cfg(windows)] pub fn is_set_in(&self, policy: MitigationPolicy) -> bool { policy & self.flags.0 == self.flags.1 }
+}
+
+impl Debug for MitigationPolicySetting {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "MitigationPolicySetting({:?})", self.name) }
+}
+
+
+
+struct Prerequisite {
+    /// Any of these settings...
+    settings: &'static [&'static str],
+    /// ...requires any of these settings.
+    requires: &'static [&'static str],
+}
+
+macro_rules! settings { ($( $name:literal $min_os:ident $flags:expr => [$( $policy:ident . $field:ident = $value:literal ),*] ),* $(,)?) => {
+    const SETTINGS : &[MitigationPolicySetting] = &[$(
+        MitigationPolicySetting {
+            name:   $name,
+            min_os: WindowsVersion::$min_os,
+            report: &[$( ReportEntry { policy: Policy::$policy, field: stringify!($field), value: $value } ),*],
+            #[cfg(windows)] flags: $flags,
+        },
+    )*];
+}}
+
+#[cfg(windows)] use process::creation::{mitigation_policy::*, mitigation_policy2::*, setting_flags::*};
+
+settings! {
+    "dep_enable"                                         WINDOWS_7        legacy(DEP_ENABLE)                                                                               => [DEPPolicy.Enable = 1],
+    "dep_atl_thunk_enable"                               WINDOWS_7        legacy(DEP_ATL_THUNK_ENABLE)                                                                     => [],
+    "sehop_enable"                                       WINDOWS_7        legacy(SEHOP_ENABLE)                                                                             => [SEHOPPolicy.EnableSehop = 1],
+    "force_relocate_images"                              WINDOWS_8        one(force_relocate_images::ALWAYS_ON, force_relocate_images::MASK)                               => [ASLRPolicy.EnableForceRelocateImages = 1],
+    "force_relocate_images.always_off"                   WINDOWS_8        one(force_relocate_images::ALWAYS_OFF, force_relocate_images::MASK)                              => [ASLRPolicy.EnableForceRelocateImages = 0],
+    "force_relocate_images.always_on_req_relocs"         WINDOWS_8        one(force_relocate_images::ALWAYS_ON_REQ_RELOCS, force_relocate_images::MASK)                    => [ASLRPolicy.EnableForceRelocateImages = 1, ASLRPolicy.DisallowStrippedImages = 1],
+    "heap_terminate"                                     WINDOWS_8        one(heap_terminate::ALWAYS_ON, heap_terminate::MASK)                                             => [],
+    "heap_terminate.always_off"                          WINDOWS_8        one(heap_terminate::ALWAYS_OFF, heap_terminate::MASK)                                            => [],
+    "bottom_up_aslr"                                     WINDOWS_8        one(bottom_up_aslr::ALWAYS_ON, bottom_up_aslr::MASK)                                             => [ASLRPolicy.EnableBottomUpRandomization = 1],
+    "bottom_up_aslr.always_off"                          WINDOWS_8        one(bottom_up_aslr::ALWAYS_OFF, bottom_up_aslr::MASK)                                            => [ASLRPolicy.EnableBottomUpRandomization = 0],
+    "high_entropy_aslr"                                  WINDOWS_8        one(high_entropy_aslr::ALWAYS_ON, high_entropy_aslr::MASK)                                       => [ASLRPolicy.EnableHighEntropy = 1],
+    "high_entropy_aslr.always_off"                       WINDOWS_8        one(high_entropy_aslr::ALWAYS_OFF, high_entropy_aslr::MASK)                                      => [ASLRPolicy.EnableHighEntropy = 0],
+    "strict_handle_checks"                               WINDOWS_8        one(strict_handle_checks::ALWAYS_ON, strict_handle_checks::MASK)                                 => [StrictHandleCheckPolicy.RaiseExceptionOnInvalidHandleReference = 1, StrictHandleCheckPolicy.HandleExceptionsPermanentlyEnabled = 1],
+    "strict_handle_checks.always_off"                    WINDOWS_8        one(strict_handle_checks::ALWAYS_OFF, strict_handle_checks::MASK)                                => [StrictHandleCheckPolicy.RaiseExceptionOnInvalidHandleReference = 0],
+    "win32k_system_call_disable"                         WINDOWS_8        one(win32k_system_call_disable::ALWAYS_ON, win32k_system_call_disable::MASK)                     => [SystemCallDisablePolicy.DisallowWin32kSystemCalls = 1],
+    "win32k_system_call_disable.always_off"              WINDOWS_8        one(win32k_system_call_disable::ALWAYS_OFF, win32k_system_call_disable::MASK)                    => [SystemCallDisablePolicy.DisallowWin32kSystemCalls = 0],
+    "extension_point_disable"                            WINDOWS_8        one(extension_point_disable::ALWAYS_ON, extension_point_disable::MASK)                           => [ExtensionPointDisablePolicy.DisableExtensionPoints = 1],
+    "extension_point_disable.always_off"                 WINDOWS_8        one(extension_point_disable::ALWAYS_OFF, extension_point_disable::MASK)                          => [ExtensionPointDisablePolicy.DisableExtensionPoints = 0],
+    "prohibit_dynamic_code"                              WINDOWS_8_1      one(prohibit_dynamic_code::ALWAYS_ON, prohibit_dynamic_code::MASK)                               => [DynamicCodePolicy.ProhibitDynamicCode = 1],
+    "prohibit_dynamic_code.always_off"                   WINDOWS_8_1      one(prohibit_dynamic_code::ALWAYS_OFF, prohibit_dynamic_code::MASK)                              => [DynamicCodePolicy.ProhibitDynamicCode = 0],
+    "prohibit_dynamic_code.always_on_allow_opt_out"      WINDOWS_10_1703  one(prohibit_dynamic_code::ALWAYS_ON_ALLOW_OPT_OUT, prohibit_dynamic_code::MASK)                 => [DynamicCodePolicy.ProhibitDynamicCode = 1, DynamicCodePolicy.AllowThreadOptOut = 1],
+    "control_flow_guard"                                 WINDOWS_8_1      one(control_flow_guard::ALWAYS_ON, control_flow_guard::MASK)                                     => [ControlFlowGuardPolicy.EnableControlFlowGuard = 1],
+    "control_flow_guard.always_off"                      WINDOWS_8_1      one(control_flow_guard::ALWAYS_OFF, control_flow_guard::MASK)                                    => [ControlFlowGuardPolicy.EnableControlFlowGuard = 0],
+    "control_flow_guard.export_suppression"              WINDOWS_10_1511  one(control_flow_guard::EXPORT_SUPPRESSION, control_flow_guard::MASK)                            => [ControlFlowGuardPolicy.EnableControlFlowGuard = 1, ControlFlowGuardPolicy.EnableExportSuppression = 1],
+    "block_non_microsoft_binaries"                       WINDOWS_8_1      one(block_non_microsoft_binaries::ALWAYS_ON, block_non_microsoft_binaries::MASK)                 => [SignaturePolicy.MicrosoftSignedOnly = 1],
+    "block_non_microsoft_binaries.always_off"            WINDOWS_8_1      one(block_non_microsoft_binaries::ALWAYS_OFF, block_non_microsoft_binaries::MASK)                => [SignaturePolicy.MicrosoftSignedOnly = 0],
+    "block_non_microsoft_binaries.allow_store"           WINDOWS_10_1507  one(block_non_microsoft_binaries::ALLOW_STORE, block_non_microsoft_binaries::MASK)               => [],
+    "font_disable"                                       WINDOWS_10_1507  one(font_disable::ALWAYS_ON, font_disable::MASK)                                                 => [FontDisablePolicy.DisableNonSystemFonts = 1],
+    "font_disable.always_off"                            WINDOWS_10_1507  one(font_disable::ALWAYS_OFF, font_disable::MASK)                                                => [FontDisablePolicy.DisableNonSystemFonts = 0],
+    "font_disable.audit_nonsystem_fonts"                 WINDOWS_10_1507  one(font_disable::AUDIT_NONSYSTEM_FONTS, font_disable::MASK)                                     => [FontDisablePolicy.AuditNonSystemFontLoading = 1],
+    "image_load_no_remote"                               WINDOWS_10_1511  one(image_load_no_remote::ALWAYS_ON, image_load_no_remote::MASK)                                 => [ImageLoadPolicy.NoRemoteImages = 1],
+    "image_load_no_remote.always_off"                    WINDOWS_10_1511  one(image_load_no_remote::ALWAYS_OFF, image_load_no_remote::MASK)                                => [ImageLoadPolicy.NoRemoteImages = 0],
+    "image_load_no_low_label"                            WINDOWS_10_1511  one(image_load_no_low_label::ALWAYS_ON, image_load_no_low_label::MASK)                           => [ImageLoadPolicy.NoLowMandatoryLabelImages = 1],
+    "image_load_no_low_label.always_off"                 WINDOWS_10_1511  one(image_load_no_low_label::ALWAYS_OFF, image_load_no_low_label::MASK)                          => [ImageLoadPolicy.NoLowMandatoryLabelImages = 0],
+    "image_load_prefer_system32"                         WINDOWS_10_1607  one(image_load_prefer_system32::ALWAYS_ON, image_load_prefer_system32::MASK)                     => [ImageLoadPolicy.PreferSystem32Images = 1],
+    "image_load_prefer_system32.always_off"              WINDOWS_10_1607  one(image_load_prefer_system32::ALWAYS_OFF, image_load_prefer_system32::MASK)                    => [ImageLoadPolicy.PreferSystem32Images = 0],
+
+    "loader_integrity_continuity"                        WINDOWS_10_1703  two(loader_integrity_continuity::ALWAYS_ON, loader_integrity_continuity::MASK)                   => [],
+    "loader_integrity_continuity.always_off"             WINDOWS_10_1703  two(loader_integrity_continuity::ALWAYS_OFF, loader_integrity_continuity::MASK)                  => [],
+    "loader_integrity_continuity.audit"                  WINDOWS_10_1703  two(loader_integrity_continuity::AUDIT, loader_integrity_continuity::MASK)                       => [],
+    "strict_control_flow_guard"                          WINDOWS_10_1703  two(strict_control_flow_guard::ALWAYS_ON, strict_control_flow_guard::MASK)                       => [ControlFlowGuardPolicy.StrictMode = 1],
+    "strict_control_flow_guard.always_off"               WINDOWS_10_1703  two(strict_control_flow_guard::ALWAYS_OFF, strict_control_flow_guard::MASK)                      => [ControlFlowGuardPolicy.StrictMode = 0],
+    "module_tampering_protection"                        WINDOWS_10_1703  two(module_tampering_protection::ALWAYS_ON, module_tampering_protection::MASK)                   => [],
+    "module_tampering_protection.always_off"             WINDOWS_10_1703  two(module_tampering_protection::ALWAYS_OFF, module_tampering_protection::MASK)                  => [],
+    "module_tampering_protection.noinherit"              WINDOWS_10_1703  two(module_tampering_protection::NOINHERIT, module_tampering_protection::MASK)                   => [],
+    "restrict_indirect_branch_prediction"                WINDOWS_10_1709  two(restrict_indirect_branch_prediction::ALWAYS_ON, restrict_indirect_branch_prediction::MASK)   => [],
+    "restrict_indirect_branch_prediction.always_off"     WINDOWS_10_1709  two(restrict_indirect_branch_prediction::ALWAYS_OFF, restrict_indirect_branch_prediction::MASK)  => [],
+    "allow_downgrade_dynamic_code_policy"                WINDOWS_10_1709  two(allow_downgrade_dynamic_code_policy::ALWAYS_ON, allow_downgrade_dynamic_code_policy::MASK)   => [DynamicCodePolicy.AllowRemoteDowngrade = 1],
+    "allow_downgrade_dynamic_code_policy.always_off"     WINDOWS_10_1709  two(allow_downgrade_dynamic_code_policy::ALWAYS_OFF, allow_downgrade_dynamic_code_policy::MASK)  => [DynamicCodePolicy.AllowRemoteDowngrade = 0],
+    "speculative_store_bypass_disable"                   WINDOWS_10_1809  two(speculative_store_bypass_disable::ALWAYS_ON, speculative_store_bypass_disable::MASK)         => [SideChannelIsolationPolicy.SpeculativeStoreBypassDisable = 1],
+    "speculative_store_bypass_disable.always_off"        WINDOWS_10_1809  two(speculative_store_bypass_disable::ALWAYS_OFF, speculative_store_bypass_disable::MASK)        => [SideChannelIsolationPolicy.SpeculativeStoreBypassDisable = 0],
+    "cet_user_shadow_stacks"                             WINDOWS_10_2004  two(cet_user_shadow_stacks::ALWAYS_ON, cet_user_shadow_stacks::MASK)                             => [UserShadowStackPolicy.EnableUserShadowStack = 1],
+    "cet_user_shadow_stacks.always_off"                  WINDOWS_10_2004  two(cet_user_shadow_stacks::ALWAYS_OFF, cet_user_shadow_stacks::MASK)                            => [UserShadowStackPolicy.EnableUserShadowStack = 0],
+    "cet_user_shadow_stacks.strict_mode"                 WINDOWS_10_2004  two(cet_user_shadow_stacks::STRICT_MODE, cet_user_shadow_stacks::MASK)                           => [UserShadowStackPolicy.EnableUserShadowStack = 1, UserShadowStackPolicy.EnableUserShadowStackStrictMode = 1],
+    "user_cet_set_context_ip_validation"                 WINDOWS_10_2004  two(user_cet_set_context_ip_validation::ALWAYS_ON, user_cet_set_context_ip_validation::MASK)     => [UserShadowStackPolicy.SetContextIpValidation = 1],
+    "user_cet_set_context_ip_validation.always_off"      WINDOWS_10_2004  two(user_cet_set_context_ip_validation::ALWAYS_OFF, user_cet_set_context_ip_validation::MASK)    => [UserShadowStackPolicy.SetContextIpValidation = 0],
+    "user_cet_set_context_ip_validation.relaxed_mode"    WINDOWS_10_2004  two(user_cet_set_context_ip_validation::RELAXED_MODE, user_cet_set_context_ip_validation::MASK)  => [UserShadowStackPolicy.SetContextIpValidation = 1, UserShadowStackPolicy.SetContextIpValidationRelaxedMode = 1],
+    "block_non_cet_binaries"                             WINDOWS_10_2004  two(block_non_cet_binaries::ALWAYS_ON, block_non_cet_binaries::MASK)                             => [UserShadowStackPolicy.BlockNonCetBinaries = 1],
+    "block_non_cet_binaries.always_off"                  WINDOWS_10_2004  two(block_non_cet_binaries::ALWAYS_OFF, block_non_cet_binaries::MASK)                            => [UserShadowStackPolicy.BlockNonCetBinaries = 0],
+    "block_non_cet_binaries.non_ehcont"                  WINDOWS_10_2004  two(block_non_cet_binaries::NON_EHCONT, block_non_cet_binaries::MASK)                            => [UserShadowStackPolicy.BlockNonCetBinaries = 1, UserShadowStackPolicy.BlockNonCetBinariesNonEhcont = 1],
+    "xtended_control_flow_guard"                         WINDOWS_10_2004  two(xtended_control_flow_guard::ALWAYS_ON, xtended_control_flow_guard::MASK)                     => [ControlFlowGuardPolicy.EnableXfg = 1],
+    "xtended_control_flow_guard.always_off"              WINDOWS_10_2004  two(xtended_control_flow_guard::ALWAYS_OFF, xtended_control_flow_guard::MASK)                    => [ControlFlowGuardPolicy.EnableXfg = 0],
+    "pointer_auth_user_ip"                               WINDOWS_11_21H2  two(pointer_auth_user_ip::ALWAYS_ON, pointer_auth_user_ip::MASK)                                 => [UserPointerAuthPolicy.EnablePointerAuthUserIp = 1],
+    "pointer_auth_user_ip.always_off"                    WINDOWS_11_21H2  two(pointer_auth_user_ip::ALWAYS_OFF, pointer_auth_user_ip::MASK)                                => [UserPointerAuthPolicy.EnablePointerAuthUserIp = 0],
+    "cet_dynamic_apis_out_of_proc_only"                  WINDOWS_10_2004  two(cet_dynamic_apis_out_of_proc_only::ALWAYS_ON, cet_dynamic_apis_out_of_proc_only::MASK)       => [UserShadowStackPolicy.CetDynamicApisOutOfProcOnly = 1],
+    "cet_dynamic_apis_out_of_proc_only.always_off"       WINDOWS_10_2004  two(cet_dynamic_apis_out_of_proc_only::ALWAYS_OFF, cet_dynamic_apis_out_of_proc_only::MASK)      => [UserShadowStackPolicy.CetDynamicApisOutOfProcOnly = 0],
+    "restrict_core_sharing"                              WINDOWS_11_21H2  two(restrict_core_sharing::ALWAYS_ON, restrict_core_sharing::MASK)                               => [SideChannelIsolationPolicy.RestrictCoreSharing = 1],
+    "restrict_core_sharing.always_off"                   WINDOWS_11_21H2  two(restrict_core_sharing::ALWAYS_OFF, restrict_core_sharing::MASK)                              => [SideChannelIsolationPolicy.RestrictCoreSharing = 0],
+}
+
+const CFG : &[&str] = &["control_flow_guard", "control_flow_guard.export_suppression"];
+const CET : &[&str] = &["cet_user_shadow_stacks", "cet_user_shadow_stacks.strict_mode"];
+const ACG : &[&str] = &["prohibit_dynamic_code", "prohibit_dynamic_code.always_on_allow_opt_out"];
+
+const PREREQUISITES : &[Prerequisite] = &[
+    Prerequisite { settings: &["dep_atl_thunk_enable"],                                                                 requires: &["dep_enable"] },
+    Prerequisite { settings: &["high_entropy_aslr"],                                                                    requires: &["bottom_up_aslr"] },
+    Prerequisite { settings: &["strict_control_flow_guard"],                                                            requires: CFG },
+    Prerequisite { settings: &["xtended_control_flow_guard"],                                                           requires: CFG },
+    Prerequisite { settings: &["allow_downgrade_dynamic_code_policy"],                                                  requires: ACG },
+    Prerequisite { settings: &["user_cet_set_context_ip_validation", "user_cet_set_context_ip_validation.relaxed_mode"], requires: CET },
+    Prerequisite { settings: &["block_non_cet_binaries", "block_non_cet_binaries.non_ehcont"],                          requires: CET },
+    Prerequisite { settings: &["cet_dynamic_apis_out_of_proc_only"],                                                    requires: CET },
+];
+
+
+
+#[cfg(std)] #[test] fn settings() {
+    for s in MitigationPolicySetting::all() {
+        assert_eq!(MitigationPolicySetting::from_name(s.name()).map(|f| f.name()), Some(s.name()));
+        assert!(!s.requires().contains(&s.name()), "{s:?}");
+    }
+    for p in PREREQUISITES { for name in p.settings.iter().chain(p.requires) { assert!(MitigationPolicySetting::from_name(name).is_some(), "{name}") } }
+    for s in MitigationPolicySetting::all() {
+        for e in s.report_entries() { assert!(Report::new().set(e.policy, 0).get(e.policy, e.field).is_some(), "{s:?} {e}") }
+    }
+    assert_eq!(MitigationPolicySetting::from_name("dep_atl_thunk_enable").unwrap().requires(), ["dep_enable"]);
+    assert_eq!(MitigationPolicySetting::from_name("dep_enable").unwrap().requires(), [] as [&str; 0]);
+}
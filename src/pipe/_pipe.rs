@@ -22,6 +22,8 @@
 //! | [`Write`]                         | [`firehazard::pipe::sync::OwnedWriter`]                                       | [`firehazard::pipe::sync::BorrowedWriter`]                              |
 //! | [`Read`] + [`Write`]              | [`firehazard::pipe::sync::OwnedDuplex`]                                       | [`firehazard::pipe::sync::BorrowedDuplex`]                              |
 //! |                                   | **with `FILE_FLAG_OVERLAPPED`:**                                              |
+//! | No I/O traits                     | [`firehazard::handle::Owned`] + [`firehazard::io::overlapped`]                | [`firehazard::handle::Borrowed`] + [`firehazard::io::overlapped`]     |
+//! | `AsyncRead` + `AsyncWrite`        | `firehazard::pipe::futures::{Connected, Duplex}` (`futures` feature)          | <span style="opacity: 25%">N/A</span>                                 |
 //! | [`Read`]                          | <span style="opacity: 25%">N/A</span>                                         | <span style="opacity: 25%">N/A</span>                                 |
 //! | [`Write`]                         | <span style="opacity: 25%">N/A</span>                                         | <span style="opacity: 25%">N/A</span>                                 |
 //! | [`Read`] + [`Write`]              | <span style="opacity: 25%">N/A</span>                                         | <span style="opacity: 25%">N/A</span>                                 |
@@ -54,11 +56,11 @@ use crate::prelude::*;
 /// Anonymous Pipes
 pub mod anonymous {
     use crate::prelude::*;
-    include!(r"anonymous\funcs\create.rs");
+    include!("anonymous/funcs/create.rs");
 }
 pub use anonymous::create;
 
-#[path = r"named\_named.rs"] pub mod named;
+#[path = "named/_named.rs"] pub mod named;
 
 pub use values::*;
 pub(crate) mod values {
@@ -67,6 +69,6 @@ pub(crate) mod values {
     mod pipe_max_instances;     pub use pipe_max_instances::*;
 }
 
-/// <strike style="opacity: 25%">`HANDLE`s to [pipe]s (created with [`file::FLAG_OVERLAPPED`])</strike> NYI
-pub mod overlapped {}
-#[path = r"sync\_sync.rs"] pub mod sync;
+#[cfg(alloc)] #[doc(no_inline)] pub use crate::io::overlapped;
+#[cfg(feature = "futures")] #[path = "futures/_futures.rs"] pub mod futures;
+#[path = "sync/_sync.rs"] pub mod sync;
@@ -0,0 +1,74 @@
+//! Async `HANDLE`s to [pipe]s (created with [`file::FLAG_OVERLAPPED`]), implementing [`AsyncRead`] + [`AsyncWrite`]
+//!
+//! Requires the `futures` cargo feature.
+//! Built on [`io::overlapped`]: every handle is [registered](io::overlapped::Reactor::register) with an [`io::overlapped::Reactor`],
+//! whose background thread wakes tasks as their I/O completes.  No particular executor is required.
+//!
+//! | Async                         | Sync                                  | Role      |
+//! | ------------------------------| --------------------------------------| ----------|
+//! | [`Listener`]                  | [`pipe::named::Listener`]             | Server    |
+//! | [`Connected`]                 | [`pipe::named::Connected`]            | Server    |
+//! | [`Server`]                    | <span style="opacity: 25%">N/A</span> | Server    |
+//! | [`Duplex`]                    | [`pipe::sync::Duplex`]                | Client    |
+//!
+//! ### Quirks: Buffered Writes
+//!
+//! [`AsyncWrite::poll_write`] copies the data into an internal buffer and starts writing it in the background, returning immediately.
+//! Errors from that write are reported by the *next* `poll_write`, or by [`AsyncWrite::poll_flush`] / [`AsyncWrite::poll_close`],
+//! which wait for the background write to finish.  Each `poll_write` is a single `WriteFile`: one message, for [`pipe::TYPE_MESSAGE`] pipes.
+//!
+//! ### Quirks: Dropping
+//!
+//! Dropping a handle with I/O still in flight cancels that I/O, and then *blocks* until the kernel is done with its buffers.
+//! This is typically brief, but happens on whatever thread drops the handle.
+//!
+//! ### Examples
+//! ```
+//! # use firehazard::*;
+//! use futures::{AsyncReadExt, AsyncWriteExt};
+//! use firehazard::pipe::futures::{Duplex, Server, Listener};
+//!
+//! let name    = r"\\.\pipe\local\firehazard-pipe-futures-example";
+//! let reactor = io::overlapped::Reactor::global();
+//! let mut server = Server::new(2, || Listener::create_w(
+//!     reactor, name, pipe::ACCESS_DUPLEX, pipe::TYPE_BYTE | pipe::READMODE_BYTE,
+//!     pipe::UNLIMITED_INSTANCES, 0, 0, None, None,
+//! )).unwrap();
+//!
+//! futures::executor::block_on(async {
+//!     let mut client = Duplex::open_w(reactor, name).unwrap();
+//!     let mut connected = server.accept().await.unwrap();
+//!
+//!     client.write_all(b"ping").await.unwrap();
+//!     client.flush().await.unwrap();
+//!
+//!     let mut buf = [0u8; 4];
+//!     connected.read_exact(&mut buf).await.unwrap();
+//!     assert_eq!(&buf, b"ping");
+//!
+//!     connected.write_all(b"pong").await.unwrap();
+//!     connected.close().await.unwrap();
+//!     client.read_exact(&mut buf).await.unwrap();
+//!     assert_eq!(&buf, b"pong");
+//! });
+//! ```
+//!
+//! [`AsyncRead`]:                  futures_io::AsyncRead
+//! [`AsyncWrite`]:                 futures_io::AsyncWrite
+//! [`AsyncWrite::poll_write`]:     futures_io::AsyncWrite::poll_write
+//! [`AsyncWrite::poll_flush`]:     futures_io::AsyncWrite::poll_flush
+//! [`AsyncWrite::poll_close`]:     futures_io::AsyncWrite::poll_close
+
+use crate::prelude::*;
+use io::overlapped::{Operation, Overlapped, Reactor, Registration};
+
+use std::vec::Vec;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+include!("connected.rs");
+include!("duplex.rs");
+include!("listener.rs");
+include!("server.rs");
+include!("stream.rs");
@@ -0,0 +1,36 @@
+#[doc(alias = "HANDLE")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-createnamedpipew)\]
+/// Async, owned, `FILE_FLAG_OVERLAPPED` server end of a named pipe, connected to a client
+///
+/// ### Alternatives
+/// *   [`pipe::named::Connected`]  &mdash; synchronous
+///
+#[derive(Debug)] pub struct Connected(Stream);
+
+impl Connected {
+    #[doc(alias = "DisconnectNamedPipe")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-disconnectnamedpipe)\]
+    /// DisconnectNamedPipe(self)
+    ///
+    /// Forcibly disconnect the client (discarding any unread data and I/O still in flight) so the instance can [accept](Listener::accept) another.
+    /// Consider [flushing](futures_io::AsyncWrite::poll_flush) first.
+    ///
+    pub fn disconnect(self) -> Result<Listener, (Self, firehazard::Error)> {
+        if let Err(err) = pipe::named::disconnect(&self.0.handle) { return Err((self, err)) }
+        let (handle, registration, overlapped) = self.0.into_parts();
+        Ok(Listener { connect: Some(Op::Idle(overlapped, ())), registration, handle })
+    }
+}
+
+impl futures_io::AsyncRead for Connected {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> { self.get_mut().0.poll_read(cx, buf) }
+}
+
+impl futures_io::AsyncWrite for Connected {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> { self.get_mut().0.poll_write(cx, buf) }
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> { self.get_mut().0.poll_flush(cx) }
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> { self.get_mut().0.poll_flush(cx) }
+}
+
+impl<'a> From<&'a Connected> for handle::Borrowed<'a> { fn from(c: &'a Connected) -> Self { (&c.0.handle).into() } }
+impl<'a> From<&'a Connected> for handle::Pseudo<'a>   { fn from(c: &'a Connected) -> Self { (&c.0.handle).into() } }
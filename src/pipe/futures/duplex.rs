@@ -0,0 +1,47 @@
+#[doc(alias = "HANDLE")]
+/// Async, owned, `FILE_FLAG_OVERLAPPED` readable and writable pipe `HANDLE` (typically the client end of a named pipe)
+///
+/// ### Alternatives
+/// *   [`pipe::sync::Duplex`]      &mdash; synchronous
+///
+#[derive(Debug)] pub struct Duplex(Stream);
+
+impl Duplex {
+    #[doc(alias = "CreateFile")]
+    #[doc(alias = "CreateFileW")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createfilew)\]
+    /// CreateFileW(name, GENERIC_READ | GENERIC_WRITE, 0, nullptr, OPEN_EXISTING, FILE_FLAG_OVERLAPPED, nullptr) + [`Reactor::register`]
+    ///
+    /// Connect to an existing named pipe as a client, as per [`pipe::sync::open_duplex_existing`], and register it with `reactor`.
+    ///
+    pub fn open_w(reactor: &Reactor, name: impl string::InWide) -> firehazard::Result<Self> {
+        let handle = create_file_w(
+            name, access::GENERIC_READ | access::GENERIC_WRITE, file::Share::NONE, None,
+            winapi::um::fileapi::OPEN_EXISTING, file::FLAG_OVERLAPPED, None,
+        )?;
+        unsafe { Self::from_overlapped(reactor, handle) }
+    }
+
+    /// Wrap and register an existing pipe `handle`.
+    ///
+    /// ### Safety
+    /// *   `handle` must have been opened or created with [`file::FLAG_OVERLAPPED`]
+    /// *   `handle` must not already be associated with a [`CompletionPort`](io::overlapped::CompletionPort)
+    pub unsafe fn from_overlapped(reactor: &Reactor, handle: handle::Owned) -> firehazard::Result<Self> {
+        let registration = reactor.register(&handle)?;
+        Ok(Self(Stream::new(handle, registration, Overlapped::new()?, Overlapped::new()?)))
+    }
+}
+
+impl futures_io::AsyncRead for Duplex {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> { self.get_mut().0.poll_read(cx, buf) }
+}
+
+impl futures_io::AsyncWrite for Duplex {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> { self.get_mut().0.poll_write(cx, buf) }
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> { self.get_mut().0.poll_flush(cx) }
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> { self.get_mut().0.poll_flush(cx) }
+}
+
+impl<'a> From<&'a Duplex> for handle::Borrowed<'a> { fn from(d: &'a Duplex) -> Self { (&d.0.handle).into() } }
+impl<'a> From<&'a Duplex> for handle::Pseudo<'a>   { fn from(d: &'a Duplex) -> Self { (&d.0.handle).into() } }
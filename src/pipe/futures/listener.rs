@@ -0,0 +1,106 @@
+#[doc(alias = "HANDLE")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-createnamedpipew)\]
+/// Async, owned, `FILE_FLAG_OVERLAPPED` named pipe instance, waiting for a client to [`accept`](Self::accept)
+///
+/// ### Alternatives
+/// *   [`pipe::named::Listener`]   &mdash; synchronous
+/// *   [`Server`]                  &mdash; accepts from several instances at once
+///
+pub struct Listener {
+    // N.B. field order matters: an in flight connect must be dropped (cancelled + completed) before `handle` is closed.
+    connect:        Option<Op<()>>,
+    registration:   Registration,
+    handle:         handle::Owned,
+}
+
+impl core::fmt::Debug for Listener {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("pipe::futures::Listener").field("handle", &self.handle).field("registration", &self.registration).finish_non_exhaustive()
+    }
+}
+
+impl Listener {
+    #[doc(alias = "CreateNamedPipe")]
+    #[doc(alias = "CreateNamedPipeW")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-createnamedpipew)\]
+    /// CreateNamedPipeW(name, open_mode | FILE_FLAG_OVERLAPPED, ...) + [`Reactor::register`]
+    ///
+    /// Create a named pipe instance, as per [`pipe::named::create_w`], and register it with `reactor`.
+    /// [`file::FLAG_OVERLAPPED`] is always added to `open_mode`.
+    ///
+    pub fn create_w<'a, 'b: 'a>(
+        reactor:                &Reactor,
+        name:                   impl string::InWide,
+        open_mode:              u32,
+        pipe_mode:              u32,
+        max_instances:          impl Into<pipe::MaxInstances>,
+        out_buffer_size:        u32,
+        in_buffer_size:         u32,
+        default_timeout:        impl Into<pipe::NMPWAIT>,
+        security_attributes:    impl Into<Option<&'a security::Attributes<'b>>>,
+    ) -> firehazard::Result<Self> {
+        let handle = handle::Owned::from(pipe::named::create_w(
+            name, open_mode | file::FLAG_OVERLAPPED, pipe_mode, max_instances,
+            out_buffer_size, in_buffer_size, default_timeout, security_attributes,
+        )?);
+        let registration = reactor.register(&handle)?;
+        Ok(Self { connect: Some(Op::Idle(Overlapped::new()?, ())), registration, handle })
+    }
+
+    #[doc(alias = "ConnectNamedPipe")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)\]
+    /// ConnectNamedPipe(self, overlapped)
+    ///
+    /// Wait for a client to connect.
+    ///
+    pub async fn accept(mut self) -> Result<Connected, (Self, firehazard::Error)> {
+        match core::future::poll_fn(|cx| self.poll_accept(cx)).await {
+            Ok(())      => self.into_connected(),
+            Err(err)    => Err((self, err)),
+        }
+    }
+
+    #[doc(alias = "ConnectNamedPipe")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)\]
+    /// ConnectNamedPipe(self, overlapped)
+    ///
+    /// Poll for a client to connect.  Once this returns <code>[Poll::Ready]\([Ok]\(()\)\)</code>, use [`into_connected`](Self::into_connected).
+    /// Clients that connect and disconnect again before being accepted (`ERROR_NO_DATA`) are [disconnected](pipe::named::disconnect) and skipped.
+    ///
+    pub fn poll_accept(&mut self, cx: &mut Context) -> Poll<firehazard::Result<()>> {
+        loop {
+            match self.connect.take().expect("pipe::futures::Listener poisoned by an earlier panic") {
+                Op::Idle(overlapped, ()) => {
+                    // SAFETY: `self.handle` outlives `self.connect`
+                    self.connect = Some(Op::Pending(unsafe { io::overlapped::connect_named_pipe_unbound(self.handle.as_handle_nn(), overlapped) }));
+                },
+                Op::Pending(op) => {
+                    if self.registration.poll_complete(cx, &op).is_pending() {
+                        self.connect = Some(Op::Pending(op));
+                        return Poll::Pending;
+                    }
+                    let completion = op.wait();
+                    self.connect = Some(Op::Idle(completion.overlapped, ()));
+                    match completion.result {
+                        Err(err) if err == ERROR_NO_DATA    => pipe::named::disconnect(&self.handle)?, // and try again
+                        result                              => return Poll::Ready(result),
+                    }
+                },
+            }
+        }
+    }
+
+    /// Convert into a [`Connected`] pipe, after [`poll_accept`](Self::poll_accept) returned <code>[Poll::Ready]\([Ok]\(()\)\)</code>.
+    ///
+    /// ### Panics
+    /// *   If a connect is still in flight (e.g. [`poll_accept`](Self::poll_accept) returned [`Poll::Pending`].)
+    pub fn into_connected(self) -> Result<Connected, (Self, firehazard::Error)> {
+        let write = match Overlapped::new() {
+            Ok(write)   => write,
+            Err(err)    => return Err((self, err)),
+        };
+        let Self { connect, registration, handle } = self;
+        let Some(Op::Idle(read, ())) = connect else { panic!("pipe::futures::Listener::into_connected called while still connecting") };
+        Ok(Connected(Stream::new(handle, registration, read, write)))
+    }
+}
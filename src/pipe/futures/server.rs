@@ -0,0 +1,97 @@
+/// An async accept loop, keeping a configurable number of named pipe [`Listener`] instances waiting for clients
+///
+/// With a single instance, clients that connect while the previous client is being [`accept`](Self::accept)ed
+/// (before the instance is replaced) fail with `ERROR_PIPE_BUSY`.  Keeping several instances pending avoids this.
+///
+/// ### Examples
+/// ```no_run
+/// # use firehazard::*;
+/// use firehazard::pipe::futures::{Server, Listener};
+/// let reactor = io::overlapped::Reactor::global();
+/// let mut server = Server::new(4, || Listener::create_w(
+///     reactor, r"\\.\pipe\local\firehazard-pipe-futures-server", pipe::ACCESS_DUPLEX, pipe::TYPE_MESSAGE | pipe::READMODE_MESSAGE,
+///     pipe::UNLIMITED_INSTANCES, 0, 0, None, None,
+/// )).unwrap();
+///
+/// futures::executor::block_on(async {
+///     loop {
+///         match server.accept().await {
+///             Ok(connected)   => { /* spawn a task to serve `connected` */ },
+///             Err(err)        => eprintln!("accept failed: {err:?}"), // only the failed instance is lost
+///         }
+///     }
+/// });
+/// ```
+///
+pub struct Server<F> {
+    create:     F,
+    instances:  usize,
+    listening:  Vec<Listener>,
+}
+
+impl<F> core::fmt::Debug for Server<F> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("pipe::futures::Server").field("instances", &self.instances).field("listening", &self.listening).finish_non_exhaustive()
+    }
+}
+
+impl<F: FnMut() -> firehazard::Result<Listener>> Server<F> {
+    /// Immediately `create` `instances` listeners (at least 1), so clients can connect before the first [`accept`](Self::accept).
+    pub fn new(instances: usize, create: F) -> firehazard::Result<Self> {
+        let mut server = Self { create, instances: instances.max(1), listening: Vec::new() };
+        server.fill()?;
+        Ok(server)
+    }
+
+    /// The number of pending instances to maintain.
+    pub fn instances(&self) -> usize { self.instances }
+
+    /// Change the number of pending instances to maintain (at least 1.)
+    /// Increases take effect on the next [`accept`](Self::accept).  Decreases take effect as accepted instances go unreplaced.
+    pub fn set_instances(&mut self, instances: usize) { self.instances = instances.max(1) }
+
+    /// Wait for a client to connect to any pending instance, then replace that instance.
+    ///
+    /// ### Errors
+    /// *   Errors `create`ing replacement instances
+    /// *   Errors connecting a specific instance (that instance is dropped, and replaced by the next `accept`)
+    ///
+    pub async fn accept(&mut self) -> firehazard::Result<Connected> {
+        self.fill()?;
+        let listening = &mut self.listening;
+        let (i, result) = core::future::poll_fn(|cx| {
+            for (i, listener) in listening.iter_mut().enumerate() {
+                if let Poll::Ready(result) = listener.poll_accept(cx) { return Poll::Ready((i, result)) }
+            }
+            Poll::Pending
+        }).await;
+        let listener = self.listening.swap_remove(i);
+        result?;
+        let connected = listener.into_connected().map_err(|(_, err)| err)?;
+        let _ = self.fill(); // best effort: errors will be reported by the next accept
+        Ok(connected)
+    }
+
+    /// [`accept`](Self::accept) a client, then capture its identity and check it against `allowlist` before any data is read or written,
+    /// as per [`pipe::named::Listener::accept_verified`].
+    /// Rejected clients are disconnected by closing their instance, which is replaced by the next `accept`.
+    ///
+    /// ### Errors
+    /// *   Errors `accept`ing a client ([`ClientRejected::identity`](pipe::named::ClientRejected::identity) is [`None`])
+    /// *   `ERROR_ACCESS_DENIED` if the client's identity isn't allowed by `allowlist`
+    /// *   Errors [capturing](pipe::named::ClientIdentity::capture) the client's identity
+    ///
+    pub async fn accept_verified(&mut self, allowlist: &pipe::named::ClientAllowlist) -> Result<(Connected, pipe::named::ClientIdentity), pipe::named::ClientRejected> {
+        let connected = self.accept().await.map_err(|error| pipe::named::ClientRejected { identity: None, error })?;
+        match pipe::named::ClientIdentity::capture(&connected.0.handle) {
+            Ok(identity) if allowlist.allows(&identity) => Ok((connected, identity)),
+            Ok(identity)                                => Err(pipe::named::ClientRejected { identity: Some(identity), error: Error(ERROR_ACCESS_DENIED) }),
+            Err(error)                                  => Err(pipe::named::ClientRejected { identity: None, error }),
+        }
+    }
+
+    fn fill(&mut self) -> firehazard::Result<()> {
+        while self.listening.len() < self.instances { self.listening.push((self.create)()?) }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+/// Shared implementation of [`Connected`] and [`Duplex`]: a registered `FILE_FLAG_OVERLAPPED` handle with one read and one write in flight at most
+struct Stream {
+    // N.B. field order matters: in flight operations must be dropped (cancelled + completed) before `handle` is closed.
+    read:           Option<Op<Vec<u8>>>,
+    unread:         core::ops::Range<usize>,
+    write:          Option<Op<Vec<u8>>>,
+    registration:   Registration,
+    handle:         handle::Owned,
+}
+
+/// An [`Overlapped`] + buffer pair that's either available, or owned by an [`Operation`] in flight on the handle stored alongside it
+enum Op<B> {
+    Idle(Overlapped, B),
+    Pending(Operation<'static, B>),
+}
+
+impl Stream {
+    fn new(handle: handle::Owned, registration: Registration, read: Overlapped, write: Overlapped) -> Self {
+        Self {
+            read:           Some(Op::Idle(read, Vec::new())),
+            unread:         0 .. 0,
+            write:          Some(Op::Idle(write, Vec::new())),
+            registration,
+            handle,
+        }
+    }
+
+    /// Cancel any I/O, and return the read [`Overlapped`] for reuse.
+    fn into_parts(self) -> (handle::Owned, Registration, Overlapped) {
+        let Self { read, write, registration, handle, .. } = self;
+        drop(write);
+        let read = match read.expect("pipe::futures stream poisoned by an earlier panic") {
+            Op::Idle(overlapped, _) => overlapped,
+            Op::Pending(op)         => { let _ = op.cancel(); op.wait().overlapped },
+        };
+        (handle, registration, read)
+    }
+
+    fn poll_read(&mut self, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        if buf.is_empty() { return Poll::Ready(Ok(0)) }
+        loop {
+            match self.read.take().expect("pipe::futures stream poisoned by an earlier panic") {
+                Op::Idle(overlapped, mut buffer) => {
+                    if !self.unread.is_empty() {
+                        let n = buf.len().min(self.unread.len());
+                        buf[..n].copy_from_slice(&buffer[self.unread.start..][..n]);
+                        self.unread.start += n;
+                        self.read = Some(Op::Idle(overlapped, buffer));
+                        return Poll::Ready(Ok(n));
+                    }
+                    buffer.resize(buf.len(), 0);
+                    // SAFETY: `self.handle` outlives `self.read`
+                    self.read = Some(Op::Pending(unsafe { io::overlapped::read_unbound(self.handle.as_handle_nn(), overlapped, buffer) }));
+                },
+                Op::Pending(op) => {
+                    if self.registration.poll_complete(cx, &op).is_pending() {
+                        self.read = Some(Op::Pending(op));
+                        return Poll::Pending;
+                    }
+                    let io::overlapped::Completion { result, transferred, overlapped, buffer } = op.wait();
+                    self.read = Some(Op::Idle(overlapped, buffer));
+                    match result {
+                        Ok(())                                      => {},
+                        Err(err) if err == ERROR_MORE_DATA          => {}, // the rest of the message will be read next
+                        Err(err) if err == ERROR_BROKEN_PIPE        => return Poll::Ready(Ok(0)), // EOF
+                        Err(err) if err == ERROR_PIPE_NOT_CONNECTED => return Poll::Ready(Ok(0)), // EOF
+                        Err(err)                                    => return Poll::Ready(Err(err.into())),
+                    }
+                    self.unread = 0 .. usize::from32(transferred);
+                    // an empty message is not EOF: loop to read the next one
+                },
+            }
+        }
+    }
+
+    fn poll_write(&mut self, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        if let Err(err) = core::task::ready!(self.poll_flush(cx)) { return Poll::Ready(Err(err)) }
+        if buf.is_empty() { return Poll::Ready(Ok(0)) }
+        let Some(Op::Idle(overlapped, mut buffer)) = self.write.take() else { unreachable!("poll_flush returned Ready without an idle write") };
+        buffer.clear();
+        buffer.extend_from_slice(buf);
+        // SAFETY: `self.handle` outlives `self.write`
+        self.write = Some(Op::Pending(unsafe { io::overlapped::write_unbound(self.handle.as_handle_nn(), overlapped, buffer) }));
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        match self.write.take().expect("pipe::futures stream poisoned by an earlier panic") {
+            idle @ Op::Idle(..) => {
+                self.write = Some(idle);
+                Poll::Ready(Ok(()))
+            },
+            Op::Pending(op) => {
+                if self.registration.poll_complete(cx, &op).is_pending() {
+                    self.write = Some(Op::Pending(op));
+                    return Poll::Pending;
+                }
+                let completion = op.wait();
+                self.write = Some(Op::Idle(completion.overlapped, completion.buffer));
+                Poll::Ready(completion.result.map_err(io::Error::from))
+            },
+        }
+    }
+}
+
+impl core::fmt::Debug for Stream {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.debug_struct("pipe::futures::Stream").field("handle", &self.handle).field("registration", &self.registration).finish_non_exhaustive()
+    }
+}
@@ -20,6 +20,15 @@
 //!
 //!
 //!
+//! ### Verifying Clients
+//!
+//! Anyone the pipe's DACL allows can connect to it, including other sandboxed processes that merely guessed its name.
+//! [`pipe::named::Listener::accept_verified`] captures each client's [`ClientIdentity`] (process, session, user, integrity level, and AppContainer)
+//! and disconnects clients not matching a [`ClientAllowlist`] before any data is exchanged.
+//! `pipe::futures::Server::accept_verified` does the same for async servers (requires the `futures` cargo feature.)
+//!
+//!
+//!
 //! ### Quirks: Accepting Connections
 //!
 //! Where [`TcpListener::accept`] returns a new handle which is bound to the accepted connection for read and write,
@@ -77,24 +86,28 @@
 
 use crate::prelude::*;
 
-include!(r"funcs\call.rs");
-include!(r"funcs\create.rs");
-include!(r"funcs\connect.rs");
-include!(r"funcs\disconnect.rs");
-include!(r"funcs\get_client_computer_name.rs");
-include!(r"funcs\get_client_process_id.rs");
-include!(r"funcs\get_client_session_id.rs");
-include!(r"funcs\get_handle_state.rs");
-include!(r"funcs\get_info.rs");
-include!(r"funcs\get_server_process_id.rs");
-include!(r"funcs\get_server_session_id.rs");
-include!(r"funcs\impersonate_client.rs");
-include!(r"funcs\peek.rs");
-include!(r"funcs\set_handle_state.rs");
-include!(r"funcs\transact.rs");
-include!(r"funcs\wait.rs");
+include!("funcs/call.rs");
+include!("funcs/create.rs");
+include!("funcs/connect.rs");
+include!("funcs/disconnect.rs");
+include!("funcs/get_client_computer_name.rs");
+include!("funcs/get_client_process_id.rs");
+include!("funcs/get_client_session_id.rs");
+include!("funcs/get_handle_state.rs");
+include!("funcs/get_info.rs");
+include!("funcs/get_server_process_id.rs");
+include!("funcs/get_server_session_id.rs");
+include!("funcs/impersonate_client.rs");
+include!("funcs/peek.rs");
+include!("funcs/set_handle_state.rs");
+include!("funcs/transact.rs");
+include!("funcs/wait.rs");
+
+include!("handles/bytes_reader.rs");
+include!("handles/connected.rs");
+include!("handles/listener.rs");
+include!("handles/message_reader.rs");
 
-include!(r"handles\bytes_reader.rs");
-include!(r"handles\connected.rs");
-include!(r"handles\listener.rs");
-include!(r"handles\message_reader.rs");
+#[cfg(alloc)] include!("client/client_allowlist.rs");
+include!("client/client_identity.rs");
+include!("client/client_rejected.rs");
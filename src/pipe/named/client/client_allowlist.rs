@@ -0,0 +1,104 @@
+/// A set of [`ClientRule`]s: a [`ClientIdentity`] is allowed if it matches *any* rule
+///
+/// An empty allowlist allows no clients.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// # let child_pids = [1234];
+/// use firehazard::pipe::named::{ClientAllowlist, ClientRule};
+/// // Only my own sandboxed children, running at Low integrity (or below)
+/// let allowlist = ClientAllowlist::from([ClientRule {
+///     process_ids:    Some(child_pids.to_vec()),
+///     max_integrity:  Some(sid::integrity::Low),
+///     ..ClientRule::default()
+/// }]);
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientAllowlist {
+    rules: alloc::vec::Vec<ClientRule>,
+}
+
+/// Conditions a [`ClientIdentity`] must *all* meet to match.  [`None`] fields match any client.
+///
+/// The default rule matches every client.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientRule {
+    /// The client's process ID must be one of these (e.g. the process IDs of your own sandboxed child processes.)
+    pub process_ids:    Option<alloc::vec::Vec<process::Id>>,
+    /// The client must be running in this session.
+    pub session_id:     Option<u32>,
+    /// The client's token user must be this SID.
+    pub user:           Option<sid::Owned>,
+    /// The client's integrity level must be this level or lower.
+    pub max_integrity:  Option<sid::integrity::Level>,
+    /// The client must be running in this AppContainer.
+    pub app_container:  Option<sid::Owned>,
+}
+
+impl ClientAllowlist {
+    /// An empty allowlist, which allows no clients until rules are [`push`](Self::push)ed.
+    pub fn new() -> Self { Self::default() }
+
+    /// Allow clients matching `rule`.
+    pub fn push(&mut self, rule: ClientRule) { self.rules.push(rule) }
+
+    /// The rules of this allowlist.
+    pub fn rules(&self) -> &[ClientRule] { &self.rules }
+
+    /// `true` if `client` matches any rule.
+    pub fn allows(&self, client: &ClientIdentity) -> bool { self.rules.iter().any(|rule| rule.matches(client)) }
+}
+
+impl<I: IntoIterator<Item = ClientRule>> From<I> for ClientAllowlist {
+    fn from(rules: I) -> Self { Self { rules: rules.into_iter().collect() } }
+}
+
+impl ClientRule {
+    /// `true` if `client` meets every condition of this rule.
+    pub fn matches(&self, client: &ClientIdentity) -> bool {
+        self.process_ids    .as_ref().is_none_or(|pids| pids.contains(&client.process_id))
+        && self.session_id  .is_none_or(|session| session == client.session_id)
+        && self.user        .as_ref().is_none_or(|user| *user == client.user)
+        && self.max_integrity.is_none_or(|max| client.integrity <= max)
+        && self.app_container.as_ref().is_none_or(|app_container| Some(app_container) == client.app_container.as_ref())
+    }
+}
+
+
+
+#[cfg(std)] #[test] fn client_allowlist() {
+    let parse       = |s: &str| s.parse::<sid::Owned>().unwrap();
+    let alice       = parse("S-1-5-21-1-2-3-1001");
+    let bob         = parse("S-1-5-21-1-2-3-1002");
+    let container   = parse("S-1-15-2-1-2-3-4-5-6-7");
+    let child       = ClientIdentity { process_id: 1234, session_id: 1, user: alice, integrity: sid::integrity::Low, app_container: None };
+
+    assert!(!ClientAllowlist::new().allows(&child), "an empty allowlist allows no clients");
+    assert!( ClientAllowlist::from([ClientRule::default()]).allows(&child), "the default rule allows every client");
+
+    let rule = ClientRule { process_ids: Some(alloc::vec![1234]), session_id: Some(1), user: Some(alice), max_integrity: Some(sid::integrity::Low), app_container: None };
+    let allowlist = ClientAllowlist::from([rule.clone()]);
+    assert!( allowlist.allows(&child));
+    assert!(!allowlist.allows(&ClientIdentity { user: bob, ..child }),                              "wrong SID");
+    assert!(!allowlist.allows(&ClientIdentity { integrity: sid::integrity::Medium, ..child }),      "integrity above max_integrity");
+    assert!( allowlist.allows(&ClientIdentity { integrity: sid::integrity::Untrusted, ..child }),   "integrity below max_integrity");
+    assert!(!allowlist.allows(&ClientIdentity { session_id: 2, ..child }),                          "session mismatch");
+    assert!(!allowlist.allows(&ClientIdentity { process_id: 4321, ..child }),                       "wrong process");
+
+    let allowlist = ClientAllowlist::from([ClientRule { app_container: Some(container), ..ClientRule::default() }]);
+    assert!(!allowlist.allows(&child),                                                              "not in an AppContainer");
+    assert!(!allowlist.allows(&ClientIdentity { app_container: Some(alice), ..child }),             "wrong AppContainer");
+    assert!( allowlist.allows(&ClientIdentity { app_container: Some(container), ..child }));
+
+    let mut allowlist = ClientAllowlist::from([rule]);
+    allowlist.push(ClientRule { user: Some(bob), max_integrity: Some(sid::integrity::Medium), ..ClientRule::default() });
+    let broker = ClientIdentity { process_id: 5678, session_id: 2, user: bob, integrity: sid::integrity::Medium, app_container: None };
+    assert_eq!(allowlist.rules().len(), 2);
+    assert!( allowlist.allows(&child),                                                              "first rule");
+    assert!( allowlist.allows(&broker),                                                             "second rule");
+    assert!(!allowlist.allows(&ClientIdentity { integrity: sid::integrity::High, ..broker }),       "neither rule");
+    assert!(!allowlist.allows(&ClientIdentity { user: alice, ..broker }),                           "each rule must match on its own");
+}
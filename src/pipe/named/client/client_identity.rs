@@ -0,0 +1,75 @@
+/// Who's on the other end of a named pipe: the client's process, session, and (impersonation) token details
+///
+/// Captured by [`ClientIdentity::capture`], typically via [`pipe::named::Listener::accept_verified`], and checked against a [`pipe::named::ClientAllowlist`].
+///
+/// ### Caveats
+/// *   Process IDs are reused once a process exits and every handle to it is closed.
+///     Keep a handle to each child process you allow by PID open for as long as you accept its connections.
+/// *   Clients that connect with a `SECURITY_ANONYMOUS` quality of service (e.g. `SECURITY_SQOS_PRESENT | SECURITY_ANONYMOUS`)
+///     can't be identified: [`ClientIdentity::capture`] fails for them.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClientIdentity {
+    /// GetNamedPipeClientProcessId
+    pub process_id:     process::Id,
+    /// GetNamedPipeClientSessionId
+    pub session_id:     u32,
+    /// TokenUser
+    pub user:           sid::Owned,
+    /// TokenIntegrityLevel
+    pub integrity:      sid::integrity::Level,
+    /// TokenAppContainerSid, or [`None`] if the client isn't running in an AppContainer
+    pub app_container:  Option<sid::Owned>,
+}
+
+impl ClientIdentity {
+    #[doc(alias = "GetNamedPipeClientProcessId")]
+    #[doc(alias = "GetNamedPipeClientSessionId")]
+    #[doc(alias = "ImpersonateNamedPipeClient")]
+    /// GetNamedPipeClientProcessId + GetNamedPipeClientSessionId + ImpersonateNamedPipeClient + OpenThreadToken + GetTokenInformation
+    ///
+    /// Capture the identity of the client connected to the server end of a named `pipe`.
+    ///
+    /// The calling thread briefly impersonates the client to open its token, then goes back to whatever token it was using before
+    /// (either its own, or the token it was already impersonating.)
+    /// If that token can't be restored, the process is terminated with [`__fastfail`] rather than continuing to run as the client.
+    ///
+    /// ### Errors
+    /// *   `ERROR_PIPE_NOT_CONNECTED`      if no client is connected
+    /// *   `ERROR_CANT_OPEN_ANONYMOUS`     if the client connected anonymously
+    /// *   `ERROR_INVALID_SID`             if the client's integrity label isn't a `S-1-16-*` SID
+    ///
+    pub fn capture(pipe: &impl firehazard::AsLocalHandle) -> firehazard::Result<Self> {
+        let process_id  = pipe::named::get_client_process_id(pipe)?;
+        let session_id  = pipe::named::get_client_session_id(pipe)?;
+        let token       = client_token(pipe)?;
+
+        let user        = (&*token.user()?.user().sid).into();
+
+        let label       = token.integrity_level()?;
+        let label       = label.label().sid;
+        let integrity   = match label.subauthorities() {
+            [rid] if label.authority_u64() == 16    => sid::integrity::Level::new(*rid), // 16 = SECURITY_MANDATORY_LABEL_AUTHORITY
+            _                                       => return Err(Error(ERROR_INVALID_SID)),
+        };
+
+        let app_container = token.app_container_sid()?;
+        let app_container = app_container.token_app_container();
+        let app_container = (!app_container.as_psid().is_null()).then(|| (&*app_container).into());
+
+        Ok(Self { process_id, session_id, user, integrity, app_container })
+    }
+}
+
+/// Open the client's token (as [`token::QUERY`]) by impersonating it, then restore the calling thread's previous token.
+fn client_token(pipe: &impl firehazard::AsLocalHandle) -> firehazard::Result<token::OwnedHandle> {
+    let previous = match open_thread_token(get_current_thread(), token::IMPERSONATE, true) {
+        Ok(previous)                        => Some(previous),
+        Err(err) if err == ERROR_NO_TOKEN   => None,
+        Err(err)                            => return Err(err),
+    };
+    let client = pipe::named::impersonate_client(pipe).and_then(|()| open_thread_token(get_current_thread(), token::QUERY, true));
+    // Continuing to run as the client (a potentially less - or more - privileged user) would be far worse than crashing.
+    if set_thread_token(None, previous.as_ref()).is_err() { __fastfail(FAST_FAIL::FATAL_APP_EXIT) }
+    client
+}
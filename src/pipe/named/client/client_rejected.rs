@@ -0,0 +1,23 @@
+/// A named pipe client that was disconnected without being accepted, by [`pipe::named::Listener::accept_verified`] or similar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive] pub struct ClientRejected {
+    /// The client's identity, or [`None`] if it couldn't be captured (or no client connected at all.)
+    pub identity:   Option<ClientIdentity>,
+    /// `ERROR_ACCESS_DENIED` if the client's identity wasn't allowed, otherwise the error connecting or capturing its identity.
+    pub error:      firehazard::Error,
+}
+
+impl From<ClientRejected> for firehazard::Error {
+    fn from(rejected: ClientRejected) -> Self { rejected.error }
+}
+
+impl core::error::Error for ClientRejected {}
+
+impl core::fmt::Display for ClientRejected {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.identity.as_ref() {
+            Some(client)    => write!(fmt, "named pipe client rejected (process {}, session {}, {:?}): {:?}", client.process_id, client.session_id, client.integrity, self.error),
+            None            => write!(fmt, "named pipe client rejected: {:?}", self.error),
+        }
+    }
+}
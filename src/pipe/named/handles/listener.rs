@@ -38,4 +38,67 @@ impl pipe::named::Listener {
             Ok(pipe::named::Connected(core::mem::ManuallyDrop::new(self).0))
         }
     }
+
+    #[cfg(alloc)]
+    #[doc(alias = "ConnectNamedPipe")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)\]
+    /// ConnectNamedPipe(handle, nullptr) + [`ClientIdentity::capture`](pipe::named::ClientIdentity::capture)
+    ///
+    /// Wait for a client to connect, then capture its identity and check it against `allowlist` before any data is read or written.
+    /// Rejected clients are [disconnected](pipe::named::disconnect).
+    /// Unlike [`accept`](Self::accept), a client that connected before this was called (`ERROR_PIPE_CONNECTED`) is accepted too.
+    ///
+    /// ### Example
+    /// ```
+    /// # use firehazard::*;
+    /// use firehazard::pipe::named::{ClientAllowlist, ClientRule};
+    /// let name = r"\\.\pipe\local\firehazard-pipe-named-accept-verified";
+    /// let create = || pipe::named::create_w(
+    ///     name, pipe::ACCESS_DUPLEX, pipe::TYPE_BYTE | pipe::READMODE_BYTE,
+    ///     pipe::UNLIMITED_INSTANCES, 0, 0, None, None,
+    /// ).unwrap();
+    ///
+    /// let self_only = ClientAllowlist::from([ClientRule {
+    ///     process_ids:    Some(vec![get_current_process_id()]),
+    ///     ..ClientRule::default()
+    /// }]);
+    ///
+    /// let listener = create();
+    /// let _client = pipe::sync::open_duplex_existing(name).unwrap();
+    /// let (_connected, identity) = listener.accept_verified(&self_only).unwrap();
+    /// assert_eq!(identity.process_id, get_current_process_id());
+    ///
+    /// // This process isn't running at Low integrity, so it isn't allowed to connect to itself:
+    /// let sandboxed_only = ClientAllowlist::from([ClientRule {
+    ///     max_integrity:  Some(sid::integrity::Low),
+    ///     ..ClientRule::default()
+    /// }]);
+    ///
+    /// let listener = create();
+    /// let _client = pipe::sync::open_duplex_existing(name).unwrap();
+    /// let (listener, rejected) = listener.accept_verified(&sandboxed_only).unwrap_err();
+    /// assert!(listener.is_some());
+    /// assert_eq!(rejected.error, winapi::shared::winerror::ERROR_ACCESS_DENIED);
+    /// assert_eq!(rejected.identity, Some(identity));
+    /// ```
+    ///
+    /// ### Errors
+    /// *   <code>([Some]\(listener\), rejected)</code>   if connecting failed, or the client was rejected and disconnected: `listener` can accept another client.
+    /// *   <code>([None], rejected)</code>                 if disconnecting a rejected client failed: the instance was closed instead, which also disconnects the client.
+    ///
+    pub fn accept_verified(self, allowlist: &pipe::named::ClientAllowlist) -> Result<(pipe::named::Connected, pipe::named::ClientIdentity), (Option<pipe::named::Listener>, pipe::named::ClientRejected)> {
+        if let Err(error) = pipe::named::connect(&self, None) {
+            if error != ERROR_PIPE_CONNECTED { return Err((Some(self), pipe::named::ClientRejected { identity: None, error })) }
+        }
+        let connected = pipe::named::Connected(core::mem::ManuallyDrop::new(self).0);
+        let rejected = match pipe::named::ClientIdentity::capture(&connected) {
+            Ok(identity) if allowlist.allows(&identity) => return Ok((connected, identity)),
+            Ok(identity)                                => pipe::named::ClientRejected { identity: Some(identity), error: Error(ERROR_ACCESS_DENIED) },
+            Err(error)                                  => pipe::named::ClientRejected { identity: None, error },
+        };
+        match connected.disconnect() {
+            Ok(listener)    => Err((Some(listener), rejected)),
+            Err(_)          => Err((None, rejected)),
+        }
+    }
 }
@@ -3,7 +3,7 @@
 use crate::prelude::*;
 #[cfg(doc)] use crate::io::{Read, Write};
 
-include!(r"conversion.rs");
-include!(r"handles.rs");
-include!(r"io.rs");
-include!(r"open_existing.rs");
+include!("conversion.rs");
+include!("handles.rs");
+include!("io.rs");
+include!("open_existing.rs");
@@ -34,7 +34,7 @@ mod c { #[doc(no_inline)] pub use crate::Error; }
     mem::transmute,
 };
 
-#[doc(no_inline)] pub(crate) use abistr::{
+#[cfg(windows)] #[doc(no_inline)] pub(crate) use abistr::{
     cstr8,
     cstr16,
     AsCStr,
@@ -52,14 +52,14 @@ mod c { #[doc(no_inline)] pub use crate::Error; }
     shared::winerror::{SUCCEEDED, *},
 };
 
-#[doc(no_inline)] pub(crate) use winresult::{
+#[cfg(windows)] #[doc(no_inline)] pub(crate) use winresult::{
     NtStatus,
     ERROR,
     STATUS,
 };
 
-#[doc(no_inline)] pub(crate) use util::{
-    From32,
+#[doc(no_inline)] pub(crate) use util::From32;
+#[cfg(windows)] #[doc(no_inline)] pub(crate) use util::{
     none2null,
     slice,
 };
@@ -1,15 +1,18 @@
 #![doc = include_str!("_privilege.md")]
 
 #[allow(unused_imports)] use crate::privilege; // doc purpouses
-mod privilege_attributes;           pub use privilege_attributes::*;
-mod privilege_luid;                 pub use privilege_luid::*;
-mod privilege_name;                 pub use privilege_name::*;
+#[cfg(windows)] mod privilege_attributes;   #[cfg(windows)] pub use privilege_attributes::*;
+#[cfg(windows)] mod privilege_luid;         #[cfg(windows)] pub use privilege_luid::*;
+mod privilege_name;                         pub use privilege_name::*;
+mod privilege_risk;                         pub use privilege_risk::*;
+mod privilege_set;                          pub use privilege_set::*;
+mod privilege_set_error;                    pub use privilege_set_error::*;
 
 
 
-pub use funcs::*;
-pub(crate) mod funcs {
+#[cfg(windows)] pub use funcs::*;
+#[cfg(windows)] pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\adjust_token_privileges.rs");
-    include!(r"funcs\lookup_privilege.rs");
+    include!("funcs/adjust_token_privileges.rs");
+    include!("funcs/lookup_privilege.rs");
 }
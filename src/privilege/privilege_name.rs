@@ -1,18 +1,19 @@
-use crate::prelude::*;
+#[allow(unused_imports)] use crate::prelude::*;
+use privilege::Risk;
 
-use winapi::um::winnt::LUID;
+#[cfg(windows)] use winapi::um::winnt::LUID;
 
 
 
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants)\]
 /// Privilege name, referencing a [privilege](https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants#constants) such as `"SeShutdownPrivilege"`
 ///
-#[derive(Clone, Copy, Debug)] pub struct Name {
+#[cfg(windows)] #[derive(Clone, Copy, Debug)] pub struct Name {
     name:       CStrNonNull<'static>,
     hardcoded:  privilege::Luid,
 }
 
-impl Name {
+#[cfg(windows)] impl Name {
     #[doc(alias = "LookupPrivilegeValueA")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-lookupprivilegevaluea)\]
     /// LookupPrivilegeValueA
@@ -37,20 +38,65 @@ impl Name {
     pub const fn hardcoded_luid(self) -> privilege::Luid { self.hardcoded }
 }
 
-impl TryFrom<Name> for privilege::Luid {
+#[cfg(windows)] impl TryFrom<Name> for privilege::Luid {
     type Error = crate::Error;
     fn try_from(n: Name) -> Result<Self, Self::Error> { n.lookup_luid() }
 }
 
-/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants)\]
-/// SE_*_NAME
+/// The hardcoded LUID of a privilege known to this crate, by name (case insensitive, like `LookupPrivilegeValue`.)
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// assert_eq!(privilege::hardcoded_luid("SeShutdownPrivilege"), Some(0x13));
+/// assert_eq!(privilege::hardcoded_luid("SeUnsolicitedInputPrivilege"), None);
+/// ```
+///
+pub fn hardcoded_luid(name: &str) -> Option<u64> {
+    KNOWN.iter().find(|k| k.name.eq_ignore_ascii_case(name)).map(|k| k.luid)
+}
+
+/// The name of a privilege known to this crate, by hardcoded LUID.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// assert_eq!(privilege::hardcoded_name(0x13_u64), Some("SeShutdownPrivilege"));
+/// assert_eq!(privilege::hardcoded_name(1_u64), None);
+/// ```
 ///
-pub mod name {
-    use super::*;
-    macro_rules! constants { ($(
-        $(#[$($attr:tt)*])*
-        pub const $id:ident = $name:tt / $luid:literal;
-    )*) => {
+pub fn hardcoded_name(luid: impl Into<u64>) -> Option<&'static str> {
+    known(luid).map(|k| k.name)
+}
+
+/// A privilege known to this crate: a row of the table behind [`privilege::Set`], [`Risk::of`], and [`sandbox::Privilege`](crate::sandbox::Privilege)
+#[derive(Clone, Copy)] pub(crate) struct Known {
+    pub(crate) name:    &'static str,
+    pub(crate) luid:    u64,
+    pub(crate) risk:    Risk,
+    #[cfg(windows)] pub(crate) id: Name,
+}
+
+/// The privilege known to this crate with the LUID `luid`.
+pub(crate) fn known(luid: impl Into<u64>) -> Option<&'static Known> {
+    let luid = luid.into();
+    KNOWN.iter().find(|k| k.luid == luid)
+}
+
+macro_rules! constants { ($(
+    $(#[$($attr:tt)*])*
+    pub const $id:ident = $name:tt / $luid:literal => $risk:ident;
+)*) => {
+    /// Every privilege known to this crate, in LUID order.
+    pub(crate) const KNOWN : &[Known] = &[$(
+        Known { name: $name, luid: $luid, risk: Risk::$risk, #[cfg(windows)] id: name::$id },
+    )*];
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants)\]
+    /// SE_*_NAME
+    ///
+    #[cfg(windows)] pub mod name {
+        use super::*;
         $(
             $(#[$($attr)*])*
             #[doc = concat!(stringify!($name))]
@@ -65,51 +111,51 @@ pub mod name {
                 assert_eq!($id.lookup_luid().expect($name), $id.hardcoded_luid(), "{} had unexpected value", $name);
             )*
         }
-    }}
-
-    // C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
-    // Line 11396 .. 11431
-    // https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-lsad/1a92af76-d45f-42c3-b67c-f1dc61bd6ee1
-    // https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/ns-ntifs-_se_exports
-    constants! {
-        // no privilege for Luid::from(1)
-        #[doc(alias = "SE_CREATE_TOKEN_NAME")]                          pub const CREATE_TOKEN                      = "SeCreateTokenPrivilege"                      / 2;
-        #[doc(alias = "SE_ASSIGNPRIMARYTOKEN_NAME"  )]                  pub const ASSIGNPRIMARYTOKEN                = "SeAssignPrimaryTokenPrivilege"               / 3;
-        #[doc(alias = "SE_LOCK_MEMORY_NAME")]                           pub const LOCK_MEMORY                       = "SeLockMemoryPrivilege"                       / 4;
-        #[doc(alias = "SE_INCREASE_QUOTA_NAME")]                        pub const INCREASE_QUOTA                    = "SeIncreaseQuotaPrivilege"                    / 5;
-        //doc(alias = "SE_UNSOLICITED_INPUT_NAME")]                     pub const UNSOLICITED_INPUT                 = "SeUnsolicitedInputPrivilege"; // ERROR_NO_SUCH_PRIVILEGE - "The privilege that is required to read unsolicited input from a terminal device. This privilege is obsolete and unused. It has no effect on the system."
-        #[doc(alias = "SE_MACHINE_ACCOUNT_NAME")]                       pub const MACHINE_ACCOUNT                   = "SeMachineAccountPrivilege"                   / 6;
-        #[doc(alias = "SE_TCB_NAME")]                                   pub const TCB                               = "SeTcbPrivilege"                              / 7;
-        #[doc(alias = "SE_SECURITY_NAME")]                              pub const SECURITY                          = "SeSecurityPrivilege"                         / 8;
-        #[doc(alias = "SE_TAKE_OWNERSHIP_NAME")]                        pub const TAKE_OWNERSHIP                    = "SeTakeOwnershipPrivilege"                    / 9;
-        #[doc(alias = "SE_LOAD_DRIVER_NAME")]                           pub const LOAD_DRIVER                       = "SeLoadDriverPrivilege"                       / 10;
-        #[doc(alias = "SE_SYSTEM_PROFILE_NAME")]                        pub const SYSTEM_PROFILE                    = "SeSystemProfilePrivilege"                    / 11;
-        #[doc(alias = "SE_SYSTEMTIME_NAME")]                            pub const SYSTEMTIME                        = "SeSystemtimePrivilege"                       / 12;
-        #[doc(alias = "SE_PROF_SINGLE_PROCESS_NAME")]                   pub const PROF_SINGLE_PROCESS               = "SeProfileSingleProcessPrivilege"             / 13;
-        #[doc(alias = "SE_INC_BASE_PRIORITY_NAME")]                     pub const INC_BASE_PRIORITY                 = "SeIncreaseBasePriorityPrivilege"             / 14;
-        #[doc(alias = "SE_CREATE_PAGEFILE_NAME")]                       pub const CREATE_PAGEFILE                   = "SeCreatePagefilePrivilege"                   / 15;
-        #[doc(alias = "SE_CREATE_PERMANENT_NAME")]                      pub const CREATE_PERMANENT                  = "SeCreatePermanentPrivilege"                  / 16;
-        #[doc(alias = "SE_BACKUP_NAME")]                                pub const BACKUP                            = "SeBackupPrivilege"                           / 17;
-        #[doc(alias = "SE_RESTORE_NAME")]                               pub const RESTORE                           = "SeRestorePrivilege"                          / 18;
-        #[doc(alias = "SE_SHUTDOWN_NAME")]                              pub const SHUTDOWN                          = "SeShutdownPrivilege"                         / 19;
-        #[doc(alias = "SE_DEBUG_NAME")]                                 pub const DEBUG                             = "SeDebugPrivilege"                            / 20;
-        #[doc(alias = "SE_AUDIT_NAME")]                                 pub const AUDIT                             = "SeAuditPrivilege"                            / 21;
-        #[doc(alias = "SE_SYSTEM_ENVIRONMENT_NAME")]                    pub const SYSTEM_ENVIRONMENT                = "SeSystemEnvironmentPrivilege"                / 22; // misdocumented as "SeSystemEnvironment" by https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-lsad/1a92af76-d45f-42c3-b67c-f1dc61bd6ee1
-        #[doc(alias = "SE_CHANGE_NOTIFY_NAME")]                         pub const CHANGE_NOTIFY                     = "SeChangeNotifyPrivilege"                     / 23;
-        #[doc(alias = "SE_REMOTE_SHUTDOWN_NAME")]                       pub const REMOTE_SHUTDOWN                   = "SeRemoteShutdownPrivilege"                   / 24;
-        #[doc(alias = "SE_UNDOCK_NAME")]                                pub const UNDOCK                            = "SeUndockPrivilege"                           / 25;
-        #[doc(alias = "SE_SYNC_AGENT_NAME")]                            pub const SYNC_AGENT                        = "SeSyncAgentPrivilege"                        / 26;
-        #[doc(alias = "SE_ENABLE_DELEGATION_NAME")]                     pub const ENABLE_DELEGATION                 = "SeEnableDelegationPrivilege"                 / 27;
-        #[doc(alias = "SE_MANAGE_VOLUME_NAME")]                         pub const MANAGE_VOLUME                     = "SeManageVolumePrivilege"                     / 28;
-        #[doc(alias = "SE_IMPERSONATE_NAME")]                           pub const IMPERSONATE                       = "SeImpersonatePrivilege"                      / 29;
-        #[doc(alias = "SE_CREATE_GLOBAL_NAME")]                         pub const CREATE_GLOBAL                     = "SeCreateGlobalPrivilege"                     / 30;
-        #[doc(alias = "SE_TRUSTED_CREDMAN_ACCESS_NAME")]                pub const TRUSTED_CREDMAN_ACCESS            = "SeTrustedCredManAccessPrivilege"             / 31;
-        #[doc(alias = "SE_RELABEL_NAME")]                               pub const RELABEL                           = "SeRelabelPrivilege"                          / 32;
-        #[doc(alias = "SE_INC_WORKING_SET_NAME")]                       pub const INC_WORKING_SET                   = "SeIncreaseWorkingSetPrivilege"               / 33;
-        #[doc(alias = "SE_TIME_ZONE_NAME")]                             pub const TIME_ZONE                         = "SeTimeZonePrivilege"                         / 34;
-        #[doc(alias = "SE_CREATE_SYMBOLIC_LINK_NAME")]                  pub const CREATE_SYMBOLIC_LINK              = "SeCreateSymbolicLinkPrivilege"               / 35;
-        #[doc(alias = "SE_DELEGATE_SESSION_USER_IMPERSONATE_NAME")]     pub const DELEGATE_SESSION_USER_IMPERSONATE = "SeDelegateSessionUserImpersonatePrivilege"   / 36; // undocumented by https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-lsad/1a92af76-d45f-42c3-b67c-f1dc61bd6ee1
     }
+}}
+
+// C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
+// Line 11396 .. 11431
+// https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-lsad/1a92af76-d45f-42c3-b67c-f1dc61bd6ee1
+// https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/ns-ntifs-_se_exports
+constants! {
+    // no privilege for Luid::from(1)
+    #[doc(alias = "SE_CREATE_TOKEN_NAME")]                          pub const CREATE_TOKEN                      = "SeCreateTokenPrivilege"                      / 2  => Escape;    // forge an arbitrary token
+    #[doc(alias = "SE_ASSIGNPRIMARYTOKEN_NAME")]                    pub const ASSIGNPRIMARYTOKEN                = "SeAssignPrimaryTokenPrivilege"               / 3  => Escape;    // spawn processes with an arbitrary (e.g. duplicated SYSTEM) token
+    #[doc(alias = "SE_LOCK_MEMORY_NAME")]                           pub const LOCK_MEMORY                       = "SeLockMemoryPrivilege"                       / 4  => Sensitive;
+    #[doc(alias = "SE_INCREASE_QUOTA_NAME")]                        pub const INCREASE_QUOTA                    = "SeIncreaseQuotaPrivilege"                    / 5  => Sensitive;
+    //doc(alias = "SE_UNSOLICITED_INPUT_NAME")]                     pub const UNSOLICITED_INPUT                 = "SeUnsolicitedInputPrivilege"; // ERROR_NO_SUCH_PRIVILEGE - "The privilege that is required to read unsolicited input from a terminal device. This privilege is obsolete and unused. It has no effect on the system."
+    #[doc(alias = "SE_MACHINE_ACCOUNT_NAME")]                       pub const MACHINE_ACCOUNT                   = "SeMachineAccountPrivilege"                   / 6  => Sensitive;
+    #[doc(alias = "SE_TCB_NAME")]                                   pub const TCB                               = "SeTcbPrivilege"                              / 7  => Escape;    // act as part of the operating system (S4U logons with arbitrary groups)
+    #[doc(alias = "SE_SECURITY_NAME")]                              pub const SECURITY                          = "SeSecurityPrivilege"                         / 8  => Sensitive;
+    #[doc(alias = "SE_TAKE_OWNERSHIP_NAME")]                        pub const TAKE_OWNERSHIP                    = "SeTakeOwnershipPrivilege"                    / 9  => Escape;    // take ownership of (and then rewrite the DACL of) any object
+    #[doc(alias = "SE_LOAD_DRIVER_NAME")]                           pub const LOAD_DRIVER                       = "SeLoadDriverPrivilege"                       / 10 => Escape;    // load (vulnerable) kernel drivers
+    #[doc(alias = "SE_SYSTEM_PROFILE_NAME")]                        pub const SYSTEM_PROFILE                    = "SeSystemProfilePrivilege"                    / 11 => Sensitive;
+    #[doc(alias = "SE_SYSTEMTIME_NAME")]                            pub const SYSTEMTIME                        = "SeSystemtimePrivilege"                       / 12 => Sensitive;
+    #[doc(alias = "SE_PROF_SINGLE_PROCESS_NAME")]                   pub const PROF_SINGLE_PROCESS               = "SeProfileSingleProcessPrivilege"             / 13 => Sensitive;
+    #[doc(alias = "SE_INC_BASE_PRIORITY_NAME")]                     pub const INC_BASE_PRIORITY                 = "SeIncreaseBasePriorityPrivilege"             / 14 => Sensitive;
+    #[doc(alias = "SE_CREATE_PAGEFILE_NAME")]                       pub const CREATE_PAGEFILE                   = "SeCreatePagefilePrivilege"                   / 15 => Sensitive;
+    #[doc(alias = "SE_CREATE_PERMANENT_NAME")]                      pub const CREATE_PERMANENT                  = "SeCreatePermanentPrivilege"                  / 16 => Sensitive;
+    #[doc(alias = "SE_BACKUP_NAME")]                                pub const BACKUP                            = "SeBackupPrivilege"                           / 17 => Escape;    // read any file (SAM/SYSTEM hives, credentials)
+    #[doc(alias = "SE_RESTORE_NAME")]                               pub const RESTORE                           = "SeRestorePrivilege"                          / 18 => Escape;    // write any file (service binaries, system DLLs)
+    #[doc(alias = "SE_SHUTDOWN_NAME")]                              pub const SHUTDOWN                          = "SeShutdownPrivilege"                         / 19 => Sensitive;
+    #[doc(alias = "SE_DEBUG_NAME")]                                 pub const DEBUG                             = "SeDebugPrivilege"                            / 20 => Escape;    // open and inject into any process
+    #[doc(alias = "SE_AUDIT_NAME")]                                 pub const AUDIT                             = "SeAuditPrivilege"                            / 21 => Sensitive;
+    #[doc(alias = "SE_SYSTEM_ENVIRONMENT_NAME")]                    pub const SYSTEM_ENVIRONMENT                = "SeSystemEnvironmentPrivilege"                / 22 => Sensitive; // misdocumented as "SeSystemEnvironment" by https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-lsad/1a92af76-d45f-42c3-b67c-f1dc61bd6ee1
+    #[doc(alias = "SE_CHANGE_NOTIFY_NAME")]                         pub const CHANGE_NOTIFY                     = "SeChangeNotifyPrivilege"                     / 23 => Low;
+    #[doc(alias = "SE_REMOTE_SHUTDOWN_NAME")]                       pub const REMOTE_SHUTDOWN                   = "SeRemoteShutdownPrivilege"                   / 24 => Sensitive;
+    #[doc(alias = "SE_UNDOCK_NAME")]                                pub const UNDOCK                            = "SeUndockPrivilege"                           / 25 => Low;
+    #[doc(alias = "SE_SYNC_AGENT_NAME")]                            pub const SYNC_AGENT                        = "SeSyncAgentPrivilege"                        / 26 => Sensitive;
+    #[doc(alias = "SE_ENABLE_DELEGATION_NAME")]                     pub const ENABLE_DELEGATION                 = "SeEnableDelegationPrivilege"                 / 27 => Sensitive;
+    #[doc(alias = "SE_MANAGE_VOLUME_NAME")]                         pub const MANAGE_VOLUME                     = "SeManageVolumePrivilege"                     / 28 => Escape;    // raw volume access (read/write any file's clusters)
+    #[doc(alias = "SE_IMPERSONATE_NAME")]                           pub const IMPERSONATE                       = "SeImpersonatePrivilege"                      / 29 => Escape;    // impersonate any token a (coerced) client connects with, e.g. "potato" attacks
+    #[doc(alias = "SE_CREATE_GLOBAL_NAME")]                         pub const CREATE_GLOBAL                     = "SeCreateGlobalPrivilege"                     / 30 => Sensitive;
+    #[doc(alias = "SE_TRUSTED_CREDMAN_ACCESS_NAME")]                pub const TRUSTED_CREDMAN_ACCESS            = "SeTrustedCredManAccessPrivilege"             / 31 => Sensitive;
+    #[doc(alias = "SE_RELABEL_NAME")]                               pub const RELABEL                           = "SeRelabelPrivilege"                          / 32 => Escape;    // raise the integrity label of (and then take over) higher integrity objects
+    #[doc(alias = "SE_INC_WORKING_SET_NAME")]                       pub const INC_WORKING_SET                   = "SeIncreaseWorkingSetPrivilege"               / 33 => Low;
+    #[doc(alias = "SE_TIME_ZONE_NAME")]                             pub const TIME_ZONE                         = "SeTimeZonePrivilege"                         / 34 => Low;
+    #[doc(alias = "SE_CREATE_SYMBOLIC_LINK_NAME")]                  pub const CREATE_SYMBOLIC_LINK              = "SeCreateSymbolicLinkPrivilege"               / 35 => Sensitive;
+    #[doc(alias = "SE_DELEGATE_SESSION_USER_IMPERSONATE_NAME")]     pub const DELEGATE_SESSION_USER_IMPERSONATE = "SeDelegateSessionUserImpersonatePrivilege"   / 36 => Escape;    // obtain impersonation tokens for other users in the same session; undocumented by https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-lsad/1a92af76-d45f-42c3-b67c-f1dc61bd6ee1
 }
 
 #[cfg(nope)] pub mod capability { // XXX: do these "string capabilities" also count as privileges?  N.B. suffix _CAPABILITY, not _NAME?
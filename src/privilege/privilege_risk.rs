@@ -0,0 +1,39 @@
+#[allow(unused_imports)] use crate::prelude::*;
+
+
+
+/// How dangerous it is to leave a privilege in a sandboxed token
+///
+/// Ordered from least to most dangerous, so the worst privilege of a set is [`privilege::Set::risk`](crate::privilege::Set::risk).
+///
+/// ### References
+/// *   [Abusing Token Privileges For LPE](https://github.com/hatRiot/token-priv) (Bryan Alexander, Stephen Breen)
+/// *   [learn.microsoft.com: Privilege Constants (Authorization)](https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants)
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive] pub enum Risk {
+    /// No meaningful effect outside the process (e.g. `SeChangeNotifyPrivilege`, `SeIncreaseWorkingSetPrivilege`.)
+    Low,
+
+    /// Affects the whole system, or bypasses some security policy, but doesn't directly grant arbitrary code execution
+    /// (e.g. `SeShutdownPrivilege`, `SeSecurityPrivilege`, `SeCreateSymbolicLinkPrivilege`.)
+    Sensitive,
+
+    /// Sandbox escape capable: grants code execution or file access as another (typically more privileged) principal, or kernel access
+    /// (e.g. `SeDebugPrivilege`, `SeImpersonatePrivilege`, `SeTcbPrivilege`.)
+    Escape,
+}
+
+impl Risk {
+    /// The risk of a privilege known to this crate, by hardcoded LUID.
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let debug = privilege::hardcoded_luid("SeDebugPrivilege").unwrap();
+    /// assert_eq!(privilege::Risk::of(debug), Some(privilege::Risk::Escape));
+    /// assert_eq!(privilege::Risk::of(1_u64), None);
+    /// ```
+    ///
+    pub fn of(luid: impl Into<u64>) -> Option<Self> { privilege::known(luid).map(|k| k.risk) }
+}
@@ -0,0 +1,261 @@
+use crate::prelude::*;
+use privilege::{Risk, SetParseError, hardcoded_luid, hardcoded_name, KNOWN};
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+
+
+/// A set of privileges known to this crate, stored as a bitset indexed by their hardcoded LUIDs
+///
+/// Unlike a `HashSet<privilege::Luid>`, this is `Copy`, doesn't allocate, and can be built, parsed, and displayed
+/// without looking up LUIDs via LSA (including on non-windows targets, so e.g. configuration can be validated offline.)
+/// LUIDs are the same values as [`privilege::Name::hardcoded_luid`](crate::privilege::Name::hardcoded_luid).
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let set : privilege::Set = "SeChangeNotifyPrivilege, sedebugprivilege".parse().unwrap();
+/// assert_eq!(set.to_string(), "SeDebugPrivilege, SeChangeNotifyPrivilege");
+/// assert!(set.contains(0x17_u64)); // SeChangeNotifyPrivilege
+/// assert_eq!(set.risk(), Some(privilege::Risk::Escape));
+/// assert_eq!((set & privilege::Risk::Escape.privileges()).to_string(), "SeDebugPrivilege");
+///
+/// let err = "SeChangeNotifyPrivilege, SeBogusPrivilege".parse::<privilege::Set>().unwrap_err();
+/// assert_eq!(err.range(), 25 .. 41);
+/// ```
+///
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Set(u64);
+
+impl Set {
+    /// The empty set.
+    pub const fn new() -> Self { Self(0) }
+
+    /// Every privilege known to this crate (`SeCreateTokenPrivilege` ..= `SeDelegateSessionUserImpersonatePrivilege`.)
+    pub const fn all() -> Self { Self(ALL) }
+
+    pub const fn len(&self) -> usize { self.0.count_ones() as usize }
+    pub const fn is_empty(&self) -> bool { self.0 == 0 }
+    pub const fn is_subset(&self, other: &Self) -> bool { self.0 & !other.0 == 0 }
+
+    /// `true` if the privilege with the LUID `luid` is in the set.
+    pub fn contains(&self, luid: impl Into<u64>) -> bool { bit(luid.into()).is_some_and(|b| self.0 & b != 0) }
+
+    /// Add the privilege with the LUID `luid`, returning `true` if it wasn't already in the set.
+    ///
+    /// ### Errors
+    /// *   `ERROR_NO_SUCH_PRIVILEGE`   if `luid` isn't a privilege known to this crate
+    ///
+    pub fn insert(&mut self, luid: impl Into<u64>) -> firehazard::Result<bool> {
+        let bit = bit(luid.into()).ok_or(firehazard::Error(ERROR_NO_SUCH_PRIVILEGE))?;
+        let added = self.0 & bit == 0;
+        self.0 |= bit;
+        Ok(added)
+    }
+
+    /// Remove the privilege with the LUID `luid`, returning `true` if it was in the set.
+    pub fn remove(&mut self, luid: impl Into<u64>) -> bool {
+        let Some(bit) = bit(luid.into()) else { return false };
+        let removed = self.0 & bit != 0;
+        self.0 &= !bit;
+        removed
+    }
+
+    /// The LUIDs of every privilege in the set, in ascending order.
+    pub fn luids(&self) -> impl Iterator<Item = u64> {
+        let bits = self.0;
+        (0 .. 64).filter(move |luid| bits & (1 << luid) != 0)
+    }
+
+    /// The names of every privilege in the set (e.g. `"SeChangeNotifyPrivilege"`), in LUID order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> { self.luids().filter_map(hardcoded_name) }
+
+    /// The most dangerous [`Risk`] of any privilege in the set, or [`None`] if the set is empty.
+    pub fn risk(&self) -> Option<Risk> { self.luids().filter_map(Risk::of).max() }
+}
+
+impl FromStr for Set {
+    type Err = SetParseError;
+
+    /// Parse comma separated privilege names (case insensitive, surrounding whitespace ignored.)
+    /// An empty (or whitespace only) string parses as the empty set.
+    fn from_str(s: &str) -> Result<Self, SetParseError> {
+        let mut set = Self::new();
+        if s.trim().is_empty() { return Ok(set) }
+        let mut offset = 0;
+        for name in s.split(',') {
+            let start = offset + (name.len() - name.trim_start().len());
+            let trimmed = name.trim();
+            let luid = hardcoded_luid(trimmed).ok_or(SetParseError { start, end: start + trimmed.len() })?;
+            set.0 |= 1 << luid;
+            offset += name.len() + 1;
+        }
+        Ok(set)
+    }
+}
+
+/// Comma separated privilege names, in LUID order
+impl Display for Set {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        for (i, name) in self.names().enumerate() {
+            if i != 0 { fmt.write_str(", ")? }
+            fmt.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Set {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "privilege::Set(\"{self}\")") }
+}
+
+impl BitOr         for Set { type Output = Self; fn bitor (self, rhs: Self) -> Self { Self(self.0 |  rhs.0) } }
+impl BitAnd        for Set { type Output = Self; fn bitand(self, rhs: Self) -> Self { Self(self.0 &  rhs.0) } }
+impl Sub           for Set { type Output = Self; fn sub   (self, rhs: Self) -> Self { Self(self.0 & !rhs.0) } }
+impl BitOrAssign   for Set { fn bitor_assign (&mut self, rhs: Self) { self.0 |=  rhs.0; } }
+impl BitAndAssign  for Set { fn bitand_assign(&mut self, rhs: Self) { self.0 &=  rhs.0; } }
+impl SubAssign     for Set { fn sub_assign   (&mut self, rhs: Self) { self.0 &= !rhs.0; } }
+
+impl Risk {
+    /// Every privilege known to this crate with exactly this risk.
+    pub const fn privileges(self) -> Set {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < KNOWN.len() {
+            if KNOWN[i].risk as u8 == self as u8 { bits |= 1 << KNOWN[i].luid }
+            i += 1;
+        }
+        Set(bits)
+    }
+}
+
+#[cfg(windows)] mod windows {
+    use super::*;
+    use winapi::um::winnt::TOKEN_PRIVILEGES;
+
+    impl From<privilege::Name> for Set {
+        fn from(name: privilege::Name) -> Self { Self(1 << u64::from(name.hardcoded_luid())) }
+    }
+
+    impl FromIterator<privilege::Name> for Set {
+        fn from_iter<I: IntoIterator<Item = privilege::Name>>(iter: I) -> Self { iter.into_iter().fold(Self::new(), |set, name| set | name.into()) }
+    }
+
+    /// Every privilege in the slice, regardless of [`privilege::Attributes`] (e.g. including disabled privileges.)
+    ///
+    /// ### Errors
+    /// *   `ERROR_NO_SUCH_PRIVILEGE`   if a LUID isn't a privilege known to this crate
+    ///
+    impl TryFrom<&'_ [privilege::LuidAndAttributes]> for Set {
+        type Error = firehazard::Error;
+        fn try_from(privileges: &'_ [privilege::LuidAndAttributes]) -> firehazard::Result<Self> {
+            let mut set = Self::new();
+            for p in privileges { set.insert(p.luid)?; }
+            Ok(set)
+        }
+    }
+
+    /// Every privilege of the token, regardless of [`privilege::Attributes`] (e.g. including disabled privileges.)
+    ///
+    /// ### Errors
+    /// *   `ERROR_NO_SUCH_PRIVILEGE`   if a LUID isn't a privilege known to this crate
+    ///
+    impl TryFrom<&'_ token::BoxTokenPrivileges> for Set {
+        type Error = firehazard::Error;
+        fn try_from(privileges: &'_ token::BoxTokenPrivileges) -> firehazard::Result<Self> { Self::try_from(privileges.privileges()) }
+    }
+
+    impl Set {
+        #[doc(alias = "TOKEN_PRIVILEGES")]
+        /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-token_privileges)\]
+        /// TOKEN_PRIVILEGES → set
+        ///
+        /// ### Safety
+        /// *   `privileges.Privileges` must be valid for `privileges.PrivilegeCount` elements.
+        ///
+        /// ### Errors
+        /// *   `ERROR_NO_SUCH_PRIVILEGE`   if a LUID isn't a privilege known to this crate
+        ///
+        pub unsafe fn from_token_privileges(privileges: &TOKEN_PRIVILEGES) -> firehazard::Result<Self> {
+            let laa = unsafe { slice::from_nullable_len_ref(privileges.Privileges.as_ptr().cast::<privilege::LuidAndAttributes>(), usize::from32(privileges.PrivilegeCount)) };
+            Self::try_from(laa)
+        }
+
+        /// Every privilege in the set, with `attributes`, as a `TOKEN_PRIVILEGES` buffer (e.g. for `AdjustTokenPrivileges`.)
+        ///
+        /// ### Examples
+        /// ```
+        /// # use firehazard::*;
+        /// let set = privilege::Set::from(privilege::name::CHANGE_NOTIFY);
+        /// let tp = set.to_token_privileges(privilege::ENABLED);
+        /// assert_eq!(tp.privileges().len(), 1);
+        /// assert_eq!(privilege::Set::try_from(&tp), Ok(set));
+        /// ```
+        ///
+        pub fn to_token_privileges(&self, attributes: impl Into<privilege::Attributes>) -> token::BoxTokenPrivileges {
+            let attributes = attributes.into();
+            let mut privileges = [privilege::LuidAndAttributes::new(0_u64, attributes); KNOWN.len()];
+            for (p, luid) in privileges.iter_mut().zip(self.luids()) { p.luid = luid.into(); }
+            token::BoxTokenPrivileges::from(&privileges[..self.len()])
+        }
+    }
+}
+
+/// The bit of a known privilege
+const fn bit(luid: u64) -> Option<u64> {
+    if luid < 64 && ALL & (1 << luid) != 0 { Some(1 << luid) } else { None }
+}
+
+const ALL : u64 = {
+    let mut bits = 0;
+    let mut i = 0;
+    while i < KNOWN.len() {
+        assert!(KNOWN[i].luid < 64, "privilege LUID doesn't fit in a Set");
+        bits |= 1 << KNOWN[i].luid;
+        i += 1;
+    }
+    bits
+};
+
+
+
+#[cfg(std)] #[test] fn set() {
+    use std::string::ToString;
+    use std::format;
+
+    assert_eq!(Set::all().len(), 35);
+    assert_eq!(Set::all().to_string().parse(), Ok(Set::all()));
+    assert_eq!("".parse(), Ok(Set::new()));
+    assert_eq!(" ".parse(), Ok(Set::new()));
+    assert_eq!(Set::new().to_string(), "");
+
+    let mut set = Set::new();
+    assert_eq!(set.insert(23_u64), Ok(true));
+    assert_eq!(set.insert(23_u64), Ok(false));
+    assert_eq!(set.insert(1_u64), Err(firehazard::Error(ERROR_NO_SUCH_PRIVILEGE)));
+    assert_eq!(set.insert(64_u64), Err(firehazard::Error(ERROR_NO_SUCH_PRIVILEGE)));
+    assert!(set.contains(23_u64) && !set.contains(20_u64) && !set.contains(u64::MAX));
+    assert_eq!(set.risk(), Some(Risk::Low));
+    assert_eq!(format!("{set:?}"), "privilege::Set(\"SeChangeNotifyPrivilege\")");
+    assert!(set.remove(23_u64) && !set.remove(23_u64) && !set.remove(99_u64));
+    assert_eq!(set.risk(), None);
+
+    assert_eq!("SeTcbPrivilege,SeImpersonatePrivilege , SeDebugPrivilege".parse::<Set>().map(|s| s.to_string()), Ok("SeTcbPrivilege, SeDebugPrivilege, SeImpersonatePrivilege".to_string()));
+    assert_eq!("SeTcbPrivilege,".parse::<Set>().unwrap_err().range(), 15 .. 15);
+    assert_eq!(" Bogus".parse::<Set>().unwrap_err().range(), 1 .. 6);
+
+    let escape = Risk::Escape.privileges();
+    assert!(escape.is_subset(&Set::all()));
+    for name in ["SeDebugPrivilege", "SeImpersonatePrivilege", "SeTcbPrivilege"] { assert!(escape.contains(hardcoded_luid(name).unwrap()), "{name}"); }
+    assert_eq!(Risk::Low.privileges() | Risk::Sensitive.privileges() | escape, Set::all());
+    assert!((Risk::Low.privileges() & Risk::Sensitive.privileges()).is_empty());
+    assert!((Set::all() - escape).risk() < Some(Risk::Escape));
+
+    for luid in 0 .. 64_u64 {
+        assert_eq!(hardcoded_name(luid).is_some(), Set::all().contains(luid), "{luid}");
+        assert_eq!(hardcoded_name(luid).and_then(hardcoded_luid), hardcoded_name(luid).map(|_| luid));
+        assert_eq!(Risk::of(luid).is_some(), Set::all().contains(luid), "{luid}");
+    }
+}
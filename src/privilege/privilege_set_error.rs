@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::Range;
+
+
+
+/// Error parsing a [`privilege::Set`](crate::privilege::Set): a privilege name unknown to this crate
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SetParseError {
+    pub(super) start:   usize,
+    pub(super) end:     usize,
+}
+
+impl SetParseError {
+    /// The byte range of the unknown (possibly empty) privilege name within the parsed string.
+    pub fn range(&self) -> Range<usize> { self.start .. self.end }
+}
+
+impl From<SetParseError> for firehazard::Error {
+    fn from(_: SetParseError) -> Self { Self(ERROR_NO_SUCH_PRIVILEGE) }
+}
+
+#[cfg(std)] impl From<SetParseError> for std::io::Error {
+    fn from(err: SetParseError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidInput, err) }
+}
+
+impl core::error::Error for SetParseError {}
+
+impl Debug for SetParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "privilege::SetParseError {{ range: {:?} }}", self.range()) }
+}
+
+impl Display for SetParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if self.start == self.end {
+            write!(fmt, "empty privilege name at offset {}", self.start)
+        } else {
+            write!(fmt, "unknown privilege name at offset {}..{}", self.start, self.end)
+        }
+    }
+}
@@ -4,6 +4,8 @@ pub mod child_process;
 pub mod desktop_app_breakaway;
 pub mod mitigation_policy;
 pub mod mitigation_policy2;
+mod mitigation_policy_check;             pub use mitigation_policy_check::*; pub(crate) use mitigation_policy_check::setting_flags;
+#[doc(no_inline)] pub use crate::mitigation::{MitigationPolicySetting, WindowsVersion};
 
 
 
@@ -0,0 +1,183 @@
+//! Offline validation of [`MitigationPolicy`] values against the named [`MitigationPolicySetting`]s:
+//! minimum Windows versions, conflicts, and missing prerequisites.
+
+use crate::prelude::*;
+use process::creation::*;
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// A problem found by [`MitigationPolicy::issues`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum MitigationPolicyIssue {
+    /// Both `ALWAYS_ON` and `ALWAYS_OFF` were set for a policy where the combination is `RESERVED` (e.g. `"heap_terminate"`.)
+    ///
+    /// Note that for some policies, `ALWAYS_ON | ALWAYS_OFF` is a different, valid setting
+    /// (e.g. `control_flow_guard::EXPORT_SUPPRESSION` or `cet_user_shadow_stacks::STRICT_MODE`) and can't be detected as a conflict.
+    Conflict { policy: &'static str },
+
+    /// `setting` only has an effect (or is only accepted) when `requires` is also set.
+    MissingPrerequisite { setting: &'static str, requires: &'static str },
+
+    /// Bits that don't belong to any known policy.
+    UnknownBits(MitigationPolicy),
+}
+
+impl Display for MitigationPolicyIssue {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match *self {
+            Self::Conflict { policy }                       => write!(fmt, "{policy}: ALWAYS_ON and ALWAYS_OFF are both set"),
+            Self::MissingPrerequisite { setting, requires } => write!(fmt, "{setting} requires {requires}"),
+            Self::UnknownBits(bits)                         => write!(fmt, "unknown mitigation policy bits: {:?}", bits),
+        }
+    }
+}
+
+impl MitigationPolicy {
+    /// Every named [`MitigationPolicySetting`] selected by `self`.
+    ///
+    /// `DEFER` settings aren't named, and so aren't returned.
+    pub fn settings(&self) -> impl Iterator<Item = &'static MitigationPolicySetting> {
+        let policy = *self;
+        MitigationPolicySetting::all().filter(move |s| s.is_set_in(policy))
+    }
+
+    /// Every conflicting setting, missing prerequisite, and unknown bit of `self`.
+    ///
+    /// An empty iterator doesn't guarantee process creation will succeed:
+    /// some settings also depend on the hardware (CET, ARM64 pointer authentication) or on how the executable was built (CFG, XFG.)
+    ///
+    /// ### Examples
+    /// ```
+    /// # #[cfg(windows)] {
+    /// # use firehazard::process::creation::*;
+    /// let policy = MitigationPolicy::from(mitigation_policy::DEP_ATL_THUNK_ENABLE | mitigation_policy::heap_terminate::ALWAYS_ON | mitigation_policy::heap_terminate::ALWAYS_OFF);
+    /// let issues = policy.issues().collect::<Vec<_>>();
+    /// assert_eq!(issues, [
+    ///     MitigationPolicyIssue::Conflict { policy: "heap_terminate" },
+    ///     MitigationPolicyIssue::MissingPrerequisite { setting: "dep_atl_thunk_enable", requires: "dep_enable" },
+    /// ]);
+    /// # }
+    /// ```
+    pub fn issues(&self) -> impl Iterator<Item = MitigationPolicyIssue> {
+        let policy = *self;
+
+        let conflicts = RESERVED.iter().filter(move |r| policy & r.mask == r.value).map(|r| MitigationPolicyIssue::Conflict { policy: r.policy });
+
+        let prerequisites = self.settings().filter_map(move |s| {
+            let requires = s.requires();
+            if requires.is_empty() || requires.iter().any(|r| MitigationPolicySetting::from_name(r).is_some_and(|r| r.is_set_in(policy))) { return None }
+            Some(MitigationPolicyIssue::MissingPrerequisite { setting: s.name(), requires: requires[0] })
+        });
+
+        let unknown = MitigationPolicySetting::all().fold(policy, |unknown, s| unknown & !s.mask());
+        let unknown = Some(unknown).filter(|u| *u != MitigationPolicy::default()).map(MitigationPolicyIssue::UnknownBits);
+
+        conflicts.chain(prerequisites).chain(unknown)
+    }
+
+    /// The oldest Windows version known to accept every setting of `self` (or [`WindowsVersion::WINDOWS_7`] if nothing is set.)
+    pub fn min_os(&self) -> WindowsVersion {
+        self.settings().map(|s| s.min_os()).max().unwrap_or(WindowsVersion::WINDOWS_7)
+    }
+
+    /// Every setting of `self` that `os` is too old to accept.
+    ///
+    /// ### Examples
+    /// ```
+    /// # #[cfg(windows)] {
+    /// # use firehazard::process::creation::*;
+    /// let policy = MitigationPolicy::new2(mitigation_policy::DEP_ENABLE, mitigation_policy2::cet_user_shadow_stacks::ALWAYS_ON);
+    /// let unsupported = policy.unsupported_on(WindowsVersion::WINDOWS_10_1809).map(|s| s.name()).collect::<Vec<_>>();
+    /// assert_eq!(unsupported, ["cet_user_shadow_stacks"]);
+    /// # }
+    /// ```
+    pub fn unsupported_on(&self, os: WindowsVersion) -> impl Iterator<Item = &'static MitigationPolicySetting> {
+        self.settings().filter(move |s| s.min_os() > os)
+    }
+}
+
+
+
+/// `(mask, value)` constructors for the [`MitigationPolicySetting`] table, which lives in the portable [`crate::mitigation`] module
+pub(crate) mod setting_flags {
+    use super::*;
+
+    pub(crate) const fn one(value: MitigationPolicyFlags1, mask: MitigationPolicyMask1) -> (MitigationPolicyMask, MitigationPolicy) {
+        (MitigationPolicyMask::new1(mask), MitigationPolicy::new1(value))
+    }
+
+    pub(crate) const fn two(value: MitigationPolicyFlags2, mask: MitigationPolicyMask2) -> (MitigationPolicyMask, MitigationPolicy) {
+        (MitigationPolicyMask::new2(MitigationPolicyMask1(0), mask), MitigationPolicy::new2(MitigationPolicyFlags1(0), value))
+    }
+
+    pub(crate) const fn legacy(value: MitigationPolicyFlags1) -> (MitigationPolicyMask, MitigationPolicy) {
+        one(value, MitigationPolicyMask1(value.0))
+    }
+}
+
+struct Reserved {
+    policy: &'static str,
+    mask:   MitigationPolicyMask,
+    value:  MitigationPolicy,
+}
+
+const fn reserved1(policy: &'static str, value: MitigationPolicyFlags1, mask: MitigationPolicyMask1) -> Reserved {
+    Reserved { policy, mask: MitigationPolicyMask::new1(mask), value: MitigationPolicy::new1(value) }
+}
+
+const fn reserved2(policy: &'static str, value: MitigationPolicyFlags2, mask: MitigationPolicyMask2) -> Reserved {
+    Reserved { policy, mask: MitigationPolicyMask::new2(MitigationPolicyMask1(0), mask), value: MitigationPolicy::new2(MitigationPolicyFlags1(0), value) }
+}
+
+use mitigation_policy::*;
+use mitigation_policy2::*;
+
+const RESERVED : &[Reserved] = &[
+    reserved1("heap_terminate",                                 heap_terminate::RESERVED,                           heap_terminate::MASK),
+    reserved1("bottom_up_aslr",                                 bottom_up_aslr::RESERVED,                           bottom_up_aslr::MASK),
+    reserved1("high_entropy_aslr",                              high_entropy_aslr::RESERVED,                        high_entropy_aslr::MASK),
+    reserved1("strict_handle_checks",                           strict_handle_checks::RESERVED,                     strict_handle_checks::MASK),
+    reserved1("win32k_system_call_disable",                     win32k_system_call_disable::RESERVED,               win32k_system_call_disable::MASK),
+    reserved1("extension_point_disable",                        extension_point_disable::RESERVED,                  extension_point_disable::MASK),
+    reserved1("image_load_no_remote",                           image_load_no_remote::RESERVED,                     image_load_no_remote::MASK),
+    reserved1("image_load_no_low_label",                        image_load_no_low_label::RESERVED,                  image_load_no_low_label::MASK),
+    reserved1("image_load_prefer_system32",                     image_load_prefer_system32::RESERVED,               image_load_prefer_system32::MASK),
+    reserved2("strict_control_flow_guard",                      strict_control_flow_guard::RESERVED,                strict_control_flow_guard::MASK),
+    reserved2("restrict_indirect_branch_prediction",            restrict_indirect_branch_prediction::RESERVED,      restrict_indirect_branch_prediction::MASK),
+    reserved2("allow_downgrade_dynamic_code_policy",            allow_downgrade_dynamic_code_policy::RESERVED,      allow_downgrade_dynamic_code_policy::MASK),
+    reserved2("speculative_store_bypass_disable",               speculative_store_bypass_disable::RESERVED,         speculative_store_bypass_disable::MASK),
+    reserved2("xtended_control_flow_guard",                     xtended_control_flow_guard::RESERVED,               xtended_control_flow_guard::MASK),
+    reserved2("pointer_auth_user_ip",                           pointer_auth_user_ip::RESERVED,                     pointer_auth_user_ip::MASK),
+    reserved2("cet_dynamic_apis_out_of_proc_only",              cet_dynamic_apis_out_of_proc_only::RESERVED,        cet_dynamic_apis_out_of_proc_only::MASK),
+    reserved2("restrict_core_sharing",                          restrict_core_sharing::RESERVED,                    restrict_core_sharing::MASK),
+];
+
+
+
+#[cfg(std)] #[test] fn issues() {
+    use std::vec::Vec;
+
+    let none = MitigationPolicy::default();
+    assert_eq!(none.issues().count(), 0);
+    assert_eq!(none.settings().count(), 0);
+    assert_eq!(none.min_os(), WindowsVersion::WINDOWS_7);
+
+    let ok = DEP_ENABLE | DEP_ATL_THUNK_ENABLE | bottom_up_aslr::ALWAYS_ON | high_entropy_aslr::ALWAYS_ON | control_flow_guard::EXPORT_SUPPRESSION | MitigationPolicy::from(strict_control_flow_guard::ALWAYS_ON | cet_user_shadow_stacks::STRICT_MODE | block_non_cet_binaries::NON_EHCONT);
+    assert_eq!(ok.issues().collect::<Vec<_>>(), []);
+    assert_eq!(ok.min_os(), WindowsVersion::WINDOWS_10_2004);
+    assert_eq!(ok.unsupported_on(WindowsVersion::WINDOWS_10_1703).map(|s| s.name()).collect::<Vec<_>>(), ["cet_user_shadow_stacks.strict_mode", "block_non_cet_binaries.non_ehcont"]);
+
+    let bad = DEP_ATL_THUNK_ENABLE | high_entropy_aslr::ALWAYS_ON | high_entropy_aslr::ALWAYS_OFF | MitigationPolicy::from(user_cet_set_context_ip_validation::ALWAYS_ON | cet_user_shadow_stacks::ALWAYS_OFF | MitigationPolicyFlags2(1 << 63));
+    assert_eq!(bad.issues().collect::<Vec<_>>(), [
+        MitigationPolicyIssue::Conflict { policy: "high_entropy_aslr" },
+        MitigationPolicyIssue::MissingPrerequisite { setting: "dep_atl_thunk_enable", requires: "dep_enable" },
+        MitigationPolicyIssue::MissingPrerequisite { setting: "user_cet_set_context_ip_validation", requires: "cet_user_shadow_stacks" },
+        MitigationPolicyIssue::UnknownBits(MitigationPolicyFlags2(1 << 63).into()),
+    ]);
+
+    for s in MitigationPolicySetting::all() {
+        assert!(s.is_set_in(s.value()));
+        assert_eq!(s.value().issues().filter(|i| !matches!(i, MitigationPolicyIssue::MissingPrerequisite { .. })).count(), 0, "{s:?}");
+    }
+}
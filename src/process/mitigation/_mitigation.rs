@@ -12,6 +12,8 @@ mod dynamic_code_policy;            pub use dynamic_code_policy::*;
 mod extension_point_disable_policy; pub use extension_point_disable_policy::*;
 mod font_disable_policy;            pub use font_disable_policy::*;
 mod image_load_policy;              pub use image_load_policy::*;
+mod mitigation_collect;
+mod mitigation_creation;
 mod mitigation_options_mask;        pub use mitigation_options_mask::*;
 mod payload_restriction_policy;     pub use payload_restriction_policy::*;
 mod redirection_trust_policy;       pub use redirection_trust_policy::*;
@@ -25,39 +27,7 @@ mod user_shadow_stack_policy;       pub use user_shadow_stack_policy::*;
 
 
 
-#[doc(alias = "PROCESS_MITIGATION_POLICY")]
-/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-process_mitigation_policy)\]
-/// PROCESS_MITIGATION_POLICY
-///
-#[non_exhaustive] #[repr(u32)] pub enum Policy {
-    // C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
-    #[doc(alias = "ProcessDEPPolicy"                    )] #[doc = "ProcessDEPPolicy"                   ] DEPPolicy,
-    #[doc(alias = "ProcessASLRPolicy"                   )] #[doc = "ProcessASLRPolicy"                  ] ASLRPolicy,
-    #[doc(alias = "ProcessDynamicCodePolicy"            )] #[doc = "ProcessDynamicCodePolicy"           ] DynamicCodePolicy,
-    #[doc(alias = "ProcessStrictHandleCheckPolicy"      )] #[doc = "ProcessStrictHandleCheckPolicy"     ] StrictHandleCheckPolicy,
-    #[doc(alias = "ProcessSystemCallDisablePolicy"      )] #[doc = "ProcessSystemCallDisablePolicy"     ] SystemCallDisablePolicy,
-    #[doc(alias = "ProcessMitigationOptionsMask"        )] #[doc = "ProcessMitigationOptionsMask"       ] MitigationOptionsMask,
-    #[doc(alias = "ProcessExtensionPointDisablePolicy"  )] #[doc = "ProcessExtensionPointDisablePolicy" ] ExtensionPointDisablePolicy,
-    #[doc(alias = "ProcessControlFlowGuardPolicy"       )] #[doc = "ProcessControlFlowGuardPolicy"      ] ControlFlowGuardPolicy,
-    #[doc(alias = "ProcessSignaturePolicy"              )] #[doc = "ProcessSignaturePolicy"             ] SignaturePolicy,
-    #[doc(alias = "ProcessFontDisablePolicy"            )] #[doc = "ProcessFontDisablePolicy"           ] FontDisablePolicy,
-    #[doc(alias = "ProcessImageLoadPolicy"              )] #[doc = "ProcessImageLoadPolicy"             ] ImageLoadPolicy,
-    #[doc(alias = "ProcessSystemCallFilterPolicy"       )] #[doc = "ProcessSystemCallFilterPolicy"      ] SystemCallFilterPolicy,
-    #[doc(alias = "ProcessPayloadRestrictionPolicy"     )] #[doc = "ProcessPayloadRestrictionPolicy"    ] PayloadRestrictionPolicy,
-    #[doc(alias = "ProcessChildProcessPolicy"           )] #[doc = "ProcessChildProcessPolicy"          ] ChildProcessPolicy,
-    #[doc(alias = "ProcessSideChannelIsolationPolicy"   )] #[doc = "ProcessSideChannelIsolationPolicy"  ] SideChannelIsolationPolicy,
-    #[doc(alias = "ProcessUserShadowStackPolicy"        )] #[doc = "ProcessUserShadowStackPolicy"       ] UserShadowStackPolicy,
-    #[doc(alias = "ProcessRedirectionTrustPolicy"       )] #[doc = "ProcessRedirectionTrustPolicy"      ] RedirectionTrustPolicy,
-    #[doc(alias = "ProcessUserPointerAuthPolicy"        )] #[doc = "ProcessUserPointerAuthPolicy"       ] UserPointerAuthPolicy,
-    #[doc(alias = "ProcessSEHOPPolicy"                  )] #[doc = "ProcessSEHOPPolicy"                 ] SEHOPPolicy,
-    //MaxProcessMitigationPolicy
-}
-
-/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-process_mitigation_policy)\]
-/// MaxProcessMitigationPolicy
-///
-#[allow(non_upper_case_globals)]
-pub const MaxProcessMitigationPolicy : u32 = Policy::SEHOPPolicy as u32 + 1;
+#[doc(no_inline)] pub use crate::mitigation::{Policy, MaxProcessMitigationPolicy, Report, ReportEntry, ReportDiff};
 
 
 
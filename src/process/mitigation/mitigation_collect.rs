@@ -0,0 +1,60 @@
+use super::*;
+use crate::prelude::*;
+
+
+
+impl Report {
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessmitigationpolicy)\]
+    /// GetProcessMitigationPolicy for every [`Policy`]
+    ///
+    /// Policies the OS doesn't support (`ERROR_INVALID_PARAMETER` or `ERROR_NOT_SUPPORTED`) are left unavailable.
+    ///
+    /// ### Errors
+    /// *   `ERROR_ACCESS_DENIED`   if `process` lacks `PROCESS_QUERY_INFORMATION` access
+    /// *   any other error from GetProcessMitigationPolicy
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let broker = process::mitigation::Report::collect(get_current_process()).unwrap();
+    /// println!("{broker}");
+    /// ```
+    ///
+    pub fn collect<'a>(process: impl Into<process::PseudoHandle<'a>>) -> firehazard::Result<Self> {
+        use winapi::um::processthreadsapi::GetProcessMitigationPolicy;
+        use winapi::um::winnt::PROCESS_MITIGATION_DEP_POLICY;
+
+        fn optional<T>(r: firehazard::Result<T>) -> firehazard::Result<Option<T>> {
+            match r {
+                Ok(v) => Ok(Some(v)),
+                Err(e) if e == ERROR_INVALID_PARAMETER || e == ERROR_NOT_SUPPORTED => Ok(None),
+                Err(e) => Err(e),
+            }
+        }
+
+        let process = process.into();
+        let mut report = Self::new();
+        for policy in Self::policies() {
+            let raw = match policy {
+                Policy::DEPPolicy => optional(get_process_mitigation_policy::<PROCESS_MITIGATION_DEP_POLICY>(process))?.map(|dep| [dep.Flags.into(), dep.Permanent.into()]),
+                Policy::MitigationOptionsMask => optional(get_process_mitigation_policy::<OptionsMask>(process))?.map(|mask| [mask.mask1().into(), mask.mask2().into()]),
+                _ => {
+                    let mut flags = 0u32;
+                    optional(firehazard::Error::get_last_if(0 == unsafe { GetProcessMitigationPolicy(
+                        process.as_handle(),
+                        policy as u32,
+                        &mut flags as *mut u32 as *mut _,
+                        size_of::<u32>(),
+                    )}))?.map(|()| [flags.into(), 0])
+                },
+            };
+            if let Some(raw) = raw { report.set_raw(policy, raw); }
+        }
+        Ok(report)
+    }
+
+    /// Set [`MitigationOptionsMask`](Policy::MitigationOptionsMask), marking it available.
+    pub fn set_options_mask(&mut self, mask: OptionsMask) -> &mut Self {
+        self.set_raw(Policy::MitigationOptionsMask, [mask.mask1().into(), mask.mask2().into()])
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+use crate::prelude::*;
+use process::creation::{MitigationPolicy, MitigationPolicySetting};
+
+
+
+/// Mapping between creation-time [`process::creation::MitigationPolicy`] flags and runtime [`Report`] bitfields,
+/// per [`MitigationPolicySetting::report_entries`].
+///
+/// Settings without a runtime equivalent aren't mapped:
+/// `heap_terminate`, `loader_integrity_continuity`, `module_tampering_protection`, `restrict_indirect_branch_prediction`,
+/// `dep_atl_thunk_enable` (meaningless for 64-bit processes), and `block_non_microsoft_binaries.allow_store`.
+impl Report {
+    /// The runtime bitfields a child process created with `policy` should report.
+    ///
+    /// `DEFER` settings have no expectations.
+    /// Settings are only expected to take effect if the OS and hardware support them,
+    /// and some (e.g. `control_flow_guard`) only apply to executables built with support for them.
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// # use process::creation::mitigation_policy::*;
+    /// let policy = process::creation::MitigationPolicy::from(prohibit_dynamic_code::ALWAYS_ON_ALLOW_OPT_OUT);
+    /// let expected = process::mitigation::Report::expected(policy).map(|e| e.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(expected, ["DynamicCodePolicy.ProhibitDynamicCode=1", "DynamicCodePolicy.AllowThreadOptOut=1"]);
+    /// ```
+    pub fn expected(policy: MitigationPolicy) -> impl Iterator<Item = ReportEntry> {
+        policy.settings().flat_map(|s| s.report_entries().iter().copied())
+    }
+
+    /// Every [`Report::expected`] bitfield of `policy` that `self` doesn't match.
+    ///
+    /// [`ReportDiff::left`] is the expected value, [`ReportDiff::right`] is the value in `self` ([`None`] if the policy was unavailable.)
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// # use firehazard::*;
+    /// # let policy = process::creation::MitigationPolicy::default();
+    /// # let child = get_current_process();
+    /// let report = process::mitigation::Report::collect(child).unwrap();
+    /// for mismatch in report.verify(policy) {
+    ///     eprintln!("mitigation not applied: {mismatch}");
+    /// }
+    /// ```
+    pub fn verify(&self, policy: MitigationPolicy) -> impl Iterator<Item = ReportDiff> + '_ {
+        Self::expected(policy).filter_map(|e| {
+            let actual = self.get(e.policy, e.field);
+            (actual != Some(e.value)).then_some(ReportDiff { policy: e.policy, field: e.field, left: Some(e.value), right: actual })
+        })
+    }
+
+    /// The creation-time flags that would enable what `self` reports (best effort.)
+    ///
+    /// Only enabling settings are produced: disabled runtime bitfields map to `DEFER`, not `ALWAYS_OFF`.
+    /// Where several settings of the same creation-time policy match, the most specific one wins
+    /// (e.g. `prohibit_dynamic_code::ALWAYS_ON_ALLOW_OPT_OUT` over `prohibit_dynamic_code::ALWAYS_ON`.)
+    pub fn to_creation_policy(&self) -> MitigationPolicy {
+        let mut result = MitigationPolicy::default();
+        for s in MitigationPolicySetting::all() {
+            let f = s.report_entries();
+            if f.is_empty() || s.is_always_off() { continue }
+            if !f.iter().all(|e| self.get(e.policy, e.field) == Some(e.value)) { continue }
+            let current = result.settings().find(|c| c.mask() == s.mask()).map_or(0, |c| c.report_entries().len());
+            if f.len() > current { result = (result & !s.mask()) | s.value() }
+        }
+        result
+    }
+}
+
+
+
+#[cfg(std)] #[test] fn round_trip() {
+    use process::creation::{mitigation_policy::*, mitigation_policy2::*};
+    use std::vec::Vec;
+    use Policy::*;
+
+    let policy = DEP_ENABLE | heap_terminate::ALWAYS_ON | prohibit_dynamic_code::ALWAYS_ON_ALLOW_OPT_OUT | control_flow_guard::ALWAYS_ON | MitigationPolicy::from(cet_user_shadow_stacks::STRICT_MODE | block_non_cet_binaries::ALWAYS_OFF);
+
+    let mut child = Report::new();
+    for e in Report::expected(policy) {
+        let flags = child.flags(e.policy).unwrap_or(0);
+        let bit = match (e.policy, e.field) {
+            (DEPPolicy, "Enable")                                           => 0,
+            (DynamicCodePolicy, "ProhibitDynamicCode")                      => 0,
+            (DynamicCodePolicy, "AllowThreadOptOut")                        => 1,
+            (ControlFlowGuardPolicy, "EnableControlFlowGuard")              => 0,
+            (UserShadowStackPolicy, "EnableUserShadowStack")                => 0,
+            (UserShadowStackPolicy, "EnableUserShadowStackStrictMode")      => 4,
+            (UserShadowStackPolicy, "BlockNonCetBinaries")                  => { child.set(e.policy, flags); continue },
+            other                                                           => panic!("unexpected {other:?}"),
+        };
+        child.set(e.policy, flags | ((e.value as u32) << bit));
+    }
+    assert_eq!(child.verify(policy).collect::<Vec<_>>(), []);
+    assert_eq!(child.to_creation_policy(), DEP_ENABLE | prohibit_dynamic_code::ALWAYS_ON_ALLOW_OPT_OUT | control_flow_guard::ALWAYS_ON | MitigationPolicy::from(cet_user_shadow_stacks::STRICT_MODE));
+
+    child.set(DynamicCodePolicy, 0b01);
+    assert_eq!(child.verify(policy).collect::<Vec<_>>(), [ReportDiff { policy: DynamicCodePolicy, field: "AllowThreadOptOut", left: Some(1), right: Some(0) }]);
+    assert_eq!(child.to_creation_policy() & prohibit_dynamic_code::MASK, prohibit_dynamic_code::ALWAYS_ON);
+}
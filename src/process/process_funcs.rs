@@ -494,6 +494,31 @@ pub fn get_exit_code_process<'a>(process: impl Into<process::Handle<'a>>) -> fir
 
 
 
+#[doc(alias = "GetProcessId")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessid)\]
+/// GetProcessId
+///
+/// Gets the process ID of `process`.
+/// While `process` remains open, the ID cannot be reused by another process.
+///
+/// ### Errors
+/// *   `ERROR_ACCESS_DENIED`   if `process` lacks `PROCESS_QUERY_[LIMITED_]INFORMATION` access
+///
+/// ### Example
+///
+/// ```
+/// # use firehazard::*;
+/// assert_eq!(get_process_id(get_current_process()), Ok(get_current_process_id()));
+/// ```
+///
+pub fn get_process_id<'a>(process: impl Into<process::PseudoHandle<'a>>) -> firehazard::Result<process::Id> {
+    let pid = unsafe { GetProcessId(process.into().as_handle()) };
+    firehazard::Error::get_last_if(pid == 0)?;
+    Ok(pid)
+}
+
+
+
 #[doc(alias = "GetProcessMitigationPolicy")]
 /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessmitigationpolicy)\]
 /// GetProcessMitigationPolicy
@@ -94,9 +94,9 @@
 pub use funcs::*;
 pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\close_pseudo_console.rs");
-    include!(r"funcs\create_pseudo_console.rs");
-    include!(r"funcs\resize_pseudo_console.rs");
+    include!("funcs/close_pseudo_console.rs");
+    include!("funcs/create_pseudo_console.rs");
+    include!("funcs/resize_pseudo_console.rs");
 }
 
 mod pseudoconsole_constants;                pub use pseudoconsole_constants::*;
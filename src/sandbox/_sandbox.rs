@@ -0,0 +1,48 @@
+//! Declarative [`sandbox::Profile`]s that compile to restricted tokens, job limits, and mitigation policies
+//!
+//! ### Text format
+//! A small subset of [TOML](https://toml.io/): `[section]` headers, `key = value` pairs, `#` comments,
+//! double quoted strings, decimal or `0x` integers (`_` separators allowed), `true`/`false`, and arrays of strings (which may span lines.)
+//! Every key is optional.
+//!
+//! ```toml
+//! [spawn]                         # the token the process is created with
+//! integrity   = "low"             # untrusted (default), low, medium, medium_plus, high, system, protected_process, or "S-1-16-N"
+//! privileges  = ["SeChangeNotifyPrivilege"]
+//! enabled     = ["@user", "BU", "WD", "@logon_session"]
+//! restricted  = ["@user", "BU", "WD", "@logon_session"]   # omit for an unrestricted token
+//!
+//! [lockdown]                      # the token the process later drops down to
+//! enabled     = ["@logon_session"]
+//! restricted  = ["@logon_session"]
+//!
+//! [job]
+//! active_process_limit        = 1
+//! job_memory_limit            = 0x4000_0000
+//! process_memory_limit        = 0x1000_0000
+//! kill_on_job_close           = true
+//! die_on_unhandled_exception  = true
+//! ui_restrictions             = ["desktop", "displaysettings", "exitwindows", "globalatoms", "handles", "readclipboard", "systemparameters", "writeclipboard"]
+//! cpu_weight                  = 5
+//! max_bandwidth               = 0
+//!
+//! [process]
+//! mitigations = ["dep_enable", "heap_terminate", "control_flow_guard", "control_flow_guard.export_suppression"]
+//! ```
+//!
+//! SIDs are `S-R-I-S...` strings, two letter SDDL aliases (see [`sid::parse_alias`]),
+//! or `@user` / `@logon_session` for the user and logon session SIDs of the token being restricted.
+//! Privileges are [`Privilege`] names, mitigations are [`Mitigation`] names.
+//!
+//! ### Planning
+//! [`Token::plan`] computes the exact `create_restricted_token` arguments for a [`TokenSource`] (a snapshot of the token being restricted),
+//! predicts the resulting group attributes, and [explains](TokenPlan#impl-Display-for-TokenPlan) what happens to each SID and privilege.
+
+#[allow(unused_imports)] use crate::prelude::*;
+
+mod sandbox_mitigation;                 pub use sandbox_mitigation::*;
+mod sandbox_privilege;                  pub use sandbox_privilege::*;
+mod sandbox_profile;                    pub use sandbox_profile::*;
+mod sandbox_profile_error;              pub use sandbox_profile_error::*;
+mod sandbox_profile_text;
+mod sandbox_token_plan;                 pub use sandbox_token_plan::*;
@@ -0,0 +1,58 @@
+#[allow(unused_imports)] use crate::prelude::*;
+
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+
+
+
+/// A named [`process::creation::MitigationPolicy`](crate::process::creation::MitigationPolicy) setting, as used by [`sandbox::Profile`]
+///
+/// Names are the [`mitigation::MitigationPolicySetting`] names:
+/// the `mitigation_policy`\[`2`\] module name (for `ALWAYS_ON`), or `module.setting` for other settings:
+///
+/// | Name                                              | Flag |
+/// | ------------------------------------------------- | ---- |
+/// | `"dep_enable"`                                    | `mitigation_policy::DEP_ENABLE`
+/// | `"heap_terminate"`                                | `mitigation_policy::heap_terminate::ALWAYS_ON`
+/// | `"control_flow_guard.export_suppression"`         | `mitigation_policy::control_flow_guard::EXPORT_SUPPRESSION`
+/// | `"allow_downgrade_dynamic_code_policy.always_off"`| `mitigation_policy2::allow_downgrade_dynamic_code_policy::ALWAYS_OFF`
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let m = sandbox::Mitigation::from_name("cet_user_shadow_stacks.strict_mode").unwrap();
+/// assert_eq!(m.name(), "cet_user_shadow_stacks.strict_mode");
+/// assert_eq!(m.policy_name(), "cet_user_shadow_stacks");
+/// assert!(sandbox::Mitigation::from_name("heap_terminate.defer").is_none());
+/// ```
+///
+#[derive(Clone, Copy)]
+pub struct Mitigation(&'static mitigation::MitigationPolicySetting);
+
+impl Mitigation {
+    /// Every named mitigation, in `mitigation_policy` then `mitigation_policy2` order.
+    pub fn all() -> impl Iterator<Item = Self> { mitigation::MitigationPolicySetting::all().map(Mitigation) }
+
+    /// Look up a mitigation by name (case sensitive.)
+    pub fn from_name(name: &str) -> Option<Self> { mitigation::MitigationPolicySetting::from_name(name).map(Mitigation) }
+
+    /// e.g. `"heap_terminate"` or `"control_flow_guard.export_suppression"`
+    pub fn name(&self) -> &'static str { self.0.name() }
+
+    /// The policy this setting belongs to (the name, minus any `.setting` suffix.)
+    pub fn policy_name(&self) -> &'static str { self.0.policy_name() }
+
+    /// `true` for `*.always_off` settings, which can't be combined with other settings of the same policy.
+    pub fn is_always_off(&self) -> bool { self.0.is_always_off() }
+
+    /// The setting's minimum Windows version, prerequisites, and (on windows) flags.
+    pub fn setting(&self) -> &'static mitigation::MitigationPolicySetting { self.0 }
+
+    /// The flag this setting corresponds to.
+    #[cfg(windows)] pub fn policy(&self) -> process::creation::MitigationPolicy { self.0.value() }
+}
+
+impl Debug      for Mitigation { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "sandbox::Mitigation({:?})", self.name()) } }
+impl PartialEq  for Mitigation { fn eq(&self, other: &Self) -> bool { self.name() == other.name() } }
+impl Eq         for Mitigation {}
+impl Hash       for Mitigation { fn hash<H: Hasher>(&self, state: &mut H) { self.name().hash(state) } }
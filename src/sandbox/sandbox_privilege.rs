@@ -0,0 +1,42 @@
+#[allow(unused_imports)] use crate::prelude::*;
+
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+
+
+
+/// A privilege name known to this crate (`"SeChangeNotifyPrivilege"`, ...), as used by [`sandbox::Profile`]
+///
+/// Unlike [`privilege::Name`](crate::privilege::Name), this is available on non-windows targets, so profiles can be validated offline.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let p = sandbox::Privilege::from_name("sechangenotifyprivilege").unwrap();
+/// assert_eq!(p.name(), "SeChangeNotifyPrivilege");
+/// assert!(sandbox::Privilege::from_name("SeUnsolicitedInputPrivilege").is_none());
+/// ```
+///
+#[derive(Clone, Copy)]
+pub struct Privilege(&'static privilege::Known);
+
+impl Privilege {
+    /// Every privilege name this crate knows about, in LUID order.
+    pub fn all() -> impl Iterator<Item = Self> { privilege::KNOWN.iter().map(Privilege) }
+
+    /// Look up a privilege by name (case insensitive, like `LookupPrivilegeValue`.)
+    pub fn from_name(name: &str) -> Option<Self> { Self::all().find(|p| p.name().eq_ignore_ascii_case(name)) }
+
+    /// e.g. `"SeChangeNotifyPrivilege"`
+    pub fn name(&self) -> &'static str { self.0.name }
+
+    /// The hardcoded LUID of this privilege (see [`privilege::hardcoded_luid`](crate::privilege::hardcoded_luid).)
+    pub fn luid(&self) -> u64 { self.0.luid }
+}
+
+impl Debug      for Privilege { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "sandbox::Privilege({:?})", self.name()) } }
+impl PartialEq  for Privilege { fn eq(&self, other: &Self) -> bool { self.name() == other.name() } }
+impl Eq         for Privilege {}
+impl Hash       for Privilege { fn hash<H: Hasher>(&self, state: &mut H) { self.name().hash(state) } }
+
+#[cfg(windows)] impl From<Privilege> for privilege::Name { fn from(p: Privilege) -> Self { p.0.id } }
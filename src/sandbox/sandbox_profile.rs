@@ -0,0 +1,331 @@
+use crate::prelude::*;
+use sandbox::{ValidationError, ValidationErrorKind};
+
+use alloc::vec::Vec;
+
+
+
+/// A declarative description of a sandboxed process: its spawn and lockdown [`Token`]s, [`Job`] limits, and [`Mitigation`](sandbox::Mitigation)s
+///
+/// Profiles can be [parsed](str::parse) from / [formatted](alloc::string::ToString) as a small TOML subset (see [`sandbox`](crate::sandbox) for the format),
+/// [validated](Self::validate) offline on any platform, and compiled into
+/// [`create_restricted_token`](crate::create_restricted_token) arguments,
+/// `job::object::*` information structs, and a
+/// `process::creation::MitigationPolicy` on windows.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let profile : sandbox::Profile = r#"
+///     [spawn]
+///     integrity   = "low"
+///     privileges  = ["SeChangeNotifyPrivilege"]
+///     enabled     = ["@user", "BU", "WD", "@logon_session"]
+///     restricted  = ["@user", "BU", "WD", "@logon_session"]
+///
+///     [lockdown]
+///     enabled     = ["@logon_session"]
+///     restricted  = ["@logon_session"]
+///
+///     [job]
+///     active_process_limit    = 1
+///     job_memory_limit        = 0x4000_0000
+///     kill_on_job_close       = true
+///
+///     [process]
+///     mitigations = ["heap_terminate", "bottom_up_aslr", "strict_handle_checks"]
+/// "#.parse().unwrap();
+///
+/// profile.validate().unwrap();
+/// assert_eq!(profile.spawn.integrity, sid::integrity::Low);
+/// assert_eq!(profile.lockdown.integrity, sid::integrity::Untrusted);
+/// assert_eq!(profile.job.active_process_limit, Some(1));
+/// assert_eq!(profile, profile.to_string().parse().unwrap());
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// The token the process is spawned with: used to load DLLs and run pre-`main` code.
+    pub spawn:          Token,
+
+    /// The token the process eventually locks itself down to.
+    /// Should never grant anything [`spawn`](Self::spawn) doesn't.
+    pub lockdown:       Token,
+
+    pub job:            Job,
+
+    /// Process creation mitigation policies.
+    pub mitigations:    Vec<sandbox::Mitigation>,
+}
+
+/// A restricted token's integrity level, privileges, and SIDs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Token {
+    /// Defaults to [`sid::integrity::Untrusted`].
+    pub integrity:      sid::integrity::Level,
+
+    /// Privileges to keep.  All others are deleted.
+    pub privileges:     Vec<sandbox::Privilege>,
+
+    /// Group SIDs to leave enabled.  All others are made deny-only.
+    pub enabled:        Vec<TokenSid>,
+
+    /// Restricting SIDs.  [`None`] creates a token that isn't restricted.
+    pub restricted:     Option<Vec<TokenSid>>,
+}
+
+/// A SID in a [`Token`], possibly resolved against the token being restricted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenSid {
+    /// A specific SID, such as `S-1-1-0` or `BU`.
+    Sid(sid::Owned),
+    /// `@user`: the user SID of the token being restricted.
+    User,
+    /// `@logon_session`: the logon session SID of the token being restricted.
+    LogonSession,
+}
+
+/// Job object limits
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Job {
+    /// `JOB_OBJECT_LIMIT_ACTIVE_PROCESS`
+    pub active_process_limit:       Option<u32>,
+    /// `JOB_OBJECT_LIMIT_JOB_MEMORY` (bytes)
+    pub job_memory_limit:           Option<usize>,
+    /// `JOB_OBJECT_LIMIT_PROCESS_MEMORY` (bytes)
+    pub process_memory_limit:       Option<usize>,
+    /// `JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE`
+    pub kill_on_job_close:          bool,
+    /// `JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION`
+    pub die_on_unhandled_exception: bool,
+    /// `JOB_OBJECT_UILIMIT_*`
+    pub ui_restrictions:            Vec<UiRestriction>,
+    /// `JOB_OBJECT_CPU_RATE_CONTROL_WEIGHT_BASED` weight (1 ..= 9)
+    pub cpu_weight:                 Option<u32>,
+    /// `JOB_OBJECT_NET_RATE_CONTROL_MAX_BANDWIDTH` (bytes/second)
+    pub max_bandwidth:              Option<u64>,
+}
+
+/// A `JOB_OBJECT_UILIMIT_*` flag
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UiRestriction { Desktop, DisplaySettings, ExitWindows, GlobalAtoms, Handles, ReadClipboard, SystemParameters, WriteClipboard }
+
+impl UiRestriction {
+    pub const ALL : [UiRestriction; 8] = [Self::Desktop, Self::DisplaySettings, Self::ExitWindows, Self::GlobalAtoms, Self::Handles, Self::ReadClipboard, Self::SystemParameters, Self::WriteClipboard];
+
+    /// The lowercase `JOB_OBJECT_UILIMIT_*` suffix, e.g. `"readclipboard"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Desktop           => "desktop",
+            Self::DisplaySettings   => "displaysettings",
+            Self::ExitWindows       => "exitwindows",
+            Self::GlobalAtoms       => "globalatoms",
+            Self::Handles           => "handles",
+            Self::ReadClipboard     => "readclipboard",
+            Self::SystemParameters  => "systemparameters",
+            Self::WriteClipboard    => "writeclipboard",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> { Self::ALL.into_iter().find(|r| r.name() == name) }
+
+    #[cfg(windows)] pub fn flags(self) -> job::object::uilimit::Flags {
+        use job::object::uilimit::*;
+        match self {
+            Self::Desktop           => DESKTOP,
+            Self::DisplaySettings   => DISPLAYSETTINGS,
+            Self::ExitWindows       => EXITWINDOWS,
+            Self::GlobalAtoms       => GLOBALATOMS,
+            Self::Handles           => HANDLES,
+            Self::ReadClipboard     => READCLIPBOARD,
+            Self::SystemParameters  => SYSTEMPARAMETERS,
+            Self::WriteClipboard    => WRITECLIPBOARD,
+        }
+    }
+}
+
+
+
+impl Profile {
+    /// Check the profile for mistakes without touching the OS:
+    ///
+    /// *   Duplicate privileges, SIDs, UI restrictions, or mitigations.
+    /// *   `restricted = []`, which (like `CreateRestrictedToken` with no restricting SIDs) would *not* restrict the token.
+    /// *   A [`lockdown`](Self::lockdown) token with a higher integrity level, or privileges or SIDs that [`spawn`](Self::spawn) lacks.
+    /// *   Job limits of `0`, or a CPU weight outside `1 ..= 9`.
+    /// *   `*.always_off` mitigations combined with other settings of the same policy, or mitigations missing their [prerequisites](mitigation::MitigationPolicySetting::requires) (e.g. `dep_atl_thunk_enable` without `dep_enable`.)
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.spawn   .validate(["spawn.privileges",    "spawn.enabled",    "spawn.restricted"   ])?;
+        self.lockdown.validate(["lockdown.privileges", "lockdown.enabled", "lockdown.restricted"])?;
+        self.job     .validate()?;
+
+        let (spawn, lockdown) = (&self.spawn, &self.lockdown);
+        if lockdown.integrity > spawn.integrity { return Err(ValidationError::new("lockdown.integrity", ValidationErrorKind::ExceedsSpawn)) }
+        if !lockdown.privileges.iter().all(|p| spawn.privileges.contains(p)) { return Err(ValidationError::new("lockdown.privileges", ValidationErrorKind::ExceedsSpawn)) }
+        if !lockdown.enabled   .iter().all(|s| spawn.enabled   .contains(s)) { return Err(ValidationError::new("lockdown.enabled",    ValidationErrorKind::ExceedsSpawn)) }
+        if let Some(spawn_restricted) = spawn.restricted.as_ref() {
+            match lockdown.restricted.as_ref() {
+                Some(r) if r.iter().all(|s| spawn_restricted.contains(s)) => {},
+                _ => return Err(ValidationError::new("lockdown.restricted", ValidationErrorKind::ExceedsSpawn)),
+            }
+        }
+
+        check_unique("process.mitigations", &self.mitigations)?;
+        for m in self.mitigations.iter() {
+            if m.is_always_off() && self.mitigations.iter().any(|o| o != m && o.policy_name() == m.policy_name()) {
+                return Err(ValidationError::new("process.mitigations", ValidationErrorKind::Conflict))
+            }
+            let requires = m.setting().requires();
+            if !requires.is_empty() && !self.mitigations.iter().any(|o| requires.contains(&o.name())) {
+                return Err(ValidationError::new("process.mitigations", ValidationErrorKind::MissingDependency))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Combine [`mitigations`](Self::mitigations) into a single `PROC_THREAD_ATTRIBUTE_MITIGATION_POLICY` value.
+    #[cfg(windows)] pub fn mitigation_policy(&self) -> process::creation::MitigationPolicy {
+        self.mitigations.iter().fold(process::creation::MitigationPolicy::default(), |policy, m| policy | m.policy())
+    }
+}
+
+impl Token {
+    fn validate(&self, [privileges, enabled, restricted]: [&'static str; 3]) -> Result<(), ValidationError> {
+        check_unique(privileges, &self.privileges)?;
+        check_unique(enabled,    &self.enabled)?;
+        if let Some(sids) = self.restricted.as_ref() {
+            if sids.is_empty() { return Err(ValidationError::new(restricted, ValidationErrorKind::Empty)) }
+            check_unique(restricted, sids)?;
+        }
+        Ok(())
+    }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-createrestrictedtoken)\]
+    /// CreateRestrictedToken +
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-settokeninformation)\]
+    /// SetTokenInformation(..., TokenIntegrityLevel, ...)
+    ///
+    /// Create a primary token from `existing` (typically the current process token) with only [`enabled`](Self::enabled) groups left enabled,
+    /// only [`privileges`](Self::privileges) kept, [`restricted`](Self::restricted) restricting SIDs, and [`integrity`](Self::integrity).
+    ///
+    /// See [`Token::plan`] for exactly which SIDs and privileges are disabled, deleted, and restricted.
+    ///
+    /// ### Errors
+    /// *   Any error from [`sandbox::TokenSource::from_token`], [`Token::plan`], or [`sandbox::TokenPlan::create_restricted_token`]
+    ///
+    #[cfg(windows)] pub fn create_restricted_token(&self, existing: &token::OwnedHandle) -> firehazard::Result<token::OwnedHandle> {
+        self.plan(&sandbox::TokenSource::from_token(existing)?)?.create_restricted_token(existing)
+    }
+}
+
+impl Job {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.active_process_limit    == Some(0) { return Err(ValidationError::new("job.active_process_limit", ValidationErrorKind::OutOfRange)) }
+        if self.job_memory_limit        == Some(0) { return Err(ValidationError::new("job.job_memory_limit",     ValidationErrorKind::OutOfRange)) }
+        if self.process_memory_limit    == Some(0) { return Err(ValidationError::new("job.process_memory_limit", ValidationErrorKind::OutOfRange)) }
+        if self.cpu_weight.is_some_and(|w| !(1 ..= 9).contains(&w)) { return Err(ValidationError::new("job.cpu_weight", ValidationErrorKind::OutOfRange)) }
+        check_unique("job.ui_restrictions", &self.ui_restrictions)
+    }
+
+    #[cfg(windows)] pub fn basic_ui_restrictions(&self) -> job::object::BasicUiRestrictions {
+        job::object::BasicUiRestrictions { ui_restrictions_class: self.ui_restrictions.iter().fold(job::object::uilimit::Flags::from(()), |flags, r| flags | r.flags()) }
+    }
+
+    #[cfg(windows)] pub fn extended_limit_information(&self) -> job::object::ExtendedLimitInformation {
+        use job::object::limit;
+        let mut limit_flags = limit::Flags::from(());
+        if self.active_process_limit    .is_some()  { limit_flags |= limit::ACTIVE_PROCESS }
+        if self.job_memory_limit        .is_some()  { limit_flags |= limit::JOB_MEMORY }
+        if self.process_memory_limit    .is_some()  { limit_flags |= limit::PROCESS_MEMORY }
+        if self.kill_on_job_close                   { limit_flags |= limit::KILL_ON_JOB_CLOSE }
+        if self.die_on_unhandled_exception          { limit_flags |= limit::DIE_ON_UNHANDLED_EXCEPTION }
+        job::object::ExtendedLimitInformation {
+            basic_limit_information: job::object::BasicLimitInformation {
+                limit_flags,
+                active_process_limit:   self.active_process_limit.unwrap_or(0),
+                .. Default::default()
+            },
+            job_memory_limit:       self.job_memory_limit.unwrap_or(0),
+            process_memory_limit:   self.process_memory_limit.unwrap_or(0),
+            .. Default::default()
+        }
+    }
+
+    #[cfg(windows)] pub fn cpu_rate_control(&self) -> Option<job::object::CpuRateControlInformation> {
+        self.cpu_weight.map(|weight| job::object::CpuRateControlInformation::from_weight(weight, false, false))
+    }
+
+    #[cfg(windows)] pub fn net_rate_control(&self) -> Option<job::object::NetRateControlInformation> {
+        self.max_bandwidth.map(job::object::NetRateControlInformation::enable_max_bandwidth)
+    }
+
+    /// [`set_information_job_object`](crate::set_information_job_object) for every limit in this profile.
+    #[cfg(windows)] pub fn apply(&self, job: &job::OwnedHandle) -> firehazard::Result<()> {
+        set_information_job_object(job, self.extended_limit_information())?;
+        set_information_job_object(job, self.basic_ui_restrictions())?;
+        if let Some(cpu) = self.cpu_rate_control() { set_information_job_object(job, cpu)? }
+        if let Some(net) = self.net_rate_control() { set_information_job_object(job, net)? }
+        Ok(())
+    }
+}
+
+fn check_unique<T: PartialEq>(field: &'static str, items: &[T]) -> Result<(), ValidationError> {
+    for (i, item) in items.iter().enumerate() {
+        if items[..i].contains(item) { return Err(ValidationError::new(field, ValidationErrorKind::Duplicate)) }
+    }
+    Ok(())
+}
+
+
+
+#[cfg(std)] #[test] fn validate() {
+    use ValidationErrorKind::*;
+    let sid = |s: &str| TokenSid::Sid(s.parse().unwrap());
+
+    let base = Profile {
+        spawn: Token {
+            integrity:  sid::integrity::Low,
+            privileges: sandbox::Privilege::from_name("SeChangeNotifyPrivilege").into_iter().collect(),
+            enabled:    alloc::vec![TokenSid::User, sid("S-1-1-0")],
+            restricted: Some(alloc::vec![TokenSid::User, sid("S-1-1-0")]),
+        },
+        lockdown: Token {
+            restricted: Some(alloc::vec![sid("S-1-0-0")]),
+            .. Token::default()
+        },
+        .. Profile::default()
+    };
+    assert_eq!(Err(ValidationError::new("lockdown.restricted", ExceedsSpawn)), base.validate());
+
+    let mut base = base;
+    base.spawn.restricted.as_mut().unwrap().push(sid("S-1-0-0"));
+    base.validate().unwrap();
+
+    let mitigation = |name| sandbox::Mitigation::from_name(name).unwrap();
+    for (field, kind, patch) in [
+        ("spawn.enabled",           Duplicate,          &(|p: &mut Profile| p.spawn.enabled.push(TokenSid::User)) as &dyn Fn(&mut Profile)),
+        ("lockdown.restricted",     Empty,              &|p| p.lockdown.restricted = Some(Vec::new())),
+        ("lockdown.integrity",      ExceedsSpawn,       &|p| p.lockdown.integrity = sid::integrity::Medium),
+        ("lockdown.privileges",     ExceedsSpawn,       &|p| p.lockdown.privileges.push(sandbox::Privilege::from_name("SeDebugPrivilege").unwrap())),
+        ("lockdown.enabled",        ExceedsSpawn,       &|p| p.lockdown.enabled.push(TokenSid::LogonSession)),
+        ("lockdown.restricted",     ExceedsSpawn,       &|p| p.lockdown.restricted = None),
+        ("job.active_process_limit",OutOfRange,         &|p| p.job.active_process_limit = Some(0)),
+        ("job.cpu_weight",          OutOfRange,         &|p| p.job.cpu_weight = Some(10)),
+        ("job.ui_restrictions",     Duplicate,          &|p| p.job.ui_restrictions = alloc::vec![UiRestriction::Handles, UiRestriction::Handles]),
+        ("process.mitigations",     Duplicate,          &|p| p.mitigations = alloc::vec![mitigation("heap_terminate"), mitigation("heap_terminate")]),
+        ("process.mitigations",     Conflict,           &|p| p.mitigations = alloc::vec![mitigation("control_flow_guard.export_suppression"), mitigation("control_flow_guard.always_off")]),
+        ("process.mitigations",     MissingDependency,  &|p| p.mitigations = alloc::vec![mitigation("dep_atl_thunk_enable")]),
+        ("process.mitigations",     MissingDependency,  &|p| p.mitigations = alloc::vec![mitigation("high_entropy_aslr")]),
+        ("process.mitigations",     MissingDependency,  &|p| p.mitigations = alloc::vec![mitigation("block_non_cet_binaries"), mitigation("control_flow_guard")]),
+    ] {
+        let mut profile = base.clone();
+        patch(&mut profile);
+        assert_eq!(Err(ValidationError::new(field, kind)), profile.validate(), "{field} {kind:?}");
+    }
+
+    let mut ok = base.clone();
+    ok.job.cpu_weight = Some(9);
+    ok.mitigations = alloc::vec![mitigation("dep_enable"), mitigation("dep_atl_thunk_enable"), mitigation("control_flow_guard"), mitigation("control_flow_guard.export_suppression"), mitigation("strict_control_flow_guard"), mitigation("cet_user_shadow_stacks.strict_mode"), mitigation("block_non_cet_binaries")];
+    ok.validate().unwrap();
+}
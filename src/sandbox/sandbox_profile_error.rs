@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Debug, Display, Formatter};
+
+
+
+/// Error parsing a [`sandbox::Profile`] from text
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    line:   usize,
+    kind:   ParseErrorKind,
+}
+
+impl ParseError {
+    pub(crate) fn new(line: usize, kind: ParseErrorKind) -> Self { Self { line, kind } }
+
+    /// 1-based line number of the offending section header or key.
+    pub fn line(&self) -> usize { self.line }
+
+    pub fn kind(&self) -> ParseErrorKind { self.kind }
+}
+
+/// What went wrong parsing a [`sandbox::Profile`] from text
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum ParseErrorKind {
+    /// Not a `[section]`, `key = value`, comment, or blank line (or an unterminated string or array.)
+    Syntax,
+    /// A `[section]` other than `[spawn]`, `[lockdown]`, `[job]`, or `[process]`.
+    UnknownSection,
+    /// A `key = value` before the first `[section]`.
+    KeyOutsideSection,
+    /// A key that isn't valid in the current section.
+    UnknownKey,
+    /// A section or key that appeared more than once.
+    Duplicate,
+    /// A string where an integer was expected, an integer where an array was expected, etc.
+    WrongType,
+    /// An unknown integrity level, privilege, SID, UI restriction, or mitigation name, or an out of range integer.
+    InvalidValue,
+}
+
+impl From<ParseError> for firehazard::Error {
+    fn from(_: ParseError) -> Self { Self(ERROR_INVALID_DATA) }
+}
+
+#[cfg(std)] impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidData, err) }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Debug for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "sandbox::ParseError {{ line: {}, kind: {:?} }}", self.line, self.kind)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ParseErrorKind::Syntax              => "syntax error",
+            ParseErrorKind::UnknownSection      => "unknown section",
+            ParseErrorKind::KeyOutsideSection   => "key outside of any section",
+            ParseErrorKind::UnknownKey          => "unknown key",
+            ParseErrorKind::Duplicate           => "duplicate section or key",
+            ParseErrorKind::WrongType           => "wrong value type",
+            ParseErrorKind::InvalidValue        => "invalid value",
+        };
+        write!(fmt, "sandbox profile line {}: {msg}", self.line)
+    }
+}
+
+
+
+/// Error returned by [`sandbox::Profile::validate`]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidationError {
+    field:  &'static str,
+    kind:   ValidationErrorKind,
+}
+
+impl ValidationError {
+    pub(crate) fn new(field: &'static str, kind: ValidationErrorKind) -> Self { Self { field, kind } }
+
+    /// The offending field, as `section.key` (e.g. `"lockdown.enabled"` or `"process.mitigations"`.)
+    pub fn field(&self) -> &'static str { self.field }
+
+    pub fn kind(&self) -> ValidationErrorKind { self.kind }
+}
+
+/// What's wrong with a [`sandbox::Profile`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum ValidationErrorKind {
+    /// A list contains the same privilege, SID, UI restriction, or mitigation more than once.
+    Duplicate,
+    /// `restricted = []`: a token with no restricting SIDs isn't restricted at all.
+    Empty,
+    /// The lockdown token has a higher integrity level, or privileges, enabled SIDs, or restricting SIDs that the spawn token lacks.
+    ExceedsSpawn,
+    /// A job limit of `0`, or a CPU weight outside of `1 ..= 9`.
+    OutOfRange,
+    /// An `*.always_off` mitigation combined with another setting of the same policy.
+    Conflict,
+    /// A mitigation without any of its [prerequisites](mitigation::MitigationPolicySetting::requires) (e.g. `dep_atl_thunk_enable` without `dep_enable`.)
+    MissingDependency,
+}
+
+impl From<ValidationError> for firehazard::Error {
+    fn from(_: ValidationError) -> Self { Self(ERROR_INVALID_PARAMETER) }
+}
+
+#[cfg(std)] impl From<ValidationError> for std::io::Error {
+    fn from(err: ValidationError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidInput, err) }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl Debug for ValidationError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "sandbox::ValidationError {{ field: {:?}, kind: {:?} }}", self.field, self.kind)
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ValidationErrorKind::Duplicate          => "contains duplicates",
+            ValidationErrorKind::Empty              => "is empty, which wouldn't restrict the token",
+            ValidationErrorKind::ExceedsSpawn       => "grants more than the spawn token",
+            ValidationErrorKind::OutOfRange         => "is out of range",
+            ValidationErrorKind::Conflict           => "combines always_off with another setting of the same policy",
+            ValidationErrorKind::MissingDependency  => "is missing a required mitigation",
+        };
+        write!(fmt, "sandbox profile {} {msg}", self.field)
+    }
+}
@@ -0,0 +1,428 @@
+use crate::prelude::*;
+use sandbox::{Profile, Token, TokenSid, UiRestriction, ParseError, ParseErrorKind};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+
+
+impl FromStr for Profile {
+    type Err = ParseError;
+
+    /// Parse the TOML subset described in the [`sandbox`](crate::sandbox) module docs.
+    /// Unspecified keys keep their [`Default`] values.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut profile = Profile::default();
+        let mut p = Parser { rest: s, line: 1 };
+        let mut section = None;
+        let mut sections = Vec::new();
+        let mut keys = Vec::new();
+
+        loop {
+            p.skip_blank();
+            if p.rest.is_empty() { break }
+            let line = p.line;
+            let err = |kind| ParseError::new(line, kind);
+
+            if p.eat('[') {
+                p.skip_spaces();
+                let name = p.ident();
+                p.skip_spaces();
+                if !p.eat(']') { return Err(err(ParseErrorKind::Syntax)) }
+                p.end_of_line().map_err(err)?;
+                let s = Section::from_name(name).ok_or(err(ParseErrorKind::UnknownSection))?;
+                if sections.contains(&s) { return Err(err(ParseErrorKind::Duplicate)) }
+                sections.push(s);
+                section = Some(s);
+            } else {
+                let key = p.ident();
+                if key.is_empty() { return Err(err(ParseErrorKind::Syntax)) }
+                p.skip_spaces();
+                if !p.eat('=') { return Err(err(ParseErrorKind::Syntax)) }
+                p.skip_spaces();
+                let value = p.value().map_err(err)?;
+                p.end_of_line().map_err(err)?;
+                let section = section.ok_or(err(ParseErrorKind::KeyOutsideSection))?;
+                if keys.contains(&(section, key)) { return Err(err(ParseErrorKind::Duplicate)) }
+                keys.push((section, key));
+                profile.set(section, key, value).map_err(err)?;
+            }
+        }
+
+        Ok(profile)
+    }
+}
+
+impl Profile {
+    fn set(&mut self, section: Section, key: &str, value: Value) -> Result<(), ParseErrorKind> {
+        use ParseErrorKind::InvalidValue;
+        match (section, key) {
+            (Section::Spawn,    _)                              => self.spawn   .set(key, value)?,
+            (Section::Lockdown, _)                              => self.lockdown.set(key, value)?,
+            (Section::Job,      "active_process_limit")         => self.job.active_process_limit        = Some(value.int()?.try_into().map_err(|_| InvalidValue)?),
+            (Section::Job,      "job_memory_limit")             => self.job.job_memory_limit            = Some(value.int()?.try_into().map_err(|_| InvalidValue)?),
+            (Section::Job,      "process_memory_limit")         => self.job.process_memory_limit        = Some(value.int()?.try_into().map_err(|_| InvalidValue)?),
+            (Section::Job,      "kill_on_job_close")            => self.job.kill_on_job_close           = value.bool()?,
+            (Section::Job,      "die_on_unhandled_exception")   => self.job.die_on_unhandled_exception  = value.bool()?,
+            (Section::Job,      "ui_restrictions")              => self.job.ui_restrictions             = value.names(UiRestriction::from_name)?,
+            (Section::Job,      "cpu_weight")                   => self.job.cpu_weight                  = Some(value.int()?.try_into().map_err(|_| InvalidValue)?),
+            (Section::Job,      "max_bandwidth")                => self.job.max_bandwidth               = Some(value.int()?),
+            (Section::Process,  "mitigations")                  => self.mitigations                     = value.names(sandbox::Mitigation::from_name)?,
+            _                                                   => return Err(ParseErrorKind::UnknownKey),
+        }
+        Ok(())
+    }
+}
+
+impl Token {
+    fn set(&mut self, key: &str, value: Value) -> Result<(), ParseErrorKind> {
+        match key {
+            "integrity"     => self.integrity   = parse_integrity(&value.string()?).ok_or(ParseErrorKind::InvalidValue)?,
+            "privileges"    => self.privileges  = value.names(sandbox::Privilege::from_name)?,
+            "enabled"       => self.enabled     = value.names(TokenSid::from_text)?,
+            "restricted"    => self.restricted  = Some(value.names(TokenSid::from_text)?),
+            _               => return Err(ParseErrorKind::UnknownKey),
+        }
+        Ok(())
+    }
+}
+
+impl TokenSid {
+    /// `@user`, `@logon_session`, a two letter SDDL alias, or a `S-R-I-S...` string
+    fn from_text(s: &str) -> Option<Self> {
+        match s {
+            "@user"             => Some(TokenSid::User),
+            "@logon_session"    => Some(TokenSid::LogonSession),
+            _ if s.starts_with('@') => None,
+            _ => sid::parse_alias(s).map(|sid| TokenSid::Sid((&*sid).into())).or_else(|| s.parse().ok().map(TokenSid::Sid)),
+        }
+    }
+}
+
+const INTEGRITY_LEVELS : &[(&str, sid::integrity::Level)] = &[
+    ("untrusted",           sid::integrity::Untrusted),
+    ("low",                 sid::integrity::Low),
+    ("medium",              sid::integrity::Medium),
+    ("medium_plus",         sid::integrity::MediumPlus),
+    ("high",                sid::integrity::High),
+    ("system",              sid::integrity::System),
+    ("protected_process",   sid::integrity::ProtectedProcess),
+];
+
+/// The [`INTEGRITY_LEVELS`] name of `level`, if it has one
+pub(super) fn integrity_name(level: sid::integrity::Level) -> Option<&'static str> { INTEGRITY_LEVELS.iter().find(|(_, l)| *l == level).map(|(name, _)| *name) }
+
+/// A name from [`INTEGRITY_LEVELS`], or a `S-1-16-N` string
+fn parse_integrity(s: &str) -> Option<sid::integrity::Level> {
+    if let Some(&(_, level)) = INTEGRITY_LEVELS.iter().find(|(name, _)| *name == s) { return Some(level) }
+    let sid = s.parse::<sid::Owned>().ok()?;
+    let sid = sid.as_sid_ptr();
+    match sid.subauthorities() {
+        [rid] if sid.authority_u64() == 16 => Some(sid::integrity::Level::new(*rid)),
+        _ => None,
+    }
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section { Spawn, Lockdown, Job, Process }
+
+impl Section {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "spawn"     => Some(Section::Spawn),
+            "lockdown"  => Some(Section::Lockdown),
+            "job"       => Some(Section::Job),
+            "process"   => Some(Section::Process),
+            _           => None,
+        }
+    }
+}
+
+enum Value {
+    String(String),
+    Int(u64),
+    Bool(bool),
+    Array(Vec<String>),
+}
+
+impl Value {
+    fn string(self) -> Result<String, ParseErrorKind> { match self { Value::String(s) => Ok(s), _ => Err(ParseErrorKind::WrongType) } }
+    fn int(self)    -> Result<u64,    ParseErrorKind> { match self { Value::Int(i)    => Ok(i), _ => Err(ParseErrorKind::WrongType) } }
+    fn bool(self)   -> Result<bool,   ParseErrorKind> { match self { Value::Bool(b)   => Ok(b), _ => Err(ParseErrorKind::WrongType) } }
+
+    /// An array of strings, each mapped through `parse`
+    fn names<T>(self, parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, ParseErrorKind> {
+        match self {
+            Value::Array(a) => a.iter().map(|s| parse(s).ok_or(ParseErrorKind::InvalidValue)).collect(),
+            _               => Err(ParseErrorKind::WrongType),
+        }
+    }
+}
+
+struct Parser<'s> {
+    rest:   &'s str,
+    line:   usize,
+}
+
+impl<'s> Parser<'s> {
+    fn eat(&mut self, ch: char) -> bool {
+        let Some(rest) = self.rest.strip_prefix(ch) else { return false };
+        if ch == '\n' { self.line += 1 }
+        self.rest = rest;
+        true
+    }
+
+    fn skip_spaces(&mut self) { self.rest = self.rest.trim_start_matches([' ', '\t', '\r']) }
+
+    fn skip_comment(&mut self) { if self.rest.starts_with('#') { self.rest = &self.rest[self.rest.find('\n').unwrap_or(self.rest.len())..] } }
+
+    /// Skip whitespace, newlines, and comments
+    fn skip_blank(&mut self) {
+        loop {
+            self.skip_spaces();
+            self.skip_comment();
+            if !self.eat('\n') { break }
+        }
+    }
+
+    fn end_of_line(&mut self) -> Result<(), ParseErrorKind> {
+        self.skip_spaces();
+        self.skip_comment();
+        if self.rest.is_empty() || self.eat('\n') { Ok(()) } else { Err(ParseErrorKind::Syntax) }
+    }
+
+    fn ident(&mut self) -> &'s str {
+        let len = self.rest.find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_')).unwrap_or(self.rest.len());
+        let (ident, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        ident
+    }
+
+    fn value(&mut self) -> Result<Value, ParseErrorKind> {
+        if self.eat('"') { return self.string().map(Value::String) }
+
+        if self.eat('[') {
+            let mut array = Vec::new();
+            loop {
+                self.skip_blank();
+                if self.eat(']') { break }
+                if !self.eat('"') { return Err(if self.rest.is_empty() { ParseErrorKind::Syntax } else { ParseErrorKind::WrongType }) }
+                array.push(self.string()?);
+                self.skip_blank();
+                if self.eat(',') { continue }
+                if self.eat(']') { break }
+                return Err(ParseErrorKind::Syntax)
+            }
+            return Ok(Value::Array(array))
+        }
+
+        match self.ident() {
+            "true"  => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            ""      => Err(ParseErrorKind::Syntax),
+            int if int.starts_with(|ch: char| ch.is_ascii_digit()) => {
+                let mut value = 0u64;
+                let (digits, radix) = match int.strip_prefix("0x") { Some(hex) => (hex, 16), None => (int, 10) };
+                if digits.is_empty() || digits.starts_with('_') { return Err(ParseErrorKind::InvalidValue) }
+                for ch in digits.chars().filter(|ch| *ch != '_') {
+                    let digit = ch.to_digit(radix).ok_or(ParseErrorKind::InvalidValue)?;
+                    value = value.checked_mul(radix.into()).and_then(|v| v.checked_add(digit.into())).ok_or(ParseErrorKind::InvalidValue)?;
+                }
+                Ok(Value::Int(value))
+            },
+            _       => Err(ParseErrorKind::Syntax),
+        }
+    }
+
+    /// The rest of a `"`-delimited string, after the opening `"`.  Supports `\"`, `\\`, `\t`, and `\n` escapes.
+    fn string(&mut self) -> Result<String, ParseErrorKind> {
+        let mut s = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((i, ch)) = chars.next() {
+            match ch {
+                '"'     => { self.rest = &self.rest[i+1..]; return Ok(s) },
+                '\n'    => break,
+                '\\'    => s.push(match chars.next() {
+                    Some((_, '"'))  => '"',
+                    Some((_, '\\')) => '\\',
+                    Some((_, 't'))  => '\t',
+                    Some((_, 'n'))  => '\n',
+                    _               => return Err(ParseErrorKind::Syntax),
+                }),
+                ch      => s.push(ch),
+            }
+        }
+        Err(ParseErrorKind::Syntax)
+    }
+}
+
+
+
+impl Display for Profile {
+    /// Format as the TOML subset described in the [`sandbox`](crate::sandbox) module docs, which [`str::parse`] reads back.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        writeln!(fmt, "[spawn]")?;
+        self.spawn.fmt(fmt)?;
+        writeln!(fmt)?;
+        writeln!(fmt, "[lockdown]")?;
+        self.lockdown.fmt(fmt)?;
+        writeln!(fmt)?;
+
+        let job = &self.job;
+        writeln!(fmt, "[job]")?;
+        if let Some(v) = job.active_process_limit   { writeln!(fmt, "active_process_limit = {v}")? }
+        if let Some(v) = job.job_memory_limit       { writeln!(fmt, "job_memory_limit = {v}")? }
+        if let Some(v) = job.process_memory_limit   { writeln!(fmt, "process_memory_limit = {v}")? }
+        writeln!(fmt, "kill_on_job_close = {}", job.kill_on_job_close)?;
+        writeln!(fmt, "die_on_unhandled_exception = {}", job.die_on_unhandled_exception)?;
+        write_array(fmt, "ui_restrictions", job.ui_restrictions.iter().map(|r| r.name()))?;
+        if let Some(v) = job.cpu_weight             { writeln!(fmt, "cpu_weight = {v}")? }
+        if let Some(v) = job.max_bandwidth          { writeln!(fmt, "max_bandwidth = {v}")? }
+        writeln!(fmt)?;
+
+        writeln!(fmt, "[process]")?;
+        write_array(fmt, "mitigations", self.mitigations.iter().map(|m| m.name()))
+    }
+}
+
+impl Token {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match integrity_name(self.integrity) {
+            Some(name)      => writeln!(fmt, "integrity = \"{name}\"")?,
+            None            => writeln!(fmt, "integrity = \"{}\"", *sid::Ptr::from(&self.integrity))?,
+        }
+        write_array(fmt, "privileges", self.privileges.iter().map(|p| p.name()))?;
+        write_array(fmt, "enabled", self.enabled.iter())?;
+        if let Some(restricted) = self.restricted.as_ref() { write_array(fmt, "restricted", restricted.iter())? }
+        Ok(())
+    }
+}
+
+impl Display for TokenSid {
+    /// `@user`, `@logon_session`, a two letter SDDL alias if the SID has one, or a `S-R-I-S...` string.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            TokenSid::User          => fmt.write_str("@user"),
+            TokenSid::LogonSession  => fmt.write_str("@logon_session"),
+            TokenSid::Sid(sid)      => match sid.as_sid_ptr().sddl_alias() {
+                Some(alias)             => fmt.write_str(alias),
+                None                    => Display::fmt(sid, fmt),
+            },
+        }
+    }
+}
+
+/// `key = ["a", "b", ...]`.  Names, SIDs, and aliases never contain `"` or `\`, so no escaping is needed.
+fn write_array<T: Display>(fmt: &mut Formatter, key: &str, items: impl Iterator<Item = T>) -> fmt::Result {
+    write!(fmt, "{key} = [")?;
+    for (i, item) in items.enumerate() {
+        if i != 0 { write!(fmt, ", ")? }
+        write!(fmt, "\"{item}\"")?;
+    }
+    writeln!(fmt, "]")
+}
+
+
+
+#[cfg(std)] #[test] fn round_trip() {
+    use std::string::ToString;
+
+    let text = r#"
+        # max_sandbox's ui_d3d11_window target
+        [spawn]
+        integrity   = "low"
+        privileges  = ["SeChangeNotifyPrivilege"]
+        enabled     = [
+            "BU",           # else STATUS_DLL_NOT_FOUND
+            "S-1-1-0",
+            "@logon_session",
+        ]
+        restricted  = ["BU", "WD", "@logon_session"]
+
+        [lockdown]
+        integrity   = "S-1-16-0"
+        privileges  = ["sechangenotifyprivilege"]
+        enabled     = ["BU"]
+        restricted  = ["S-1-5-32-545"]
+
+        [job]
+        active_process_limit        = 1
+        job_memory_limit            = 0x4000_0000
+        kill_on_job_close           = true
+        die_on_unhandled_exception  = true
+        ui_restrictions             = ["desktop", "handles", "readclipboard", "writeclipboard"]
+        cpu_weight                  = 5
+        max_bandwidth               = 0
+
+        [process]
+        mitigations = ["dep_enable", "heap_terminate", "control_flow_guard.export_suppression", "cet_user_shadow_stacks.strict_mode"]
+    "#;
+
+    let profile = text.parse::<Profile>().unwrap();
+    profile.validate().unwrap();
+    let users = TokenSid::Sid("S-1-5-32-545".parse().unwrap());
+    let world = TokenSid::Sid("S-1-1-0".parse().unwrap());
+    assert_eq!(profile.spawn.enabled, [users, world, TokenSid::LogonSession]);
+    assert_eq!(profile.spawn.restricted.as_deref(), Some(&[users, world, TokenSid::LogonSession][..]));
+    assert_eq!(profile.lockdown.integrity, sid::integrity::Untrusted);
+    assert_eq!(profile.lockdown.privileges, profile.spawn.privileges);
+    assert_eq!(profile.job.job_memory_limit, Some(1 << 30));
+    assert_eq!(profile.job.max_bandwidth, Some(0));
+    assert_eq!(profile.mitigations.len(), 4);
+
+    let emitted = profile.to_string();
+    assert!(emitted.contains(r#"enabled = ["BU", "WD", "@logon_session"]"#), "{emitted}");
+    assert_eq!(profile, emitted.parse().unwrap());
+
+    let default = Profile::default();
+    assert_eq!(default, "".parse().unwrap());
+    assert_eq!(default, default.to_string().parse().unwrap());
+    assert_eq!(None, default.to_string().parse::<Profile>().unwrap().spawn.restricted);
+
+    let custom = "[spawn]\nintegrity = \"S-1-16-4352\"".parse::<Profile>().unwrap();
+    assert_eq!(custom.spawn.integrity, sid::integrity::Level::new(0x1100));
+    assert_eq!(custom, custom.to_string().parse().unwrap());
+}
+
+#[cfg(std)] #[test] fn errors() {
+    use ParseErrorKind::*;
+
+    for (text, line, kind) in [
+        ("[spawn",                                              1, Syntax),
+        ("[spawn] x",                                           1, Syntax),
+        ("[sandbox]",                                           1, UnknownSection),
+        ("integrity = \"low\"",                                 1, KeyOutsideSection),
+        ("[spawn]\n[job]\n[spawn]",                             3, Duplicate),
+        ("[spawn]\nintegrity = \"low\"\nintegrity = \"low\"",   3, Duplicate),
+        ("[spawn]\n\n# comment\nintegrity \"low\"",             4, Syntax),
+        ("[spawn]\nintegrity = \"low",                          2, Syntax),
+        ("[spawn]\nintegrity = \"lo\\w\"",                      2, Syntax),
+        ("[spawn]\nintegrity = low",                            2, Syntax),
+        ("[spawn]\nintegrity = 1",                              2, WrongType),
+        ("[spawn]\nintegrity = \"lowest\"",                     2, InvalidValue),
+        ("[spawn]\nintegrity = \"S-1-5-32\"",                   2, InvalidValue),
+        ("[spawn]\ncolor = \"red\"",                            2, UnknownKey),
+        ("[spawn]\nenabled = \"WD\"",                           2, WrongType),
+        ("[spawn]\nenabled = [1]",                              2, WrongType),
+        ("[spawn]\nenabled = [\"WD\" \"BU\"]",                  2, Syntax),
+        ("[spawn]\nenabled = [\n\"WD\",\n",                     2, Syntax),
+        ("[spawn]\nenabled = [\"@admin\"]",                     2, InvalidValue),
+        ("[spawn]\nenabled = [\"DA\"]",                         2, InvalidValue),
+        ("[spawn]\nprivileges = [\"SeFakePrivilege\"]",         2, InvalidValue),
+        ("[job]\nactive_process_limit = 0x1_0000_0000",         2, InvalidValue),
+        ("[job]\nmax_bandwidth = 18446744073709551616",         2, InvalidValue),
+        ("[job]\ncpu_weight = 0x",                              2, InvalidValue),
+        ("[job]\nkill_on_job_close = 1",                        2, WrongType),
+        ("[job]\nui_restrictions = [\"clipboard\"]",            2, InvalidValue),
+        ("[process]\nmitigations = [\"aslr\"]",                 2, InvalidValue),
+        ("[process]\nintegrity = \"low\"",                      2, UnknownKey),
+    ] {
+        let err = text.parse::<Profile>().unwrap_err();
+        assert_eq!((line, kind), (err.line(), err.kind()), "{text:?}");
+    }
+}
@@ -0,0 +1,374 @@
+use crate::prelude::*;
+use sandbox::{Token, TokenSid};
+use sid::group::{ENABLED, ENABLED_BY_DEFAULT, INTEGRITY, LOGON_ID, USE_FOR_DENY_ONLY};
+
+use alloc::vec::Vec;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// The user, groups, and privileges of an existing token, as needed by [`Token::plan`]
+///
+/// Captured from a live token with [`TokenSource::from_token`] on windows, or constructed by hand
+/// (e.g. from a crash report) to plan and explain restrictions offline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenSource {
+    /// `TOKEN_USER::User.Sid`
+    pub user:       sid::Owned,
+
+    /// `TOKEN_GROUPS_AND_PRIVILEGES::Sids`, minus the user.
+    /// The logon session SID is the group with [`sid::group::LOGON_ID`].
+    pub groups:     Vec<TokenGroup>,
+
+    /// `TOKEN_GROUPS_AND_PRIVILEGES::Privileges`
+    pub privileges: Vec<sandbox::Privilege>,
+}
+
+/// A group SID of a [`TokenSource`] and its `SE_GROUP_*` attributes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TokenGroup {
+    pub sid:        sid::Owned,
+    pub attributes: sid::Attributes,
+}
+
+/// The exact [`create_restricted_token`](crate::create_restricted_token) arguments for restricting a [`TokenSource`] to a [`Token`], and their predicted effects
+///
+/// [`Display`] explains what happens to each SID and privilege, one per line.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// use sandbox::{Token, TokenGroup, TokenSid, TokenSource};
+/// let sid = |s: &str| s.parse::<sid::Owned>().unwrap();
+/// let source = TokenSource {
+///     user:       sid("S-1-5-21-1-2-3-1001"),
+///     groups:     vec![
+///         TokenGroup { sid: sid("S-1-1-0"),      attributes: sid::group::MANDATORY | sid::group::ENABLED_BY_DEFAULT | sid::group::ENABLED },
+///         TokenGroup { sid: sid("S-1-5-32-544"), attributes: sid::group::USE_FOR_DENY_ONLY },
+///         TokenGroup { sid: sid("S-1-5-5-0-99"), attributes: sid::group::MANDATORY | sid::group::ENABLED_BY_DEFAULT | sid::group::ENABLED | sid::group::LOGON_ID },
+///     ],
+///     privileges: ["SeChangeNotifyPrivilege", "SeShutdownPrivilege"].into_iter().filter_map(sandbox::Privilege::from_name).collect(),
+/// };
+/// let token = Token {
+///     integrity:  sid::integrity::Low,
+///     privileges: sandbox::Privilege::from_name("SeChangeNotifyPrivilege").into_iter().collect(),
+///     enabled:    vec![TokenSid::LogonSession],
+///     restricted: Some(vec![TokenSid::LogonSession]),
+/// };
+///
+/// let plan = token.plan(&source).unwrap();
+/// assert_eq!(plan.sids_to_disable, [sid("S-1-5-21-1-2-3-1001"), sid("S-1-1-0")]);
+/// assert_eq!(plan.privileges_to_delete, sandbox::Privilege::from_name("SeShutdownPrivilege").into_iter().collect::<Vec<_>>());
+/// assert_eq!(plan.sids_to_restrict, Some(vec![sid("S-1-5-5-0-99")]));
+/// println!("{plan}");
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenPlan {
+    /// The integrity level the restricted token will be lowered to.
+    pub integrity:              sid::integrity::Level,
+
+    /// `SidsToDisable`: SIDs that will become [`sid::group::USE_FOR_DENY_ONLY`].
+    pub sids_to_disable:        Vec<sid::Owned>,
+
+    /// `PrivilegesToDelete`
+    pub privileges_to_delete:   Vec<sandbox::Privilege>,
+
+    /// `SidsToRestrict`.  [`None`] creates a token that isn't restricted.
+    pub sids_to_restrict:       Option<Vec<sid::Owned>>,
+
+    /// The user, then every group of the [`TokenSource`], then any SIDs only mentioned by the [`Token`].
+    pub sids:                   Vec<SidPlan>,
+
+    /// Every privilege held by the [`TokenSource`] or requested by the [`Token`].
+    pub privileges:             Vec<PrivilegePlan>,
+}
+
+/// What a [`TokenPlan`] does to a single SID
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SidPlan {
+    pub sid:            sid::Owned,
+
+    /// `true` for the user SID, `false` for groups.
+    pub user:           bool,
+
+    /// The SID's attributes in the [`TokenSource`], or [`None`] if it's absent.
+    pub before:         Option<sid::Attributes>,
+
+    /// The SID's predicted attributes in the restricted token, or [`None`] if it's absent.
+    pub after:          Option<sid::Attributes>,
+
+    /// The SID is one of the restricting SIDs.
+    pub restricting:    bool,
+
+    pub action:         SidAction,
+}
+
+/// Why a [`SidPlan`] ends up the way it does
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum SidAction {
+    /// Listed in [`Token::enabled`]: left as is.
+    Keep,
+    /// Not listed in [`Token::enabled`]: passed to `SidsToDisable`, making it deny-only.
+    Disable,
+    /// Already [`sid::group::USE_FOR_DENY_ONLY`] in the source token.  Deny-only SIDs can't be re-enabled.
+    AlreadyDenyOnly,
+    /// The source token's integrity label: replaced by [`TokenPlan::integrity`] rather than disabled.
+    IntegrityLabel,
+    /// Absent from the source token, but listed in [`Token::restricted`]: only passes the restricting SID access check.
+    RestrictOnly,
+    /// Absent from the source token, but listed in [`Token::enabled`]: has no effect.
+    Missing,
+}
+
+/// What a [`TokenPlan`] does to a single privilege
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PrivilegePlan {
+    pub privilege:  sandbox::Privilege,
+    pub action:     PrivilegeAction,
+}
+
+/// Why a [`PrivilegePlan`] ends up the way it does
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum PrivilegeAction {
+    /// Listed in [`Token::privileges`]: kept.
+    Keep,
+    /// Not listed in [`Token::privileges`]: passed to `PrivilegesToDelete`.
+    Delete,
+    /// Listed in [`Token::privileges`], but not held by the source token: has no effect.
+    Missing,
+}
+
+
+
+impl TokenSource {
+    /// The group with [`sid::group::LOGON_ID`], if any.
+    pub fn logon_session(&self) -> Option<&sid::Owned> { self.groups.iter().find(|g| has(g.attributes, LOGON_ID)).map(|g| &g.sid) }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-gettokeninformation)\]
+    /// GetTokenInformation(token, TokenUser / TokenGroupsAndPrivileges, ...)
+    ///
+    /// ### Errors
+    /// *   `ERROR_NO_SUCH_PRIVILEGE`   if `token` holds a privilege [`sandbox::Privilege`] doesn't know about
+    /// *   Any error from [`token::OwnedHandle::user`] or [`token::OwnedHandle::groups_and_privileges`]
+    ///
+    #[cfg(windows)] pub fn from_token(token: &token::OwnedHandle) -> firehazard::Result<Self> {
+        let user    = token.user()?;
+        let user    = sid::Owned::from(&*user.user().sid);
+        let gap     = token.groups_and_privileges()?;
+        let groups  = gap.sids().iter().map(|g| TokenGroup { sid: (&*g.sid).into(), attributes: g.attributes }).filter(|g| g.sid != user).collect();
+        let privileges = gap.privileges().iter().map(|p| {
+            sandbox::Privilege::all().find(|k| k.luid() == u64::from(p.luid)).ok_or(firehazard::Error(ERROR_NO_SUCH_PRIVILEGE))
+        }).collect::<firehazard::Result<_>>()?;
+        Ok(Self { user, groups, privileges })
+    }
+
+    fn resolve(&self, sid: &TokenSid) -> firehazard::Result<sid::Owned> {
+        match sid {
+            TokenSid::Sid(sid)          => Ok(*sid),
+            TokenSid::User              => Ok(self.user),
+            TokenSid::LogonSession      => self.logon_session().copied().ok_or(firehazard::Error(ERROR_NO_SUCH_LOGON_SESSION)),
+        }
+    }
+}
+
+impl Token {
+    /// Compute the [`create_restricted_token`](crate::create_restricted_token) arguments that restrict `source` to this token, without touching the OS.
+    ///
+    /// Predicted attributes assume `CreateRestrictedToken`'s documented behavior:
+    /// disabled SIDs gain [`sid::group::USE_FOR_DENY_ONLY`] and lose [`sid::group::ENABLED`] / [`sid::group::ENABLED_BY_DEFAULT`],
+    /// and everything else is left untouched.
+    ///
+    /// ### Errors
+    /// *   `ERROR_NO_SUCH_LOGON_SESSION`   if `@logon_session` is used, but `source` has no logon session SID
+    ///
+    pub fn plan(&self, source: &TokenSource) -> firehazard::Result<TokenPlan> {
+        let enabled     = self.enabled.iter().map(|s| source.resolve(s)).collect::<firehazard::Result<Vec<_>>>()?;
+        let restricted  = self.restricted.as_ref().map(|r| r.iter().map(|s| source.resolve(s)).collect::<firehazard::Result<Vec<_>>>()).transpose()?;
+        let restricting = |sid: &sid::Owned| restricted.as_ref().is_some_and(|r| r.contains(sid));
+
+        let mut sids = Vec::new();
+        let mut sids_to_disable = Vec::new();
+        let user = TokenGroup { sid: source.user, attributes: sid::Attributes::default() };
+        for (is_user, group) in core::iter::once((true, &user)).chain(source.groups.iter().map(|g| (false, g))) {
+            let before = group.attributes;
+            let action = if has(before, USE_FOR_DENY_ONLY)     { SidAction::AlreadyDenyOnly }
+                else if has(before, INTEGRITY)                  { SidAction::IntegrityLabel }
+                else if enabled.contains(&group.sid)            { SidAction::Keep }
+                else                                            { SidAction::Disable };
+            let after = if action == SidAction::Disable {
+                sids_to_disable.push(group.sid);
+                (before & !(ENABLED | ENABLED_BY_DEFAULT)) | USE_FOR_DENY_ONLY
+            } else {
+                before
+            };
+            sids.push(SidPlan { sid: group.sid, user: is_user, before: Some(before), after: Some(after), restricting: restricting(&group.sid), action });
+        }
+        for sid in enabled.iter().map(|s| (s, SidAction::Missing)).chain(restricted.iter().flatten().map(|s| (s, SidAction::RestrictOnly))) {
+            let (sid, action) = sid;
+            if sids.iter().any(|s| s.sid == *sid) { continue }
+            sids.push(SidPlan { sid: *sid, user: false, before: None, after: None, restricting: restricting(sid), action });
+        }
+
+        let mut privileges = Vec::new();
+        let mut privileges_to_delete = Vec::new();
+        for &privilege in source.privileges.iter() {
+            let action = if self.privileges.contains(&privilege) { PrivilegeAction::Keep } else { PrivilegeAction::Delete };
+            if action == PrivilegeAction::Delete { privileges_to_delete.push(privilege) }
+            privileges.push(PrivilegePlan { privilege, action });
+        }
+        for &privilege in self.privileges.iter().filter(|p| !source.privileges.contains(p)) {
+            privileges.push(PrivilegePlan { privilege, action: PrivilegeAction::Missing });
+        }
+
+        Ok(TokenPlan { integrity: self.integrity, sids_to_disable, privileges_to_delete, sids_to_restrict: restricted, sids, privileges })
+    }
+}
+
+impl TokenPlan {
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-createrestrictedtoken)\]
+    /// CreateRestrictedToken +
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-settokeninformation)\]
+    /// SetTokenInformation(..., TokenIntegrityLevel, ...)
+    ///
+    /// Create a primary token from `existing` (which should be the token this plan's [`TokenSource`] was captured from) using exactly this plan's arguments.
+    ///
+    /// ### Errors
+    /// *   `ERROR_NO_SUCH_PRIVILEGE`       if a privilege isn't known to this version of Windows
+    /// *   Any error from [`create_restricted_token`](crate::create_restricted_token) or [`token::OwnedHandle::set_integrity_level`]
+    ///
+    #[cfg(windows)] pub fn create_restricted_token(&self, existing: &token::OwnedHandle) -> firehazard::Result<token::OwnedHandle> {
+        let sids_to_disable         = self.sids_to_disable.iter().map(|sid| sid::AndAttributes::new(sid, None)).collect::<Vec<_>>();
+        let privileges_to_delete    = self.privileges_to_delete.iter().map(|p| Ok(privilege::LuidAndAttributes::new(privilege::Name::from(*p).lookup_luid()?, ()))).collect::<firehazard::Result<Vec<_>>>()?;
+        let sids_to_restrict        = self.sids_to_restrict.as_ref().map(|r| r.iter().map(|sid| sid::AndAttributes::new(sid, None)).collect::<Vec<_>>());
+
+        let token = create_restricted_token(existing, None, Some(&sids_to_disable[..]), Some(&privileges_to_delete[..]), sids_to_restrict.as_deref())?;
+        token.set_integrity_level(sid::AndAttributes::new(&self.integrity, None))?;
+        Ok(token)
+    }
+}
+
+fn has(attributes: sid::Attributes, flag: sid::Attributes) -> bool { u32::from(attributes) & u32::from(flag) != 0 }
+
+impl Display for TokenPlan {
+    /// One line per SID and privilege, e.g.:
+    ///
+    /// ```text
+    /// integrity   S-1-16-4096 (low)
+    /// user        S-1-5-21-1-2-3-1001 (@user): disabled (not in `enabled`), deny-only
+    /// group       S-1-5-5-0-99 (@logon_session): kept, restricting
+    /// privilege   SeShutdownPrivilege: deleted (not in `privileges`)
+    /// ```
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match super::sandbox_profile_text::integrity_name(self.integrity) {
+            Some(name)  => writeln!(fmt, "integrity   {} ({name})", *sid::Ptr::from(&self.integrity))?,
+            None        => writeln!(fmt, "integrity   {}", *sid::Ptr::from(&self.integrity))?,
+        }
+
+        for s in self.sids.iter() {
+            let kind = if s.user { "user" } else if s.before.is_some() { "group" } else { "sid" };
+            write!(fmt, "{kind:11} {}", s.sid)?;
+            let sid = s.sid.as_sid_ptr();
+            if s.user                                           { write!(fmt, " ({})", TokenSid::User)? }
+            else if s.before.is_some_and(|a| has(a, LOGON_ID))  { write!(fmt, " ({})", TokenSid::LogonSession)? }
+            else if let Some(name) = sid.well_known_name() {
+                match sid.sddl_alias() {
+                    Some(alias)                                 => write!(fmt, " ({alias}, {name})")?,
+                    None                                        => write!(fmt, " ({name})")?,
+                }
+            }
+            fmt.write_str(match s.action {
+                SidAction::Keep             => ": kept",
+                SidAction::Disable          => ": disabled (not in `enabled`), deny-only",
+                SidAction::AlreadyDenyOnly  => ": already deny-only",
+                SidAction::IntegrityLabel   => ": integrity label, replaced",
+                SidAction::RestrictOnly     => ": not in source token, restricting only",
+                SidAction::Missing          => ": not in source token, ignored",
+            })?;
+            if s.restricting && s.action != SidAction::RestrictOnly { fmt.write_str(", restricting")? }
+            if s.action == SidAction::Keep && !s.user && s.before.is_some_and(|a| !has(a, ENABLED)) { fmt.write_str(" (but not enabled)")? }
+            writeln!(fmt)?;
+        }
+
+        for p in self.privileges.iter() {
+            writeln!(fmt, "privilege   {}{}", p.privilege.name(), match p.action {
+                PrivilegeAction::Keep       => ": kept",
+                PrivilegeAction::Delete     => ": deleted (not in `privileges`)",
+                PrivilegeAction::Missing    => ": not held by source token, ignored",
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+
+
+#[cfg(std)] #[test] fn plan() {
+    use std::string::ToString;
+    use alloc::vec;
+    use sid::group::MANDATORY;
+
+    let sid = |s: &str| s.parse::<sid::Owned>().unwrap();
+    let privilege = |name| sandbox::Privilege::from_name(name).unwrap();
+    let normal = MANDATORY | ENABLED_BY_DEFAULT | ENABLED;
+
+    let source = TokenSource {
+        user:       sid("S-1-5-21-1-2-3-1001"),
+        groups:     vec![
+            TokenGroup { sid: sid("S-1-1-0"),       attributes: normal },
+            TokenGroup { sid: sid("S-1-5-32-545"),  attributes: normal },
+            TokenGroup { sid: sid("S-1-5-32-544"),  attributes: USE_FOR_DENY_ONLY },
+            TokenGroup { sid: sid("S-1-5-5-0-99"),  attributes: normal | LOGON_ID },
+            TokenGroup { sid: sid("S-1-16-8192"),   attributes: INTEGRITY | sid::group::INTEGRITY_ENABLED },
+        ],
+        privileges: vec![privilege("SeChangeNotifyPrivilege"), privilege("SeShutdownPrivilege")],
+    };
+    assert_eq!(Some(&sid("S-1-5-5-0-99")), source.logon_session());
+
+    let token = Token {
+        integrity:  sid::integrity::Low,
+        privileges: vec![privilege("SeChangeNotifyPrivilege"), privilege("SeDebugPrivilege")],
+        enabled:    vec![TokenSid::User, TokenSid::Sid(sid("S-1-5-32-544")), TokenSid::Sid(sid("S-1-5-11"))],
+        restricted: Some(vec![TokenSid::User, TokenSid::LogonSession, TokenSid::Sid(sid("S-1-0-0"))]),
+    };
+    let plan = token.plan(&source).unwrap();
+    assert_eq!(plan.sids_to_disable, [sid("S-1-1-0"), sid("S-1-5-32-545"), sid("S-1-5-5-0-99")]);
+    assert_eq!(plan.privileges_to_delete, [privilege("SeShutdownPrivilege")]);
+    assert_eq!(plan.sids_to_restrict, Some(vec![sid("S-1-5-21-1-2-3-1001"), sid("S-1-5-5-0-99"), sid("S-1-0-0")]));
+
+    let actions = plan.sids.iter().map(|s| (s.sid, s.action, s.after, s.restricting)).collect::<Vec<_>>();
+    assert_eq!(actions, [
+        (sid("S-1-5-21-1-2-3-1001"),    SidAction::Keep,            Some(sid::Attributes::default()),       true ),
+        (sid("S-1-1-0"),                SidAction::Disable,         Some(MANDATORY | USE_FOR_DENY_ONLY),    false),
+        (sid("S-1-5-32-545"),           SidAction::Disable,         Some(MANDATORY | USE_FOR_DENY_ONLY),    false),
+        (sid("S-1-5-32-544"),           SidAction::AlreadyDenyOnly, Some(USE_FOR_DENY_ONLY),                false),
+        (sid("S-1-5-5-0-99"),           SidAction::Disable,         Some(MANDATORY | LOGON_ID | USE_FOR_DENY_ONLY), true),
+        (sid("S-1-16-8192"),            SidAction::IntegrityLabel,  Some(INTEGRITY | sid::group::INTEGRITY_ENABLED), false),
+        (sid("S-1-5-11"),               SidAction::Missing,         None,                                   false),
+        (sid("S-1-0-0"),                SidAction::RestrictOnly,    None,                                   true ),
+    ]);
+    let privileges = plan.privileges.iter().map(|p| (p.privilege.name(), p.action)).collect::<Vec<_>>();
+    assert_eq!(privileges, [
+        ("SeChangeNotifyPrivilege",     PrivilegeAction::Keep),
+        ("SeShutdownPrivilege",         PrivilegeAction::Delete),
+        ("SeDebugPrivilege",            PrivilegeAction::Missing),
+    ]);
+
+    let text = plan.to_string();
+    for line in [
+        "integrity   S-1-16-4096 (low)\n",
+        "user        S-1-5-21-1-2-3-1001 (@user): kept, restricting\n",
+        "group       S-1-1-0 (WD, Everyone): disabled (not in `enabled`), deny-only\n",
+        "group       S-1-5-5-0-99 (@logon_session): disabled (not in `enabled`), deny-only, restricting\n",
+        "sid         S-1-0-0 (NULL SID): not in source token, restricting only\n",
+        "privilege   SeShutdownPrivilege: deleted (not in `privileges`)\n",
+        "privilege   SeDebugPrivilege: not held by source token, ignored\n",
+    ] {
+        assert!(text.contains(line), "missing {line:?} in:\n{text}");
+    }
+
+    let no_logon = TokenSource { groups: Vec::new(), ..source };
+    assert_eq!(Err(firehazard::Error(ERROR_NO_SUCH_LOGON_SESSION)), token.plan(&no_logon));
+}
@@ -1,8 +1,14 @@
 //! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-security_descriptor)\]
 //! [`Attributes`], [`Descriptor`], [`DescriptorBuilder`], etc.
 
-mod security_attributes;            pub use security_attributes::*;
-mod security_capabilities;          pub use security_capabilities::*;
-mod security_descriptor;            pub use security_descriptor::*;
-mod security_descriptor_builder;    pub use security_descriptor_builder::*;
-mod security_impersonation_level;   pub use security_impersonation_level::*;
+#[cfg(alloc)] #[path = "access_check/_access_check.rs"] pub mod access_check;
+#[path = "sddl/_sddl.rs"] pub mod sddl;
+
+#[cfg(windows)] mod security_attributes;              #[cfg(windows)] pub use security_attributes::*;
+#[cfg(windows)] mod security_capabilities;            #[cfg(windows)] pub use security_capabilities::*;
+mod security_descriptor;                              pub use security_descriptor::*;
+#[cfg(windows)] mod security_descriptor_builder;      #[cfg(windows)] pub use security_descriptor_builder::*;
+mod security_descriptor_control;                      pub use security_descriptor_control::*;
+#[cfg(alloc)] mod security_descriptor_relative;       #[cfg(alloc)] pub use security_descriptor_relative::*;
+#[cfg(alloc)] mod security_descriptor_relative_error; #[cfg(alloc)] pub use security_descriptor_relative_error::*;
+#[cfg(windows)] mod security_impersonation_level;     #[cfg(windows)] pub use security_impersonation_level::*;
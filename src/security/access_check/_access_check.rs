@@ -0,0 +1,51 @@
+//! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/how-dacls-control-access-to-an-object)\]
+//! Offline model of `AccessCheck`: which rights would a token be granted by a security descriptor?
+//!
+//! Unlike `AccessCheck`, this doesn't require a real token or calling into `advapi32.dll`,
+//! so descriptors can be audited on any host, against tokens that don't (yet) exist.
+//! Every decision is recorded in [`Outcome::trace`], explaining *why* access was granted or denied.
+//!
+//! ### Model
+//! 1.  `GENERIC_*` rights in the desired access mask, and in each ACE, are mapped through an [`access::GenericMapping`].
+//! 2.  `ACCESS_SYSTEM_SECURITY` requires [`Privileges::security`].
+//!     `WRITE_OWNER` is granted by [`Privileges::take_ownership`].
+//! 3.  The mandatory label (the first `SYSTEM_MANDATORY_LABEL_ACE` of the SACL, or `Medium` + `NO_WRITE_UP` if absent)
+//!     removes write, read, and/or execute rights if the token's integrity level is lower.
+//! 4.  The owner is implicitly granted `READ_CONTROL | WRITE_DAC`, unless the DACL has an `OWNER RIGHTS` (`S-1-3-4`) ACE.
+//! 5.  The DACL is walked in order: the first ACE to allow or deny a given right decides it.
+//!     A null or absent DACL grants everything.
+//! 6.  Restricted tokens repeat the walk using only their restricted SIDs, and are only granted rights both walks grant.
+//!     Write restricted tokens only repeat the walk for write rights.
+//! 7.  AppContainer tokens repeat the walk using only their AppContainer SID, capabilities, and `ALL APPLICATION PACKAGES` (`S-1-15-2-1`).
+//! 8.  Callback ACEs only apply if their [conditional expression](ace::condition) is `TRUE` (or, for deny ACEs, `UNKNOWN`.)
+//!     Application data that isn't a valid conditional expression is `UNKNOWN`, so it fails closed.
+//!     `@User.*` / `@Device.*` claims come from the [`Token`], `@Resource.*` attributes from the SACL's resource attribute ACEs,
+//!     and `Member_of*` is evaluated against the SIDs of the current walk.
+//!
+//! ### Examples
+//! ```
+//! # use firehazard::*;
+//! use security::access_check::{self, Token};
+//!
+//! let sd = security::RelativeDescriptor::from_sddl("D:(A;;FA;;;WD)S:(ML;;NW;;;ME)").unwrap();
+//! let everyone = [sid::AndAttributes::new(sid!(S-1-1-0), sid::group::ENABLED)];
+//! let token = Token { groups: &everyone, integrity: sid::integrity::Low, ..Token::new(sid!(S-1-5-21-1-2-3-1001)) };
+//!
+//! let read  = access_check::evaluate(sd.as_descriptor(), &token, access::GENERIC_READ,  &access::GenericMapping::FILE);
+//! let write = access_check::evaluate(sd.as_descriptor(), &token, access::GENERIC_WRITE, &access::GenericMapping::FILE);
+//! assert!( read.is_allowed());
+//! assert!(!write.is_allowed()); // blocked by the Medium integrity label
+//! ```
+//!
+//! ### Limitations
+//! *   Object type lists aren't supported: object ACEs with an `ObjectType` are skipped.
+//! *   `SeBackupPrivilege` / `SeRestorePrivilege` (backup intent), and central access policies, aren't modeled.
+//!
+//! ### References
+//! *   [How DACLs Control Access to an Object](https://learn.microsoft.com/en-us/windows/win32/secauthz/how-dacls-control-access-to-an-object)
+//! *   [How AccessCheck Works](https://learn.microsoft.com/en-us/windows/win32/secauthz/how-accesscheck-works)
+//! *   [Mandatory Integrity Control](https://learn.microsoft.com/en-us/windows/win32/secauthz/mandatory-integrity-control)
+
+mod access_check_evaluate;              pub use access_check_evaluate::*;
+mod access_check_outcome;               pub use access_check_outcome::*;
+mod access_check_token;                 pub use access_check_token::*;
@@ -0,0 +1,304 @@
+use crate::prelude::*;
+use super::*;
+
+use winapi::um::winnt::*;
+
+use alloc::vec::Vec;
+
+
+
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-accesscheck)\]
+/// ≈ AccessCheck, without a real token or calling into `advapi32.dll`
+///
+/// See the [module documentation](self) for what is (and isn't) modeled.
+///
+pub fn evaluate(sd: &security::Descriptor, token: &Token, desired: impl Into<access::Mask>, mapping: &access::GenericMapping) -> Outcome {
+    let mut trace = Vec::new();
+    let desired = u32::from(mapping.map(desired));
+    let maximum_allowed = desired & MAXIMUM_ALLOWED != 0;
+    let desired = desired & !MAXIMUM_ALLOWED;
+    let wanted = if maximum_allowed { desired | STANDARD_RIGHTS_ALL | SPECIFIC_RIGHTS_ALL } else { desired };
+
+    let mut privileged = 0;
+    if wanted & ACCESS_SYSTEM_SECURITY != 0 {
+        let held = token.privileges.security;
+        if held { privileged |= ACCESS_SYSTEM_SECURITY }
+        trace.push(Step::Privilege { privilege: Privilege::Security, held, granted: mask(privileged & ACCESS_SYSTEM_SECURITY) });
+    }
+    if wanted & WRITE_OWNER != 0 && token.privileges.take_ownership {
+        privileged |= WRITE_OWNER;
+        trace.push(Step::Privilege { privilege: Privilege::TakeOwnership, held: true, granted: mask(WRITE_OWNER) });
+    }
+
+    let blocked = mandatory_label(sd, token, mapping, wanted, &mut trace);
+    let resource_attributes = sd.sacl().into_iter().flat_map(|sacl| sacl.aces()).filter(|ace| !inherit_only(*ace)).filter_map(|ace| ace.resource_attribute()?.ok()).collect::<Vec<_>>();
+    let context = ace::condition::Context {
+        user_claims:            token.user_claims,
+        device_claims:          token.device_claims,
+        resource_attributes:    &resource_attributes,
+        device_groups:          token.device_groups,
+        ..Default::default()
+    };
+    let walk = Walk { sd, mapping, wanted: wanted & !ACCESS_SYSTEM_SECURITY & !privileged };
+
+    let user = |sid: &sid::Value, deny: bool| {
+        let user = *token.user.sid == *sid && (deny || u32::from(token.user.attributes) & SE_GROUP_USE_FOR_DENY_ONLY == 0);
+        user || holds(token.groups, sid, deny)
+    };
+    let user_and_groups = core::iter::once(if u32::from(token.user.attributes) & SE_GROUP_USE_FOR_DENY_ONLY != 0 { token.user } else { sid::AndAttributes::new(token.user.sid, sid::group::ENABLED) })
+        .chain(token.groups.iter().copied()).collect::<Vec<_>>();
+    let mut granted = walk.run(Pass::Normal, &user, &ace::condition::Context { groups: &user_and_groups, ..context }, &mut trace);
+
+    if !token.restricted_sids.is_empty() {
+        let restricted = walk.run(Pass::Restricted, &|sid, deny| holds(token.restricted_sids, sid, deny), &ace::condition::Context { groups: token.restricted_sids, ..context }, &mut trace);
+        let unrestricted = if token.write_restricted { !write_rights(mapping) } else { 0 };
+        granted &= restricted | unrestricted;
+    }
+
+    if let Some(app_container) = token.app_container {
+        let package = |sid: &sid::Value, deny: bool| {
+            *app_container == *sid || *sid!(S-1-15-2-1) == *sid || holds(token.capabilities, sid, deny)
+        };
+        let packages = [app_container, sid!(S-1-15-2-1)].into_iter().map(|sid| sid::AndAttributes::new(sid, sid::group::ENABLED))
+            .chain(token.capabilities.iter().copied()).collect::<Vec<_>>();
+        granted &= walk.run(Pass::AppContainer, &package, &ace::condition::Context { groups: &packages, ..context }, &mut trace);
+    }
+
+    let granted = (granted | privileged) & wanted & !blocked;
+    Outcome { desired: mask(desired), maximum_allowed, granted: mask(granted), trace }
+}
+
+
+
+struct Walk<'s> {
+    sd:         &'s security::Descriptor<'s>,
+    mapping:    &'s access::GenericMapping,
+    wanted:     u32,
+}
+
+impl Walk<'_> {
+    /// Walk the DACL for a single set of SIDs, returning the granted rights.
+    fn run(&self, pass: Pass, holds: &dyn Fn(&sid::Value, bool) -> bool, context: &ace::condition::Context, trace: &mut Vec<Step>) -> u32 {
+        let wanted = self.wanted;
+        let Some(dacl) = self.sd.dacl() else {
+            trace.push(Step::NullDacl { pass, granted: mask(wanted) });
+            return wanted;
+        };
+
+        let owner_rights = sid!(S-1-3-4);
+        let is_owner = self.sd.owner().is_some_and(|owner| holds(&owner, false));
+        let mut granted = 0;
+        let mut denied  = 0;
+
+        let has_owner_rights_ace = dacl.aces().any(|ace| !inherit_only(ace) && ace.sid().is_some_and(|sid| *sid == *owner_rights));
+        if is_owner && !has_owner_rights_ace {
+            granted = (READ_CONTROL | WRITE_DAC) & wanted;
+            trace.push(Step::OwnerRights { pass, granted: mask(granted) });
+        }
+
+        for (index, ace) in dacl.aces().enumerate() {
+            if wanted & !granted == 0 { break }
+            let effect = 'effect: {
+                let ty = ace.header().ty;
+                let deny = match ty {
+                    ace::Type::ACCESS_ALLOWED   | ace::Type::ACCESS_ALLOWED_OBJECT  => false,
+                    ace::Type::ACCESS_DENIED    | ace::Type::ACCESS_DENIED_OBJECT   => true,
+                    ace::Type::ACCESS_ALLOWED_CALLBACK | ace::Type::ACCESS_ALLOWED_CALLBACK_OBJECT => false,
+                    ace::Type::ACCESS_DENIED_CALLBACK  | ace::Type::ACCESS_DENIED_CALLBACK_OBJECT  => true,
+                    _                                                               => break 'effect AceEffect::Ignored,
+                };
+                if inherit_only(ace)            { break 'effect AceEffect::InheritOnly }
+                let (Some(ace_mask), Some(sid)) = (ace.mask(), ace.sid()) else { break 'effect AceEffect::Malformed };
+                if ace.object_type().is_some()  { break 'effect AceEffect::ObjectSpecific }
+
+                let applies = if *sid == *owner_rights { is_owner } else { holds(&sid, deny) };
+                if !applies { break 'effect AceEffect::SidNotPresent }
+
+                if ty.is_callback() {
+                    // An undecodable condition is UNKNOWN: fail closed, applying deny ACEs but not allow ACEs.
+                    let result = match ace::condition::Expression::from_bytes(ace.application_data()) {
+                        Ok(condition)   => condition.evaluate(&ace::condition::Context { deny, ..*context }),
+                        Err(_)          => None,
+                    };
+                    if result != Some(true) && !(deny && result.is_none()) { break 'effect AceEffect::ConditionNotMet }
+                }
+
+                let decided = u32::from(self.mapping.map(ace_mask)) & wanted & !granted & !denied;
+                if decided == 0 { break 'effect AceEffect::NoEffect }
+                if deny {
+                    denied |= decided;
+                    AceEffect::Denied(mask(decided))
+                } else {
+                    granted |= decided;
+                    AceEffect::Allowed(mask(decided))
+                }
+            };
+            trace.push(Step::Ace { pass, index, effect });
+        }
+
+        granted
+    }
+}
+
+/// Determine which rights the mandatory label blocks.
+fn mandatory_label(sd: &security::Descriptor, token: &Token, mapping: &access::GenericMapping, wanted: u32, trace: &mut Vec<Step>) -> u32 {
+    if !token.mandatory_policy_no_write_up { return 0 }
+
+    let label = sd.sacl().into_iter().flat_map(|sacl| sacl.aces().enumerate()).find_map(|(index, ace)| {
+        let label = ace::MandatoryLabel::from_ace(ace).filter(|label| !label.is_inherit_only())?;
+        Some((Some(index), label.level, label.policy))
+    });
+    let (ace, label, policy) = label.unwrap_or((None, sid::integrity::Medium, ace::MandatoryLabelPolicy::NO_WRITE_UP));
+
+    let mut blocked = 0;
+    if token.integrity < label {
+        let mut permitted = 0;
+        for (flag, rights) in [
+            (SYSTEM_MANDATORY_LABEL_NO_WRITE_UP,    write_rights(mapping)               ),
+            (SYSTEM_MANDATORY_LABEL_NO_READ_UP,     u32::from(mapping.generic_read)     ),
+            (SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP,  u32::from(mapping.generic_execute)  ),
+        ] {
+            if u32::from(policy) & flag != 0 { blocked |= rights } else { permitted |= rights }
+        }
+        blocked &= !permitted; // e.g. READ_CONTROL is part of both FILE_GENERIC_WRITE and FILE_GENERIC_READ
+    }
+
+    trace.push(Step::MandatoryLabel { ace, label, policy, blocked: mask(blocked & wanted) });
+    blocked
+}
+
+fn holds(sids: &[sid::AndAttributes], sid: &sid::Value, deny: bool) -> bool {
+    let usable = if deny { SE_GROUP_ENABLED | SE_GROUP_USE_FOR_DENY_ONLY } else { SE_GROUP_ENABLED };
+    sids.iter().any(|s| *s.sid == *sid && u32::from(s.attributes) & usable != 0 && (deny || u32::from(s.attributes) & SE_GROUP_USE_FOR_DENY_ONLY == 0))
+}
+
+fn write_rights(mapping: &access::GenericMapping) -> u32 { u32::from(mapping.generic_write) | DELETE | WRITE_DAC | WRITE_OWNER }
+fn inherit_only(ace: ace::Ref) -> bool { ace.header().flags.as_u8() & INHERIT_ONLY_ACE != 0 }
+fn mask(mask: u32) -> access::Mask { unsafe { access::Mask::from_unchecked(mask) } }
+
+
+
+#[cfg(std)] #[test] fn evaluate_tokens() {
+    use std::{format, vec};
+
+    let file = &access::GenericMapping::FILE;
+    let check = |sddl: &str, token: &Token, desired: u32| {
+        let sd = security::RelativeDescriptor::from_sddl(sddl).unwrap();
+        let outcome = evaluate(sd.as_descriptor(), token, mask(desired), file);
+        (outcome.is_allowed(), u32::from(outcome.granted), outcome)
+    };
+
+    let user        = sid!(S-1-5-21-1-2-3-1001);
+    let enabled     = |sid| sid::AndAttributes::new(sid, sid::group::ENABLED);
+    let groups      = [enabled(sid!(S-1-1-0)), enabled(sid!(S-1-5-32-545))];
+    let token       = Token { groups: &groups, ..Token::new(user) };
+
+    // deny first
+    assert!( check("D:(D;;FW;;;BU)(A;;FA;;;WD)", &token, FILE_READ_DATA).0);
+    assert!(!check("D:(D;;FW;;;BU)(A;;FA;;;WD)", &token, FILE_GENERIC_READ).0); // FW includes READ_CONTROL | SYNCHRONIZE
+    assert!(!check("D:(D;;FW;;;BU)(A;;FA;;;WD)", &token, FILE_GENERIC_WRITE).0);
+    assert!( check("D:(A;;FA;;;WD)(D;;FW;;;BU)", &token, FILE_GENERIC_WRITE).0); // non-canonical: first match wins
+    assert!( check("D:(A;;GA;;;WD)",             &token, FILE_ALL_ACCESS).0); // ACE generic rights are mapped
+    assert!(!check("D:(A;IO;FA;;;WD)",           &token, FILE_GENERIC_READ).0);
+
+    // null vs empty DACL
+    assert_eq!(check("D:NO_ACCESS_CONTROL",        &token, MAXIMUM_ALLOWED).1,    STANDARD_RIGHTS_ALL | SPECIFIC_RIGHTS_ALL);
+    assert!( check("",                           &token, FILE_ALL_ACCESS).0);
+    assert!(!check("D:",                         &token, READ_CONTROL).0);
+    assert!(!check("D:",                         &token, MAXIMUM_ALLOWED).0);
+
+    // owner rights
+    let owned = "O:S-1-5-21-1-2-3-1001D:";
+    assert_eq!(check(owned,                        &token, MAXIMUM_ALLOWED).1,    READ_CONTROL | WRITE_DAC);
+    assert_eq!(check("O:S-1-5-21-1-2-3-1001D:(A;;RC;;;OW)", &token, MAXIMUM_ALLOWED).1, READ_CONTROL);
+
+    // deny only groups
+    let deny_only   = [sid::AndAttributes::new(sid!(S-1-5-32-544), sid::group::USE_FOR_DENY_ONLY)];
+    let filtered    = Token { groups: &deny_only, ..Token::new(user) };
+    assert!(!check("D:(A;;FA;;;BA)",             &filtered, FILE_GENERIC_READ).0);
+    assert_eq!(check("D:(D;;FW;;;BA)(A;;FA;;;S-1-5-21-1-2-3-1001)", &filtered, FILE_ALL_ACCESS).1, FILE_ALL_ACCESS & !FILE_GENERIC_WRITE);
+
+    // privileges
+    assert!(!check("D:NO_ACCESS_CONTROL",        &token, ACCESS_SYSTEM_SECURITY).0);
+    let security    = Token { privileges: Privileges { security: true, take_ownership: true }, ..token };
+    assert!( check("D:NO_ACCESS_CONTROL",        &security, ACCESS_SYSTEM_SECURITY).0);
+    assert!( check("D:",                         &security, WRITE_OWNER).0);
+
+    // mandatory labels
+    let low = Token { integrity: sid::integrity::Low, ..token };
+    assert!( check("D:(A;;FA;;;WD)",             &low, FILE_GENERIC_READ).0);
+    assert!(!check("D:(A;;FA;;;WD)",             &low, FILE_GENERIC_WRITE).0); // default label: Medium + NO_WRITE_UP
+    assert!( check("D:(A;;FA;;;WD)S:(ML;;NW;;;LW)", &low, FILE_GENERIC_WRITE).0);
+    assert_eq!(check("D:(A;;FA;;;WD)S:(ML;;NRNX;;;HI)", &token, FILE_GENERIC_READ).1, READ_CONTROL | SYNCHRONIZE); // shared with FILE_GENERIC_WRITE
+    assert!( check("D:(A;;FA;;;WD)S:(ML;;NW;;;HI)", &Token { mandatory_policy_no_write_up: false, ..token }, FILE_GENERIC_WRITE).0);
+    let (_, _, outcome) = check("D:(A;;FA;;;WD)S:(ML;;NW;;;ME)", &low, DELETE);
+    assert_eq!(outcome.trace[0], Step::MandatoryLabel { ace: Some(0), label: sid::integrity::Medium, policy: ace::MandatoryLabelPolicy::NO_WRITE_UP, blocked: mask(DELETE) });
+
+    // restricted tokens
+    let restricting = [enabled(sid!(S-1-1-0))];
+    let restricted  = Token { restricted_sids: &restricting, ..token };
+    let dacl        = "D:(A;;FA;;;BU)(A;;FR;;;WD)";
+    assert_eq!(check(dacl,                         &token,      MAXIMUM_ALLOWED).1, FILE_ALL_ACCESS);
+    assert_eq!(check(dacl,                         &restricted, MAXIMUM_ALLOWED).1, FILE_GENERIC_READ);
+    let write_restricted = Token { write_restricted: true, ..restricted };
+    assert!( check(dacl,                         &write_restricted, FILE_GENERIC_EXECUTE).0);
+    assert!(!check(dacl,                         &write_restricted, FILE_GENERIC_WRITE).0);
+
+    // AppContainers
+    let capabilities = [enabled(sid!(S-1-15-3-1))];
+    let app     = Token { app_container: Some(sid!(S-1-15-2-1-2-3-4-5-6-7)), integrity: sid::integrity::Low, ..token };
+    let dacl    = "D:(A;;FA;;;WD)(A;;FR;;;AC)(A;;FW;;;S-1-15-3-1)S:(ML;;NW;;;LW)";
+    assert_eq!(check(dacl,                         &app, MAXIMUM_ALLOWED).1, FILE_GENERIC_READ);
+    assert_eq!(check(dacl,                         &Token { capabilities: &capabilities, ..app }, MAXIMUM_ALLOWED).1, FILE_GENERIC_READ | FILE_GENERIC_WRITE);
+
+    // trace
+    let (_, _, outcome) = check("D:(D;;FW;;;BA)(A;;FR;;;BU)(A;;FA;;;WD)", &token, FILE_GENERIC_READ);
+    assert_eq!(&outcome.trace[1..], &[
+        Step::Ace { pass: Pass::Normal, index: 0, effect: AceEffect::SidNotPresent },
+        Step::Ace { pass: Pass::Normal, index: 1, effect: AceEffect::Allowed(mask(FILE_GENERIC_READ)) },
+    ]);
+    assert!(format!("{outcome}").contains("ACE 1: allowed 0x00120089"));
+
+    // conditional ACEs
+    let dacl = security::RelativeDescriptor::from_sddl(concat!("D:",
+        "(XD;;FW;;;WD;(@User.Project != \"firehazard\"))",
+        "(XA;;FA;;;WD;(@User.Clearance >= @Resource.Secrecy))",
+        "(XA;;FR;;;WD;(Member_of {SID(BA)}))",
+    )).unwrap();
+    let dacl = acl::Builder::from_acl(dacl.dacl().unwrap());
+    let mut sacl = acl::Builder::new(acl::REVISION);
+    sacl.add_resource_attribute(acl::REVISION, ace::Flags::default(), &claim::Attribute::new("Secrecy", claim::Values::Uint64(vec![2]))).unwrap();
+    let control = security::DescriptorControl::DACL_PRESENT | security::DescriptorControl::SACL_PRESENT;
+    let sd = security::RelativeDescriptor::from_parts(control, None, None, Some(sacl.as_bytes()), Some(dacl.as_bytes()));
+    let claims = |clearance: i64, project: &str| [
+        claim::Attribute::new("Clearance",  claim::Values::Int64(vec![clearance])),
+        claim::Attribute::new("Project",    claim::Values::String(vec![project.into()])),
+    ];
+    let check = |token: &Token| u32::from(evaluate(sd.as_descriptor(), token, mask(MAXIMUM_ALLOWED), file).granted);
+    let admins = [enabled(sid!(S-1-1-0)), enabled(sid!(S-1-5-32-544))];
+    assert_eq!(check(&token),                                                           0); // no claims: UNKNOWN denies FW, but doesn't allow FA
+    assert_eq!(check(&Token { user_claims: &claims(3, "firehazard"), ..token }),        FILE_ALL_ACCESS);
+    assert_eq!(check(&Token { user_claims: &claims(3, "other"), ..token }),             FILE_ALL_ACCESS & !FILE_GENERIC_WRITE);
+    assert_eq!(check(&Token { user_claims: &claims(1, "firehazard"), ..token }),        0);
+    assert_eq!(check(&Token { user_claims: &claims(1, "firehazard"), groups: &admins, ..token }), FILE_GENERIC_READ);
+    let outcome = evaluate(sd.as_descriptor(), &token, mask(FILE_GENERIC_READ), file);
+    assert_eq!(outcome.trace[1..], [
+        Step::Ace { pass: Pass::Normal, index: 0, effect: AceEffect::Denied(mask(READ_CONTROL | SYNCHRONIZE)) },
+        Step::Ace { pass: Pass::Normal, index: 1, effect: AceEffect::ConditionNotMet },
+        Step::Ace { pass: Pass::Normal, index: 2, effect: AceEffect::ConditionNotMet },
+    ]);
+
+    // malformed conditions are UNKNOWN: deny ACEs apply, allow ACEs don't
+    let mut dacl = acl::Builder::new(acl::REVISION);
+    dacl.push(acl::REVISION, acl::Ace::new(ace::Type::ACCESS_DENIED_CALLBACK,  ace::Flags::default(), FILE_GENERIC_WRITE, sid!(S-1-1-0)).application_data(b"artx\xFF\xFF\xFF\xFF")).unwrap();
+    dacl.push(acl::REVISION, acl::Ace::new(ace::Type::ACCESS_ALLOWED_CALLBACK, ace::Flags::default(), FILE_ALL_ACCESS,    sid!(S-1-1-0)).application_data(b"artx\xFF\xFF\xFF\xFF")).unwrap();
+    dacl.push(acl::REVISION, acl::Ace::new(ace::Type::ACCESS_ALLOWED,          ace::Flags::default(), FILE_GENERIC_READ,  sid!(S-1-1-0))).unwrap();
+    let sd = security::RelativeDescriptor::from_parts(security::DescriptorControl::DACL_PRESENT, None, None, None, Some(dacl.as_bytes()));
+    let outcome = evaluate(sd.as_descriptor(), &token, mask(FILE_GENERIC_READ | FILE_GENERIC_WRITE), file);
+    assert_eq!(u32::from(outcome.granted), FILE_GENERIC_READ & !FILE_GENERIC_WRITE);
+    assert_eq!(outcome.trace[1..], [
+        Step::Ace { pass: Pass::Normal, index: 0, effect: AceEffect::Denied(mask(FILE_GENERIC_WRITE)) },
+        Step::Ace { pass: Pass::Normal, index: 1, effect: AceEffect::ConditionNotMet },
+        Step::Ace { pass: Pass::Normal, index: 2, effect: AceEffect::Allowed(mask(FILE_GENERIC_READ & !FILE_GENERIC_WRITE)) },
+    ]);
+}
@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+use alloc::vec::Vec;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// The result of [`evaluate`](super::evaluate)
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    /// The desired access, after generic mapping, excluding `MAXIMUM_ALLOWED`.
+    pub desired:            access::Mask,
+
+    /// If `MAXIMUM_ALLOWED` was requested.
+    pub maximum_allowed:    bool,
+
+    /// The rights that would be granted.
+    ///
+    /// Unlike `AccessCheck`, which reports no rights at all when any desired right is missing,
+    /// this includes the subset of desired rights that would have been granted.
+    pub granted:            access::Mask,
+
+    /// Every step of the evaluation, in order.
+    pub trace:              Vec<Step>,
+}
+
+impl Outcome {
+    /// If `AccessCheck` would succeed: every desired right was granted, and at least one right was granted.
+    pub fn is_allowed(&self) -> bool { self.missing() == access::Mask::default() && self.granted != access::Mask::default() }
+
+    /// Desired rights that weren't granted.
+    pub fn missing(&self) -> access::Mask { self.desired & !self.granted }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        writeln!(fmt, "desired 0x{:08X}{}, granted 0x{:08X}", u32::from(self.desired), if self.maximum_allowed { " | MAXIMUM_ALLOWED" } else { "" }, u32::from(self.granted))?;
+        for step in self.trace.iter() { writeln!(fmt, "    {step}")?; }
+        Ok(())
+    }
+}
+
+
+
+/// A single step of [`evaluate`](super::evaluate), as recorded in [`Outcome::trace`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive] pub enum Step {
+    /// A privilege was checked for `ACCESS_SYSTEM_SECURITY` or `WRITE_OWNER`.
+    Privilege { privilege: Privilege, held: bool, granted: access::Mask },
+
+    /// The mandatory label was checked.
+    /// `ace` is [`None`] if the SACL has no label, and the default (`Medium`, `NO_WRITE_UP`) was used.
+    MandatoryLabel { ace: Option<usize>, label: sid::integrity::Level, policy: ace::MandatoryLabelPolicy, blocked: access::Mask },
+
+    /// The owner was implicitly granted `READ_CONTROL | WRITE_DAC`.
+    OwnerRights { pass: Pass, granted: access::Mask },
+
+    /// The DACL is null or absent, granting everything.
+    NullDacl { pass: Pass, granted: access::Mask },
+
+    /// An ACE of the DACL was examined.
+    Ace { pass: Pass, index: usize, effect: AceEffect },
+}
+
+impl Display for Step {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match *self {
+            Step::Privilege { privilege, held: true, granted }  => write!(fmt, "{privilege:?} privilege granted 0x{:08X}", u32::from(granted)),
+            Step::Privilege { privilege, held: false, .. }      => write!(fmt, "{privilege:?} privilege not held"),
+            Step::MandatoryLabel { ace, label, policy, blocked } => {
+                match ace {
+                    Some(index) => write!(fmt, "mandatory label (ACE {index} of SACL): {label:?}")?,
+                    None        => write!(fmt, "mandatory label (default): {label:?}")?,
+                }
+                write!(fmt, ", policy 0x{:X}, blocked 0x{:08X}", u32::from(policy), u32::from(blocked))
+            },
+            Step::OwnerRights { pass, granted }                 => write!(fmt, "{pass:?} pass: owner granted 0x{:08X}", u32::from(granted)),
+            Step::NullDacl { pass, granted }                    => write!(fmt, "{pass:?} pass: null DACL granted 0x{:08X}", u32::from(granted)),
+            Step::Ace { pass, index, effect }                   => write!(fmt, "{pass:?} pass: ACE {index}: {effect}"),
+        }
+    }
+}
+
+/// A privilege, as recorded by [`Step::Privilege`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum Privilege {
+    /// `SeSecurityPrivilege`
+    Security,
+    /// `SeTakeOwnershipPrivilege`
+    TakeOwnership,
+}
+
+/// Which set of SIDs a DACL walk was evaluated against
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pass {
+    /// The token's user and groups.
+    Normal,
+    /// The token's restricted SIDs.
+    Restricted,
+    /// The token's AppContainer SID and capabilities.
+    AppContainer,
+}
+
+/// What an ACE did, as recorded by [`Step::Ace`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive] pub enum AceEffect {
+    /// The ACE granted these (previously undecided) rights.
+    Allowed(access::Mask),
+    /// The ACE denied these (previously undecided) rights.
+    Denied(access::Mask),
+    /// The ACE applies, but every right it covers was already decided by earlier ACEs.
+    NoEffect,
+    /// The ACE's SID isn't held by the token (in this pass.)
+    SidNotPresent,
+    /// The ACE is `INHERIT_ONLY_ACE`, and only affects child objects.
+    InheritOnly,
+    /// The ACE only applies to a specific object type or property.
+    ObjectSpecific,
+    /// The ACE is a callback ACE whose conditional expression isn't `TRUE` (or, for a deny ACE, is `FALSE`.)
+    /// Application data that isn't a valid conditional expression is `UNKNOWN`.
+    ConditionNotMet,
+    /// The ACE type doesn't grant or deny access (e.g. an audit ACE in the DACL.)
+    Ignored,
+    /// The ACE is too small to contain its access mask or SID.
+    Malformed,
+}
+
+impl Display for AceEffect {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match *self {
+            AceEffect::Allowed(mask)    => write!(fmt, "allowed 0x{:08X}", u32::from(mask)),
+            AceEffect::Denied(mask)     => write!(fmt, "denied 0x{:08X}", u32::from(mask)),
+            AceEffect::NoEffect         => write!(fmt, "no effect (rights already decided)"),
+            AceEffect::SidNotPresent    => write!(fmt, "skipped (SID not present)"),
+            AceEffect::InheritOnly      => write!(fmt, "skipped (inherit only)"),
+            AceEffect::ObjectSpecific   => write!(fmt, "skipped (object specific)"),
+            AceEffect::ConditionNotMet  => write!(fmt, "skipped (condition not met)"),
+            AceEffect::Ignored          => write!(fmt, "skipped (not an allow or deny ACE)"),
+            AceEffect::Malformed        => write!(fmt, "skipped (malformed)"),
+        }
+    }
+}
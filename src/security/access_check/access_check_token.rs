@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+
+
+/// The parts of an access token relevant to [`evaluate`](super::evaluate)
+///
+/// Construct with [`Token::new`], then override fields with struct update syntax:
+/// ```
+/// # use firehazard::*;
+/// # use security::access_check::Token;
+/// let groups = [sid::AndAttributes::new(sid!(S-1-5-32-545), sid::group::ENABLED)];
+/// let token = Token { groups: &groups, ..Token::new(sid!(S-1-5-21-1-2-3-1001)) };
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Token<'a> {
+    /// The token's user.
+    /// The user SID is always enabled, unless [`sid::group::USE_FOR_DENY_ONLY`] is set.
+    pub user:                           sid::AndAttributes<'a>,
+
+    /// The token's groups.
+    /// Only [`sid::group::ENABLED`] groups are granted access, but [`sid::group::USE_FOR_DENY_ONLY`] groups are still denied access.
+    pub groups:                         &'a [sid::AndAttributes<'a>],
+
+    /// If non-empty, the token is restricted, and access must also be granted to one of these SIDs.
+    pub restricted_sids:                &'a [sid::AndAttributes<'a>],
+
+    /// `WRITE_RESTRICTED`: only write access is subject to [`Token::restricted_sids`].
+    pub write_restricted:               bool,
+
+    /// The token's mandatory integrity level.
+    pub integrity:                      sid::integrity::Level,
+
+    /// `TOKEN_MANDATORY_POLICY_NO_WRITE_UP`: the token is subject to mandatory labels.
+    pub mandatory_policy_no_write_up:   bool,
+
+    /// If [`Some`], the token is an AppContainer token, and access must also be granted to the AppContainer.
+    pub app_container:                  Option<sid::Ptr<'a>>,
+
+    /// The AppContainer's capabilities.  Ignored unless [`Token::app_container`] is [`Some`].
+    pub capabilities:                   &'a [sid::AndAttributes<'a>],
+
+    /// Enabled privileges that affect access checks.
+    pub privileges:                     Privileges,
+
+    /// The token's user claims, referenced by `@User.*` in callback ACE conditions.
+    pub user_claims:                    &'a [claim::Attribute],
+
+    /// The token's device claims, referenced by `@Device.*` in callback ACE conditions.
+    pub device_claims:                  &'a [claim::Attribute],
+
+    /// The token's device groups, referenced by `Device_Member_of*` in callback ACE conditions.
+    pub device_groups:                  &'a [sid::AndAttributes<'a>],
+}
+
+impl<'a> Token<'a> {
+    /// A Medium integrity token for `user`, without groups, restrictions, privileges, or claims.
+    pub fn new(user: impl Into<sid::Ptr<'a>>) -> Self {
+        Self {
+            user:                           sid::AndAttributes::new(user, ()),
+            groups:                         &[],
+            restricted_sids:                &[],
+            write_restricted:               false,
+            integrity:                      sid::integrity::Medium,
+            mandatory_policy_no_write_up:   true,
+            app_container:                  None,
+            capabilities:                   &[],
+            privileges:                     Privileges::default(),
+            user_claims:                    &[],
+            device_claims:                  &[],
+            device_groups:                  &[],
+        }
+    }
+}
+
+
+
+/// Enabled privileges that [`evaluate`](super::evaluate) understands
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Privileges {
+    /// `SeSecurityPrivilege`: required for `ACCESS_SYSTEM_SECURITY`.
+    pub security:       bool,
+
+    /// `SeTakeOwnershipPrivilege`: grants `WRITE_OWNER` regardless of the DACL.
+    pub take_ownership: bool,
+}
@@ -0,0 +1,37 @@
+//! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format)\]
+//! Security Descriptor Definition Language: pure Rust parsing and emitting
+//!
+//! Unlike `ConvertStringSecurityDescriptorToSecurityDescriptor` / `ConvertSecurityDescriptorToStringSecurityDescriptor`,
+//! this doesn't call into `advapi32.dll`, so SDDL can be validated and round-tripped on any host.
+//!
+//! ### Examples
+//! ```
+//! # use firehazard::*;
+//! let sd = security::RelativeDescriptor::from_sddl("O:BAG:SYD:(A;;GA;;;WD)S:(ML;;NW;;;LW)").unwrap();
+//! assert_eq!(Some(&*sid!(S-1-1-0)), sd.dacl().unwrap().aces().next().unwrap().sid().as_deref());
+//! assert_eq!("O:BAG:SYD:(A;;GA;;;WD)S:(ML;;NW;;;LW)", sd.to_sddl().unwrap().to_string());
+//! ```
+//!
+//! ### Supported
+//! *   Owner, group, DACL and SACL (including `NO_ACCESS_CONTROL` null ACLs), with their control flags (`P`, `AI`, `AR`.)
+//! *   Every ACE type with an SDDL alias except those listed below, including object ACEs (`OA`, `OD`, `OU`, `OL`) and mandatory labels (`ML`.)
+//! *   Callback ACEs (`XA`, `XD`, `XU`, `ZA`) with a conditional expression as their 7th field, e.g. `(XA;;FR;;;WD;(@User.clearance >= 3))`,
+//!     via [`ace::condition::Expression`](crate::ace::condition::Expression).
+//!
+//! ### Limitations
+//! *   Domain relative SID aliases (`DA`, `LA`, ...) can't be resolved without a domain or machine SID.
+//! *   Resource attribute (`RA`) and access filter (`FL`) ACEs aren't yet supported: parsing them fails with [`ParseErrorKind::UnsupportedAceType`],
+//!     and emitting their application data fails with [`EmitError::UnsupportedApplicationData`].
+//! *   `ACCESS_DENIED_CALLBACK_OBJECT` and `SYSTEM_AUDIT_CALLBACK_OBJECT` ACEs have no SDDL alias, so can't be emitted.
+//! *   Conditional expressions require the `alloc` feature, and those SDDL can't represent (e.g. string literals containing `"`) can't be emitted.
+//!
+//! ### References
+//! *   [Security Descriptor String Format](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format)
+//! *   [ACE Strings](https://learn.microsoft.com/en-us/windows/win32/secauthz/ace-strings)
+//! *   [SID Strings](https://learn.microsoft.com/en-us/windows/win32/secauthz/sid-strings)
+
+#[path = "sddl_aliases.rs"] mod aliases;
+
+mod sddl_emit;                          pub use sddl_emit::*;
+mod sddl_error;                         pub use sddl_error::*;
+#[cfg(alloc)] mod sddl_parse;           #[cfg(alloc)] pub(crate) use sddl_parse::*;
@@ -0,0 +1,109 @@
+//! Alias tables shared by the parser and emitter.
+//!
+//! ### References
+//! *   [ACE Strings](https://learn.microsoft.com/en-us/windows/win32/secauthz/ace-strings)
+//! *   [SID Strings](https://learn.microsoft.com/en-us/windows/win32/secauthz/sid-strings)
+
+use crate::prelude::*;
+use winapi::shared::sddl::*;
+use winapi::um::winnt::*;
+
+
+
+/// `D:` / `S:` ACL flags, in the order they're emitted.
+pub(super) const DACL_FLAGS : &[(&str, security::DescriptorControl)] = &[
+    (SDDL_PROTECTED,            security::DescriptorControl::DACL_PROTECTED         ),
+    (SDDL_AUTO_INHERIT_REQ,     security::DescriptorControl::DACL_AUTO_INHERIT_REQ  ),
+    (SDDL_AUTO_INHERITED,       security::DescriptorControl::DACL_AUTO_INHERITED    ),
+];
+
+/// `S:` ACL flags, in the order they're emitted.
+pub(super) const SACL_FLAGS : &[(&str, security::DescriptorControl)] = &[
+    (SDDL_PROTECTED,            security::DescriptorControl::SACL_PROTECTED         ),
+    (SDDL_AUTO_INHERIT_REQ,     security::DescriptorControl::SACL_AUTO_INHERIT_REQ  ),
+    (SDDL_AUTO_INHERITED,       security::DescriptorControl::SACL_AUTO_INHERITED    ),
+];
+
+/// ACE types with an SDDL representation.
+/// `ACCESS_DENIED_CALLBACK_OBJECT` and `SYSTEM_AUDIT_CALLBACK_OBJECT` have none.
+pub(super) const ACE_TYPES : &[(&str, ace::Type)] = &[
+    (SDDL_ACCESS_ALLOWED,                   ace::Type::ACCESS_ALLOWED                   ),
+    (SDDL_ACCESS_DENIED,                    ace::Type::ACCESS_DENIED                    ),
+    (SDDL_OBJECT_ACCESS_ALLOWED,            ace::Type::ACCESS_ALLOWED_OBJECT            ),
+    (SDDL_OBJECT_ACCESS_DENIED,             ace::Type::ACCESS_DENIED_OBJECT             ),
+    (SDDL_AUDIT,                            ace::Type::SYSTEM_AUDIT                     ),
+    (SDDL_ALARM,                            ace::Type::SYSTEM_ALARM                     ),
+    (SDDL_OBJECT_AUDIT,                     ace::Type::SYSTEM_AUDIT_OBJECT              ),
+    (SDDL_OBJECT_ALARM,                     ace::Type::SYSTEM_ALARM_OBJECT              ),
+    (SDDL_MANDATORY_LABEL,                  ace::Type::SYSTEM_MANDATORY_LABEL           ),
+    (SDDL_PROCESS_TRUST_LABEL,              ace::Type::SYSTEM_PROCESS_TRUST_LABEL       ),
+    (SDDL_CALLBACK_ACCESS_ALLOWED,          ace::Type::ACCESS_ALLOWED_CALLBACK          ),
+    (SDDL_CALLBACK_ACCESS_DENIED,           ace::Type::ACCESS_DENIED_CALLBACK           ),
+    (SDDL_RESOURCE_ATTRIBUTE,               ace::Type::SYSTEM_RESOURCE_ATTRIBUTE        ),
+    (SDDL_SCOPED_POLICY_ID,                 ace::Type::SYSTEM_SCOPED_POLICY_ID          ),
+    (SDDL_CALLBACK_AUDIT,                   ace::Type::SYSTEM_AUDIT_CALLBACK            ),
+    (SDDL_CALLBACK_OBJECT_ACCESS_ALLOWED,   ace::Type::ACCESS_ALLOWED_CALLBACK_OBJECT   ),
+    (SDDL_ACCESS_FILTER,                    ace::Type::SYSTEM_ACCESS_FILTER             ),
+];
+
+/// ACE flags, in the order they're emitted (ascending bit order, matching `ConvertSecurityDescriptorToStringSecurityDescriptor`.)
+pub(super) const ACE_FLAGS : &[(&str, u8)] = &[
+    (SDDL_OBJECT_INHERIT,           OBJECT_INHERIT_ACE          ),
+    (SDDL_CONTAINER_INHERIT,        CONTAINER_INHERIT_ACE       ),
+    (SDDL_NO_PROPAGATE,             NO_PROPAGATE_INHERIT_ACE    ),
+    (SDDL_INHERIT_ONLY,             INHERIT_ONLY_ACE            ),
+    (SDDL_INHERITED,                INHERITED_ACE               ),
+    ("CR",                          0x20                        ), // SDDL_CRITICAL / CRITICAL_ACE_FLAG: newer than winapi 0.3
+    (SDDL_AUDIT_SUCCESS,            SUCCESSFUL_ACCESS_ACE_FLAG  ),
+    (SDDL_AUDIT_FAILURE,            FAILED_ACCESS_ACE_FLAG      ),
+    (SDDL_TRUST_PROTECTED_FILTER,   0x40                        ), // parse only: TRUST_PROTECTED_FILTER_ACE_FLAG aliases SUCCESSFUL_ACCESS_ACE_FLAG
+];
+
+/// Access rights that are emitted as a single alias only when they're an exact match for the entire mask.
+pub(super) const COMPOSITE_RIGHTS : &[(&str, u32)] = &[
+    (SDDL_FILE_ALL,                 FILE_ALL_ACCESS             ),
+    (SDDL_FILE_READ,                FILE_GENERIC_READ           ),
+    (SDDL_FILE_WRITE,               FILE_GENERIC_WRITE          ),
+    (SDDL_FILE_EXECUTE,             FILE_GENERIC_EXECUTE        ),
+    (SDDL_KEY_ALL,                  KEY_ALL_ACCESS              ),
+    (SDDL_KEY_READ,                 KEY_READ                    ),
+    (SDDL_KEY_WRITE,                KEY_WRITE                   ),
+    (SDDL_KEY_EXECUTE,              KEY_EXECUTE                 ), // == KEY_READ: parse only
+];
+
+/// Single bit access rights, in the order they're emitted (ascending bit order.)
+pub(super) const RIGHTS : &[(&str, u32)] = &[
+    (SDDL_CREATE_CHILD,             0x0000_0001                 ), // ADS_RIGHT_DS_CREATE_CHILD
+    (SDDL_DELETE_CHILD,             0x0000_0002                 ), // ADS_RIGHT_DS_DELETE_CHILD
+    (SDDL_LIST_CHILDREN,            0x0000_0004                 ), // ADS_RIGHT_ACTRL_DS_LIST
+    (SDDL_SELF_WRITE,               0x0000_0008                 ), // ADS_RIGHT_DS_SELF
+    (SDDL_READ_PROPERTY,            0x0000_0010                 ), // ADS_RIGHT_DS_READ_PROP
+    (SDDL_WRITE_PROPERTY,           0x0000_0020                 ), // ADS_RIGHT_DS_WRITE_PROP
+    (SDDL_DELETE_TREE,              0x0000_0040                 ), // ADS_RIGHT_DS_DELETE_TREE
+    (SDDL_LIST_OBJECT,              0x0000_0080                 ), // ADS_RIGHT_DS_LIST_OBJECT
+    (SDDL_CONTROL_ACCESS,           0x0000_0100                 ), // ADS_RIGHT_DS_CONTROL_ACCESS
+    (SDDL_STANDARD_DELETE,          DELETE                      ),
+    (SDDL_READ_CONTROL,             READ_CONTROL                ),
+    (SDDL_WRITE_DAC,                WRITE_DAC                   ),
+    (SDDL_WRITE_OWNER,              WRITE_OWNER                 ),
+    (SDDL_GENERIC_ALL,              GENERIC_ALL                 ),
+    (SDDL_GENERIC_EXECUTE,          GENERIC_EXECUTE             ),
+    (SDDL_GENERIC_WRITE,            GENERIC_WRITE               ),
+    (SDDL_GENERIC_READ,             GENERIC_READ                ),
+];
+
+/// Mandatory label policy rights (only meaningful for `ML` ACEs), in the order they're emitted.
+pub(super) const LABEL_RIGHTS : &[(&str, u32)] = &[
+    (SDDL_NO_WRITE_UP,              SYSTEM_MANDATORY_LABEL_NO_WRITE_UP      ),
+    (SDDL_NO_READ_UP,               SYSTEM_MANDATORY_LABEL_NO_READ_UP       ),
+    (SDDL_NO_EXECUTE_UP,            SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP    ),
+];
+
+/// Domain relative SID aliases, which can't be resolved without a domain or machine SID.
+/// Machine and domain independent aliases are in the [`sid::WellKnown`] table.
+#[cfg(alloc)] pub(super) const DOMAIN_SIDS : &[&str] = &[
+    SDDL_DOMAIN_ADMINISTRATORS, SDDL_DOMAIN_GUESTS, SDDL_DOMAIN_USERS, SDDL_DOMAIN_DOMAIN_CONTROLLERS, SDDL_DOMAIN_COMPUTERS,
+    SDDL_LOCAL_ADMIN, SDDL_LOCAL_GUEST, SDDL_SCHEMA_ADMINISTRATORS, SDDL_CERT_SERV_ADMINISTRATORS, SDDL_RAS_SERVERS,
+    SDDL_ENTERPRISE_ADMINS, SDDL_GROUP_POLICY_ADMINS, SDDL_ENTERPRISE_RO_DCs, SDDL_CLONEABLE_CONTROLLERS, SDDL_PROTECTED_USERS,
+    SDDL_KEY_ADMINS, SDDL_ENTERPRISE_KEY_ADMINS,
+];
@@ -0,0 +1,239 @@
+use crate::prelude::*;
+use super::aliases::*;
+use super::EmitError;
+use winapi::shared::sddl::*;
+use winapi::um::winnt::*;
+
+use core::fmt::{self, Formatter, Write};
+
+
+
+/// Canonical [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format) for a [`security::Descriptor`], as returned by [`security::Descriptor::to_sddl`]
+///
+/// Formatting matches `ConvertSecurityDescriptorToStringSecurityDescriptor`:
+/// *   Components are emitted in `O:`, `G:`, `D:`, `S:` order.
+/// *   Machine independent SIDs use their two letter alias (`BA`, `WD`, `SY`, ...), other SIDs use `S-1-...`.
+/// *   Access rights that exactly match a composite alias (`FA`, `FR`, `KA`, ...) use it, otherwise single bit aliases are concatenated if they cover the whole mask, otherwise `0x...` hex is used.
+///
+#[derive(Clone, Copy)] pub struct Display<'a> {
+    sd: &'a security::Descriptor<'a>,
+}
+
+impl<'a> Display<'a> {
+    /// Verify `sd` can be represented as SDDL.
+    pub(crate) fn new(sd: &'a security::Descriptor<'a>) -> Result<Self, EmitError> {
+        match write(&mut Discard, sd) {
+            Ok(())                      => Ok(Self { sd }),
+            Err(WriteError::Emit(err))  => Err(err),
+            Err(WriteError::Fmt)        => unreachable!("Discard doesn't fail"),
+        }
+    }
+}
+
+impl fmt::Display for Display<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write(fmt, self.sd).map_err(|_| fmt::Error) }
+}
+
+impl fmt::Debug for Display<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "\"{self}\"") }
+}
+
+
+
+enum WriteError { Fmt, Emit(EmitError) }
+impl From<fmt::Error> for WriteError { fn from(_: fmt::Error) -> Self { Self::Fmt } }
+impl From<EmitError>  for WriteError { fn from(err: EmitError) -> Self { Self::Emit(err) } }
+
+struct Discard;
+impl Write for Discard { fn write_str(&mut self, _: &str) -> fmt::Result { Ok(()) } }
+
+fn write(w: &mut impl Write, sd: &security::Descriptor) -> Result<(), WriteError> {
+    if let Some(owner) = sd.owner() {
+        write!(w, "{SDDL_OWNER}:")?;
+        write_sid(w, &owner)?;
+    }
+    if let Some(group) = sd.group() {
+        write!(w, "{SDDL_GROUP}:")?;
+        write_sid(w, &group)?;
+    }
+    let control = sd.control();
+    if u16::from(control) & SE_DACL_PRESENT != 0 { write_acl(w, SDDL_DACL, control, DACL_FLAGS, sd.dacl())?; }
+    if u16::from(control) & SE_SACL_PRESENT != 0 { write_acl(w, SDDL_SACL, control, SACL_FLAGS, sd.sacl())?; }
+    Ok(())
+}
+
+fn write_acl(w: &mut impl Write, prefix: &str, control: security::DescriptorControl, flags: &[(&str, security::DescriptorControl)], acl: Option<acl::Ref>) -> Result<(), WriteError> {
+    write!(w, "{prefix}:")?;
+    for &(flag_str, flag) in flags {
+        if control & flag != security::DescriptorControl::NONE { w.write_str(flag_str)?; }
+    }
+    let Some(acl) = acl else { return Ok(w.write_str(SDDL_NULL_ACL)?) };
+    for ace in acl.aces() { write_ace(w, ace)?; }
+    Ok(())
+}
+
+fn write_ace(w: &mut impl Write, ace: ace::Ref) -> Result<(), WriteError> {
+    let header = ace.header();
+    let ty = header.ty;
+    let ty_str = ACE_TYPES.iter().find(|(_, t)| *t == ty).ok_or(EmitError::UnsupportedAceType(ty))?.0;
+    let (Some(mask), Some(sid)) = (ace.mask(), ace.sid()) else { return Err(EmitError::MalformedAce(ty).into()) };
+    #[cfg(alloc)] let condition = match ace.application_data() {
+        []                          => None,
+        data if ty.is_callback()    => Some(ace::condition::Expression::from_bytes(data).map_err(|_| EmitError::UnsupportedApplicationData(ty))?),
+        _                           => return Err(EmitError::UnsupportedApplicationData(ty).into()),
+    };
+    #[cfg(alloc)] if condition.as_ref().is_some_and(|c| !c.is_sddl_representable()) { return Err(EmitError::UnsupportedApplicationData(ty).into()) }
+    #[cfg(not(alloc))] if !ace.application_data().is_empty() { return Err(EmitError::UnsupportedApplicationData(ty).into()) }
+
+    write!(w, "({ty_str};")?;
+
+    let mut flags = header.flags.as_u8();
+    for &(flag_str, flag) in ACE_FLAGS {
+        if flags & flag == 0 { continue }
+        w.write_str(flag_str)?;
+        flags &= !flag;
+    }
+    if flags != 0 { return Err(EmitError::UnsupportedAceFlags(ty).into()) }
+    w.write_char(';')?;
+
+    write_rights(w, ty, mask.into())?;
+    w.write_char(';')?;
+
+    if let Some(guid) = ace.object_type()           { write_guid(w, &guid)?; }
+    w.write_char(';')?;
+    if let Some(guid) = ace.inherited_object_type() { write_guid(w, &guid)?; }
+    w.write_char(';')?;
+
+    write_sid(w, &sid)?;
+    #[cfg(alloc)] {
+        use ace::condition::Expression;
+        match condition {
+            None                                                    => {},
+            Some(c @ (Expression::Unary(..) | Expression::Binary(..)))  => write!(w, ";{c}")?, // already parenthesized
+            Some(c)                                                 => write!(w, ";({c})")?,
+        }
+    }
+    w.write_char(')')?;
+    Ok(())
+}
+
+fn write_rights(w: &mut impl Write, ty: ace::Type, mask: u32) -> fmt::Result {
+    let write_pairs = |w: &mut dyn Write, table: &[(&str, u32)]| -> Option<fmt::Result> {
+        let all = table.iter().fold(0, |all, (_, bit)| all | bit);
+        if mask == 0 || mask & !all != 0 { return None }
+        Some(table.iter().filter(|(_, bit)| mask & bit != 0).try_for_each(|(alias, _)| w.write_str(alias)))
+    };
+
+    if ty == ace::Type::SYSTEM_MANDATORY_LABEL {
+        if let Some(r) = write_pairs(w, LABEL_RIGHTS) { return r }
+    } else {
+        if let Some((alias, _)) = COMPOSITE_RIGHTS.iter().find(|(_, m)| *m == mask) { return w.write_str(alias) }
+        if let Some(r) = write_pairs(w, RIGHTS) { return r }
+    }
+    write!(w, "0x{mask:x}")
+}
+
+fn write_guid(w: &mut impl Write, guid: &winapi::shared::guiddef::GUID) -> fmt::Result {
+    let [a,b,c,d,e,f,g,h] = guid.Data4;
+    write!(w, "{:08x}-{:04x}-{:04x}-{a:02x}{b:02x}-{c:02x}{d:02x}{e:02x}{f:02x}{g:02x}{h:02x}", guid.Data1, guid.Data2, guid.Data3)
+}
+
+fn write_sid(w: &mut impl Write, sid: &sid::Value) -> fmt::Result {
+    match sid.sddl_alias() {
+        Some(alias) => w.write_str(alias),
+        None        => write!(w, "{sid}"),
+    }
+}
+
+#[cfg(std)] #[test] fn round_trip() {
+    use std::string::ToString;
+    for sddl in [
+        "",
+        "O:BAG:SYD:(A;;GA;;;WD)S:(ML;;NW;;;LW)",
+        "O:BAG:SYD:PAI(A;OICIID;FA;;;SY)(A;;0x1200a9;;;BU)(D;;CCDCLCSWRPWPDTLOCRSDRCWDWO;;;S-1-5-21-1-2-3-1001)S:P(ML;;NWNR;;;HI)",
+        "D:NO_ACCESS_CONTROL",
+        "D:PARAI",
+        "D:(A;;0x0;;;AC)(A;;GXGR;;;S-1-15-3-1)(AU;SAFA;KA;;;WD)",
+        "D:(OA;CI;RPWP;bf967aba-0de6-11d0-a285-00aa003049e2;bf967a86-0de6-11d0-a285-00aa003049e2;PS)(OD;;CR;;bf967a86-0de6-11d0-a285-00aa003049e2;AN)",
+        "O:S-1-0x123456789abc-1",
+        "D:(XA;;FA;;;WD;((@User.clearance >= 3) && (Member_of {SID(BA)})))(XD;;FW;;;WD;(Not_Exists @Device.managed))",
+        "D:(ZA;;CR;bf967aba-0de6-11d0-a285-00aa003049e2;;AU;(@User.x))S:(XU;SA;FA;;;WD;(@Resource.Secrecy Any_of {1, 2}))",
+    ] {
+        let sd = security::RelativeDescriptor::from_sddl(sddl).unwrap();
+        assert_eq!(sddl, sd.to_sddl().unwrap().to_string());
+    }
+
+    for (input, canonical) in [
+        ("o:ba g:sy d:(a;ciOI;0x1f01ff;;;s-1-1-0)",     "O:BAG:SYD:(A;OICI;FA;;;WD)"),
+        ("D:(A;;2032127;;;S-1-5-18)",                   "D:(A;;FA;;;SY)"),
+        ("D:(A;;KX;;;BU)(A;;RCSDWDWO;;;BA)",            "D:(A;;KR;;;BU)(A;;SDRCWDWO;;;BA)"),
+        ("D:(A;;;;;BU)",                                "D:(A;;0x0;;;BU)"),
+        ("S:(AU;TP;0x1;;;WD)",                          "S:(AU;SA;CC;;;WD)"),
+        ("S:AI(ML;;0x8;;;ME)",                          "S:AI(ML;;0x8;;;ME)"),
+        ("D:(xa;;FA;;;WD; @user.x==1 )",                "D:(XA;;FA;;;WD;(@User.x == 1))"),
+    ] {
+        let sd = security::RelativeDescriptor::from_sddl(input).unwrap();
+        assert_eq!(canonical, sd.to_sddl().unwrap().to_string(), "{input:?}");
+    }
+}
+
+#[cfg(std)] #[test] fn emit_errors() {
+    let emit = |ty: u8| {
+        #[repr(C, align(4))] struct Aligned([u8; 8 + 24]);
+        let mut acl = Aligned([0; 32]);
+        acl.0[ 0 ..  8].copy_from_slice(&[4, 0, 32, 0, 1, 0, 0, 0]);
+        acl.0[ 8 .. 32].copy_from_slice(&[ty, 0, 24, 0,  0, 0, 0, 0x10,  0, 0, 0, 0,  1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0]);
+        let sd = security::Descriptor {
+            desc: SECURITY_DESCRIPTOR { Revision: 1, Control: SE_DACL_PRESENT, Dacl: acl.0.as_mut_ptr().cast(), ..Default::default() },
+            phantom: PhantomData,
+        };
+        sd.to_sddl().map(|_| ())
+    };
+    assert_eq!(Err(EmitError::UnsupportedAceType(ace::Type::ACCESS_DENIED_CALLBACK_OBJECT)),     emit(ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE));
+    assert_eq!(Err(EmitError::UnsupportedApplicationData(ace::Type::ACCESS_ALLOWED_CALLBACK)),   emit(ACCESS_ALLOWED_CALLBACK_ACE_TYPE)); // 4 trailing bytes after the SID
+    assert_eq!(Ok(()),                                                                          emit(ACCESS_ALLOWED_OBJECT_ACE_TYPE));
+}
+
+#[cfg(std)] #[test] fn ace_flags_round_trip() {
+    use std::string::ToString;
+    for flags in 0 ..= u8::MAX {
+        #[repr(C, align(4))] struct Aligned([u8; 8 + 20]);
+        let mut acl = Aligned([0; 28]);
+        acl.0[ 0 ..  8].copy_from_slice(&[4, 0, 28, 0, 1, 0, 0, 0]);
+        acl.0[ 8 .. 28].copy_from_slice(&[ACCESS_ALLOWED_ACE_TYPE, flags, 20, 0,  0, 0, 0, 0x10,  1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0]);
+        let sd = security::Descriptor {
+            desc: SECURITY_DESCRIPTOR { Revision: 1, Control: SE_DACL_PRESENT, Dacl: acl.0.as_mut_ptr().cast(), ..Default::default() },
+            phantom: PhantomData,
+        };
+        let sddl = sd.to_sddl().unwrap().to_string();
+        let parsed = security::RelativeDescriptor::from_sddl(&sddl).unwrap();
+        assert_eq!(flags, parsed.dacl().unwrap().aces().next().unwrap().header().flags.as_u8(), "{sddl}");
+    }
+}
+
+#[cfg(std)] #[test] fn string_literal_injection() {
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    // Patch a binary descriptor's condition string, as if it had been decoded from untrusted application data.
+    const PLACEHOLDER : &str = "________________________________________________";
+    let template = security::RelativeDescriptor::from_sddl(&std::format!("D:(XA;;FR;;;BU;(@User.x == \"{PLACEHOLDER}\"))")).unwrap();
+    let with_string = |s: &str| {
+        let utf16 = |s: &str| s.encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<u8>>();
+        let (from, to) = (utf16(PLACEHOLDER), utf16(&std::format!("{s:<48}")));
+        assert_eq!(from.len(), to.len());
+        let mut bytes = template.as_bytes().to_vec();
+        let at = bytes.windows(from.len()).position(|w| w == from).unwrap();
+        bytes[at .. at + to.len()].copy_from_slice(&to);
+        security::RelativeDescriptor::from_bytes(&bytes).unwrap()
+    };
+
+    let injected = with_string(r#"")) (A;;FA;;;WD) (XA;;FR;;;BU;(@User.x == ""#);
+    assert_eq!(Err(EmitError::UnsupportedApplicationData(ace::Type::ACCESS_ALLOWED_CALLBACK)), injected.to_sddl().map(|_| ()));
+    assert_eq!(Err(EmitError::UnsupportedApplicationData(ace::Type::ACCESS_ALLOWED_CALLBACK)), with_string("nul\0").to_sddl().map(|_| ()));
+
+    let quoted = with_string(")) (A;;FA;;;WD) (XA;;FR;;;BU;(@User.x == ");
+    let reparsed = security::RelativeDescriptor::from_sddl(&quoted.to_sddl().unwrap().to_string()).unwrap();
+    assert_eq!(1, reparsed.dacl().unwrap().aces().count());
+    assert_eq!(quoted.as_bytes(), reparsed.as_bytes());
+}
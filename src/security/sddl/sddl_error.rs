@@ -0,0 +1,150 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Debug, Display, Formatter};
+
+
+
+/// Error parsing [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format)
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub(super) offset:  usize,
+    pub(super) kind:    ParseErrorKind,
+}
+
+impl ParseError {
+    #[cfg(alloc)] pub(super) fn new(offset: usize, kind: ParseErrorKind) -> Self { Self { offset, kind } }
+
+    /// Byte offset into the SDDL string where the error was detected.
+    pub fn offset(&self) -> usize { self.offset }
+
+    pub fn kind(&self) -> ParseErrorKind { self.kind }
+}
+
+/// What went wrong parsing [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum ParseErrorKind {
+    /// The string ended in the middle of a component or ACE.
+    UnexpectedEnd,
+    /// A character that isn't valid at this position.
+    UnexpectedChar(char),
+    /// `O:`, `G:`, `D:`, or `S:` was specified more than once.
+    DuplicateComponent,
+    /// An ACL flag other than `P`, `AR`, `AI`, or `NO_ACCESS_CONTROL`.
+    UnknownAclFlag,
+    /// A malformed `S-1-...` SID string.
+    InvalidSid,
+    /// A two letter SID alias that isn't known.
+    UnknownSidAlias,
+    /// A two letter SID alias that is relative to a domain or machine SID (e.g. `DA`, `LA`), and can't be resolved offline.
+    DomainRelativeSidAlias,
+    /// An ACE type string that isn't known.
+    UnknownAceType,
+    /// An ACE type without offline encoding support (e.g. `RA`, `FL`.)
+    UnsupportedAceType,
+    /// An ACE flag string that isn't known.
+    UnknownAceFlag,
+    /// An access rights string that isn't a known alias or an integer.
+    UnknownRights,
+    /// A malformed object or inherited object type GUID.
+    InvalidGuid,
+    /// An object or inherited object type GUID on an ACE type that isn't an object ACE.
+    UnexpectedGuid,
+    /// ACEs must have 6 fields: `type;flags;rights;object_guid;inherit_object_guid;sid`, plus a conditional expression for callback ACEs.
+    WrongFieldCount,
+    /// A 7th ACE field on an ACE type other than a callback ACE (e.g. a resource attribute), which isn't yet supported.
+    UnsupportedApplicationData,
+    /// A callback ACE's conditional expression (7th ACE field) is malformed.
+    InvalidCondition(ace::condition::ParseErrorKind),
+    /// The ACL would exceed the 0xFFFC byte limit of an ACL.
+    AclTooLarge,
+    /// The ACE couldn't be encoded (e.g. its application data couldn't be serialized.)
+    InvalidAce,
+}
+
+impl From<ParseError> for firehazard::Error {
+    fn from(err: ParseError) -> Self {
+        Self(match err.kind {
+            ParseErrorKind::InvalidSid              => ERROR_INVALID_SID,
+            ParseErrorKind::UnknownSidAlias         => ERROR_NONE_MAPPED,
+            ParseErrorKind::DomainRelativeSidAlias  => ERROR_NONE_MAPPED,
+            ParseErrorKind::AclTooLarge             => ERROR_ALLOTTED_SPACE_EXCEEDED,
+            _                                       => ERROR_INVALID_PARAMETER,
+        })
+    }
+}
+
+#[cfg(std)] impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidInput, err) }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Debug for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "sddl::ParseError {{ offset: {}, kind: {:?} }}", self.offset, self.kind)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ParseErrorKind::UnexpectedEnd               => "unexpected end of string",
+            ParseErrorKind::UnexpectedChar(ch)          => return write!(fmt, "unexpected character {ch:?} at offset {}", self.offset),
+            ParseErrorKind::DuplicateComponent          => "owner, group, DACL, or SACL specified more than once",
+            ParseErrorKind::UnknownAclFlag              => "unknown ACL flag (expected P, AR, AI, or NO_ACCESS_CONTROL)",
+            ParseErrorKind::InvalidSid                  => "invalid SID string",
+            ParseErrorKind::UnknownSidAlias             => "unknown SID alias",
+            ParseErrorKind::DomainRelativeSidAlias      => "domain relative SID alias cannot be resolved offline",
+            ParseErrorKind::UnknownAceType              => "unknown ACE type",
+            ParseErrorKind::UnsupportedAceType          => "ACE type not supported",
+            ParseErrorKind::UnknownAceFlag              => "unknown ACE flag",
+            ParseErrorKind::UnknownRights               => "unknown access rights",
+            ParseErrorKind::InvalidGuid                 => "invalid GUID",
+            ParseErrorKind::UnexpectedGuid              => "object GUIDs are only valid on object ACEs",
+            ParseErrorKind::WrongFieldCount             => "ACE has the wrong number of fields",
+            ParseErrorKind::UnsupportedApplicationData  => "application data is only supported for callback ACEs",
+            ParseErrorKind::InvalidCondition(kind)      => return write!(fmt, "invalid conditional expression ({kind:?}) at offset {}", self.offset),
+            ParseErrorKind::AclTooLarge                 => "ACL exceeds 0xFFFC bytes",
+            ParseErrorKind::InvalidAce                  => "ACE couldn't be encoded",
+        };
+        write!(fmt, "{msg} at offset {}", self.offset)
+    }
+}
+
+
+
+/// Error emitting [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format) for a [`security::Descriptor`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum EmitError {
+    /// The ACE type has no SDDL representation (e.g. `ACCESS_DENIED_CALLBACK_OBJECT`, reserved types.)
+    UnsupportedAceType(ace::Type),
+    /// The ACE is truncated or otherwise malformed.
+    MalformedAce(ace::Type),
+    /// The ACE has application data other than a conditional expression on a callback ACE (e.g. a resource attribute), which isn't yet supported,
+    /// or a conditional expression SDDL can't represent (e.g. a string literal containing `"`.)
+    UnsupportedApplicationData(ace::Type),
+    /// The ACE has flags without an SDDL alias, which would be lost.
+    UnsupportedAceFlags(ace::Type),
+}
+
+impl From<EmitError> for firehazard::Error {
+    fn from(err: EmitError) -> Self {
+        Self(match err {
+            EmitError::MalformedAce(_)  => ERROR_INVALID_ACL,
+            _                           => ERROR_NOT_SUPPORTED,
+        })
+    }
+}
+
+impl core::error::Error for EmitError {}
+
+impl Display for EmitError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            EmitError::UnsupportedAceType(ty)           => write!(fmt, "{ty:?} has no SDDL representation"),
+            EmitError::MalformedAce(ty)                 => write!(fmt, "malformed {ty:?} ACE"),
+            EmitError::UnsupportedApplicationData(ty)   => write!(fmt, "{ty:?} application data cannot be rendered as SDDL"),
+            EmitError::UnsupportedAceFlags(ty)          => write!(fmt, "{ty:?} ACE flags cannot be rendered as SDDL"),
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::prelude::*;
+use super::aliases::*;
+use super::{ParseError, ParseErrorKind};
+use winapi::shared::guiddef::GUID;
+use winapi::shared::sddl::SDDL_NULL_ACL;
+use winapi::um::winnt::*;
+
+use alloc::vec::Vec;
+
+
+
+/// Parse [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format) into a [`security::RelativeDescriptor`].
+///
+/// Component keywords, aliases, and hex digits are case insensitive, and ASCII whitespace is allowed between components, ACEs, and ACE fields.
+pub(crate) fn parse(sddl: &str) -> Result<security::RelativeDescriptor, ParseError> {
+    let mut p = Parser { s: sddl, pos: 0 };
+    let mut control = security::DescriptorControl::NONE;
+    let mut owner = None;
+    let mut group = None;
+    let mut dacl = None;
+    let mut sacl = None;
+
+    loop {
+        p.skip_ws();
+        let start = p.pos;
+        let Some(component) = p.peek() else { break };
+        if !component.is_ascii() { return Err(p.unexpected()) }
+        p.pos += 1;
+        if !p.eat(":") { return Err(p.unexpected()) }
+
+        match component.to_ascii_uppercase() {
+            b'O' => {
+                if owner.is_some() { return Err(ParseError::new(start, ParseErrorKind::DuplicateComponent)) }
+                owner = Some(p.sid()?);
+            },
+            b'G' => {
+                if group.is_some() { return Err(ParseError::new(start, ParseErrorKind::DuplicateComponent)) }
+                group = Some(p.sid()?);
+            },
+            b'D' => {
+                if u16::from(control) & SE_DACL_PRESENT != 0 { return Err(ParseError::new(start, ParseErrorKind::DuplicateComponent)) }
+                control |= security::DescriptorControl::DACL_PRESENT;
+                dacl = p.acl(&mut control, DACL_FLAGS)?;
+            },
+            b'S' => {
+                if u16::from(control) & SE_SACL_PRESENT != 0 { return Err(ParseError::new(start, ParseErrorKind::DuplicateComponent)) }
+                control |= security::DescriptorControl::SACL_PRESENT;
+                sacl = p.acl(&mut control, SACL_FLAGS)?;
+            },
+            _ => return Err(ParseError::new(start, ParseErrorKind::UnexpectedChar(char::from(component)))),
+        }
+    }
+
+    Ok(security::RelativeDescriptor::from_parts(
+        control,
+        owner.as_ref().map(sid::Owned::as_bytes),
+        group.as_ref().map(sid::Owned::as_bytes),
+        sacl.as_ref().map(acl::Builder::as_bytes),
+        dacl.as_ref().map(acl::Builder::as_bytes),
+    ))
+}
+
+
+
+struct Parser<'s> {
+    s:      &'s str,
+    pos:    usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str { &self.s[self.pos..] }
+    fn peek(&self) -> Option<u8> { self.s.as_bytes().get(self.pos).copied() }
+    fn skip_ws(&mut self) { while self.peek().is_some_and(|b| b.is_ascii_whitespace()) { self.pos += 1; } }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let matches = self.rest().get(..token.len()).is_some_and(|r| r.eq_ignore_ascii_case(token));
+        if matches { self.pos += token.len(); }
+        matches
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.rest().chars().next() {
+            None        => ParseError::new(self.pos, ParseErrorKind::UnexpectedEnd),
+            Some(ch)    => ParseError::new(self.pos, ParseErrorKind::UnexpectedChar(ch)),
+        }
+    }
+
+    /// `O:` / `G:` SID
+    fn sid(&mut self) -> Result<sid::Owned, ParseError> {
+        self.skip_ws();
+        let len = sid_token_len(self.rest());
+        if len == 0 { return Err(self.unexpected()) }
+        let sid = parse_sid(&self.rest()[..len]).map_err(|kind| ParseError::new(self.pos, kind))?;
+        self.pos += len;
+        Ok(sid)
+    }
+
+    /// `D:` / `S:` flags and ACEs.  Returns [`None`] for a null ACL.
+    fn acl(&mut self, control: &mut security::DescriptorControl, flags: &[(&str, security::DescriptorControl)]) -> Result<Option<acl::Builder>, ParseError> {
+        let mut null = false;
+        'flags: loop {
+            self.skip_ws();
+            if self.eat(SDDL_NULL_ACL) { null = true; continue }
+            for &(flag_str, flag) in flags {
+                if self.eat(flag_str) { *control |= flag; continue 'flags }
+            }
+            break
+        }
+
+        let mut acl = acl::Builder::new(acl::REVISION);
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                Some(b'(') if null  => return Err(self.unexpected()),
+                Some(b'(')          => self.ace(&mut acl)?,
+                Some(b) if b.is_ascii_alphabetic() && self.s.as_bytes().get(self.pos + 1) != Some(&b':') => {
+                    return Err(ParseError::new(self.pos, ParseErrorKind::UnknownAclFlag));
+                },
+                _ => break,
+            }
+        }
+
+        Ok(if null { None } else { Some(acl) })
+    }
+
+    /// `(type;flags;rights;object_guid;inherit_object_guid;sid)` or `(type;flags;rights;object_guid;inherit_object_guid;sid;(condition))`
+    fn ace(&mut self, acl: &mut acl::Builder) -> Result<(), ParseError> {
+        let start = self.pos;
+        debug_assert_eq!(Some(b'('), self.peek());
+        self.pos += 1;
+
+        let mut fields = [(0, 0); 7];
+        let mut field = 0;
+        let mut field_start = self.pos;
+        let mut depth = 0_usize;
+        let mut quoted = false;
+        loop {
+            let Some(b) = self.peek() else { return Err(self.unexpected()) };
+            match b {
+                b'"'                        => quoted = !quoted,
+                _ if quoted                 => {},
+                b'('                        => depth += 1,
+                b')' if depth > 0           => depth -= 1,
+                b')' | b';' if depth == 0   => {
+                    if field == fields.len() { return Err(ParseError::new(start, ParseErrorKind::WrongFieldCount)) }
+                    fields[field] = (field_start, self.pos);
+                    field += 1;
+                    field_start = self.pos + 1;
+                    if b == b')' { break }
+                },
+                _ => {},
+            }
+            self.pos += 1;
+        }
+        self.pos += 1; // ')'
+        if field < 6 { return Err(ParseError::new(start, ParseErrorKind::WrongFieldCount)) }
+        let field_count = field;
+
+        let field = |i: usize| -> (usize, &str) {
+            let (start, end) = fields[i];
+            let raw = &self.s[start..end];
+            let trimmed = raw.trim_start_matches(|ch: char| ch.is_ascii_whitespace());
+            (start + raw.len() - trimmed.len(), trimmed.trim_end_matches(|ch: char| ch.is_ascii_whitespace()))
+        };
+        let err = |(offset, _): (usize, &str), kind| ParseError::new(offset, kind);
+
+        let f = field(0);
+        let ty = ACE_TYPES.iter().find(|(s, _)| s.eq_ignore_ascii_case(f.1)).map(|&(_, ty)| ty).ok_or(err(f, ParseErrorKind::UnknownAceType))?;
+        let object = match ty {
+            ace::Type::SYSTEM_RESOURCE_ATTRIBUTE | ace::Type::SYSTEM_ACCESS_FILTER => return Err(err(f, ParseErrorKind::UnsupportedAceType)),
+            ace::Type::ACCESS_ALLOWED_OBJECT | ace::Type::ACCESS_DENIED_OBJECT | ace::Type::SYSTEM_AUDIT_OBJECT | ace::Type::SYSTEM_ALARM_OBJECT | ace::Type::ACCESS_ALLOWED_CALLBACK_OBJECT => true,
+            _ => false,
+        };
+
+        let f = field(1);
+        let flags = parse_pairs(f.1, &[ACE_FLAGS]).ok_or(err(f, ParseErrorKind::UnknownAceFlag))?;
+
+        let f = field(2);
+        let mask = parse_rights(f.1).ok_or(err(f, ParseErrorKind::UnknownRights))?;
+
+        let mut guids = [None; 2];
+        for (i, guid) in guids.iter_mut().enumerate() {
+            let f = field(3 + i);
+            if f.1.is_empty() { continue }
+            if !object { return Err(err(f, ParseErrorKind::UnexpectedGuid)) }
+            *guid = Some(parse_guid(f.1).ok_or(err(f, ParseErrorKind::InvalidGuid))?);
+        }
+
+        let f = field(5);
+        if sid_token_len(f.1) != f.1.len() || f.1.is_empty() { return Err(err(f, ParseErrorKind::InvalidSid)) }
+        let sid = parse_sid(f.1).map_err(|kind| err(f, kind))?;
+
+        let mut condition = Vec::new();
+        if field_count == 7 {
+            let f = field(6);
+            if !ty.is_callback() { return Err(err(f, ParseErrorKind::UnsupportedApplicationData)) }
+            let expression = f.1.parse::<ace::condition::Expression>().map_err(|e| ParseError::new(f.0 + e.offset(), ParseErrorKind::InvalidCondition(e.kind())))?;
+            condition = expression.to_bytes();
+        }
+
+        let mut ace = acl::Ace::new(ty, unsafe { ace::Flags::from_unchecked(flags) }, mask, sid.as_sid_ptr()).application_data(&condition);
+        if object { ace = ace.object(guids[0], guids[1]); }
+        match acl.push(acl::REVISION, ace) {
+            Ok(_)                                   => Ok(()),
+            Err(acl::BuilderError::TooLarge)        => Err(ParseError::new(start, ParseErrorKind::AclTooLarge)),
+            Err(acl::BuilderError::InvalidSid)      => Err(err(field(5), ParseErrorKind::InvalidSid)),
+            Err(acl::BuilderError::InvalidClaim(_)) => Err(ParseError::new(start, ParseErrorKind::InvalidAce)),
+        }
+    }
+}
+
+
+
+/// Parse a two letter alias (e.g. `BA`), or a `S-R-I-S...` string.
+fn parse_sid(s: &str) -> Result<sid::Owned, ParseErrorKind> {
+    if s.len() == 2 && s.bytes().all(|b| b.is_ascii_alphabetic()) {
+        if let Some(sid) = sid::parse_alias(s) { return Ok(sid::Owned::from(&*sid)) }
+        if DOMAIN_SIDS.iter().any(|alias| alias.eq_ignore_ascii_case(s)) { return Err(ParseErrorKind::DomainRelativeSidAlias) }
+        return Err(ParseErrorKind::UnknownSidAlias);
+    }
+    s.parse().map_err(|_| ParseErrorKind::InvalidSid)
+}
+
+/// Length of the SID alias or `S-...` string at the start of `s` (which may be followed by e.g. `G:...`), or 0 if there isn't one.
+fn sid_token_len(s: &str) -> usize {
+    let b = s.as_bytes();
+    if !(b.len() >= 2 && b[0].eq_ignore_ascii_case(&b'S') && b[1] == b'-') {
+        return if b.len() >= 2 && b[0].is_ascii_alphabetic() && b[1].is_ascii_alphabetic() { 2 } else { 0 };
+    }
+    let mut i = 2;
+    loop {
+        if b.get(i) == Some(&b'0') && b.get(i+1).is_some_and(|x| x.eq_ignore_ascii_case(&b'x')) {
+            i += 2;
+            while b.get(i).is_some_and(u8::is_ascii_hexdigit) { i += 1; }
+        } else {
+            while b.get(i).is_some_and(u8::is_ascii_digit) { i += 1; }
+        }
+        if b.get(i) == Some(&b'-') && b.get(i+1).is_some_and(u8::is_ascii_digit) { i += 1 } else { return i }
+    }
+}
+
+/// Decimal or `0x` prefixed hexadecimal
+fn parse_int(s: &str) -> Option<u64> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) { return None }
+        u64::from_str_radix(hex, 16).ok()
+    } else {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) { return None }
+        s.parse().ok()
+    }
+}
+
+/// Concatenated two letter aliases, e.g. `OICI`
+fn parse_pairs<T: Copy + core::ops::BitOr<Output = T> + Default>(s: &str, tables: &[&[(&str, T)]]) -> Option<T> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() { return None }
+    let mut value = T::default();
+    for i in (0 .. s.len()).step_by(2) {
+        let pair = &s[i .. i + 2];
+        value = value | tables.iter().copied().flatten().find(|(alias, _)| alias.eq_ignore_ascii_case(pair))?.1;
+    }
+    Some(value)
+}
+
+/// `GA`, `FRFW`, `0x1F01FF`, `2032127`, or empty (0)
+fn parse_rights(s: &str) -> Option<u32> {
+    if s.is_empty() { return Some(0) }
+    if s.as_bytes()[0].is_ascii_digit() { return parse_int(s).and_then(|m| u32::try_from(m).ok()) }
+    parse_pairs(s, &[RIGHTS, COMPOSITE_RIGHTS, LABEL_RIGHTS])
+}
+
+/// `bf967aba-0de6-11d0-a285-00aa003049e2` (no braces)
+fn parse_guid(s: &str) -> Option<GUID> {
+    let b = s.as_bytes();
+    if b.len() != 36 || [8, 13, 18, 23].iter().any(|&i| b[i] != b'-') { return None }
+    let hex : Vec<u8> = b.iter().copied().filter(|&c| c != b'-').collect();
+    if hex.len() != 32 || !hex.iter().all(u8::is_ascii_hexdigit) { return None }
+    let mut be = [0u8; 16];
+    for (i, byte) in be.iter_mut().enumerate() {
+        let pair = core::str::from_utf8(&hex[2*i .. 2*i+2]).ok()?;
+        *byte = u8::from_str_radix(pair, 16).ok()?;
+    }
+    Some(GUID {
+        Data1: u32::from_be_bytes([be[0], be[1], be[2], be[3]]),
+        Data2: u16::from_be_bytes([be[4], be[5]]),
+        Data3: u16::from_be_bytes([be[6], be[7]]),
+        Data4: [be[8], be[9], be[10], be[11], be[12], be[13], be[14], be[15]],
+    })
+}
+
+#[cfg(std)] #[test] fn golden_bytes() {
+    let sd = parse("O:BAG:SYD:(A;;GA;;;WD)").unwrap();
+    assert_eq!(sd.as_bytes(), &[
+        0x01, 0x00, 0x04, 0x80,  0x30, 0, 0, 0,  0x40, 0, 0, 0,  0, 0, 0, 0,  0x14, 0, 0, 0, // SECURITY_DESCRIPTOR_RELATIVE { rev, sbz1, DACL_PRESENT|SELF_RELATIVE, owner, group, sacl, dacl }
+        0x02, 0x00, 0x1C, 0x00,  0x01, 0x00, 0x00, 0x00,                                    // ACL { ACL_REVISION, sbz1, size, count, sbz2 }
+        0x00, 0x00, 0x14, 0x00,  0x00, 0x00, 0x00, 0x10,  1, 1, 0, 0, 0, 0, 0, 1,  0, 0, 0, 0, // ACCESS_ALLOWED_ACE { ..., GENERIC_ALL, S-1-1-0 }
+        1, 2, 0, 0, 0, 0, 0, 5,  32, 0, 0, 0,  0x20, 0x02, 0, 0,                            // S-1-5-32-544
+        1, 1, 0, 0, 0, 0, 0, 5,  18, 0, 0, 0,                                               // S-1-5-18
+    ][..]);
+
+    let sd = parse("D:(OA;;RP;bf967aba-0de6-11d0-a285-00aa003049e2;;AU)").unwrap();
+    let acl = sd.dacl().unwrap();
+    assert_eq!(u8::from(acl::REVISION_DS), acl.acl_revision());
+    assert_eq!(&acl.as_bytes()[8..], &[
+        0x05, 0x00, 0x28, 0x00,  0x10, 0x00, 0x00, 0x00,  0x01, 0x00, 0x00, 0x00,              // ACCESS_ALLOWED_OBJECT_ACE { ..., RP, ACE_OBJECT_TYPE_PRESENT }
+        0xba, 0x7a, 0x96, 0xbf,  0xe6, 0x0d,  0xd0, 0x11,  0xa2, 0x85, 0x00, 0xaa, 0x00, 0x30, 0x49, 0xe2,
+        1, 1, 0, 0, 0, 0, 0, 5,  11, 0, 0, 0,                                               // S-1-5-11
+    ][..]);
+}
+
+#[cfg(std)] #[test] fn errors() {
+    use ParseErrorKind::*;
+    for (sddl, offset, kind) in [
+        ("O:XX",                            2, UnknownSidAlias),
+        ("O:DA",                            2, DomainRelativeSidAlias),
+        ("O:S-2-5",                         2, InvalidSid),
+        ("O:S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15-16", 2, InvalidSid),
+        ("O:BAO:BA",                        4, DuplicateComponent),
+        ("X:BA",                            0, UnexpectedChar('X')),
+        ("O",                               1, UnexpectedEnd),
+        ("D:PX",                            3, UnknownAclFlag),
+        ("D:(A;;GA;;;WD",                  13, UnexpectedEnd),
+        ("D:(A;;GA;;WD)",                   2, WrongFieldCount),
+        ("D:(Q;;GA;;;WD)",                  3, UnknownAceType),
+        ("S:(RA;;;;;WD)",                   3, UnsupportedAceType),
+        ("D:(A;XX;GA;;;WD)",                5, UnknownAceFlag),
+        ("D:(A;;ZZ;;;WD)",                  6, UnknownRights),
+        ("D:(A;;GA;bf967aba;;WD)",          9, UnexpectedGuid),
+        ("D:(OA;;GA;bf967aba;;WD)",        10, InvalidGuid),
+        ("D:(A;;GA;;;WD;(@User.x == 1))",  14, UnsupportedApplicationData),
+        ("D:(XA;;GA;;;WD;(@User.x == ))",  27, InvalidCondition(ace::condition::ParseErrorKind::UnexpectedChar(')'))),
+        ("D:(XA;;GA;;;WD;(@User.x);)",      2, WrongFieldCount),
+        ("D:(A;;GA;;;S-1-1-0 x)",          11, InvalidSid),
+    ] {
+        let err = parse(sddl).unwrap_err();
+        assert_eq!((offset, kind), (err.offset(), err.kind()), "{sddl:?}: {err}");
+    }
+
+    let mut huge = std::string::String::from("D:");
+    for _ in 0 .. 0xFFFC / 76 { huge.push_str("(A;;GA;;;S-1-5-21-1-2-3-4-5-6-7-8-9-10-11-12-13-14)"); }
+    assert_eq!(0xFFFC / 76, parse(&huge).unwrap().dacl().unwrap().ace_count());
+    huge.push_str("(A;;GA;;;S-1-5-21-1-2-3-4-5-6-7-8-9-10-11-12-13-14)");
+    assert_eq!(AclTooLarge, parse(&huge).unwrap_err().kind());
+}
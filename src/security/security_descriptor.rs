@@ -1,6 +1,8 @@
 use crate::prelude::*;
 use winapi::um::winnt::*;
 
+use core::fmt::{self, Debug, Formatter};
+
 
 
 #[doc(alias = "SECURITY_DESCRIPTOR")]
@@ -14,3 +16,65 @@ use winapi::um::winnt::*;
 
 unsafe impl Send for Descriptor<'_> {}
 unsafe impl Sync for Descriptor<'_> {}
+
+impl<'b> Descriptor<'b> {
+    /// SECURITY_DESCRIPTOR::Revision
+    pub fn revision(&self) -> u8 { self.desc.Revision }
+
+    /// SECURITY_DESCRIPTOR::Control
+    pub fn control(&self) -> security::DescriptorControl { unsafe { security::DescriptorControl::from_unchecked(self.desc.Control) } }
+
+    /// SECURITY_DESCRIPTOR::Owner
+    pub fn owner(&self) -> Option<sid::Ptr<'b>> { self.component(self.desc.Owner.cast(), |r| r.Owner).map(|sid| unsafe { sid::Ptr::from_raw_unchecked(sid.as_ptr()) }) }
+
+    /// SECURITY_DESCRIPTOR::Group
+    pub fn group(&self) -> Option<sid::Ptr<'b>> { self.component(self.desc.Group.cast(), |r| r.Group).map(|sid| unsafe { sid::Ptr::from_raw_unchecked(sid.as_ptr()) }) }
+
+    /// SECURITY_DESCRIPTOR::Dacl, or [`None`] if the DACL is absent (`!SE_DACL_PRESENT`) or null (grants all access.)
+    pub fn dacl(&self) -> Option<acl::Ref<'b>> {
+        if self.desc.Control & SE_DACL_PRESENT == 0 { return None }
+        self.component(self.desc.Dacl, |r| r.Dacl).map(|acl| unsafe { acl::Ref::from_raw_unchecked(acl) })
+    }
+
+    /// SECURITY_DESCRIPTOR::Sacl, or [`None`] if the SACL is absent (`!SE_SACL_PRESENT`) or null.
+    pub fn sacl(&self) -> Option<acl::Ref<'b>> {
+        if self.desc.Control & SE_SACL_PRESENT == 0 { return None }
+        self.component(self.desc.Sacl, |r| r.Sacl).map(|acl| unsafe { acl::Ref::from_raw_unchecked(acl) })
+    }
+
+    /// The integrity label of the object: the first `SYSTEM_MANDATORY_LABEL_ACE` of the SACL that isn't `INHERIT_ONLY_ACE`.
+    ///
+    /// Returns [`None`] if there's no such label, in which case the system treats the object as `Medium` + `NO_WRITE_UP`.
+    pub fn integrity_label(&self) -> Option<ace::MandatoryLabel> {
+        self.sacl()?.aces()
+            .filter(|ace| ace.header().ty == ace::Type::SYSTEM_MANDATORY_LABEL)
+            .filter_map(ace::MandatoryLabel::from_ace)
+            .find(|label| !label.is_inherit_only())
+    }
+
+    /// Render as canonical [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format), without calling into `advapi32.dll`.
+    ///
+    /// Fails if the descriptor contains ACEs that can't (yet) be represented, such as conditional ACEs.
+    pub fn to_sddl(&self) -> Result<security::sddl::Display<'_>, security::sddl::EmitError> { security::sddl::Display::new(self) }
+
+    /// Resolve an absolute pointer, or a `SECURITY_DESCRIPTOR_RELATIVE` offset if `SE_SELF_RELATIVE`.
+    fn component<T>(&self, absolute: *mut T, relative: impl FnOnce(&SECURITY_DESCRIPTOR_RELATIVE) -> u32) -> Option<NonNull<T>> {
+        if self.desc.Control & SE_SELF_RELATIVE == 0 { return NonNull::new(absolute) }
+        let header : &SECURITY_DESCRIPTOR_RELATIVE = unsafe { &*(self as *const Self).cast() };
+        let offset = relative(header);
+        if offset == 0 { return None }
+        NonNull::new(unsafe { (self as *const Self).cast::<u8>().add(usize::from32(offset)) }.cast_mut().cast())
+    }
+}
+
+impl Debug for Descriptor<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("security::Descriptor")
+            .field("control",   &self.control())
+            .field("owner",     &self.owner())
+            .field("group",     &self.group())
+            .field("sacl",      &self.sacl())
+            .field("dacl",      &self.dacl())
+            .finish()
+    }
+}
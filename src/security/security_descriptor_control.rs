@@ -0,0 +1,141 @@
+#[doc(alias = "SECURITY_DESCRIPTOR_CONTROL")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+/// SECURITY_DESCRIPTOR_CONTROL/WORD/[u16] bitset of `SE_*` values
+///
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)] pub struct DescriptorControl(u16);
+
+#[doc(alias = "SECURITY_DESCRIPTOR_CONTROL")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+/// SECURITY_DESCRIPTOR_CONTROL/WORD/[u16] mask for `SE_*` values
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)] pub struct DescriptorControlMask(u16);
+
+flags!(impl .. for DescriptorControl(u16, u32) - DescriptorControlMask);
+
+impl DescriptorControl {
+    /// ### Safety
+    /// *   Some APIs might theoretically assume flags are a valid?
+    pub const unsafe fn from_unchecked(flags: u16) -> Self { Self(flags) }
+
+    pub fn as_u16(self) -> u16 { self.0 }
+
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// 0
+    pub const NONE : Self = Self(0);
+
+    #[doc(alias = "SE_OWNER_DEFAULTED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_OWNER_DEFAULTED
+    ///
+    pub const OWNER_DEFAULTED       : Self = Self(crate::winapi::um::winnt::SE_OWNER_DEFAULTED);
+
+    #[doc(alias = "SE_GROUP_DEFAULTED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_GROUP_DEFAULTED
+    ///
+    pub const GROUP_DEFAULTED       : Self = Self(crate::winapi::um::winnt::SE_GROUP_DEFAULTED);
+
+    #[doc(alias = "SE_DACL_PRESENT")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_DACL_PRESENT
+    /// &mdash;
+    /// The descriptor has a DACL.  If set with a null DACL, all access is granted.
+    ///
+    pub const DACL_PRESENT          : Self = Self(crate::winapi::um::winnt::SE_DACL_PRESENT);
+
+    #[doc(alias = "SE_DACL_DEFAULTED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_DACL_DEFAULTED
+    ///
+    pub const DACL_DEFAULTED        : Self = Self(crate::winapi::um::winnt::SE_DACL_DEFAULTED);
+
+    #[doc(alias = "SE_SACL_PRESENT")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_SACL_PRESENT
+    ///
+    pub const SACL_PRESENT          : Self = Self(crate::winapi::um::winnt::SE_SACL_PRESENT);
+
+    #[doc(alias = "SE_SACL_DEFAULTED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_SACL_DEFAULTED
+    ///
+    pub const SACL_DEFAULTED        : Self = Self(crate::winapi::um::winnt::SE_SACL_DEFAULTED);
+
+    #[doc(alias = "SE_DACL_AUTO_INHERIT_REQ")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_DACL_AUTO_INHERIT_REQ (SDDL: `D:AR`)
+    ///
+    pub const DACL_AUTO_INHERIT_REQ : Self = Self(crate::winapi::um::winnt::SE_DACL_AUTO_INHERIT_REQ);
+
+    #[doc(alias = "SE_SACL_AUTO_INHERIT_REQ")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_SACL_AUTO_INHERIT_REQ (SDDL: `S:AR`)
+    ///
+    pub const SACL_AUTO_INHERIT_REQ : Self = Self(crate::winapi::um::winnt::SE_SACL_AUTO_INHERIT_REQ);
+
+    #[doc(alias = "SE_DACL_AUTO_INHERITED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_DACL_AUTO_INHERITED (SDDL: `D:AI`)
+    ///
+    pub const DACL_AUTO_INHERITED   : Self = Self(crate::winapi::um::winnt::SE_DACL_AUTO_INHERITED);
+
+    #[doc(alias = "SE_SACL_AUTO_INHERITED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_SACL_AUTO_INHERITED (SDDL: `S:AI`)
+    ///
+    pub const SACL_AUTO_INHERITED   : Self = Self(crate::winapi::um::winnt::SE_SACL_AUTO_INHERITED);
+
+    #[doc(alias = "SE_DACL_PROTECTED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_DACL_PROTECTED (SDDL: `D:P`)
+    /// &mdash;
+    /// Prevents the DACL from being modified by inheritable ACEs.
+    ///
+    pub const DACL_PROTECTED        : Self = Self(crate::winapi::um::winnt::SE_DACL_PROTECTED);
+
+    #[doc(alias = "SE_SACL_PROTECTED")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_SACL_PROTECTED (SDDL: `S:P`)
+    /// &mdash;
+    /// Prevents the SACL from being modified by inheritable ACEs.
+    ///
+    pub const SACL_PROTECTED        : Self = Self(crate::winapi::um::winnt::SE_SACL_PROTECTED);
+
+    #[doc(alias = "SE_RM_CONTROL_VALID")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_RM_CONTROL_VALID
+    ///
+    pub const RM_CONTROL_VALID      : Self = Self(crate::winapi::um::winnt::SE_RM_CONTROL_VALID);
+
+    #[doc(alias = "SE_SELF_RELATIVE")]
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-control)\]
+    /// SE_SELF_RELATIVE
+    /// &mdash;
+    /// The descriptor's owner, group, SACL, and DACL are stored as offsets into a single contiguous allocation.
+    ///
+    pub const SELF_RELATIVE         : Self = Self(crate::winapi::um::winnt::SE_SELF_RELATIVE);
+}
+
+impl core::fmt::Debug for DescriptorControl {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use crate::winapi::um::winnt::*;
+        flags!(self.0, fmt, "0x{:04X}", [
+            SE_OWNER_DEFAULTED,
+            SE_GROUP_DEFAULTED,
+            SE_DACL_PRESENT,
+            SE_DACL_DEFAULTED,
+            SE_SACL_PRESENT,
+            SE_SACL_DEFAULTED,
+            SE_DACL_AUTO_INHERIT_REQ,
+            SE_SACL_AUTO_INHERIT_REQ,
+            SE_DACL_AUTO_INHERITED,
+            SE_SACL_AUTO_INHERITED,
+            SE_DACL_PROTECTED,
+            SE_SACL_PROTECTED,
+            SE_RM_CONTROL_VALID,
+            SE_SELF_RELATIVE,
+        ])
+    }
+}
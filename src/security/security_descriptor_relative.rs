@@ -0,0 +1,314 @@
+use crate::prelude::*;
+use winapi::um::winnt::*;
+
+use alloc::vec::Vec;
+
+use core::fmt::{self, Debug, Formatter};
+use core::str::FromStr;
+
+
+
+#[doc(alias = "SECURITY_DESCRIPTOR_RELATIVE")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/absolute-and-self-relative-security-descriptors)\]
+/// Owned, self-relative SECURITY_DESCRIPTOR
+///
+/// The owner, group, SACL, and DACL are stored as offsets into a single contiguous buffer (`SE_SELF_RELATIVE`),
+/// which makes this type trivially clonable, comparable, and serializable.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let sd : security::RelativeDescriptor = "O:BAG:SYD:(A;;GA;;;WD)".parse().unwrap();
+/// assert_eq!(Some(&*sid!(S-1-5-32-544)), sd.owner().as_deref());
+/// assert_eq!("O:BAG:SYD:(A;;GA;;;WD)", sd.to_sddl().unwrap().to_string());
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RelativeDescriptor {
+    buffer: Vec<u64>, // 8-byte aligned, and at least size_of::<SECURITY_DESCRIPTOR>() bytes for `as_descriptor`
+    len:    usize,
+}
+
+impl RelativeDescriptor {
+    /// Parse [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format) without calling into `advapi32.dll`.
+    ///
+    /// Only machine independent SID aliases (e.g. `BA`, `WD`, `SY`, `LW`) are accepted - domain relative aliases (e.g. `DA`, `LA`) require a domain or machine SID to resolve.
+    pub fn from_sddl(sddl: &str) -> Result<Self, security::sddl::ParseError> { security::sddl::parse(sddl) }
+
+    /// Parse a binary `SECURITY_DESCRIPTOR_RELATIVE` and the owner, group, SACL, and DACL it references, such as [`as_bytes`](Self::as_bytes) or `MakeSelfRelativeSD` would produce.
+    ///
+    /// Every offset is bounds checked before use, so `bytes` may come from an untrusted source:
+    /// *   Offsets must be 0 (absent), or 4 byte aligned, past the 20 byte header, and within `bytes`.
+    /// *   SIDs must be revision 1, with no more than 15 sub-authorities, fitting within `bytes`.
+    /// *   ACLs must be revision 2 ..= 4, and their ACEs must be 4 byte aligned and fit within the ACL, which must fit within `bytes`.
+    ///
+    /// ACE contents (types, SIDs, masks) are *not* validated - see [`acl::Ref::validate`] for that.
+    /// Bytes not referenced by any offset are preserved as-is.
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let sd = security::RelativeDescriptor::from_sddl("O:BAD:(A;;GA;;;WD)").unwrap();
+    /// let copy = security::RelativeDescriptor::from_bytes(sd.as_bytes()).unwrap();
+    /// assert_eq!(sd, copy);
+    ///
+    /// assert_eq!(Err(security::RelativeDescriptorError::Truncated), security::RelativeDescriptor::from_bytes(&[1, 0, 0, 0x80]));
+    /// ```
+    ///
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, security::RelativeDescriptorError> {
+        use security::{DescriptorComponent as Component, RelativeDescriptorError as E};
+
+        let header = bytes.get(..SECURITY_DESCRIPTOR_MIN_LENGTH_RELATIVE).ok_or(E::Truncated)?;
+        if u32::from(header[0]) != SECURITY_DESCRIPTOR_REVISION { return Err(E::UnsupportedRevision(header[0])) }
+        if u16_at(header, 2) & SE_SELF_RELATIVE == 0 { return Err(E::NotSelfRelative) }
+
+        for (component, field) in [(Component::Owner, 4), (Component::Group, 8), (Component::Sacl, 12), (Component::Dacl, 16)] {
+            let offset = usize::from32(u32_at(header, field));
+            if offset == 0 { continue }
+            if offset < header.len() || !offset.is_multiple_of(4) || offset >= bytes.len() { return Err(E::InvalidOffset(component)) }
+            let data = &bytes[offset..];
+            match component {
+                Component::Owner | Component::Group => if !valid_sid(data) { return Err(E::InvalidSid(component)) },
+                Component::Sacl  | Component::Dacl  => if !valid_acl(data) { return Err(E::InvalidAcl(component)) },
+            }
+        }
+
+        Ok(Self::from_bytes_unchecked(bytes))
+    }
+
+    /// Serialize any (absolute or self-relative) [`security::Descriptor`], such as one built by `security::DescriptorBuilder`, like `MakeSelfRelativeSD` would.
+    pub fn from_descriptor(sd: &security::Descriptor) -> Self {
+        Self::from_parts(
+            sd.control(),
+            sd.owner().as_ref().map(|sid| sid.as_bytes()),
+            sd.group().as_ref().map(|sid| sid.as_bytes()),
+            sd.sacl().map(acl::Ref::as_bytes),
+            sd.dacl().map(acl::Ref::as_bytes),
+        )
+    }
+
+    /// Assemble a self-relative descriptor from its parts.
+    ///
+    /// `SE_SELF_RELATIVE` is added to `control`.
+    /// `SE_DACL_PRESENT`/`SE_SACL_PRESENT` are honored as-is: a present but [`None`] ACL is serialized as a null ACL.
+    pub(crate) fn from_parts(control: security::DescriptorControl, owner: Option<&[u8]>, group: Option<&[u8]>, sacl: Option<&[u8]>, dacl: Option<&[u8]>) -> Self {
+        let control = control.as_u16() | SE_SELF_RELATIVE;
+        let mut bytes = Vec::<u8>::with_capacity(20);
+        bytes.extend_from_slice(&[SECURITY_DESCRIPTOR_REVISION as u8, 0]);
+        bytes.extend_from_slice(&control.to_le_bytes());
+        bytes.extend_from_slice(&[0; 16]); // Owner, Group, Sacl, Dacl offsets
+
+        // Same order as MakeSelfRelativeSD / ConvertStringSecurityDescriptorToSecurityDescriptor: SACL, DACL, Owner, Group
+        let mut append = |field: usize, data: Option<&[u8]>, present: bool| {
+            let Some(data) = data.filter(|_| present) else { return };
+            let offset = bytes.len() as u32;
+            bytes[field .. field + 4].copy_from_slice(&offset.to_le_bytes());
+            bytes.extend_from_slice(data);
+            while !bytes.len().is_multiple_of(4) { bytes.push(0); }
+        };
+        append(12, sacl,  control & SE_SACL_PRESENT != 0);
+        append(16, dacl,  control & SE_DACL_PRESENT != 0);
+        append( 4, owner, true);
+        append( 8, group, true);
+
+        Self::from_bytes_unchecked(&bytes)
+    }
+
+    fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+        let words = bytes.len().max(size_of::<SECURITY_DESCRIPTOR>()).div_ceil(8);
+        let mut buffer = alloc::vec![0u64; words];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut buffer[..])[..bytes.len()].copy_from_slice(bytes);
+        Self { buffer, len: bytes.len() }
+    }
+
+    /// The serialized `SECURITY_DESCRIPTOR_RELATIVE` and everything it references.
+    pub fn as_bytes(&self) -> &[u8] { &bytemuck::cast_slice(&self.buffer[..])[..self.len] }
+
+    pub fn as_descriptor(&self) -> &security::Descriptor<'_> { unsafe { &*self.buffer.as_ptr().cast() } }
+
+    /// SECURITY_DESCRIPTOR::Control
+    pub fn control(&self) -> security::DescriptorControl { self.as_descriptor().control() }
+
+    /// SECURITY_DESCRIPTOR::Owner
+    pub fn owner(&self) -> Option<sid::Ptr<'_>> { self.as_descriptor().owner() }
+
+    /// SECURITY_DESCRIPTOR::Group
+    pub fn group(&self) -> Option<sid::Ptr<'_>> { self.as_descriptor().group() }
+
+    /// SECURITY_DESCRIPTOR::Dacl, or [`None`] if absent or null.
+    pub fn dacl(&self) -> Option<acl::Ref<'_>> { self.as_descriptor().dacl() }
+
+    /// SECURITY_DESCRIPTOR::Sacl, or [`None`] if absent or null.
+    pub fn sacl(&self) -> Option<acl::Ref<'_>> { self.as_descriptor().sacl() }
+
+    /// The integrity label of the object, or [`None`] if unlabeled (see [`security::Descriptor::integrity_label`].)
+    pub fn integrity_label(&self) -> Option<ace::MandatoryLabel> { self.as_descriptor().integrity_label() }
+
+    /// Replace the integrity label of the object, or remove it if [`None`].
+    ///
+    /// Every `SYSTEM_MANDATORY_LABEL_ACE` that isn't `INHERIT_ONLY_ACE` is removed from the SACL, and `label` is inserted as the first ACE.
+    /// Other ACEs of the SACL (audit ACEs, inherit only labels, etc.) are preserved.
+    /// The SACL is created if absent, unless `label` is [`None`] (removing the label from a descriptor without a SACL is a no-op.)
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let mut sd = security::RelativeDescriptor::from_sddl("D:(A;;GA;;;WD)S:(AU;SA;GA;;;WD)").unwrap();
+    /// sd.set_integrity_label(ace::MandatoryLabel::new(sid::integrity::Low, ace::MandatoryLabelPolicy::NO_WRITE_UP)).unwrap();
+    /// assert_eq!("D:(A;;GA;;;WD)S:(ML;;NW;;;LW)(AU;SA;GA;;;WD)", sd.to_sddl().unwrap().to_string());
+    /// ```
+    ///
+    pub fn set_integrity_label(&mut self, label: impl Into<Option<ace::MandatoryLabel>>) -> Result<&mut Self, acl::BuilderError> {
+        let label = label.into();
+        let sd = self.as_descriptor();
+        if label.is_none() && sd.sacl().is_none() { return Ok(self) }
+        let revision = sd.sacl().map_or(acl::REVISION, |sacl| unsafe { acl::Revision::from_unchecked(sacl.acl_revision()) });
+        let mut sacl = acl::Builder::new(revision);
+        if let Some(label) = label { sacl.add_mandatory_ace(revision, label.flags, label.policy, &label.level)?; }
+        for ace in sd.sacl().into_iter().flat_map(|sacl| sacl.aces()) {
+            if ace::MandatoryLabel::from_ace(ace).is_some_and(|label| !label.is_inherit_only()) { continue }
+            sacl.insert(usize::MAX, revision, ace.as_bytes(), 1)?;
+        }
+
+        *self = Self::from_parts(
+            sd.control() | security::DescriptorControl::SACL_PRESENT,
+            sd.owner().as_ref().map(|sid| sid.as_bytes()),
+            sd.group().as_ref().map(|sid| sid.as_bytes()),
+            Some(sacl.as_bytes()),
+            sd.dacl().map(acl::Ref::as_bytes),
+        );
+        Ok(self)
+    }
+
+    /// Render as canonical [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-string-format).
+    pub fn to_sddl(&self) -> Result<security::sddl::Display<'_>, security::sddl::EmitError> { self.as_descriptor().to_sddl() }
+}
+
+impl<'b> From<&'b RelativeDescriptor> for &'b security::Descriptor<'b> { fn from(sd: &'b RelativeDescriptor) -> Self { sd.as_descriptor() } }
+impl From<&security::Descriptor<'_>> for RelativeDescriptor { fn from(sd: &security::Descriptor) -> Self { Self::from_descriptor(sd) } }
+
+impl TryFrom<&[u8]> for RelativeDescriptor {
+    type Error = security::RelativeDescriptorError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { Self::from_bytes(bytes) }
+}
+
+impl FromStr for RelativeDescriptor {
+    type Err = security::sddl::ParseError;
+    fn from_str(sddl: &str) -> Result<Self, Self::Err> { Self::from_sddl(sddl) }
+}
+
+impl Debug for RelativeDescriptor {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Debug::fmt(self.as_descriptor(), fmt) }
+}
+
+
+
+const SECURITY_DESCRIPTOR_MIN_LENGTH_RELATIVE : usize = 20; // size_of::<SECURITY_DESCRIPTOR_RELATIVE>()
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 { u16::from_le_bytes([bytes[offset], bytes[offset + 1]]) }
+fn u32_at(bytes: &[u8], offset: usize) -> u32 { u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]]) }
+
+/// `data` starts with a well formed SID (a subset of `RtlValidSid`)
+fn valid_sid(data: &[u8]) -> bool {
+    let [revision, count, ..] = *data else { return false };
+    revision == SID_REVISION && count <= SID_MAX_SUB_AUTHORITIES && data.len() >= 8 + 4 * usize::from(count)
+}
+
+/// `data` starts with a structurally well formed ACL (a subset of `RtlValidAcl`)
+fn valid_acl(data: &[u8]) -> bool {
+    if data.len() < size_of::<ACL>() { return false }
+    if !(MIN_ACL_REVISION ..= MAX_ACL_REVISION).contains(&data[0]) { return false }
+    let size = usize::from(u16_at(data, 2));
+    if size < size_of::<ACL>() || !size.is_multiple_of(4) || size > data.len() { return false }
+    let mut pos = size_of::<ACL>();
+    for _ in 0 .. u16_at(data, 4) {
+        if pos + size_of::<ACE_HEADER>() > size { return false }
+        let ace_size = usize::from(u16_at(data, pos + 2));
+        if ace_size < size_of::<ACE_HEADER>() || !ace_size.is_multiple_of(4) || pos + ace_size > size { return false }
+        pos += ace_size;
+    }
+    true
+}
+
+
+
+#[cfg(std)] #[test] fn from_bytes() {
+    use security::{DescriptorComponent as Component, RelativeDescriptorError as E};
+    use std::string::ToString;
+
+    let sd = RelativeDescriptor::from_sddl("O:BAG:SYD:PAI(A;OICIID;FA;;;SY)(A;;0x1200a9;;;BU)S:(ML;;NW;;;LW)").unwrap();
+    let bytes = sd.as_bytes();
+    assert_eq!(sd, RelativeDescriptor::from_bytes(bytes).unwrap());
+    assert_eq!(sd, RelativeDescriptor::from_descriptor(sd.as_descriptor()));
+
+    let absolute = security::Descriptor {
+        desc: SECURITY_DESCRIPTOR {
+            Revision:   1,
+            Control:    SE_DACL_PRESENT,
+            Owner:      sid!(S-1-5-18).as_psid(),
+            Dacl:       sd.dacl().unwrap().as_pacl().as_ptr(),
+            ..Default::default()
+        },
+        phantom: PhantomData,
+    };
+    assert_eq!("O:SYD:(A;OICIID;FA;;;SY)(A;;0x1200a9;;;BU)", RelativeDescriptor::from_descriptor(&absolute).to_sddl().unwrap().to_string());
+
+    let patch = |offset: usize, patch: &[u8]| {
+        let mut bytes = bytes.to_vec();
+        bytes[offset .. offset + patch.len()].copy_from_slice(patch);
+        RelativeDescriptor::from_bytes(&bytes)
+    };
+    let owner = usize::from32(u32_at(bytes, 4));
+    let dacl  = usize::from32(u32_at(bytes, 16));
+    assert_eq!(Err(E::Truncated),                           RelativeDescriptor::from_bytes(&bytes[..19]));
+    assert_eq!(Err(E::UnsupportedRevision(2)),              patch(0, &[2]));
+    assert_eq!(Err(E::NotSelfRelative),                     patch(3, &[0x00]));
+    assert_eq!(Err(E::InvalidOffset(Component::Owner)),     patch(4, &[8, 0, 0, 0]));
+    assert_eq!(Err(E::InvalidOffset(Component::Group)),     patch(8, &[0xFF, 0xFF, 0xFF, 0xFF]));
+    assert_eq!(Err(E::InvalidOffset(Component::Dacl)),      patch(16, &(dacl as u32 + 2).to_le_bytes()));
+    assert_eq!(Err(E::InvalidSid(Component::Owner)),        patch(owner + 1, &[16]));
+    assert_eq!(Err(E::InvalidAcl(Component::Dacl)),         patch(dacl, &[1]));
+    assert_eq!(Err(E::InvalidAcl(Component::Dacl)),         patch(dacl + 2, &[0xFC, 0xFF]));
+    assert_eq!(Err(E::InvalidAcl(Component::Dacl)),         patch(dacl + 4, &[3]));
+    assert_eq!(Err(E::InvalidAcl(Component::Dacl)),         patch(dacl + 8 + 2, &[0, 0]));
+    assert_eq!(Err(E::InvalidSid(Component::Owner)),        RelativeDescriptor::from_bytes(&bytes[..owner + 12]));
+
+    // Every truncation and single byte corruption must either be rejected, or be safe to walk.
+    for len in 0 .. bytes.len() { let _ = RelativeDescriptor::from_bytes(&bytes[..len]); }
+    for offset in 0 .. bytes.len() {
+        for value in [0x00, 0x01, 0x04, 0x0F, 0x10, 0x7F, 0x80, 0xFF] {
+            let Ok(sd) = patch(offset, &[value]) else { continue };
+            let _ = std::format!("{sd:?}");
+            for acl in [sd.sacl(), sd.dacl()].into_iter().flatten() {
+                for ace in acl.aces() { let _ = (ace.sid(), ace.mask(), ace.application_data()); }
+            }
+        }
+    }
+}
+
+#[cfg(std)] #[test] fn integrity_label() {
+    use ace::{MandatoryLabel, MandatoryLabelPolicy as P};
+    use std::string::ToString;
+
+    let mut sd = RelativeDescriptor::from_sddl("D:(A;;GA;;;WD)S:(ML;OICIIO;NWNR;;;HI)(ML;;NW;;;ME)(AU;SA;GA;;;WD)").unwrap();
+    assert_eq!(Some(MandatoryLabel::new(sid::integrity::Medium, P::NO_WRITE_UP)), sd.integrity_label());
+
+    sd.set_integrity_label(MandatoryLabel::new(sid::integrity::Low, P::NO_WRITE_UP | P::NO_EXECUTE_UP)).unwrap();
+    assert_eq!("D:(A;;GA;;;WD)S:(ML;;NWNX;;;LW)(ML;OICIIO;NWNR;;;HI)(AU;SA;GA;;;WD)", sd.to_sddl().unwrap().to_string());
+    assert_eq!(Some(sid::integrity::Low), sd.integrity_label().map(|label| label.level));
+
+    sd.set_integrity_label(None).unwrap();
+    assert_eq!("D:(A;;GA;;;WD)S:(ML;OICIIO;NWNR;;;HI)(AU;SA;GA;;;WD)", sd.to_sddl().unwrap().to_string());
+    assert_eq!(None, sd.integrity_label());
+
+    let mut sd = RelativeDescriptor::from_sddl("O:SYD:").unwrap();
+    let before = sd.clone();
+    sd.set_integrity_label(None).unwrap();
+    assert_eq!(before, sd);
+    assert_eq!(0, sd.control().as_u16() & SE_SACL_PRESENT);
+    assert_eq!("O:SYD:", sd.to_sddl().unwrap().to_string());
+
+    sd.set_integrity_label(MandatoryLabel { flags: ace::OBJECT_INHERIT_ACE | ace::CONTAINER_INHERIT_ACE, ..MandatoryLabel::new(sid::integrity::Untrusted, P::NO_WRITE_UP) }).unwrap();
+    assert_eq!("O:SYD:S:(ML;OICI;NW;;;S-1-16-0)", sd.to_sddl().unwrap().to_string());
+    assert_eq!(sd, RelativeDescriptor::from_bytes(sd.as_bytes()).unwrap());
+}
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// The owner, group, SACL, or DACL of a security descriptor
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DescriptorComponent { Owner, Group, Sacl, Dacl }
+
+/// Error parsing a binary self-relative security descriptor, as returned by [`security::RelativeDescriptor::from_bytes`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum RelativeDescriptorError {
+    /// The buffer is smaller than the 20 byte `SECURITY_DESCRIPTOR_RELATIVE` header.
+    Truncated,
+    /// `SECURITY_DESCRIPTOR_RELATIVE::Revision` isn't `SECURITY_DESCRIPTOR_REVISION` (1).
+    UnsupportedRevision(u8),
+    /// `SE_SELF_RELATIVE` isn't set in `SECURITY_DESCRIPTOR_RELATIVE::Control`.
+    NotSelfRelative,
+    /// The component's offset overlaps the header, isn't 4 byte aligned, or is past the end of the buffer.
+    InvalidOffset(DescriptorComponent),
+    /// The owner or group SID has the wrong revision, more than 15 sub-authorities, or extends past the end of the buffer.
+    InvalidSid(DescriptorComponent),
+    /// The SACL or DACL has an unsupported revision, or its size or ACE sizes are misaligned or extend past the end of the ACL or buffer.
+    InvalidAcl(DescriptorComponent),
+}
+
+impl From<RelativeDescriptorError> for firehazard::Error {
+    fn from(err: RelativeDescriptorError) -> Self {
+        Self(match err {
+            RelativeDescriptorError::InvalidSid(_)  => ERROR_INVALID_SID,
+            RelativeDescriptorError::InvalidAcl(_)  => ERROR_INVALID_ACL,
+            _                                       => ERROR_INVALID_SECURITY_DESCR,
+        })
+    }
+}
+
+#[cfg(std)] impl From<RelativeDescriptorError> for std::io::Error {
+    fn from(err: RelativeDescriptorError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidData, err) }
+}
+
+impl core::error::Error for RelativeDescriptorError {}
+
+impl Display for RelativeDescriptorError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            RelativeDescriptorError::Truncated              => write!(fmt, "security descriptor is smaller than its 20 byte header"),
+            RelativeDescriptorError::UnsupportedRevision(r) => write!(fmt, "unsupported security descriptor revision {r}"),
+            RelativeDescriptorError::NotSelfRelative        => write!(fmt, "security descriptor isn't self-relative"),
+            RelativeDescriptorError::InvalidOffset(c)       => write!(fmt, "security descriptor {c:?} offset is out of bounds or misaligned"),
+            RelativeDescriptorError::InvalidSid(c)          => write!(fmt, "security descriptor {c:?} SID is malformed"),
+            RelativeDescriptorError::InvalidAcl(c)          => write!(fmt, "security descriptor {c:?} ACL is malformed"),
+        }
+    }
+}
@@ -7,17 +7,20 @@
 mod sid_and_attributes;                 pub use sid_and_attributes::*;
 mod sid_attributes;                     pub use sid_attributes::*;
 mod sid_constants;                      pub use sid_constants::*;
+mod sid_owned;                          pub use sid_owned::*;
+mod sid_parse;                          pub use sid_parse::*;
 mod sid_ptr;                            pub use sid_ptr::*;
-mod sid_box;                            pub use sid_box::*;
+#[cfg(windows)] mod sid_box;            #[cfg(windows)] pub use sid_box::*;
 mod sid_static;                         pub use sid_static::*;
 mod sid_value;                          pub use sid_value::*;
+mod sid_well_known;                     pub use sid_well_known::*;
 
 
 
-pub use funcs::*;
-pub(crate) mod funcs {
+#[cfg(windows)] pub use funcs::*;
+#[cfg(windows)] pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\convert_sid_to_string_sid.rs");
-    include!(r"funcs\convert_string_sid_to_sid.rs");
-    include!(r"funcs\equal_sid.rs");
+    include!("funcs/convert_sid_to_string_sid.rs");
+    include!("funcs/convert_string_sid_to_sid.rs");
+    include!("funcs/equal_sid.rs");
 }
@@ -21,6 +21,7 @@
 ///
 /// ### See Also
 /// *   [sid!] for compile-time validated error-free [`sid::Ptr`]s.
+/// *   [`sid::Owned`]'s [`FromStr`](core::str::FromStr) impl to parse at runtime without advapi32 or allocation.
 ///
 pub fn convert_string_sid_to_sid_a(s: impl string::InNarrow) -> firehazard::Result<sid::Box<alloc::LocalAllocFree>> {
     string::convert_to_cstrnn::<{limit::stack::SID_STRING}, _, _>(s, |s| {
@@ -54,6 +55,7 @@ pub fn convert_string_sid_to_sid_a(s: impl string::InNarrow) -> firehazard::Resu
 ///
 /// ### See Also
 /// *   [sid!] for compile-time validated error-free [`sid::Ptr`]s.
+/// *   [`sid::Owned`]'s [`FromStr`](core::str::FromStr) impl to parse at runtime without advapi32 or allocation.
 ///
 pub fn convert_string_sid_to_sid_w(s: impl string::InWide) -> firehazard::Result<sid::Box<alloc::LocalAllocFree>> {
     string::convert_to_cstrnn::<{limit::stack::SID_STRING}, _, _>(s, |s| {
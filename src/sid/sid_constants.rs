@@ -1,13 +1,19 @@
-#[allow(unused_imports)] use winapi::um::winnt::*; // mostly for docs
+#[cfg(windows)] #[allow(unused_imports)] use winapi::um::winnt::*; // mostly for docs
 
 macro_rules! well_known_sids {
+    (@sddl) => { None };
+    (@sddl $sddl:ident) => { Some(crate::winapi::shared::sddl::$sddl) };
+    (@subauth ZERO) => { "* `0`" };
+    (@subauth $subauth:ident) => { concat!("* [", stringify!($subauth), "]") };
+
     ($(
         $( #[desc = $desc:literal] )*
+        $( #[sddl = $sddl:ident] )?
         #[values($rev:ident, $auth:ident $(, $subauth:ident)* $(,)?)]
         $( #[$($attr:meta),+] )*
         pub const $name:ident = sid!($($sid:tt)*);
     )*) => {
-        #[allow(unused_imports)] use winapi::um::winnt::*;
+        #[cfg(windows)] #[allow(unused_imports)] use winapi::um::winnt::*;
 
         $(
             #[doc = concat!("`",$(stringify!($sid)),*,"`")]
@@ -16,13 +22,19 @@ macro_rules! well_known_sids {
             /// SID Components:
             #[doc = concat!("* [", stringify!($rev), "]")]
             #[doc = concat!("* [", stringify!($auth), "]")]
-        $(  #[doc = concat!("* [", stringify!($subauth), "]")] )*
+        $(  #[doc = well_known_sids!(@subauth $subauth)] )*
             $( #[$($attr),+] )*
             pub const $name : crate::sid::Ptr<'static> = crate::sid!($($sid)*);
         )*
 
-        #[cfg(std)] #[test] fn validate_sid_values() {
+        /// Registry entries for [`crate::sid::WellKnown`]
+        pub(crate) const WELL_KNOWN : &[crate::sid::WellKnown] = &[$(
+            crate::sid::WellKnown { sid: $name, names: &[$($desc),*], sddl: well_known_sids!(@sddl $($sddl)?) },
+        )*];
+
+        #[cfg(all(std, windows))] #[test] fn validate_sid_values() {
             #[allow(unused_mut)] let mut errors = false;
+            #[allow(dead_code)] const ZERO : u32 = 0;
 
             $(
                 let name = stringify!($name);
@@ -62,15 +74,15 @@ macro_rules! well_known_sids {
 well_known_sids! {
     // C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
     // "Universal well-known SIDs"
-    #[desc = "NULL SID"]                        #[values(SID_REVISION, SECURITY_NULL_SID_AUTHORITY,    SECURITY_NULL_RID)]                  pub const NULL                  = sid!(S-1-0-0);
-    #[desc = "Everyone"]                        #[values(SID_REVISION, SECURITY_WORLD_SID_AUTHORITY,   SECURITY_WORLD_RID)]                 pub const WORLD                 = sid!(S-1-1-0);
-    #[desc = "LOCAL"]                           #[values(SID_REVISION, SECURITY_LOCAL_SID_AUTHORITY,   SECURITY_LOCAL_RID)]                 pub const LOCAL                 = sid!(S-1-2-0);
-    #[desc = "CONSOLE LOGON"]                   #[values(SID_REVISION, SECURITY_LOCAL_SID_AUTHORITY,   SECURITY_LOCAL_LOGON_RID)]           pub const LOCAL_LOGON           = sid!(S-1-2-1); // ? aka "Console Logon"
-    #[desc = "CREATOR OWNER"]                   #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_OWNER_RID)]         pub const CREATOR_ONWER         = sid!(S-1-3-0);
-    #[desc = "CREATOR GROUP"]                   #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_GROUP_RID)]         pub const CREATOR_GROUP         = sid!(S-1-3-1);
-    #[desc = "CREATOR OWNER SERVER"]            #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_OWNER_SERVER_RID)]  pub const CREATOR_OWNER_SERVER  = sid!(S-1-3-2);
-    #[desc = "CREATOR GROUP SERVER"]            #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_GROUP_SERVER_RID)]  pub const CREATOR_GROUP_SERVER  = sid!(S-1-3-3);
-    #[desc = "OWNER RIGHTS"]                    #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_OWNER_RIGHTS_RID)]  pub const CREATOR_OWNER_RIGHTS  = sid!(S-1-3-4);
+    #[desc = "NULL SID"]                                                                        #[values(SID_REVISION, SECURITY_NULL_SID_AUTHORITY,    SECURITY_NULL_RID)]                  pub const NULL                  = sid!(S-1-0-0);
+    #[desc = "Everyone"]                        #[sddl = SDDL_EVERYONE]                         #[values(SID_REVISION, SECURITY_WORLD_SID_AUTHORITY,   SECURITY_WORLD_RID)]                 pub const WORLD                 = sid!(S-1-1-0);
+    #[desc = "LOCAL"]                                                                           #[values(SID_REVISION, SECURITY_LOCAL_SID_AUTHORITY,   SECURITY_LOCAL_RID)]                 pub const LOCAL                 = sid!(S-1-2-0);
+    #[desc = "CONSOLE LOGON"]                                                                   #[values(SID_REVISION, SECURITY_LOCAL_SID_AUTHORITY,   SECURITY_LOCAL_LOGON_RID)]           pub const LOCAL_LOGON           = sid!(S-1-2-1); // ? aka "Console Logon"
+    #[desc = "CREATOR OWNER"]                   #[sddl = SDDL_CREATOR_OWNER]                    #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_OWNER_RID)]         pub const CREATOR_ONWER         = sid!(S-1-3-0);
+    #[desc = "CREATOR GROUP"]                   #[sddl = SDDL_CREATOR_GROUP]                    #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_GROUP_RID)]         pub const CREATOR_GROUP         = sid!(S-1-3-1);
+    #[desc = "CREATOR OWNER SERVER"]                                                            #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_OWNER_SERVER_RID)]  pub const CREATOR_OWNER_SERVER  = sid!(S-1-3-2);
+    #[desc = "CREATOR GROUP SERVER"]                                                            #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_GROUP_SERVER_RID)]  pub const CREATOR_GROUP_SERVER  = sid!(S-1-3-3);
+    #[desc = "OWNER RIGHTS"]                    #[sddl = SDDL_OWNER_RIGHTS]                     #[values(SID_REVISION, SECURITY_CREATOR_SID_AUTHORITY, SECURITY_CREATOR_OWNER_RIGHTS_RID)]  pub const CREATOR_OWNER_RIGHTS  = sid!(S-1-3-4);
 }
 
 /// `S-1-5-*` aka `NT AUTHORITY\*`
@@ -83,27 +95,33 @@ well_known_sids! {
 pub mod nt_authority { well_known_sids! {
     // C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
     // "NT well-known SIDs"
-    #[desc = "DIALUP"]                          #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_DIALUP_RID)]                         pub const DIALUP                    = sid!(S-1-5-1);
-    #[desc = "NETWORK"]                         #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_NETWORK_RID)]                        pub const NETWORK                   = sid!(S-1-5-2);
-    #[desc = "BATCH"]                           #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BATCH_RID)]                          pub const BATCH                     = sid!(S-1-5-3);
-    #[desc = "INTERACTIVE"]                     #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_INTERACTIVE_RID)]                    pub const INTERACTIVE               = sid!(S-1-5-4);
-    //#[desc = "NETWORK SERVICE"]               #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_LOGON_IDS_RID)]                      // Logon IDs                        = sid!(S-1-5-5-x-y); // aka LogonSessionId_x_yyyyyyyyy
-    #[desc = "SERVICE"]                         #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_SERVICE_RID)]                        pub const SERVICE                   = sid!(S-1-5-6);
-    #[desc = "ANONYMOUS LOGON"]                 #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_ANONYMOUS_LOGON_RID)]                pub const ANONYMOUS_LOGON           = sid!(S-1-5-7);
-    #[desc = "PROXY"]                           #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_PROXY_RID)]                          pub const PROXY                     = sid!(S-1-5-8);
-    #[desc = "ENTERPRISE DOMAIN CONTROLLERS"]   #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_ENTERPRISE_CONTROLLERS_RID)]         pub const ENTERPRISE_CONTROLLERS    = sid!(S-1-5-9);
-    #[desc = "ENTERPRISE DOMAIN CONTROLLERS"]   #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_SERVER_LOGON_RID)]                   pub const SERVER_LOGON              = sid!(S-1-5-9); // n.b. same value as SECURITY_ENTERPRISE_CONTROLLERS_RID
-    #[desc = "SELF"]                            #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_PRINCIPAL_SELF_RID)]                 pub const PRINCIPAL_SELF            = sid!(S-1-5-10);
-    #[desc = "Authenticated Users"]             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_AUTHENTICATED_USER_RID)]             pub const AUTHENTICATED_USER        = sid!(S-1-5-11);
-    #[desc = "RESTRICTED"]                      #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_RESTRICTED_CODE_RID)]                pub const RESTRICTED_CODE           = sid!(S-1-5-12);
-    #[desc = "TERMINAL SERVER USER"]            #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_TERMINAL_SERVER_RID)]                pub const TERMINAL_SERVER           = sid!(S-1-5-13);
-    #[desc = "REMOTE INTERACTIVE LOGON"]        #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_REMOTE_LOGON_RID)]                   pub const REMOTE_LOGON              = sid!(S-1-5-14);
-    #[desc = "This Organization"]               #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_THIS_ORGANIZATION_RID)]              pub const THIS_ORGANIZATION         = sid!(S-1-5-15);
-
-    #[desc = "IUSR"]                            #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_IUSER_RID)]                          pub const IUSER                     = sid!(S-1-5-17);
-    #[desc = "SYSTEM"]                          #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_LOCAL_SYSTEM_RID)]                   pub const LOCAL_SYSTEM              = sid!(S-1-5-18);
-    #[desc = "LOCAL SERVICE"]                   #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_LOCAL_SERVICE_RID)]                  pub const LOCAL_SERVICE             = sid!(S-1-5-19);
-    #[desc = "NETWORK SERVICE"]                 #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_NETWORK_SERVICE_RID)]                pub const NETWORK_SERVICE           = sid!(S-1-5-20);
+    #[desc = "DIALUP"]                                                                          #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_DIALUP_RID)]                         pub const DIALUP                    = sid!(S-1-5-1);
+    #[desc = "NETWORK"]                         #[sddl = SDDL_NETWORK]                          #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_NETWORK_RID)]                        pub const NETWORK                   = sid!(S-1-5-2);
+    #[desc = "BATCH"]                                                                           #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BATCH_RID)]                          pub const BATCH                     = sid!(S-1-5-3);
+    #[desc = "INTERACTIVE"]                     #[sddl = SDDL_INTERACTIVE]                      #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_INTERACTIVE_RID)]                    pub const INTERACTIVE               = sid!(S-1-5-4);
+    //#[desc = "NETWORK SERVICE"]                                                               #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_LOGON_IDS_RID)]                      // Logon IDs                        = sid!(S-1-5-5-x-y); // aka LogonSessionId_x_yyyyyyyyy
+    #[desc = "SERVICE"]                         #[sddl = SDDL_SERVICE]                          #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_SERVICE_RID)]                        pub const SERVICE                   = sid!(S-1-5-6);
+    #[desc = "ANONYMOUS LOGON"]                 #[sddl = SDDL_ANONYMOUS]                        #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_ANONYMOUS_LOGON_RID)]                pub const ANONYMOUS_LOGON           = sid!(S-1-5-7);
+    #[desc = "PROXY"]                                                                           #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_PROXY_RID)]                          pub const PROXY                     = sid!(S-1-5-8);
+    #[desc = "ENTERPRISE DOMAIN CONTROLLERS"]   #[sddl = SDDL_ENTERPRISE_DOMAIN_CONTROLLERS]    #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_ENTERPRISE_CONTROLLERS_RID)]         pub const ENTERPRISE_CONTROLLERS    = sid!(S-1-5-9);
+    #[desc = "ENTERPRISE DOMAIN CONTROLLERS"]                                                   #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_SERVER_LOGON_RID)]                   pub const SERVER_LOGON              = sid!(S-1-5-9); // n.b. same value as SECURITY_ENTERPRISE_CONTROLLERS_RID
+    #[desc = "SELF"]                            #[sddl = SDDL_PERSONAL_SELF]                    #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_PRINCIPAL_SELF_RID)]                 pub const PRINCIPAL_SELF            = sid!(S-1-5-10);
+    #[desc = "Authenticated Users"]             #[sddl = SDDL_AUTHENTICATED_USERS]              #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_AUTHENTICATED_USER_RID)]             pub const AUTHENTICATED_USER        = sid!(S-1-5-11);
+    #[desc = "RESTRICTED"]                      #[sddl = SDDL_RESTRICTED_CODE]                  #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_RESTRICTED_CODE_RID)]                pub const RESTRICTED_CODE           = sid!(S-1-5-12);
+    #[desc = "TERMINAL SERVER USER"]                                                            #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_TERMINAL_SERVER_RID)]                pub const TERMINAL_SERVER           = sid!(S-1-5-13);
+    #[desc = "REMOTE INTERACTIVE LOGON"]                                                        #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_REMOTE_LOGON_RID)]                   pub const REMOTE_LOGON              = sid!(S-1-5-14);
+    #[desc = "This Organization"]                                                               #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_THIS_ORGANIZATION_RID)]              pub const THIS_ORGANIZATION         = sid!(S-1-5-15);
+
+    #[desc = "IUSR"]                                                                            #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_IUSER_RID)]                          pub const IUSER                     = sid!(S-1-5-17);
+    #[desc = "SYSTEM"]                          #[sddl = SDDL_LOCAL_SYSTEM]                     #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_LOCAL_SYSTEM_RID)]                   pub const LOCAL_SYSTEM              = sid!(S-1-5-18);
+    #[desc = "LOCAL SERVICE"]                   #[sddl = SDDL_LOCAL_SERVICE]                    #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_LOCAL_SERVICE_RID)]                  pub const LOCAL_SERVICE             = sid!(S-1-5-19);
+    #[desc = "NETWORK SERVICE"]                 #[sddl = SDDL_NETWORK_SERVICE]                  #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_NETWORK_SERVICE_RID)]                pub const NETWORK_SERVICE           = sid!(S-1-5-20);
+
+    #[desc = "WRITE RESTRICTED"]                #[sddl = SDDL_WRITE_RESTRICTED_CODE]            #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_WRITE_RESTRICTED_CODE_RID)]          pub const WRITE_RESTRICTED_CODE     = sid!(S-1-5-33);
+    #[desc = "USER MODE DRIVERS"]
+    #[sddl = SDDL_USER_MODE_DRIVERS]
+    #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_USERMODEDRIVERHOST_ID_BASE_RID, ZERO, ZERO, ZERO, ZERO, ZERO)]
+    pub const USER_MODE_DRIVERS = sid!(S-1-5-84-0-0-0-0-0);
 
     //  const local users and groups        = sid!(S-1-5-21-x-y);       // SECURITY_NT_AUTHORITY    SECURITY_NT_NON_UNIQUE  x y     includes local named accounts, groups, like "MaulingMonkey" and "docker-users"
     //  const NtNonUnique                   = sid!(S-1-5-0x15-x-y);
@@ -135,7 +153,7 @@ pub mod builtin {
         }
 
         // missing from winapi, sourced from C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
-        #[cfg(test)] const DOMAIN_USER_RID_WDAG_ACCOUNT : u32 = 0x000001F8;
+        #[cfg(all(test, windows))] const DOMAIN_USER_RID_WDAG_ACCOUNT : u32 = 0x000001F8;
     }
 
     pub mod group {
@@ -163,52 +181,67 @@ pub mod builtin {
         //! [ADMINS], [USERS], [GUESTS], ...
         well_known_sids! {
             #[desc = r"BUILTIN\Administrators"]
+            #[sddl = SDDL_BUILTIN_ADMINISTRATORS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_ADMINS)]
             pub const ADMINS = sid!(S-1-5-32-0x220);
 
             #[desc = r"BUILTIN\Users"]
+            #[sddl = SDDL_BUILTIN_USERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_USERS)]
             pub const USERS = sid!(S-1-5-0x20-0x221);
 
             #[desc = r"BUILTIN\Guests"]
+            #[sddl = SDDL_BUILTIN_GUESTS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_GUESTS)]
             pub const GUESTS = sid!(S-1-5-0x20-0x222);
 
             #[desc = r"BUILTIN\Power Users"]
+            #[sddl = SDDL_POWER_USERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_POWER_USERS)]
             pub const POWER_USERS = sid!(S-1-5-0x20-0x223);
 
+            #[desc = r"BUILTIN\Account Operators"]
+            #[sddl = SDDL_ACCOUNT_OPERATORS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_ACCOUNT_OPS)]
             pub const ACCOUNT_OPS = sid!(S-1-5-0x20-0x224);
 
+            #[desc = r"BUILTIN\Server Operators"]
+            #[sddl = SDDL_SERVER_OPERATORS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_SYSTEM_OPS)]
             pub const SYSTEM_OPS = sid!(S-1-5-0x20-0x225);
 
             #[desc = r"BUILTIN\Print Operators"]
+            #[sddl = SDDL_PRINTER_OPERATORS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_PRINT_OPS)]
             pub const PRINT_OPS = sid!(S-1-5-0x20-0x226);
 
             #[desc = r"BUILTIN\Backup Operators"]
+            #[sddl = SDDL_BACKUP_OPERATORS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_BACKUP_OPS)]
             pub const BACKUP_OPS = sid!(S-1-5-0x20-0x227);
 
 
 
             #[desc = r"BUILTIN\Replicator"]
+            #[sddl = SDDL_REPLICATOR]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_REPLICATOR)]
             pub const REPLICATOR = sid!(S-1-5-0x20-0x228);
 
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_RAS_SERVERS)]
             pub const RAS_SERVERS = sid!(S-1-5-0x20-0x229);
 
+            #[desc = r"BUILTIN\Pre-Windows 2000 Compatible Access"]
+            #[sddl = SDDL_ALIAS_PREW2KCOMPACC]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_PREW2KCOMPACCESS)]
             pub const PREW2KCOMPACCESS = sid!(S-1-5-0x20-0x22A);
 
             #[desc = r"BUILTIN\Remote Desktop Users"]
+            #[sddl = SDDL_REMOTE_DESKTOP]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_REMOTE_DESKTOP_USERS)]
             pub const REMOTE_DESKTOP_USERS = sid!(S-1-5-0x20-0x22B);
 
             #[desc = r"BUILTIN\Network Configuration Operators"]
+            #[sddl = SDDL_NETWORK_CONFIGURATION_OPS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_NETWORK_CONFIGURATION_OPS)]
             pub const NETWORK_CONFIGURATION_OPS = sid!(S-1-5-0x20-0x22C);
 
@@ -218,10 +251,12 @@ pub mod builtin {
 
 
             #[desc = r"BUILTIN\Performance Monitor Users"]
+            #[sddl = SDDL_PERFMON_USERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_MONITORING_USERS)]
             pub const MONITORING_USERS = sid!(S-1-5-0x20-0x22E);
 
             #[desc = r"BUILTIN\Performance Log Users"]
+            #[sddl = SDDL_PERFLOG_USERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_LOGGING_USERS)]
             pub const LOGGING_USERS = sid!(S-1-5-0x20-0x22F);
 
@@ -236,10 +271,12 @@ pub mod builtin {
             pub const DCOM_USERS = sid!(S-1-5-0x20-0x232);
 
             #[desc = r"BUILTIN\IIS_IUSRS"]
+            #[sddl = SDDL_IIS_USERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_IUSERS)]
             pub const IUSERS = sid!(S-1-5-0x20-0x238);
 
             #[desc = r"BUILTIN\Cryptographic Operators"]
+            #[sddl = SDDL_CRYPTO_OPERATORS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_CRYPTO_OPERATORS)]
             pub const CRYPTO_OPERATORS = sid!(S-1-5-0x20-0x239);
 
@@ -250,34 +287,42 @@ pub mod builtin {
             pub const NON_CACHEABLE_PRINCIPALS_GROUP = sid!(S-1-5-0x20-0x23C);
 
             #[desc = r"BUILTIN\Event Log Readers"]
+            #[sddl = SDDL_EVENT_LOG_READERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_EVENT_LOG_READERS_GROUP)]
             pub const EVENT_LOG_READERS_GROUP = sid!(S-1-5-0x20-0x23D);
 
             #[desc = r"BUILTIN\Certificate Service DCOM Access"]
+            #[sddl = SDDL_CERTSVC_DCOM_ACCESS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_CERTSVC_DCOM_ACCESS_GROUP)]
             pub const CERTSVC_DCOM_ACCESS_GROUP = sid!(S-1-5-0x20-0x23E);
 
             #[desc = r"BUILTIN\RDS Remote Access Servers"]
+            #[sddl = SDDL_RDS_REMOTE_ACCESS_SERVERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_RDS_REMOTE_ACCESS_SERVERS)]
             pub const RDS_REMOTE_ACCESS_SERVERS = sid!(S-1-5-0x20-0x23F);
 
             #[desc = r"BUILTIN\RDS Endpoint Servers"]
+            #[sddl = SDDL_RDS_ENDPOINT_SERVERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_RDS_ENDPOINT_SERVERS)]
             pub const RDS_ENDPOINT_SERVERS = sid!(S-1-5-0x20-0x240);
 
             #[desc = r"BUILTIN\RDS Management Servers"]
+            #[sddl = SDDL_RDS_MANAGEMENT_SERVERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_RDS_MANAGEMENT_SERVERS)]
             pub const RDS_MANAGEMENT_SERVERS = sid!(S-1-5-0x20-0x241);
 
             #[desc = r"BUILTIN\Hyper-V Administrators"]
+            #[sddl = SDDL_HYPER_V_ADMINS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_HYPER_V_ADMINS)]
             pub const HYPER_V_ADMINS = sid!(S-1-5-0x20-0x242);
 
             #[desc = r"BUILTIN\Access Control Assistance Operators"]
+            #[sddl = SDDL_ACCESS_CONTROL_ASSISTANCE_OPS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_ACCESS_CONTROL_ASSISTANCE_OPS)]
             pub const ACCESS_CONTROL_ASSISTANCE_OPS = sid!(S-1-5-0x20-0x243);
 
             #[desc = r"BUILTIN\Remote Management Users"]
+            #[sddl = SDDL_REMOTE_MANAGEMENT_USERS]
             #[values(SID_REVISION, SECURITY_NT_AUTHORITY, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_REMOTE_MANAGEMENT_USERS)]
             pub const REMOTE_MANAGEMENT_USERS = sid!(S-1-5-0x20-0x244);
 
@@ -298,6 +343,63 @@ pub mod builtin {
         }
 
         // missing from winapi, sourced from C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
-        #[cfg(test)] const DOMAIN_ALIAS_RID_DEVICE_OWNERS : u32 = 0x00000247;
+        #[cfg(all(test, windows))] const DOMAIN_ALIAS_RID_DEVICE_OWNERS : u32 = 0x00000247;
     }
 }
+
+/// `S-1-15-2-*` aka `APPLICATION PACKAGE AUTHORITY\*`
+///
+/// |  C    | Desc |
+/// | ----- | ---- |
+/// | 1     | [`SID_REVISION`]
+/// | 15    | [`SECURITY_APP_PACKAGE_AUTHORITY`]
+/// | 2     | [`SECURITY_APP_PACKAGE_BASE_RID`]
+/// | \*    | SECURITY_BUILTIN_PACKAGE_\*
+pub mod app_package_authority { well_known_sids! {
+    #[desc = r"APPLICATION PACKAGE AUTHORITY\ALL APPLICATION PACKAGES"]
+    #[sddl = SDDL_ALL_APP_PACKAGES]
+    #[values(SID_REVISION, SECURITY_APP_PACKAGE_AUTHORITY, SECURITY_APP_PACKAGE_BASE_RID, SECURITY_BUILTIN_PACKAGE_ANY_PACKAGE)]
+    pub const ALL_APP_PACKAGES = sid!(S-1-15-2-1);
+
+    #[desc = r"APPLICATION PACKAGE AUTHORITY\ALL RESTRICTED APPLICATION PACKAGES"]
+    #[values(SID_REVISION, SECURITY_APP_PACKAGE_AUTHORITY, SECURITY_APP_PACKAGE_BASE_RID, SECURITY_BUILTIN_PACKAGE_ANY_RESTRICTED_PACKAGE)]
+    pub const ALL_RESTRICTED_APP_PACKAGES = sid!(S-1-15-2-2);
+}}
+
+/// `S-1-16-*` aka `Mandatory Label\*`
+///
+/// See also [`sid::integrity`](crate::sid::integrity).
+///
+/// |  C    | Desc |
+/// | ----- | ---- |
+/// | 1     | [`SID_REVISION`]
+/// | 16    | [`SECURITY_MANDATORY_LABEL_AUTHORITY`]
+/// | \*    | SECURITY_MANDATORY_\*_RID
+pub mod mandatory_label {
+    well_known_sids! {
+        #[desc = r"Mandatory Label\Untrusted Mandatory Level"]                                          #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_UNTRUSTED_RID)]            pub const UNTRUSTED         = sid!(S-1-16-0);
+        #[desc = r"Mandatory Label\Low Mandatory Level"]                #[sddl = SDDL_ML_LOW]           #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_LOW_RID)]                  pub const LOW               = sid!(S-1-16-4096);
+        #[desc = r"Mandatory Label\Medium Mandatory Level"]             #[sddl = SDDL_ML_MEDIUM]        #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_MEDIUM_RID)]               pub const MEDIUM            = sid!(S-1-16-8192);
+        #[desc = r"Mandatory Label\Medium Plus Mandatory Level"]        #[sddl = SDDL_ML_MEDIUM_PLUS]   #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_MEDIUM_PLUS_RID)]          pub const MEDIUM_PLUS       = sid!(S-1-16-8448);
+        #[desc = r"Mandatory Label\High Mandatory Level"]               #[sddl = SDDL_ML_HIGH]          #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_HIGH_RID)]                 pub const HIGH              = sid!(S-1-16-12288);
+        #[desc = r"Mandatory Label\System Mandatory Level"]             #[sddl = SDDL_ML_SYSTEM]        #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_SYSTEM_RID)]               pub const SYSTEM            = sid!(S-1-16-16384);
+        #[desc = r"Mandatory Label\Protected Process Mandatory Level"]                                  #[values(SID_REVISION, SECURITY_MANDATORY_LABEL_AUTHORITY, SECURITY_MANDATORY_PROTECTED_PROCESS_RID)]    pub const PROTECTED_PROCESS = sid!(S-1-16-20480);
+    }
+
+    // winapi 0.3.9 has `SECURITY_MANDATORY_MEDIUM_RID + 0x10`, winnt.h has `+ 0x100`
+    #[cfg(all(test, windows))] const SECURITY_MANDATORY_MEDIUM_PLUS_RID : u32 = 0x00002100;
+    // missing from winapi, sourced from C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h
+    #[cfg(all(test, windows))] const SECURITY_MANDATORY_PROTECTED_PROCESS_RID : u32 = 0x00005000;
+}
+
+/// `S-1-18-*` aka authentication authority asserted identities
+///
+/// |  C    | Desc |
+/// | ----- | ---- |
+/// | 1     | [`SID_REVISION`]
+/// | 18    | [`SECURITY_AUTHENTICATION_AUTHORITY`]
+/// | \*    | SECURITY_AUTHENTICATION_\*_RID
+pub mod authentication_authority { well_known_sids! {
+    #[desc = "Authentication authority asserted identity"]  #[sddl = SDDL_AUTHORITY_ASSERTED]  #[values(SID_REVISION, SECURITY_AUTHENTICATION_AUTHORITY, SECURITY_AUTHENTICATION_AUTHORITY_ASSERTED_RID)]   pub const AUTHORITY_ASSERTED    = sid!(S-1-18-1);
+    #[desc = "Service asserted identity"]                   #[sddl = SDDL_SERVICE_ASSERTED]    #[values(SID_REVISION, SECURITY_AUTHENTICATION_AUTHORITY, SECURITY_AUTHENTICATION_SERVICE_ASSERTED_RID)]     pub const SERVICE_ASSERTED      = sid!(S-1-18-2);
+}}
@@ -59,3 +59,9 @@ impl Level {
 #[doc(alias = "SECURITY_MANDATORY_HIGH_RID"                 )] #[doc = "SECURITY_MANDATORY_HIGH_RID"                ] pub const High              : Level = Level::new(0x3000);
 #[doc(alias = "SECURITY_MANDATORY_SYSTEM_RID"               )] #[doc = "SECURITY_MANDATORY_SYSTEM_RID"              ] pub const System            : Level = Level::new(0x4000);
 #[doc(alias = "SECURITY_MANDATORY_PROTECTED_PROCESS_RID"    )] #[doc = "SECURITY_MANDATORY_PROTECTED_PROCESS_RID"   ] pub const ProtectedProcess  : Level = Level::new(0x5000);
+
+#[test] fn ordering() {
+    assert!(Untrusted < Low && Low < Medium && Medium < MediumPlus && MediumPlus < High && High < System && System < ProtectedProcess);
+    assert_eq!(Level::new(0x2000), Medium);
+    assert_eq!(0x1000, sid::Ptr::from(&Low).subauthorities()[0]);
+}
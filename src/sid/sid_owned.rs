@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+use winapi::um::winnt::SID_MAX_SUB_AUTHORITIES;
+
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+
+
+
+#[doc(alias = "SID")]
+/// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-sid)\]
+/// ≈ SID, stored inline (no allocation) with room for up to `SID_MAX_SUB_AUTHORITIES` (15) sub-authorities
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let sid = sid::Owned::new(1, 5, &[32, 544]).unwrap();
+/// assert_eq!(*sid.as_sid_ptr(), *sid!(S-1-5-32-544));
+/// assert_eq!(None, sid::Owned::new(1, 5, &[0; 16])); // too many sub-authorities
+/// ```
+///
+#[derive(Clone, Copy)]
+#[repr(C)] pub struct Owned {
+    revision:               u8,
+    sub_authority_count:    u8,
+    identifier_authority:   [u8; 6],
+    sub_authority:          [u32; SID_MAX_SUB_AUTHORITIES as usize],
+}
+
+impl Owned {
+    /// Create a SID from its parts.
+    ///
+    /// Returns [`None`] if `authority` doesn't fit in 48 bits, or if there are more than 15 `sub_authorities`.
+    pub const fn new(revision: u8, authority: u64, sub_authorities: &[u32]) -> Option<Self> {
+        if authority >> 48 != 0 || sub_authorities.len() > SID_MAX_SUB_AUTHORITIES as usize { return None }
+        let [_, _, a, b, c, d, e, f] = authority.to_be_bytes();
+        let mut sub_authority = [0; SID_MAX_SUB_AUTHORITIES as usize];
+        let mut i = 0;
+        while i < sub_authorities.len() { sub_authority[i] = sub_authorities[i]; i += 1; }
+        Some(Self { revision, sub_authority_count: sub_authorities.len() as u8, identifier_authority: [a, b, c, d, e, f], sub_authority })
+    }
+
+    /// Parse a SID from its binary form (the inverse of [`Owned::as_bytes`].)
+    ///
+    /// Returns [`None`] unless `bytes` is exactly one revision 1 SID with up to 15 sub-authorities.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&[revision, sub_authority_count, a, b, c, d, e, f], subs) = bytes.split_first_chunk::<8>()?;
+        if revision != 1 || subs.len() != 4 * usize::from(sub_authority_count) { return None }
+        let mut sub_authority = [0; SID_MAX_SUB_AUTHORITIES as usize];
+        if subs.len() > 4 * sub_authority.len() { return None }
+        for (dst, src) in sub_authority.iter_mut().zip(subs.chunks_exact(4)) { *dst = u32::from_le_bytes([src[0], src[1], src[2], src[3]]); }
+        Some(Self { revision, sub_authority_count, identifier_authority: [a, b, c, d, e, f], sub_authority })
+    }
+
+    pub const fn as_sid_ptr(&self) -> sid::Ptr<'_> { unsafe { sid::Ptr::from_raw_unchecked(self as *const _ as *mut _) } }
+
+    /// The SID (revision, sub-authority count, identifier authority, and sub-authorities) as bytes, excluding unused sub-authority slots.
+    pub fn as_bytes(&self) -> &[u8] { unsafe { core::slice::from_raw_parts((self as *const Self).cast::<u8>(), 8 + 4 * usize::from(self.sub_authority_count)) } }
+}
+
+impl<'s> From<&'s Owned> for sid::Ptr<'s> { fn from(sid: &'s Owned) -> Self { sid.as_sid_ptr() } }
+
+impl From<&sid::Value> for Owned {
+    /// ### Panics
+    /// If `sid` has more than 15 sub-authorities (which [`sid::Ptr::from_raw`] would've rejected.)
+    fn from(sid: &sid::Value) -> Self {
+        Self::new(sid.revision(), sid.authority_u64(), sid.subauthorities()).expect("sid::Value has more than SID_MAX_SUB_AUTHORITIES sub-authorities")
+    }
+}
+
+impl Debug      for Owned { fn fmt(&self, fmt: &mut Formatter) -> fmt::Result                     { Debug::fmt(&*self.as_sid_ptr(), fmt) } }
+impl PartialEq  for Owned { fn eq          (&self, other: &Self) -> bool                        { *self.as_sid_ptr() == *other.as_sid_ptr() } }
+impl Eq         for Owned {}
+impl PartialOrd for Owned { fn partial_cmp  (&self, other: &Self) -> Option<core::cmp::Ordering> { Some(self.cmp(other)) } }
+impl Ord        for Owned { fn cmp          (&self, other: &Self) -> core::cmp::Ordering         { (*self.as_sid_ptr()).cmp(&*other.as_sid_ptr()) } }
+impl Hash       for Owned { fn hash<H: Hasher>(&self, state: &mut H)                              { (*self.as_sid_ptr()).hash(state) } }
+
+impl PartialEq<sid::Value> for Owned { fn eq(&self, other: &sid::Value) -> bool { *self.as_sid_ptr() == *other } }
+impl PartialEq<Owned> for sid::Value { fn eq(&self, other: &Owned     ) -> bool { *self == *other.as_sid_ptr() } }
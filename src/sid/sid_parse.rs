@@ -0,0 +1,253 @@
+use crate::prelude::*;
+
+use winapi::um::winnt::{SID_MAX_SUB_AUTHORITIES, SID_REVISION};
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+
+
+
+impl FromStr for sid::Owned {
+    type Err = ParseError;
+
+    /// Parse a `S-R-I-S...` string, without calling `ConvertStringSidToSid`.
+    ///
+    /// *   The `S-` prefix is case insensitive.
+    /// *   The revision must be `1`.
+    /// *   The identifier authority and sub-authorities may be decimal or `0x` prefixed hexadecimal.
+    /// *   The identifier authority must fit in 48 bits, sub-authorities in 32 bits.
+    /// *   There may be at most 15 sub-authorities.
+    ///
+    /// Two letter SDDL aliases like `BA` are *not* accepted here, see [`sid::parse_alias`].
+    ///
+    /// ### Examples
+    /// ```
+    /// # use firehazard::*;
+    /// let admins : sid::Owned = "S-1-5-32-544".parse().unwrap();
+    /// assert_eq!(admins, *sid!(S-1-5-32-544));
+    /// assert_eq!(admins, "s-1-0x5-0x20-0x220".parse::<sid::Owned>().unwrap());
+    /// assert_eq!(admins.to_string(), "S-1-5-32-544");
+    ///
+    /// let err = "S-1-5-32-x".parse::<sid::Owned>().unwrap_err();
+    /// assert_eq!(err.component(), sid::ParseComponent::SubAuthority(1));
+    /// assert_eq!(err.kind(), sid::ParseErrorKind::InvalidDigit);
+    /// assert_eq!(err.offset(), 9);
+    /// ```
+    ///
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let b = s.as_bytes();
+        if !(b.len() >= 2 && b[0].eq_ignore_ascii_case(&b'S') && b[1] == b'-') {
+            return Err(ParseError { offset: 0, component: ParseComponent::Prefix, kind: ParseErrorKind::MissingPrefix });
+        }
+
+        let mut parts = Parts { s, offset: 2 };
+
+        let (offset, revision) = parts.next();
+        let revision = parse_int(revision, 0xFF).map_err(|kind| ParseError { offset, component: ParseComponent::Revision, kind })?;
+        if revision != u64::from(SID_REVISION) { return Err(ParseError { offset, component: ParseComponent::Revision, kind: ParseErrorKind::UnsupportedRevision }) }
+
+        let (offset, authority) = parts.next();
+        let authority = parse_int(authority, 0xFFFF_FFFF_FFFF).map_err(|kind| ParseError { offset, component: ParseComponent::Authority, kind })?;
+
+        let mut sub_authorities = [0u32; SID_MAX_SUB_AUTHORITIES as usize];
+        let mut count = 0;
+        while !parts.is_done() {
+            let (offset, sub_authority) = parts.next();
+            let component = ParseComponent::SubAuthority(count);
+            if count == sub_authorities.len() { return Err(ParseError { offset, component, kind: ParseErrorKind::TooManySubAuthorities }) }
+            sub_authorities[count] = parse_int(sub_authority, 0xFFFF_FFFF).map_err(|kind| ParseError { offset, component, kind })? as u32;
+            count += 1;
+        }
+
+        Ok(sid::Owned::new(revision as u8, authority, &sub_authorities[..count]).expect("authority and sub-authority count already validated"))
+    }
+}
+
+/// `-` separated components, tracking byte offsets for errors
+struct Parts<'s> {
+    s:      &'s str,
+    offset: usize,
+}
+
+impl<'s> Parts<'s> {
+    fn is_done(&self) -> bool { self.offset > self.s.len() }
+
+    /// The next component and its offset.  Past the end of the string, returns an empty component.
+    fn next(&mut self) -> (usize, &'s str) {
+        let start = self.offset.min(self.s.len());
+        let rest = &self.s[start..];
+        let len = rest.find('-').unwrap_or(rest.len());
+        self.offset = start + len + 1;
+        (start, &rest[..len])
+    }
+}
+
+/// Decimal or `0x` prefixed hexadecimal, no greater than `max`
+fn parse_int(s: &str, max: u64) -> Result<u64, ParseErrorKind> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex)   => (hex, 16),
+        None        => (s, 10),
+    };
+    if digits.is_empty() { return Err(ParseErrorKind::Empty) }
+    let mut value = 0u64;
+    for ch in digits.chars() {
+        let digit = ch.to_digit(radix).ok_or(ParseErrorKind::InvalidDigit)?;
+        value = value.checked_mul(radix.into()).and_then(|v| v.checked_add(digit.into())).filter(|v| *v <= max).ok_or(ParseErrorKind::Overflow)?;
+    }
+    Ok(value)
+}
+
+
+
+/// Error parsing a `S-R-I-S...` SID string, as returned by [`sid::Owned::from_str`]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    offset:     usize,
+    component:  ParseComponent,
+    kind:       ParseErrorKind,
+}
+
+impl ParseError {
+    /// Byte offset into the SID string of the offending component.
+    pub fn offset(&self) -> usize { self.offset }
+
+    pub fn component(&self) -> ParseComponent { self.component }
+
+    pub fn kind(&self) -> ParseErrorKind { self.kind }
+}
+
+/// Which part of a `S-R-I-S...` SID string a [`ParseError`] refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseComponent {
+    /// The leading `S-`
+    Prefix,
+    /// `R`
+    Revision,
+    /// `I`
+    Authority,
+    /// The Nth (0-based) `S`
+    SubAuthority(usize),
+}
+
+/// What went wrong parsing a `S-R-I-S...` SID string
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum ParseErrorKind {
+    /// The string doesn't start with `S-`.
+    MissingPrefix,
+    /// The component is empty (e.g. `S-1-`, `S-1-5--1`, or `S-1-0x`.)
+    Empty,
+    /// The component contains a character that isn't a decimal digit (or hexadecimal digit after `0x`.)
+    InvalidDigit,
+    /// The component is too large (more than 48 bits for the identifier authority, 32 bits for sub-authorities.)
+    Overflow,
+    /// The revision isn't `1`.
+    UnsupportedRevision,
+    /// There are more than 15 sub-authorities.
+    TooManySubAuthorities,
+}
+
+impl From<ParseError> for firehazard::Error {
+    fn from(_: ParseError) -> Self { Self(ERROR_INVALID_SID) }
+}
+
+#[cfg(std)] impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidInput, err) }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Debug for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "sid::ParseError {{ offset: {}, component: {:?}, kind: {:?} }}", self.offset, self.component, self.kind)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ParseErrorKind::MissingPrefix           => "expected \"S-\"",
+            ParseErrorKind::Empty                   => "empty",
+            ParseErrorKind::InvalidDigit            => "invalid digit",
+            ParseErrorKind::Overflow                => "out of range",
+            ParseErrorKind::UnsupportedRevision     => "unsupported revision (expected 1)",
+            ParseErrorKind::TooManySubAuthorities   => "more than 15 sub-authorities",
+        };
+        let offset = self.offset;
+        match self.component {
+            ParseComponent::Prefix          => write!(fmt, "SID string at offset {offset}: {msg}"),
+            ParseComponent::Revision        => write!(fmt, "SID revision at offset {offset}: {msg}"),
+            ParseComponent::Authority       => write!(fmt, "SID identifier authority at offset {offset}: {msg}"),
+            ParseComponent::SubAuthority(n) => write!(fmt, "SID sub-authority {n} at offset {offset}: {msg}"),
+        }
+    }
+}
+
+
+
+impl Display for sid::Value {
+    /// `S-R-I-S...`, matching `ConvertSidToStringSid`: the identifier authority is decimal if it fits in 32 bits, `0x` prefixed hexadecimal otherwise.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if self.as_ptr_sid().is_null() { return write!(fmt, "NULL") }
+        let authority = self.authority_u64();
+        if authority >> 32 == 0 {
+            write!(fmt, "S-{}-{authority}", self.revision())?;
+        } else {
+            write!(fmt, "S-{}-0x{authority:012x}", self.revision())?;
+        }
+        for sa in self.subauthorities() { write!(fmt, "-{sa}")?; }
+        Ok(())
+    }
+}
+
+impl Display for sid::Owned {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(&*self.as_sid_ptr(), fmt) }
+}
+
+
+
+#[cfg(std)] #[test] fn round_trip() {
+    use std::string::ToString;
+
+    for (input, canonical) in [
+        ("S-1-0-0",                                             "S-1-0-0"),
+        ("S-1-5-32-544",                                        "S-1-5-32-544"),
+        ("s-1-0x5-0x20-0X220",                                  "S-1-5-32-544"),
+        ("S-1-5",                                               "S-1-5"),
+        ("S-1-4294967295-4294967295",                           "S-1-4294967295-4294967295"),
+        ("S-1-4294967296",                                      "S-1-0x000100000000"),
+        ("S-1-0xFFFFFFFFFFFF",                                  "S-1-0xffffffffffff"),
+        ("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15",           "S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15"),
+    ] {
+        let sid = input.parse::<sid::Owned>().unwrap_or_else(|err| panic!("{input:?}: {err}"));
+        assert_eq!(canonical, sid.to_string(), "{input:?}");
+        assert_eq!(sid, canonical.parse::<sid::Owned>().unwrap());
+    }
+}
+
+#[cfg(std)] #[test] fn errors() {
+    use ParseComponent::*;
+    use ParseErrorKind::*;
+
+    for (input, offset, component, kind) in [
+        ("",                                                    0, Prefix,              MissingPrefix),
+        ("1-5-32",                                              0, Prefix,              MissingPrefix),
+        ("S-",                                                  2, Revision,            Empty),
+        ("S-2-5",                                               2, Revision,            UnsupportedRevision),
+        ("S-256-5",                                             2, Revision,            Overflow),
+        ("S-1",                                                 3, Authority,           Empty),
+        ("S-1-",                                                4, Authority,           Empty),
+        ("S-1-0x",                                              4, Authority,           Empty),
+        ("S-1-0x1000000000000",                                 4, Authority,           Overflow),
+        ("S-1-281474976710656",                                 4, Authority,           Overflow),
+        ("S-1-5-",                                              6, SubAuthority(0),     Empty),
+        ("S-1-5--1",                                            6, SubAuthority(0),     Empty),
+        ("S-1-5-32-x",                                          9, SubAuthority(1),     InvalidDigit),
+        ("S-1-5-32-0x22G",                                      9, SubAuthority(1),     InvalidDigit),
+        ("S-1-5-32-544 ",                                       9, SubAuthority(1),     InvalidDigit),
+        ("S-1-5-4294967296",                                    6, SubAuthority(0),     Overflow),
+        ("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15-16",        42, SubAuthority(15),   TooManySubAuthorities),
+    ] {
+        let err = input.parse::<sid::Owned>().unwrap_err();
+        assert_eq!((offset, component, kind), (err.offset(), err.component(), err.kind()), "{input:?}");
+    }
+}
@@ -1,9 +1,8 @@
 use crate::prelude::*;
 
-use winapi::shared::ntstatus::STATUS_SUCCESS;
-use winapi::um::lsalookup::{LSA_OBJECT_ATTRIBUTES, LSA_REFERENCED_DOMAIN_LIST, LSA_TRANSLATED_NAME};
-use winapi::um::ntlsa::*;
-use winapi::um::securitybaseapi::EqualSid;
+#[cfg(all(std, windows))] use winapi::shared::ntstatus::STATUS_SUCCESS;
+#[cfg(all(std, windows))] use winapi::um::lsalookup::{LSA_OBJECT_ATTRIBUTES, LSA_REFERENCED_DOMAIN_LIST, LSA_TRANSLATED_NAME};
+#[cfg(all(std, windows))] use winapi::um::ntlsa::*;
 use winapi::um::winnt::*;
 
 use core::fmt::{self, Debug, Formatter};
@@ -41,13 +40,20 @@ impl Value {
     /// SID::SubAuthority
     pub fn subauthorities(&self) -> &[u32]  { unsafe{core::slice::from_raw_parts(core::ptr::addr_of!((*self.0).SubAuthority) as *const u32, (*self.0).SubAuthorityCount.into())} }
 
+    /// The entire SID (revision, sub-authority count, identifier authority, and sub-authorities) as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.0.is_null() { return &[] }
+        let len = 8 + 4 * self.subauthorities().len();
+        unsafe { core::slice::from_raw_parts(self.0.cast::<u8>(), len) }
+    }
+
     fn as_tuple(&self) -> (u8, [u8; 6], &[u32]) { (self.revision(), self.authority(), self.subauthorities()) }
 
     #[doc(alias = "LsaLookupSids2")]
     /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/ntsecapi/nf-ntsecapi-lsalookupsids2)\]
     /// LsaLookupSids2
     ///
-    #[cfg(std)] pub fn lsa_lookup_sids2(&self) -> firehazard::Result<std::string::String> {
+    #[cfg(all(std, windows))] pub fn lsa_lookup_sids2(&self) -> firehazard::Result<std::string::String> {
         if self.0.is_null() { return Err(Error(E_STRING_NOT_NULL_TERMINATED as _)) }
         // .cast() spam notes:
         // it appears PLSA_HANDLE points to void, not LSA_HANDLE, for whatever twisted reason.
@@ -113,10 +119,10 @@ impl Value {
         Ok(result)
     }
 
-    #[cfg(not(std))] fn lsa_lookup_sids2(&self) -> firehazard::Result<&'static str> { Err(Error(ERROR_CALL_NOT_IMPLEMENTED)) }
+    #[cfg(not(all(std, windows)))] fn lsa_lookup_sids2(&self) -> firehazard::Result<&'static str> { Err(Error(ERROR_CALL_NOT_IMPLEMENTED)) }
 }
 
-impl PartialEq  for Value { fn eq(&self, other: &Self) -> bool { 0 != unsafe { EqualSid(self.as_psid(), other.as_psid()) } } }
+impl PartialEq  for Value { fn eq          (&self, other: &Self) -> bool                        { self.as_tuple().eq            (&other.as_tuple()) } }
 impl Eq         for Value {}
 impl PartialOrd for Value { fn partial_cmp  (&self, other: &Self) -> Option<core::cmp::Ordering> { self.as_tuple().partial_cmp   (&other.as_tuple()) } }
 impl Ord        for Value { fn cmp          (&self, other: &Self) -> core::cmp::Ordering         { self.as_tuple().cmp           (&other.as_tuple()) } }
@@ -128,10 +134,13 @@ impl Debug for Value {
         write!(fmt, "S-{}-{}", self.revision(), self.authority_u64())?;
         for sa in self.subauthorities().iter().copied() { write!(fmt, "-{sa}")?; }
         if let Ok(lsa) = self.lsa_lookup_sids2() { write!(fmt, " {lsa:?}")?; }
+        else if let Some(name) = self.well_known_name() { write!(fmt, " {name:?}")?; }
+        else if let Some(capability) = capability::Constant::from_sid(self) { write!(fmt, " capability {:?}", capability.name())?; }
         Ok(())
     }
 }
 
 #[cfg(std)] #[test] fn debug_fmt() {
     assert_eq!("S-1-2-3-4-5-6-7", std::format!("{:?}", sid!(S-1-2-3-4-5-6-7)));
+    #[cfg(not(windows))] assert_eq!(r#"S-1-5-32-544 "BUILTIN\\Administrators""#, std::format!("{:?}", sid!(S-1-5-32-544)));
 }
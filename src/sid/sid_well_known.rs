@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+
+
+/// A well known SID, with its display name(s) and [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/sid-strings) alias
+///
+/// This is a static table independent of `LsaLookupSids2`, usable without `std` and on non-windows targets.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// let admins = sid::WellKnown::from_sddl("BA").unwrap();
+/// assert_eq!(*admins.sid, *sid::builtin::alias::ADMINS);
+/// assert_eq!(admins.name(), Some(r"BUILTIN\Administrators"));
+///
+/// assert_eq!(sid!(S-1-1-0).well_known_name(), Some("Everyone"));
+/// assert_eq!(sid::WellKnown::from_name("everyone").unwrap().sddl, Some("WD"));
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct WellKnown {
+    pub sid:    sid::Ptr<'static>,
+
+    /// Display names, as returned by `LsaLookupSids2`, preferred name first.
+    /// May be empty.
+    pub names:  &'static [&'static str],
+
+    /// Two letter SDDL alias (`WD`, `BA`, `AC`, ...), if the SID has a machine and domain independent one.
+    pub sddl:   Option<&'static str>,
+}
+
+const TABLES : &[&[WellKnown]] = &[
+    super::sid_constants::WELL_KNOWN,
+    sid::nt_authority::WELL_KNOWN,
+    sid::builtin::user::WELL_KNOWN,
+    sid::builtin::group::WELL_KNOWN,
+    sid::builtin::alias::WELL_KNOWN,
+    sid::app_package_authority::WELL_KNOWN,
+    sid::mandatory_label::WELL_KNOWN,
+    sid::authentication_authority::WELL_KNOWN,
+];
+
+impl WellKnown {
+    /// Every well known SID this crate knows about, in declaration order.
+    /// A few SIDs (e.g. [`sid::nt_authority::SERVER_LOGON`]) appear more than once under different names.
+    pub fn all() -> impl Iterator<Item = &'static WellKnown> { TABLES.iter().flat_map(|table| table.iter()) }
+
+    /// Look up a well known SID.  If the SID appears more than once, the first entry is returned.
+    pub fn from_sid(sid: &sid::Value) -> Option<&'static Self> { Self::all().find(|wk| *wk.sid == *sid) }
+
+    /// Look up a well known SID by display name (case insensitive.)
+    /// Names with a domain (`BUILTIN\Administrators`) also match without it (`Administrators`.)
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        Self::all().find(|wk| wk.names.iter().any(|n| n.eq_ignore_ascii_case(name) || n.rsplit_once('\\').is_some_and(|(_, n)| n.eq_ignore_ascii_case(name))))
+    }
+
+    /// Look up a well known SID by SDDL alias (case insensitive, like `ConvertStringSidToSid`.)
+    pub fn from_sddl(alias: &str) -> Option<&'static Self> { Self::all().find(|wk| wk.sddl.is_some_and(|a| a.eq_ignore_ascii_case(alias))) }
+
+    /// The preferred display name, if any.
+    pub fn name(&self) -> Option<&'static str> { self.names.first().copied() }
+}
+
+/// Parse a machine and domain independent two letter SDDL SID alias such as `WD`, `BA`, or `AC` (case insensitive.)
+///
+/// Domain relative aliases (`DA`, `DU`, `LA`, ...) return [`None`]: resolving them requires the domain or machine SID.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// assert_eq!(*sid::parse_alias("BA").unwrap(), *sid!(S-1-5-32-544));
+/// assert_eq!(*sid::parse_alias("ac").unwrap(), *sid!(S-1-15-2-1));
+/// assert!(sid::parse_alias("DA").is_none());
+/// ```
+///
+pub fn parse_alias(alias: &str) -> Option<sid::Ptr<'static>> { WellKnown::from_sddl(alias).map(|wk| wk.sid) }
+
+impl sid::Value {
+    /// The display name of this SID from the [well known SID table](sid::WellKnown), without calling `LsaLookupSids2`.
+    pub fn well_known_name(&self) -> Option<&'static str> { WellKnown::from_sid(self).and_then(|wk| wk.name()) }
+
+    /// The two letter SDDL alias of this SID (`WD`, `BA`, `AC`, ...), if it has a machine and domain independent one.
+    pub fn sddl_alias(&self) -> Option<&'static str> { WellKnown::all().filter(|wk| *wk.sid == *self).find_map(|wk| wk.sddl) }
+}
+
+
+
+#[cfg(std)] #[test] fn registry() {
+    use std::collections::BTreeSet;
+
+    let mut aliases = BTreeSet::new();
+    for wk in WellKnown::all() {
+        if let Some(alias) = wk.sddl {
+            assert!(aliases.insert(alias), "duplicate SDDL alias {alias:?}");
+            assert_eq!(*parse_alias(alias).unwrap(), *wk.sid);
+            assert_eq!(wk.sid.sddl_alias(), Some(alias));
+        }
+    }
+    assert_eq!(aliases.len(), 49);
+
+    for (alias, sid) in [
+        ("WD", sid!(S-1-1-0)),
+        ("sy", sid!(S-1-5-18)),
+        ("me", sid!(S-1-16-8192)),
+        ("AC", sid!(S-1-15-2-1)),
+        ("UD", sid!(S-1-5-84-0-0-0-0-0)),
+    ] {
+        assert_eq!(*parse_alias(alias).unwrap(), *sid, "{alias}");
+    }
+    assert!(parse_alias("DA").is_none());
+    assert!(parse_alias("").is_none());
+
+    assert_eq!(sid!(S-1-5-32-544).well_known_name(), Some(r"BUILTIN\Administrators"));
+    assert_eq!(sid!(S-1-16-12288).well_known_name(), Some(r"Mandatory Label\High Mandatory Level"));
+    assert_eq!(sid!(S-1-5-9).sddl_alias(), Some("ED"));
+    assert_eq!(sid!(S-1-5-21-1-2-3-500).well_known_name(), None);
+    assert_eq!(*WellKnown::from_name("administrators").unwrap().sid, *sid!(S-1-5-32-544));
+    assert!(WellKnown::from_name("BUILTIN").is_none());
+}
@@ -3,7 +3,7 @@
 
 use crate::prelude::*;
 
-include!(r"character_types.rs");
-include!(r"convert_to_cstrnn.rs");
-include!(r"in.rs");
-include!(r"out.rs");
+include!("character_types.rs");
+include!("convert_to_cstrnn.rs");
+include!("in.rs");
+include!("out.rs");
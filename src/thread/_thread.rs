@@ -6,20 +6,20 @@ use crate::prelude::*;
 pub use funcs::*;
 pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\exit_thread.rs");
-    include!(r"funcs\get_current_thread_id.rs");
-    include!(r"funcs\get_current_thread.rs");
-    include!(r"funcs\get_exit_code_thread.rs");
-    include!(r"funcs\get_thread_id.rs");
-    include!(r"funcs\is_thread_alive.rs");
-    include!(r"funcs\resume_thread.rs");
-    include!(r"funcs\suspend_thread.rs");
-    include!(r"funcs\wait_for_thread.rs");
+    include!("funcs/exit_thread.rs");
+    include!("funcs/get_current_thread_id.rs");
+    include!("funcs/get_current_thread.rs");
+    include!("funcs/get_exit_code_thread.rs");
+    include!("funcs/get_thread_id.rs");
+    include!("funcs/is_thread_alive.rs");
+    include!("funcs/resume_thread.rs");
+    include!("funcs/suspend_thread.rs");
+    include!("funcs/wait_for_thread.rs");
 }
 
 #[cfg(test)] mod tests {
     use crate::prelude::*;
-    include!(r"tests\test_wait_exit.rs");
+    include!("tests/test_wait_exit.rs");
 }
 
 mod thread_handles;                     pub use thread_handles::*;
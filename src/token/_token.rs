@@ -1,10 +1,13 @@
 //! \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/secauthz/access-tokens)\]
 //! Access token handle types and related functions
 
-mod token_handles;                      pub use token_handles::*;
+#[cfg(windows)] mod token_handles;                      #[cfg(windows)] pub use token_handles::*;
+#[cfg(alloc)] mod token_snapshot;                       #[cfg(alloc)] pub use token_snapshot::*;
+#[cfg(alloc)] mod token_snapshot_encoding;
+#[cfg(alloc)] mod token_snapshot_error;                 #[cfg(alloc)] pub use token_snapshot_error::*;
 
-pub use boxes::*;
-mod boxes {
+#[cfg(windows)] pub use boxes::*;
+#[cfg(windows)] mod boxes {
     mod boxes_util;                     use boxes_util::*;
     mod token_appcontainer_information; pub use token_appcontainer_information::*;
     mod token_default_dacl;             pub use token_default_dacl::*;
@@ -17,8 +20,8 @@ mod boxes {
     mod token_user;                     pub use token_user::*;
 }
 
-pub use values::*;
-mod values {
+#[cfg(windows)] pub use values::*;
+#[cfg(windows)] mod values {
     mod token_access_rights;            pub use token_access_rights::*;
     mod token_elevation_type;           pub use token_elevation_type::*;
     mod token_elevation;                pub use token_elevation::*;
@@ -28,17 +31,17 @@ mod values {
     mod token_type;                     pub use token_type::*;
 }
 
-pub use funcs::*;
-pub(crate) mod funcs {
+#[cfg(windows)] pub use funcs::*;
+#[cfg(windows)] pub(crate) mod funcs {
     use crate::prelude::*;
-    include!(r"funcs\create_restricted_token.rs");
-    include!(r"funcs\duplicate_token_ex.rs");
-    include!(r"funcs\get_current_x_token.rs");
+    include!("funcs/create_restricted_token.rs");
+    include!("funcs/duplicate_token_ex.rs");
+    include!("funcs/get_current_x_token.rs");
     pub mod get_token_information;
-    include!(r"funcs\is_token_restricted.rs");
-    include!(r"funcs\open_process_token.rs");
-    include!(r"funcs\open_thread_token.rs");
-    include!(r"funcs\revert_to_self.rs");
-    include!(r"funcs\set_thread_token.rs");
+    include!("funcs/is_token_restricted.rs");
+    include!("funcs/open_process_token.rs");
+    include!("funcs/open_thread_token.rs");
+    include!("funcs/revert_to_self.rs");
+    include!("funcs/set_thread_token.rs");
     pub mod set_token_information;
 }
@@ -0,0 +1,272 @@
+use crate::prelude::*;
+
+use alloc::vec::Vec;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// An owned, portable copy of (almost) every `GetTokenInformation` class of a token
+///
+/// *   [`Snapshot::collect`] queries a live token on windows.
+/// *   [`Snapshot::to_text`] / [`FromStr`](core::str::FromStr) and [`Snapshot::to_bytes`] / [`Snapshot::from_bytes`] provide stable text and binary encodings,
+///     which can be decoded on any platform (e.g. when analyzing a crash report from a sandboxed child.)
+/// *   [`Snapshot::diff`] lists every field that differs between two snapshots.
+///
+/// Every field is [`None`] if the class couldn't be queried (e.g. `TokenImpersonationLevel` of a primary token, or a class the OS doesn't support.)
+/// `TokenGroupsAndPrivileges` and `TokenAccessInformation` are not captured separately, as they only repeat other classes,
+/// and `TokenLinkedToken` is not captured, as it's a handle.
+///
+/// ### Text format
+/// A `token::Snapshot v1` header line, followed by one `field = value` line per available field, in `TOKEN_INFORMATION_CLASS` order.
+/// SIDs are `S-R-I-S...` strings, flags and LUIDs are `0x` hex, lists are comma separated, and ACLs and claims are hex encoded bytes.
+/// Unknown fields are ignored when parsing, so newer snapshots can be read by older decoders.
+///
+/// ```text
+/// token::Snapshot v1
+/// user = S-1-5-21-1-2-3-1001 0x0
+/// groups = S-1-1-0 0x7, S-1-5-32-545 0x7
+/// privileges = 0x17 0x3
+/// token_type = 1
+/// session_id = 1
+/// ```
+///
+/// ### Binary format
+/// `b"FHTS"`, a little endian `u16` version (`1`), then one record per available field:
+/// a little endian `u16` `TOKEN_INFORMATION_CLASS`, a `u32` payload length, and the payload.
+/// Unknown records are skipped when decoding.
+///
+/// ### Examples
+/// ```
+/// # use firehazard::*;
+/// # #[cfg(windows)] {
+/// let token = open_process_token(get_current_process(), token::QUERY | token::QUERY_SOURCE).unwrap();
+/// let snapshot = token::Snapshot::collect(&token).unwrap();
+/// assert!(snapshot.user.is_some());
+///
+/// let text = snapshot.to_text().unwrap();
+/// assert_eq!(snapshot, text.parse().unwrap());
+/// assert_eq!(snapshot, token::Snapshot::from_bytes(&snapshot.to_bytes().unwrap()).unwrap());
+/// # }
+///
+/// let crash_report = "token::Snapshot v1\nuser = S-1-5-21-1-2-3-1001 0x0\nsession_id = 2\n";
+/// let child : token::Snapshot = crash_report.parse().unwrap();
+/// let broker = token::Snapshot { session_id: Some(1), ..child.clone() };
+/// let diff = broker.diff(&child).map(|d| d.to_string()).collect::<Vec<_>>();
+/// assert_eq!(diff, ["session_id: 1 -> 2"]);
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    /// TokenUser
+    pub user:                       Option<SnapshotGroup>,
+    /// TokenGroups
+    pub groups:                     Option<Vec<SnapshotGroup>>,
+    /// TokenPrivileges
+    pub privileges:                 Option<Vec<SnapshotPrivilege>>,
+    /// TokenOwner
+    pub owner:                      Option<sid::Owned>,
+    /// TokenPrimaryGroup
+    pub primary_group:              Option<sid::Owned>,
+    /// TokenDefaultDacl: the raw `ACL` bytes, or empty if the token has no default DACL.
+    pub default_dacl:               Option<Vec<u8>>,
+    /// TokenSource: [`None`] if the token was opened without [`token::QUERY_SOURCE`] access.
+    pub source:                     Option<SnapshotSource>,
+    /// TokenType: `TokenPrimary` (1) or `TokenImpersonation` (2)
+    pub token_type:                 Option<u32>,
+    /// TokenImpersonationLevel: `SECURITY_IMPERSONATION_LEVEL`
+    pub impersonation_level:        Option<u32>,
+    /// TokenStatistics
+    pub statistics:                 Option<SnapshotStatistics>,
+    /// TokenRestrictedSids
+    pub restricted_sids:            Option<Vec<SnapshotGroup>>,
+    /// TokenSessionId
+    pub session_id:                 Option<u32>,
+    /// TokenSandBoxInert
+    pub sandbox_inert:              Option<bool>,
+    /// TokenOrigin: `OriginatingLogonSession`
+    pub origin:                     Option<u64>,
+    /// TokenElevationType: `TOKEN_ELEVATION_TYPE`
+    pub elevation_type:             Option<u32>,
+    /// TokenElevation: `TokenIsElevated`
+    pub elevation:                  Option<bool>,
+    /// TokenHasRestrictions
+    pub has_restrictions:           Option<bool>,
+    /// TokenVirtualizationAllowed
+    pub virtualization_allowed:     Option<bool>,
+    /// TokenVirtualizationEnabled
+    pub virtualization_enabled:     Option<bool>,
+    /// TokenIntegrityLevel
+    pub integrity_level:            Option<SnapshotGroup>,
+    /// TokenUIAccess
+    pub ui_access:                  Option<bool>,
+    /// TokenMandatoryPolicy: `TOKEN_MANDATORY_POLICY_*`
+    pub mandatory_policy:           Option<u32>,
+    /// TokenLogonSid
+    pub logon_sid:                  Option<Vec<SnapshotGroup>>,
+    /// TokenIsAppContainer
+    pub is_app_container:           Option<bool>,
+    /// TokenCapabilities
+    pub capabilities:               Option<Vec<SnapshotGroup>>,
+    /// TokenAppContainerSid: [`None`] if the token isn't an app container.
+    pub app_container_sid:          Option<sid::Owned>,
+    /// TokenAppContainerNumber
+    pub app_container_number:       Option<u32>,
+    /// TokenUserClaimAttributes
+    pub user_claims:                Option<Vec<claim::Attribute>>,
+    /// TokenDeviceClaimAttributes
+    pub device_claims:              Option<Vec<claim::Attribute>>,
+    /// TokenDeviceGroups
+    pub device_groups:              Option<Vec<SnapshotGroup>>,
+    /// TokenRestrictedDeviceGroups
+    pub restricted_device_groups:   Option<Vec<SnapshotGroup>>,
+}
+
+/// A SID and its `SE_GROUP_*` attributes, in a [`Snapshot`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotGroup {
+    pub sid:        sid::Owned,
+    pub attributes: sid::Attributes,
+}
+
+/// A privilege LUID and its `SE_PRIVILEGE_*` attributes, in a [`Snapshot`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotPrivilege {
+    pub luid:       u64,
+    pub attributes: u32,
+}
+
+/// TOKEN_SOURCE, in a [`Snapshot`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SnapshotSource {
+    /// `SourceName`, e.g. `*b"User32\0\0"`
+    pub name:       [u8; 8],
+    pub identifier: u64,
+}
+
+/// TOKEN_STATISTICS, in a [`Snapshot`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SnapshotStatistics {
+    pub token_id:               u64,
+    pub authentication_id:      u64,
+    pub expiration_time:        i64,
+    pub token_type:             u32,
+    pub impersonation_level:    u32,
+    pub dynamic_charged:        u32,
+    pub dynamic_available:      u32,
+    pub group_count:            u32,
+    pub privilege_count:        u32,
+    pub modified_id:            u64,
+}
+
+/// A single field that differs between two [`Snapshot`]s
+#[derive(Clone, Copy)]
+pub struct SnapshotDiff<'a> {
+    field:  &'static super::token_snapshot_encoding::Field,
+    left:   &'a Snapshot,
+    right:  &'a Snapshot,
+}
+
+
+
+impl Snapshot {
+    /// \[[microsoft.com](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-gettokeninformation)\]
+    /// GetTokenInformation for every captured `TOKEN_INFORMATION_CLASS`
+    ///
+    /// Classes that fail with `ERROR_INVALID_PARAMETER` or `ERROR_NOT_SUPPORTED` (not applicable to this token, or not supported by this OS) are left [`None`].
+    /// [`Snapshot::source`] is left [`None`] if `token` lacks [`token::QUERY_SOURCE`] access.
+    /// Claims that can't be represented as a [`claim::Attribute`] (e.g. `CLAIM_SECURITY_ATTRIBUTE_TYPE_FQBN` claims) are silently skipped rather than recorded,
+    /// and claim lists with an unsupported `CLAIM_SECURITY_ATTRIBUTES_INFORMATION` version are left [`None`].
+    ///
+    /// ### Errors
+    /// *   `ERROR_ACCESS_DENIED`   if `token` lacks [`token::QUERY`] access
+    /// *   any other error from GetTokenInformation
+    ///
+    #[cfg(windows)] pub fn collect(token: &token::OwnedHandle) -> firehazard::Result<Self> {
+        use winapi::um::winnt::{TOKEN_SOURCE, TOKEN_TYPE, TOKEN_ELEVATION_TYPE, SECURITY_IMPERSONATION_LEVEL, CLAIM_SECURITY_ATTRIBUTES_INFORMATION, CLAIM_SECURITY_ATTRIBUTES_INFORMATION_VERSION_V1};
+
+        fn optional<T>(r: firehazard::Result<T>) -> firehazard::Result<Option<T>> {
+            match r {
+                Ok(v) => Ok(Some(v)),
+                Err(e) if e == ERROR_INVALID_PARAMETER || e == ERROR_NOT_SUPPORTED => Ok(None),
+                Err(e) => Err(e),
+            }
+        }
+        fn group(g: &sid::AndAttributes) -> SnapshotGroup { SnapshotGroup { sid: (&*g.sid).into(), attributes: g.attributes } }
+        fn groups(g: token::BoxTokenGroups) -> Vec<SnapshotGroup> { g.groups().iter().map(group).collect() }
+        fn claims(info: &CLAIM_SECURITY_ATTRIBUTES_INFORMATION) -> Option<Vec<claim::Attribute>> {
+            if info.Version != CLAIM_SECURITY_ATTRIBUTES_INFORMATION_VERSION_V1 { return None }
+            if info.AttributeCount == 0 { return Some(Vec::new()) }
+            let attributes = unsafe { *info.Attribute.pAttributeV1() };
+            if attributes.is_null() { return None }
+            let attributes = unsafe { core::slice::from_raw_parts(attributes, usize::from32(info.AttributeCount)) };
+            Some(attributes.iter().filter_map(|a| unsafe { claim::Attribute::from_v1(a) }.ok()).collect())
+        }
+
+        Ok(Self {
+            user:                       optional(token.user())?.map(|u| group(u.user())),
+            groups:                     optional(token.groups())?.map(groups),
+            privileges:                 optional(token.privileges())?.map(|p| p.privileges().iter().map(|p| SnapshotPrivilege { luid: u64::from(p.luid.0), attributes: p.attributes.into() }).collect()),
+            owner:                      optional(token.owner())?.map(|o| (&*o.owner()).into()),
+            primary_group:              optional(token.primary_group())?.map(|g| (&*g.primary_group()).into()),
+            default_dacl:               optional(token.default_dacl())?.map(|d| d.default_dacl().map_or(Vec::new(), |acl| acl.as_bytes().to_vec())),
+            source:                     match token.source() { Err(e) if e == ERROR_ACCESS_DENIED => None, r => optional(r)? }.map(|s| { let s = TOKEN_SOURCE::from(s); SnapshotSource { name: s.SourceName.map(|c| c as u8), identifier: u64::from(Luid::from(s.SourceIdentifier)) } }),
+            token_type:                 optional(token.ty())?.map(|t| TOKEN_TYPE::from(t)),
+            impersonation_level:        optional(token.impersonation_level())?.map(|l| SECURITY_IMPERSONATION_LEVEL::from(l)),
+            statistics:                 optional(token.statistics())?.map(|s| SnapshotStatistics {
+                token_id:               u64::from(Luid::from(s.TokenId)),
+                authentication_id:      u64::from(Luid::from(s.AuthenticationId)),
+                expiration_time:        unsafe { *s.ExpirationTime.QuadPart() },
+                token_type:             s.TokenType,
+                impersonation_level:    s.ImpersonationLevel,
+                dynamic_charged:        s.DynamicCharged,
+                dynamic_available:      s.DynamicAvailable,
+                group_count:            s.GroupCount,
+                privilege_count:        s.PrivilegeCount,
+                modified_id:            u64::from(Luid::from(s.ModifiedId)),
+            }),
+            restricted_sids:            optional(token.restricted_sids())?.map(groups),
+            session_id:                 optional(token.session_id())?,
+            sandbox_inert:              optional(token.sandbox_inert())?,
+            origin:                     optional(token.origin())?.map(|o| u64::from(Luid::from(o.OriginatingLogonSession))),
+            elevation_type:             optional(token.elevation_type())?.map(|t| TOKEN_ELEVATION_TYPE::from(t)),
+            elevation:                  optional(token.is_elevated())?,
+            has_restrictions:           optional(token.has_restrictions())?,
+            virtualization_allowed:     optional(token.virtualization_allowed())?,
+            virtualization_enabled:     optional(token.virtualization_enabled())?,
+            integrity_level:            optional(token.integrity_level())?.map(|l| group(l.label())),
+            ui_access:                  optional(token.ui_access())?,
+            mandatory_policy:           optional(token.mandatory_policy())?.map(u32::from),
+            logon_sid:                  optional(token.logon_sid())?.map(groups),
+            is_app_container:           optional(token.is_app_container())?,
+            capabilities:               optional(token.capabilities())?.map(groups),
+            app_container_sid:          optional(token.app_container_sid())?.and_then(|a| { let sid = a.token_app_container(); (!sid.as_psid().is_null()).then(|| (&*sid).into()) }),
+            app_container_number:       optional(token.app_container_number())?,
+            user_claims:                optional(token.user_claim_attributes())?.and_then(|c| claims(&c)),
+            device_claims:              optional(token.device_claim_attributes())?.and_then(|c| claims(&c)),
+            device_groups:              optional(token.device_groups())?.map(groups),
+            restricted_device_groups:   optional(token.restricted_device_groups())?.map(groups),
+        })
+    }
+
+    /// Every field that differs between `self` and `other`, including fields available in only one of the snapshots.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = SnapshotDiff<'a>> + 'a {
+        super::token_snapshot_encoding::FIELDS.iter().filter(move |f| !(f.eq)(self, other)).map(move |field| SnapshotDiff { field, left: self, right: other })
+    }
+}
+
+impl SnapshotDiff<'_> {
+    /// e.g. `"session_id"`
+    pub fn field(&self) -> &'static str { self.field.name }
+}
+
+/// `field: left -> right`, with `(unavailable)` for missing fields
+impl Display for SnapshotDiff<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "{}: {} -> {}", self.field.name, self.field.text(self.left), self.field.text(self.right))
+    }
+}
+
+impl fmt::Debug for SnapshotDiff<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "token::SnapshotDiff({self})") }
+}
@@ -0,0 +1,499 @@
+use crate::prelude::*;
+use token::{Snapshot, SnapshotGroup, SnapshotPrivilege, SnapshotSource, SnapshotStatistics, SnapshotDecodeError as E};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+
+
+const TEXT_HEADER   : &str      = "token::Snapshot v1";
+const SIGNATURE     : &[u8; 4]  = b"FHTS";
+const VERSION       : u16       = 1;
+
+impl Snapshot {
+    /// Encode in the binary format described in the [`Snapshot`] docs.
+    ///
+    /// ### Errors
+    /// *   [`claim::AttributeError`] if a claim can't be encoded as a `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1` (only possible for hand built claims)
+    pub fn to_bytes(&self) -> Result<Vec<u8>, claim::AttributeError> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(SIGNATURE);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        for field in FIELDS {
+            if !(field.present)(self) { continue }
+            bytes.extend_from_slice(&field.class.to_le_bytes());
+            let len = bytes.len();
+            bytes.extend_from_slice(&0u32.to_le_bytes());
+            (field.write_bin)(self, &mut bytes)?;
+            let size = (bytes.len() - len - 4) as u32;
+            bytes[len .. len + 4].copy_from_slice(&size.to_le_bytes());
+        }
+        Ok(bytes)
+    }
+
+    /// Decode the binary format described in the [`Snapshot`] docs.
+    /// Every length is bounds checked, so `bytes` may come from an untrusted source.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, E> {
+        let mut r = Reader(bytes);
+        if r.take(4) != Some(SIGNATURE) { return Err(E::MissingHeader) }
+        let version = r.u16().ok_or(E::Truncated)?;
+        if version != VERSION { return Err(E::UnsupportedVersion(version)) }
+
+        let mut snapshot = Self::default();
+        let mut seen = Seen::default();
+        while !r.0.is_empty() {
+            let class   = r.u16().ok_or(E::Truncated)?;
+            let len     = r.u32().ok_or(E::Truncated)?;
+            let payload = r.take(usize::from32(len)).ok_or(E::Truncated)?;
+            let Some(field) = seen.insert(FIELDS.iter().position(|f| f.class == class))? else { continue };
+            let mut payload = Reader(payload);
+            if (field.read_bin)(&mut snapshot, &mut payload).is_none() || !payload.0.is_empty() { return Err(E::InvalidValue(field.name)) }
+        }
+        Ok(snapshot)
+    }
+
+    /// Encode in the text format described in the [`Snapshot`] docs.
+    ///
+    /// ### Errors
+    /// *   [`claim::AttributeError`] if a claim can't be encoded as a `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1` (only possible for hand built claims)
+    pub fn to_text(&self) -> Result<String, claim::AttributeError> {
+        for claim in self.user_claims.iter().chain(self.device_claims.iter()).flatten() { claim.to_relative_bytes()?; }
+        Ok(Text(self).to_string())
+    }
+}
+
+/// [`Snapshot::to_text`], once every claim is known to be encodable
+struct Text<'a>(&'a Snapshot);
+
+impl Display for Text<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        writeln!(fmt, "{TEXT_HEADER}")?;
+        for field in FIELDS.iter().filter(|f| (f.present)(self.0)) {
+            writeln!(fmt, "{} = {}", field.name, field.text(self.0))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Snapshot {
+    type Err = E;
+
+    /// Parse the text format described in the [`Snapshot`] docs.
+    fn from_str(s: &str) -> Result<Self, E> {
+        let mut lines = s.lines().enumerate().map(|(i, line)| (i + 1, line.trim())).filter(|(_, line)| !line.is_empty());
+        match lines.next() {
+            Some((_, header)) if header == TEXT_HEADER  => {},
+            Some((_, header)) if header.starts_with("token::Snapshot v") => return Err(E::UnsupportedVersion(header["token::Snapshot v".len()..].parse().unwrap_or(u16::MAX))),
+            _                                           => return Err(E::MissingHeader),
+        }
+
+        let mut snapshot = Self::default();
+        let mut seen = Seen::default();
+        for (line, text) in lines {
+            let (key, value) = text.split_once('=').ok_or(E::Syntax { line })?;
+            let (key, value) = (key.trim(), value.trim());
+            if key.is_empty() { return Err(E::Syntax { line }) }
+            let Some(field) = seen.insert(FIELDS.iter().position(|f| f.name == key))? else { continue };
+            (field.read_text)(&mut snapshot, value).ok_or(E::InvalidValue(field.name))?;
+        }
+        Ok(snapshot)
+    }
+}
+
+
+
+/// A captured `TOKEN_INFORMATION_CLASS`: its name, and type erased accessors for [`Snapshot`]'s field
+pub(super) struct Field {
+    pub(super) class:       u16,
+    pub(super) name:        &'static str,
+    pub(super) eq:          fn(&Snapshot, &Snapshot) -> bool,
+    present:                fn(&Snapshot) -> bool,
+    write_text:             fn(&Snapshot, &mut Formatter) -> fmt::Result,
+    read_text:              fn(&mut Snapshot, &str) -> Option<()>,
+    write_bin:              fn(&Snapshot, &mut Vec<u8>) -> Result<(), claim::AttributeError>,
+    read_bin:               fn(&mut Snapshot, &mut Reader) -> Option<()>,
+}
+
+impl Field {
+    /// The field's value in `snapshot` as text, or `(unavailable)`
+    pub(super) fn text<'a>(&'a self, snapshot: &'a Snapshot) -> impl Display + 'a { FieldText(self, snapshot) }
+}
+
+/// A bitset of the [`FIELDS`] (by index) already decoded.
+/// Unknown fields aren't tracked: repeats of those are skipped just like the first occurrence.
+#[derive(Default)] struct Seen(u64);
+const _ : () = assert!(FIELDS.len() <= 64);
+
+impl Seen {
+    /// [`None`] for unknown fields, or [`SnapshotDecodeError::Duplicate`](E::Duplicate) if the field was already seen.
+    fn insert(&mut self, index: Option<usize>) -> Result<Option<&'static Field>, E> {
+        let Some(index) = index else { return Ok(None) };
+        let field = &FIELDS[index];
+        if self.0 & (1 << index) != 0 { return Err(E::Duplicate(field.name)) }
+        self.0 |= 1 << index;
+        Ok(Some(field))
+    }
+}
+
+struct FieldText<'a>(&'a Field, &'a Snapshot);
+
+impl Display for FieldText<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if (self.0.present)(self.1) { (self.0.write_text)(self.1, fmt) } else { fmt.write_str("(unavailable)") }
+    }
+}
+
+macro_rules! fields { ($( $class:literal $field:ident ),* $(,)?) => {
+    pub(super) const FIELDS : &[Field] = &[$(
+        Field {
+            class:      $class,
+            name:       stringify!($field),
+            eq:         |a, b| a.$field == b.$field,
+            present:    |s| s.$field.is_some(),
+            write_text: |s, fmt| s.$field.as_ref().map_or(Ok(()), |v| v.write_text(fmt)),
+            read_text:  |s, text| { s.$field = Some(Value::read_text(text)?); Some(()) },
+            write_bin:  |s, out| s.$field.as_ref().map_or(Ok(()), |v| v.write_bin(out)),
+            read_bin:   |s, r| { s.$field = Some(Value::read_bin(r)?); Some(()) },
+        },
+    )*];
+}}
+
+fields! {
+    1   user,
+    2   groups,
+    3   privileges,
+    4   owner,
+    5   primary_group,
+    6   default_dacl,
+    7   source,
+    8   token_type,
+    9   impersonation_level,
+    10  statistics,
+    11  restricted_sids,
+    12  session_id,
+    15  sandbox_inert,
+    17  origin,
+    18  elevation_type,
+    20  elevation,
+    21  has_restrictions,
+    23  virtualization_allowed,
+    24  virtualization_enabled,
+    25  integrity_level,
+    26  ui_access,
+    27  mandatory_policy,
+    28  logon_sid,
+    29  is_app_container,
+    30  capabilities,
+    31  app_container_sid,
+    32  app_container_number,
+    33  user_claims,
+    34  device_claims,
+    37  device_groups,
+    38  restricted_device_groups,
+}
+
+
+
+/// Text and binary encoding of a single field's value
+trait Value : Sized {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result;
+    fn read_text(text: &str) -> Option<Self>;
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError>;
+    fn read_bin(r: &mut Reader) -> Option<Self>;
+}
+
+pub(super) struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.0.len() { return None }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Some(head)
+    }
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> { self.take(N)?.try_into().ok() }
+    fn u16(&mut self) -> Option<u16> { self.array().map(u16::from_le_bytes) }
+    fn u32(&mut self) -> Option<u32> { self.array().map(u32::from_le_bytes) }
+    fn u64(&mut self) -> Option<u64> { self.array().map(u64::from_le_bytes) }
+}
+
+/// Decimal, or `0x` hexadecimal
+fn parse_u64(text: &str) -> Option<u64> {
+    match text.strip_prefix("0x") {
+        Some(hex)   => u64::from_str_radix(hex, 16).ok(),
+        None        => text.parse().ok(),
+    }
+}
+
+fn write_hex(fmt: &mut Formatter, bytes: &[u8]) -> fmt::Result {
+    bytes.iter().try_for_each(|b| write!(fmt, "{b:02x}"))
+}
+
+fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() { return None }
+    (0 .. text.len()).step_by(2).map(|i| u8::from_str_radix(&text[i .. i + 2], 16).ok()).collect()
+}
+
+/// Whitespace separated words, which must number exactly `N`
+fn words<const N: usize>(text: &str) -> Option<[&str; N]> {
+    let mut words = text.split_whitespace();
+    let result = core::array::from_fn(|_| words.next().unwrap_or(""));
+    (words.next().is_none() && result.iter().all(|w| !w.is_empty())).then_some(result)
+}
+
+impl Value for bool {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(self, fmt) }
+    fn read_text(text: &str) -> Option<Self> { text.parse().ok() }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { out.push((*self).into()); Ok(()) }
+    fn read_bin(r: &mut Reader) -> Option<Self> { match r.take(1)? { [0] => Some(false), [1] => Some(true), _ => None } }
+}
+
+impl Value for u32 {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(self, fmt) }
+    fn read_text(text: &str) -> Option<Self> { parse_u64(text)?.try_into().ok() }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { out.extend_from_slice(&self.to_le_bytes()); Ok(()) }
+    fn read_bin(r: &mut Reader) -> Option<Self> { r.u32() }
+}
+
+impl Value for u64 {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "0x{self:x}") }
+    fn read_text(text: &str) -> Option<Self> { parse_u64(text) }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { out.extend_from_slice(&self.to_le_bytes()); Ok(()) }
+    fn read_bin(r: &mut Reader) -> Option<Self> { r.u64() }
+}
+
+impl Value for sid::Owned {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { Display::fmt(self, fmt) }
+    fn read_text(text: &str) -> Option<Self> { text.parse().ok() }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { out.extend_from_slice(self.as_bytes()); Ok(()) }
+    fn read_bin(r: &mut Reader) -> Option<Self> {
+        let sub_authorities = *r.0.get(1)?;
+        sid::Owned::from_bytes(r.take(8 + 4 * usize::from(sub_authorities))?)
+    }
+}
+
+/// `S-1-1-0 0x7`
+impl Value for SnapshotGroup {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "{} 0x{:x}", self.sid, u32::from(self.attributes)) }
+    fn read_text(text: &str) -> Option<Self> {
+        let [sid, attributes] = words(text)?;
+        Some(Self { sid: sid.parse().ok()?, attributes: unsafe { sid::Attributes::from_unchecked(u32::read_text(attributes)?) } })
+    }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { u32::from(self.attributes).write_bin(out)?; self.sid.write_bin(out) }
+    fn read_bin(r: &mut Reader) -> Option<Self> {
+        let attributes = unsafe { sid::Attributes::from_unchecked(r.u32()?) };
+        Some(Self { attributes, sid: sid::Owned::read_bin(r)? })
+    }
+}
+
+/// `0x17 0x3`
+impl Value for SnapshotPrivilege {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { write!(fmt, "0x{:x} 0x{:x}", self.luid, self.attributes) }
+    fn read_text(text: &str) -> Option<Self> {
+        let [luid, attributes] = words(text)?;
+        Some(Self { luid: parse_u64(luid)?, attributes: u32::read_text(attributes)? })
+    }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { self.luid.write_bin(out)?; self.attributes.write_bin(out) }
+    fn read_bin(r: &mut Reader) -> Option<Self> { Some(Self { luid: r.u64()?, attributes: r.u32()? }) }
+}
+
+/// `5573657233320000 0x1234` (hex encoded name, identifier)
+impl Value for SnapshotSource {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { write_hex(fmt, &self.name)?; write!(fmt, " 0x{:x}", self.identifier) }
+    fn read_text(text: &str) -> Option<Self> {
+        let [name, identifier] = words(text)?;
+        Some(Self { name: parse_hex(name)?.try_into().ok()?, identifier: parse_u64(identifier)? })
+    }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { out.extend_from_slice(&self.name); self.identifier.write_bin(out) }
+    fn read_bin(r: &mut Reader) -> Option<Self> { Some(Self { name: r.array()?, identifier: r.u64()? }) }
+}
+
+/// `token_id authentication_id expiration_time token_type impersonation_level dynamic_charged dynamic_available group_count privilege_count modified_id`
+impl Value for SnapshotStatistics {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result {
+        let s = self;
+        write!(fmt, "0x{:x} 0x{:x} {} {} {} {} {} {} {} 0x{:x}", s.token_id, s.authentication_id, s.expiration_time, s.token_type, s.impersonation_level, s.dynamic_charged, s.dynamic_available, s.group_count, s.privilege_count, s.modified_id)
+    }
+    fn read_text(text: &str) -> Option<Self> {
+        let [token_id, authentication_id, expiration_time, token_type, impersonation_level, dynamic_charged, dynamic_available, group_count, privilege_count, modified_id] = words(text)?;
+        Some(Self {
+            token_id:               parse_u64(token_id)?,
+            authentication_id:      parse_u64(authentication_id)?,
+            expiration_time:        expiration_time.parse().ok()?,
+            token_type:             u32::read_text(token_type)?,
+            impersonation_level:    u32::read_text(impersonation_level)?,
+            dynamic_charged:        u32::read_text(dynamic_charged)?,
+            dynamic_available:      u32::read_text(dynamic_available)?,
+            group_count:            u32::read_text(group_count)?,
+            privilege_count:        u32::read_text(privilege_count)?,
+            modified_id:            parse_u64(modified_id)?,
+        })
+    }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> {
+        let s = self;
+        for q in [s.token_id, s.authentication_id, s.expiration_time as u64] { q.write_bin(out)? }
+        for d in [s.token_type, s.impersonation_level, s.dynamic_charged, s.dynamic_available, s.group_count, s.privilege_count] { d.write_bin(out)? }
+        s.modified_id.write_bin(out)
+    }
+    fn read_bin(r: &mut Reader) -> Option<Self> {
+        Some(Self {
+            token_id:               r.u64()?,
+            authentication_id:      r.u64()?,
+            expiration_time:        r.u64()? as i64,
+            token_type:             r.u32()?,
+            impersonation_level:    r.u32()?,
+            dynamic_charged:        r.u32()?,
+            dynamic_available:      r.u32()?,
+            group_count:            r.u32()?,
+            privilege_count:        r.u32()?,
+            modified_id:            r.u64()?,
+        })
+    }
+}
+
+/// Hex encoded bytes
+impl Value for Vec<u8> {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result { write_hex(fmt, self) }
+    fn read_text(text: &str) -> Option<Self> { parse_hex(text) }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> { out.extend_from_slice(self); Ok(()) }
+    fn read_bin(r: &mut Reader) -> Option<Self> { Some(r.take(r.0.len())?.to_vec()) }
+}
+
+/// Hex encoded `CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1`
+impl Value for claim::Attribute {
+    fn write_text(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self.to_relative_bytes() {
+            Ok(bytes)   => write_hex(fmt, &bytes),
+            Err(err)    => write!(fmt, "(invalid: {err})"), // only reachable from SnapshotDiff: Snapshot::to_text checks claims first
+        }
+    }
+    fn read_text(text: &str) -> Option<Self> { claim::Attribute::from_relative_bytes(&parse_hex(text)?).ok() }
+    fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> {
+        let bytes = self.to_relative_bytes()?;
+        (bytes.len() as u32).write_bin(out)?;
+        out.extend_from_slice(&bytes);
+        Ok(())
+    }
+    fn read_bin(r: &mut Reader) -> Option<Self> {
+        let len = r.u32()?;
+        claim::Attribute::from_relative_bytes(r.take(usize::from32(len))?).ok()
+    }
+}
+
+/// Comma separated items / `u32` count prefixed items
+macro_rules! list { ($($ty:ty),* $(,)?) => {$(
+    impl Value for Vec<$ty> {
+        fn write_text(&self, fmt: &mut Formatter) -> fmt::Result {
+            for (i, item) in self.iter().enumerate() {
+                if i != 0 { fmt.write_str(", ")? }
+                item.write_text(fmt)?;
+            }
+            Ok(())
+        }
+        fn read_text(text: &str) -> Option<Self> {
+            if text.is_empty() { return Some(Vec::new()) }
+            text.split(',').map(|item| <$ty>::read_text(item.trim())).collect()
+        }
+        fn write_bin(&self, out: &mut Vec<u8>) -> Result<(), claim::AttributeError> {
+            (self.len() as u32).write_bin(out)?;
+            self.iter().try_for_each(|item| item.write_bin(out))
+        }
+        fn read_bin(r: &mut Reader) -> Option<Self> {
+            let count = r.u32()?;
+            let mut items = Vec::new();
+            for _ in 0 .. count { items.push(<$ty>::read_bin(r)?) }
+            Some(items)
+        }
+    }
+)*}}
+
+list!(SnapshotGroup, SnapshotPrivilege, claim::Attribute);
+
+
+
+#[cfg(std)] #[test] fn round_trip() {
+    use std::string::ToString;
+    use std::{format, vec};
+
+    let sid = |s: &str| s.parse::<sid::Owned>().unwrap();
+    let group = |s: &str, attributes: u32| SnapshotGroup { sid: sid(s), attributes: unsafe { sid::Attributes::from_unchecked(attributes) } };
+
+    assert_eq!(Snapshot::default(), TEXT_HEADER.parse().unwrap());
+    assert_eq!(Snapshot::default(), Snapshot::from_bytes(&Snapshot::default().to_bytes().unwrap()).unwrap());
+
+    let snapshot = Snapshot {
+        user:                   Some(group("S-1-5-21-1-2-3-1001", 0)),
+        groups:                 Some(vec![group("S-1-1-0", 7), group("S-1-5-32-544", 0x10), group("S-1-5-5-0-99", 0xC0000007)]),
+        privileges:             Some(vec![SnapshotPrivilege { luid: 0x17, attributes: 3 }, SnapshotPrivilege { luid: 0x13, attributes: 0 }]),
+        owner:                  Some(sid("S-1-5-21-1-2-3-1001")),
+        default_dacl:           Some(vec![2, 0, 8, 0, 0, 0, 0, 0]),
+        source:                 Some(SnapshotSource { name: *b"User32\0\0", identifier: 0x1234 }),
+        token_type:             Some(1),
+        statistics:             Some(SnapshotStatistics { token_id: 0x1_0000_0001, expiration_time: i64::MAX, group_count: 3, ..Default::default() }),
+        restricted_sids:        Some(vec![]),
+        session_id:             Some(1),
+        elevation:              Some(false),
+        integrity_level:        Some(group("S-1-16-4096", 0x20)),
+        capabilities:           Some(vec![group("S-1-15-3-1", 4)]),
+        user_claims:            Some(vec![claim::Attribute::new("Clearance", claim::Values::Int64(vec![3, -1]))]),
+        .. Snapshot::default()
+    };
+
+    let text = snapshot.to_text().unwrap();
+    assert!(text.starts_with("token::Snapshot v1\nuser = S-1-5-21-1-2-3-1001 0x0\ngroups = S-1-1-0 0x7, S-1-5-32-544 0x10, S-1-5-5-0-99 0xc0000007\n"), "{text}");
+    assert!(text.contains("\nsource = 5573657233320000 0x1234\n"), "{text}");
+    assert!(text.contains("\nrestricted_sids = \n"), "{text}");
+    assert_eq!(snapshot, text.parse().unwrap());
+
+    let bytes = snapshot.to_bytes().unwrap();
+    assert_eq!(&bytes[..12], b"FHTS\x01\x00\x01\x00\x20\x00\x00\x00");
+    assert_eq!(snapshot, Snapshot::from_bytes(&bytes).unwrap());
+
+    // forwards compatibility
+    assert_eq!(snapshot, format!("{text}future_field = 123\n").parse().unwrap());
+    let mut future = bytes.clone();
+    future.extend_from_slice(&[99, 0, 2, 0, 0, 0, 1, 2]);
+    assert_eq!(snapshot, Snapshot::from_bytes(&future).unwrap());
+    assert_eq!(snapshot, format!("{text}future_field = 123\nfuture_field = 456\n").parse().unwrap());
+    future.extend_from_slice(&[99, 0, 0, 0, 0, 0]);
+    assert_eq!(snapshot, Snapshot::from_bytes(&future).unwrap());
+
+    // hand built claims that can't be encoded
+    let invalid = Snapshot { device_claims: Some(vec![claim::Attribute::new("", claim::Values::Boolean(vec![true]))]), ..snapshot.clone() };
+    assert_eq!(Err(claim::AttributeError::InvalidName), invalid.to_text());
+    assert_eq!(Err(claim::AttributeError::InvalidName), invalid.to_bytes());
+    let diff = snapshot.diff(&invalid).map(|d| d.to_string()).collect::<Vec<_>>();
+    assert_eq!(diff, ["device_claims: (unavailable) -> (invalid: claim attribute name is empty or contains '\\0')"]);
+
+    let diff = snapshot.diff(&Snapshot { session_id: Some(2), elevation: None, ..snapshot.clone() }).map(|d| d.to_string()).collect::<Vec<_>>();
+    assert_eq!(diff, ["session_id: 1 -> 2", "elevation: false -> (unavailable)"]);
+
+    for (text, err) in [
+        ("",                                            E::MissingHeader),
+        ("token::Snapshot v2",                          E::UnsupportedVersion(2)),
+        ("token::Snapshot v1\nuser",                    E::Syntax { line: 2 }),
+        ("token::Snapshot v1\n\n = 1",                  E::Syntax { line: 3 }),
+        ("token::Snapshot v1\nsession_id = -1",         E::InvalidValue("session_id")),
+        ("token::Snapshot v1\nuser = S-1-1-0",          E::InvalidValue("user")),
+        ("token::Snapshot v1\ngroups = S-1-1-0 0x7,",   E::InvalidValue("groups")),
+        ("token::Snapshot v1\nsource = 00 0x0",         E::InvalidValue("source")),
+        ("token::Snapshot v1\nui_access = 1",           E::InvalidValue("ui_access")),
+        ("token::Snapshot v1\norigin = 1\norigin = 1",  E::Duplicate("origin")),
+    ] {
+        assert_eq!(Err(err), text.parse::<Snapshot>(), "{text:?}");
+    }
+
+    assert_eq!(Err(E::MissingHeader), Snapshot::from_bytes(b"FHT"));
+    assert_eq!(Err(E::UnsupportedVersion(2)), Snapshot::from_bytes(b"FHTS\x02\x00"));
+    for len in 7 .. bytes.len() {
+        match Snapshot::from_bytes(&bytes[..len]) {
+            Ok(partial) => assert_ne!(snapshot, partial, "{len}"), // truncated at a record boundary
+            Err(err)    => assert!(matches!(err, E::Truncated | E::InvalidValue(_)), "{len}: {err:?}"),
+        }
+    }
+    assert_eq!(Err(E::InvalidValue("session_id")), Snapshot::from_bytes(b"FHTS\x01\x00\x0C\x00\x05\x00\x00\x00\x01\x00\x00\x00\x00"));
+    assert_eq!(Err(E::Duplicate("session_id")), Snapshot::from_bytes(b"FHTS\x01\x00\x0C\x00\x04\x00\x00\x00\x01\x00\x00\x00\x0C\x00\x04\x00\x00\x00\x01\x00\x00\x00"));
+}
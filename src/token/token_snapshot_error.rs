@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Display, Formatter};
+
+
+
+/// Error parsing a [`token::Snapshot`] from its text or binary encoding
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive] pub enum SnapshotDecodeError {
+    /// The text doesn't start with a `token::Snapshot v…` line, or the bytes don't start with `b"FHTS"`.
+    MissingHeader,
+    /// The snapshot was encoded by a newer, incompatible version.
+    UnsupportedVersion(u16),
+    /// A (1-based) line of text isn't a `field = value` pair.
+    Syntax { line: usize },
+    /// The bytes end in the middle of a header or record.
+    Truncated,
+    /// A (known) field was encoded more than once.
+    Duplicate(&'static str),
+    /// A field's value is malformed.
+    InvalidValue(&'static str),
+}
+
+impl From<SnapshotDecodeError> for firehazard::Error {
+    fn from(_: SnapshotDecodeError) -> Self { Self(ERROR_INVALID_DATA) }
+}
+
+#[cfg(std)] impl From<SnapshotDecodeError> for std::io::Error {
+    fn from(err: SnapshotDecodeError) -> Self { std::io::Error::new(std::io::ErrorKind::InvalidData, err) }
+}
+
+impl core::error::Error for SnapshotDecodeError {}
+
+impl Display for SnapshotDecodeError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            SnapshotDecodeError::MissingHeader          => write!(fmt, "token snapshot is missing its header"),
+            SnapshotDecodeError::UnsupportedVersion(v)  => write!(fmt, "unsupported token snapshot version {v}"),
+            SnapshotDecodeError::Syntax { line }        => write!(fmt, "token snapshot line {line} isn't a `field = value` pair"),
+            SnapshotDecodeError::Truncated              => write!(fmt, "token snapshot is truncated"),
+            SnapshotDecodeError::Duplicate(field)       => write!(fmt, "token snapshot field {field:?} is present more than once"),
+            SnapshotDecodeError::InvalidValue(field)    => write!(fmt, "token snapshot field {field:?} has a malformed value"),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Minimal [SHA-256](https://csrc.nist.gov/pubs/fips/180-4/upd1/final), for deriving AppContainer and capability SIDs without `bcrypt.dll`.
+
+#![allow(dead_code)] // not every platform/feature combination uses every fn
+
+const K : [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const H0 : [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// Incremental SHA-256 hasher.
+#[derive(Clone)] pub(crate) struct Sha256 {
+    state:  [u32; 8],
+    block:  [u8; 64],
+    len:    u64, // total bytes hashed
+}
+
+impl Sha256 {
+    pub fn new() -> Self { Self { state: H0, block: [0; 64], len: 0 } }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.block[(self.len % 64) as usize] = byte;
+            self.len += 1;
+            if self.len.is_multiple_of(64) { compress(&mut self.state, &self.block); }
+        }
+    }
+
+    pub fn finish(mut self) -> [u8; 32] {
+        let bits = self.len.wrapping_mul(8);
+        self.update(&[0x80]);
+        while self.len % 64 != 56 { self.update(&[0]); }
+        self.update(&bits.to_be_bytes());
+
+        let mut digest = [0; 32];
+        for (out, word) in digest.chunks_exact_mut(4).zip(self.state) { out.copy_from_slice(&word.to_be_bytes()); }
+        digest
+    }
+}
+
+fn compress(state: &mut [u32; 8], block: &[u8; 64]) {
+    let mut w = [0u32; 64];
+    for (w, chunk) in w.iter_mut().zip(block.chunks_exact(4)) { *w = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]); }
+    for i in 16 .. 64 {
+        let s0 = w[i-15].rotate_right(7) ^ w[i-15].rotate_right(18) ^ (w[i-15] >> 3);
+        let s1 = w[i-2].rotate_right(17) ^ w[i-2].rotate_right(19) ^ (w[i-2] >> 10);
+        w[i] = w[i-16].wrapping_add(s0).wrapping_add(w[i-7]).wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0 .. 64 {
+        let s1  = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch  = (e & f) ^ (!e & g);
+        let t1  = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(w[i]);
+        let s0  = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2  = s0.wrapping_add(maj);
+        h = g; g = f; f = e; e = d.wrapping_add(t1);
+        d = c; c = b; b = a; a = t1.wrapping_add(t2);
+    }
+
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) { *s = s.wrapping_add(v); }
+}
+
+#[cfg(std)] #[test] fn known_answers() {
+    let hex = |digest: [u8; 32]| digest.iter().map(|b| std::format!("{b:02x}")).collect::<std::string::String>();
+    let sha256 = |data: &[u8]| { let mut h = Sha256::new(); h.update(data); hex(h.finish()) };
+    assert_eq!(sha256(b""),     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    assert_eq!(sha256(b"abc"),  "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_eq!(sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+
+    let mut h = Sha256::new();
+    for _ in 0 .. 1000 { h.update(&[b'a'; 1000]); }
+    assert_eq!(hex(h.finish()), "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0");
+}
@@ -0,0 +1,40 @@
+//! [`winapi`](https://docs.rs/winapi/0.3) placeholders for non-windows targets
+//!
+//! `winapi` itself is `#![cfg(windows)]`.  This mirrors just enough of its layout (types, structures, and constants - no functions)
+//! to let the portable parts of this crate ([`access`](crate::access), [`ace`](crate::ace), [`acl`](crate::acl), [`sid`](crate::sid), ...)
+//! build and test on any host.
+
+#![allow(dead_code, non_camel_case_types, non_snake_case, non_upper_case_globals, overflowing_literals, unused_imports, clippy::upper_case_acronyms)]
+
+macro_rules! STRUCT {
+    ($(#[debug])? struct $name:ident { $($field:ident : $fty:ty),+ $(,)? }) => {
+        #[repr(C)] #[derive(Clone, Copy)] pub struct $name { $(pub $field : $fty),+ }
+        impl Default for $name { fn default() -> Self { unsafe { core::mem::zeroed() } } }
+    };
+}
+
+pub mod ctypes {
+    pub use core::ffi::c_void;
+    pub type c_char     = i8;
+    pub type c_schar    = i8;
+    pub type c_uchar    = u8;
+    pub type c_short    = i16;
+    pub type c_ushort   = u16;
+    pub type c_int      = i32;
+    pub type c_uint     = u32;
+    pub type c_long     = i32; // LLP64
+    pub type c_ulong    = u32; // LLP64
+    pub type wchar_t    = u16;
+}
+
+pub mod shared {
+    pub mod guiddef;
+    pub mod minwindef;
+    pub mod ntstatus;
+    pub mod sddl;
+    pub mod winerror;
+}
+
+pub mod um {
+    pub mod winnt;
+}
@@ -0,0 +1,11 @@
+// Subset of winapi 0.3.9 (Apache-2.0 OR MIT), for non-windows targets.
+use crate::winapi::ctypes::{c_uchar, c_ulong, c_ushort};
+STRUCT!{#[debug] struct GUID {
+    Data1: c_ulong,
+    Data2: c_ushort,
+    Data3: c_ushort,
+    Data4: [c_uchar; 8],
+}}
+pub type LPGUID = *mut GUID;
+pub type LPCGUID = *const GUID;
+pub type IID = GUID;
@@ -0,0 +1,11 @@
+// Subset of winapi 0.3.9 (Apache-2.0 OR MIT), for non-windows targets.
+use crate::winapi::ctypes::{c_int, c_uchar, c_ulong, c_ushort};
+pub type ULONG = c_ulong;
+pub type USHORT = c_ushort;
+pub type UCHAR = c_uchar;
+pub const FALSE: BOOL = 0;
+pub const TRUE: BOOL = 1;
+pub type DWORD = c_ulong;
+pub type BOOL = c_int;
+pub type BYTE = c_uchar;
+pub type WORD = c_ushort;
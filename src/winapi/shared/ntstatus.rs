@@ -0,0 +1,18 @@
+// Subset of winapi 0.3.9 (Apache-2.0 OR MIT), for non-windows targets.
+use crate::winapi::ctypes::c_long;
+
+pub type NTSTATUS = c_long;
+
+pub const STATUS_SUCCESS: NTSTATUS = 0x00000000;
+pub const STATUS_ACCESS_DENIED: NTSTATUS = 0xC0000022;
+pub const STATUS_ACCESS_VIOLATION: NTSTATUS = 0xC0000005;
+pub const STATUS_BAD_IMPERSONATION_LEVEL: NTSTATUS = 0xC00000A5;
+pub const STATUS_BUFFER_OVERFLOW: NTSTATUS = 0x80000005;
+pub const STATUS_BUFFER_TOO_SMALL: NTSTATUS = 0xC0000023;
+pub const STATUS_DLL_INIT_FAILED: NTSTATUS = 0xC0000142;
+pub const STATUS_DLL_NOT_FOUND: NTSTATUS = 0xC0000135;
+pub const STATUS_INFO_LENGTH_MISMATCH: NTSTATUS = 0xC0000004;
+pub const STATUS_INVALID_HANDLE: NTSTATUS = 0xC0000008;
+pub const STATUS_INVALID_INFO_CLASS: NTSTATUS = 0xC0000003;
+pub const STATUS_NONE_MAPPED: NTSTATUS = 0xC0000073;
+pub const STATUS_OBJECT_TYPE_MISMATCH: NTSTATUS = 0xC0000024;
@@ -0,0 +1,151 @@
+// Subset of winapi 0.3.9 (Apache-2.0 OR MIT), for non-windows targets.
+use super::minwindef::UCHAR;
+pub const SDDL_REVISION_1: UCHAR = 1;
+pub const SDDL_REVISION: UCHAR = SDDL_REVISION_1;
+pub const SDDL_OWNER: &str = "O";
+pub const SDDL_GROUP: &str = "G";
+pub const SDDL_DACL: &str = "D";
+pub const SDDL_SACL: &str = "S";
+pub const SDDL_PROTECTED: &str = "P";
+pub const SDDL_AUTO_INHERIT_REQ: &str = "AR";
+pub const SDDL_AUTO_INHERITED: &str = "AI";
+pub const SDDL_NULL_ACL: &str = "NO_ACCESS_CONTROL";
+pub const SDDL_ACCESS_ALLOWED: &str = "A";
+pub const SDDL_ACCESS_DENIED: &str = "D";
+pub const SDDL_OBJECT_ACCESS_ALLOWED: &str = "OA";
+pub const SDDL_OBJECT_ACCESS_DENIED: &str = "OD";
+pub const SDDL_AUDIT: &str = "AU";
+pub const SDDL_ALARM: &str = "AL";
+pub const SDDL_OBJECT_AUDIT: &str = "OU";
+pub const SDDL_OBJECT_ALARM: &str = "OL";
+pub const SDDL_MANDATORY_LABEL: &str = "ML";
+pub const SDDL_PROCESS_TRUST_LABEL: &str = "TL";
+pub const SDDL_CALLBACK_ACCESS_ALLOWED: &str = "XA";
+pub const SDDL_CALLBACK_ACCESS_DENIED: &str = "XD";
+pub const SDDL_RESOURCE_ATTRIBUTE: &str = "RA";
+pub const SDDL_SCOPED_POLICY_ID: &str = "SP";
+pub const SDDL_CALLBACK_AUDIT: &str = "XU";
+pub const SDDL_CALLBACK_OBJECT_ACCESS_ALLOWED: &str = "ZA";
+pub const SDDL_ACCESS_FILTER: &str = "FL";
+pub const SDDL_INT: &str = "TI";
+pub const SDDL_UINT: &str = "TU";
+pub const SDDL_WSTRING: &str = "TS";
+pub const SDDL_SID: &str = "TD";
+pub const SDDL_BLOB: &str = "TX";
+pub const SDDL_BOOLEAN: &str = "TB";
+pub const SDDL_CONTAINER_INHERIT: &str = "CI";
+pub const SDDL_OBJECT_INHERIT: &str = "OI";
+pub const SDDL_NO_PROPAGATE: &str = "NP";
+pub const SDDL_INHERIT_ONLY: &str = "IO";
+pub const SDDL_INHERITED: &str = "ID";
+pub const SDDL_TRUST_PROTECTED_FILTER: &str = "TP";
+pub const SDDL_AUDIT_SUCCESS: &str = "SA";
+pub const SDDL_AUDIT_FAILURE: &str = "FA";
+pub const SDDL_READ_PROPERTY: &str = "RP";
+pub const SDDL_WRITE_PROPERTY: &str = "WP";
+pub const SDDL_CREATE_CHILD: &str = "CC";
+pub const SDDL_DELETE_CHILD: &str = "DC";
+pub const SDDL_LIST_CHILDREN: &str = "LC";
+pub const SDDL_SELF_WRITE: &str = "SW";
+pub const SDDL_LIST_OBJECT: &str = "LO";
+pub const SDDL_DELETE_TREE: &str = "DT";
+pub const SDDL_CONTROL_ACCESS: &str = "CR";
+pub const SDDL_READ_CONTROL: &str = "RC";
+pub const SDDL_WRITE_DAC: &str = "WD";
+pub const SDDL_WRITE_OWNER: &str = "WO";
+pub const SDDL_STANDARD_DELETE: &str = "SD";
+pub const SDDL_GENERIC_ALL: &str = "GA";
+pub const SDDL_GENERIC_READ: &str = "GR";
+pub const SDDL_GENERIC_WRITE: &str = "GW";
+pub const SDDL_GENERIC_EXECUTE: &str = "GX";
+pub const SDDL_FILE_ALL: &str = "FA";
+pub const SDDL_FILE_READ: &str = "FR";
+pub const SDDL_FILE_WRITE: &str = "FW";
+pub const SDDL_FILE_EXECUTE: &str = "FX";
+pub const SDDL_KEY_ALL: &str = "KA";
+pub const SDDL_KEY_READ: &str = "KR";
+pub const SDDL_KEY_WRITE: &str = "KW";
+pub const SDDL_KEY_EXECUTE: &str = "KX";
+pub const SDDL_NO_WRITE_UP: &str = "NW";
+pub const SDDL_NO_READ_UP: &str = "NR";
+pub const SDDL_NO_EXECUTE_UP: &str = "NX";
+pub const SDDL_DOMAIN_ADMINISTRATORS: &str = "DA";
+pub const SDDL_DOMAIN_GUESTS: &str = "DG";
+pub const SDDL_DOMAIN_USERS: &str = "DU";
+pub const SDDL_ENTERPRISE_DOMAIN_CONTROLLERS: &str = "ED";
+pub const SDDL_DOMAIN_DOMAIN_CONTROLLERS: &str = "DD";
+pub const SDDL_DOMAIN_COMPUTERS: &str = "DC";
+pub const SDDL_BUILTIN_ADMINISTRATORS: &str = "BA";
+pub const SDDL_BUILTIN_GUESTS: &str = "BG";
+pub const SDDL_BUILTIN_USERS: &str = "BU";
+pub const SDDL_LOCAL_ADMIN: &str = "LA";
+pub const SDDL_LOCAL_GUEST: &str = "LG";
+pub const SDDL_ACCOUNT_OPERATORS: &str = "AO";
+pub const SDDL_BACKUP_OPERATORS: &str = "BO";
+pub const SDDL_PRINTER_OPERATORS: &str = "PO";
+pub const SDDL_SERVER_OPERATORS: &str = "SO";
+pub const SDDL_AUTHENTICATED_USERS: &str = "AU";
+pub const SDDL_PERSONAL_SELF: &str = "PS";
+pub const SDDL_CREATOR_OWNER: &str = "CO";
+pub const SDDL_CREATOR_GROUP: &str = "CG";
+pub const SDDL_LOCAL_SYSTEM: &str = "SY";
+pub const SDDL_POWER_USERS: &str = "PU";
+pub const SDDL_EVERYONE: &str = "WD";
+pub const SDDL_REPLICATOR: &str = "RE";
+pub const SDDL_INTERACTIVE: &str = "IU";
+pub const SDDL_NETWORK: &str = "NU";
+pub const SDDL_SERVICE: &str = "SU";
+pub const SDDL_RESTRICTED_CODE: &str = "RC";
+pub const SDDL_WRITE_RESTRICTED_CODE: &str = "WR";
+pub const SDDL_ANONYMOUS: &str = "AN";
+pub const SDDL_SCHEMA_ADMINISTRATORS: &str = "SA";
+pub const SDDL_CERT_SERV_ADMINISTRATORS: &str = "CA";
+pub const SDDL_RAS_SERVERS: &str = "RS";
+pub const SDDL_ENTERPRISE_ADMINS: &str = "EA";
+pub const SDDL_GROUP_POLICY_ADMINS: &str = "PA";
+pub const SDDL_ALIAS_PREW2KCOMPACC: &str = "RU";
+pub const SDDL_LOCAL_SERVICE: &str = "LS";
+pub const SDDL_NETWORK_SERVICE: &str = "NS";
+pub const SDDL_REMOTE_DESKTOP: &str = "RD";
+pub const SDDL_NETWORK_CONFIGURATION_OPS: &str = "NO";
+pub const SDDL_PERFMON_USERS: &str = "MU";
+pub const SDDL_PERFLOG_USERS: &str = "LU";
+pub const SDDL_IIS_USERS: &str = "IS";
+pub const SDDL_CRYPTO_OPERATORS: &str = "CY";
+pub const SDDL_OWNER_RIGHTS: &str = "OW";
+pub const SDDL_EVENT_LOG_READERS: &str = "ER";
+pub const SDDL_ENTERPRISE_RO_DCs: &str = "RO";
+pub const SDDL_CERTSVC_DCOM_ACCESS: &str = "CD";
+pub const SDDL_ALL_APP_PACKAGES: &str = "AC";
+pub const SDDL_RDS_REMOTE_ACCESS_SERVERS: &str = "RA";
+pub const SDDL_RDS_ENDPOINT_SERVERS: &str = "ES";
+pub const SDDL_RDS_MANAGEMENT_SERVERS: &str = "MS";
+pub const SDDL_USER_MODE_DRIVERS: &str = "UD";
+pub const SDDL_HYPER_V_ADMINS: &str = "HA";
+pub const SDDL_CLONEABLE_CONTROLLERS: &str = "CN";
+pub const SDDL_ACCESS_CONTROL_ASSISTANCE_OPS: &str = "AA";
+pub const SDDL_REMOTE_MANAGEMENT_USERS: &str = "RM";
+pub const SDDL_AUTHORITY_ASSERTED: &str = "AS";
+pub const SDDL_SERVICE_ASSERTED: &str = "SS";
+pub const SDDL_PROTECTED_USERS: &str = "AP";
+pub const SDDL_KEY_ADMINS: &str = "KA";
+pub const SDDL_ENTERPRISE_KEY_ADMINS: &str = "EK";
+pub const SDDL_ML_LOW: &str = "LW";
+pub const SDDL_ML_MEDIUM: &str = "ME";
+pub const SDDL_ML_MEDIUM_PLUS: &str = "MP";
+pub const SDDL_ML_HIGH: &str = "HI";
+pub const SDDL_ML_SYSTEM: &str = "SI";
+pub const SDDL_SEPERATOR: &str = ";";
+pub const SDDL_DELIMINATOR: &str = ":";
+pub const SDDL_ACE_BEGIN: &str = "(";
+pub const SDDL_ACE_END: &str = ")";
+pub const SDDL_ACE_COND_BEGIN: &str = "(";
+pub const SDDL_ACE_COND_END: &str = ")";
+pub const SDDL_SPACE: &str = " ";
+pub const SDDL_ACE_COND_BLOB_PREFIX: &str = "#";
+pub const SDDL_ACE_COND_SID_PREFIX: &str = "SID";
+pub const SDDL_ACE_COND_ATTRIBUTE_PREFIX: &str = "@";
+pub const SDDL_ACE_COND_USER_ATTRIBUTE_PREFIX: &str = "@USER.";
+pub const SDDL_ACE_COND_RESOURCE_ATTRIBUTE_PREFIX: &str = "@RESOURCE.";
+pub const SDDL_ACE_COND_DEVICE_ATTRIBUTE_PREFIX: &str = "@DEVICE.";
+pub const SDDL_ACE_COND_TOKEN_ATTRIBUTE_PREFIX: &str = "@TOKEN.";
@@ -0,0 +1,49 @@
+// Subset of winapi 0.3.9 (Apache-2.0 OR MIT), for non-windows targets.
+use super::minwindef::DWORD;
+use crate::winapi::ctypes::c_long;
+
+pub type HRESULT = c_long;
+
+#[inline] pub fn SUCCEEDED(hr: HRESULT) -> bool { hr >= 0 }
+#[inline] pub fn FAILED(hr: HRESULT) -> bool { hr < 0 }
+
+pub const ERROR_ACCESS_DENIED: DWORD = 5;
+pub const ERROR_ALLOTTED_SPACE_EXCEEDED: DWORD = 1344;
+pub const ERROR_ALREADY_EXISTS: DWORD = 183;
+pub const ERROR_BAD_ARGUMENTS: DWORD = 160;
+pub const ERROR_BAD_ENVIRONMENT: DWORD = 10;
+pub const ERROR_BAD_INHERITANCE_ACL: DWORD = 1340;
+pub const ERROR_BAD_LENGTH: DWORD = 24;
+pub const ERROR_BAD_PATHNAME: DWORD = 161;
+pub const ERROR_BAD_TOKEN_TYPE: DWORD = 1349;
+pub const ERROR_BROKEN_PIPE: DWORD = 109;
+pub const ERROR_BUSY: DWORD = 170;
+pub const ERROR_CALL_NOT_IMPLEMENTED: DWORD = 120;
+pub const ERROR_DYNAMIC_CODE_BLOCKED: DWORD = 1655;
+pub const ERROR_EXCL_SEM_ALREADY_OWNED: DWORD = 101;
+pub const ERROR_FILE_NOT_FOUND: DWORD = 2;
+pub const ERROR_ILLEGAL_CHARACTER: DWORD = 582;
+pub const ERROR_INCORRECT_SIZE: DWORD = 1462;
+pub const ERROR_INSUFFICIENT_BUFFER: DWORD = 122;
+pub const ERROR_INVALID_ACL: DWORD = 1336;
+pub const ERROR_INVALID_DATA: DWORD = 13;
+pub const ERROR_INVALID_ENVIRONMENT: DWORD = 1805;
+pub const ERROR_INVALID_HANDLE: DWORD = 6;
+pub const ERROR_INVALID_ID_AUTHORITY: DWORD = 1343;
+pub const ERROR_INVALID_PARAMETER: DWORD = 87;
+pub const ERROR_INVALID_SECURITY_DESCR: DWORD = 1338;
+pub const ERROR_INVALID_SID: DWORD = 1337;
+pub const ERROR_INVALID_SUB_AUTHORITY: DWORD = 1335;
+pub const ERROR_NONE_MAPPED: DWORD = 1332;
+pub const ERROR_NOT_APPCONTAINER: DWORD = 4250;
+pub const ERROR_NOT_SUPPORTED: DWORD = 50;
+pub const ERROR_NO_SUCH_LOGON_SESSION: DWORD = 1312;
+pub const ERROR_NO_SUCH_PRIVILEGE: DWORD = 1313;
+pub const ERROR_NO_TOKEN: DWORD = 1008;
+pub const ERROR_PARTIAL_COPY: DWORD = 299;
+pub const ERROR_PATH_NOT_FOUND: DWORD = 3;
+pub const ERROR_PIPE_LOCAL: DWORD = 229;
+pub const ERROR_PRIVILEGE_NOT_HELD: DWORD = 1314;
+pub const E_INVALIDARG: HRESULT = 0x80070057;
+pub const E_STRING_NOT_NULL_TERMINATED: HRESULT = 0x80000017;
+pub const NO_ERROR: DWORD = 0;